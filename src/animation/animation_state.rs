@@ -41,8 +41,9 @@ pub struct AnimationState {
     /// Whether this animation has completed or not
     completed: bool,
 
-    /// The time this animation started.
-    start_time: Option<f64>,
+    /// The number of animation frames played so far, accumulated each update so that changes
+    /// to animation_speed do not cause the animation to jump.
+    animation_frame: Option<f64>,
 
     /// The index of the current animation frame.
     current_frame_index: usize,
@@ -59,6 +60,9 @@ pub struct AnimationState {
 
     /// Seconds to delay animation start by
     start_delay: Option<f32>,
+
+    /// Seconds to hold the current frame for, used for a short pause when a hit lands
+    hit_stop: f32,
 }
 
 impl Default for AnimationState {
@@ -68,7 +72,7 @@ impl Default for AnimationState {
             completed: false,
             max_loop_count: Some(1),
             animation_speed: 1.0,
-            start_time: None,
+            animation_frame: None,
             interpolate_weight: 0.0,
             current_loop_count: 0,
             current_frame_fract: 0.0,
@@ -76,6 +80,7 @@ impl Default for AnimationState {
             next_frame_index: 1,
             last_absolute_event_frame: 0,
             start_delay: None,
+            hit_stop: 0.0,
         }
     }
 }
@@ -127,6 +132,10 @@ impl AnimationState {
         }
     }
 
+    pub fn set_hit_stop(&mut self, duration: f32) {
+        self.hit_stop = self.hit_stop.max(duration);
+    }
+
     pub fn completed(&self) -> bool {
         self.completed
     }
@@ -175,20 +184,23 @@ impl AnimationState {
             }
         }
 
-        let current_time = time.elapsed_seconds_f64();
-        let start_time = if let Some(start_time) = self.start_time {
-            start_time
-        } else {
-            self.start_time = Some(current_time);
-            current_time
-        };
+        if self.hit_stop > 0.0 {
+            self.hit_stop -= time.delta_seconds();
+            return false;
+        }
 
         if self.interpolate_weight < 1.0 {
             self.interpolate_weight += time.delta_seconds() / zmo_asset.interpolation_interval;
         }
 
-        let animation_frame_number =
-            (current_time - start_time) * (zmo_asset.fps as f64) * self.animation_speed as f64;
+        let animation_frame_number = if let Some(animation_frame) = self.animation_frame.as_mut() {
+            *animation_frame +=
+                time.delta_seconds_f64() * (zmo_asset.fps as f64) * self.animation_speed as f64;
+            *animation_frame
+        } else {
+            self.animation_frame = Some(0.0);
+            0.0
+        };
 
         self.current_loop_count = animation_frame_number as usize / zmo_asset.num_frames;
         self.completed = self.current_loop_count >= self.max_loop_count.unwrap_or(usize::MAX);
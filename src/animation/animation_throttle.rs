@@ -0,0 +1,137 @@
+use bevy::{
+    math::Vec3A,
+    prelude::{
+        Camera3d, Commands, Component, Entity, GlobalTransform, Or, Query, Res, ResMut, Resource,
+        With, Without,
+    },
+    reflect::Reflect,
+    render::primitives::{Frustum, Sphere},
+};
+
+use crate::{
+    animation::SkeletalAnimation,
+    components::{ParticleSequence, TargetCamera},
+};
+
+/// Radius of the bounding sphere used to test if an animated entity is within the view frustum
+const ANIMATION_THROTTLE_BOUNDING_RADIUS: f32 = 3.0;
+
+#[derive(Resource)]
+pub struct AnimationThrottleSettings {
+    pub enabled: bool,
+    /// Entities further than this from the camera are updated at `reduced_interval`
+    pub reduced_distance: f32,
+    pub reduced_interval: f32,
+    /// Entities which are off-screen are updated at `skipped_interval`
+    pub skipped_interval: f32,
+}
+
+impl Default for AnimationThrottleSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            reduced_distance: 50.0,
+            reduced_interval: 1.0 / 15.0,
+            skipped_interval: 0.5,
+        }
+    }
+}
+
+/// Number of animated entities at each throttle level, for the diagnostics window
+#[derive(Default, Resource)]
+pub struct AnimationThrottleStats {
+    pub full: usize,
+    pub reduced: usize,
+    pub skipped: usize,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum AnimationThrottleLevel {
+    #[default]
+    Full,
+    /// Far from the camera
+    Reduced,
+    /// Off-screen
+    Skipped,
+}
+
+#[derive(Component, Default, Reflect)]
+pub struct AnimationThrottle {
+    pub level: AnimationThrottleLevel,
+    /// Time accumulated since the entity was last updated
+    pub pending_delta: f32,
+}
+
+impl AnimationThrottle {
+    /// Accumulates `delta` and returns the total time to update by when the entity is due an
+    /// update at its current throttle level.
+    pub fn tick(&mut self, settings: &AnimationThrottleSettings, delta: f32) -> Option<f32> {
+        self.pending_delta += delta;
+
+        let interval = match self.level {
+            AnimationThrottleLevel::Full => 0.0,
+            AnimationThrottleLevel::Reduced => settings.reduced_interval,
+            AnimationThrottleLevel::Skipped => settings.skipped_interval,
+        };
+
+        if self.pending_delta >= interval {
+            Some(std::mem::take(&mut self.pending_delta))
+        } else {
+            None
+        }
+    }
+}
+
+pub fn animation_throttle_system(
+    mut commands: Commands,
+    mut query_throttled: Query<
+        (Entity, &GlobalTransform, Option<&mut AnimationThrottle>),
+        Or<(With<SkeletalAnimation>, With<ParticleSequence>)>,
+    >,
+    query_camera: Query<(&GlobalTransform, &Frustum), (With<Camera3d>, Without<TargetCamera>)>,
+    settings: Res<AnimationThrottleSettings>,
+    mut stats: ResMut<AnimationThrottleStats>,
+) {
+    let camera = query_camera.get_single().ok();
+    *stats = AnimationThrottleStats::default();
+
+    for (entity, global_transform, throttle) in query_throttled.iter_mut() {
+        let translation = global_transform.translation();
+        let level = match camera {
+            Some((camera_transform, frustum)) if settings.enabled => {
+                let sphere = Sphere {
+                    center: Vec3A::from(translation),
+                    radius: ANIMATION_THROTTLE_BOUNDING_RADIUS,
+                };
+
+                if !frustum.intersects_sphere(&sphere, true) {
+                    AnimationThrottleLevel::Skipped
+                } else if camera_transform.translation().distance(translation)
+                    > settings.reduced_distance
+                {
+                    AnimationThrottleLevel::Reduced
+                } else {
+                    AnimationThrottleLevel::Full
+                }
+            }
+            _ => AnimationThrottleLevel::Full,
+        };
+
+        match level {
+            AnimationThrottleLevel::Full => stats.full += 1,
+            AnimationThrottleLevel::Reduced => stats.reduced += 1,
+            AnimationThrottleLevel::Skipped => stats.skipped += 1,
+        }
+
+        if let Some(mut throttle) = throttle {
+            if throttle.level != level {
+                throttle.level = level;
+            }
+        } else {
+            commands.entity(entity).insert(AnimationThrottle {
+                level,
+                pending_delta: 0.0,
+            });
+        }
+    }
+}
@@ -6,13 +6,18 @@ use bevy::{
 };
 
 mod animation_state;
+mod animation_throttle;
 mod camera_animation;
 mod mesh_animation;
 mod skeletal_animation;
 mod transform_animation;
 mod zmo_asset_loader;
+mod zmo_retarget;
 
 pub use animation_state::AnimationFrameEvent;
+pub use animation_throttle::{
+    AnimationThrottle, AnimationThrottleLevel, AnimationThrottleSettings, AnimationThrottleStats,
+};
 pub use camera_animation::CameraAnimation;
 pub use mesh_animation::MeshAnimation;
 pub use skeletal_animation::SkeletalAnimation;
@@ -20,8 +25,10 @@ pub use transform_animation::TransformAnimation;
 pub use zmo_asset_loader::{
     ZmoAsset, ZmoAssetAnimationTexture, ZmoAssetBone, ZmoAssetLoader, ZmoTextureAssetLoader,
 };
+pub use zmo_retarget::{retarget_zmo, ZmoRetargetBoneError, ZmoRetargetReport};
 
 use animation_state::AnimationState;
+use animation_throttle::animation_throttle_system;
 use camera_animation::camera_animation_system;
 use mesh_animation::mesh_animation_system;
 use skeletal_animation::skeletal_animation_system;
@@ -42,9 +49,12 @@ impl Plugin for RoseAnimationPlugin {
             .init_asset_loader::<ZmoAssetLoader>()
             .init_asset_loader::<ZmoTextureAssetLoader>();
 
-        app.add_event::<AnimationFrameEvent>();
+        app.add_event::<AnimationFrameEvent>()
+            .init_resource::<AnimationThrottleSettings>()
+            .init_resource::<AnimationThrottleStats>();
 
         app.register_type::<AnimationState>()
+            .register_type::<AnimationThrottle>()
             .register_type::<CameraAnimation>()
             .register_type::<MeshAnimation>()
             .register_type::<SkeletalAnimation>()
@@ -57,9 +67,10 @@ impl Plugin for RoseAnimationPlugin {
         .add_systems(
             PostUpdate,
             (
+                animation_throttle_system,
                 camera_animation_system,
                 mesh_animation_system,
-                skeletal_animation_system,
+                skeletal_animation_system.after(animation_throttle_system),
                 transform_animation_system,
             )
                 .in_set(RoseAnimationSystem),
@@ -12,7 +12,9 @@ use bevy::{
 };
 
 use crate::{
-    animation::{AnimationFrameEvent, AnimationState, ZmoAsset},
+    animation::{
+        AnimationFrameEvent, AnimationState, AnimationThrottle, AnimationThrottleSettings, ZmoAsset,
+    },
     resources::GameData,
 };
 
@@ -35,15 +37,21 @@ impl SkeletalAnimation {
 }
 
 pub fn skeletal_animation_system(
-    mut query_animations: Query<(Entity, &mut SkeletalAnimation, Option<&SkinnedMesh>)>,
+    mut query_animations: Query<(
+        Entity,
+        &mut SkeletalAnimation,
+        Option<&SkinnedMesh>,
+        Option<&mut AnimationThrottle>,
+    )>,
     mut query_transform: Query<&mut Transform>,
     mut animation_frame_events: EventWriter<AnimationFrameEvent>,
     motion_assets: Res<Assets<ZmoAsset>>,
     asset_server: Res<AssetServer>,
     game_data: Res<GameData>,
+    throttle_settings: Res<AnimationThrottleSettings>,
     time: Res<Time>,
 ) {
-    for (entity, mut skeletal_animation, skinned_mesh) in query_animations.iter_mut() {
+    for (entity, mut skeletal_animation, skinned_mesh, throttle) in query_animations.iter_mut() {
         if skeletal_animation.completed() {
             continue;
         }
@@ -77,6 +85,17 @@ pub fn skeletal_animation_system(
         let Some(skinned_mesh) = skinned_mesh else {
             continue;
         };
+
+        // The animation always advances so events are still sent, but updating the bones of
+        // far away or off-screen entities can be done less often
+        if let Some(mut throttle) = throttle {
+            if throttle
+                .tick(&throttle_settings, time.delta_seconds())
+                .is_none()
+            {
+                continue;
+            }
+        }
         let current_frame_fract = animation.current_frame_fract();
         let current_frame_index = animation.current_frame_index();
         let next_frame_index = animation.next_frame_index();
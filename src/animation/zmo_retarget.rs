@@ -0,0 +1,104 @@
+use thiserror::Error;
+
+use rose_file_readers::ZmdFile;
+
+use crate::animation::{ZmoAsset, ZmoAssetBone};
+
+#[derive(Error, Debug)]
+pub enum ZmoRetargetBoneError {
+    #[error("not in source skeleton")]
+    MissingSourceBone,
+    #[error("not in target skeleton")]
+    MissingTargetBone,
+    #[error("parent bone differs, source {source_parent} target {target_parent}")]
+    ParentMismatch {
+        source_parent: usize,
+        target_parent: usize,
+    },
+}
+
+#[derive(Default)]
+pub struct ZmoRetargetReport {
+    pub num_mapped_bones: usize,
+    pub failed_bones: Vec<(usize, ZmoRetargetBoneError)>,
+}
+
+fn bone_length(skeleton: &ZmdFile, bone_id: usize) -> f32 {
+    skeleton.bones.get(bone_id).map_or(0.0, |bone| {
+        (bone.position.x * bone.position.x
+            + bone.position.y * bone.position.y
+            + bone.position.z * bone.position.z)
+            .sqrt()
+    })
+}
+
+/// Retargets a motion authored for the source skeleton onto the target skeleton.
+///
+/// Bones are mapped by index, a bone only maps if it exists in both skeletons with the same
+/// parent. Rotations are copied as is, translations are scaled by the ratio of the bone lengths
+/// to compensate for the difference in skeleton proportions. Bones which fail to map keep the
+/// original animation data and are returned in the report.
+pub fn retarget_zmo(
+    zmo: &ZmoAsset,
+    source: &ZmdFile,
+    target: &ZmdFile,
+) -> (ZmoAsset, ZmoRetargetReport) {
+    let mut report = ZmoRetargetReport::default();
+    let mut bones = Vec::with_capacity(zmo.bones.len());
+
+    for (bone_id, bone) in zmo.bones.iter().enumerate() {
+        if bone.translation.is_empty() && bone.rotation.is_empty() && bone.scale.is_empty() {
+            bones.push(ZmoAssetBone::default());
+            continue;
+        }
+
+        let mapping = match (source.bones.get(bone_id), target.bones.get(bone_id)) {
+            (None, _) => Err(ZmoRetargetBoneError::MissingSourceBone),
+            (_, None) => Err(ZmoRetargetBoneError::MissingTargetBone),
+            (Some(source_bone), Some(target_bone)) if source_bone.parent != target_bone.parent => {
+                Err(ZmoRetargetBoneError::ParentMismatch {
+                    source_parent: source_bone.parent as usize,
+                    target_parent: target_bone.parent as usize,
+                })
+            }
+            _ => Ok(()),
+        };
+
+        if let Err(error) = mapping {
+            report.failed_bones.push((bone_id, error));
+            bones.push(bone.clone());
+            continue;
+        }
+
+        let source_length = bone_length(source, bone_id);
+        let target_length = bone_length(target, bone_id);
+        let translation_scale = if source_length > f32::EPSILON {
+            target_length / source_length
+        } else {
+            1.0
+        };
+
+        report.num_mapped_bones += 1;
+        bones.push(ZmoAssetBone {
+            translation: bone
+                .translation
+                .iter()
+                .map(|translation| *translation * translation_scale)
+                .collect(),
+            rotation: bone.rotation.clone(),
+            scale: bone.scale.clone(),
+        });
+    }
+
+    (
+        ZmoAsset {
+            num_frames: zmo.num_frames,
+            fps: zmo.fps,
+            frame_events: zmo.frame_events.clone(),
+            interpolation_interval: zmo.interpolation_interval,
+            bones,
+            animation_texture: None,
+        },
+        report,
+    )
+}
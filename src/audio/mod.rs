@@ -52,57 +52,73 @@ use self::{
     spatial_sound::spatial_sound_gain_changed_system,
 };
 
+const DEFAULT_SAMPLE_RATE: u32 = 44100;
+
+fn create_output_stream<S>(root_mixer: S) -> Option<(cpal::Stream, u32)>
+where
+    S: oddio::Signal<Frame = [f32; 2]> + Send + 'static,
+{
+    let host = cpal::default_host();
+    let device = host.default_output_device()?;
+    let sample_rate = device.default_output_config().ok()?.sample_rate();
+    let config = cpal::StreamConfig {
+        channels: 2,
+        sample_rate,
+        buffer_size: cpal::BufferSize::Default,
+    };
+
+    let stream = device
+        .build_output_stream(
+            &config,
+            move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+                let frames = oddio::frame_stereo(data);
+                oddio::run(&root_mixer, sample_rate.0, frames);
+            },
+            move |err| {
+                eprintln!("{}", err);
+            },
+            None,
+        )
+        .ok()?;
+    stream.play().ok()?;
+
+    Some((stream, sample_rate.0))
+}
+
 pub struct OddioPlugin;
 
 impl Plugin for OddioPlugin {
     fn build(&self, app: &mut App) {
-        let host = cpal::default_host();
-        let device = host
-            .default_output_device()
-            .expect("no output device available");
-        let sample_rate = device.default_output_config().unwrap().sample_rate();
-        let config = cpal::StreamConfig {
-            channels: 2,
-            sample_rate,
-            buffer_size: cpal::BufferSize::Default,
-        };
-
         let (mut root_mixer_handle, root_mixer) = oddio::split(oddio::Mixer::new());
         let (scene_handle, scene) = oddio::split(oddio::SpatialScene::new());
         root_mixer_handle.control().play(scene);
 
-        let stream = device
-            .build_output_stream(
-                &config,
-                move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
-                    let frames = oddio::frame_stereo(data);
-                    oddio::run(&root_mixer, sample_rate.0, frames);
-                },
-                move |err| {
-                    eprintln!("{}", err);
-                },
-                None,
-            )
-            .unwrap();
-        stream.play().unwrap();
-
-        app.insert_non_send_resource(stream)
-            .insert_resource(OddioContext {
-                mixer: root_mixer_handle,
-                spatial: scene_handle,
-                sample_rate: sample_rate.0,
-            })
-            .add_asset::<AudioSource>()
-            .init_asset_loader::<OggLoader>()
-            .init_asset_loader::<WavLoader>()
-            .add_systems(
-                Last,
-                (
-                    spatial_sound_gain_changed_system.before(spatial_sound_system),
-                    spatial_sound_system,
-                    global_sound_gain_changed_system.before(global_sound_system),
-                    global_sound_system,
-                ),
-            );
+        // When there is no output device, such as when running headless, we still create the
+        // mixer so that sounds can be spawned, they just never play.
+        let mut sample_rate = DEFAULT_SAMPLE_RATE;
+        if let Some((stream, stream_sample_rate)) = create_output_stream(root_mixer) {
+            sample_rate = stream_sample_rate;
+            app.insert_non_send_resource(stream);
+        } else {
+            log::warn!("No audio output device available, sound will be disabled");
+        }
+
+        app.insert_resource(OddioContext {
+            mixer: root_mixer_handle,
+            spatial: scene_handle,
+            sample_rate,
+        })
+        .add_asset::<AudioSource>()
+        .init_asset_loader::<OggLoader>()
+        .init_asset_loader::<WavLoader>()
+        .add_systems(
+            Last,
+            (
+                spatial_sound_gain_changed_system.before(spatial_sound_system),
+                spatial_sound_system,
+                global_sound_gain_changed_system.before(global_sound_system),
+                global_sound_system,
+            ),
+        );
     }
 }
@@ -3,14 +3,14 @@ use bevy::{
     math::Vec3,
     prelude::{
         AssetServer, Assets, Camera3d, Changed, Commands, Component, Entity, GlobalTransform,
-        Handle, Local, Query, Res, ResMut, With,
+        Handle, Local, Query, Res, ResMut, With, Without,
     },
     time::Time,
 };
 
 use crate::{
     audio::{AudioSource, OddioContext, SoundGain, SoundRadius, StreamingSound},
-    components::PlayerCharacter,
+    components::{PlayerCharacter, TargetCamera},
 };
 
 struct SpatialControlHandle(
@@ -86,7 +86,7 @@ pub fn spatial_sound_system(
     mut context: ResMut<OddioContext>,
     audio: Res<Assets<AudioSource>>,
     asset_server: Res<AssetServer>,
-    camera: Query<&GlobalTransform, With<Camera3d>>,
+    camera: Query<&GlobalTransform, (With<Camera3d>, Without<TargetCamera>)>,
     mut query_spatial_sounds: Query<(
         Entity,
         &mut SpatialSound,
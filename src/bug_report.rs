@@ -0,0 +1,76 @@
+use std::{
+    io::{Cursor, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+
+use crate::http::http_post;
+
+pub struct BugReport {
+    pub description: String,
+    pub snapshot: String,
+    pub chat_history: Vec<String>,
+    pub log_history: Vec<String>,
+    pub packet_history: Option<Vec<String>>,
+    pub screenshot_png: Option<Vec<u8>>,
+}
+
+fn write_zip_file(
+    zip: &mut zip::ZipWriter<Cursor<Vec<u8>>>,
+    name: &str,
+    data: &[u8],
+) -> Result<()> {
+    zip.start_file(name, zip::write::FileOptions::default())?;
+    zip.write_all(data)?;
+    Ok(())
+}
+
+pub fn create_bug_report_zip(report: &BugReport) -> Result<Vec<u8>> {
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+
+    write_zip_file(&mut zip, "description.txt", report.description.as_bytes())?;
+    write_zip_file(&mut zip, "snapshot.txt", report.snapshot.as_bytes())?;
+    write_zip_file(
+        &mut zip,
+        "chat.txt",
+        report.chat_history.join("\n").as_bytes(),
+    )?;
+    write_zip_file(
+        &mut zip,
+        "log.txt",
+        report.log_history.join("\n").as_bytes(),
+    )?;
+
+    if let Some(packet_history) = report.packet_history.as_ref() {
+        write_zip_file(
+            &mut zip,
+            "packets.txt",
+            packet_history.join("\n").as_bytes(),
+        )?;
+    }
+
+    if let Some(screenshot_png) = report.screenshot_png.as_ref() {
+        write_zip_file(&mut zip, "screenshot.png", screenshot_png)?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+/// Saves the bug report zip into the given directory, returns the path of the written file.
+pub fn save_bug_report(directory: &Path, report: &BugReport) -> Result<PathBuf> {
+    let zip = create_bug_report_zip(report)?;
+    let path = directory.join(format!(
+        "bug_report_{}.zip",
+        chrono::Local::now().format("%Y%m%d_%H%M%S")
+    ));
+
+    std::fs::create_dir_all(directory)?;
+    std::fs::write(&path, zip)?;
+    Ok(path)
+}
+
+pub fn upload_bug_report(url: &str, report: &BugReport) -> Result<()> {
+    let zip = create_bug_report_zip(report)?;
+    http_post(url, "application/zip", &zip)
+}
@@ -1,6 +1,11 @@
 mod ability_values;
+mod skills;
 
 pub use ability_values::{
     ability_values_add_value, ability_values_add_value_exclusive, ability_values_get_value,
     ability_values_set_value, ability_values_set_value_exclusive,
 };
+pub use skills::{
+    skill_can_target_entity, skill_can_use, skill_has_target_entity, skill_is_beneficial,
+    SkillCasterQuery, SkillCasterQueryItem, SkillTargetQuery, SkillTargetQueryItem, SkillUseError,
+};
@@ -0,0 +1,247 @@
+use bevy::{ecs::query::WorldQuery, math::Vec3Swizzles, prelude::Entity};
+
+use rose_data::{AbilityType, SkillData, SkillTargetFilter, SkillType};
+use rose_game_common::components::{
+    AbilityValues, CharacterInfo, Equipment, ExperiencePoints, HealthPoints, Inventory, Level,
+    ManaPoints, MoveSpeed, SkillPoints, Stamina, StatPoints, Team, UnionMembership,
+};
+
+use crate::{
+    bundles::ability_values_get_value,
+    components::{Clan, ClientEntity, ClientEntityType, Command, PartyInfo, Position},
+    resources::GameData,
+};
+
+#[derive(WorldQuery)]
+pub struct SkillCasterQuery<'w> {
+    pub entity: Entity,
+
+    pub ability_values: &'w AbilityValues,
+    pub character_info: &'w CharacterInfo,
+    pub command: &'w Command,
+    pub equipment: &'w Equipment,
+    pub experience_points: &'w ExperiencePoints,
+    pub health_points: &'w HealthPoints,
+    pub inventory: &'w Inventory,
+    pub level: &'w Level,
+    pub mana_points: &'w ManaPoints,
+    pub move_speed: &'w MoveSpeed,
+    pub position: &'w Position,
+    pub skill_points: &'w SkillPoints,
+    pub stamina: &'w Stamina,
+    pub stat_points: &'w StatPoints,
+    pub team: &'w Team,
+    pub union_membership: &'w UnionMembership,
+    pub clan: Option<&'w Clan>,
+    pub party_info: Option<&'w PartyInfo>,
+}
+
+#[derive(WorldQuery)]
+pub struct SkillTargetQuery<'w> {
+    pub entity: Entity,
+
+    pub character_info: Option<&'w CharacterInfo>,
+    pub client_entity: &'w ClientEntity,
+    pub command: &'w Command,
+    pub position: &'w Position,
+    pub team: &'w Team,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum SkillUseError {
+    InvalidStance,
+    NotEnoughAbility(AbilityType),
+    RequiredEquipment,
+    InvalidTarget,
+    OutOfRange,
+}
+
+impl SkillUseError {
+    pub fn get_message(&self, game_data: &GameData) -> String {
+        match *self {
+            SkillUseError::InvalidStance => "Cannot use skills right now".to_string(),
+            SkillUseError::NotEnoughAbility(ability_type) => format!(
+                "Not enough {}",
+                game_data.string_database.get_ability_type(ability_type)
+            ),
+            SkillUseError::RequiredEquipment => "Required equipment is not equipped".to_string(),
+            SkillUseError::InvalidTarget => "Invalid target".to_string(),
+            SkillUseError::OutOfRange => "Target is out of range".to_string(),
+        }
+    }
+}
+
+pub fn skill_has_target_entity(skill_data: &SkillData) -> bool {
+    matches!(
+        skill_data.skill_type,
+        SkillType::EnforceWeapon
+            | SkillType::Immediate
+            | SkillType::TargetBound
+            | SkillType::TargetBoundDuration
+            | SkillType::TargetStateDuration
+            | SkillType::SelfAndTarget
+            | SkillType::Resurrection
+            | SkillType::EnforceBullet
+            | SkillType::FireBullet
+            | SkillType::AreaTarget
+    )
+}
+
+pub fn skill_is_beneficial(skill_data: &SkillData) -> bool {
+    matches!(
+        skill_data.target_filter,
+        SkillTargetFilter::OnlySelf
+            | SkillTargetFilter::Group
+            | SkillTargetFilter::Guild
+            | SkillTargetFilter::Allied
+    )
+}
+
+pub fn skill_can_use(
+    game_data: &GameData,
+    caster: &SkillCasterQueryItem,
+    skill_data: &SkillData,
+) -> Result<(), SkillUseError> {
+    if !matches!(
+        skill_data.skill_type,
+        SkillType::BasicAction | SkillType::Emote
+    ) && matches!(caster.command, Command::Sit(_) | Command::PersonalStore)
+    {
+        return Err(SkillUseError::InvalidStance);
+    }
+
+    for &(ability_type, mut value) in skill_data.use_ability.iter() {
+        if matches!(ability_type, AbilityType::Mana) {
+            let use_mana_rate = (100 - caster.ability_values.get_save_mana()) as f32 / 100.0;
+            value = (value as f32 * use_mana_rate) as i32;
+        }
+
+        let current_value = ability_values_get_value(
+            ability_type,
+            caster.ability_values,
+            Some(caster.character_info),
+            Some(caster.experience_points),
+            Some(caster.health_points),
+            Some(caster.inventory),
+            Some(caster.level),
+            Some(caster.mana_points),
+            Some(caster.move_speed),
+            Some(caster.skill_points),
+            Some(caster.stamina),
+            Some(caster.stat_points),
+            Some(caster.team),
+            Some(caster.union_membership),
+        );
+        if current_value.map_or(false, |current_value| current_value < value) {
+            return Err(SkillUseError::NotEnoughAbility(ability_type));
+        }
+    }
+
+    if !skill_data.required_equipment_class.is_empty()
+        && !caster
+            .equipment
+            .equipped_items
+            .iter()
+            .filter_map(|(_, item)| item.as_ref())
+            .filter_map(|item| game_data.items.get_base_item(item.item))
+            .any(|item_data| {
+                skill_data
+                    .required_equipment_class
+                    .contains(&item_data.class)
+            })
+    {
+        return Err(SkillUseError::RequiredEquipment);
+    }
+
+    Ok(())
+}
+
+pub fn skill_can_target_entity(
+    caster: &SkillCasterQueryItem,
+    target: &SkillTargetQueryItem,
+    skill_data: &SkillData,
+) -> Result<(), SkillUseError> {
+    let target_is_alive = !target.command.is_die();
+    let target_is_caster = target.entity == caster.entity;
+    let target_is_valid = match skill_data.target_filter {
+        SkillTargetFilter::OnlySelf => target_is_alive && target_is_caster,
+        SkillTargetFilter::Group => {
+            target_is_alive
+                && (target_is_caster
+                    || caster.party_info.map_or(false, |party_info| {
+                        party_info.contains_member(target.client_entity.id)
+                    }))
+        }
+        SkillTargetFilter::Guild => {
+            target_is_alive
+                && (target_is_caster
+                    || target.character_info.map_or(false, |character_info| {
+                        caster.clan.map_or(false, |clan| {
+                            clan.find_member(&character_info.name).is_some()
+                        })
+                    }))
+        }
+        SkillTargetFilter::Allied => target_is_alive && target.team.id == caster.team.id,
+        SkillTargetFilter::Monster => {
+            target_is_alive && matches!(target.client_entity.entity_type, ClientEntityType::Monster)
+        }
+        SkillTargetFilter::Enemy => {
+            target_is_alive
+                && target.team.id != Team::DEFAULT_NPC_TEAM_ID
+                && target.team.id != caster.team.id
+        }
+        SkillTargetFilter::EnemyCharacter => {
+            target_is_alive
+                && target.team.id != caster.team.id
+                && matches!(
+                    target.client_entity.entity_type,
+                    ClientEntityType::Character
+                )
+        }
+        SkillTargetFilter::Character => {
+            target_is_alive
+                && matches!(
+                    target.client_entity.entity_type,
+                    ClientEntityType::Character
+                )
+        }
+        SkillTargetFilter::CharacterOrMonster => {
+            target_is_alive
+                && matches!(
+                    target.client_entity.entity_type,
+                    ClientEntityType::Character | ClientEntityType::Monster
+                )
+        }
+        SkillTargetFilter::DeadAlliedCharacter => {
+            !target_is_alive
+                && target.team.id == caster.team.id
+                && matches!(
+                    target.client_entity.entity_type,
+                    ClientEntityType::Character
+                )
+        }
+        SkillTargetFilter::EnemyMonster => {
+            target_is_alive
+                && target.team.id != caster.team.id
+                && matches!(target.client_entity.entity_type, ClientEntityType::Monster)
+        }
+    };
+
+    if !target_is_valid {
+        return Err(SkillUseError::InvalidTarget);
+    }
+
+    if !target_is_caster
+        && skill_data.cast_range > 0
+        && caster
+            .position
+            .position
+            .xy()
+            .distance(target.position.position.xy())
+            > skill_data.cast_range as f32
+    {
+        return Err(SkillUseError::OutOfRange);
+    }
+
+    Ok(())
+}
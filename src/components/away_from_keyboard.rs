@@ -0,0 +1,5 @@
+use bevy::{prelude::Component, reflect::Reflect};
+
+/// Added to the player whilst they are away, shown as an AFK tag in their name tag
+#[derive(Component, Default, Reflect)]
+pub struct AwayFromKeyboard;
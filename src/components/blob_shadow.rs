@@ -0,0 +1,35 @@
+use bevy::{
+    math::Vec3,
+    prelude::{Component, Entity},
+    reflect::Reflect,
+};
+
+/// A blob shadow decal drawn on the terrain under the owner entity
+#[derive(Component, Reflect)]
+pub struct BlobShadow {
+    pub owner: Entity,
+    pub radius: f32,
+    /// Owner translation when the decal mesh was last conformed to the heightmap
+    pub last_translation: Option<Vec3>,
+}
+
+impl BlobShadow {
+    pub fn new(owner: Entity, radius: f32) -> Self {
+        Self {
+            owner,
+            radius,
+            last_translation: None,
+        }
+    }
+}
+
+#[derive(Component, Reflect)]
+pub struct BlobShadowEntity {
+    pub entity: Entity,
+}
+
+impl BlobShadowEntity {
+    pub fn new(entity: Entity) -> Self {
+        Self { entity }
+    }
+}
@@ -0,0 +1,5 @@
+use bevy::{prelude::Component, reflect::Reflect};
+
+/// Deco zone object with a campfire effect, only used to show a status icon whilst resting nearby
+#[derive(Component, Reflect)]
+pub struct Campfire;
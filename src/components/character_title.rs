@@ -0,0 +1,13 @@
+use bevy::{prelude::Component, reflect::Reflect};
+
+/// The active title of a character, shown on a second row of their name tag
+#[derive(Component, Clone, Reflect)]
+pub struct CharacterTitle {
+    pub title: String,
+}
+
+impl CharacterTitle {
+    pub fn new(title: String) -> Self {
+        Self { title }
+    }
+}
@@ -26,6 +26,12 @@ pub struct CommandEmote {
     pub is_stop: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Reflect)]
+pub struct CommandHitStun {
+    /// The attack to resume once the hit stun motion has completed
+    pub resume_attack_target: Option<Entity>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Reflect)]
 pub enum CommandSit {
     Sitting,
@@ -69,6 +75,7 @@ pub enum Command {
     Emote(CommandEmote),
     Sit(CommandSit),
     CastSkill(CommandCastSkill),
+    HitStun(CommandHitStun),
 }
 
 impl Command {
@@ -108,6 +115,12 @@ impl Command {
         Self::Emote(CommandEmote { motion_id, is_stop })
     }
 
+    pub fn with_hit_stun(resume_attack_target: Option<Entity>) -> Self {
+        Self::HitStun(CommandHitStun {
+            resume_attack_target,
+        })
+    }
+
     pub fn with_move(
         destination: Vec3,
         target: Option<Entity>,
@@ -154,6 +167,7 @@ impl Command {
             | Command::Die
             | Command::Emote(_)
             | Command::Sit(_)
+            | Command::HitStun(_)
             | Command::PersonalStore => None,
         }
     }
@@ -174,6 +188,10 @@ impl Command {
         matches!(self, Command::Emote(_))
     }
 
+    pub fn is_hit_stun(&self) -> bool {
+        matches!(self, Command::HitStun(_))
+    }
+
     pub fn is_stop(&self) -> bool {
         matches!(self, Command::Stop)
     }
@@ -209,6 +227,7 @@ impl Command {
                 CommandCastSkillState::CastingRepeat
             ),
             Command::PersonalStore => false,
+            Command::HitStun(_) => true,
         }
     }
 }
@@ -255,6 +274,12 @@ impl NextCommand {
         Self(Some(Command::Emote(CommandEmote { motion_id, is_stop })))
     }
 
+    pub fn with_hit_stun(resume_attack_target: Option<Entity>) -> Self {
+        Self(Some(Command::HitStun(CommandHitStun {
+            resume_attack_target,
+        })))
+    }
+
     pub fn with_move(
         destination: Vec3,
         target: Option<Entity>,
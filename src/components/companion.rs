@@ -0,0 +1,17 @@
+use bevy::prelude::{Component, Entity};
+
+use rose_data::NpcId;
+
+/// Companion (fairy, pet) which should hover near this entity
+#[derive(Component, Clone)]
+pub struct Companion {
+    pub npc_id: NpcId,
+}
+
+/// Spawned model for the Companion of owner_entity
+#[derive(Component)]
+pub struct CompanionModel {
+    pub owner_entity: Entity,
+    pub npc_id: NpcId,
+    pub bob_time: f32,
+}
@@ -100,6 +100,15 @@ impl Cooldowns {
         }
     }
 
+    fn get_cooldown_remaining(&self, cooldown: Option<&(Duration, Duration)>) -> Option<Duration> {
+        let global_remaining = self.global.as_ref().map(|(current, _)| *current);
+
+        match (cooldown.map(|(current, _)| *current), global_remaining) {
+            (Some(remaining), Some(global_remaining)) => Some(remaining.max(global_remaining)),
+            (remaining, global_remaining) => remaining.or(global_remaining),
+        }
+    }
+
     pub fn get_consumable_cooldown_percent(&self, group: ConsumableCooldownGroup) -> Option<f32> {
         self.get_cooldown(self.consumable_items[group].as_ref())
     }
@@ -112,6 +121,24 @@ impl Cooldowns {
         self.get_cooldown(self.skill_groups.get(&group.get()).and_then(|x| x.as_ref()))
     }
 
+    pub fn get_consumable_cooldown_remaining(
+        &self,
+        group: ConsumableCooldownGroup,
+    ) -> Option<Duration> {
+        self.get_cooldown_remaining(self.consumable_items[group].as_ref())
+    }
+
+    pub fn get_skill_cooldown_remaining(&self, skill_id: SkillId) -> Option<Duration> {
+        self.get_cooldown_remaining(self.skills.get(&skill_id.get()).and_then(|x| x.as_ref()))
+    }
+
+    pub fn get_skill_group_cooldown_remaining(
+        &self,
+        group: SkillCooldownGroup,
+    ) -> Option<Duration> {
+        self.get_cooldown_remaining(self.skill_groups.get(&group.get()).and_then(|x| x.as_ref()))
+    }
+
     pub fn set_global_cooldown(&mut self, duration: Duration) {
         self.global = Some((duration, duration));
     }
@@ -1,6 +0,0 @@
-use bevy::prelude::Component;
-
-#[derive(Component)]
-pub struct DamageDigits {
-    pub damage: u32,
-}
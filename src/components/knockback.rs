@@ -0,0 +1,23 @@
+use bevy::{math::Vec3, prelude::Component, reflect::Reflect};
+
+/// Smoothly slides an entity to the position it was knocked back to by the server
+#[derive(Component, Reflect)]
+pub struct Knockback {
+    pub start: Vec3,
+    pub end: Vec3,
+    pub time: f32,
+    pub duration: f32,
+}
+
+impl Knockback {
+    pub const DURATION: f32 = 0.25;
+
+    pub fn new(start: Vec3, end: Vec3) -> Self {
+        Self {
+            start,
+            end,
+            time: 0.0,
+            duration: Self::DURATION,
+        }
+    }
+}
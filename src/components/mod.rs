@@ -1,21 +1,27 @@
+mod away_from_keyboard;
 mod bank;
+mod blob_shadow;
+mod campfire;
 mod character_model;
 mod character_model_blink_timer;
+mod character_title;
 mod clan;
 mod clan_membership;
 mod client_entity;
 mod client_entity_name;
 mod collision;
 mod command;
+mod companion;
 mod cooldowns;
-mod damage_digits;
 mod dead;
 mod dummy_bone_offset;
 mod effect;
 mod event_object;
 mod facing_direction;
 mod item_drop_model;
+mod knockback;
 mod model_height;
+mod monster_aggro;
 mod name_tag_entity;
 mod night_time_effect;
 mod npc_model;
@@ -28,18 +34,27 @@ mod personal_store;
 mod player_character;
 mod position;
 mod projectile;
+mod resting;
+mod sit_points;
 mod sound_category;
+mod stunned;
+mod target_camera;
 mod vehicle;
 mod vehicle_model;
 mod vehicle_sound;
 mod visible_status_effects;
 mod warp_object;
+mod water;
 mod zone;
 mod zone_object;
 
+pub use away_from_keyboard::AwayFromKeyboard;
 pub use bank::Bank;
+pub use blob_shadow::{BlobShadow, BlobShadowEntity};
+pub use campfire::Campfire;
 pub use character_model::{CharacterModel, CharacterModelPart, CharacterModelPartIndex};
 pub use character_model_blink_timer::CharacterBlinkTimer;
+pub use character_title::CharacterTitle;
 pub use clan::{Clan, ClanMember};
 pub use clan_membership::ClanMembership;
 pub use client_entity::{ClientEntity, ClientEntityId, ClientEntityType};
@@ -54,20 +69,23 @@ pub use collision::{
 };
 pub use command::{
     Command, CommandAttack, CommandCastSkill, CommandCastSkillState, CommandCastSkillTarget,
-    CommandEmote, CommandMove, CommandSit, NextCommand,
+    CommandEmote, CommandHitStun, CommandMove, CommandSit, NextCommand,
 };
+pub use companion::{Companion, CompanionModel};
 pub use cooldowns::{ConsumableCooldownGroup, Cooldowns};
-pub use damage_digits::DamageDigits;
 pub use dead::Dead;
 pub use dummy_bone_offset::DummyBoneOffset;
 pub use effect::{Effect, EffectMesh, EffectParticle};
 pub use event_object::EventObject;
 pub use facing_direction::FacingDirection;
 pub use item_drop_model::ItemDropModel;
+pub use knockback::Knockback;
 pub use model_height::ModelHeight;
+pub use monster_aggro::MonsterAggro;
 pub use name_tag_entity::{
-    NameTag, NameTagEntity, NameTagHealthbarBackground, NameTagHealthbarForeground, NameTagName,
-    NameTagTargetMark, NameTagType, RemoveNameTagCommand,
+    NameTag, NameTagEntity, NameTagHealthbarBackground, NameTagHealthbarForeground,
+    NameTagManabarBackground, NameTagManabarForeground, NameTagName, NameTagTargetMark,
+    NameTagType, RemoveNameTagCommand,
 };
 pub use night_time_effect::NightTimeEffect;
 pub use npc_model::NpcModel;
@@ -82,13 +100,18 @@ pub use personal_store::{PersonalStore, PersonalStoreModel};
 pub use player_character::PlayerCharacter;
 pub use position::Position;
 pub use projectile::{Projectile, ProjectileParabola, ProjectileTarget};
+pub use resting::Resting;
+pub use sit_points::{SitPoint, SitPoints};
 pub use sound_category::SoundCategory;
-pub use vehicle::Vehicle;
+pub use stunned::Stunned;
+pub use target_camera::TargetCamera;
+pub use vehicle::{Vehicle, VehicleMountMotion};
 pub use vehicle_model::VehicleModel;
 pub use vehicle_sound::{VehicleSound, VehicleSoundState};
 pub use visible_status_effects::{VisibleStatusEffect, VisibleStatusEffects};
 pub use warp_object::WarpObject;
-pub use zone::Zone;
+pub use water::{WaterContact, WaterPlane, WaterRipple};
+pub use zone::{Zone, ZoneBlock};
 pub use zone_object::{
     ZoneObject, ZoneObjectAnimatedObject, ZoneObjectId, ZoneObjectPart,
     ZoneObjectPartCollisionShape, ZoneObjectTerrain,
@@ -0,0 +1,7 @@
+use bevy::{prelude::Component, reflect::Reflect};
+
+/// Added to monsters whose current command is targeting the player
+#[derive(Component, Default, Reflect)]
+pub struct MonsterAggro {
+    pub flash_timer: f32,
+}
@@ -32,6 +32,16 @@ pub struct NameTagHealthbarForeground {
 #[derive(Component)]
 pub struct NameTagHealthbarBackground;
 
+#[derive(Component)]
+pub struct NameTagManabarForeground {
+    pub uv_min_x: f32,
+    pub uv_max_x: f32,
+    pub full_width: f32,
+}
+
+#[derive(Component)]
+pub struct NameTagManabarBackground;
+
 #[derive(Component, Deref, DerefMut)]
 pub struct NameTagEntity(pub Entity);
 
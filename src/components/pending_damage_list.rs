@@ -29,6 +29,10 @@ impl PendingDamage {
             from_skill,
         }
     }
+
+    pub fn is_miss(&self) -> bool {
+        self.damage.amount == 0 && !self.is_kill
+    }
 }
 
 #[derive(Component, Deref, DerefMut)]
@@ -36,6 +40,26 @@ pub struct PendingDamageList {
     pub pending_damage: Vec<PendingDamage>,
 }
 
+impl PendingDamageList {
+    /// Returns true if all pending damage from attacker with skill_id is a miss
+    pub fn is_miss(&self, attacker: Entity, skill_id: Option<SkillId>) -> bool {
+        let mut matching_damage = self
+            .pending_damage
+            .iter()
+            .filter(|pending_damage| {
+                pending_damage.attacker == Some(attacker)
+                    && pending_damage
+                        .from_skill
+                        .map(|(damage_skill_id, _)| damage_skill_id)
+                        == skill_id
+            })
+            .peekable();
+
+        matching_damage.peek().is_some()
+            && matching_damage.all(|pending_damage| pending_damage.is_miss())
+    }
+}
+
 impl Default for PendingDamageList {
     fn default() -> Self {
         Self {
@@ -33,4 +33,7 @@ pub struct Projectile {
     pub parabola: Option<ProjectileParabola>,
 
     pub apply_damage: bool,
+
+    /// The attack missed, the projectile flies past the target instead of hitting it
+    pub is_miss: bool,
 }
@@ -0,0 +1,7 @@
+use bevy::{prelude::Component, reflect::Reflect};
+
+#[derive(Component, Reflect)]
+pub struct Resting {
+    pub near_campfire: bool,
+    pub last_health_points: i32,
+}
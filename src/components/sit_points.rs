@@ -0,0 +1,42 @@
+use bevy::{
+    math::{Vec2, Vec3},
+    prelude::{Component, GlobalTransform},
+    reflect::Reflect,
+};
+
+#[derive(Clone, Copy, Default, Reflect)]
+pub struct SitPoint {
+    /// Offset in cm from the object origin, in the object's local space
+    pub offset: Vec3,
+    /// Direction the character faces when seated, in radians in the object's local space
+    pub facing: f32,
+}
+
+/// Deco zone object which characters can sit on, such as benches and chairs
+#[derive(Component, Default, Reflect)]
+pub struct SitPoints {
+    pub points: Vec<SitPoint>,
+}
+
+impl SitPoints {
+    /// Returns the world position in cm and facing direction of every sit point on the object
+    pub fn world_points(
+        &self,
+        global_transform: &GlobalTransform,
+    ) -> impl Iterator<Item = (Vec3, Vec2)> + '_ {
+        let affine = global_transform.affine();
+
+        self.points.iter().map(move |point| {
+            let position = affine.transform_point3(
+                Vec3::new(point.offset.x, point.offset.z, -point.offset.y) / 100.0,
+            );
+            let facing =
+                affine.transform_vector3(Vec3::new(point.facing.cos(), 0.0, -point.facing.sin()));
+
+            (
+                Vec3::new(position.x * 100.0, -position.z * 100.0, position.y * 100.0),
+                Vec2::new(facing.x, -facing.z).normalize_or_zero(),
+            )
+        })
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::{
+    prelude::{Component, Entity},
+    reflect::Reflect,
+};
+
+/// Added to entities which are unable to act due to a stun or sleep status effect
+#[derive(Component, Reflect)]
+pub struct Stunned {
+    /// The stun stars effect spawned above the entity's head
+    pub effect_entity: Entity,
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::Component;
+
+/// A second camera which renders a close up of the selected target to an image, excluded from
+/// queries which expect the main camera to be the only Camera3d.
+#[derive(Component)]
+pub struct TargetCamera;
@@ -11,3 +11,8 @@ pub struct Vehicle {
     pub vehicle_model_entity: Entity,
     pub action_motions: EnumMap<VehicleMotionAction, Handle<ZmoAsset>>,
 }
+
+/// Mount or dismount motion is playing, command_system waits for it to complete before
+/// returning to the idle motion
+#[derive(Component)]
+pub struct VehicleMountMotion;
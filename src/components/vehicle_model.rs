@@ -10,6 +10,8 @@ pub struct VehicleModel {
     pub driver_model_entity: Entity,
     pub driver_dummy_entity: Entity,
     pub model_parts: EnumMap<VehiclePartIndex, (usize, Vec<Entity>)>,
+    /// Engine exhaust effects which are only visible whilst moving
+    pub exhaust_effects: Vec<Entity>,
     pub vehicle_action_motions: EnumMap<VehicleMotionAction, Handle<ZmoAsset>>,
     pub character_action_motions: EnumMap<VehicleMotionAction, Handle<ZmoAsset>>,
 }
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use bevy::{
+    math::{Vec2, Vec3},
+    prelude::Component,
+};
+
+/// The surface of a zone water plane, in world space
+#[derive(Component)]
+pub struct WaterPlane {
+    /// Minimum x, z of the plane
+    pub min: Vec2,
+    /// Maximum x, z of the plane
+    pub max: Vec2,
+    pub height: f32,
+}
+
+impl WaterPlane {
+    pub fn new(start: Vec3, end: Vec3) -> Self {
+        Self {
+            min: Vec2::new(start.x.min(end.x), start.z.min(end.z)),
+            max: Vec2::new(start.x.max(end.x), start.z.max(end.z)),
+            height: start.y,
+        }
+    }
+
+    pub fn contains(&self, translation: Vec3) -> bool {
+        translation.x >= self.min.x
+            && translation.x <= self.max.x
+            && translation.z >= self.min.y
+            && translation.z <= self.max.y
+    }
+}
+
+/// Tracks whether an entity is standing in water, to detect when it enters a water plane
+#[derive(Component, Default)]
+pub struct WaterContact {
+    /// Height of the water surface the entity is standing in
+    pub surface_height: Option<f32>,
+    pub last_translation: Vec3,
+    pub last_ripple: Duration,
+}
+
+/// An expanding ripple decal drawn on the water surface
+#[derive(Component)]
+pub struct WaterRipple {
+    pub age: f32,
+    pub lifetime: f32,
+    pub radius: f32,
+}
+
+impl WaterRipple {
+    pub fn new(lifetime: f32, radius: f32) -> Self {
+        Self {
+            age: 0.0,
+            lifetime,
+            radius,
+        }
+    }
+}
@@ -6,3 +6,10 @@ use rose_data::ZoneId;
 pub struct Zone {
     pub id: ZoneId,
 }
+
+/// Parent of the terrain, water and objects spawned for a single block of a zone
+#[derive(Component)]
+pub struct ZoneBlock {
+    pub block_x: usize,
+    pub block_y: usize,
+}
@@ -73,6 +73,7 @@ pub enum ZoneObject {
     DecoObject(ZoneObjectId),
     DecoObjectPart(ZoneObjectPart),
     Terrain(ZoneObjectTerrain),
+    FarTerrain(ZoneObjectTerrain),
     EffectObject {
         ifo_object_id: usize,
         effect_path: String,
@@ -0,0 +1,288 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use rose_file_readers::{ChrFile, LtbFile, StbFile, VirtualFilesystem, ZmdFile, ZscFile};
+
+use crate::http::http_download;
+
+#[derive(Copy, Clone, Debug)]
+enum RequiredFileType {
+    Chr,
+    Ltb,
+    Raw,
+    Stb,
+    Zmd,
+    Zsc,
+}
+
+const REQUIRED_FILES: &[(&str, RequiredFileType)] = &[
+    // Databases
+    ("3DDATA/STB/LIST_ZONE.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_NPC.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_SKILL.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_STATUS.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_QUEST.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_CLASS.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_EFFECT.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_SKY.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_STEPSOUND.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_MORPH_OBJECT.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/WARP.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/TYPE_MOTION.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/FILE_MOTION.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_WEAPON.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_BODY.STB", RequiredFileType::Stb),
+    ("3DDATA/STB/LIST_USEITEM.STB", RequiredFileType::Stb),
+    ("3DDATA/EVENT/ULNGTB_CON.LTB", RequiredFileType::Ltb),
+    // Key models
+    ("3DDATA/AVATAR/MALE.ZMD", RequiredFileType::Zmd),
+    ("3DDATA/AVATAR/FEMALE.ZMD", RequiredFileType::Zmd),
+    ("3DDATA/AVATAR/LIST_MFACE.ZSC", RequiredFileType::Zsc),
+    ("3DDATA/AVATAR/LIST_MHAIR.ZSC", RequiredFileType::Zsc),
+    ("3DDATA/AVATAR/LIST_MBODY.ZSC", RequiredFileType::Zsc),
+    ("3DDATA/AVATAR/LIST_WFACE.ZSC", RequiredFileType::Zsc),
+    ("3DDATA/AVATAR/LIST_WHAIR.ZSC", RequiredFileType::Zsc),
+    ("3DDATA/AVATAR/LIST_WBODY.ZSC", RequiredFileType::Zsc),
+    ("3DDATA/WEAPON/LIST_WEAPON.ZSC", RequiredFileType::Zsc),
+    ("3DDATA/PAT/LIST_PAT.ZSC", RequiredFileType::Zsc),
+    ("3DDATA/NPC/LIST_NPC.CHR", RequiredFileType::Chr),
+    ("3DDATA/NPC/PART_NPC.ZSC", RequiredFileType::Zsc),
+    ("3DDATA/ITEM/LIST_FIELDITEM.ZSC", RequiredFileType::Zsc),
+    ("3DDATA/SPECIAL/EVENT_OBJECT.ZSC", RequiredFileType::Zsc),
+    (
+        "3DDATA/SPECIAL/LIST_DECO_SPECIAL.ZSC",
+        RequiredFileType::Zsc,
+    ),
+    // Textures
+    ("3DDATA/EFFECT/TRAIL.DDS", RequiredFileType::Raw),
+    ("ETC/SPECULAR_SPHEREMAP.DDS", RequiredFileType::Raw),
+];
+
+#[derive(Error, Debug)]
+pub enum DataVerifyError {
+    #[error("File is missing")]
+    Missing,
+    #[error("File is corrupt: {0}")]
+    Corrupt(String),
+}
+
+#[derive(Error, Debug)]
+pub enum DataDownloadError {
+    #[error("Invalid checksum manifest line {0}")]
+    InvalidManifest(usize),
+    #[error("File is not listed in the checksum manifest")]
+    NotInManifest,
+    #[error("Checksum mismatch, expected {expected} but downloaded file has {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
+}
+
+pub struct DataVerifyFailure {
+    pub path: String,
+    pub error: DataVerifyError,
+}
+
+#[derive(Default)]
+pub struct DataVerifyReport {
+    pub num_checked: usize,
+    pub failures: Vec<DataVerifyFailure>,
+}
+
+impl DataVerifyReport {
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+fn verify_file(
+    vfs: &VirtualFilesystem,
+    path: &str,
+    file_type: RequiredFileType,
+) -> Result<(), DataVerifyError> {
+    if vfs.open_file(path).is_err() {
+        return Err(DataVerifyError::Missing);
+    }
+
+    let result = match file_type {
+        RequiredFileType::Chr => vfs.read_file::<ChrFile, _>(path).map(|_| ()),
+        RequiredFileType::Ltb => vfs.read_file::<LtbFile, _>(path).map(|_| ()),
+        RequiredFileType::Stb => vfs.read_file::<StbFile, _>(path).map(|_| ()),
+        RequiredFileType::Zmd => vfs.read_file::<ZmdFile, _>(path).map(|_| ()),
+        RequiredFileType::Zsc => vfs.read_file::<ZscFile, _>(path).map(|_| ()),
+        RequiredFileType::Raw => Ok(()),
+    };
+
+    result.map_err(|error| DataVerifyError::Corrupt(error.to_string()))
+}
+
+pub fn verify_data(vfs: &VirtualFilesystem) -> DataVerifyReport {
+    let mut report = DataVerifyReport::default();
+
+    for &(path, file_type) in REQUIRED_FILES.iter() {
+        report.num_checked += 1;
+
+        if let Err(error) = verify_file(vfs, path, file_type) {
+            log::warn!("Data verification failed for {}: {}", path, error);
+            report.failures.push(DataVerifyFailure {
+                path: path.to_string(),
+                error,
+            });
+        }
+    }
+
+    report
+}
+
+fn normalise_manifest_path(path: &str) -> String {
+    path.replace('\\', "/").to_ascii_uppercase()
+}
+
+/// Loads a checksum manifest in the `sha256sum` format, one `<sha256>  <path>` per line. The
+/// manifest is shipped with the client so a compromised overlay server cannot replace files.
+pub fn load_overlay_manifest(path: &Path) -> Result<HashMap<String, String>> {
+    let mut manifest = HashMap::new();
+
+    for (line_number, line) in std::fs::read_to_string(path)?.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (checksum, file_path) = line
+            .split_once(char::is_whitespace)
+            .ok_or(DataDownloadError::InvalidManifest(line_number + 1))?;
+
+        // sha256sum marks binary mode files with a * before the path
+        let file_path = file_path.trim_start().trim_start_matches('*');
+        if checksum.len() != 64
+            || !checksum.chars().all(|c| c.is_ascii_hexdigit())
+            || file_path.is_empty()
+        {
+            return Err(DataDownloadError::InvalidManifest(line_number + 1).into());
+        }
+
+        manifest.insert(
+            normalise_manifest_path(file_path),
+            checksum.to_ascii_lowercase(),
+        );
+    }
+
+    Ok(manifest)
+}
+
+/// Writes into the file while hashing everything which passes through
+struct HashingWriter {
+    writer: BufWriter<File>,
+    hasher: Sha256,
+}
+
+impl Write for HashingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let size = self.writer.write(buf)?;
+        self.hasher.update(&buf[..size]);
+        Ok(size)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Streams the file to a temporary path next to the destination, it is only moved into place
+/// once its checksum matches the expected one.
+fn download_file(url: &str, destination: &Path, expected: &str) -> Result<()> {
+    if let Some(parent) = destination.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut download_path = destination.as_os_str().to_owned();
+    download_path.push(".download");
+
+    let result = (|| -> Result<()> {
+        let mut writer = HashingWriter {
+            writer: BufWriter::new(File::create(&download_path)?),
+            hasher: Sha256::new(),
+        };
+        http_download(url, &mut writer)?;
+        writer.flush()?;
+
+        // The file must be closed before it can be renamed on Windows
+        let HashingWriter { writer, hasher } = writer;
+        drop(writer);
+
+        let actual = format!("{:x}", hasher.finalize());
+        if actual != expected {
+            return Err(DataDownloadError::ChecksumMismatch {
+                expected: expected.to_string(),
+                actual,
+            }
+            .into());
+        }
+
+        std::fs::rename(&download_path, destination)?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        std::fs::remove_file(&download_path).ok();
+    }
+
+    result
+}
+
+/// Downloads each of the given VFS paths from the overlay url into the data directory, only
+/// files listed in the checksum manifest are downloaded. Returns the number of files which were
+/// successfully downloaded and verified.
+pub fn download_data_overlay(
+    overlay_url: &str,
+    manifest_path: &Path,
+    data_path: &Path,
+    paths: &[String],
+) -> usize {
+    let manifest = match load_overlay_manifest(manifest_path) {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            log::error!(
+                "Failed to load data overlay manifest {} with error: {}",
+                manifest_path.to_string_lossy(),
+                error
+            );
+            return 0;
+        }
+    };
+
+    let mut num_downloaded = 0;
+
+    for path in paths {
+        let url = format!(
+            "{}/{}",
+            overlay_url.trim_end_matches('/'),
+            path.replace('\\', "/")
+        );
+        let destination = data_path.join(path.replace('\\', "/"));
+
+        let result = manifest
+            .get(&normalise_manifest_path(path))
+            .ok_or_else(|| DataDownloadError::NotInManifest.into())
+            .and_then(|expected| download_file(&url, &destination, expected));
+
+        match result {
+            Ok(_) => {
+                log::info!("Downloaded {} to {}", url, destination.to_string_lossy());
+                num_downloaded += 1;
+            }
+            Err(error) => {
+                log::error!("Failed to download {} with error: {}", url, error);
+            }
+        }
+    }
+
+    num_downloaded
+}
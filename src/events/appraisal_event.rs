@@ -0,0 +1,8 @@
+use bevy::prelude::Event;
+use rose_game_common::components::ItemSlot;
+
+#[derive(Event)]
+pub enum AppraisalEvent {
+    Open,
+    Result { item_slot: ItemSlot, success: bool },
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::Event;
+
+use rose_game_common::messages::ClientEntityId;
+
+#[derive(Event)]
+pub enum CharacterTitleEvent {
+    /// The active title of a character changed, None if they no longer have a title
+    UpdateEntity {
+        entity_id: ClientEntityId,
+        title: Option<String>,
+    },
+    /// The list of titles unlocked by the player
+    UpdateList {
+        titles: Vec<String>,
+        active: Option<usize>,
+        can_change: bool,
+    },
+}
@@ -5,6 +5,8 @@ pub enum ChatboxEvent {
     Say(String, String),
     Shout(String, String),
     Whisper(String, String),
+    Clan(String, String),
+    Party(String, String),
     Announce(Option<String>, String),
     System(String),
     Quest(String),
@@ -3,5 +3,6 @@ use bevy::prelude::{Entity, Event};
 #[derive(Event, Copy, Clone, Debug)]
 pub enum ClientEntityEvent {
     Die(Entity),
+    JobChanged(Entity, u16),
     LevelUp(Entity, Option<u32>),
 }
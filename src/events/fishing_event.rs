@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use bevy::prelude::Event;
+
+#[derive(Event, Clone)]
+pub enum FishingEvent {
+    /// Cast a line into the water in front of the player
+    Cast,
+    /// Reel in after a bite, accuracy is from 0.0 to 1.0 from the timing mini-game
+    Reel {
+        accuracy: f32,
+    },
+    Cancel,
+    CastResult {
+        accepted: bool,
+    },
+    Bite {
+        reel_window: Duration,
+    },
+    Result {
+        caught: bool,
+    },
+}
@@ -10,6 +10,9 @@ pub struct HitEvent {
     pub skill_id: Option<SkillId>,
     pub apply_damage: bool,
     pub ignore_miss: bool,
+    /// When false the hit effects have already been spawned elsewhere, e.g. where a projectile
+    /// impacted a wall
+    pub spawn_hit_effects: bool,
 }
 
 impl HitEvent {
@@ -21,6 +24,7 @@ impl HitEvent {
             skill_id: None,
             apply_damage: true,
             ignore_miss: false,
+            spawn_hit_effects: true,
         }
     }
 
@@ -32,6 +36,7 @@ impl HitEvent {
             skill_id: Some(skill_id),
             apply_damage: true,
             ignore_miss: false,
+            spawn_hit_effects: true,
         }
     }
 
@@ -43,6 +48,7 @@ impl HitEvent {
             skill_id: Some(skill_id),
             apply_damage: true,
             ignore_miss: true,
+            spawn_hit_effects: true,
         }
     }
 
@@ -50,4 +56,9 @@ impl HitEvent {
         self.apply_damage = apply_damage;
         self
     }
+
+    pub fn spawn_hit_effects(mut self, spawn_hit_effects: bool) -> Self {
+        self.spawn_hit_effects = spawn_hit_effects;
+        self
+    }
 }
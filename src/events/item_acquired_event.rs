@@ -0,0 +1,22 @@
+use bevy::prelude::{Entity, Event};
+
+use rose_data::Item;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ItemAcquiredSource {
+    Pickup,
+    Reward,
+    PartyMember(Entity),
+}
+
+#[derive(Event)]
+pub struct ItemAcquiredEvent {
+    pub source: ItemAcquiredSource,
+    pub item: Item,
+}
+
+impl ItemAcquiredEvent {
+    pub fn new(source: ItemAcquiredSource, item: Item) -> Self {
+        Self { source, item }
+    }
+}
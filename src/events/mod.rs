@@ -1,11 +1,15 @@
+mod appraisal_event;
 mod bank_event;
 mod character_select_event;
+mod character_title_event;
 mod chatbox_event;
 mod clan_dialog_event;
 mod client_entity_event;
 mod conversation_dialog_event;
+mod fishing_event;
 mod game_connection_event;
 mod hit_event;
+mod item_acquired_event;
 mod login_event;
 mod message_box_event;
 mod move_destination_effect_event;
@@ -16,21 +20,29 @@ mod party_event;
 mod personal_store_event;
 mod player_command_event;
 mod quest_trigger_event;
+mod repair_event;
+mod sound_effect_event;
 mod spawn_effect_event;
 mod spawn_projectile_event;
 mod system_func_event;
+mod toast_event;
+mod tutorial_hint_event;
 mod use_item_event;
 mod world_connection_event;
 mod zone_event;
 
+pub use appraisal_event::AppraisalEvent;
 pub use bank_event::BankEvent;
 pub use character_select_event::CharacterSelectEvent;
+pub use character_title_event::CharacterTitleEvent;
 pub use chatbox_event::ChatboxEvent;
 pub use clan_dialog_event::ClanDialogEvent;
 pub use client_entity_event::ClientEntityEvent;
 pub use conversation_dialog_event::ConversationDialogEvent;
+pub use fishing_event::FishingEvent;
 pub use game_connection_event::GameConnectionEvent;
 pub use hit_event::HitEvent;
+pub use item_acquired_event::{ItemAcquiredEvent, ItemAcquiredSource};
 pub use login_event::LoginEvent;
 pub use message_box_event::MessageBoxEvent;
 pub use move_destination_effect_event::MoveDestinationEffectEvent;
@@ -41,9 +53,13 @@ pub use party_event::PartyEvent;
 pub use personal_store_event::PersonalStoreEvent;
 pub use player_command_event::PlayerCommandEvent;
 pub use quest_trigger_event::QuestTriggerEvent;
+pub use repair_event::RepairEvent;
+pub use sound_effect_event::SoundEffectEvent;
 pub use spawn_effect_event::{SpawnEffect, SpawnEffectData, SpawnEffectEvent};
 pub use spawn_projectile_event::SpawnProjectileEvent;
 pub use system_func_event::SystemFuncEvent;
+pub use toast_event::{ToastCategory, ToastEvent};
+pub use tutorial_hint_event::{TutorialHint, TutorialHintEvent};
 pub use use_item_event::UseItemEvent;
 pub use world_connection_event::WorldConnectionEvent;
 pub use zone_event::{LoadZoneEvent, ZoneEvent};
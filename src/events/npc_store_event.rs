@@ -1,6 +1,8 @@
 use bevy::prelude::Event;
 
-use rose_game_common::messages::ClientEntityId;
+use rose_game_common::{components::ItemSlot, messages::ClientEntityId};
+
+use crate::protocol::NpcStoreStockItem;
 
 #[derive(Event)]
 pub enum NpcStoreEvent {
@@ -12,4 +14,9 @@ pub enum NpcStoreEvent {
         store_tab_slot: usize,
         quantity: usize,
     },
+    AddToSellList(ItemSlot),
+    UpdateStock {
+        npc_entity_id: ClientEntityId,
+        items: Vec<NpcStoreStockItem>,
+    },
 }
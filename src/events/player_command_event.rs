@@ -1,4 +1,7 @@
-use bevy::prelude::{Entity, Event};
+use bevy::{
+    math::Vec2,
+    prelude::{Entity, Event},
+};
 
 use rose_data::{AmmoIndex, EquipmentIndex, VehiclePartIndex};
 use rose_game_common::components::{HotbarSlot, ItemSlot, SkillSlot};
@@ -11,9 +14,13 @@ pub enum PlayerCommandEvent {
     DropItem(ItemSlot),
     UseItem(ItemSlot),
     UseHotbar(usize, usize),
+    UseHotbarSelfCast(usize, usize),
     SetHotbar(usize, usize, Option<HotbarSlot>),
     Attack(Entity),
     Move(Position, Option<Entity>),
+    /// Walk to a sit point on a zone object then sit down facing the given direction
+    MoveToSitPoint(Position, Vec2),
+    Sit,
     UnequipAmmo(AmmoIndex),
     UnequipEquipment(EquipmentIndex),
     UnequipVehicle(VehiclePartIndex),
@@ -0,0 +1,8 @@
+use bevy::prelude::Event;
+
+use rose_game_common::messages::ClientEntityId;
+
+#[derive(Event)]
+pub enum RepairEvent {
+    OpenRepairFromClientEntity { client_entity_id: ClientEntityId },
+}
@@ -0,0 +1,24 @@
+use bevy::{math::Vec3, prelude::Event};
+
+use rose_data::SoundId;
+
+use crate::components::SoundCategory;
+
+/// Plays a one-shot sound at a position in the world, attenuated by its distance from the
+/// listener.
+#[derive(Event)]
+pub struct SoundEffectEvent {
+    pub sound_id: SoundId,
+    pub category: SoundCategory,
+    pub position: Vec3,
+}
+
+impl SoundEffectEvent {
+    pub fn new(sound_id: SoundId, category: SoundCategory, position: Vec3) -> Self {
+        Self {
+            sound_id,
+            category,
+            position,
+        }
+    }
+}
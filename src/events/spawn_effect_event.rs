@@ -1,6 +1,6 @@
 use bevy::prelude::{Entity, Event, Transform};
 
-use rose_data::EffectFileId;
+use rose_data::{EffectFileId, SkillId};
 use rose_file_readers::VfsPathBuf;
 
 pub enum SpawnEffect {
@@ -11,6 +11,8 @@ pub enum SpawnEffect {
 pub struct SpawnEffectData {
     pub effect: SpawnEffect,
     pub manual_despawn: bool,
+    /// The entity and skill which caused this effect, used to filter skill effects
+    pub skill_source: Option<(Entity, SkillId)>,
 }
 
 impl SpawnEffectData {
@@ -18,6 +20,7 @@ impl SpawnEffectData {
         Self {
             effect: SpawnEffect::Path(path),
             manual_despawn: false,
+            skill_source: None,
         }
     }
 
@@ -25,6 +28,7 @@ impl SpawnEffectData {
         Self {
             effect: SpawnEffect::FileId(effect_file_id),
             manual_despawn: false,
+            skill_source: None,
         }
     }
 
@@ -32,6 +36,11 @@ impl SpawnEffectData {
         self.manual_despawn = manual_despawn;
         self
     }
+
+    pub fn with_skill_source(mut self, source: Entity, skill_id: SkillId) -> Self {
+        self.skill_source = Some((source, skill_id));
+        self
+    }
 }
 
 #[derive(Event)]
@@ -46,6 +55,16 @@ pub enum SpawnEffectEvent {
     OnEntity(Entity, Option<usize>, SpawnEffectData),
 
     // Spawns an effect with the given transform
-    #[allow(dead_code)]
     WithTransform(Transform, SpawnEffectData),
 }
+
+impl SpawnEffectEvent {
+    pub fn spawn_effect_data(&self) -> &SpawnEffectData {
+        match self {
+            SpawnEffectEvent::AtEntity(_, spawn_effect_data)
+            | SpawnEffectEvent::InEntity(_, spawn_effect_data)
+            | SpawnEffectEvent::OnEntity(_, _, spawn_effect_data)
+            | SpawnEffectEvent::WithTransform(_, spawn_effect_data) => spawn_effect_data,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::Event;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ToastCategory {
+    Item,
+    RareItem,
+    Quest,
+    Social,
+    Connection,
+    SoundCaption,
+    Achievement,
+}
+
+#[derive(Event)]
+pub struct ToastEvent {
+    pub category: ToastCategory,
+    pub text: String,
+}
+
+impl ToastEvent {
+    pub fn new(category: ToastCategory, text: impl Into<String>) -> Self {
+        Self {
+            category,
+            text: text.into(),
+        }
+    }
+}
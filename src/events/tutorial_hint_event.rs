@@ -0,0 +1,28 @@
+use bevy::prelude::Event;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TutorialHint {
+    EnterGame,
+    SelectTarget,
+    ItemPickup,
+    LearnSkill,
+    LevelUp,
+    PlayerDied,
+}
+
+impl TutorialHint {
+    /// Name used to remember which hints have been shown in the character settings
+    pub fn key(&self) -> &'static str {
+        match self {
+            TutorialHint::EnterGame => "enter_game",
+            TutorialHint::SelectTarget => "select_target",
+            TutorialHint::ItemPickup => "item_pickup",
+            TutorialHint::LearnSkill => "learn_skill",
+            TutorialHint::LevelUp => "level_up",
+            TutorialHint::PlayerDied => "player_died",
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct TutorialHintEvent(pub TutorialHint);
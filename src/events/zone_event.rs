@@ -20,4 +20,6 @@ impl LoadZoneEvent {
 #[derive(Event)]
 pub enum ZoneEvent {
     Loaded(ZoneId),
+    /// Loading progress of the zone currently being loaded, from 0 to 100
+    LoadingProgress(u8),
 }
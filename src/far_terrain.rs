@@ -0,0 +1,198 @@
+use std::path::{Path, PathBuf};
+
+use bevy::{
+    math::Vec3,
+    prelude::Mesh,
+    render::mesh::{Indices, PrimitiveTopology},
+};
+use rose_data::ZoneId;
+
+use crate::zone_loader::ZoneLoaderBlock;
+
+/// Number of far terrain vertices along each edge of a block, sampled from the 65x65 HIM
+pub const FAR_TERRAIN_BLOCK_VERTICES: usize = 9;
+
+/// Far terrain is lowered slightly so it never pokes through the real terrain at the boundary
+pub const FAR_TERRAIN_HEIGHT_OFFSET: f32 = 2.0;
+
+const FAR_TERRAIN_BLOCK_SIZE: f32 = 160.0;
+const FAR_TERRAIN_CACHE_DIRECTORY: &str = "cache/far_terrain";
+const FAR_TERRAIN_CACHE_MAGIC: &[u8; 4] = b"RFT2";
+
+/// Coarse heights for every block in a zone, used to draw terrain beyond the terrain view
+/// distance. Generated from the HIM files at zone load and cached on disk, keyed by the zone id
+/// and a hash of every block's HIM file so edited heightmaps are never served stale.
+#[derive(Default)]
+pub struct FarTerrainHeightmap {
+    pub blocks: Vec<Option<Vec<f32>>>,
+}
+
+fn take_bytes<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if data.len() < len {
+        return None;
+    }
+
+    let (bytes, remaining) = data.split_at(len);
+    *data = remaining;
+    Some(bytes)
+}
+
+fn cache_path(zone_id: ZoneId) -> PathBuf {
+    Path::new(FAR_TERRAIN_CACHE_DIRECTORY).join(format!("{}.bin", zone_id.get()))
+}
+
+impl FarTerrainHeightmap {
+    /// Hash of the HIM contents of every loaded block, along with its position in the zone
+    pub fn source_hash(blocks: &[Option<Box<ZoneLoaderBlock>>]) -> md5::Digest {
+        let mut context = md5::Context::new();
+        for (index, block) in blocks.iter().enumerate() {
+            if let Some(block) = block {
+                context.consume((index as u32).to_le_bytes());
+                context.consume(block.him_hash.0);
+            }
+        }
+        context.compute()
+    }
+
+    pub fn from_blocks(blocks: &[Option<Box<ZoneLoaderBlock>>]) -> Self {
+        let step = 1.0 / (FAR_TERRAIN_BLOCK_VERTICES - 1) as f32;
+
+        Self {
+            blocks: blocks
+                .iter()
+                .map(|block| {
+                    let heightmap = &block.as_ref()?.him;
+                    let mut heights =
+                        Vec::with_capacity(FAR_TERRAIN_BLOCK_VERTICES * FAR_TERRAIN_BLOCK_VERTICES);
+
+                    for y in 0..FAR_TERRAIN_BLOCK_VERTICES {
+                        for x in 0..FAR_TERRAIN_BLOCK_VERTICES {
+                            let heightmap_x =
+                                (x as f32 * step * (heightmap.width - 1) as f32).round() as i32;
+                            let heightmap_y =
+                                (y as f32 * step * (heightmap.height - 1) as f32).round() as i32;
+                            heights.push(heightmap.get_clamped(heightmap_x, heightmap_y) / 100.0);
+                        }
+                    }
+
+                    Some(heights)
+                })
+                .collect(),
+        }
+    }
+
+    // Called from the zone asset loader, which runs on the IO task pool, so blocking file access
+    // here does not stall the main thread
+    pub fn load_cached(zone_id: ZoneId, source_hash: &md5::Digest) -> Option<Self> {
+        let file_data = std::fs::read(cache_path(zone_id)).ok()?;
+        let mut data = file_data.as_slice();
+        let magic = take_bytes(&mut data, FAR_TERRAIN_CACHE_MAGIC.len())?;
+        let hash = take_bytes(&mut data, source_hash.0.len())?;
+        if magic != FAR_TERRAIN_CACHE_MAGIC || hash != source_hash.0 {
+            return None;
+        }
+
+        let block_size = FAR_TERRAIN_BLOCK_VERTICES * FAR_TERRAIN_BLOCK_VERTICES * 4;
+        let mut blocks = Vec::with_capacity(64 * 64);
+        for _ in 0..64 * 64 {
+            if take_bytes(&mut data, 1)?[0] == 0 {
+                blocks.push(None);
+                continue;
+            }
+
+            blocks.push(Some(
+                take_bytes(&mut data, block_size)?
+                    .chunks_exact(4)
+                    .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                    .collect(),
+            ));
+        }
+
+        if !data.is_empty() {
+            return None;
+        }
+
+        Some(Self { blocks })
+    }
+
+    pub fn save_cached(&self, zone_id: ZoneId, source_hash: &md5::Digest) {
+        let path = cache_path(zone_id);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        let mut data = Vec::new();
+        data.extend_from_slice(FAR_TERRAIN_CACHE_MAGIC);
+        data.extend_from_slice(&source_hash.0);
+        for block in self.blocks.iter() {
+            if let Some(heights) = block {
+                data.push(1);
+                for height in heights.iter() {
+                    data.extend_from_slice(&height.to_le_bytes());
+                }
+            } else {
+                data.push(0);
+            }
+        }
+
+        // Write to a temporary file first so a concurrent load never sees a partial cache
+        let temp_path = path.with_extension("bin.tmp");
+        if let Err(error) =
+            std::fs::write(&temp_path, data).and_then(|_| std::fs::rename(&temp_path, &path))
+        {
+            log::warn!(
+                "Failed to save far terrain cache to {} with error: {}",
+                path.to_string_lossy(),
+                error
+            );
+        }
+    }
+
+    pub fn create_block_mesh(&self, block_x: usize, block_y: usize) -> Option<Mesh> {
+        let heights = self.blocks.get(block_x + block_y * 64)?.as_ref()?;
+        let spacing = FAR_TERRAIN_BLOCK_SIZE / (FAR_TERRAIN_BLOCK_VERTICES - 1) as f32;
+        let get_height = |x: i32, y: i32| {
+            let x = x.clamp(0, FAR_TERRAIN_BLOCK_VERTICES as i32 - 1) as usize;
+            let y = y.clamp(0, FAR_TERRAIN_BLOCK_VERTICES as i32 - 1) as usize;
+            heights[x + y * FAR_TERRAIN_BLOCK_VERTICES]
+        };
+
+        let mut positions = Vec::with_capacity(heights.len());
+        let mut normals = Vec::with_capacity(heights.len());
+        let mut indices = Vec::new();
+
+        for y in 0..FAR_TERRAIN_BLOCK_VERTICES as i32 {
+            for x in 0..FAR_TERRAIN_BLOCK_VERTICES as i32 {
+                let normal = Vec3::new(
+                    get_height(x - 1, y) - get_height(x + 1, y),
+                    2.0 * spacing,
+                    get_height(x, y - 1) - get_height(x, y + 1),
+                )
+                .normalize();
+
+                positions.push([x as f32 * spacing, get_height(x, y), y as f32 * spacing]);
+                normals.push([normal.x, normal.y, normal.z]);
+            }
+        }
+
+        let width = FAR_TERRAIN_BLOCK_VERTICES as u16;
+        for y in 0..(width - 1) {
+            for x in 0..(width - 1) {
+                let start = y * width + x;
+                indices.push(start);
+                indices.push(start + width);
+                indices.push(start + 1);
+
+                indices.push(start + 1);
+                indices.push(start + width);
+                indices.push(start + 1 + width);
+            }
+        }
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.set_indices(Some(Indices::U16(indices)));
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        Some(mesh)
+    }
+}
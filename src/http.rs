@@ -0,0 +1,34 @@
+use std::{io::Write, time::Duration};
+
+use anyhow::Result;
+
+const HTTP_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const HTTP_READ_TIMEOUT: Duration = Duration::from_secs(30);
+const HTTP_WRITE_TIMEOUT: Duration = Duration::from_secs(30);
+const HTTP_MAX_REDIRECTS: u32 = 5;
+
+fn http_agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .timeout_connect(HTTP_CONNECT_TIMEOUT)
+        .timeout_read(HTTP_READ_TIMEOUT)
+        .timeout_write(HTTP_WRITE_TIMEOUT)
+        .redirects(HTTP_MAX_REDIRECTS)
+        .user_agent("rose-offline-client")
+        .build()
+}
+
+/// Downloads the given url, the response body is streamed into the writer as it is received.
+/// Returns the number of bytes written.
+pub fn http_download(url: &str, writer: &mut impl Write) -> Result<u64> {
+    let response = http_agent().get(url).call()?;
+    Ok(std::io::copy(&mut response.into_reader(), writer)?)
+}
+
+/// Posts the body to the given url, the response body is ignored.
+pub fn http_post(url: &str, content_type: &str, body: &[u8]) -> Result<()> {
+    http_agent()
+        .post(url)
+        .set("Content-Type", content_type)
+        .send_bytes(body)?;
+    Ok(())
+}
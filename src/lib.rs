@@ -14,7 +14,7 @@ use bevy::{
     },
     render::{render_resource::WgpuFeatures, settings::WgpuSettings},
     transform::TransformSystem,
-    window::{Window, WindowMode},
+    window::{Window, WindowPosition},
 };
 use bevy_egui::{egui, EguiContexts, EguiSet};
 use bevy_rapier3d::plugin::PhysicsSet;
@@ -22,8 +22,10 @@ use enum_map::enum_map;
 use exe_resource_loader::{ExeResourceCursor, ExeResourceLoader};
 use serde::Deserialize;
 use std::{
+    collections::HashMap,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 
 use rose_data::{CharacterMotionDatabaseOptions, NpcDatabaseOptions, ZoneId};
@@ -34,11 +36,16 @@ use rose_file_readers::{
 
 pub mod animation;
 pub mod audio;
+pub mod bug_report;
 pub mod bundles;
 pub mod components;
+pub mod data_verifier;
 pub mod effect_loader;
 pub mod events;
 pub mod exe_resource_loader;
+pub mod far_terrain;
+pub mod http;
+pub mod log_history;
 pub mod model_loader;
 pub mod protocol;
 pub mod render;
@@ -52,72 +59,121 @@ pub mod zone_loader;
 
 use audio::OddioPlugin;
 use events::{
-    BankEvent, CharacterSelectEvent, ChatboxEvent, ClanDialogEvent, ClientEntityEvent,
-    ConversationDialogEvent, GameConnectionEvent, HitEvent, LoadZoneEvent, LoginEvent,
-    MessageBoxEvent, MoveDestinationEffectEvent, NetworkEvent, NpcStoreEvent,
-    NumberInputDialogEvent, PartyEvent, PersonalStoreEvent, PlayerCommandEvent, QuestTriggerEvent,
-    SpawnEffectEvent, SpawnProjectileEvent, SystemFuncEvent, UseItemEvent, WorldConnectionEvent,
-    ZoneEvent,
+    AppraisalEvent, BankEvent, CharacterSelectEvent, CharacterTitleEvent, ChatboxEvent,
+    ClanDialogEvent, ClientEntityEvent, ConversationDialogEvent, FishingEvent, GameConnectionEvent,
+    HitEvent, ItemAcquiredEvent, LoadZoneEvent, LoginEvent, MessageBoxEvent,
+    MoveDestinationEffectEvent, NetworkEvent, NpcStoreEvent, NumberInputDialogEvent, PartyEvent,
+    PersonalStoreEvent, PlayerCommandEvent, QuestTriggerEvent, RepairEvent, SoundEffectEvent,
+    SpawnEffectEvent, SpawnProjectileEvent, SystemFuncEvent, ToastEvent, TutorialHintEvent,
+    UseItemEvent, WorldConnectionEvent, ZoneEvent,
 };
 use model_loader::ModelLoader;
-use render::{DamageDigitMaterial, RoseRenderPlugin};
+use render::{DamageDigitRenderData, RoseRenderPlugin};
 use resources::{
-    load_ui_resources, run_network_thread, ui_requested_cursor_apply_system, update_ui_resources,
-    AppState, ClientEntityList, DamageDigitsSpawner, DebugRenderConfig, GameData, NameTagSettings,
-    NetworkThread, NetworkThreadMessage, RenderConfiguration, SelectedTarget, ServerConfiguration,
-    SoundCache, SoundSettings, SpecularTexture, VfsResource, WorldTime, ZoneTime,
+    hud_visible, load_ui_resources, run_network_thread, ui_requested_cursor_apply_system,
+    update_ui_resources, AccessibilitySettings, AppState, AutoTravel, AwayState, BackgroundMusic,
+    BugReportConfiguration, ChannelQueue, ChannelSwitch, CharacterSettings, CharacterTitles,
+    ClientEntityList, ColorPalette, ConfigFile, ControlScheme, DamageDigitsSpawner,
+    DataOverlayConfiguration, DebugRenderConfig, DisplayMode, DisplaySettings,
+    DropNotificationSettings, EffectSettings, Fishing, GameData, HeadlessScript, HudSettings,
+    HudWidget, IgnoreList, InputFocus, InterfaceSettings, KeyBinding, KeyBindingAction,
+    KeyBindings, MapExploration, MapPings, NameTagHealthbarCategory, NameTagHealthbarPolicy,
+    NameTagSettings, NetworkThread, NetworkThreadMessage, PlayerCommandBuffer, RenderConfiguration,
+    RenderPreset, RequestCategory, RequestSettings, SelectedTarget, ServerConfiguration,
+    ServerProfile, ServerProfiles, SessionStats, ShadowQuality, SitPointDatabase, SitPointTarget,
+    SkillQueue, SoundCache, SoundSettings, SpecularTexture, Unstick, VfsResource, WaterQuality,
+    WorldTime, ZoneLoadTracker, ZoneTime,
 };
 use scripting::RoseScriptingPlugin;
 use systems::{
-    ability_values_system, animation_effect_system, animation_sound_system, auto_login_system,
-    background_music_system, character_model_add_collider_system, character_model_blink_system,
-    character_model_update_system, character_select_enter_system, character_select_event_system,
-    character_select_exit_system, character_select_input_system, character_select_models_system,
-    character_select_system, clan_system, client_entity_event_system, collision_height_only_system,
-    collision_player_system, collision_player_system_join_zoin, command_system,
+    ability_values_system, achievement_system, animation_effect_system, animation_sound_system,
+    auto_login_system, auto_travel_system, background_music_system, blob_shadow_system,
+    bloom_system, channel_switch_system, character_model_add_collider_system,
+    character_model_blink_system, character_model_update_system, character_select_enter_system,
+    character_select_event_system, character_select_exit_system, character_select_input_system,
+    character_select_models_system, character_select_system, character_settings_exit_system,
+    character_settings_load_system, character_settings_save_system, character_title_system,
+    clan_system, client_entity_event_system, collision_height_only_system, collision_player_system,
+    collision_player_system_join_zoin, command_system, companion_system,
     conversation_dialog_system, cooldown_system, damage_digit_render_system,
-    debug_render_collider_system, debug_render_directional_light_system,
-    debug_render_skeleton_system, directional_light_system, effect_system, facing_direction_system,
-    free_camera_system, game_connection_system, game_mouse_input_system, game_state_enter_system,
-    game_zone_change_system, hit_event_system, item_drop_model_add_collider_system,
-    item_drop_model_system, login_connection_system, login_event_system, login_state_enter_system,
-    login_state_exit_system, login_system, model_viewer_enter_system, model_viewer_exit_system,
-    model_viewer_system, move_destination_effect_system, name_tag_system,
-    name_tag_update_color_system, name_tag_update_healthbar_system, name_tag_visibility_system,
-    network_thread_system, npc_idle_sound_system, npc_model_add_collider_system,
-    npc_model_update_system, orbit_camera_system, particle_sequence_system,
-    passive_recovery_system, pending_damage_system, pending_skill_effect_system,
-    personal_store_model_add_collider_system, personal_store_model_system, player_command_system,
-    projectile_system, quest_trigger_system, spawn_effect_system, spawn_projectile_system,
-    status_effect_system, system_func_event_system, update_position_system, use_item_event_system,
-    vehicle_model_system, vehicle_sound_system, visible_status_effects_system,
-    world_connection_system, world_time_system, zone_time_system, zone_viewer_enter_system,
-    DebugInspectorPlugin,
+    debug_render_collider_system, debug_render_command_system,
+    debug_render_directional_light_system, debug_render_monster_spawn_system,
+    debug_render_range_system, debug_render_skeleton_system, directional_light_system,
+    drop_notification_system, effect_system, extension_message_system, facing_direction_system,
+    fishing_system, free_camera_system, game_connection_system, game_mouse_input_system,
+    game_state_enter_system, game_zone_change_system, headless_script_system, hit_event_system,
+    hud_visibility_system, input_focus_system, item_drop_model_add_collider_system,
+    item_drop_model_system, item_durability_warning_system, knockback_system,
+    login_connection_system, login_event_system, login_state_enter_system, login_state_exit_system,
+    login_system, map_exploration_system, map_ping_system, model_viewer_enter_system,
+    model_viewer_exit_system, model_viewer_retarget_system, model_viewer_scene_system,
+    model_viewer_system, model_viewer_training_dummy_system, monster_aggro_system,
+    move_destination_effect_system, name_tag_system, name_tag_update_color_system,
+    name_tag_update_healthbar_system, name_tag_visibility_system, network_thread_system,
+    npc_idle_sound_system, npc_idle_system, npc_model_add_collider_system, npc_model_update_system,
+    orbit_camera_system, particle_sequence_system, passive_recovery_system, pending_damage_system,
+    pending_skill_effect_system, personal_store_model_add_collider_system,
+    personal_store_model_system, player_command_buffer_system, player_command_system,
+    player_keyboard_move_system, projectile_system, quest_trigger_system, rest_system,
+    session_stats_system, sit_point_system, sound_caption_system, sound_effect_system,
+    spawn_effect_system, spawn_projectile_system, spectate_camera_system, status_effect_system,
+    stun_system, system_func_event_system, target_camera_exit_system, target_camera_system,
+    terrain_view_distance_system, tutorial_hint_system, unstick_system, update_position_system,
+    use_item_event_system, vehicle_exhaust_system, vehicle_model_system, vehicle_sound_system,
+    visible_status_effects_system, water_splash_system, weapon_trail_system, window_focus_system,
+    world_connection_system, world_time_system, zone_preload_system, zone_time_system,
+    zone_viewer_enter_system, DebugInspectorPlugin,
 };
 use ui::{
-    load_dialog_sprites_system, ui_bank_system, ui_character_create_system,
+    load_dialog_sprites_system, ui_accessibility_system, ui_achievements_system,
+    ui_aggro_indicator_system, ui_announcement_banner_system, ui_appraisal_system, ui_bank_system,
+    ui_bug_report_system, ui_channel_list_system, ui_character_create_system,
     ui_character_info_system, ui_character_select_name_tag_system, ui_character_select_system,
-    ui_chatbox_system, ui_clan_system, ui_create_clan_system, ui_debug_camera_info_system,
-    ui_debug_client_entity_list_system, ui_debug_command_viewer_system,
-    ui_debug_diagnostics_system, ui_debug_dialog_list_system, ui_debug_effect_list_system,
-    ui_debug_entity_inspector_system, ui_debug_item_list_system, ui_debug_menu_system,
-    ui_debug_npc_list_system, ui_debug_physics_system, ui_debug_render_system,
-    ui_debug_skill_list_system, ui_debug_zone_lighting_system, ui_debug_zone_list_system,
-    ui_debug_zone_time_system, ui_drag_and_drop_system, ui_game_menu_system, ui_hotbar_system,
-    ui_inventory_system, ui_item_drop_name_system, ui_login_system, ui_message_box_system,
-    ui_minimap_system, ui_npc_store_system, ui_number_input_dialog_system, ui_party_option_system,
-    ui_party_system, ui_personal_store_system, ui_player_info_system, ui_quest_list_system,
-    ui_respawn_system, ui_selected_target_system, ui_server_select_system, ui_settings_system,
-    ui_skill_list_system, ui_skill_tree_system, ui_sound_event_system, ui_status_effects_system,
-    ui_window_sound_system, widgets::Dialog, DialogLoader, UiSoundEvent, UiStateDebugWindows,
-    UiStateDragAndDrop, UiStateWindows,
+    ui_chatbox_system, ui_clan_system, ui_clock_system, ui_create_clan_system,
+    ui_debug_camera_info_system, ui_debug_client_entity_list_system,
+    ui_debug_command_viewer_system, ui_debug_diagnostics_system, ui_debug_dialog_list_system,
+    ui_debug_effect_list_system, ui_debug_entity_inspector_system, ui_debug_item_list_system,
+    ui_debug_menu_system, ui_debug_npc_list_system, ui_debug_physics_system,
+    ui_debug_render_system, ui_debug_skill_list_system, ui_debug_zone_lighting_system,
+    ui_debug_zone_list_system, ui_debug_zone_time_system, ui_drag_and_drop_system,
+    ui_escape_menu_system, ui_fishing_system, ui_game_menu_system, ui_help_system,
+    ui_hotbar_system, ui_ignore_list_system, ui_inventory_system, ui_item_drop_name_system,
+    ui_login_system, ui_message_box_system, ui_minimap_system, ui_monster_info_system,
+    ui_npc_store_system, ui_number_input_dialog_system, ui_party_option_system, ui_party_system,
+    ui_personal_store_system, ui_player_info_system, ui_quest_list_system, ui_repair_system,
+    ui_request_popup_system, ui_respawn_system, ui_selected_target_system, ui_server_select_system,
+    ui_session_stats_system, ui_settings_system, ui_skill_list_system, ui_skill_tree_system,
+    ui_sound_event_system, ui_status_effects_system, ui_target_camera_system, ui_toast_system,
+    ui_tutorial_hint_system, ui_window_sound_system, ui_zone_loading_system, widgets::Dialog,
+    DialogLoader, ItemRarity, UiSoundEvent, UiStateDebugWindows, UiStateDragAndDrop,
+    UiStateWindows,
 };
 use vfs_asset_io::VfsAssetIo;
 use zms_asset_loader::{ZmsAssetLoader, ZmsMaterialNumFaces, ZmsNoSkinAssetLoader};
-use zone_loader::{zone_loader_system, ZoneLoader, ZoneLoaderAsset};
+use zone_loader::{zone_block_streaming_system, zone_loader_system, ZoneLoader, ZoneLoaderAsset};
 
 use crate::components::SoundCategory;
 
+#[derive(Default, Deserialize)]
+pub enum ColorPaletteConfig {
+    #[default]
+    #[serde(rename = "default")]
+    Default,
+    #[serde(rename = "red_green")]
+    RedGreen,
+    #[serde(rename = "blue_yellow")]
+    BlueYellow,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct AccessibilityConfig {
+    pub color_palette: ColorPaletteConfig,
+    pub minimum_font_size: f32,
+    pub high_contrast: bool,
+    pub sound_captions: bool,
+}
+
 #[derive(Default, Deserialize)]
 #[serde(default)]
 pub struct AccountConfig {
@@ -153,9 +209,27 @@ pub enum FilesystemDeviceConfig {
 #[serde(default)]
 pub struct FilesystemConfig {
     pub devices: Vec<FilesystemDeviceConfig>,
+    pub overlay_url: Option<String>,
+    pub overlay_manifest: Option<String>,
 }
 
 impl FilesystemConfig {
+    /// The checksum manifest shipped with the client which overlay downloads are verified against
+    pub fn overlay_manifest_path(&self) -> PathBuf {
+        PathBuf::from(
+            self.overlay_manifest
+                .as_deref()
+                .unwrap_or("data_overlay.sha256"),
+        )
+    }
+
+    pub fn data_path(&self) -> Option<PathBuf> {
+        self.devices.iter().rev().find_map(|device| match device {
+            FilesystemDeviceConfig::Directory(path) => Some(PathBuf::from(path)),
+            _ => None,
+        })
+    }
+
     pub fn create_virtual_filesystem(&self) -> Option<Arc<VirtualFilesystem>> {
         let mut vfs_devices: Vec<Box<dyn VirtualFilesystemDevice + Send + Sync>> = Vec::new();
         for device_config in self.devices.iter() {
@@ -240,18 +314,41 @@ impl FilesystemConfig {
     }
 }
 
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct BugReportConfig {
+    pub upload_url: Option<String>,
+    pub directory: String,
+}
+
+impl Default for BugReportConfig {
+    fn default() -> Self {
+        Self {
+            upload_url: None,
+            directory: "bug_reports".into(),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(default)]
 pub struct ServerConfig {
-    pub ip: String,
-    pub port: u16,
+    pub ip: Option<String>,
+    pub port: Option<u16>,
+    pub profiles_path: String,
+    pub profile: Option<String>,
+    /// Disconnect from the game server if nothing is received for this long, 0 to disable
+    pub connection_timeout_seconds: u64,
 }
 
 impl Default for ServerConfig {
     fn default() -> Self {
         Self {
-            ip: "127.0.0.1".into(),
-            port: 29000,
+            ip: None,
+            port: None,
+            profiles_path: "servers.toml".into(),
+            profile: None,
+            connection_timeout_seconds: 180,
         }
     }
 }
@@ -281,15 +378,64 @@ pub enum GraphicsModeConfig {
     Window { width: f32, height: f32 },
     #[serde(rename = "fullscreen")]
     Fullscreen,
+    #[serde(rename = "exclusive_fullscreen")]
+    ExclusiveFullscreen { width: f32, height: f32 },
+}
+
+#[derive(Deserialize)]
+pub enum ShadowQualityConfig {
+    #[serde(rename = "off")]
+    Off,
+    #[serde(rename = "blob")]
+    Blob,
+    #[serde(rename = "shadow_map")]
+    ShadowMap,
+}
+
+#[derive(Deserialize)]
+pub enum WaterQualityConfig {
+    #[serde(rename = "low")]
+    Low,
+    #[serde(rename = "high")]
+    High,
+}
+
+#[derive(Deserialize)]
+pub enum RenderPresetConfig {
+    #[serde(rename = "low")]
+    Low,
+    #[serde(rename = "medium")]
+    Medium,
+    #[serde(rename = "high")]
+    High,
+    #[serde(rename = "ultra")]
+    Ultra,
 }
 
 #[derive(Deserialize)]
 #[serde(default)]
 pub struct GraphicsConfig {
     pub mode: GraphicsModeConfig,
+    pub monitor: Option<usize>,
     pub passthrough_terrain_textures: bool,
     pub trail_effect_duration_multiplier: f32,
+    pub weapon_trails: bool,
+    pub terrain_view_distance: Option<f32>,
+    pub zone_block_streaming_distance: Option<f32>,
+    pub shadow_quality: ShadowQualityConfig,
+    pub particle_density: f32,
+    pub water_quality: WaterQualityConfig,
+    pub bloom: bool,
+    pub projectile_collision: bool,
+    pub hide_other_player_skill_effects: bool,
+    pub hide_screen_covering_effects: bool,
+    pub max_effects: Option<usize>,
+    /// When set, overrides the individual quality options above
+    pub render_preset: Option<RenderPresetConfig>,
     pub disable_vsync: bool,
+    /// Number of samples for multisample anti-aliasing, 1 disables it
+    pub msaa_samples: u32,
+    pub headless: bool,
 }
 
 impl Default for GraphicsConfig {
@@ -299,9 +445,220 @@ impl Default for GraphicsConfig {
                 width: 1920.0,
                 height: 1080.0,
             },
+            monitor: None,
             passthrough_terrain_textures: false,
             trail_effect_duration_multiplier: 1.0,
+            weapon_trails: true,
+            terrain_view_distance: Some(1280.0),
+            zone_block_streaming_distance: Some(1600.0),
+            shadow_quality: ShadowQualityConfig::ShadowMap,
+            particle_density: 1.0,
+            water_quality: WaterQualityConfig::High,
+            bloom: true,
+            projectile_collision: true,
+            hide_other_player_skill_effects: false,
+            hide_screen_covering_effects: false,
+            max_effects: None,
+            render_preset: None,
             disable_vsync: false,
+            msaa_samples: 1,
+            headless: false,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub enum ControlSchemeConfig {
+    #[serde(rename = "classic")]
+    Classic,
+    #[serde(rename = "modern")]
+    Modern,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct InterfaceConfig {
+    pub show_clock: bool,
+    pub clock_use_24_hour: bool,
+    pub streamer_mode: bool,
+    pub idle_camera_orbit_minutes: u32,
+    pub wasd_movement: bool,
+    pub control_scheme: ControlSchemeConfig,
+    pub camera_sensitivity: f32,
+    pub combat_click_through: bool,
+    pub aggro_indicator: bool,
+    pub target_camera: bool,
+    pub minimap_rotate: bool,
+    pub cooldown_text: bool,
+    pub cooldown_ready_flash: bool,
+    pub skill_queue_window_ms: u64,
+    pub auto_self_cast: bool,
+    pub tutorial_hints: bool,
+    pub focus_loss_mute_audio: bool,
+    pub focus_loss_reduce_fps: bool,
+    pub focus_loss_stop_auto_run: bool,
+    pub announcement_banner_seconds: u64,
+    pub shout_banners: bool,
+    pub toast_items: bool,
+    pub toast_quests: bool,
+    pub toast_social: bool,
+    pub toast_connection: bool,
+    pub drop_notifications: DropNotificationConfig,
+    pub afk_message: String,
+    pub requests: RequestConfig,
+    pub hud: HudConfig,
+    pub name_tags: NameTagConfig,
+}
+
+impl Default for InterfaceConfig {
+    fn default() -> Self {
+        Self {
+            show_clock: true,
+            clock_use_24_hour: true,
+            streamer_mode: false,
+            idle_camera_orbit_minutes: 0,
+            wasd_movement: false,
+            control_scheme: ControlSchemeConfig::Classic,
+            camera_sensitivity: 1.0,
+            combat_click_through: false,
+            aggro_indicator: true,
+            target_camera: false,
+            minimap_rotate: false,
+            cooldown_text: true,
+            cooldown_ready_flash: true,
+            skill_queue_window_ms: 500,
+            auto_self_cast: true,
+            tutorial_hints: true,
+            focus_loss_mute_audio: false,
+            focus_loss_reduce_fps: true,
+            focus_loss_stop_auto_run: true,
+            announcement_banner_seconds: 8,
+            shout_banners: true,
+            toast_items: true,
+            toast_quests: true,
+            toast_social: true,
+            toast_connection: true,
+            drop_notifications: DropNotificationConfig::default(),
+            afk_message: "I am away from my keyboard right now.".to_string(),
+            requests: RequestConfig::default(),
+            hud: HudConfig::default(),
+            name_tags: NameTagConfig::default(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub enum DropFanfareRarityConfig {
+    #[serde(rename = "off")]
+    Off,
+    #[serde(rename = "common")]
+    Common,
+    #[serde(rename = "rare")]
+    Rare,
+    #[serde(rename = "unique")]
+    Unique,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct DropNotificationConfig {
+    pub fanfare_rarity: DropFanfareRarityConfig,
+    pub party_fanfare_rarity: DropFanfareRarityConfig,
+    pub mute_common_drops: bool,
+}
+
+impl Default for DropNotificationConfig {
+    fn default() -> Self {
+        Self {
+            fanfare_rarity: DropFanfareRarityConfig::Rare,
+            party_fanfare_rarity: DropFanfareRarityConfig::Unique,
+            mute_common_drops: false,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct RequestConfig {
+    pub timeout_seconds: u64,
+    pub decline_party_while_busy: bool,
+    pub decline_trade_while_busy: bool,
+    pub decline_duel_while_busy: bool,
+    pub decline_party_from_strangers: bool,
+    pub decline_trade_from_strangers: bool,
+    pub decline_duel_from_strangers: bool,
+}
+
+impl Default for RequestConfig {
+    fn default() -> Self {
+        Self {
+            timeout_seconds: 30,
+            decline_party_while_busy: false,
+            decline_trade_while_busy: true,
+            decline_duel_while_busy: true,
+            decline_party_from_strangers: false,
+            decline_trade_from_strangers: false,
+            decline_duel_from_strangers: false,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct HudConfig {
+    pub restore_on_combat: bool,
+    pub chatbox: bool,
+    pub clock: bool,
+    pub hotbar: bool,
+    pub minimap: bool,
+    pub party: bool,
+    pub player_info: bool,
+    pub selected_target: bool,
+    pub status_effects: bool,
+}
+
+impl Default for HudConfig {
+    fn default() -> Self {
+        Self {
+            restore_on_combat: true,
+            chatbox: true,
+            clock: true,
+            hotbar: true,
+            minimap: true,
+            party: true,
+            player_info: true,
+            selected_target: true,
+            status_effects: true,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub enum NameTagHealthbarPolicyConfig {
+    #[serde(rename = "always")]
+    Always,
+    #[serde(rename = "in_combat")]
+    InCombat,
+    #[serde(rename = "never")]
+    Never,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct NameTagConfig {
+    pub party_member_healthbar: NameTagHealthbarPolicyConfig,
+    pub pet_healthbar: NameTagHealthbarPolicyConfig,
+    pub character_healthbar: NameTagHealthbarPolicyConfig,
+    pub monster_healthbar: NameTagHealthbarPolicyConfig,
+}
+
+impl Default for NameTagConfig {
+    fn default() -> Self {
+        Self {
+            party_member_healthbar: NameTagHealthbarPolicyConfig::Always,
+            pet_healthbar: NameTagHealthbarPolicyConfig::Always,
+            character_healthbar: NameTagHealthbarPolicyConfig::Never,
+            monster_healthbar: NameTagHealthbarPolicyConfig::Never,
         }
     }
 }
@@ -339,6 +696,7 @@ impl Default for SoundVolumeConfig {
 pub struct SoundConfig {
     pub enabled: bool,
     pub volume: SoundVolumeConfig,
+    pub music_cross_fade: f32,
 }
 
 impl Default for SoundConfig {
@@ -346,6 +704,7 @@ impl Default for SoundConfig {
         Self {
             enabled: true,
             volume: SoundVolumeConfig::default(),
+            music_cross_fade: 3.0,
         }
     }
 }
@@ -353,11 +712,18 @@ impl Default for SoundConfig {
 #[derive(Default, Deserialize)]
 #[serde(default)]
 pub struct Config {
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+    pub accessibility: AccessibilityConfig,
     pub account: AccountConfig,
     pub auto_login: AutoLoginConfig,
+    pub bug_report: BugReportConfig,
     pub filesystem: FilesystemConfig,
     pub game: GameConfig,
     pub graphics: GraphicsConfig,
+    pub interface: InterfaceConfig,
+    /// Maps action names such as "hotbar_slot_1" to bindings such as "F1" or "Ctrl+MouseLeft"
+    pub key_bindings: HashMap<String, String>,
     pub server: ServerConfig,
     pub sound: SoundConfig,
 }
@@ -371,14 +737,21 @@ pub fn load_config(path: &Path) -> Config {
                 path.to_string_lossy(),
                 error
             );
-            return Config::default();
+            // Still remember the path so settings changed in game are saved to it
+            return Config {
+                path: Some(path.into()),
+                ..Config::default()
+            };
         }
     };
 
     match toml::from_str(&toml_str) {
         Ok(config) => {
             println!("Read configuration from {}", path.to_string_lossy());
-            config
+            Config {
+                path: Some(path.into()),
+                ..config
+            }
         }
         Err(error) => {
             println!(
@@ -391,6 +764,93 @@ pub fn load_config(path: &Path) -> Config {
     }
 }
 
+pub fn run_data_verifier(config: &Config) -> bool {
+    let virtual_filesystem =
+        if let Some(virtual_filesystem) = config.filesystem.create_virtual_filesystem() {
+            virtual_filesystem
+        } else {
+            println!("No filesystem devices");
+            return false;
+        };
+
+    let report = data_verifier::verify_data(&virtual_filesystem);
+    for failure in report.failures.iter() {
+        println!("{}: {}", failure.path, failure.error);
+    }
+    println!(
+        "Verified {} files, {} failed",
+        report.num_checked,
+        report.failures.len()
+    );
+
+    if report.is_ok() {
+        return true;
+    }
+
+    let (Some(overlay_url), Some(data_path)) = (
+        config.filesystem.overlay_url.as_ref(),
+        config.filesystem.data_path(),
+    ) else {
+        return false;
+    };
+
+    let paths: Vec<String> = report
+        .failures
+        .iter()
+        .map(|failure| failure.path.clone())
+        .collect();
+    let num_downloaded = data_verifier::download_data_overlay(
+        overlay_url,
+        &config.filesystem.overlay_manifest_path(),
+        &data_path,
+        &paths,
+    );
+    println!(
+        "Downloaded {} of {} files from {} into {}",
+        num_downloaded,
+        paths.len(),
+        overlay_url,
+        data_path.to_string_lossy()
+    );
+
+    if num_downloaded != paths.len() {
+        return false;
+    }
+
+    // Verify again with the downloaded files to make sure they are usable
+    let Some(virtual_filesystem) = config.filesystem.create_virtual_filesystem() else {
+        return false;
+    };
+    let report = data_verifier::verify_data(&virtual_filesystem);
+    for failure in report.failures.iter() {
+        println!("{}: {}", failure.path, failure.error);
+    }
+
+    report.is_ok()
+}
+
+fn load_server_profiles(config: &Config) -> ServerProfiles {
+    let mut server_profiles = ServerProfiles::load(Path::new(&config.server.profiles_path));
+
+    if let Some(ip) = config.server.ip.as_ref() {
+        server_profiles.select_address(ip, config.server.port.unwrap_or(29000));
+    } else if let Some(index) = config.server.profile.as_ref().and_then(|name| {
+        server_profiles
+            .profiles
+            .iter()
+            .position(|profile| &profile.name == name)
+    }) {
+        server_profiles.selected = index;
+    }
+
+    if server_profiles.profiles.is_empty() {
+        server_profiles.profiles.push(ServerProfile::default());
+        server_profiles.selected = 0;
+    }
+
+    server_profiles
+}
+
 #[derive(Default)]
 pub struct SystemsConfig {
     pub disable_player_command_system: bool,
@@ -405,6 +865,27 @@ pub fn run_model_viewer(config: &Config) {
     run_client(config, AppState::ModelViewer, SystemsConfig::default());
 }
 
+/// Runs the given headless script without creating a window or renderer, returns false if any
+/// step of the script failed.
+pub fn run_headless(config: &Config, script: HeadlessScript) -> bool {
+    let failed = script.failed.clone();
+    let mut script = Some(script);
+
+    run_client(
+        config,
+        AppState::ZoneViewer,
+        SystemsConfig {
+            add_custom_systems: Some(Box::new(move |app| {
+                app.insert_resource(script.take().unwrap())
+                    .add_systems(Update, headless_script_system);
+            })),
+            ..Default::default()
+        },
+    );
+
+    !failed.load(std::sync::atomic::Ordering::Relaxed)
+}
+
 pub fn run_zone_viewer(config: &Config, zone_id: Option<ZoneId>) {
     run_client(
         config,
@@ -447,6 +928,11 @@ enum UiSystemSets {
 }
 
 fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsConfig) {
+    log_history::init_logging(
+        Level::INFO,
+        "wgpu=error,packets=debug,quest=trace,lua=debug,con=trace,animation=info",
+    );
+
     let virtual_filesystem =
         if let Some(virtual_filesystem) = config.filesystem.create_virtual_filesystem() {
             virtual_filesystem
@@ -455,13 +941,23 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             return;
         };
 
-    let (window_width, window_height) =
-        if let GraphicsModeConfig::Window { width, height } = config.graphics.mode {
-            (width, height)
-        } else {
-            (1920.0, 1080.0)
-        };
+    let display_settings = DisplaySettings {
+        mode: match config.graphics.mode {
+            GraphicsModeConfig::Window { .. } => DisplayMode::Windowed,
+            GraphicsModeConfig::Fullscreen => DisplayMode::BorderlessFullscreen,
+            GraphicsModeConfig::ExclusiveFullscreen { .. } => DisplayMode::ExclusiveFullscreen,
+        },
+        resolution: match config.graphics.mode {
+            GraphicsModeConfig::Window { width, height }
+            | GraphicsModeConfig::ExclusiveFullscreen { width, height } => {
+                (width as u32, height as u32)
+            }
+            GraphicsModeConfig::Fullscreen => (1920, 1080),
+        },
+        monitor: config.graphics.monitor,
+    };
 
+    let headless = config.graphics.headless;
     let mut app = App::new();
 
     // Must Initialise asset server before asset plugin
@@ -471,55 +967,83 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     .insert_resource(AssetServer::new(VfsAssetIo::new(virtual_filesystem)));
 
     // Initialise bevy engine
-    app.insert_resource(Msaa::Off)
+    let mut default_plugins = bevy::prelude::DefaultPlugins
+        .set(bevy::render::RenderPlugin {
+            wgpu_settings: WgpuSettings {
+                features: WgpuFeatures::TEXTURE_COMPRESSION_BC,
+                // When running headless we disable all wgpu backends to skip rendering
+                backends: if headless {
+                    None
+                } else {
+                    WgpuSettings::default().backends
+                },
+                ..Default::default()
+            },
+        })
+        .set(bevy::window::WindowPlugin {
+            primary_window: Some(Window {
+                title: "rose-offline-client".to_string(),
+                present_mode: if config.graphics.disable_vsync {
+                    bevy::window::PresentMode::Immediate
+                } else {
+                    bevy::window::PresentMode::Fifo
+                },
+                resolution: bevy::window::WindowResolution::new(
+                    display_settings.resolution.0 as f32,
+                    display_settings.resolution.1 as f32,
+                ),
+                mode: display_settings.window_mode(),
+                position: WindowPosition::Centered(display_settings.monitor_selection()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .disable::<bevy::log::LogPlugin>()
+        .set(bevy::pbr::PbrPlugin {
+            prepass_enabled: false,
+        });
+
+    if headless {
+        // Without winit the primary window is never created, so we drive the app loop ourselves
+        // with a fixed time step so that runs are deterministic
+        default_plugins = default_plugins.disable::<bevy::winit::WinitPlugin>();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            Duration::from_secs_f64(1.0 / 60.0),
+        ))
+        .add_plugins(bevy::app::ScheduleRunnerPlugin::run_loop(Duration::ZERO));
+    }
+
+    let msaa = match config.graphics.msaa_samples {
+        2 => Msaa::Sample2,
+        4 => Msaa::Sample4,
+        8 => Msaa::Sample8,
+        _ => Msaa::Off,
+    };
+
+    app.insert_resource(msaa)
         .insert_resource(ClearColor(Color::rgb(0.70, 0.90, 1.0)))
         .insert_resource(bevy::gizmos::GizmoConfig {
             depth_bias: -0.1,
             ..Default::default()
         })
         .add_plugins((
-            bevy::prelude::DefaultPlugins
-                .set(bevy::render::RenderPlugin {
-                    wgpu_settings: WgpuSettings {
-                        features: WgpuFeatures::TEXTURE_COMPRESSION_BC,
-                        // backends: Some(Backends::DX12),
-                        ..Default::default()
-                    },
-                })
-                .set(bevy::window::WindowPlugin {
-                    primary_window: Some(Window {
-                        title: "rose-offline-client".to_string(),
-                        present_mode: if config.graphics.disable_vsync {
-                            bevy::window::PresentMode::Immediate
-                        } else {
-                            bevy::window::PresentMode::Fifo
-                        },
-                        resolution: bevy::window::WindowResolution::new(
-                            window_width,
-                            window_height,
-                        ),
-                        mode: if matches!(config.graphics.mode, GraphicsModeConfig::Fullscreen) {
-                            WindowMode::BorderlessFullscreen
-                        } else {
-                            WindowMode::Windowed
-                        },
-                        ..Default::default()
-                    }),
-                    ..Default::default()
-                })
-                .set(bevy::log::LogPlugin {
-                    level: Level::INFO,
-                    filter:
-                        "wgpu=error,packets=debug,quest=trace,lua=debug,con=trace,animation=info"
-                            .to_string(),
-                })
-                .set(bevy::pbr::PbrPlugin {
-                    prepass_enabled: false,
-                }),
+            default_plugins,
             bevy::diagnostic::EntityCountDiagnosticsPlugin,
             bevy::diagnostic::FrameTimeDiagnosticsPlugin,
         ));
 
+    let mut key_bindings = KeyBindings::default();
+    for (name, value) in config.key_bindings.iter() {
+        match (
+            KeyBindingAction::from_config_name(name),
+            KeyBinding::from_config_value(value),
+        ) {
+            (Some(action), Some(binding)) => key_bindings.bindings[action] = binding,
+            _ => log::warn!("Ignoring invalid key binding {} = \"{}\"", name, value),
+        }
+    }
+    key_bindings.global = key_bindings.bindings;
+
     // Initialise 3rd party bevy plugins
     app.insert_resource(bevy_rapier3d::prelude::RapierConfiguration {
         physics_pipeline_active: false,
@@ -546,22 +1070,181 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         .add_asset::<ExeResourceCursor>()
         .init_asset_loader::<DialogLoader>()
         .add_asset::<Dialog>()
-        .insert_resource(RenderConfiguration {
-            passthrough_terrain_textures: config.graphics.passthrough_terrain_textures,
-            trail_effect_duration_multiplier: config.graphics.trail_effect_duration_multiplier,
+        .insert_resource({
+            let mut render_configuration = RenderConfiguration {
+                preset: RenderPreset::Custom,
+                passthrough_terrain_textures: config.graphics.passthrough_terrain_textures,
+                trail_effect_duration_multiplier: config.graphics.trail_effect_duration_multiplier,
+                weapon_trails: config.graphics.weapon_trails,
+                // A distance of 0 is saved by the settings window for unlimited / disabled
+                terrain_view_distance: config
+                    .graphics
+                    .terrain_view_distance
+                    .filter(|distance| *distance > 0.0),
+                zone_block_streaming_distance: config
+                    .graphics
+                    .zone_block_streaming_distance
+                    .filter(|distance| *distance > 0.0),
+                shadow_quality: match config.graphics.shadow_quality {
+                    ShadowQualityConfig::Off => ShadowQuality::Off,
+                    ShadowQualityConfig::Blob => ShadowQuality::Blob,
+                    ShadowQualityConfig::ShadowMap => ShadowQuality::ShadowMap,
+                },
+                particle_density: config.graphics.particle_density.clamp(0.0, 1.0),
+                water_quality: match config.graphics.water_quality {
+                    WaterQualityConfig::Low => WaterQuality::Low,
+                    WaterQualityConfig::High => WaterQuality::High,
+                },
+                bloom: config.graphics.bloom,
+                projectile_collision: config.graphics.projectile_collision,
+            };
+            if let Some(render_preset) = config.graphics.render_preset.as_ref() {
+                render_configuration.apply_preset(match render_preset {
+                    RenderPresetConfig::Low => RenderPreset::Low,
+                    RenderPresetConfig::Medium => RenderPreset::Medium,
+                    RenderPresetConfig::High => RenderPreset::High,
+                    RenderPresetConfig::Ultra => RenderPreset::Ultra,
+                });
+            }
+            render_configuration
+        })
+        .insert_resource(EffectSettings {
+            hide_other_player_skill_effects: config.graphics.hide_other_player_skill_effects,
+            hide_screen_covering_effects: config.graphics.hide_screen_covering_effects,
+            max_effects: config.graphics.max_effects.filter(|max_effects| *max_effects > 0),
         })
+        .insert_resource(load_server_profiles(config))
         .insert_resource(ServerConfiguration {
-            ip: config.server.ip.clone(),
-            port: format!("{}", config.server.port),
             preset_username: Some(config.account.username.clone()),
             preset_password: Some(config.account.password.clone()),
             preset_server_id: config.auto_login.server_id,
             preset_channel_id: config.auto_login.channel_id,
             preset_character_name: config.auto_login.character_name.clone(),
             auto_login: config.auto_login.enabled,
+            connection_timeout: (config.server.connection_timeout_seconds > 0)
+                .then(|| Duration::from_secs(config.server.connection_timeout_seconds)),
+        })
+        .insert_resource(DataOverlayConfiguration {
+            overlay_url: config.filesystem.overlay_url.clone(),
+            overlay_manifest_path: config.filesystem.overlay_manifest_path(),
+            data_path: config.filesystem.data_path(),
+        })
+        .insert_resource(BugReportConfiguration {
+            upload_url: config.bug_report.upload_url.clone(),
+            directory: PathBuf::from(&config.bug_report.directory),
+        })
+        .insert_resource(display_settings)
+        .insert_resource(ConfigFile {
+            path: config.path.clone(),
+        })
+        .insert_resource(key_bindings)
+        .insert_resource(AccessibilitySettings {
+            color_palette: match config.accessibility.color_palette {
+                ColorPaletteConfig::Default => ColorPalette::Default,
+                ColorPaletteConfig::RedGreen => ColorPalette::RedGreen,
+                ColorPaletteConfig::BlueYellow => ColorPalette::BlueYellow,
+            },
+            minimum_font_size: config.accessibility.minimum_font_size,
+            high_contrast: config.accessibility.high_contrast,
+            sound_captions: config.accessibility.sound_captions,
+        })
+        .insert_resource(InterfaceSettings {
+            show_clock: config.interface.show_clock,
+            clock_use_24_hour: config.interface.clock_use_24_hour,
+            streamer_mode: config.interface.streamer_mode,
+            idle_camera_orbit: (config.interface.idle_camera_orbit_minutes > 0).then(|| {
+                Duration::from_secs(config.interface.idle_camera_orbit_minutes as u64 * 60)
+            }),
+            wasd_movement: config.interface.wasd_movement,
+            control_scheme: match config.interface.control_scheme {
+                ControlSchemeConfig::Classic => ControlScheme::Classic,
+                ControlSchemeConfig::Modern => ControlScheme::Modern,
+            },
+            camera_sensitivity: config.interface.camera_sensitivity.clamp(0.1, 5.0),
+            combat_click_through: config.interface.combat_click_through,
+            aggro_indicator: config.interface.aggro_indicator,
+            target_camera: config.interface.target_camera,
+            minimap_rotate: config.interface.minimap_rotate,
+            cooldown_text: config.interface.cooldown_text,
+            cooldown_ready_flash: config.interface.cooldown_ready_flash,
+            skill_queue_window: Duration::from_millis(config.interface.skill_queue_window_ms),
+            auto_self_cast: config.interface.auto_self_cast,
+            tutorial_hints: config.interface.tutorial_hints,
+            focus_loss_mute_audio: config.interface.focus_loss_mute_audio,
+            focus_loss_reduce_fps: config.interface.focus_loss_reduce_fps,
+            focus_loss_stop_auto_run: config.interface.focus_loss_stop_auto_run,
+            announcement_banner_duration: (config.interface.announcement_banner_seconds > 0)
+                .then(|| Duration::from_secs(config.interface.announcement_banner_seconds)),
+            shout_banners: config.interface.shout_banners,
+            toast_items: config.interface.toast_items,
+            toast_quests: config.interface.toast_quests,
+            toast_social: config.interface.toast_social,
+            toast_connection: config.interface.toast_connection,
+        })
+        .insert_resource({
+            let fanfare_rarity = |rarity: &DropFanfareRarityConfig| match rarity {
+                DropFanfareRarityConfig::Off => None,
+                DropFanfareRarityConfig::Common => Some(ItemRarity::Common),
+                DropFanfareRarityConfig::Rare => Some(ItemRarity::Rare),
+                DropFanfareRarityConfig::Unique => Some(ItemRarity::Unique),
+            };
+            let drop_notifications = &config.interface.drop_notifications;
+            DropNotificationSettings {
+                fanfare_rarity: fanfare_rarity(&drop_notifications.fanfare_rarity),
+                party_fanfare_rarity: fanfare_rarity(&drop_notifications.party_fanfare_rarity),
+                mute_common_drops: drop_notifications.mute_common_drops,
+            }
+        })
+        .insert_resource(AwayState::new(config.interface.afk_message.clone()))
+        .insert_resource({
+            let requests = &config.interface.requests;
+            RequestSettings {
+                timeout: Duration::from_secs(requests.timeout_seconds),
+                decline_while_busy: enum_map! {
+                    RequestCategory::Party => requests.decline_party_while_busy,
+                    RequestCategory::Trade => requests.decline_trade_while_busy,
+                    RequestCategory::Duel => requests.decline_duel_while_busy,
+                },
+                decline_from_strangers: enum_map! {
+                    RequestCategory::Party => requests.decline_party_from_strangers,
+                    RequestCategory::Trade => requests.decline_trade_from_strangers,
+                    RequestCategory::Duel => requests.decline_duel_from_strangers,
+                },
+            }
+        })
+        .insert_resource(HudSettings {
+            hidden: false,
+            restore_on_combat: config.interface.hud.restore_on_combat,
+            widgets: enum_map! {
+                HudWidget::Chatbox => config.interface.hud.chatbox,
+                HudWidget::Clock => config.interface.hud.clock,
+                HudWidget::Hotbar => config.interface.hud.hotbar,
+                HudWidget::Minimap => config.interface.hud.minimap,
+                HudWidget::Party => config.interface.hud.party,
+                HudWidget::PlayerInfo => config.interface.hud.player_info,
+                HudWidget::SelectedTarget => config.interface.hud.selected_target,
+                HudWidget::StatusEffects => config.interface.hud.status_effects,
+            },
+        })
+        .insert_resource({
+            let healthbar_policy = |policy: &NameTagHealthbarPolicyConfig| match policy {
+                NameTagHealthbarPolicyConfig::Always => NameTagHealthbarPolicy::Always,
+                NameTagHealthbarPolicyConfig::InCombat => NameTagHealthbarPolicy::InCombat,
+                NameTagHealthbarPolicyConfig::Never => NameTagHealthbarPolicy::Never,
+            };
+            let name_tags = &config.interface.name_tags;
+            NameTagSettings {
+                healthbar_policy: enum_map! {
+                    NameTagHealthbarCategory::PartyMember => healthbar_policy(&name_tags.party_member_healthbar),
+                    NameTagHealthbarCategory::Pet => healthbar_policy(&name_tags.pet_healthbar),
+                    NameTagHealthbarCategory::Character => healthbar_policy(&name_tags.character_healthbar),
+                    NameTagHealthbarCategory::Monster => healthbar_policy(&name_tags.monster_healthbar),
+                },
+                ..Default::default()
+            }
         })
         .insert_resource(SoundSettings {
-            enabled: config.sound.enabled,
+            enabled: config.sound.enabled && !headless,
             global_gain: config.sound.volume.global,
             gains: enum_map! {
                 SoundCategory::BackgroundMusic => config.sound.volume.background_music,
@@ -572,6 +1255,8 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
                 SoundCategory::NpcSounds => config.sound.volume.npc_sounds,
                 SoundCategory::Ui => config.sound.volume.ui_sounds,
             },
+            music_cross_fade: config.sound.music_cross_fade,
+            muted: false,
         })
         .add_plugins((
             RoseAnimationPlugin,
@@ -584,14 +1269,18 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     app.add_state::<AppState>()
         .insert_resource(State::new(app_state));
 
-    app.add_event::<BankEvent>()
+    app.add_event::<AppraisalEvent>()
+        .add_event::<BankEvent>()
         .add_event::<ChatboxEvent>()
         .add_event::<CharacterSelectEvent>()
+        .add_event::<CharacterTitleEvent>()
         .add_event::<ClanDialogEvent>()
         .add_event::<ClientEntityEvent>()
         .add_event::<ConversationDialogEvent>()
+        .add_event::<FishingEvent>()
         .add_event::<GameConnectionEvent>()
         .add_event::<HitEvent>()
+        .add_event::<ItemAcquiredEvent>()
         .add_event::<LoginEvent>()
         .add_event::<LoadZoneEvent>()
         .add_event::<MessageBoxEvent>()
@@ -603,9 +1292,13 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         .add_event::<PersonalStoreEvent>()
         .add_event::<PlayerCommandEvent>()
         .add_event::<QuestTriggerEvent>()
+        .add_event::<RepairEvent>()
+        .add_event::<SoundEffectEvent>()
         .add_event::<SystemFuncEvent>()
         .add_event::<SpawnEffectEvent>()
         .add_event::<SpawnProjectileEvent>()
+        .add_event::<ToastEvent>()
+        .add_event::<TutorialHintEvent>()
         .add_event::<UseItemEvent>()
         .add_event::<WorldConnectionEvent>()
         .add_event::<ZoneEvent>()
@@ -620,9 +1313,13 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         (apply_deferred,).in_set(GameStages::DebugRenderPreFlush),
     );
 
+    app.init_resource::<InputFocus>();
     app.add_systems(
         Update,
-        (free_camera_system, orbit_camera_system).in_set(GameSystemSets::UpdateCamera),
+        (
+            input_focus_system.before(GameSystemSets::UpdateCamera),
+            (free_camera_system, orbit_camera_system).in_set(GameSystemSets::UpdateCamera),
+        ),
     );
     app.add_systems(
         Update,
@@ -630,6 +1327,7 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             (
                 auto_login_system,
                 background_music_system,
+                window_focus_system,
                 character_model_update_system,
                 character_model_add_collider_system.after(character_model_update_system),
                 personal_store_model_system,
@@ -674,14 +1372,19 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
                 spawn_effect_system,
                 move_destination_effect_system.after(game_mouse_input_system),
                 npc_idle_sound_system,
+                sound_effect_system.after(animation_sound_system),
                 name_tag_system,
                 name_tag_visibility_system.after(game_mouse_input_system),
                 name_tag_update_color_system,
+                monster_aggro_system.after(name_tag_update_color_system),
+                spectate_camera_system.after(monster_aggro_system),
                 world_time_system,
                 system_func_event_system,
                 load_dialog_sprites_system,
                 zone_time_system.after(world_time_system),
                 directional_light_system,
+                bloom_system,
+                weapon_trail_system.after(command_system),
             ),
         ),
     );
@@ -693,12 +1396,23 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
 
     app.add_systems(
         Update,
-        ui_item_drop_name_system.in_set(UiSystemSets::UiFirst),
+        (ui_aggro_indicator_system, ui_item_drop_name_system)
+            .run_if(hud_visible)
+            .in_set(UiSystemSets::UiFirst),
+    );
+    app.add_systems(
+        Update,
+        ui_accessibility_system.before(UiSystemSets::UiDebugMenu),
     );
 
     app.add_systems(
         Update,
-        (ui_message_box_system, ui_number_input_dialog_system).in_set(UiSystemSets::UiLast),
+        (
+            ui_message_box_system,
+            ui_number_input_dialog_system,
+            ui_toast_system,
+        )
+            .in_set(UiSystemSets::UiLast),
     );
     app.add_systems(
         Update,
@@ -743,7 +1457,11 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     // with model destruction but to also be before global transform is calculated.
     app.add_systems(
         PostUpdate,
-        (vehicle_model_system, vehicle_sound_system)
+        (
+            vehicle_model_system,
+            vehicle_sound_system,
+            vehicle_exhaust_system,
+        )
             .chain()
             .in_set(GameStages::AfterUpdate),
     );
@@ -754,6 +1472,8 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         (
             zone_loader_system,
             game_zone_change_system.after(zone_loader_system),
+            zone_block_streaming_system.after(zone_loader_system),
+            terrain_view_distance_system.after(zone_block_streaming_system),
         )
             .in_set(GameStages::ZoneChange),
     );
@@ -765,6 +1485,9 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             debug_render_collider_system,
             debug_render_skeleton_system,
             debug_render_directional_light_system,
+            debug_render_monster_spawn_system,
+            debug_render_command_system,
+            debug_render_range_system,
         )
             .in_set(GameStages::DebugRender),
     );
@@ -779,7 +1502,12 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     app.add_systems(OnExit(AppState::ModelViewer), model_viewer_exit_system);
     app.add_systems(
         PostUpdate,
-        model_viewer_system
+        (
+            model_viewer_system,
+            model_viewer_scene_system.after(model_viewer_system),
+            model_viewer_retarget_system.after(model_viewer_system),
+            model_viewer_training_dummy_system.after(model_viewer_system),
+        )
             .run_if(in_state(AppState::ModelViewer))
             .in_set(GameStages::ZoneChange)
             .before(EguiSet::ProcessOutput), // model_viewer_system renders UI so must be before egui
@@ -839,23 +1567,47 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     );
 
     // Game
-    app.init_resource::<UiStateDragAndDrop>()
+    app.init_resource::<ChannelQueue>()
+        .init_resource::<ChannelSwitch>()
+        .init_resource::<CharacterSettings>()
+        .init_resource::<CharacterTitles>()
+        .init_resource::<IgnoreList>()
+        .init_resource::<MapExploration>()
+        .init_resource::<Fishing>()
+        .init_resource::<MapPings>()
+        .init_resource::<SitPointTarget>()
+        .init_resource::<UiStateDragAndDrop>()
         .init_resource::<UiStateWindows>()
         .init_resource::<UiStateDebugWindows>()
         .init_resource::<ClientEntityList>()
         .init_resource::<DebugRenderConfig>()
         .init_resource::<WorldTime>()
         .init_resource::<ZoneTime>()
-        .init_resource::<SelectedTarget>()
-        .init_resource::<NameTagSettings>();
+        .init_resource::<ZoneLoadTracker>()
+        .init_resource::<AutoTravel>()
+        .init_resource::<BackgroundMusic>()
+        .init_resource::<PlayerCommandBuffer>()
+        .init_resource::<SkillQueue>()
+        .init_resource::<Unstick>()
+        .init_resource::<SessionStats>()
+        .init_resource::<SelectedTarget>();
 
-    app.add_systems(OnEnter(AppState::Game), game_state_enter_system);
+    app.add_systems(
+        OnEnter(AppState::Game),
+        (game_state_enter_system, character_settings_load_system),
+    );
+    app.add_systems(
+        OnExit(AppState::Game),
+        (character_settings_exit_system, target_camera_exit_system),
+    );
 
     app.add_systems(
         Update,
         (
             ability_values_system,
+            character_settings_save_system,
             clan_system,
+            companion_system,
             command_system
                 .after(npc_model_update_system)
                 .after(npc_model_add_collider_system)
@@ -872,8 +1624,45 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             use_item_event_system.before(spawn_effect_system),
             status_effect_system,
             passive_recovery_system,
+            rest_system.before(passive_recovery_system),
             quest_trigger_system,
             game_mouse_input_system.after(GameSystemSets::Ui),
+            player_keyboard_move_system.after(GameSystemSets::Ui),
+            auto_travel_system
+                .after(GameSystemSets::Ui)
+                .after(game_mouse_input_system)
+                .before(player_keyboard_move_system),
+        )
+            .run_if(in_state(AppState::Game)),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            npc_idle_system
+                .after(command_system)
+                .before(facing_direction_system),
+            stun_system.before(command_system),
+            knockback_system
+                .after(update_position_system)
+                .before(collision_player_system),
+            tutorial_hint_system.before(UiSystemSets::Ui),
+            item_durability_warning_system,
+            sound_caption_system,
+            session_stats_system,
+            drop_notification_system,
+            blob_shadow_system,
+            water_splash_system,
+            unstick_system.before(collision_player_system),
+            map_exploration_system,
+            map_ping_system,
+            fishing_system.after(command_system),
+            sit_point_system.after(command_system),
+            hud_visibility_system.before(UiSystemSets::UiFirst),
+            target_camera_system.after(hud_visibility_system),
+            zone_preload_system,
+            away_system.before(UiSystemSets::UiFirst),
+            achievement_system,
         )
             .run_if(in_state(AppState::Game)),
     );
@@ -882,29 +1671,52 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         Update,
         (
             (
+                ui_appraisal_system,
                 ui_bank_system,
-                ui_chatbox_system,
                 ui_character_info_system,
                 ui_clan_system,
+                ui_clock_system,
                 ui_create_clan_system,
                 ui_inventory_system,
                 ui_game_menu_system.after(ui_character_info_system),
-                ui_hotbar_system,
                 ui_minimap_system,
                 ui_npc_store_system,
-                ui_party_system,
                 ui_party_option_system,
                 ui_personal_store_system,
                 ui_player_info_system,
-            ),
+            )
+                .run_if(hud_visible),
             (
+                ui_achievements_system,
+                ui_announcement_banner_system,
+                ui_bug_report_system,
+                ui_channel_list_system,
+                ui_fishing_system,
+                ui_help_system,
+                ui_ignore_list_system,
+                ui_monster_info_system,
                 ui_quest_list_system,
-                ui_respawn_system,
+                ui_repair_system,
                 ui_selected_target_system,
+                ui_session_stats_system,
                 ui_skill_list_system,
                 ui_skill_tree_system,
                 ui_settings_system,
                 ui_status_effects_system,
+                ui_target_camera_system,
+                ui_tutorial_hint_system,
+            )
+                .run_if(hud_visible),
+            // These hide their own windows with the HUD so they keep handling hotkeys, chat
+            // messages and party invites, and dialogs which block gameplay cannot be hidden
+            (
+                ui_chatbox_system,
+                ui_escape_menu_system,
+                ui_hotbar_system,
+                ui_party_system,
+                ui_request_popup_system,
+                ui_respawn_system,
+                ui_zone_loading_system,
                 conversation_dialog_system,
             ),
         )
@@ -915,9 +1727,12 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     if !systems_config.disable_player_command_system {
         app.add_systems(
             Update,
-            (player_command_system
-                .after(cooldown_system)
-                .after(game_mouse_input_system),)
+            (
+                player_command_buffer_system.before(player_command_system),
+                player_command_system
+                    .after(cooldown_system)
+                    .after(game_mouse_input_system),
+            )
                 .run_if(in_state(AppState::Game)),
         );
     }
@@ -937,6 +1752,11 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             login_connection_system,
             world_connection_system,
             game_connection_system,
+            extension_message_system,
+            channel_switch_system.after(game_connection_system),
+            character_title_system
+                .after(game_connection_system)
+                .after(extension_message_system),
         ),
     );
 
@@ -1088,6 +1908,10 @@ fn load_game_data_irose(
             rose_data_irose::get_quest_database(&vfs_resource.vfs, string_database.clone())
                 .expect("Failed to load quest database"),
         ),
+        sit_points: SitPointDatabase::load(&vfs_resource.vfs).unwrap_or_else(|error| {
+            log::info!("No sit points loaded: {}", error);
+            SitPointDatabase::default()
+        }),
         skills,
         skybox: rose_data_irose::get_skybox_database(&vfs_resource.vfs)
             .expect("Failed to load skybox database"),
@@ -1114,6 +1938,10 @@ fn load_game_data_irose(
             .vfs
             .read_file::<StbFile, _>("3DDATA/STB/LIST_MORPH_OBJECT.STB")
             .expect("Failed to load 3DDATA/STB/LIST_MORPH_OBJECT.STB"),
+        stb_warp: vfs_resource
+            .vfs
+            .read_file::<StbFile, _>("3DDATA/STB/WARP.STB")
+            .expect("Failed to load 3DDATA/STB/WARP.STB"),
         character_select_positions: vec![
             Transform::from_translation(Vec3::new(5205.0, 1.0, -5205.0))
                 .with_rotation(Quat::from_xyzw(0.0, 1.0, 0.0, 0.0))
@@ -1139,7 +1967,6 @@ fn load_common_game_data(
     vfs_resource: Res<VfsResource>,
     game_data: Res<GameData>,
     asset_server: Res<AssetServer>,
-    mut damage_digit_materials: ResMut<Assets<DamageDigitMaterial>>,
     mut egui_context: EguiContexts,
 ) {
     commands.insert_resource(SpecularTexture {
@@ -1170,10 +1997,12 @@ fn load_common_game_data(
         BloomSettings::NATURAL,
     ));
 
-    commands.insert_resource(DamageDigitsSpawner::load(
-        &asset_server,
-        &mut damage_digit_materials,
-    ));
+    commands.insert_resource(DamageDigitsSpawner::load(&asset_server));
+    commands.insert_resource(DamageDigitRenderData::new([
+        asset_server.load("3DDATA/EFFECT/SPECIAL/DIGITNUMBER01.DDS"),
+        asset_server.load("3DDATA/EFFECT/SPECIAL/DIGITNUMBER02.DDS"),
+        asset_server.load("3DDATA/EFFECT/SPECIAL/DIGITNUMBERMISS.DDS"),
+    ]));
 
     let mut fonts = egui::FontDefinitions::default();
     fonts.font_data.insert(
@@ -0,0 +1,108 @@
+use std::{collections::VecDeque, fmt::Write, sync::Mutex, time::SystemTime};
+
+use bevy::{log::Level, utils::tracing};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::{layer::Context, prelude::*, EnvFilter, Layer, Registry};
+
+const LOG_HISTORY_SIZE: usize = 500;
+
+struct LogHistoryEntry {
+    time: SystemTime,
+    level: tracing::Level,
+    target: String,
+    message: String,
+}
+
+static LOG_HISTORY: Mutex<VecDeque<LogHistoryEntry>> = Mutex::new(VecDeque::new());
+
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl tracing::field::Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name().starts_with("log.") {
+            return;
+        }
+
+        if !self.message.is_empty() {
+            self.message.push(' ');
+        }
+
+        if field.name() == "message" {
+            write!(self.message, "{:?}", value).ok();
+        } else {
+            write!(self.message, "{}={:?}", field.name(), value).ok();
+        }
+    }
+}
+
+/// Keeps the most recent log lines in memory so they can be attached to bug reports.
+struct LogHistoryLayer;
+
+impl<S: tracing::Subscriber> Layer<S> for LogHistoryLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let normalized_metadata = event.normalized_metadata();
+        let metadata = normalized_metadata
+            .as_ref()
+            .unwrap_or_else(|| event.metadata());
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        if let Ok(mut history) = LOG_HISTORY.lock() {
+            if history.len() >= LOG_HISTORY_SIZE {
+                history.pop_front();
+            }
+
+            history.push_back(LogHistoryEntry {
+                time: SystemTime::now(),
+                level: *metadata.level(),
+                target: metadata.target().to_string(),
+                message: visitor.message,
+            });
+        }
+    }
+}
+
+/// Replaces bevy's LogPlugin so that log lines are also recorded into the log history.
+pub fn init_logging(level: Level, filter: &str) {
+    let default_filter = format!("{},{}", level, filter);
+    let filter_layer = EnvFilter::try_from_default_env()
+        .or_else(|_| EnvFilter::try_new(&default_filter))
+        .unwrap();
+
+    let subscriber = Registry::default()
+        .with(filter_layer)
+        .with(tracing_subscriber::fmt::Layer::default().with_writer(std::io::stderr))
+        .with(LogHistoryLayer);
+
+    if tracing_log::LogTracer::init().is_err() {
+        log::warn!("Could not set global logger as it is already set");
+    }
+
+    if tracing::subscriber::set_global_default(subscriber).is_err() {
+        log::warn!("Could not set global tracing subscriber as it is already set");
+    }
+}
+
+pub fn log_history() -> Vec<String> {
+    LOG_HISTORY
+        .lock()
+        .map(|history| {
+            history
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "{} {} {}: {}",
+                        chrono::DateTime::<chrono::Local>::from(entry.time).format("%H:%M:%S%.3f"),
+                        entry.level,
+                        entry.target,
+                        entry.message
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
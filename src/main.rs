@@ -2,8 +2,10 @@ use std::path::Path;
 
 use rose_data::ZoneId;
 use rose_offline_client::{
-    load_config, run_game, run_model_viewer, run_zone_viewer, Config, FilesystemDeviceConfig,
-    SystemsConfig,
+    load_config,
+    resources::{HeadlessScript, DEFAULT_CONFIG_PATH},
+    run_data_verifier, run_game, run_headless, run_model_viewer, run_zone_viewer, Config,
+    FilesystemDeviceConfig, SystemsConfig,
 };
 
 fn main() {
@@ -44,6 +46,23 @@ fn main() {
                 .help("Optional path to extracted data, any files here override ones in data.idx")
                 .takes_value(true),
         )
+        .arg(
+            clap::Arg::new("data-overlay-url")
+                .long("data-overlay-url")
+                .help("Optional url to download missing or corrupt data files from into --data-path")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("data-overlay-manifest")
+                .long("data-overlay-manifest")
+                .help("Path to the sha256 checksum manifest which downloaded data files must match, defaults to data_overlay.sha256")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("verify-data")
+                .long("verify-data")
+                .help("Verify that required game data files exist and can be read, then exit"),
+        )
         .arg(
             clap::Arg::new("zone")
                 .long("zone")
@@ -60,6 +79,17 @@ fn main() {
                 .long("model-viewer")
                 .help("Run model viewer"),
         )
+        .arg(
+            clap::Arg::new("headless")
+                .long("headless")
+                .help("Run without creating a window or renderer"),
+        )
+        .arg(
+            clap::Arg::new("headless-script")
+                .long("headless-script")
+                .help("Path to a headless script to run, implies --headless. Exits with an error code if the script fails")
+                .takes_value(true),
+        )
         .arg(
             clap::Arg::new("disable-vsync")
                 .long("disable-vsync")
@@ -75,8 +105,19 @@ fn main() {
             clap::Arg::new("port")
                 .long("port")
                 .help("Server port for game login")
-                .takes_value(true)
-                .default_value("29000"),
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("servers")
+                .long("servers")
+                .help("Path to servers.toml containing saved server profiles")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("server-profile")
+                .long("server-profile")
+                .help("Name of the server profile from servers.toml to select")
+                .takes_value(true),
         )
         .arg(
             clap::Arg::new("username")
@@ -146,17 +187,28 @@ fn main() {
         );
     let matches = command.get_matches();
 
-    let mut config = matches
-        .value_of("config")
-        .map(Path::new)
-        .map_or_else(Config::default, load_config);
+    let mut config = match matches.value_of("config").map(Path::new) {
+        Some(path) => load_config(path),
+        None if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+            load_config(Path::new(DEFAULT_CONFIG_PATH))
+        }
+        None => Config::default(),
+    };
 
     if let Some(ip) = matches.value_of("ip") {
-        config.server.ip = ip.into();
+        config.server.ip = Some(ip.into());
     }
 
     if let Some(port) = matches.value_of("port").and_then(|s| s.parse::<u16>().ok()) {
-        config.server.port = port;
+        config.server.port = Some(port);
+    }
+
+    if let Some(path) = matches.value_of("servers") {
+        config.server.profiles_path = path.into();
+    }
+
+    if let Some(name) = matches.value_of("server-profile") {
+        config.server.profile = Some(name.into());
     }
 
     if let Some(username) = matches.value_of("username") {
@@ -193,6 +245,10 @@ fn main() {
         config.graphics.disable_vsync = true;
     }
 
+    if matches.is_present("headless") || matches.is_present("headless-script") {
+        config.graphics.headless = true;
+    }
+
     if matches.is_present("passthrough-terrain-textures") {
         config.graphics.passthrough_terrain_textures = true;
     }
@@ -255,7 +311,34 @@ fn main() {
             .push(FilesystemDeviceConfig::Vfs("data.idx".into()));
     }
 
-    if matches.is_present("model-viewer") {
+    if let Some(overlay_url) = matches.value_of("data-overlay-url") {
+        config.filesystem.overlay_url = Some(overlay_url.into());
+    }
+
+    if let Some(overlay_manifest) = matches.value_of("data-overlay-manifest") {
+        config.filesystem.overlay_manifest = Some(overlay_manifest.into());
+    }
+
+    if matches.is_present("verify-data") {
+        if !run_data_verifier(&config) {
+            std::process::exit(1);
+        }
+    } else if let Some(script_path) = matches.value_of("headless-script") {
+        match HeadlessScript::load(Path::new(script_path)) {
+            Ok(script) => {
+                if !run_headless(&config, script) {
+                    std::process::exit(1);
+                }
+            }
+            Err(error) => {
+                println!(
+                    "Failed to load headless script {} with error: {}",
+                    script_path, error
+                );
+                std::process::exit(1);
+            }
+        }
+    } else if matches.is_present("model-viewer") {
         run_model_viewer(&config);
     } else if matches.is_present("zone-viewer") {
         run_zone_viewer(
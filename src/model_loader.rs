@@ -45,6 +45,22 @@ const TRAIL_COLOURS: [Color; 9] = [
     Color::rgba(1.0, 0.5, 0.0, 1.0),
 ];
 
+// Melee weapons without a trail in their effect data use a trail colour for their class
+const WEAPON_CLASS_TRAIL_DURATION: f32 = 0.25;
+
+fn weapon_class_trail_colour(item_class: ItemClass) -> Option<Color> {
+    match item_class {
+        ItemClass::OneHandedSword | ItemClass::TwoHandedSword => {
+            Some(Color::rgba(0.85, 0.9, 1.0, 1.0))
+        }
+        ItemClass::OneHandedBlunt => Some(Color::rgba(1.0, 0.85, 0.6, 1.0)),
+        ItemClass::TwoHandedSpear => Some(Color::rgba(0.7, 1.0, 0.8, 1.0)),
+        ItemClass::TwoHandedAxe => Some(Color::rgba(1.0, 0.6, 0.5, 1.0)),
+        ItemClass::Katar | ItemClass::DualSwords => Some(Color::rgba(0.9, 0.7, 1.0, 1.0)),
+        _ => None,
+    }
+}
+
 #[derive(Resource)]
 pub struct ModelLoader {
     vfs: Arc<VirtualFilesystem>,
@@ -257,23 +273,26 @@ impl ModelLoader {
 
         for (link_dummy_bone_id, effect_id) in npc_model_data.effect_ids.iter() {
             if let Some(effect_path) = self.npc_chr.effect_files.get(*effect_id as usize) {
-                if let Some(dummy_bone_entity) = skinned_mesh
+                // Models without a skeleton, such as most companions, have no dummy bones so
+                // their idle effects are attached to the model itself
+                let parent_entity = skinned_mesh
                     .joints
                     .get(dummy_bone_offset + *link_dummy_bone_id as usize)
-                {
-                    if let Some(effect_entity) = spawn_effect(
-                        &self.vfs,
-                        commands,
-                        asset_server,
-                        particle_materials,
-                        effect_mesh_materials,
-                        effect_path.into(),
-                        false,
-                        None,
-                    ) {
-                        commands.entity(*dummy_bone_entity).add_child(effect_entity);
-                        model_parts.push(effect_entity);
-                    }
+                    .copied()
+                    .unwrap_or(model_entity);
+
+                if let Some(effect_entity) = spawn_effect(
+                    &self.vfs,
+                    commands,
+                    asset_server,
+                    particle_materials,
+                    effect_mesh_materials,
+                    effect_path.into(),
+                    false,
+                    None,
+                ) {
+                    commands.entity(parent_entity).add_child(effect_entity);
+                    model_parts.push(effect_entity);
                 }
             }
         }
@@ -508,6 +527,7 @@ impl ModelLoader {
                             / 100.0,
                         trail_texture: self.trail_effect_image.clone_weak(),
                         distance_per_point: 10.0 / 100.0,
+                        emitting: false,
                     },
                     Transform::default(),
                     GlobalTransform::default(),
@@ -599,27 +619,29 @@ impl ModelLoader {
             return ArrayVec::default();
         };
 
-        let weapon_effect_data = if let Some(weapon_effect_data) = weapon_item_data
+        let effect_trail = weapon_item_data
             .effect_id
             .and_then(|id| self.effect_database.get_effect(id))
-        {
-            weapon_effect_data
+            .and_then(|weapon_effect_data| {
+                let trail_colour_index = weapon_effect_data.trail_colour_index?;
+                Some((
+                    TRAIL_COLOURS
+                        .get(trail_colour_index.get())
+                        .cloned()
+                        .unwrap_or(Color::WHITE),
+                    weapon_effect_data.trail_duration.as_secs_f32(),
+                ))
+            });
+
+        let (colour, duration) = if let Some(effect_trail) = effect_trail.or_else(|| {
+            weapon_class_trail_colour(weapon_item_data.item_data.class)
+                .map(|colour| (colour, WEAPON_CLASS_TRAIL_DURATION))
+        }) {
+            effect_trail
         } else {
             return ArrayVec::default();
         };
 
-        let trail_colour_index =
-            if let Some(trail_colour_index) = weapon_effect_data.trail_colour_index {
-                trail_colour_index
-            } else {
-                return ArrayVec::default();
-            };
-
-        let colour = TRAIL_COLOURS
-            .get(trail_colour_index.get())
-            .cloned()
-            .unwrap_or(Color::WHITE);
-
         let mut parts = ArrayVec::new();
 
         if let Some(trail_entity) = self.spawn_weapon_trail(
@@ -628,7 +650,7 @@ impl ModelLoader {
             weapon_item_number,
             0,
             colour,
-            weapon_effect_data.trail_duration.as_secs_f32(),
+            duration,
         ) {
             commands.entity(weapon_bone_entity).add_child(trail_entity);
             parts.push(trail_entity);
@@ -645,7 +667,7 @@ impl ModelLoader {
                 weapon_item_number,
                 2,
                 colour,
-                weapon_effect_data.trail_duration.as_secs_f32(),
+                duration,
             ) {
                 commands
                     .entity(subweapon_bone_entity)
@@ -945,6 +967,7 @@ impl ModelLoader {
             skinned_mesh_inverse_bindposes_assets,
         );
         let mut model_parts = EnumMap::default();
+        let mut exhaust_effects = Vec::new();
 
         for vehicle_part_index in [
             VehiclePartIndex::Body,
@@ -999,6 +1022,44 @@ impl ModelLoader {
                             }
                         }
                     }
+
+                    // The engine move effect is the exhaust, emitted from the same dummy bones
+                    // as the engine's idle effects
+                    if let (VehiclePartIndex::Engine, Some(effect_path)) = (
+                        vehicle_part_index,
+                        item_data
+                            .move_effect_file_id
+                            .and_then(|id| self.effect_database.get_effect_file(id)),
+                    ) {
+                        for (dummy_index, _) in item_data
+                            .dummy_effect_file_ids
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, effect_file_id)| effect_file_id.is_some())
+                        {
+                            if let Some(dummy_bone_entity) =
+                                skinned_mesh.joints.get(dummy_bone_offset + dummy_index)
+                            {
+                                if let Some(effect_entity) = spawn_effect(
+                                    &self.vfs,
+                                    commands,
+                                    asset_server,
+                                    particle_materials,
+                                    effect_mesh_materials,
+                                    effect_path.into(),
+                                    false,
+                                    None,
+                                ) {
+                                    commands
+                                        .entity(effect_entity)
+                                        .insert(Visibility::Hidden);
+                                    commands.entity(*dummy_bone_entity).add_child(effect_entity);
+                                    model_parts[vehicle_part_index].1.push(effect_entity);
+                                    exhaust_effects.push(effect_entity);
+                                }
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -1018,6 +1079,7 @@ impl ModelLoader {
                 driver_model_entity,
                 driver_dummy_entity: skinned_mesh.joints[dummy_bone_offset],
                 model_parts,
+                exhaust_effects,
                 vehicle_action_motions: enum_map! {
                     action =>  {
                         if let Some(motion_data) = self.character_motion_database.get_vehicle_action_motion(
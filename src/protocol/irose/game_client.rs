@@ -1,20 +1,21 @@
 use async_trait::async_trait;
 use num_traits::FromPrimitive;
-use std::net::SocketAddr;
+use std::{net::SocketAddr, time::Duration};
 use tokio::net::TcpStream;
 
 use rose_data::{QuestTriggerHash, SkillId};
 use rose_game_common::{
-    components::MoveMode,
+    components::{InventoryPageType, ItemSlot, MoveMode, INVENTORY_PAGE_SIZE},
     messages::{
         client::ClientMessage,
         server::{
             CharacterData, CharacterDataItems, ConnectionRequestError, ServerMessage,
             SpawnEntityCharacter,
         },
+        ClientEntityId,
     },
 };
-use rose_network_common::{Connection, Packet, PacketCodec};
+use rose_network_common::{Connection, Packet, PacketCodec, PacketReader, PacketWriter};
 use rose_network_irose::{
     game_client_packets::{
         PacketClientAttack, PacketClientBankMoveItem, PacketClientBankOpen,
@@ -65,12 +66,47 @@ use rose_network_irose::{
     ClientPacketCodec, IROSE_112_TABLE,
 };
 
-use crate::protocol::{ProtocolClient, ProtocolClientError};
+use crate::protocol::{
+    ExtensionClientMessage, ExtensionServerMessage, NpcStoreStockItem, ProtocolClient,
+    ProtocolClientError,
+};
+
+// CLI_ALIVE, sent periodically so the server does not drop an idle connection
+const PACKET_ALIVE: u16 = 0x700;
+
+// CLI_APPRAISAL_REQ / GSV_APPRAISAL_REPLY, rose_game_common has no message for appraisal
+const PACKET_APPRAISAL: u16 = 0x7ba;
+
+// CLI_PARTY_CHAT / GSV_PARTY_CHAT, rose_game_common has no message for party chat
+const PACKET_PARTY_CHAT: u16 = 0x786;
+
+// iROSE inventory indices start after the equipment slots
+const INVENTORY_INDEX_START: usize = 12;
+
+// The following packets are not part of iROSE, they use otherwise unused ids and are only sent
+// to servers whose profile enables extensions.
+
+// Fishing, the server replies with the same id for cast and reel
+const PACKET_FISHING_CAST: u16 = 0x7f0;
+const PACKET_FISHING_BITE: u16 = 0x7f1;
+const PACKET_FISHING_REEL: u16 = 0x7f2;
+const PACKET_FISHING_CANCEL: u16 = 0x7f3;
+
+// Limited-quantity store stock, the server replies to a stock request with the same id
+const PACKET_NPC_STORE_STOCK: u16 = 0x7f4;
+
+// Character titles, the client sends the title list id to change the active title
+const PACKET_CHARACTER_TITLE: u16 = 0x7f5;
+const PACKET_CHARACTER_TITLE_LIST: u16 = 0x7f6;
 
 pub struct GameClient {
     server_address: SocketAddr,
     client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
     server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+    extension_message_rx: Option<tokio::sync::mpsc::UnboundedReceiver<ExtensionClientMessage>>,
+    extension_message_tx: crossbeam_channel::Sender<ExtensionServerMessage>,
+    extensions_enabled: bool,
+    connection_timeout: Option<Duration>,
     packet_codec: Box<dyn PacketCodec + Send + Sync>,
 }
 
@@ -80,16 +116,69 @@ impl GameClient {
         packet_codec_seed: u32,
         client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
         server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+        extension_message_rx: tokio::sync::mpsc::UnboundedReceiver<ExtensionClientMessage>,
+        extension_message_tx: crossbeam_channel::Sender<ExtensionServerMessage>,
+        extensions_enabled: bool,
+        connection_timeout: Option<Duration>,
     ) -> Self {
         Self {
             server_address,
             client_message_rx,
             server_message_tx,
+            // Without extensions only the messages which are part of iROSE are sent
+            extension_message_rx: Some(extension_message_rx),
+            extension_message_tx,
+            extensions_enabled,
+            connection_timeout,
             packet_codec: Box::new(ClientPacketCodec::init(&IROSE_112_TABLE, packet_codec_seed)),
         }
     }
 
-    async fn handle_packet(&self, packet: &Packet) -> Result<(), anyhow::Error> {
+    pub(crate) async fn handle_packet(&self, packet: &Packet) -> Result<(), anyhow::Error> {
+        if packet.command == PACKET_ALIVE {
+            // Some servers echo the keep-alive, it needs no handling
+            return Ok(());
+        }
+
+        if packet.command == PACKET_APPRAISAL {
+            let mut reader = PacketReader::from(packet);
+            let index = reader.read_u16()? as usize;
+            let result = reader.read_u8()?;
+            // Only the equipment page has items which can be appraised
+            let Some(index) = index
+                .checked_sub(INVENTORY_INDEX_START)
+                .filter(|index| *index < INVENTORY_PAGE_SIZE)
+            else {
+                return Ok(());
+            };
+
+            self.extension_message_tx
+                .send(ExtensionServerMessage::AppraisalResult {
+                    item_slot: ItemSlot::Inventory(InventoryPageType::Equipment, index),
+                    success: result == 0,
+                })
+                .ok();
+            return Ok(());
+        }
+
+        if packet.command == PACKET_PARTY_CHAT {
+            let mut reader = PacketReader::from(packet);
+            let entity_id = ClientEntityId(reader.read_u16()? as usize);
+            let text = reader.read_null_terminated_utf8()?.to_string();
+
+            self.extension_message_tx
+                .send(ExtensionServerMessage::PartyChat { entity_id, text })
+                .ok();
+            return Ok(());
+        }
+
+        if self.extensions_enabled {
+            if let Some(message) = self.handle_extension_packet(packet)? {
+                self.extension_message_tx.send(message).ok();
+                return Ok(());
+            }
+        }
+
         match FromPrimitive::from_u16(packet.command) {
             Some(ServerPackets::ConnectReply) => {
                 let response = PacketConnectionReply::try_from(packet)?;
@@ -1586,6 +1675,145 @@ impl GameClient {
         }
         Ok(())
     }
+
+    fn keep_alive_packet(&self) -> Option<Packet> {
+        Some(PacketWriter::new(PACKET_ALIVE).into())
+    }
+
+    fn connection_timeout(&self) -> Option<Duration> {
+        self.connection_timeout
+    }
+
+    fn handle_extension_packet(
+        &self,
+        packet: &Packet,
+    ) -> Result<Option<ExtensionServerMessage>, anyhow::Error> {
+        let mut reader = PacketReader::from(packet);
+
+        Ok(match packet.command {
+            PACKET_FISHING_CAST => Some(ExtensionServerMessage::FishingCastResult {
+                accepted: reader.read_u8()? != 0,
+            }),
+            PACKET_FISHING_BITE => Some(ExtensionServerMessage::FishingBite {
+                reel_window: Duration::from_millis(reader.read_u16()? as u64),
+            }),
+            PACKET_FISHING_REEL => Some(ExtensionServerMessage::FishingResult {
+                caught: reader.read_u8()? != 0,
+            }),
+            PACKET_NPC_STORE_STOCK => {
+                let npc_entity_id = ClientEntityId(reader.read_u16()? as usize);
+                let num_items = reader.read_u8()? as usize;
+                let mut items = Vec::with_capacity(num_items);
+                for _ in 0..num_items {
+                    let tab_index = reader.read_u8()? as usize;
+                    let item_index = reader.read_u8()? as usize;
+                    let remaining = reader.read_u16()? as usize;
+                    let restock_seconds = reader.read_u32()?;
+                    items.push(NpcStoreStockItem {
+                        tab_index,
+                        item_index,
+                        remaining,
+                        restock: (restock_seconds > 0)
+                            .then(|| Duration::from_secs(restock_seconds as u64)),
+                    });
+                }
+
+                Some(ExtensionServerMessage::NpcStoreStock {
+                    npc_entity_id,
+                    items,
+                })
+            }
+            PACKET_CHARACTER_TITLE => {
+                let entity_id = ClientEntityId(reader.read_u16()? as usize);
+                let title = reader.read_null_terminated_utf8()?;
+
+                Some(ExtensionServerMessage::CharacterTitle {
+                    entity_id,
+                    title: (!title.is_empty()).then(|| title.to_string()),
+                })
+            }
+            PACKET_CHARACTER_TITLE_LIST => {
+                let can_change = reader.read_u8()? != 0;
+                let active = reader.read_u8()?;
+                let num_titles = reader.read_u8()? as usize;
+                let mut titles = Vec::with_capacity(num_titles);
+                for _ in 0..num_titles {
+                    titles.push(reader.read_null_terminated_utf8()?.to_string());
+                }
+
+                Some(ExtensionServerMessage::CharacterTitleList {
+                    active: (active != 0xff).then_some(active as usize),
+                    titles,
+                    can_change,
+                })
+            }
+            _ => None,
+        })
+    }
+
+    async fn handle_extension_message(
+        &self,
+        connection: &mut Connection<'_>,
+        message: ExtensionClientMessage,
+    ) -> Result<(), anyhow::Error> {
+        if !self.extensions_enabled
+            && !matches!(
+                message,
+                ExtensionClientMessage::AppraisalRequest { .. }
+                    | ExtensionClientMessage::PartyChat { .. }
+            )
+        {
+            return Ok(());
+        }
+
+        let packet: Packet = match message {
+            ExtensionClientMessage::AppraisalRequest { item_slot } => {
+                let ItemSlot::Inventory(InventoryPageType::Equipment, index) = item_slot else {
+                    return Ok(());
+                };
+
+                let mut writer = PacketWriter::new(PACKET_APPRAISAL);
+                writer.write_u16((INVENTORY_INDEX_START + index) as u16);
+                writer.into()
+            }
+            ExtensionClientMessage::FishingCast { x, y } => {
+                let mut writer = PacketWriter::new(PACKET_FISHING_CAST);
+                writer.write_f32(x);
+                writer.write_f32(y);
+                writer.into()
+            }
+            ExtensionClientMessage::FishingReel { accuracy } => {
+                let mut writer = PacketWriter::new(PACKET_FISHING_REEL);
+                writer.write_u8((accuracy.clamp(0.0, 1.0) * 100.0) as u8);
+                writer.into()
+            }
+            ExtensionClientMessage::FishingCancel => {
+                PacketWriter::new(PACKET_FISHING_CANCEL).into()
+            }
+            ExtensionClientMessage::NpcStoreStockRequest { npc_entity_id } => {
+                let mut writer = PacketWriter::new(PACKET_NPC_STORE_STOCK);
+                writer.write_u16(npc_entity_id.0 as u16);
+                writer.into()
+            }
+            ExtensionClientMessage::SetActiveTitle { index } => {
+                let mut writer = PacketWriter::new(PACKET_CHARACTER_TITLE_LIST);
+                writer.write_u8(index.map_or(0xff, |index| index as u8));
+                writer.into()
+            }
+            ExtensionClientMessage::ClanChat { .. } => {
+                // Clan chat is part of iROSE and is sent to the world server
+                return Ok(());
+            }
+            ExtensionClientMessage::PartyChat { text } => {
+                let mut writer = PacketWriter::new(PACKET_PARTY_CHAT);
+                writer.write_null_terminated_utf8(&text);
+                writer.into()
+            }
+        };
+
+        connection.write_packet(packet).await?;
+        Ok(())
+    }
 }
 
 implement_protocol_client! { GameClient }
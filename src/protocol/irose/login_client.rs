@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use num_traits::FromPrimitive;
-use std::net::SocketAddr;
+use std::{net::SocketAddr, time::Duration};
 use tokio::net::TcpStream;
 
 use rose_game_common::messages::{
@@ -22,12 +22,13 @@ use rose_network_irose::{
     ClientPacketCodec, IROSE_112_TABLE,
 };
 
-use crate::protocol::{ProtocolClient, ProtocolClientError};
+use crate::protocol::{ExtensionClientMessage, ProtocolClient, ProtocolClientError};
 
 pub struct LoginClient {
     server_address: SocketAddr,
     client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
     server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+    extension_message_rx: Option<tokio::sync::mpsc::UnboundedReceiver<ExtensionClientMessage>>,
     packet_codec: Box<dyn PacketCodec + Send + Sync>,
 }
 
@@ -41,6 +42,7 @@ impl LoginClient {
             server_address,
             client_message_rx,
             server_message_tx,
+            extension_message_rx: None,
             packet_codec: Box::new(ClientPacketCodec::default(&IROSE_112_TABLE)),
         }
     }
@@ -171,6 +173,22 @@ impl LoginClient {
         }
         Ok(())
     }
+
+    fn keep_alive_packet(&self) -> Option<Packet> {
+        None
+    }
+
+    fn connection_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    async fn handle_extension_message(
+        &self,
+        _connection: &mut Connection<'_>,
+        _message: ExtensionClientMessage,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
 }
 
 implement_protocol_client! { LoginClient }
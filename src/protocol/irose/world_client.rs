@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use num_traits::FromPrimitive;
-use std::net::SocketAddr;
+use std::{net::SocketAddr, time::Duration};
 use tokio::net::TcpStream;
 
 use rose_game_common::{
@@ -10,7 +10,7 @@ use rose_game_common::{
         server::{ConnectionRequestError, CreateCharacterError, ServerMessage},
     },
 };
-use rose_network_common::{Connection, Packet, PacketCodec};
+use rose_network_common::{Connection, Packet, PacketCodec, PacketReader, PacketWriter};
 use rose_network_irose::{
     world_client_packets::{
         PacketClientCharacterList, PacketClientClanCommand, PacketClientConnectRequest,
@@ -24,12 +24,21 @@ use rose_network_irose::{
     ClientPacketCodec, IROSE_112_TABLE,
 };
 
-use crate::protocol::{ProtocolClient, ProtocolClientError};
+use crate::protocol::{
+    ExtensionClientMessage, ExtensionServerMessage, ProtocolClient, ProtocolClientError,
+};
+
+// CLI_CLAN_CHAT / WSV_CLAN_CHAT, the server relays the text to every online clan member along
+// with the sender's name. rose_game_common has no message for clan chat, so it is sent through
+// the extension channel.
+const PACKET_CLAN_CHAT: u16 = 0x787;
 
 pub struct WorldClient {
     server_address: SocketAddr,
     client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
     server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+    extension_message_rx: Option<tokio::sync::mpsc::UnboundedReceiver<ExtensionClientMessage>>,
+    extension_message_tx: crossbeam_channel::Sender<ExtensionServerMessage>,
     packet_codec: Box<dyn PacketCodec + Send + Sync>,
 }
 
@@ -39,16 +48,30 @@ impl WorldClient {
         packet_codec_seed: u32,
         client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
         server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+        extension_message_rx: tokio::sync::mpsc::UnboundedReceiver<ExtensionClientMessage>,
+        extension_message_tx: crossbeam_channel::Sender<ExtensionServerMessage>,
     ) -> Self {
         Self {
             server_address,
             client_message_rx,
             server_message_tx,
+            extension_message_rx: Some(extension_message_rx),
+            extension_message_tx,
             packet_codec: Box::new(ClientPacketCodec::init(&IROSE_112_TABLE, packet_codec_seed)),
         }
     }
 
     async fn handle_packet(&self, packet: &Packet) -> Result<(), anyhow::Error> {
+        if packet.command == PACKET_CLAN_CHAT {
+            let mut reader = PacketReader::from(packet);
+            let name = reader.read_null_terminated_utf8()?.to_string();
+            let text = reader.read_null_terminated_utf8()?.to_string();
+            self.extension_message_tx
+                .send(ExtensionServerMessage::ClanChat { name, text })
+                .ok();
+            return Ok(());
+        }
+
         match FromPrimitive::from_u16(packet.command) {
             Some(ServerPackets::ConnectReply) => {
                 let response = PacketConnectionReply::try_from(packet)?;
@@ -209,6 +232,27 @@ impl WorldClient {
         }
         Ok(())
     }
+
+    fn keep_alive_packet(&self) -> Option<Packet> {
+        None
+    }
+
+    fn connection_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    async fn handle_extension_message(
+        &self,
+        connection: &mut Connection<'_>,
+        message: ExtensionClientMessage,
+    ) -> Result<(), anyhow::Error> {
+        if let ExtensionClientMessage::ClanChat { text } = message {
+            let mut writer = PacketWriter::new(PACKET_CLAN_CHAT);
+            writer.write_null_terminated_utf8(&text);
+            connection.write_packet(writer.into()).await?;
+        }
+        Ok(())
+    }
 }
 
 implement_protocol_client! { WorldClient }
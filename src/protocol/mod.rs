@@ -1,10 +1,196 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
 use async_trait::async_trait;
+use rose_game_common::{components::ItemSlot, messages::ClientEntityId};
 use thiserror::Error;
 
+/// How often keep-alive packets are sent, for protocols which support them
+pub const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Number of recently received packets to keep for bug reports
+const PACKET_HISTORY_SIZE: usize = 200;
+
+struct PacketHistoryEntry {
+    time: SystemTime,
+    command: u16,
+    data: Vec<u8>,
+}
+
+/// Ring buffer of the most recent packets, entries are only formatted when a bug report is built.
+struct PacketHistory {
+    entries: Vec<PacketHistoryEntry>,
+    next: usize,
+}
+
+static PACKET_HISTORY: Mutex<PacketHistory> = Mutex::new(PacketHistory {
+    entries: Vec::new(),
+    next: 0,
+});
+
+pub fn record_packet_history(command: u16, data: &[u8]) {
+    // Never block the network thread, if a bug report is being built we skip the packet
+    let Ok(mut history) = PACKET_HISTORY.try_lock() else {
+        return;
+    };
+    let time = SystemTime::now();
+
+    if history.entries.len() < PACKET_HISTORY_SIZE {
+        history.entries.push(PacketHistoryEntry {
+            time,
+            command,
+            data: data.to_vec(),
+        });
+    } else {
+        let index = history.next;
+        let entry = &mut history.entries[index];
+        entry.time = time;
+        entry.command = command;
+        entry.data.clear();
+        entry.data.extend_from_slice(data);
+    }
+
+    history.next = (history.next + 1) % PACKET_HISTORY_SIZE;
+}
+
+pub fn packet_history() -> Vec<String> {
+    let Ok(history) = PACKET_HISTORY.lock() else {
+        return Vec::new();
+    };
+    let (newer, older) = history
+        .entries
+        .split_at(history.next.min(history.entries.len()));
+
+    older
+        .iter()
+        .chain(newer.iter())
+        .map(|entry| {
+            format!(
+                "{} [{:03X}] {:02x?}",
+                chrono::DateTime::<chrono::Local>::from(entry.time).format("%H:%M:%S%.3f"),
+                entry.command,
+                entry.data
+            )
+        })
+        .collect()
+}
+
+/// Messages for optional features which are not part of the rose_game_common protocol. Servers
+/// which do not support a feature never reply, so callers must time out gracefully.
+#[derive(Clone, Debug)]
+pub enum ExtensionClientMessage {
+    /// Reveal the hidden stats of an equipment item, this is part of iROSE so it is always sent
+    AppraisalRequest {
+        item_slot: ItemSlot,
+    },
+    /// Cast a fishing line to the given world position in cm
+    FishingCast {
+        x: f32,
+        y: f32,
+    },
+    /// Reel in after a bite, accuracy is how close to the centre of the timing bar the player
+    /// stopped from 0.0 to 1.0
+    FishingReel {
+        accuracy: f32,
+    },
+    FishingCancel,
+    /// Request the remaining stock of the limited-quantity items in an NPC store
+    NpcStoreStockRequest {
+        npc_entity_id: ClientEntityId,
+    },
+    /// Choose which unlocked title is shown to other players, None to hide the title
+    SetActiveTitle {
+        index: Option<usize>,
+    },
+    /// Send a chat message to every online member of the player's clan
+    ClanChat {
+        text: String,
+    },
+    /// Send a chat message to the members of the player's party, this is part of iROSE so it is
+    /// always sent
+    PartyChat {
+        text: String,
+    },
+}
+
+/// Remaining stock of a limited-quantity NPC store item
+#[derive(Clone, Debug)]
+pub struct NpcStoreStockItem {
+    pub tab_index: usize,
+    pub item_index: usize,
+    pub remaining: usize,
+    /// Time until the item is restocked, None if it does not restock
+    pub restock: Option<Duration>,
+}
+
+#[derive(Clone, Debug)]
+pub enum ExtensionServerMessage {
+    /// Reply to an appraisal request, on success the item in the slot is now appraised
+    AppraisalResult {
+        item_slot: ItemSlot,
+        success: bool,
+    },
+    FishingCastResult {
+        accepted: bool,
+    },
+    FishingBite {
+        reel_window: Duration,
+    },
+    FishingResult {
+        caught: bool,
+    },
+    /// The full stock of an NPC store, items which are not listed have unlimited stock. Sent in
+    /// reply to a stock request and whenever the stock changes while the store is open.
+    NpcStoreStock {
+        npc_entity_id: ClientEntityId,
+        items: Vec<NpcStoreStockItem>,
+    },
+    /// The active title of a character, sent when they come into view and whenever it changes
+    CharacterTitle {
+        entity_id: ClientEntityId,
+        title: Option<String>,
+    },
+    /// The titles unlocked by the player and which one is active
+    CharacterTitleList {
+        titles: Vec<String>,
+        active: Option<usize>,
+        can_change: bool,
+    },
+    /// A chat message from a member of the player's clan
+    ClanChat {
+        name: String,
+        text: String,
+    },
+    /// A chat message from a member of the player's party
+    PartyChat {
+        entity_id: ClientEntityId,
+        text: String,
+    },
+}
+
+/// Waits for the next extension message, protocol clients without an extension channel wait
+/// forever so the branch never fires
+pub async fn recv_extension_message(
+    extension_message_rx: &mut Option<tokio::sync::mpsc::UnboundedReceiver<ExtensionClientMessage>>,
+) -> ExtensionClientMessage {
+    if let Some(message_rx) = extension_message_rx.as_mut() {
+        if let Some(message) = message_rx.recv().await {
+            return message;
+        }
+    }
+
+    *extension_message_rx = None;
+    std::future::pending().await
+}
+
 #[derive(Debug, Error)]
 pub enum ProtocolClientError {
     #[error("client initiated disconnect")]
     ClientInitiatedDisconnect,
+    #[error("connection timed out")]
+    ConnectionTimeout,
 }
 
 #[async_trait]
@@ -20,12 +206,29 @@ macro_rules! implement_protocol_client {
             async fn run_connection(&mut self) -> Result<(), anyhow::Error> {
                 let socket = TcpStream::connect(&self.server_address).await?;
                 let mut connection = Connection::new(socket, self.packet_codec.as_ref());
+                let keep_alive_enabled = self.keep_alive_packet().is_some();
+                // The first keep-alive is sent one interval after connecting, not immediately
+                let mut keep_alive_interval = tokio::time::interval_at(
+                    tokio::time::Instant::now() + $crate::protocol::KEEP_ALIVE_INTERVAL,
+                    $crate::protocol::KEEP_ALIVE_INTERVAL,
+                );
+                // Without keep-alive packets an idle server may never send anything
+                let connection_timeout = self.connection_timeout().filter(|_| keep_alive_enabled);
+                let connection_timeout_sleep = tokio::time::sleep(
+                    connection_timeout.unwrap_or($crate::protocol::KEEP_ALIVE_INTERVAL),
+                );
+                tokio::pin!(connection_timeout_sleep);
 
                 loop {
                     tokio::select! {
                         packet = connection.read_packet() => {
                             match packet {
                                 Ok(packet) => {
+                                    if let Some(connection_timeout) = connection_timeout {
+                                        connection_timeout_sleep.as_mut().reset(tokio::time::Instant::now() + connection_timeout);
+                                    }
+                                    $crate::protocol::record_packet_history(packet.command, &packet.data[..]);
+
                                     match self.handle_packet(&packet).await {
                                         Ok(_) => {},
                                         Err(error) => {
@@ -45,7 +248,18 @@ macro_rules! implement_protocol_client {
                             } else {
                                 return Err(ProtocolClientError::ClientInitiatedDisconnect.into());
                             }
-                        }
+                        },
+                        extension_message = $crate::protocol::recv_extension_message(&mut self.extension_message_rx) => {
+                            self.handle_extension_message(&mut connection, extension_message).await?;
+                        },
+                        _ = keep_alive_interval.tick(), if keep_alive_enabled => {
+                            if let Some(packet) = self.keep_alive_packet() {
+                                connection.write_packet(packet).await?;
+                            }
+                        },
+                        _ = &mut connection_timeout_sleep, if connection_timeout.is_some() => {
+                            return Err(ProtocolClientError::ConnectionTimeout.into());
+                        },
                     };
                 }
 
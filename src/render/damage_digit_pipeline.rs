@@ -1,6 +1,6 @@
 use bevy::{
     app::prelude::*,
-    asset::{load_internal_asset, Assets, Handle, HandleUntyped},
+    asset::{load_internal_asset, Handle, HandleUntyped},
     core_pipeline::core_3d::Transparent3d,
     ecs::{
         prelude::*,
@@ -19,17 +19,13 @@ use bevy::{
         render_resource::*,
         renderer::{RenderDevice, RenderQueue},
         texture::{BevyDefault, Image},
-        view::{
-            ComputedVisibility, ExtractedView, ViewTarget, ViewUniform, ViewUniformOffset,
-            ViewUniforms,
-        },
+        view::{ExtractedView, ViewTarget, ViewUniform, ViewUniformOffset, ViewUniforms},
         Extract, ExtractSchedule, Render, RenderApp, RenderSet,
     },
 };
-use bytemuck::Pod;
-use std::{collections::HashMap, num::NonZeroU64, ops::Range};
+use std::{num::NonZeroU64, ops::Range};
 
-use crate::render::{DamageDigitMaterial, DamageDigitRenderData};
+use crate::render::{DamageDigitInstance, DamageDigitRenderData, DAMAGE_DIGIT_NUM_TEXTURES};
 
 pub const DAMAGE_DIGIT_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 39699708885);
@@ -45,14 +41,17 @@ impl Plugin for DamageDigitRenderPlugin {
             Shader::from_wgsl
         );
 
-        let render_app = app.sub_app_mut(RenderApp);
+        let render_app = match app.get_sub_app_mut(RenderApp) {
+            Ok(render_app) => render_app,
+            Err(_) => return,
+        };
+
         render_app
             .add_systems(ExtractSchedule, extract_damage_digits)
             .add_systems(Render, prepare_damage_digits.in_set(RenderSet::Prepare))
             .add_systems(Render, queue_damage_digits.in_set(RenderSet::Queue))
             .init_resource::<DamageDigitMeta>()
             .init_resource::<ExtractedDamageDigits>()
-            .init_resource::<MaterialBindGroups>()
             .init_resource::<SpecializedRenderPipelines<DamageDigitPipeline>>()
             .add_render_command::<Transparent3d, DrawDamageDigit>();
     }
@@ -70,7 +69,7 @@ impl Plugin for DamageDigitRenderPlugin {
 #[derive(Resource)]
 struct DamageDigitPipeline {
     view_layout: BindGroupLayout,
-    particle_layout: BindGroupLayout,
+    instance_layout: BindGroupLayout,
     material_layout: BindGroupLayout,
 }
 
@@ -92,72 +91,52 @@ impl FromWorld for DamageDigitPipeline {
             label: None,
         });
 
-        let particle_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+        let instance_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
             label: None,
             entries: &[
-                // Positions
+                // Instances
                 BindGroupLayoutEntry {
                     binding: 0,
                     visibility: ShaderStages::VERTEX,
                     ty: BindingType::Buffer {
                         ty: BufferBindingType::Storage { read_only: true },
                         has_dynamic_offset: false,
-                        min_binding_size: Some(Vec4::min_size()),
-                    },
-                    count: None,
-                },
-                // Sizes
-                BindGroupLayoutEntry {
-                    binding: 1,
-                    visibility: ShaderStages::VERTEX,
-                    ty: BindingType::Buffer {
-                        ty: BufferBindingType::Storage { read_only: true },
-                        has_dynamic_offset: false,
-                        min_binding_size: Some(Vec2::min_size()),
-                    },
-                    count: None,
-                },
-                // UVs
-                BindGroupLayoutEntry {
-                    binding: 2,
-                    visibility: ShaderStages::VERTEX,
-                    ty: BindingType::Buffer {
-                        ty: BufferBindingType::Storage { read_only: true },
-                        has_dynamic_offset: false,
-                        min_binding_size: Some(Vec4::min_size()),
+                        min_binding_size: NonZeroU64::new(
+                            std::mem::size_of::<DamageDigitInstance>() as u64,
+                        ),
                     },
                     count: None,
                 },
             ],
         });
 
+        // One texture for each of DAMAGE_DIGIT_TEXTURE_*, followed by a shared sampler
+        let mut material_entries: Vec<BindGroupLayoutEntry> = (0..DAMAGE_DIGIT_NUM_TEXTURES)
+            .map(|binding| BindGroupLayoutEntry {
+                binding: binding as u32,
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Texture {
+                    multisampled: false,
+                    sample_type: TextureSampleType::Float { filterable: true },
+                    view_dimension: TextureViewDimension::D2,
+                },
+                count: None,
+            })
+            .collect();
+        material_entries.push(BindGroupLayoutEntry {
+            binding: DAMAGE_DIGIT_NUM_TEXTURES as u32,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Sampler(SamplerBindingType::Filtering),
+            count: None,
+        });
         let material_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
             label: None,
-            entries: &[
-                // Base Color Texture
-                BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Texture {
-                        multisampled: false,
-                        sample_type: TextureSampleType::Float { filterable: true },
-                        view_dimension: TextureViewDimension::D2,
-                    },
-                    count: None,
-                },
-                // Base Color Texture Sampler
-                BindGroupLayoutEntry {
-                    binding: 1,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
-                    count: None,
-                },
-            ],
+            entries: &material_entries,
         });
 
         Self {
             view_layout,
-            particle_layout,
+            instance_layout,
             material_layout,
         }
     }
@@ -221,7 +200,7 @@ impl SpecializedRenderPipeline for DamageDigitPipeline {
             }),
             layout: vec![
                 self.view_layout.clone(),
-                self.particle_layout.clone(),
+                self.instance_layout.clone(),
                 self.material_layout.clone(),
             ],
             primitive: PrimitiveState {
@@ -260,78 +239,48 @@ impl SpecializedRenderPipeline for DamageDigitPipeline {
     }
 }
 
-struct ExtractedDamageDigitRenderData {
-    material: Handle<DamageDigitMaterial>,
-    positions: Vec<Vec4>,
-    sizes: Vec<Vec2>,
-    uvs: Vec<Vec4>,
-}
-
-#[derive(Default, Component, Resource)]
+#[derive(Default, Resource)]
 struct ExtractedDamageDigits {
-    particles: Vec<ExtractedDamageDigitRenderData>,
+    textures: Option<[Handle<Image>; DAMAGE_DIGIT_NUM_TEXTURES]>,
+    instances: Vec<DamageDigitInstance>,
 }
 
 fn extract_damage_digits(
     mut extracted_damage_digits: ResMut<ExtractedDamageDigits>,
-    materials: Extract<Res<Assets<DamageDigitMaterial>>>,
-    images: Extract<Res<Assets<Image>>>,
-    query: Extract<
-        Query<(
-            &ComputedVisibility,
-            &DamageDigitRenderData,
-            &Handle<DamageDigitMaterial>,
-        )>,
-    >,
+    damage_digit_render_data: Extract<Option<Res<DamageDigitRenderData>>>,
 ) {
-    extracted_damage_digits.particles.clear();
-    for (_visible, particles, material_handle) in query.iter() {
-        /*
-        // TODO: Fix aabb calculation so culling works correctly.
-        if !visible.is_visible {
-            continue;
-        }
-        */
-        if let Some(material) = materials.get(material_handle) {
-            if !images.contains(&material.texture) {
-                continue;
-            }
-
-            extracted_damage_digits
-                .particles
-                .push(ExtractedDamageDigitRenderData {
-                    material: material_handle.clone_weak(),
-                    positions: particles.positions.clone(),
-                    sizes: particles.sizes.clone(),
-                    uvs: particles.uvs.clone(),
-                });
-        }
+    extracted_damage_digits.instances.clear();
+
+    if let Some(damage_digit_render_data) = damage_digit_render_data.as_ref() {
+        extracted_damage_digits.textures = Some(
+            damage_digit_render_data
+                .textures
+                .clone()
+                .map(|handle| handle.clone_weak()),
+        );
+        extracted_damage_digits
+            .instances
+            .extend_from_slice(&damage_digit_render_data.instances);
     }
 }
 
 #[derive(Resource)]
 struct DamageDigitMeta {
-    ranges: Vec<Range<u64>>,
     total_count: u64,
     view_bind_group: Option<BindGroup>,
-    particle_bind_group: Option<BindGroup>,
-
-    positions: BufferVec<Vec4>,
-    sizes: BufferVec<Vec2>,
-    uvs: BufferVec<Vec4>,
+    instance_bind_group: Option<BindGroup>,
+    material_bind_group: Option<BindGroup>,
+    instances: BufferVec<DamageDigitInstance>,
 }
 
 impl Default for DamageDigitMeta {
     fn default() -> Self {
         DamageDigitMeta {
-            ranges: Vec::default(),
             total_count: 0,
             view_bind_group: None,
-            particle_bind_group: None,
-
-            positions: BufferVec::new(BufferUsages::STORAGE),
-            sizes: BufferVec::new(BufferUsages::STORAGE),
-            uvs: BufferVec::new(BufferUsages::STORAGE),
+            instance_bind_group: None,
+            material_bind_group: None,
+            instances: BufferVec::new(BufferUsages::STORAGE),
         }
     }
 }
@@ -340,101 +289,36 @@ fn prepare_damage_digits(
     render_device: Res<RenderDevice>,
     render_queue: Res<RenderQueue>,
     mut commands: Commands,
-    mut particle_meta: ResMut<DamageDigitMeta>,
-    mut extracted_damage_digits: ResMut<ExtractedDamageDigits>,
+    mut damage_digit_meta: ResMut<DamageDigitMeta>,
+    extracted_damage_digits: Res<ExtractedDamageDigits>,
 ) {
-    particle_meta.positions.clear();
-    particle_meta.sizes.clear();
-    particle_meta.uvs.clear();
-
-    let mut total_count = 0;
-    for particle in extracted_damage_digits.particles.iter() {
-        total_count += particle.positions.len();
-    }
+    damage_digit_meta.instances.clear();
 
-    particle_meta.total_count = total_count as u64;
-    particle_meta.ranges.clear();
+    let total_count = extracted_damage_digits.instances.len();
+    damage_digit_meta.total_count = total_count as u64;
     if total_count == 0 {
         return;
     }
 
-    particle_meta.positions.reserve(total_count, &render_device);
-    particle_meta.sizes.reserve(total_count, &render_device);
-    particle_meta.uvs.reserve(total_count, &render_device);
-
-    extracted_damage_digits
-        .particles
-        .sort_by(|a, b| a.material.cmp(&b.material));
-
-    let mut start: u32 = 0;
-    let mut end: u32 = 0;
-    let mut current_batch: Option<Handle<DamageDigitMaterial>> = None;
-    for particle in extracted_damage_digits.particles.iter() {
-        if start != end {
-            if let Some(current_batch_material) = &current_batch {
-                if current_batch_material != &particle.material {
-                    let current_batch_material = current_batch.take().unwrap();
-                    commands.spawn(DamageDigitBatch {
-                        range: start..end,
-                        handle: current_batch_material,
-                    });
-                    current_batch = Some(particle.material.clone_weak());
-                    start = end;
-                }
-            }
-        } else {
-            current_batch = Some(particle.material.clone_weak());
-        }
-
-        batch_copy(&particle.positions, &mut particle_meta.positions);
-        batch_copy(&particle.sizes, &mut particle_meta.sizes);
-        batch_copy(&particle.uvs, &mut particle_meta.uvs);
-        end += particle.positions.len() as u32;
-    }
-
-    if start != end {
-        if let Some(current_batch_material) = current_batch {
-            commands.spawn(DamageDigitBatch {
-                range: start..end,
-                handle: current_batch_material,
-            });
-        }
+    damage_digit_meta
+        .instances
+        .reserve(total_count, &render_device);
+    for instance in extracted_damage_digits.instances.iter() {
+        damage_digit_meta.instances.push(*instance);
     }
-
-    particle_meta
-        .positions
-        .write_buffer(&render_device, &render_queue);
-    particle_meta
-        .sizes
-        .write_buffer(&render_device, &render_queue);
-    particle_meta
-        .uvs
+    damage_digit_meta
+        .instances
         .write_buffer(&render_device, &render_queue);
-}
 
-fn batch_copy<T: Pod>(src: &[T], dst: &mut BufferVec<T>) {
-    for item in src.iter() {
-        dst.push(*item);
-    }
-}
-
-fn bind_buffer<T: Pod>(buffer: &BufferVec<T>, count: u64) -> BindingResource {
-    BindingResource::Buffer(BufferBinding {
-        buffer: buffer.buffer().expect("missing buffer"),
-        offset: 0,
-        size: Some(NonZeroU64::new(std::mem::size_of::<T>() as u64 * count).unwrap()),
-    })
+    // Every damage digit is drawn in a single batch
+    commands.spawn(DamageDigitBatch {
+        range: 0..total_count as u32,
+    });
 }
 
 #[derive(Component)]
 struct DamageDigitBatch {
     range: Range<u32>,
-    handle: Handle<DamageDigitMaterial>,
-}
-
-#[derive(Default, Resource)]
-struct MaterialBindGroups {
-    values: HashMap<Handle<DamageDigitMaterial>, BindGroup>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -442,14 +326,13 @@ fn queue_damage_digits(
     transparent_draw_functions: Res<DrawFunctions<Transparent3d>>,
     mut views: Query<(&ExtractedView, &mut RenderPhase<Transparent3d>)>,
     render_device: Res<RenderDevice>,
-    mut material_bind_groups: ResMut<MaterialBindGroups>,
     mut damage_digit_meta: ResMut<DamageDigitMeta>,
+    extracted_damage_digits: Res<ExtractedDamageDigits>,
     view_uniforms: Res<ViewUniforms>,
     damage_digit_pipeline: Res<DamageDigitPipeline>,
     mut pipelines: ResMut<SpecializedRenderPipelines<DamageDigitPipeline>>,
     pipeline_cache: Res<PipelineCache>,
-    damage_digit_batches: Query<(Entity, &DamageDigitBatch)>,
-    render_materials: Res<RenderAssets<DamageDigitMaterial>>,
+    damage_digit_batches: Query<Entity, With<DamageDigitBatch>>,
     gpu_images: Res<RenderAssets<Image>>,
     msaa: Res<Msaa>,
 ) {
@@ -457,6 +340,18 @@ fn queue_damage_digits(
         return;
     }
 
+    // All of the digit textures must be loaded before anything can be drawn
+    let Some(textures) = extracted_damage_digits.textures.as_ref() else {
+        return;
+    };
+    let Some(gpu_textures) = textures
+        .iter()
+        .map(|handle| gpu_images.get(handle))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+
     if let Some(view_bindings) = view_uniforms.uniforms.binding() {
         damage_digit_meta.view_bind_group.get_or_insert_with(|| {
             render_device.create_bind_group(&BindGroupDescriptor {
@@ -470,30 +365,47 @@ fn queue_damage_digits(
         });
     }
 
-    damage_digit_meta.particle_bind_group =
-        Some(render_device.create_bind_group(&BindGroupDescriptor {
-            entries: &[
-                BindGroupEntry {
-                    binding: 0,
-                    resource: bind_buffer(
-                        &damage_digit_meta.positions,
-                        damage_digit_meta.total_count,
+    damage_digit_meta.instance_bind_group = Some(
+        render_device.create_bind_group(&BindGroupDescriptor {
+            entries: &[BindGroupEntry {
+                binding: 0,
+                resource: BindingResource::Buffer(BufferBinding {
+                    buffer: damage_digit_meta
+                        .instances
+                        .buffer()
+                        .expect("missing buffer"),
+                    offset: 0,
+                    size: NonZeroU64::new(
+                        std::mem::size_of::<DamageDigitInstance>() as u64
+                            * damage_digit_meta.total_count,
                     ),
-                },
-                BindGroupEntry {
-                    binding: 1,
-                    resource: bind_buffer(&damage_digit_meta.sizes, damage_digit_meta.total_count),
-                },
-                BindGroupEntry {
-                    binding: 2,
-                    resource: bind_buffer(&damage_digit_meta.uvs, damage_digit_meta.total_count),
-                },
-            ],
+                }),
+            }],
             label: Some("damage_digit_bind_group"),
-            layout: &damage_digit_pipeline.particle_layout,
+            layout: &damage_digit_pipeline.instance_layout,
+        }),
+    );
+
+    let mut material_entries: Vec<BindGroupEntry> = gpu_textures
+        .iter()
+        .enumerate()
+        .map(|(binding, gpu_image)| BindGroupEntry {
+            binding: binding as u32,
+            resource: BindingResource::TextureView(&gpu_image.texture_view),
+        })
+        .collect();
+    material_entries.push(BindGroupEntry {
+        binding: DAMAGE_DIGIT_NUM_TEXTURES as u32,
+        resource: BindingResource::Sampler(&gpu_textures[0].sampler),
+    });
+    damage_digit_meta.material_bind_group =
+        Some(render_device.create_bind_group(&BindGroupDescriptor {
+            entries: &material_entries,
+            label: Some("damage_digit_material_bind_group"),
+            layout: &damage_digit_pipeline.material_layout,
         }));
 
-    let draw_particle_function = transparent_draw_functions
+    let draw_damage_digit_function = transparent_draw_functions
         .read()
         .get_id::<DrawDamageDigit>()
         .unwrap();
@@ -501,36 +413,12 @@ fn queue_damage_digits(
         let view_key = DamageDigitPipelineKey::from_msaa_samples(msaa.samples())
             | DamageDigitPipelineKey::from_hdr(view.hdr);
 
-        for (entity, batch) in damage_digit_batches.iter() {
-            let gpu_material = render_materials
-                .get(&batch.handle)
-                .expect("Failed to get DamageDigitMaterial PreparedAsset");
-
-            if let Some(gpu_image) = gpu_images.get(&gpu_material.texture) {
-                material_bind_groups.values.insert(
-                    batch.handle.clone_weak(),
-                    render_device.create_bind_group(&BindGroupDescriptor {
-                        entries: &[
-                            BindGroupEntry {
-                                binding: 0,
-                                resource: BindingResource::TextureView(&gpu_image.texture_view),
-                            },
-                            BindGroupEntry {
-                                binding: 1,
-                                resource: BindingResource::Sampler(&gpu_image.sampler),
-                            },
-                        ],
-                        label: Some("damage_digit_material_bind_group"),
-                        layout: &damage_digit_pipeline.material_layout,
-                    }),
-                );
-            }
-
+        for entity in damage_digit_batches.iter() {
             transparent_phase.add(Transparent3d {
                 distance: 10.0,
                 pipeline: pipelines.specialize(&pipeline_cache, &damage_digit_pipeline, view_key),
                 entity,
-                draw_function: draw_particle_function,
+                draw_function: draw_damage_digit_function,
             });
         }
     }
@@ -587,7 +475,7 @@ impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetDamageDigitBindGroup<
             I,
             damage_digit_meta
                 .into_inner()
-                .particle_bind_group
+                .instance_bind_group
                 .as_ref()
                 .unwrap(),
             &[],
@@ -598,23 +486,23 @@ impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetDamageDigitBindGroup<
 
 struct SetDamageDigitMaterialBindGroup<const I: usize>;
 impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetDamageDigitMaterialBindGroup<I> {
-    type Param = SRes<MaterialBindGroups>;
+    type Param = SRes<DamageDigitMeta>;
     type ViewWorldQuery = ();
-    type ItemWorldQuery = Read<DamageDigitBatch>;
+    type ItemWorldQuery = ();
 
     fn render<'w>(
         _: &P,
         _: ROQueryItem<'w, Self::ViewWorldQuery>,
-        batch: ROQueryItem<'w, Self::ItemWorldQuery>,
-        material_bind_groups: SystemParamItem<'w, '_, Self::Param>,
+        _: ROQueryItem<'w, Self::ItemWorldQuery>,
+        damage_digit_meta: SystemParamItem<'w, '_, Self::Param>,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
         pass.set_bind_group(
             I,
-            material_bind_groups
+            damage_digit_meta
                 .into_inner()
-                .values
-                .get(&batch.handle)
+                .material_bind_group
+                .as_ref()
                 .unwrap(),
             &[],
         );
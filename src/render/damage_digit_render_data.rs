@@ -1,31 +1,63 @@
-use bevy::{math::*, prelude::*};
+use bevy::prelude::{Handle, Image, Resource, Vec2, Vec3, Vec4};
+use bytemuck::{Pod, Zeroable};
 
-#[derive(Component)]
+pub const DAMAGE_DIGIT_TEXTURE_DAMAGE: u32 = 0;
+pub const DAMAGE_DIGIT_TEXTURE_DAMAGE_PLAYER: u32 = 1;
+pub const DAMAGE_DIGIT_TEXTURE_MISS: u32 = 2;
+pub const DAMAGE_DIGIT_NUM_TEXTURES: usize = 3;
+
+/// A single digit billboard, must match the layout of `DamageDigitInstance` in damage_digit.wgsl
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct DamageDigitInstance {
+    /// xyz is the world position, w is the x offset of the digit within the number
+    pub position: Vec4,
+    pub uv: Vec4,
+    pub color: Vec4,
+    pub size: Vec2,
+    pub texture_index: u32,
+    /// Normalised age of the number from 0.0 to 1.0, used to fade out at the end
+    pub age: f32,
+}
+
+/// All damage digits to be drawn this frame, drawn as a single instanced batch
+#[derive(Resource)]
 pub struct DamageDigitRenderData {
-    pub positions: Vec<Vec4>,
-    pub sizes: Vec<Vec2>,
-    pub uvs: Vec<Vec4>,
+    pub textures: [Handle<Image>; DAMAGE_DIGIT_NUM_TEXTURES],
+    pub instances: Vec<DamageDigitInstance>,
 }
 
 impl DamageDigitRenderData {
-    pub fn new(capacity: usize) -> Self {
+    pub fn new(textures: [Handle<Image>; DAMAGE_DIGIT_NUM_TEXTURES]) -> Self {
         Self {
-            positions: Vec::with_capacity(capacity),
-            sizes: Vec::with_capacity(capacity),
-            uvs: Vec::with_capacity(capacity),
+            textures,
+            instances: Vec::new(),
         }
     }
 
     #[inline(always)]
-    pub fn add(&mut self, position: Vec3, digit_x_offset: f32, size: Vec2, uv: Vec4) {
-        self.positions.push(Vec4::from((position, digit_x_offset)));
-        self.sizes.push(size);
-        self.uvs.push(uv);
+    #[allow(clippy::too_many_arguments)]
+    pub fn add(
+        &mut self,
+        position: Vec3,
+        digit_x_offset: f32,
+        size: Vec2,
+        uv: Vec4,
+        color: Vec4,
+        texture_index: u32,
+        age: f32,
+    ) {
+        self.instances.push(DamageDigitInstance {
+            position: Vec4::from((position, digit_x_offset)),
+            uv,
+            color,
+            size,
+            texture_index,
+            age,
+        });
     }
 
     pub fn clear(&mut self) {
-        self.positions.clear();
-        self.sizes.clear();
-        self.uvs.clear();
+        self.instances.clear();
     }
 }
@@ -0,0 +1,131 @@
+use bevy::{
+    asset::load_internal_asset,
+    math::Vec4,
+    pbr::{
+        DrawMesh, DrawPrepass, MeshPipelineKey, SetMaterialBindGroup, SetMeshBindGroup,
+        SetMeshViewBindGroup,
+    },
+    prelude::{App, FromWorld, HandleUntyped, Material, MaterialPlugin, Mesh, Plugin, World},
+    reflect::{TypePath, TypeUuid},
+    render::{
+        mesh::MeshVertexBufferLayout,
+        prelude::Shader,
+        render_phase::SetItemPipeline,
+        render_resource::{
+            AsBindGroup, BindGroupLayout, RenderPipelineDescriptor, SpecializedMeshPipelineError,
+        },
+    },
+};
+
+use crate::render::zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta};
+
+pub const FAR_TERRAIN_MATERIAL_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 0x5e1f0c8a4d2b9e37);
+
+#[derive(Default)]
+pub struct FarTerrainMaterialPlugin {
+    pub prepass_enabled: bool,
+}
+
+impl Plugin for FarTerrainMaterialPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            FAR_TERRAIN_MATERIAL_SHADER_HANDLE,
+            "shaders/far_terrain_material.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.add_plugins(MaterialPlugin::<
+            FarTerrainMaterial,
+            DrawFarTerrainMaterial,
+            DrawPrepass<FarTerrainMaterial>,
+        > {
+            prepass_enabled: self.prepass_enabled,
+            ..Default::default()
+        });
+    }
+}
+
+#[derive(Clone)]
+pub struct FarTerrainMaterialPipelineData {
+    pub zone_lighting_layout: BindGroupLayout,
+}
+
+impl FromWorld for FarTerrainMaterialPipelineData {
+    fn from_world(world: &mut World) -> Self {
+        FarTerrainMaterialPipelineData {
+            zone_lighting_layout: world
+                .resource::<ZoneLightingUniformMeta>()
+                .bind_group_layout
+                .clone(),
+        }
+    }
+}
+
+/// Untextured material for the coarse terrain drawn beyond the terrain view distance, the
+/// colour is blended from flat to steep by the slope and is mostly hidden by zone fog.
+#[derive(Debug, Clone, TypeUuid, TypePath, AsBindGroup)]
+#[uuid = "3c1d6f0e-8b27-4a5c-9f41-7d2e6b8a0c53"]
+pub struct FarTerrainMaterial {
+    #[uniform(0)]
+    pub flat_color: Vec4,
+
+    #[uniform(1)]
+    pub steep_color: Vec4,
+}
+
+impl Default for FarTerrainMaterial {
+    fn default() -> Self {
+        Self {
+            flat_color: Vec4::new(0.36, 0.42, 0.25, 1.0),
+            steep_color: Vec4::new(0.42, 0.38, 0.32, 1.0),
+        }
+    }
+}
+
+impl Material for FarTerrainMaterial {
+    type PipelineData = FarTerrainMaterialPipelineData;
+
+    fn vertex_shader() -> bevy::render::render_resource::ShaderRef {
+        FAR_TERRAIN_MATERIAL_SHADER_HANDLE.typed().into()
+    }
+
+    fn fragment_shader() -> bevy::render::render_resource::ShaderRef {
+        FAR_TERRAIN_MATERIAL_SHADER_HANDLE.typed().into()
+    }
+
+    fn specialize(
+        pipeline: &bevy::pbr::MaterialPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        layout: &MeshVertexBufferLayout,
+        key: bevy::pbr::MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        if key.mesh_key.contains(MeshPipelineKey::DEPTH_PREPASS)
+            || key.mesh_key.contains(MeshPipelineKey::NORMAL_PREPASS)
+        {
+            return Ok(());
+        }
+
+        descriptor
+            .layout
+            .insert(3, pipeline.data.zone_lighting_layout.clone());
+
+        let vertex_layout = layout.get_layout(&[
+            Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
+            Mesh::ATTRIBUTE_NORMAL.at_shader_location(1),
+        ])?;
+        descriptor.vertex.buffers = vec![vertex_layout];
+
+        Ok(())
+    }
+}
+
+type DrawFarTerrainMaterial = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetMaterialBindGroup<FarTerrainMaterial, 1>,
+    SetMeshBindGroup<2>,
+    SetZoneLightingBindGroup<3>,
+    DrawMesh,
+);
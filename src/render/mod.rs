@@ -3,10 +3,10 @@ use bevy::{
     render::{mesh::MeshVertexAttribute, render_resource::VertexFormat},
 };
 
-mod damage_digit_material;
 mod damage_digit_pipeline;
 mod damage_digit_render_data;
 mod effect_mesh_material;
+mod far_terrain_material;
 mod object_material;
 mod particle_material;
 mod particle_pipeline;
@@ -27,11 +27,14 @@ pub const MESH_ATTRIBUTE_UV_2: MeshVertexAttribute =
 pub const MESH_ATTRIBUTE_UV_3: MeshVertexAttribute =
     MeshVertexAttribute::new("Vertex_Uv4", 519697814, VertexFormat::Float32x2);
 
-pub use damage_digit_material::DamageDigitMaterial;
-pub use damage_digit_render_data::DamageDigitRenderData;
+pub use damage_digit_render_data::{
+    DamageDigitInstance, DamageDigitRenderData, DAMAGE_DIGIT_NUM_TEXTURES,
+    DAMAGE_DIGIT_TEXTURE_DAMAGE, DAMAGE_DIGIT_TEXTURE_DAMAGE_PLAYER, DAMAGE_DIGIT_TEXTURE_MISS,
+};
 pub use effect_mesh_material::{
     EffectMeshAnimationFlags, EffectMeshAnimationRenderState, EffectMeshMaterial,
 };
+pub use far_terrain_material::FarTerrainMaterial;
 pub use object_material::{
     ObjectMaterial, ObjectMaterialBlend, ObjectMaterialClipFace, ObjectMaterialGlow,
 };
@@ -46,9 +49,9 @@ pub use water_material::WaterMaterial;
 pub use world_ui::WorldUiRect;
 pub use zone_lighting::ZoneLighting;
 
-use damage_digit_material::DamageDigitMaterialPlugin;
 use damage_digit_pipeline::DamageDigitRenderPlugin;
 use effect_mesh_material::EffectMeshMaterialPlugin;
+use far_terrain_material::FarTerrainMaterialPlugin;
 use object_material::ObjectMaterialPlugin;
 use particle_material::ParticleMaterialPlugin;
 use particle_pipeline::ParticleRenderPlugin;
@@ -69,12 +72,12 @@ impl Plugin for RoseRenderPlugin {
         app.add_plugins((
             ZoneLightingPlugin,
             TerrainMaterialPlugin { prepass_enabled },
+            FarTerrainMaterialPlugin { prepass_enabled },
             EffectMeshMaterialPlugin { prepass_enabled },
             ObjectMaterialPlugin { prepass_enabled },
             WaterMaterialPlugin { prepass_enabled },
             ParticleMaterialPlugin,
             ParticleRenderPlugin,
-            DamageDigitMaterialPlugin,
             DamageDigitRenderPlugin,
             SkyMaterialPlugin { prepass_enabled },
             TrailEffectRenderPlugin,
@@ -55,7 +55,11 @@ impl Plugin for ParticleRenderPlugin {
             compute_particles_aabb.in_set(VisibilitySystems::CalculateBounds),
         );
 
-        let render_app = app.sub_app_mut(RenderApp);
+        let render_app = match app.get_sub_app_mut(RenderApp) {
+            Ok(render_app) => render_app,
+            Err(_) => return,
+        };
+
         render_app
             .add_systems(ExtractSchedule, extract_particles)
             .add_systems(Render, prepare_particles.in_set(RenderSet::Prepare))
@@ -3,19 +3,27 @@
 @group(0) @binding(0)
 var<uniform> view: View;
 
-struct PositionBuffer { data: array<vec4<f32>>, };
-struct SizeBuffer { data: array<vec2<f32>>, };
-struct UvBuffer { data: array<vec4<f32>>, };
+struct DamageDigitInstance {
+  // xyz is the world position, w is the x offset of the digit within the number
+  position: vec4<f32>,
+  uv: vec4<f32>,
+  color: vec4<f32>,
+  size: vec2<f32>,
+  texture_index: u32,
+  age: f32,
+};
+
+struct DamageDigitInstances { data: array<DamageDigitInstance>, };
 
 @group(1) @binding(0)
-var<storage, read> positions: PositionBuffer;
-@group(1) @binding(1)
-var<storage, read> sizes: SizeBuffer;
-@group(1) @binding(2)
-var<storage, read> uvs: UvBuffer;
+var<storage, read> instances: DamageDigitInstances;
 @group(2) @binding(0)
-var base_color_texture: texture_2d<f32>;
+var damage_texture: texture_2d<f32>;
 @group(2) @binding(1)
+var damage_player_texture: texture_2d<f32>;
+@group(2) @binding(2)
+var miss_texture: texture_2d<f32>;
+@group(2) @binding(3)
 var base_color_sampler: sampler;
 
 struct VertexInput {
@@ -25,6 +33,9 @@ struct VertexInput {
 struct VertexOutput {
   @builtin(position) position: vec4<f32>,
   @location(0) uv: vec2<f32>,
+  @location(1) color: vec4<f32>,
+  @location(2) @interpolate(flat) texture_index: u32,
+  @location(3) alpha: f32,
 };
 
 @vertex
@@ -46,9 +57,10 @@ fn vs_main(model: VertexInput) -> VertexOutput {
   let camera_up =
     normalize(vec3<f32>(view.view_proj.x.y, view.view_proj.y.y, view.view_proj.z.y));
 
-  let particle_position = positions.data[digit_idx].xyz;
-  let x_offset = positions.data[digit_idx].w;
-  let size = sizes.data[digit_idx];
+  let instance = instances.data[digit_idx];
+  let particle_position = instance.position.xyz;
+  let x_offset = instance.position.w;
+  let size = instance.size;
   var vertex_position: vec2<f32> = vertex_positions[vert_idx].xy;
   vertex_position.x = vertex_positions[vert_idx].x + x_offset;
 
@@ -60,7 +72,12 @@ fn vs_main(model: VertexInput) -> VertexOutput {
   var out: VertexOutput;
   out.position = view.view_proj * vec4<f32>(world_space, 1.0);
 
-  let texture = uvs.data[digit_idx];
+  out.color = instance.color;
+  out.texture_index = instance.texture_index;
+  // Fade out over the last 10% of the animation
+  out.alpha = clamp((1.0 - instance.age) / 0.1, 0.0, 1.0);
+
+  let texture = instance.uv;
   if (vertex_positions[vert_idx].x < 0.0) {
     out.uv.x = texture.x;
   } else {
@@ -78,5 +95,25 @@ fn vs_main(model: VertexInput) -> VertexOutput {
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-  return textureSample(base_color_texture, base_color_sampler, in.uv);
+  // Sample every texture so that sampling stays in uniform control flow, then select
+  let damage_color = textureSample(damage_texture, base_color_sampler, in.uv);
+  let damage_player_color = textureSample(damage_player_texture, base_color_sampler, in.uv);
+  let miss_color = textureSample(miss_texture, base_color_sampler, in.uv);
+
+  var color: vec4<f32> = damage_color;
+  if (in.texture_index == 1u) {
+    color = damage_player_color;
+  } else if (in.texture_index == 2u) {
+    color = miss_color;
+  }
+  color.a = color.a * in.alpha;
+
+  if (in.color.a == 0.0) {
+    return color;
+  }
+
+  // Recolour the digit texture by its brightness so that tints are not muddied by the
+  // original texture colour
+  let brightness = max(color.r, max(color.g, color.b));
+  return vec4<f32>(in.color.rgb * brightness, color.a);
 }
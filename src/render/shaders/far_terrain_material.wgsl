@@ -0,0 +1,49 @@
+#import bevy_pbr::mesh_bindings mesh
+#import bevy_pbr::mesh_view_bindings view
+#import bevy_pbr::mesh_functions mesh_position_local_to_world, mesh_normal_local_to_world
+#import rose_client::zone_lighting apply_zone_lighting
+
+struct Vertex {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_position: vec4<f32>,
+    @location(1) world_normal: vec3<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    out.world_position = mesh_position_local_to_world(mesh.model, vec4<f32>(vertex.position, 1.0));
+    out.world_normal = mesh_normal_local_to_world(vertex.normal);
+    out.clip_position = view.view_proj * out.world_position;
+    return out;
+}
+
+@group(1) @binding(0)
+var<uniform> flat_color: vec4<f32>;
+@group(1) @binding(1)
+var<uniform> steep_color: vec4<f32>;
+
+struct FragmentInput {
+    @builtin(position) frag_coord: vec4<f32>,
+    @location(0) world_position: vec4<f32>,
+    @location(1) world_normal: vec3<f32>,
+};
+
+@fragment
+fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
+    let view_z = dot(vec4<f32>(
+        view.inverse_view[0].z,
+        view.inverse_view[1].z,
+        view.inverse_view[2].z,
+        view.inverse_view[3].z
+    ), in.world_position);
+
+    let slope = 1.0 - clamp(normalize(in.world_normal).y, 0.0, 1.0);
+    let terrain_color = mix(flat_color, steep_color, smoothstep(0.1, 0.4, slope));
+    return apply_zone_lighting(in.world_position, in.world_normal, terrain_color, view_z);
+}
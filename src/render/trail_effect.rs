@@ -52,7 +52,11 @@ impl Plugin for TrailEffectRenderPlugin {
         app.add_systems(Update, (initialise_trail_effects,));
         app.add_systems(PostUpdate, (update_trail_effects,));
 
-        let render_app = app.sub_app_mut(RenderApp);
+        let render_app = match app.get_sub_app_mut(RenderApp) {
+            Ok(render_app) => render_app,
+            Err(_) => return,
+        };
+
         render_app
             .add_systems(ExtractSchedule, extract_trail_effects)
             .add_systems(Render, prepare_trail_effects.in_set(RenderSet::Prepare))
@@ -82,6 +86,8 @@ pub struct TrailEffect {
     pub end_offset: Vec3,
     pub trail_texture: Handle<Image>,
     pub distance_per_point: f32,
+    /// New points are only added to the trail whilst emitting, e.g. during an attack swing
+    pub emitting: bool,
 }
 
 #[derive(Copy, Clone, Default)]
@@ -97,6 +103,7 @@ pub struct TrailEffectPositionHistory {
     catmull_points: [TrailEffectPoint; 4],
     trail_length_excess: f32,
     last_temp_points: usize,
+    was_emitting: bool,
 }
 
 impl Default for TrailEffectPositionHistory {
@@ -106,6 +113,7 @@ impl Default for TrailEffectPositionHistory {
             catmull_points: Default::default(),
             trail_length_excess: 0.0,
             last_temp_points: 0,
+            was_emitting: false,
         }
     }
 }
@@ -154,6 +162,29 @@ pub fn update_trail_effects(
     let now = time.elapsed_seconds();
 
     for (trail_effect, mut history, transform) in query.iter_mut() {
+        // Pop old points
+        let last_time =
+            now - trail_effect.duration * render_configuration.trail_effect_duration_multiplier;
+        while history
+            .history
+            .back()
+            .map_or(false, |point| point.time < last_time)
+        {
+            history.history.pop_back();
+        }
+
+        if !trail_effect.emitting {
+            // Let the previous trail fade out
+            history.was_emitting = false;
+            continue;
+        } else if !history.was_emitting {
+            // Start a new trail rather than joining onto the end of the previous swing
+            history.was_emitting = true;
+            history.history.clear();
+            history.trail_length_excess = 0.0;
+            history.last_temp_points = 0;
+        }
+
         let transform = transform.compute_transform();
         let point = TrailEffectPoint {
             start: transform.translation
@@ -283,17 +314,6 @@ pub fn update_trail_effects(
             // Store the 0->1 num points to be removed next time
             history.last_temp_points = num_to_add as usize + 1;
         }
-
-        // Pop old points
-        let last_time =
-            now - trail_effect.duration * render_configuration.trail_effect_duration_multiplier;
-        while history
-            .history
-            .back()
-            .map_or(false, |point| point.time < last_time)
-        {
-            history.history.pop_back();
-        }
     }
 }
 
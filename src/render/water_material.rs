@@ -36,7 +36,10 @@ use bevy::{
     },
 };
 
-use crate::render::zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta};
+use crate::{
+    render::zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta},
+    resources::{RenderConfiguration, WaterQuality},
+};
 
 pub const WATER_MESH_MATERIAL_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 0x333959e64b35d5d9);
@@ -79,11 +82,18 @@ pub struct WaterPushConstantData {
     pub next_weight: f32,
 }
 
-fn extract_water_push_constant_data(mut commands: Commands, time: Extract<Res<Time>>) {
+fn extract_water_push_constant_data(
+    mut commands: Commands,
+    time: Extract<Res<Time>>,
+    render_configuration: Extract<Res<RenderConfiguration>>,
+) {
     let time = time.elapsed_seconds_wrapped() * 10.0;
     let current_index = (time as i32) % WATER_MATERIAL_NUM_TEXTURES as i32;
     let next_index = (current_index + 1) % WATER_MATERIAL_NUM_TEXTURES as i32;
-    let next_weight = time.fract();
+    let next_weight = match render_configuration.water_quality {
+        WaterQuality::Low => 0.0,
+        WaterQuality::High => time.fract(),
+    };
 
     commands.insert_resource(WaterPushConstantData {
         current_index,
@@ -0,0 +1,60 @@
+use bevy::prelude::Resource;
+use bevy_egui::egui;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColorPalette {
+    #[default]
+    Default,
+
+    /// Avoids relying on red and green, for deuteranopia and protanopia
+    RedGreen,
+
+    /// Avoids relying on blue and yellow, for tritanopia
+    BlueYellow,
+}
+
+#[derive(Default, Resource)]
+pub struct AccessibilitySettings {
+    /// Colours used for team and hostility indicators and damage digits
+    pub color_palette: ColorPalette,
+
+    /// Chat and tooltip text is never drawn smaller than this
+    pub minimum_font_size: f32,
+
+    /// Use stronger colours and thicker borders for drag and drop slot highlights
+    pub high_contrast: bool,
+
+    /// Show important audio cues as on screen captions
+    pub sound_captions: bool,
+}
+
+impl AccessibilitySettings {
+    pub fn hostile_color(&self) -> egui::Color32 {
+        match self.color_palette {
+            ColorPalette::Default => egui::Color32::RED,
+            ColorPalette::RedGreen => egui::Color32::from_rgb(230, 97, 1),
+            ColorPalette::BlueYellow => egui::Color32::from_rgb(220, 38, 127),
+        }
+    }
+
+    pub fn friendly_color(&self) -> egui::Color32 {
+        egui::Color32::WHITE
+    }
+
+    pub fn npc_color(&self) -> egui::Color32 {
+        match self.color_palette {
+            ColorPalette::Default => egui::Color32::GREEN,
+            ColorPalette::RedGreen => egui::Color32::from_rgb(93, 178, 255),
+            ColorPalette::BlueYellow => egui::Color32::from_rgb(0, 220, 200),
+        }
+    }
+
+    /// Returns a tint for damage digits, or None to use the original digit texture colours
+    pub fn damage_digit_color(&self, is_damage_player: bool) -> Option<egui::Color32> {
+        match self.color_palette {
+            ColorPalette::Default => None,
+            _ if is_damage_player => Some(self.hostile_color()),
+            _ => Some(egui::Color32::WHITE),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::{Resource, Vec3};
+
+/// Keeps the player moving without holding any input, either running forward in the
+/// current facing direction or walking through a queue of waypoints.
+#[derive(Default, Resource)]
+pub struct AutoTravel {
+    pub auto_run: bool,
+    pub waypoints: VecDeque<Vec3>,
+}
+
+impl AutoTravel {
+    pub fn is_active(&self) -> bool {
+        self.auto_run || !self.waypoints.is_empty()
+    }
+
+    pub fn cancel(&mut self) {
+        self.auto_run = false;
+        self.waypoints.clear();
+    }
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use bevy::{prelude::Resource, utils::HashMap};
+
+/// Each player only receives one auto-reply within this duration
+pub const AWAY_AUTO_REPLY_COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Resource)]
+pub struct AwayState {
+    pub is_away: bool,
+    /// Auto-reply message used when /afk is entered without a message
+    pub default_message: String,
+    pub message: String,
+    /// Set by the /afk chat command with the message to reply with
+    pub requested: Option<String>,
+    pub last_replies: HashMap<String, Duration>,
+}
+
+impl AwayState {
+    pub fn new(default_message: String) -> Self {
+        Self {
+            is_away: false,
+            default_message,
+            message: String::new(),
+            requested: None,
+            last_replies: HashMap::default(),
+        }
+    }
+
+    pub fn request(&mut self, message: &str) {
+        self.requested = Some(if message.is_empty() {
+            self.default_message.clone()
+        } else {
+            message.to_string()
+        });
+    }
+
+    /// Returns true if an auto-reply should be sent to this player, and records it as sent
+    pub fn try_auto_reply(&mut self, name: &str, now: Duration) -> bool {
+        if !self.is_away {
+            return false;
+        }
+
+        if self.last_replies.get(name).map_or(false, |last_reply| {
+            now - *last_reply < AWAY_AUTO_REPLY_COOLDOWN
+        }) {
+            return false;
+        }
+
+        self.last_replies.insert(name.to_string(), now);
+        true
+    }
+}
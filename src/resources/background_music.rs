@@ -0,0 +1,36 @@
+use bevy::prelude::{Entity, Handle, Resource};
+use rose_data::ZoneId;
+
+use crate::audio::AudioSource;
+
+/// A background music track which is playing, or fading in or out
+pub struct BackgroundMusicTrack {
+    pub entity: Entity,
+    pub audio_source: Handle<AudioSource>,
+    /// Fade volume in 0..1, multiplied with the background music gain
+    pub volume: f32,
+}
+
+#[derive(Default, Resource)]
+pub struct BackgroundMusic {
+    pub zone: Option<ZoneId>,
+    pub is_night: bool,
+    /// The track being faded in, or playing at full volume
+    pub current: Option<BackgroundMusicTrack>,
+    /// Previous tracks which are fading out, despawned once silent
+    pub fading_out: Vec<BackgroundMusicTrack>,
+}
+
+impl BackgroundMusic {
+    /// Switches to a new track, the current track fades out and the new one fades in
+    pub fn set_track(&mut self, track: Option<BackgroundMusicTrack>) {
+        if let Some(previous) = self.current.take() {
+            self.fading_out.push(previous);
+        }
+        self.current = track;
+    }
+
+    pub fn is_playing(&self, audio_source: Option<&Handle<AudioSource>>) -> bool {
+        self.current.as_ref().map(|track| &track.audio_source) == audio_source
+    }
+}
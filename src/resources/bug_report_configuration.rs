@@ -0,0 +1,8 @@
+use bevy::prelude::Resource;
+use std::path::PathBuf;
+
+#[derive(Resource)]
+pub struct BugReportConfiguration {
+    pub upload_url: Option<String>,
+    pub directory: PathBuf,
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::Resource;
+use std::time::Duration;
+
+pub const CHANNEL_QUEUE_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+/// Tracks the channel currently being joined so that a full or rejected channel can be retried.
+///
+/// The irose login protocol does not report a queue position, so when every channel of a server
+/// has rejected us we wait for [`CHANNEL_QUEUE_RETRY_DELAY`] before trying them all again.
+#[derive(Resource)]
+pub struct ChannelQueue {
+    pub auto_next_channel: bool,
+    pub joining: Option<(usize, usize)>,
+    pub rejected: bool,
+    pub rejected_channels: Vec<usize>,
+    pub attempts: u32,
+    pub retry_remaining: Option<Duration>,
+}
+
+impl Default for ChannelQueue {
+    fn default() -> Self {
+        Self {
+            auto_next_channel: true,
+            joining: None,
+            rejected: false,
+            rejected_channels: Vec::new(),
+            attempts: 0,
+            retry_remaining: None,
+        }
+    }
+}
+
+impl ChannelQueue {
+    pub fn start(&mut self, server_id: usize, channel_id: usize) {
+        self.joining = Some((server_id, channel_id));
+        self.rejected = false;
+        self.rejected_channels.clear();
+        self.attempts = 1;
+        self.retry_remaining = None;
+    }
+
+    pub fn reset(&mut self) {
+        self.joining = None;
+        self.rejected = false;
+        self.rejected_channels.clear();
+        self.attempts = 0;
+        self.retry_remaining = None;
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::Resource;
+use std::time::Duration;
+
+pub const CHANNEL_SWITCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChannelSwitchStage {
+    /// Waiting for the login server to accept the new channel
+    JoiningChannel,
+    /// Game connection has been torn down, connecting to the new world server
+    ConnectingWorld,
+    /// Character has been selected, waiting for the new game server
+    ConnectingGame,
+    /// Loading the zone the new game server has placed us in
+    LoadingZone,
+}
+
+/// Tracks the channel we are playing on, and any in progress switch to another channel.
+///
+/// Switching channel keeps the login connection alive and only replaces the world and game
+/// connections, so the player does not have to go back through login or character select.
+#[derive(Default, Resource)]
+pub struct ChannelSwitch {
+    pub current: Option<(usize, usize)>,
+    pub target: Option<(usize, usize)>,
+    pub character_name: String,
+    pub stage: Option<ChannelSwitchStage>,
+    pub started: Duration,
+}
+
+impl ChannelSwitch {
+    pub fn start(
+        &mut self,
+        server_id: usize,
+        channel_id: usize,
+        character_name: String,
+        now: Duration,
+    ) {
+        self.target = Some((server_id, channel_id));
+        self.character_name = character_name;
+        self.stage = Some(ChannelSwitchStage::JoiningChannel);
+        self.started = now;
+    }
+
+    pub fn is_switching(&self) -> bool {
+        self.stage.is_some()
+    }
+
+    pub fn finish(&mut self) {
+        self.current = self.target.take();
+        self.stage = None;
+    }
+
+    pub fn cancel(&mut self) {
+        self.target = None;
+        self.stage = None;
+    }
+}
@@ -0,0 +1,109 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use super::profile_storage::{load_toml, sanitise_path_component, save_toml};
+
+const CHARACTER_SETTINGS_DIRECTORY: &str = "characters";
+const CHARACTER_SETTINGS_FILE: &str = "settings.toml";
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct HotbarSettings {
+    pub page: usize,
+    pub vertical: bool,
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WindowLayoutSettings {
+    pub character_info_open: bool,
+    pub clan_open: bool,
+    pub inventory_open: bool,
+    pub party_open: bool,
+    pub quest_list_open: bool,
+    pub skill_list_open: bool,
+}
+
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ChatChannelFilter {
+    #[default]
+    All,
+    Whisper,
+    Trade,
+    Party,
+    Clan,
+    Allied,
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ChatSettings {
+    pub channel_filter: ChatChannelFilter,
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ChatMacro {
+    pub name: String,
+    pub text: String,
+}
+
+/// Achievements are tracked by the client, so they work on any server
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AchievementSettings {
+    /// Keys of the achievements which have been unlocked
+    pub unlocked: BTreeSet<String>,
+    pub visited_zones: BTreeSet<u16>,
+    pub defeated_bosses: BTreeSet<u16>,
+}
+
+/// Client side settings and UI state which are stored per character, in a profile
+/// directory keyed by server and character name.
+#[derive(Default, Resource, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CharacterSettings {
+    #[serde(skip)]
+    pub path: PathBuf,
+    pub hotbar: HotbarSettings,
+    pub windows: WindowLayoutSettings,
+    pub chat: ChatSettings,
+    /// Quest ids which are highlighted in the quest list
+    pub tracked_quests: Vec<usize>,
+    pub macros: Vec<ChatMacro>,
+    /// Overrides of the global key bindings, mapping action name to key name
+    pub hotkeys: BTreeMap<String, String>,
+    /// Windows for which the first time contextual help tip has already been shown
+    pub seen_help_tips: BTreeSet<String>,
+    /// Tutorial hints which have already been shown
+    pub seen_tutorial_hints: BTreeSet<String>,
+    pub achievements: AchievementSettings,
+}
+
+impl CharacterSettings {
+    pub fn profile_directory(server_key: &str, character_name: &str) -> PathBuf {
+        Path::new(CHARACTER_SETTINGS_DIRECTORY)
+            .join(sanitise_path_component(server_key))
+            .join(sanitise_path_component(character_name))
+    }
+
+    pub fn load(profile_directory: &Path) -> Self {
+        let path = profile_directory.join(CHARACTER_SETTINGS_FILE);
+        let mut settings: CharacterSettings = load_toml(&path, "character settings");
+        settings.path = path;
+        settings
+    }
+
+    pub fn save(&self) {
+        if self.path.as_os_str().is_empty() {
+            return;
+        }
+
+        save_toml(&self.path, self, "character settings");
+    }
+}
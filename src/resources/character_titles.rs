@@ -0,0 +1,19 @@
+use bevy::prelude::Resource;
+
+/// Titles the player has unlocked, sent by servers which support titles
+#[derive(Default, Resource)]
+pub struct CharacterTitles {
+    pub titles: Vec<String>,
+    /// Index into titles of the title currently shown to other players
+    pub active: Option<usize>,
+    /// Whether the server allows the player to choose their active title
+    pub can_change: bool,
+}
+
+impl CharacterTitles {
+    pub fn active_title(&self) -> Option<&str> {
+        self.active
+            .and_then(|index| self.titles.get(index))
+            .map(|title| title.as_str())
+    }
+}
@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::Resource;
+
+/// Settings are saved here when the client was started without a config file
+pub const DEFAULT_CONFIG_PATH: &str = "config.toml";
+
+/// The config file which settings changed in game are saved to
+#[derive(Default, Resource)]
+pub struct ConfigFile {
+    pub path: Option<PathBuf>,
+}
+
+impl ConfigFile {
+    pub fn path(&self) -> &Path {
+        self.path
+            .as_deref()
+            .unwrap_or_else(|| Path::new(DEFAULT_CONFIG_PATH))
+    }
+
+    /// Updates the config file, any settings which are not changed by update are left untouched.
+    ///
+    /// The file is created if it does not exist yet.
+    pub fn update(&self, update: impl FnOnce(&mut toml::Table)) {
+        let path = self.path();
+        let mut document = match std::fs::read_to_string(path) {
+            Ok(toml_str) => match toml_str.parse::<toml::Table>() {
+                Ok(document) => document,
+                Err(error) => {
+                    // Do not overwrite a config file the user has to fix by hand
+                    log::error!(
+                        "Failed to save settings, could not parse {} with error: {}",
+                        path.to_string_lossy(),
+                        error
+                    );
+                    return;
+                }
+            },
+            Err(_) => toml::Table::new(),
+        };
+
+        update(&mut document);
+
+        match toml::to_string_pretty(&document) {
+            Ok(toml_str) => {
+                if let Err(error) = std::fs::write(path, toml_str) {
+                    log::error!(
+                        "Failed to save settings to {} with error: {}",
+                        path.to_string_lossy(),
+                        error
+                    );
+                }
+            }
+            Err(error) => log::error!("Failed to serialise settings: {}", error),
+        }
+    }
+}
+
+/// Returns the named table within a config table, creating it if it does not exist
+pub fn config_table_mut<'a>(table: &'a mut toml::Table, name: &str) -> Option<&'a mut toml::Table> {
+    let section = table
+        .entry(name)
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+    if section.as_table_mut().is_none() {
+        log::error!("Failed to save settings, [{}] is not a table", name);
+    }
+    section.as_table_mut()
+}
@@ -1,47 +1,33 @@
-use bevy::{
-    prelude::{
-        AssetServer, Assets, BuildChildren, Commands, ComputedVisibility, GlobalTransform, Handle,
-        Resource, Transform, Vec3, Visibility,
-    },
-    render::primitives::Aabb,
-};
+use bevy::prelude::{AssetServer, GlobalTransform, Handle, Resource, Vec3};
 
-use crate::{
-    animation::{TransformAnimation, ZmoAsset},
-    components::DamageDigits,
-    render::{DamageDigitMaterial, DamageDigitRenderData},
-};
+use crate::animation::{TransformAnimation, ZmoAsset};
 
+/// A damage number which is currently being animated
+pub struct ActiveDamageDigits {
+    pub translation: Vec3,
+    pub damage: u32,
+    pub is_damage_player: bool,
+    pub animation: TransformAnimation,
+}
+
+/// Damage numbers are not entities, they are stored here and drawn as a single instanced
+/// batch by damage_digit_render_system to avoid spawning entities for every hit.
 #[derive(Resource)]
 pub struct DamageDigitsSpawner {
-    pub texture_damage: Handle<DamageDigitMaterial>,
-    pub texture_damage_player: Handle<DamageDigitMaterial>,
-    pub texture_miss: Handle<DamageDigitMaterial>,
     pub motion: Handle<ZmoAsset>,
+    pub active: Vec<ActiveDamageDigits>,
 }
 
 impl DamageDigitsSpawner {
-    pub fn load(
-        asset_server: &AssetServer,
-        damage_digit_materials: &mut Assets<DamageDigitMaterial>,
-    ) -> Self {
+    pub fn load(asset_server: &AssetServer) -> Self {
         Self {
-            texture_damage: damage_digit_materials.add(DamageDigitMaterial {
-                texture: asset_server.load("3DDATA/EFFECT/SPECIAL/DIGITNUMBER01.DDS"),
-            }),
-            texture_damage_player: damage_digit_materials.add(DamageDigitMaterial {
-                texture: asset_server.load("3DDATA/EFFECT/SPECIAL/DIGITNUMBER02.DDS"),
-            }),
-            texture_miss: damage_digit_materials.add(DamageDigitMaterial {
-                texture: asset_server.load("3DDATA/EFFECT/SPECIAL/DIGITNUMBERMISS.DDS"),
-            }),
             motion: asset_server.load("3DDATA/EFFECT/SPECIAL/HIT_FIGURE_01.ZMO"),
+            active: Vec::new(),
         }
     }
 
     pub fn spawn(
-        &self,
-        commands: &mut Commands,
+        &mut self,
         global_transform: &GlobalTransform,
         model_height: f32,
         damage: u32,
@@ -49,34 +35,11 @@ impl DamageDigitsSpawner {
     ) {
         let (scale, _, translation) = global_transform.to_scale_rotation_translation();
 
-        // We need to spawn inside a parent entity for positioning because the ActiveMotion will set the translation absolutely
-        commands
-            .spawn((
-                Transform::from_translation(
-                    translation + Vec3::new(0.0, model_height * scale.y, 0.0),
-                ),
-                GlobalTransform::default(),
-                Visibility::default(),
-                ComputedVisibility::default(),
-            ))
-            .with_children(|child_builder| {
-                child_builder.spawn((
-                    DamageDigits { damage },
-                    DamageDigitRenderData::new(4),
-                    if damage == 0 {
-                        self.texture_miss.clone_weak()
-                    } else if is_damage_player {
-                        self.texture_damage_player.clone_weak()
-                    } else {
-                        self.texture_damage.clone_weak()
-                    },
-                    TransformAnimation::once(self.motion.clone_weak()),
-                    Transform::default(),
-                    GlobalTransform::default(),
-                    Aabb::default(),
-                    Visibility::default(),
-                    ComputedVisibility::default(),
-                ));
-            });
+        self.active.push(ActiveDamageDigits {
+            translation: translation + Vec3::new(0.0, model_height * scale.y, 0.0),
+            damage,
+            is_damage_player,
+            animation: TransformAnimation::once(self.motion.clone_weak()),
+        });
     }
 }
@@ -0,0 +1,9 @@
+use bevy::prelude::Resource;
+use std::path::PathBuf;
+
+#[derive(Resource)]
+pub struct DataOverlayConfiguration {
+    pub overlay_url: Option<String>,
+    pub overlay_manifest_path: PathBuf,
+    pub data_path: Option<PathBuf>,
+}
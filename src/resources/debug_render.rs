@@ -1,5 +1,7 @@
 use bevy::prelude::{Color, Entity, Resource};
 
+use rose_data::SkillId;
+
 const DEBUG_RENDER_COLOR_LIST: [Color; 8] = [
     Color::RED,
     Color::GREEN,
@@ -18,6 +20,14 @@ pub struct DebugRenderConfig {
     pub bone_up: bool,
     pub directional_light_frustum: bool,
     pub directional_light_frustum_freeze: bool,
+    pub monster_spawns: bool,
+    pub command_paths: bool,
+    pub velocities: bool,
+    pub npc_aggro_ranges: bool,
+    pub skill_ranges: bool,
+
+    /// The skill under the mouse cursor on the hotbar, set by the hotbar so its range can be drawn
+    pub hovered_hotbar_skill: Option<SkillId>,
 }
 
 impl DebugRenderConfig {
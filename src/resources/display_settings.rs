@@ -0,0 +1,86 @@
+use bevy::{
+    prelude::Resource,
+    window::{MonitorSelection, Window, WindowMode, WindowPosition},
+};
+
+use crate::resources::{config_table_mut, ConfigFile};
+
+pub const DISPLAY_RESOLUTIONS: [(u32, u32); 8] = [
+    (1280, 720),
+    (1366, 768),
+    (1600, 900),
+    (1920, 1080),
+    (2560, 1080),
+    (2560, 1440),
+    (3440, 1440),
+    (3840, 2160),
+];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DisplayMode {
+    Windowed,
+    BorderlessFullscreen,
+    ExclusiveFullscreen,
+}
+
+#[derive(Resource)]
+pub struct DisplaySettings {
+    pub mode: DisplayMode,
+    pub resolution: (u32, u32),
+
+    /// Index of the monitor to display on, or None to use the current monitor
+    pub monitor: Option<usize>,
+}
+
+impl DisplaySettings {
+    pub fn window_mode(&self) -> WindowMode {
+        match self.mode {
+            DisplayMode::Windowed => WindowMode::Windowed,
+            DisplayMode::BorderlessFullscreen => WindowMode::BorderlessFullscreen,
+            DisplayMode::ExclusiveFullscreen => WindowMode::SizedFullscreen,
+        }
+    }
+
+    pub fn monitor_selection(&self) -> MonitorSelection {
+        self.monitor
+            .map_or(MonitorSelection::Current, MonitorSelection::Index)
+    }
+
+    pub fn apply(&self, window: &mut Window) {
+        window.mode = self.window_mode();
+        window
+            .resolution
+            .set(self.resolution.0 as f32, self.resolution.1 as f32);
+        window.position = WindowPosition::Centered(self.monitor_selection());
+    }
+
+    /// Update the [graphics] section of the config file, leaving all other settings untouched
+    pub fn save(&self, config_file: &ConfigFile) {
+        config_file.update(|document| {
+            let Some(graphics) = config_table_mut(document, "graphics") else {
+                return;
+            };
+
+            let mut mode = toml::Table::new();
+            mode.insert(
+                "type".into(),
+                match self.mode {
+                    DisplayMode::Windowed => "window",
+                    DisplayMode::BorderlessFullscreen => "fullscreen",
+                    DisplayMode::ExclusiveFullscreen => "exclusive_fullscreen",
+                }
+                .into(),
+            );
+            if self.mode != DisplayMode::BorderlessFullscreen {
+                mode.insert("width".into(), (self.resolution.0 as f64).into());
+                mode.insert("height".into(), (self.resolution.1 as f64).into());
+            }
+            graphics.insert("mode".into(), toml::Value::Table(mode));
+
+            match self.monitor {
+                Some(monitor) => graphics.insert("monitor".into(), (monitor as i64).into()),
+                None => graphics.remove("monitor"),
+            };
+        });
+    }
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::Resource;
+
+use crate::ui::ItemRarity;
+
+#[derive(Resource)]
+pub struct DropNotificationSettings {
+    /// Items looted by the player of at least this rarity play a fanfare and show a golden
+    /// toast, or None to disable
+    pub fanfare_rarity: Option<ItemRarity>,
+
+    /// Items looted by party members of at least this rarity play a fanfare and show a golden
+    /// toast, or None to disable
+    pub party_fanfare_rarity: Option<ItemRarity>,
+
+    /// Hide the chatbox acquisition messages for junk and common items
+    pub mute_common_drops: bool,
+}
+
+impl Default for DropNotificationSettings {
+    fn default() -> Self {
+        Self {
+            fanfare_rarity: Some(ItemRarity::Rare),
+            party_fanfare_rarity: Some(ItemRarity::Unique),
+            mute_common_drops: false,
+        }
+    }
+}
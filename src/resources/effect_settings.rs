@@ -0,0 +1,13 @@
+use bevy::prelude::Resource;
+
+/// Skills with an area of effect at least this large (in cm) have screen-covering effects
+pub const SCREEN_COVERING_SKILL_SCOPE: f32 = 600.0;
+
+#[derive(Default, Resource)]
+pub struct EffectSettings {
+    pub hide_other_player_skill_effects: bool,
+    pub hide_screen_covering_effects: bool,
+    /// Maximum number of simultaneous effects, effects which are manually despawned such as
+    /// status effects and equipment glow do not count towards or get limited by this.
+    pub max_effects: Option<usize>,
+}
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use bevy::prelude::{Entity, Resource, Vec3};
+
+#[derive(Clone, Copy, Default)]
+pub enum FishingPhase {
+    #[default]
+    Idle,
+    /// Waiting for the server to accept the cast
+    Casting { requested: Duration },
+    /// Line is in the water waiting for a bite
+    Waiting,
+    /// A fish is biting, the player must reel in within the window
+    Bite {
+        started: Duration,
+        reel_window: Duration,
+    },
+    /// Waiting for the server to tell us whether the catch succeeded
+    Reeling { requested: Duration },
+}
+
+#[derive(Default, Resource)]
+pub struct Fishing {
+    pub phase: FishingPhase,
+    /// World position of the bobber
+    pub bobber_position: Option<Vec3>,
+    pub bobber_entity: Option<Entity>,
+    /// Set when the server never replied to a cast, fishing is disabled until reconnecting
+    pub unsupported: bool,
+}
+
+impl Fishing {
+    pub fn is_active(&self) -> bool {
+        !matches!(self.phase, FishingPhase::Idle)
+    }
+}
@@ -1,20 +1,29 @@
-use bevy::prelude::Resource;
+use bevy::prelude::{Commands, DespawnRecursiveExt, Resource};
 
 use rose_game_common::{
     data::Password,
     messages::{client::ClientMessage, server::ServerMessage},
 };
 
+use crate::{
+    protocol::{ExtensionClientMessage, ExtensionServerMessage},
+    resources::ClientEntityList,
+};
+
 #[derive(Resource)]
 pub struct GameConnection {
     pub client_message_tx: tokio::sync::mpsc::UnboundedSender<ClientMessage>,
     pub server_message_rx: crossbeam_channel::Receiver<ServerMessage>,
+    pub extension_message_tx: tokio::sync::mpsc::UnboundedSender<ExtensionClientMessage>,
+    pub extension_message_rx: crossbeam_channel::Receiver<ExtensionServerMessage>,
 }
 
 impl GameConnection {
     pub fn new(
         client_message_tx: tokio::sync::mpsc::UnboundedSender<ClientMessage>,
         server_message_rx: crossbeam_channel::Receiver<ServerMessage>,
+        extension_message_tx: tokio::sync::mpsc::UnboundedSender<ExtensionClientMessage>,
+        extension_message_rx: crossbeam_channel::Receiver<ExtensionServerMessage>,
         login_token: u32,
         password: Password,
     ) -> Self {
@@ -28,6 +37,33 @@ impl GameConnection {
         Self {
             client_message_tx,
             server_message_rx,
+            extension_message_tx,
+            extension_message_rx,
+        }
+    }
+}
+
+/// Disconnects from the game server and despawns every entity it spawned, including the player.
+/// The world and login connections are left untouched.
+pub fn teardown_game_connection(
+    commands: &mut Commands,
+    client_entity_list: &mut ClientEntityList,
+) {
+    commands.remove_resource::<GameConnection>();
+
+    for entity in client_entity_list
+        .client_entities
+        .iter()
+        .flatten()
+        .chain(client_entity_list.player_entity.iter())
+    {
+        if let Some(mut entity_commands) = commands.get_entity(*entity) {
+            entity_commands.despawn_recursive();
         }
     }
+
+    client_entity_list.clear();
+    client_entity_list.player_entity = None;
+    client_entity_list.player_entity_id = None;
+    client_entity_list.zone_id = None;
 }
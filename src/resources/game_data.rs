@@ -9,6 +9,8 @@ use rose_data::{
 use rose_file_readers::{LtbFile, StbFile, ZscFile};
 use rose_game_common::data::AbilityValueCalculator;
 
+use crate::resources::SitPointDatabase;
+
 #[derive(Resource)]
 pub struct GameData {
     pub ability_value_calculator: Box<dyn AbilityValueCalculator + Send + Sync>,
@@ -21,6 +23,7 @@ pub struct GameData {
     pub job_class: Arc<JobClassDatabase>,
     pub npcs: Arc<NpcDatabase>,
     pub quests: Arc<QuestDatabase>,
+    pub sit_points: SitPointDatabase,
     pub skills: Arc<SkillDatabase>,
     pub skybox: Arc<SkyboxDatabase>,
     pub sounds: Arc<SoundDatabase>,
@@ -31,5 +34,6 @@ pub struct GameData {
     pub zsc_event_object: ZscFile,
     pub zsc_special_object: ZscFile,
     pub stb_morph_object: StbFile,
+    pub stb_warp: StbFile,
     pub character_select_positions: Vec<Transform>,
 }
@@ -0,0 +1,64 @@
+use std::{
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use bevy::prelude::Resource;
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize)]
+pub struct HeadlessScriptPacket {
+    pub command: u16,
+    /// Hex encoded packet data
+    #[serde(default)]
+    pub data: String,
+}
+
+/// A single step of a headless script, all fields are optional and are applied in order:
+/// load_zone, packet, quest_trigger, expect_zone, expect_entity_count, exit.
+/// The app exits once every step has been run.
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HeadlessScriptStep {
+    pub frame: u32,
+    pub load_zone: Option<u16>,
+    pub packet: Option<HeadlessScriptPacket>,
+    pub quest_trigger: Option<String>,
+    pub expect_zone: Option<u16>,
+    pub expect_entity_count: Option<usize>,
+    pub exit: bool,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct HeadlessScriptFile {
+    #[serde(rename = "step")]
+    steps: Vec<HeadlessScriptStep>,
+}
+
+#[derive(Resource)]
+pub struct HeadlessScript {
+    pub steps: Vec<HeadlessScriptStep>,
+    pub failed: Arc<AtomicBool>,
+}
+
+impl HeadlessScript {
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        let toml_str = std::fs::read_to_string(path)?;
+        let mut file = toml::from_str::<HeadlessScriptFile>(&toml_str)?;
+        file.steps.sort_by_key(|step| step.frame);
+
+        Ok(Self {
+            steps: file.steps,
+            failed: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    pub fn fail(&self, message: &str) {
+        log::error!("Headless script failed: {}", message);
+        self.failed.store(true, Ordering::Relaxed);
+    }
+}
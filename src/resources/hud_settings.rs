@@ -0,0 +1,52 @@
+use bevy::prelude::{Res, Resource};
+use enum_map::{Enum, EnumMap};
+
+#[derive(Copy, Clone, Debug, Enum, PartialEq, Eq)]
+pub enum HudWidget {
+    Chatbox,
+    Clock,
+    Hotbar,
+    Minimap,
+    Party,
+    PlayerInfo,
+    SelectedTarget,
+    StatusEffects,
+}
+
+impl HudWidget {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HudWidget::Chatbox => "Chatbox",
+            HudWidget::Clock => "Clock",
+            HudWidget::Hotbar => "Hotbar",
+            HudWidget::Minimap => "Minimap",
+            HudWidget::Party => "Party",
+            HudWidget::PlayerInfo => "Player Info",
+            HudWidget::SelectedTarget => "Selected Target",
+            HudWidget::StatusEffects => "Status Effects",
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct HudSettings {
+    /// Hides all game windows except critical alerts such as message boxes and respawn
+    pub hidden: bool,
+
+    /// Show the HUD again when the player starts attacking or is attacked
+    pub restore_on_combat: bool,
+
+    /// Which widgets are shown whilst the HUD is not hidden
+    pub widgets: EnumMap<HudWidget, bool>,
+}
+
+impl HudSettings {
+    pub fn is_visible(&self, widget: HudWidget) -> bool {
+        !self.hidden && self.widgets[widget]
+    }
+}
+
+/// Run condition for game windows which should be hidden along with the rest of the HUD
+pub fn hud_visible(hud_settings: Res<HudSettings>) -> bool {
+    !hud_settings.hidden
+}
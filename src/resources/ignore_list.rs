@@ -0,0 +1,78 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use super::profile_storage::{load_toml, sanitise_path_component, save_toml};
+
+const ACCOUNT_SETTINGS_DIRECTORY: &str = "accounts";
+const IGNORE_LIST_FILE: &str = "ignore_list.toml";
+
+/// Names of players whose chat and requests are ignored, stored per account in a profile
+/// directory keyed by server and username so it is shared by all characters on the account.
+#[derive(Default, Resource, Deserialize, Serialize)]
+#[serde(default)]
+pub struct IgnoreList {
+    #[serde(skip)]
+    pub path: PathBuf,
+    pub names: BTreeSet<String>,
+}
+
+impl IgnoreList {
+    pub fn profile_directory(server_key: &str, username: &str) -> PathBuf {
+        Path::new(ACCOUNT_SETTINGS_DIRECTORY)
+            .join(sanitise_path_component(server_key))
+            .join(sanitise_path_component(username))
+    }
+
+    pub fn load(profile_directory: &Path) -> Self {
+        let path = profile_directory.join(IGNORE_LIST_FILE);
+        let mut ignore_list: IgnoreList = load_toml(&path, "ignore list");
+        ignore_list.path = path;
+        ignore_list
+    }
+
+    pub fn save(&self) {
+        if self.path.as_os_str().is_empty() {
+            return;
+        }
+
+        save_toml(&self.path, self, "ignore list");
+    }
+
+    /// Character names are matched case insensitively
+    pub fn is_ignored(&self, name: &str) -> bool {
+        self.names
+            .iter()
+            .any(|ignored| ignored.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns true if the name was not already ignored
+    pub fn add(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        if name.is_empty() || self.is_ignored(name) {
+            return false;
+        }
+
+        self.names.insert(name.to_string());
+        self.save();
+        true
+    }
+
+    /// Returns true if the name was previously ignored
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.names.len();
+        self.names
+            .retain(|ignored| !ignored.eq_ignore_ascii_case(name.trim()));
+
+        if self.names.len() != len {
+            self.save();
+            true
+        } else {
+            false
+        }
+    }
+}
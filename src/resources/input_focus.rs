@@ -0,0 +1,29 @@
+use bevy::prelude::Resource;
+
+/// Whether keyboard input is currently captured by the UI rather than gameplay.
+///
+/// This is updated once at the start of each frame by `input_focus_system`, so every gameplay
+/// and hotkey system sees the same focus state no matter where in the frame it runs. Systems
+/// should check [`InputFocus::keyboard_captured`] rather than asking egui directly.
+#[derive(Default, Resource)]
+pub struct InputFocus {
+    pub text_input: bool,
+    pub modal_dialog: bool,
+    modal_dialog_requested: bool,
+}
+
+impl InputFocus {
+    pub fn keyboard_captured(&self) -> bool {
+        self.text_input || self.modal_dialog
+    }
+
+    /// Called every frame by a modal dialog whilst it is open to block gameplay input
+    pub fn request_modal(&mut self) {
+        self.modal_dialog_requested = true;
+    }
+
+    pub fn update(&mut self, text_input: bool) {
+        self.text_input = text_input;
+        self.modal_dialog = std::mem::take(&mut self.modal_dialog_requested);
+    }
+}
@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use bevy::prelude::Resource;
+
+use crate::events::ToastCategory;
+
+const STREAMER_MODE_CHARACTER_NAME: &str = "Player";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ControlScheme {
+    /// Left click to move and interact, right mouse button rotates the camera
+    Classic,
+
+    /// Both mouse buttons rotate the camera and move with WASD, left click still selects targets
+    Modern,
+}
+
+#[derive(Resource)]
+pub struct InterfaceSettings {
+    pub show_clock: bool,
+    pub clock_use_24_hour: bool,
+
+    /// Hides sensitive information such as other player's names, whispers and server addresses
+    pub streamer_mode: bool,
+
+    /// Slowly orbit the camera around the player after this long without any input
+    pub idle_camera_orbit: Option<Duration>,
+
+    /// Move the player relative to the camera with WASD, in addition to click to move
+    pub wasd_movement: bool,
+
+    /// Which mouse buttons control the camera and whether clicking the ground moves the player
+    pub control_scheme: ControlScheme,
+
+    /// Multiplier for how fast the camera rotates when dragging the mouse
+    pub camera_sensitivity: f32,
+
+    /// Clicks pass through friendly players and NPCs whilst attacking, to target monsters behind them
+    pub combat_click_through: bool,
+
+    /// Show arrows at the edge of the screen pointing towards off-screen monsters attacking the player
+    pub aggro_indicator: bool,
+
+    /// Show a close up view of the selected target rendered by a second camera
+    pub target_camera: bool,
+
+    /// Rotate the minimap with the camera instead of keeping north up
+    pub minimap_rotate: bool,
+
+    /// Show the remaining cooldown in seconds on hotbar and inventory slots
+    pub cooldown_text: bool,
+
+    /// Flash hotbar and inventory slots when their cooldown completes
+    pub cooldown_ready_flash: bool,
+
+    /// Skills used within this long of the current action or cooldown finishing are queued
+    pub skill_queue_window: Duration,
+
+    /// Cast beneficial skills on ourself when the selected target is not a valid target
+    pub auto_self_cast: bool,
+
+    /// Show tutorial hint popups the first time certain events happen for each character
+    pub tutorial_hints: bool,
+
+    /// Mute all audio whilst the window is not focused
+    pub focus_loss_mute_audio: bool,
+
+    /// Limit the frame rate whilst the window is not focused
+    pub focus_loss_reduce_fps: bool,
+
+    /// Cancel auto run and travel waypoints when the window loses focus
+    pub focus_loss_stop_auto_run: bool,
+
+    /// How long announcements scroll across the banner at the top of the screen, or None to
+    /// only show them in the chatbox
+    pub announcement_banner_duration: Option<Duration>,
+
+    /// Also show shout messages in the announcement banner
+    pub shout_banners: bool,
+
+    /// Which categories of toast notification popups are shown
+    pub toast_items: bool,
+    pub toast_quests: bool,
+    pub toast_social: bool,
+    pub toast_connection: bool,
+}
+
+impl Default for InterfaceSettings {
+    fn default() -> Self {
+        Self {
+            show_clock: true,
+            clock_use_24_hour: true,
+            streamer_mode: false,
+            idle_camera_orbit: None,
+            wasd_movement: false,
+            control_scheme: ControlScheme::Classic,
+            camera_sensitivity: 1.0,
+            combat_click_through: false,
+            aggro_indicator: true,
+            target_camera: false,
+            minimap_rotate: false,
+            cooldown_text: true,
+            cooldown_ready_flash: true,
+            skill_queue_window: Duration::from_millis(500),
+            auto_self_cast: true,
+            tutorial_hints: true,
+            focus_loss_mute_audio: false,
+            focus_loss_reduce_fps: true,
+            focus_loss_stop_auto_run: true,
+            announcement_banner_duration: Some(Duration::from_secs(8)),
+            shout_banners: true,
+            toast_items: true,
+            toast_quests: true,
+            toast_social: true,
+            toast_connection: true,
+        }
+    }
+}
+
+impl InterfaceSettings {
+    /// WASD movement is always enabled with the modern control scheme
+    pub fn is_wasd_movement_enabled(&self) -> bool {
+        self.wasd_movement || self.control_scheme == ControlScheme::Modern
+    }
+
+    pub fn is_click_to_move_enabled(&self) -> bool {
+        self.control_scheme == ControlScheme::Classic
+    }
+
+    pub fn is_toast_enabled(&self, category: ToastCategory) -> bool {
+        match category {
+            ToastCategory::Item | ToastCategory::RareItem => self.toast_items,
+            ToastCategory::Quest => self.toast_quests,
+            ToastCategory::Social => self.toast_social,
+            ToastCategory::Connection => self.toast_connection,
+            // Sound captions are controlled by the accessibility settings
+            ToastCategory::SoundCaption => true,
+            // Achievements share the quest toast setting as both are progression
+            ToastCategory::Achievement => self.toast_quests,
+        }
+    }
+
+    /// Returns the name to display for another player's character
+    pub fn other_character_name<'a>(&self, name: &'a str) -> &'a str {
+        if self.streamer_mode {
+            STREAMER_MODE_CHARACTER_NAME
+        } else {
+            name
+        }
+    }
+}
@@ -0,0 +1,499 @@
+use std::collections::BTreeMap;
+
+use bevy::{
+    prelude::{Input, KeyCode, MouseButton, Resource},
+    reflect::{DynamicEnum, DynamicVariant, FromReflect},
+};
+use enum_map::{enum_map, Enum, EnumMap};
+
+#[derive(Copy, Clone, Debug, Enum, PartialEq, Eq)]
+pub enum KeyBindingAction {
+    Help,
+    ToggleDebugUi,
+    MoveForward,
+    MoveBackward,
+    MoveLeft,
+    MoveRight,
+    AutoRun,
+    ToggleTargetLock,
+    ToggleHud,
+    Interact,
+    RotateCamera,
+    ToggleCharacterInfo,
+    ToggleInventory,
+    ToggleSkillList,
+    ToggleQuestList,
+    ToggleClan,
+    ToggleSettings,
+    ToggleAchievements,
+    HotbarSlot1,
+    HotbarSlot2,
+    HotbarSlot3,
+    HotbarSlot4,
+    HotbarSlot5,
+    HotbarSlot6,
+    HotbarSlot7,
+    HotbarSlot8,
+    HotbarPage1,
+    HotbarPage2,
+    HotbarPage3,
+    HotbarPage4,
+    HotbarPage5,
+    HotbarPage6,
+    HotbarPage7,
+    HotbarPage8,
+}
+
+pub const HOTBAR_SLOT_ACTIONS: [KeyBindingAction; 8] = [
+    KeyBindingAction::HotbarSlot1,
+    KeyBindingAction::HotbarSlot2,
+    KeyBindingAction::HotbarSlot3,
+    KeyBindingAction::HotbarSlot4,
+    KeyBindingAction::HotbarSlot5,
+    KeyBindingAction::HotbarSlot6,
+    KeyBindingAction::HotbarSlot7,
+    KeyBindingAction::HotbarSlot8,
+];
+
+pub const HOTBAR_PAGE_ACTIONS: [KeyBindingAction; 8] = [
+    KeyBindingAction::HotbarPage1,
+    KeyBindingAction::HotbarPage2,
+    KeyBindingAction::HotbarPage3,
+    KeyBindingAction::HotbarPage4,
+    KeyBindingAction::HotbarPage5,
+    KeyBindingAction::HotbarPage6,
+    KeyBindingAction::HotbarPage7,
+    KeyBindingAction::HotbarPage8,
+];
+
+impl KeyBindingAction {
+    pub fn description(&self) -> &'static str {
+        match self {
+            KeyBindingAction::Help => "Open game guide",
+            KeyBindingAction::ToggleDebugUi => "Toggle debug menu",
+            KeyBindingAction::MoveForward => "Move forward",
+            KeyBindingAction::MoveBackward => "Move backward",
+            KeyBindingAction::MoveLeft => "Move left",
+            KeyBindingAction::MoveRight => "Move right",
+            KeyBindingAction::AutoRun => "Toggle auto run",
+            KeyBindingAction::ToggleTargetLock => "Lock or unlock the selected target",
+            KeyBindingAction::ToggleHud => "Hide or show the interface",
+            KeyBindingAction::Interact => "Move, attack or interact",
+            KeyBindingAction::RotateCamera => "Hold to rotate camera",
+            KeyBindingAction::ToggleCharacterInfo => "Open character info",
+            KeyBindingAction::ToggleInventory => "Open inventory",
+            KeyBindingAction::ToggleSkillList => "Open skill list",
+            KeyBindingAction::ToggleQuestList => "Open quest list",
+            KeyBindingAction::ToggleClan => "Open clan window",
+            KeyBindingAction::ToggleSettings => "Open settings",
+            KeyBindingAction::ToggleAchievements => "Open achievements",
+            KeyBindingAction::HotbarSlot1 => "Use hotbar slot 1",
+            KeyBindingAction::HotbarSlot2 => "Use hotbar slot 2",
+            KeyBindingAction::HotbarSlot3 => "Use hotbar slot 3",
+            KeyBindingAction::HotbarSlot4 => "Use hotbar slot 4",
+            KeyBindingAction::HotbarSlot5 => "Use hotbar slot 5",
+            KeyBindingAction::HotbarSlot6 => "Use hotbar slot 6",
+            KeyBindingAction::HotbarSlot7 => "Use hotbar slot 7",
+            KeyBindingAction::HotbarSlot8 => "Use hotbar slot 8",
+            KeyBindingAction::HotbarPage1 => "Change to hotbar page 1",
+            KeyBindingAction::HotbarPage2 => "Change to hotbar page 2",
+            KeyBindingAction::HotbarPage3 => "Change to hotbar page 3",
+            KeyBindingAction::HotbarPage4 => "Change to hotbar page 4",
+            KeyBindingAction::HotbarPage5 => "Change to hotbar page 5",
+            KeyBindingAction::HotbarPage6 => "Change to hotbar page 6",
+            KeyBindingAction::HotbarPage7 => "Change to hotbar page 7",
+            KeyBindingAction::HotbarPage8 => "Change to hotbar page 8",
+        }
+    }
+
+    /// The name of the action in the key_bindings section of the config file
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            KeyBindingAction::Help => "help",
+            KeyBindingAction::ToggleDebugUi => "toggle_debug_ui",
+            KeyBindingAction::MoveForward => "move_forward",
+            KeyBindingAction::MoveBackward => "move_backward",
+            KeyBindingAction::MoveLeft => "move_left",
+            KeyBindingAction::MoveRight => "move_right",
+            KeyBindingAction::AutoRun => "auto_run",
+            KeyBindingAction::ToggleTargetLock => "toggle_target_lock",
+            KeyBindingAction::ToggleHud => "toggle_hud",
+            KeyBindingAction::Interact => "interact",
+            KeyBindingAction::RotateCamera => "rotate_camera",
+            KeyBindingAction::ToggleCharacterInfo => "toggle_character_info",
+            KeyBindingAction::ToggleInventory => "toggle_inventory",
+            KeyBindingAction::ToggleSkillList => "toggle_skill_list",
+            KeyBindingAction::ToggleQuestList => "toggle_quest_list",
+            KeyBindingAction::ToggleClan => "toggle_clan",
+            KeyBindingAction::ToggleSettings => "toggle_settings",
+            KeyBindingAction::ToggleAchievements => "toggle_achievements",
+            KeyBindingAction::HotbarSlot1 => "hotbar_slot_1",
+            KeyBindingAction::HotbarSlot2 => "hotbar_slot_2",
+            KeyBindingAction::HotbarSlot3 => "hotbar_slot_3",
+            KeyBindingAction::HotbarSlot4 => "hotbar_slot_4",
+            KeyBindingAction::HotbarSlot5 => "hotbar_slot_5",
+            KeyBindingAction::HotbarSlot6 => "hotbar_slot_6",
+            KeyBindingAction::HotbarSlot7 => "hotbar_slot_7",
+            KeyBindingAction::HotbarSlot8 => "hotbar_slot_8",
+            KeyBindingAction::HotbarPage1 => "hotbar_page_1",
+            KeyBindingAction::HotbarPage2 => "hotbar_page_2",
+            KeyBindingAction::HotbarPage3 => "hotbar_page_3",
+            KeyBindingAction::HotbarPage4 => "hotbar_page_4",
+            KeyBindingAction::HotbarPage5 => "hotbar_page_5",
+            KeyBindingAction::HotbarPage6 => "hotbar_page_6",
+            KeyBindingAction::HotbarPage7 => "hotbar_page_7",
+            KeyBindingAction::HotbarPage8 => "hotbar_page_8",
+        }
+    }
+
+    pub fn from_config_name(name: &str) -> Option<Self> {
+        (0..Self::LENGTH)
+            .map(Self::from_usize)
+            .find(|action| action.config_name() == name)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyBindingInput {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+impl KeyBindingInput {
+    /// Parses the name written by config_name, keys use the name of their KeyCode variant
+    fn from_config_name(name: &str) -> Option<Self> {
+        match name {
+            "MouseLeft" => Some(Self::Mouse(MouseButton::Left)),
+            "MouseRight" => Some(Self::Mouse(MouseButton::Right)),
+            "MouseMiddle" => Some(Self::Mouse(MouseButton::Middle)),
+            name => {
+                if let Some(button) = name
+                    .strip_prefix("Mouse")
+                    .and_then(|button| button.parse::<u16>().ok())
+                {
+                    Some(Self::Mouse(MouseButton::Other(button)))
+                } else {
+                    KeyCode::from_reflect(&DynamicEnum::new(name, DynamicVariant::Unit))
+                        .map(Self::Key)
+                }
+            }
+        }
+    }
+
+    fn config_name(&self) -> String {
+        match self {
+            Self::Key(key) => format!("{:?}", key),
+            Self::Mouse(MouseButton::Left) => "MouseLeft".to_string(),
+            Self::Mouse(MouseButton::Right) => "MouseRight".to_string(),
+            Self::Mouse(MouseButton::Middle) => "MouseMiddle".to_string(),
+            Self::Mouse(MouseButton::Other(button)) => format!("Mouse{}", button),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub input: KeyBindingInput,
+    pub control: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+/// A modifier key which some actions give their own meaning to, such as shift clicking to queue
+/// a waypoint, so it is allowed to be held without being part of the binding
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyModifier {
+    Control,
+    Alt,
+    Shift,
+}
+
+impl KeyBinding {
+    pub const fn new(key: KeyCode) -> Self {
+        Self {
+            input: KeyBindingInput::Key(key),
+            control: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub const fn with_control(key: KeyCode) -> Self {
+        Self {
+            input: KeyBindingInput::Key(key),
+            control: true,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub const fn with_alt(key: KeyCode) -> Self {
+        Self {
+            input: KeyBindingInput::Key(key),
+            control: false,
+            alt: true,
+            shift: false,
+        }
+    }
+
+    pub const fn mouse(button: MouseButton) -> Self {
+        Self {
+            input: KeyBindingInput::Mouse(button),
+            control: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub fn display_name(&self) -> String {
+        let input = match self.input {
+            KeyBindingInput::Key(key) => format!("{:?}", key),
+            KeyBindingInput::Mouse(MouseButton::Other(button)) => format!("Mouse {}", button),
+            KeyBindingInput::Mouse(button) => format!("{:?} Click", button),
+        };
+
+        let mut name = String::new();
+        if self.control {
+            name.push_str("Ctrl + ");
+        }
+        if self.alt {
+            name.push_str("Alt + ");
+        }
+        if self.shift {
+            name.push_str("Shift + ");
+        }
+        name.push_str(&input);
+        name
+    }
+
+    /// Parses a binding from the config file such as "F1", "Ctrl+H" or "MouseLeft"
+    pub fn from_config_value(value: &str) -> Option<Self> {
+        let mut control = false;
+        let mut alt = false;
+        let mut shift = false;
+        let mut input = None;
+
+        for part in value.split('+').map(|part| part.trim()) {
+            match part {
+                "Ctrl" => control = true,
+                "Alt" => alt = true,
+                "Shift" => shift = true,
+                name => input = Some(KeyBindingInput::from_config_name(name)?),
+            }
+        }
+
+        Some(Self {
+            input: input?,
+            control,
+            alt,
+            shift,
+        })
+    }
+
+    pub fn config_value(&self) -> String {
+        let mut value = String::new();
+        if self.control {
+            value.push_str("Ctrl+");
+        }
+        if self.alt {
+            value.push_str("Alt+");
+        }
+        if self.shift {
+            value.push_str("Shift+");
+        }
+        value.push_str(&self.input.config_name());
+        value
+    }
+
+    /// The held modifiers must exactly match the binding, so that W does not also trigger
+    /// when Alt + W is pressed. The ignored modifier may be held when not part of the binding.
+    fn modifiers_match(
+        &self,
+        keyboard_input: &Input<KeyCode>,
+        ignored_modifier: Option<KeyModifier>,
+    ) -> bool {
+        [
+            (
+                KeyModifier::Control,
+                self.control,
+                [KeyCode::ControlLeft, KeyCode::ControlRight],
+            ),
+            (
+                KeyModifier::Alt,
+                self.alt,
+                [KeyCode::AltLeft, KeyCode::AltRight],
+            ),
+            (
+                KeyModifier::Shift,
+                self.shift,
+                [KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            ),
+        ]
+        .into_iter()
+        .all(|(modifier, required, keys)| {
+            keyboard_input.any_pressed(keys) == required
+                || (!required && ignored_modifier == Some(modifier))
+        })
+    }
+
+    pub fn pressed(
+        &self,
+        keyboard_input: &Input<KeyCode>,
+        mouse_input: &Input<MouseButton>,
+    ) -> bool {
+        self.modifiers_match(keyboard_input, None)
+            && match self.input {
+                KeyBindingInput::Key(key) => keyboard_input.pressed(key),
+                KeyBindingInput::Mouse(button) => mouse_input.pressed(button),
+            }
+    }
+
+    pub fn just_pressed(
+        &self,
+        keyboard_input: &Input<KeyCode>,
+        mouse_input: &Input<MouseButton>,
+    ) -> bool {
+        self.just_pressed_with_modifier(None, keyboard_input, mouse_input)
+    }
+
+    /// As just_pressed, but also matches whilst the given modifier is held
+    pub fn just_pressed_with_modifier(
+        &self,
+        modifier: Option<KeyModifier>,
+        keyboard_input: &Input<KeyCode>,
+        mouse_input: &Input<MouseButton>,
+    ) -> bool {
+        self.modifiers_match(keyboard_input, modifier)
+            && match self.input {
+                KeyBindingInput::Key(key) => keyboard_input.just_pressed(key),
+                KeyBindingInput::Mouse(button) => mouse_input.just_pressed(button),
+            }
+    }
+}
+
+/// The keys currently bound to each game action, used both for handling input and for
+/// generating the controls listed in the help window.
+#[derive(Resource)]
+pub struct KeyBindings {
+    /// The bindings in use, the global bindings with any per-character overrides applied
+    pub bindings: EnumMap<KeyBindingAction, KeyBinding>,
+    /// The bindings from the config file, these are what is saved back to the config
+    pub global: EnumMap<KeyBindingAction, KeyBinding>,
+}
+
+fn default_key_bindings() -> EnumMap<KeyBindingAction, KeyBinding> {
+    enum_map! {
+        KeyBindingAction::Help => KeyBinding::new(KeyCode::F10),
+        KeyBindingAction::ToggleDebugUi => KeyBinding::with_control(KeyCode::D),
+        KeyBindingAction::MoveForward => KeyBinding::new(KeyCode::W),
+        KeyBindingAction::MoveBackward => KeyBinding::new(KeyCode::S),
+        KeyBindingAction::MoveLeft => KeyBinding::new(KeyCode::A),
+        KeyBindingAction::MoveRight => KeyBinding::new(KeyCode::D),
+        KeyBindingAction::AutoRun => KeyBinding::new(KeyCode::Numlock),
+        KeyBindingAction::ToggleTargetLock => KeyBinding::new(KeyCode::L),
+        KeyBindingAction::ToggleHud => KeyBinding::with_control(KeyCode::H),
+        KeyBindingAction::Interact => KeyBinding::mouse(MouseButton::Left),
+        KeyBindingAction::RotateCamera => KeyBinding::mouse(MouseButton::Right),
+        KeyBindingAction::ToggleCharacterInfo => KeyBinding::with_alt(KeyCode::A),
+        KeyBindingAction::ToggleInventory => KeyBinding::with_alt(KeyCode::I),
+        KeyBindingAction::ToggleSkillList => KeyBinding::with_alt(KeyCode::S),
+        KeyBindingAction::ToggleQuestList => KeyBinding::with_alt(KeyCode::Q),
+        KeyBindingAction::ToggleClan => KeyBinding::with_alt(KeyCode::N),
+        KeyBindingAction::ToggleSettings => KeyBinding::with_alt(KeyCode::O),
+        KeyBindingAction::ToggleAchievements => KeyBinding::with_alt(KeyCode::J),
+        KeyBindingAction::HotbarSlot1 => KeyBinding::new(KeyCode::F1),
+        KeyBindingAction::HotbarSlot2 => KeyBinding::new(KeyCode::F2),
+        KeyBindingAction::HotbarSlot3 => KeyBinding::new(KeyCode::F3),
+        KeyBindingAction::HotbarSlot4 => KeyBinding::new(KeyCode::F4),
+        KeyBindingAction::HotbarSlot5 => KeyBinding::new(KeyCode::F5),
+        KeyBindingAction::HotbarSlot6 => KeyBinding::new(KeyCode::F6),
+        KeyBindingAction::HotbarSlot7 => KeyBinding::new(KeyCode::F7),
+        KeyBindingAction::HotbarSlot8 => KeyBinding::new(KeyCode::F8),
+        KeyBindingAction::HotbarPage1 => KeyBinding::with_control(KeyCode::Key1),
+        KeyBindingAction::HotbarPage2 => KeyBinding::with_control(KeyCode::Key2),
+        KeyBindingAction::HotbarPage3 => KeyBinding::with_control(KeyCode::Key3),
+        KeyBindingAction::HotbarPage4 => KeyBinding::with_control(KeyCode::Key4),
+        KeyBindingAction::HotbarPage5 => KeyBinding::with_control(KeyCode::Key5),
+        KeyBindingAction::HotbarPage6 => KeyBinding::with_control(KeyCode::Key6),
+        KeyBindingAction::HotbarPage7 => KeyBinding::with_control(KeyCode::Key7),
+        KeyBindingAction::HotbarPage8 => KeyBinding::with_control(KeyCode::Key8),
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let bindings = default_key_bindings();
+        Self {
+            bindings,
+            global: bindings,
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Rebuilds the bindings in use from the global bindings with the per-character overrides,
+    /// which map action names to binding values as in the config file, layered on top
+    pub fn apply_overrides(&mut self, overrides: &BTreeMap<String, String>) {
+        self.bindings = self.global;
+        for (name, value) in overrides.iter() {
+            match (
+                KeyBindingAction::from_config_name(name),
+                KeyBinding::from_config_value(value),
+            ) {
+                (Some(action), Some(binding)) => self.bindings[action] = binding,
+                _ => log::warn!(
+                    "Ignoring invalid character key binding {} = \"{}\"",
+                    name,
+                    value
+                ),
+            }
+        }
+    }
+
+    pub fn get(&self, action: KeyBindingAction) -> &KeyBinding {
+        &self.bindings[action]
+    }
+
+    pub fn pressed(
+        &self,
+        action: KeyBindingAction,
+        keyboard_input: &Input<KeyCode>,
+        mouse_input: &Input<MouseButton>,
+    ) -> bool {
+        self.bindings[action].pressed(keyboard_input, mouse_input)
+    }
+
+    pub fn just_pressed(
+        &self,
+        action: KeyBindingAction,
+        keyboard_input: &Input<KeyCode>,
+        mouse_input: &Input<MouseButton>,
+    ) -> bool {
+        self.bindings[action].just_pressed(keyboard_input, mouse_input)
+    }
+
+    pub fn hotbar_slot_just_pressed(
+        &self,
+        keyboard_input: &Input<KeyCode>,
+        mouse_input: &Input<MouseButton>,
+    ) -> Option<usize> {
+        // Alt may be held to force a skill to be cast on ourself
+        HOTBAR_SLOT_ACTIONS.iter().position(|&action| {
+            self.bindings[action].just_pressed_with_modifier(
+                Some(KeyModifier::Alt),
+                keyboard_input,
+                mouse_input,
+            )
+        })
+    }
+
+    pub fn hotbar_page_just_pressed(
+        &self,
+        keyboard_input: &Input<KeyCode>,
+        mouse_input: &Input<MouseButton>,
+    ) -> Option<usize> {
+        HOTBAR_PAGE_ACTIONS
+            .iter()
+            .position(|&action| self.just_pressed(action, keyboard_input, mouse_input))
+    }
+}
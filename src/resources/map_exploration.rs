@@ -0,0 +1,96 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use bevy::{math::Vec2, prelude::Resource};
+use serde::{Deserialize, Serialize};
+
+use rose_data::ZoneId;
+
+use super::profile_storage::{load_toml, save_toml_compact};
+
+const MAP_EXPLORATION_FILE: &str = "exploration.toml";
+
+/// Size of an exploration cell in world units (cm), a zone is 256 x 256 cells
+pub const MAP_EXPLORATION_CELL_SIZE: f32 = 4000.0;
+const MAP_EXPLORATION_NUM_CELLS: i32 = 256;
+
+/// Areas of each zone which the player has visited, stored per character in the same profile
+/// directory as the character settings.
+#[derive(Default, Resource, Deserialize, Serialize)]
+#[serde(default)]
+pub struct MapExploration {
+    #[serde(skip)]
+    pub path: PathBuf,
+    #[serde(skip)]
+    pub modified: bool,
+    /// Explored cell indices keyed by zone id
+    pub zones: BTreeMap<String, BTreeSet<u32>>,
+}
+
+impl MapExploration {
+    pub fn load(profile_directory: &Path) -> Self {
+        let path = profile_directory.join(MAP_EXPLORATION_FILE);
+        let mut exploration: MapExploration = load_toml(&path, "map exploration");
+        exploration.path = path;
+        exploration
+    }
+
+    pub fn save(&mut self) {
+        if self.path.as_os_str().is_empty() || !self.modified {
+            return;
+        }
+
+        if save_toml_compact(&self.path, self, "map exploration") {
+            self.modified = false;
+        }
+    }
+
+    /// Returns the cell containing a world position (in cm)
+    pub fn get_cell(position: Vec2) -> (i32, i32) {
+        (
+            (position.x / MAP_EXPLORATION_CELL_SIZE).floor() as i32,
+            (position.y / MAP_EXPLORATION_CELL_SIZE).floor() as i32,
+        )
+    }
+
+    fn get_cell_index(cell_x: i32, cell_y: i32) -> Option<u32> {
+        if (0..MAP_EXPLORATION_NUM_CELLS).contains(&cell_x)
+            && (0..MAP_EXPLORATION_NUM_CELLS).contains(&cell_y)
+        {
+            Some((cell_x + cell_y * MAP_EXPLORATION_NUM_CELLS) as u32)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_explored_cells(&self, zone_id: ZoneId) -> Option<&BTreeSet<u32>> {
+        self.zones.get(&zone_id.get().to_string())
+    }
+
+    pub fn is_explored(explored_cells: &BTreeSet<u32>, cell_x: i32, cell_y: i32) -> bool {
+        Self::get_cell_index(cell_x, cell_y).map_or(false, |index| explored_cells.contains(&index))
+    }
+
+    /// Marks every cell within radius of the position as explored
+    pub fn explore(&mut self, zone_id: ZoneId, position: Vec2, radius: f32) {
+        let explored_cells = self.zones.entry(zone_id.get().to_string()).or_default();
+        let (min_x, min_y) = Self::get_cell(position - Vec2::splat(radius));
+        let (max_x, max_y) = Self::get_cell(position + Vec2::splat(radius));
+
+        for cell_y in min_y..=max_y {
+            for cell_x in min_x..=max_x {
+                let Some(index) = Self::get_cell_index(cell_x, cell_y) else {
+                    continue;
+                };
+
+                let cell_center = (Vec2::new(cell_x as f32, cell_y as f32) + Vec2::splat(0.5))
+                    * MAP_EXPLORATION_CELL_SIZE;
+                if cell_center.distance(position) <= radius && explored_cells.insert(index) {
+                    self.modified = true;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::{ops::Range, time::Duration};
+
+use bevy::{math::Vec2, prelude::Resource};
+use rose_data::ZoneId;
+
+/// How long a ping marker flashes on the minimap and in the world
+pub const MAP_PING_DURATION: Duration = Duration::from_secs(6);
+
+const MAP_PING_PREFIX: &str = "[Ping ";
+const MAP_PING_SUFFIX: &str = "]";
+
+#[derive(Clone, Copy)]
+pub struct MapPing {
+    pub zone_id: ZoneId,
+    /// World position in cm
+    pub position: Vec2,
+    pub started: Duration,
+}
+
+/// Locations which were pinged through chat and are currently being flashed
+#[derive(Default, Resource)]
+pub struct MapPings {
+    pub pings: Vec<MapPing>,
+}
+
+impl MapPings {
+    /// Formats a position as chat text which can be parsed back with `find_chat_pings`,
+    /// positions are written in zone coordinates to match the minimap readout.
+    pub fn chat_text(zone_id: ZoneId, position: Vec2) -> String {
+        format!(
+            "{}{}: {}, {}{}",
+            MAP_PING_PREFIX,
+            zone_id.get(),
+            (position.x / 100.0) as i32,
+            (position.y / 100.0) as i32,
+            MAP_PING_SUFFIX
+        )
+    }
+
+    /// Returns the byte range, zone and world position of every ping in a chat message
+    pub fn find_chat_pings(text: &str) -> Vec<(Range<usize>, ZoneId, Vec2)> {
+        let mut pings = Vec::new();
+        let mut search_start = 0;
+
+        while let Some(offset) = text[search_start..].find(MAP_PING_PREFIX) {
+            let start = search_start + offset;
+            let args_start = start + MAP_PING_PREFIX.len();
+            let Some(args_len) = text[args_start..].find(MAP_PING_SUFFIX) else {
+                break;
+            };
+            let end = args_start + args_len + MAP_PING_SUFFIX.len();
+            search_start = end;
+
+            let Some((zone, coordinates)) = text[args_start..args_start + args_len].split_once(':')
+            else {
+                continue;
+            };
+            let Some((x, y)) = coordinates.split_once(',') else {
+                continue;
+            };
+            let (Some(zone_id), Ok(x), Ok(y)) = (
+                zone.trim().parse::<u16>().ok().and_then(ZoneId::new),
+                x.trim().parse::<i32>(),
+                y.trim().parse::<i32>(),
+            ) else {
+                continue;
+            };
+
+            pings.push((
+                start..end,
+                zone_id,
+                Vec2::new(x as f32 * 100.0, y as f32 * 100.0),
+            ));
+        }
+
+        pings
+    }
+
+    pub fn add(&mut self, zone_id: ZoneId, position: Vec2, now: Duration) {
+        // Clicking the same ping again restarts it rather than stacking markers
+        self.pings
+            .retain(|ping| ping.zone_id != zone_id || ping.position != position);
+        self.pings.push(MapPing {
+            zone_id,
+            position,
+            started: now,
+        });
+    }
+
+    pub fn update(&mut self, now: Duration) {
+        self.pings
+            .retain(|ping| now.saturating_sub(ping.started) < MAP_PING_DURATION);
+    }
+
+    /// Pulses between 0 and 1 a few times a second for the flashing marker
+    pub fn flash(ping: &MapPing, now: Duration) -> f32 {
+        let elapsed = now.saturating_sub(ping.started).as_secs_f32();
+        0.5 + 0.5 * (elapsed * std::f32::consts::TAU * 1.5).cos()
+    }
+}
@@ -1,52 +1,116 @@
+mod accessibility_settings;
 mod account;
 mod app_state;
+mod auto_travel;
+mod away_state;
+mod background_music;
+mod bug_report_configuration;
+mod channel_queue;
+mod channel_switch;
 mod character_list;
 mod character_select_state;
+mod character_settings;
+mod character_titles;
 mod client_entity_list;
+mod config_file;
 mod current_zone;
 mod damage_digits_spawner;
+mod data_overlay_configuration;
 mod debug_inspector;
 mod debug_render;
+mod display_settings;
+mod drop_notification_settings;
+mod effect_settings;
+mod fishing;
 mod game_connection;
 mod game_data;
+mod headless_script;
+mod hud_settings;
+mod ignore_list;
+mod input_focus;
+mod interface_settings;
+mod key_bindings;
 mod login_connection;
 mod login_state;
+mod map_exploration;
+mod map_pings;
 mod name_tag_cache;
 mod name_tag_settings;
 mod network_thread;
+mod player_command_buffer;
+mod profile_storage;
 mod render_configuration;
+mod request_settings;
 mod selected_target;
 mod server_configuration;
 mod server_list;
+mod server_profiles;
+mod session_stats;
+mod sit_points;
+mod skill_queue;
 mod sound_cache;
 mod sound_settings;
 mod specular_texture;
 mod ui_resources;
+mod unstick;
 mod virtual_filesystem;
 mod world_connection;
 mod world_rates;
 mod world_time;
+mod zone_load_tracker;
 mod zone_time;
 
+pub use accessibility_settings::{AccessibilitySettings, ColorPalette};
 pub use account::Account;
 pub use app_state::AppState;
+pub use auto_travel::AutoTravel;
+pub use away_state::{AwayState, AWAY_AUTO_REPLY_COOLDOWN};
+pub use background_music::{BackgroundMusic, BackgroundMusicTrack};
+pub use bug_report_configuration::BugReportConfiguration;
+pub use channel_queue::{ChannelQueue, CHANNEL_QUEUE_RETRY_DELAY};
+pub use channel_switch::{ChannelSwitch, ChannelSwitchStage, CHANNEL_SWITCH_TIMEOUT};
 pub use character_list::CharacterList;
 pub use character_select_state::CharacterSelectState;
+pub use character_settings::{
+    AchievementSettings, CharacterSettings, ChatChannelFilter, ChatMacro, ChatSettings,
+    HotbarSettings, WindowLayoutSettings,
+};
+pub use character_titles::CharacterTitles;
 pub use client_entity_list::ClientEntityList;
+pub use config_file::{config_table_mut, ConfigFile, DEFAULT_CONFIG_PATH};
 pub use current_zone::CurrentZone;
-pub use damage_digits_spawner::DamageDigitsSpawner;
+pub use damage_digits_spawner::{ActiveDamageDigits, DamageDigitsSpawner};
+pub use data_overlay_configuration::DataOverlayConfiguration;
 pub use debug_inspector::DebugInspector;
 pub use debug_render::DebugRenderConfig;
-pub use game_connection::GameConnection;
+pub use display_settings::{DisplayMode, DisplaySettings, DISPLAY_RESOLUTIONS};
+pub use drop_notification_settings::DropNotificationSettings;
+pub use effect_settings::{EffectSettings, SCREEN_COVERING_SKILL_SCOPE};
+pub use fishing::{Fishing, FishingPhase};
+pub use game_connection::{teardown_game_connection, GameConnection};
 pub use game_data::GameData;
+pub use headless_script::{HeadlessScript, HeadlessScriptPacket, HeadlessScriptStep};
+pub use hud_settings::{hud_visible, HudSettings, HudWidget};
+pub use ignore_list::IgnoreList;
+pub use input_focus::InputFocus;
+pub use interface_settings::{ControlScheme, InterfaceSettings};
+pub use key_bindings::{KeyBinding, KeyBindingAction, KeyBindingInput, KeyBindings, KeyModifier};
 pub use login_connection::LoginConnection;
 pub use login_state::LoginState;
-pub use name_tag_settings::NameTagSettings;
+pub use map_exploration::{MapExploration, MAP_EXPLORATION_CELL_SIZE};
+pub use map_pings::{MapPing, MapPings, MAP_PING_DURATION};
+pub use name_tag_settings::{NameTagHealthbarCategory, NameTagHealthbarPolicy, NameTagSettings};
 pub use network_thread::{run_network_thread, NetworkThread, NetworkThreadMessage};
-pub use render_configuration::RenderConfiguration;
+pub use player_command_buffer::{BufferedPlayerCommand, PlayerCommandBuffer};
+pub use render_configuration::{RenderConfiguration, RenderPreset, ShadowQuality, WaterQuality};
+pub use request_settings::{RequestCategory, RequestSettings};
 pub use selected_target::SelectedTarget;
 pub use server_configuration::ServerConfiguration;
 pub use server_list::{ServerList, ServerListGameServer, ServerListWorldServer};
+pub use server_profiles::{ServerProfile, ServerProfiles};
+pub use session_stats::SessionStats;
+pub use sit_points::{PendingSitPoint, SitPointDatabase, SitPointTarget};
+pub use skill_queue::SkillQueue;
 pub use sound_cache::SoundCache;
 pub use sound_settings::SoundSettings;
 pub use specular_texture::SpecularTexture;
@@ -54,8 +118,10 @@ pub use ui_resources::{
     load_ui_resources, ui_requested_cursor_apply_system, update_ui_resources, UiCursorType,
     UiRequestedCursor, UiResources, UiSprite, UiSpriteSheet, UiSpriteSheetType, UiTexture,
 };
+pub use unstick::{Unstick, STUCK_DETECTION_DURATION, UNSTICK_COOLDOWN};
 pub use virtual_filesystem::VfsResource;
 pub use world_connection::WorldConnection;
 pub use world_rates::WorldRates;
 pub use world_time::WorldTime;
+pub use zone_load_tracker::ZoneLoadTracker;
 pub use zone_time::{ZoneTime, ZoneTimeState};
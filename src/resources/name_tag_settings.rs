@@ -1,12 +1,33 @@
 use bevy::prelude::Resource;
-use enum_map::{enum_map, EnumMap};
+use enum_map::{enum_map, Enum, EnumMap};
 
 use crate::components::NameTagType;
 
+#[derive(Copy, Clone, Debug, Enum)]
+pub enum NameTagHealthbarCategory {
+    PartyMember,
+    Pet,
+    Character,
+    Monster,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NameTagHealthbarPolicy {
+    Always,
+    InCombat,
+    Never,
+}
+
 #[derive(Resource)]
 pub struct NameTagSettings {
     pub show_all: EnumMap<NameTagType, bool>,
     pub font_size: EnumMap<NameTagType, f32>,
+
+    /// When to show health and mana bars, bars are always visible on the selected target
+    pub healthbar_policy: EnumMap<NameTagHealthbarCategory, NameTagHealthbarPolicy>,
+
+    /// Maximum distance from the player at which health and mana bars are shown
+    pub healthbar_distance: EnumMap<NameTagHealthbarCategory, f32>,
 }
 
 impl Default for NameTagSettings {
@@ -22,6 +43,18 @@ impl Default for NameTagSettings {
                 NameTagType::Npc => 16.0,
                 NameTagType::Monster => 16.0,
             },
+            healthbar_policy: enum_map! {
+                NameTagHealthbarCategory::PartyMember => NameTagHealthbarPolicy::Always,
+                NameTagHealthbarCategory::Pet => NameTagHealthbarPolicy::Always,
+                NameTagHealthbarCategory::Character => NameTagHealthbarPolicy::Never,
+                NameTagHealthbarCategory::Monster => NameTagHealthbarPolicy::Never,
+            },
+            healthbar_distance: enum_map! {
+                NameTagHealthbarCategory::PartyMember => 10000.0,
+                NameTagHealthbarCategory::Pet => 3000.0,
+                NameTagHealthbarCategory::Character => 3000.0,
+                NameTagHealthbarCategory::Monster => 3000.0,
+            },
         }
     }
 }
@@ -31,7 +31,9 @@ pub fn run_network_thread(
                     match control_rx.recv().await {
                         Some(NetworkThreadMessage::RunProtocolClient(mut client)) => {
                             tokio::spawn(async move {
-                                client.run_connection().await.ok();
+                                if let Err(error) = client.run_connection().await {
+                                    log::info!("Connection closed: {}", error);
+                                }
                             });
                         }
                         Some(NetworkThreadMessage::Exit) => return,
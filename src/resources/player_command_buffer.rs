@@ -0,0 +1,35 @@
+use bevy::{math::Vec3, prelude::Resource};
+
+use crate::events::PlayerCommandEvent;
+
+/// Maximum number of commands held while a zone is loading, older commands are dropped first
+const MAX_BUFFERED_PLAYER_COMMANDS: usize = 8;
+
+pub struct BufferedPlayerCommand {
+    pub event: PlayerCommandEvent,
+    /// Player position when the command was issued, used to translate movement targets
+    pub player_position: Vec3,
+}
+
+/// Player commands issued whilst the zone is loading, they are replayed once the zone has loaded
+#[derive(Default, Resource)]
+pub struct PlayerCommandBuffer {
+    pub commands: Vec<BufferedPlayerCommand>,
+}
+
+impl PlayerCommandBuffer {
+    pub fn push(&mut self, event: PlayerCommandEvent, player_position: Vec3) {
+        if self.commands.len() >= MAX_BUFFERED_PLAYER_COMMANDS {
+            self.commands.remove(0);
+        }
+
+        self.commands.push(BufferedPlayerCommand {
+            event,
+            player_position,
+        });
+    }
+
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+}
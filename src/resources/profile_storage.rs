@@ -0,0 +1,67 @@
+use std::path::Path;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Replaces anything which is not alphanumeric so server addresses and character or account
+/// names can be used as directory names
+pub fn sanitise_path_component(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Reads a TOML file, a missing or invalid file gives the default value. The description is
+/// only used for the error log, e.g. "character settings".
+pub fn load_toml<T: Default + DeserializeOwned>(path: &Path, description: &str) -> T {
+    match std::fs::read_to_string(path) {
+        Ok(toml_str) => match toml::from_str::<T>(&toml_str) {
+            Ok(value) => value,
+            Err(error) => {
+                log::error!(
+                    "Failed to parse {} from {} with error: {}",
+                    description,
+                    path.to_string_lossy(),
+                    error
+                );
+                T::default()
+            }
+        },
+        Err(_) => T::default(),
+    }
+}
+
+/// Writes a TOML file, creating its directory if needed. Returns true if the file was saved.
+pub fn save_toml<T: Serialize>(path: &Path, value: &T, description: &str) -> bool {
+    write_toml(path, toml::to_string_pretty(value), description)
+}
+
+/// As save_toml but without pretty printing, for large files such as long lists of numbers
+pub fn save_toml_compact<T: Serialize>(path: &Path, value: &T, description: &str) -> bool {
+    write_toml(path, toml::to_string(value), description)
+}
+
+fn write_toml(path: &Path, toml_str: Result<String, toml::ser::Error>, description: &str) -> bool {
+    let toml_str = match toml_str {
+        Ok(toml_str) => toml_str,
+        Err(error) => {
+            log::error!("Failed to serialise {}: {}", description, error);
+            return false;
+        }
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+
+    if let Err(error) = std::fs::write(path, toml_str) {
+        log::error!(
+            "Failed to save {} to {} with error: {}",
+            description,
+            path.to_string_lossy(),
+            error
+        );
+        return false;
+    }
+
+    true
+}
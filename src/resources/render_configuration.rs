@@ -1,7 +1,93 @@
 use bevy::prelude::Resource;
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShadowQuality {
+    Off,
+    /// Cheap blob shadow decals under characters and monsters
+    Blob,
+    /// Shadow maps from the directional light
+    ShadowMap,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WaterQuality {
+    /// Water texture animation steps between frames
+    Low,
+    /// Water texture animation blends between frames
+    High,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RenderPreset {
+    Low,
+    Medium,
+    High,
+    Ultra,
+    /// Individual options have been changed from a preset
+    Custom,
+}
+
 #[derive(Resource)]
 pub struct RenderConfiguration {
+    pub preset: RenderPreset,
     pub passthrough_terrain_textures: bool,
     pub trail_effect_duration_multiplier: f32,
+    /// Draw trails behind melee weapons during attack swings
+    pub weapon_trails: bool,
+    /// Terrain blocks further than this from the camera are replaced by the coarse far
+    /// terrain, `None` draws every block at full detail.
+    pub terrain_view_distance: Option<f32>,
+    /// Only zone blocks within this distance of the player are spawned, with blocks further
+    /// away despawned again, `None` spawns every block of the zone.
+    pub zone_block_streaming_distance: Option<f32>,
+    pub shadow_quality: ShadowQuality,
+    /// Fraction of each particle emitter's maximum particles which are allowed to be alive
+    pub particle_density: f32,
+    pub water_quality: WaterQuality,
+    pub bloom: bool,
+    /// Projectiles stop and spawn their hit effect where they hit walls or terrain
+    pub projectile_collision: bool,
+}
+
+impl RenderConfiguration {
+    /// Sets every option covered by the preset, options not covered by presets are unchanged.
+    pub fn apply_preset(&mut self, preset: RenderPreset) {
+        self.preset = preset;
+
+        match preset {
+            RenderPreset::Low => {
+                self.shadow_quality = ShadowQuality::Off;
+                self.terrain_view_distance = Some(640.0);
+                self.particle_density = 0.25;
+                self.water_quality = WaterQuality::Low;
+                self.bloom = false;
+                self.weapon_trails = false;
+            }
+            RenderPreset::Medium => {
+                self.shadow_quality = ShadowQuality::Blob;
+                self.terrain_view_distance = Some(960.0);
+                self.particle_density = 0.5;
+                self.water_quality = WaterQuality::Low;
+                self.bloom = false;
+                self.weapon_trails = true;
+            }
+            RenderPreset::High => {
+                self.shadow_quality = ShadowQuality::ShadowMap;
+                self.terrain_view_distance = Some(1280.0);
+                self.particle_density = 1.0;
+                self.water_quality = WaterQuality::High;
+                self.bloom = true;
+                self.weapon_trails = true;
+            }
+            RenderPreset::Ultra => {
+                self.shadow_quality = ShadowQuality::ShadowMap;
+                self.terrain_view_distance = None;
+                self.particle_density = 1.0;
+                self.water_quality = WaterQuality::High;
+                self.bloom = true;
+                self.weapon_trails = true;
+            }
+            RenderPreset::Custom => {}
+        }
+    }
 }
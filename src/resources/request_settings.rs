@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use bevy::prelude::Resource;
+use enum_map::{Enum, EnumMap};
+
+#[derive(Copy, Clone, Debug, Enum, PartialEq, Eq)]
+pub enum RequestCategory {
+    Party,
+    Trade,
+    Duel,
+}
+
+impl RequestCategory {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RequestCategory::Party => "Party",
+            RequestCategory::Trade => "Trade",
+            RequestCategory::Duel => "Duel",
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct RequestSettings {
+    /// Requests which are not answered within this duration are declined
+    pub timeout: Duration,
+
+    /// Automatically decline requests whilst away, fighting or running a personal store
+    pub decline_while_busy: EnumMap<RequestCategory, bool>,
+
+    /// Automatically decline requests from players who are not in our clan
+    pub decline_from_strangers: EnumMap<RequestCategory, bool>,
+}
@@ -4,4 +4,7 @@ use bevy::prelude::{Entity, Resource};
 pub struct SelectedTarget {
     pub selected: Option<Entity>,
     pub hover: Option<Entity>,
+
+    /// Prevents clicking on another entity from changing the selected target
+    pub locked: bool,
 }
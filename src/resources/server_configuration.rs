@@ -1,13 +1,14 @@
 use bevy::prelude::Resource;
+use std::time::Duration;
 
 #[derive(Resource)]
 pub struct ServerConfiguration {
-    pub ip: String,
-    pub port: String,
     pub preset_username: Option<String>,
     pub preset_password: Option<String>,
     pub preset_server_id: Option<usize>,
     pub preset_channel_id: Option<usize>,
     pub preset_character_name: Option<String>,
     pub auto_login: bool,
+    /// Used for protocols with keep-alive packets, so the server always has something to reply to
+    pub connection_timeout: Option<Duration>,
 }
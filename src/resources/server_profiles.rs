@@ -0,0 +1,137 @@
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use super::profile_storage::{load_toml, save_toml};
+
+const KEYRING_SERVICE: &str = "rose-offline-client";
+
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ServerProfile {
+    pub name: String,
+    pub ip: String,
+    pub port: u16,
+    pub network_version: String,
+    pub username: String,
+    pub remember_me: bool,
+    /// The server supports the packets for features which are not part of the network protocol,
+    /// such as fishing, limited store stock and character titles
+    pub extensions: bool,
+}
+
+impl Default for ServerProfile {
+    fn default() -> Self {
+        Self {
+            name: "Default".into(),
+            ip: "127.0.0.1".into(),
+            port: 29000,
+            network_version: "irose".into(),
+            username: String::new(),
+            remember_me: false,
+            extensions: false,
+        }
+    }
+}
+
+impl ServerProfile {
+    fn keyring_entry(&self) -> Option<keyring::Entry> {
+        if self.username.is_empty() {
+            return None;
+        }
+
+        keyring::Entry::new(
+            KEYRING_SERVICE,
+            &format!("{}@{}:{}", self.username, self.ip, self.port),
+        )
+        .ok()
+    }
+
+    pub fn load_password(&self) -> Option<String> {
+        self.keyring_entry()?.get_password().ok()
+    }
+
+    pub fn store_password(&self, password: &str) {
+        if let Some(entry) = self.keyring_entry() {
+            if let Err(error) = entry.set_password(password) {
+                log::warn!("Failed to store password in OS keyring: {}", error);
+            }
+        }
+    }
+
+    pub fn delete_password(&self) {
+        if let Some(entry) = self.keyring_entry() {
+            entry.delete_password().ok();
+        }
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+struct ServerProfilesFile {
+    selected: Option<String>,
+    #[serde(rename = "server")]
+    servers: Vec<ServerProfile>,
+}
+
+#[derive(Resource)]
+pub struct ServerProfiles {
+    pub path: PathBuf,
+    pub profiles: Vec<ServerProfile>,
+    pub selected: usize,
+}
+
+impl ServerProfiles {
+    pub fn load(path: &Path) -> Self {
+        let file: ServerProfilesFile = load_toml(path, "server profiles");
+
+        let selected = file
+            .selected
+            .as_ref()
+            .and_then(|name| file.servers.iter().position(|server| &server.name == name))
+            .unwrap_or(0);
+
+        Self {
+            path: path.into(),
+            profiles: file.servers,
+            selected,
+        }
+    }
+
+    pub fn save(&self) {
+        let file = ServerProfilesFile {
+            selected: self.selected().map(|profile| profile.name.clone()),
+            servers: self.profiles.clone(),
+        };
+
+        save_toml(&self.path, &file, "server profiles");
+    }
+
+    pub fn selected(&self) -> Option<&ServerProfile> {
+        self.profiles.get(self.selected)
+    }
+
+    pub fn selected_mut(&mut self) -> Option<&mut ServerProfile> {
+        self.profiles.get_mut(self.selected)
+    }
+
+    /// Selects the profile matching the given address, adding a new profile if one does not exist
+    pub fn select_address(&mut self, ip: &str, port: u16) {
+        if let Some(index) = self
+            .profiles
+            .iter()
+            .position(|profile| profile.ip == ip && profile.port == port)
+        {
+            self.selected = index;
+        } else {
+            self.profiles.push(ServerProfile {
+                name: format!("{}:{}", ip, port),
+                ip: ip.into(),
+                port,
+                ..Default::default()
+            });
+            self.selected = self.profiles.len() - 1;
+        }
+    }
+}
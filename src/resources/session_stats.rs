@@ -0,0 +1,92 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bevy::prelude::Resource;
+
+/// Statistics for the current play session, reset when entering the game or manually from the
+/// session stats window.
+#[derive(Default, Resource)]
+pub struct SessionStats {
+    pub start_time: Duration,
+    pub xp_gained: u64,
+    pub zuly_earned: u64,
+    pub zuly_spent: u64,
+    pub deaths: u32,
+    /// Count of each item looted or rewarded, keyed by item name
+    pub items_looted: BTreeMap<String, u32>,
+    /// Count of each monster killed by the player, keyed by monster name
+    pub monsters_killed: BTreeMap<String, u32>,
+    /// Money in the player inventory when last checked, used to track earned and spent zuly
+    pub last_money: Option<i64>,
+}
+
+impl SessionStats {
+    pub fn reset(&mut self, now: Duration) {
+        *self = Self {
+            start_time: now,
+            last_money: self.last_money,
+            ..Default::default()
+        };
+    }
+
+    pub fn update_money(&mut self, money: i64) {
+        if let Some(last_money) = self.last_money {
+            if money > last_money {
+                self.zuly_earned += (money - last_money) as u64;
+            } else {
+                self.zuly_spent += (last_money - money) as u64;
+            }
+        }
+        self.last_money = Some(money);
+    }
+
+    pub fn duration(&self, now: Duration) -> Duration {
+        now.saturating_sub(self.start_time)
+    }
+
+    pub fn to_csv(&self, now: Duration) -> String {
+        let escape = |value: &str| -> String {
+            if value.contains(',') || value.contains('"') {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut csv = String::from("Category,Name,Value\n");
+        writeln!(
+            csv,
+            "Session,Duration (seconds),{}",
+            self.duration(now).as_secs()
+        )
+        .ok();
+        writeln!(csv, "Session,XP Gained,{}", self.xp_gained).ok();
+        writeln!(csv, "Session,Zuly Earned,{}", self.zuly_earned).ok();
+        writeln!(csv, "Session,Zuly Spent,{}", self.zuly_spent).ok();
+        writeln!(csv, "Session,Deaths,{}", self.deaths).ok();
+        for (name, count) in self.monsters_killed.iter() {
+            writeln!(csv, "Monster Killed,{},{}", escape(name), count).ok();
+        }
+        for (name, count) in self.items_looted.iter() {
+            writeln!(csv, "Item Looted,{},{}", escape(name), count).ok();
+        }
+        csv
+    }
+
+    /// Writes the statistics as a csv file into the given directory, returns the path of the
+    /// written file.
+    pub fn export_csv(&self, directory: &Path, now: Duration) -> std::io::Result<PathBuf> {
+        let path = directory.join(format!(
+            "session_stats_{}.csv",
+            chrono::Local::now().format("%Y%m%d_%H%M%S")
+        ));
+
+        std::fs::create_dir_all(directory)?;
+        std::fs::write(&path, self.to_csv(now))?;
+        Ok(path)
+    }
+}
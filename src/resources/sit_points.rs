@@ -0,0 +1,99 @@
+use std::{collections::HashMap, time::Duration};
+
+use bevy::{
+    math::{Vec2, Vec3},
+    prelude::Resource,
+};
+use serde::Deserialize;
+
+use rose_file_readers::{VfsFile, VirtualFilesystem};
+
+use crate::components::SitPoint;
+
+/// Optional supplementary data file listing which deco objects can be sat on, each
+/// `[[object]]` has a `zsc` path, `object_id` and one or more `[[object.point]]` entries.
+const SIT_POINTS_PATH: &str = "3DDATA/SUPPLEMENTARY/SIT_POINTS.TOML";
+
+#[derive(Deserialize)]
+struct SitPointFileEntry {
+    /// Offset in cm from the object origin as x, y, height
+    offset: [f32; 3],
+    /// Facing direction in degrees, 0 faces along the object's x axis
+    #[serde(default)]
+    facing: f32,
+}
+
+#[derive(Deserialize)]
+struct SitPointFileObject {
+    zsc: String,
+    object_id: usize,
+    #[serde(rename = "point")]
+    points: Vec<SitPointFileEntry>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct SitPointFile {
+    #[serde(rename = "object")]
+    objects: Vec<SitPointFileObject>,
+}
+
+#[derive(Default)]
+pub struct SitPointDatabase {
+    objects: HashMap<(String, usize), Vec<SitPoint>>,
+}
+
+fn normalise_zsc_path(path: &str) -> String {
+    path.replace('\\', "/").to_uppercase()
+}
+
+impl SitPointDatabase {
+    pub fn load(vfs: &VirtualFilesystem) -> Result<Self, anyhow::Error> {
+        let toml_str = match vfs.open_file(SIT_POINTS_PATH)? {
+            VfsFile::Buffer(buffer) => String::from_utf8(buffer)?,
+            VfsFile::View(view) => String::from_utf8(view.into())?,
+        };
+        let file = toml::from_str::<SitPointFile>(&toml_str)?;
+
+        Ok(Self {
+            objects: file
+                .objects
+                .into_iter()
+                .map(|object| {
+                    (
+                        (normalise_zsc_path(&object.zsc), object.object_id),
+                        object
+                            .points
+                            .into_iter()
+                            .map(|point| SitPoint {
+                                offset: Vec3::from(point.offset),
+                                facing: point.facing.to_radians(),
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    pub fn get(&self, zsc_path: &str, zsc_object_id: usize) -> Option<&[SitPoint]> {
+        self.objects
+            .get(&(normalise_zsc_path(zsc_path), zsc_object_id))
+            .map(|points| points.as_slice())
+    }
+}
+
+/// Sit point the player is currently walking towards, once reached the player sits down
+#[derive(Clone, Copy)]
+pub struct PendingSitPoint {
+    /// World position in cm
+    pub position: Vec3,
+    pub facing: Vec2,
+    pub started: Duration,
+    pub has_moved: bool,
+}
+
+#[derive(Default, Resource)]
+pub struct SitPointTarget {
+    pub pending: Option<PendingSitPoint>,
+}
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use bevy::prelude::Resource;
+
+use rose_game_common::components::SkillSlot;
+
+/// A skill which was used while the player was still busy, it will be used as soon as
+/// the current command and cooldowns allow
+#[derive(Default, Resource)]
+pub struct SkillQueue {
+    pub skill_slot: Option<SkillSlot>,
+    pub self_cast: bool,
+    pub expire_time: Duration,
+}
+
+impl SkillQueue {
+    pub fn queue(&mut self, skill_slot: SkillSlot, self_cast: bool, expire_time: Duration) {
+        self.skill_slot = Some(skill_slot);
+        self.self_cast = self_cast;
+        self.expire_time = expire_time;
+    }
+
+    pub fn clear(&mut self) {
+        self.skill_slot = None;
+    }
+}
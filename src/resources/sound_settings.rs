@@ -8,11 +8,16 @@ pub struct SoundSettings {
     pub enabled: bool,
     pub global_gain: f32,
     pub gains: EnumMap<SoundCategory, f32>,
+    /// Seconds taken to cross fade between background music tracks
+    pub music_cross_fade: f32,
+
+    /// Temporarily silence all sounds without changing the configured volumes
+    pub muted: bool,
 }
 
 impl SoundSettings {
     pub fn gain(&self, category: SoundCategory) -> SoundGain {
-        if self.enabled {
+        if self.enabled && !self.muted {
             SoundGain::Ratio(self.global_gain * self.gains[category])
         } else {
             SoundGain::Ratio(0.0)
@@ -41,6 +41,15 @@ impl UiSprite {
     }
 }
 
+/// Sprite names in UI_STRID.ID for the job class icons, indexed by job / 100
+const JOB_ICON_SPRITE_NAMES: [&str; 5] = [
+    "UI24_CLASS_VISITOR",
+    "UI24_CLASS_SOLDIER",
+    "UI24_CLASS_MUSE",
+    "UI24_CLASS_HAWKER",
+    "UI24_CLASS_DEALER",
+];
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Enum)]
 pub enum UiSpriteSheetType {
     Ui,
@@ -84,6 +93,7 @@ pub enum UiCursorType {
     NoUi,
     Repair,
     Appraisal,
+    Warp,
 }
 
 #[derive(Default, Clone)]
@@ -231,6 +241,12 @@ impl UiResources {
         Some(&texture.handle)
     }
 
+    /// The job class icon, or None if the UI data does not have one in which case only the job
+    /// name is shown
+    pub fn get_job_icon(&self, job: u16) -> Option<UiSprite> {
+        self.get_sprite(0, JOB_ICON_SPRITE_NAMES.get(job as usize / 100)?)
+    }
+
     pub fn get_item_socket_sprite(&self) -> Option<UiSprite> {
         let texture = &self.sprite_sheets[UiSpriteSheetType::ItemSocketEmpty]
             .as_ref()?
@@ -561,6 +577,8 @@ pub fn load_ui_resources(
             UiCursorType::NoUi =>  UiCursor::new(asset_server.load("trose.exe#cursor_201")),
             UiCursorType::Repair =>  UiCursor::new(asset_server.load("trose.exe#cursor_203")),
             UiCursorType::Appraisal =>  UiCursor::new(asset_server.load("trose.exe#cursor_206")),
+            // The original client has no dedicated warp cursor, so reuse the interaction cursor
+            UiCursorType::Warp =>  UiCursor::new(asset_server.load("trose.exe#cursor_192")),
         },
     });
 }
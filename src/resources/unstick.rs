@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use bevy::prelude::Resource;
+
+/// Being blocked for this long without making any progress counts as being stuck
+pub const STUCK_DETECTION_DURATION: Duration = Duration::from_secs(3);
+
+/// Blocked movement attempts further apart than this are treated as separate attempts
+const STUCK_RETRY_WINDOW: Duration = Duration::from_secs(2);
+
+pub const UNSTICK_COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Default, Resource)]
+pub struct Unstick {
+    /// When the player first collided without moving, reset whenever they move freely
+    pub blocked_since: Option<Duration>,
+    pub last_blocked: Option<Duration>,
+    /// Set once the stuck hint has been shown for the current blocked period
+    pub hint_shown: bool,
+    /// Set by the /unstick chat command
+    pub requested: bool,
+    pub last_used: Option<Duration>,
+}
+
+impl Unstick {
+    pub fn set_blocked(&mut self, now: Duration) {
+        let continued = self.last_blocked.map_or(false, |last_blocked| {
+            now - last_blocked <= STUCK_RETRY_WINDOW
+        });
+        if !continued {
+            self.blocked_since = Some(now);
+            self.hint_shown = false;
+        }
+        self.last_blocked = Some(now);
+    }
+
+    pub fn set_moving(&mut self) {
+        self.blocked_since = None;
+        self.last_blocked = None;
+        self.hint_shown = false;
+    }
+
+    pub fn is_stuck(&self, now: Duration) -> bool {
+        match (self.blocked_since, self.last_blocked) {
+            (Some(blocked_since), Some(last_blocked)) => {
+                now - last_blocked <= STUCK_RETRY_WINDOW
+                    && now - blocked_since >= STUCK_DETECTION_DURATION
+            }
+            _ => false,
+        }
+    }
+
+    pub fn cooldown_remaining(&self, now: Duration) -> Option<Duration> {
+        self.last_used
+            .map(|last_used| (last_used + UNSTICK_COOLDOWN).saturating_sub(now))
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
@@ -4,16 +4,22 @@ use rose_game_common::{
     messages::{client::ClientMessage, server::ServerMessage},
 };
 
+use crate::protocol::{ExtensionClientMessage, ExtensionServerMessage};
+
 #[derive(Resource)]
 pub struct WorldConnection {
     pub client_message_tx: tokio::sync::mpsc::UnboundedSender<ClientMessage>,
     pub server_message_rx: crossbeam_channel::Receiver<ServerMessage>,
+    pub extension_message_tx: tokio::sync::mpsc::UnboundedSender<ExtensionClientMessage>,
+    pub extension_message_rx: crossbeam_channel::Receiver<ExtensionServerMessage>,
 }
 
 impl WorldConnection {
     pub fn new(
         client_message_tx: tokio::sync::mpsc::UnboundedSender<ClientMessage>,
         server_message_rx: crossbeam_channel::Receiver<ServerMessage>,
+        extension_message_tx: tokio::sync::mpsc::UnboundedSender<ExtensionClientMessage>,
+        extension_message_rx: crossbeam_channel::Receiver<ExtensionServerMessage>,
         login_token: u32,
         password: Password,
     ) -> Self {
@@ -27,6 +33,8 @@ impl WorldConnection {
         Self {
             client_message_tx,
             server_message_rx,
+            extension_message_tx,
+            extension_message_rx,
         }
     }
 }
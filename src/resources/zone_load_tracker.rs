@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{Entity, HandleUntyped, Resource},
+    utils::HashSet,
+};
+
+use rose_data::ZoneId;
+
+use crate::zone_loader::ZONE_NUM_BLOCKS;
+
+/// Records every asset requested whilst spawning a zone, so the zone is only reported as loaded
+/// once its meshes, textures and effects have finished loading.
+#[derive(Default, Resource)]
+pub struct ZoneLoadTracker {
+    pub zone_id: Option<ZoneId>,
+    pub zone_entity: Option<Entity>,
+    pub started: Duration,
+    pub handles: HashSet<HandleUntyped>,
+    pub num_loaded: usize,
+    pub blocks_spawned: usize,
+    pub dependencies_collected: bool,
+}
+
+impl ZoneLoadTracker {
+    pub fn begin(&mut self, zone_id: ZoneId, started: Duration) {
+        *self = Self {
+            zone_id: Some(zone_id),
+            started,
+            ..Default::default()
+        };
+    }
+
+    pub fn finish(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn track(&mut self, handle: HandleUntyped) {
+        self.handles.insert(handle);
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.zone_id.is_some()
+    }
+
+    /// Progress from 0 to 1, zone data loading counts as the first 10% and spawning the zone
+    /// blocks as the next 30%
+    pub fn progress(&self) -> f32 {
+        if self.zone_entity.is_none() {
+            0.0
+        } else if self.blocks_spawned < ZONE_NUM_BLOCKS {
+            0.1 + 0.3 * (self.blocks_spawned as f32 / ZONE_NUM_BLOCKS as f32)
+        } else if self.handles.is_empty() {
+            if self.dependencies_collected {
+                1.0
+            } else {
+                0.4
+            }
+        } else {
+            0.4 + 0.6 * (self.num_loaded as f32 / self.handles.len() as f32)
+        }
+    }
+}
@@ -13,7 +13,7 @@ pub struct ZoneTime {
     pub state: ZoneTimeState,
     pub state_percent_complete: f32,
     pub time: u32,
-    pub debug_overwrite_time: Option<u32>,
+    pub debug_overwrite_time: Option<f32>,
 }
 
 impl Default for ZoneTime {
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use rose_game_common::{components::CharacterGender, messages::ClientEntityId};
 
 use crate::{
-    events::{BankEvent, ClanDialogEvent, NpcStoreEvent},
+    events::{AppraisalEvent, BankEvent, ClanDialogEvent, NpcStoreEvent, RepairEvent},
     scripting::{
         lua4::Lua4Value,
         lua_game_constants::{
@@ -34,14 +34,15 @@ impl Default for LuaGameFunctions {
             ) -> Vec<Lua4Value>,
         > = HashMap::new();
 
+        closures.insert("GF_appraisal".into(), GF_appraisal);
         closures.insert("GF_getVariable".into(), GF_getVariable);
         closures.insert("GF_openBank".into(), GF_openBank);
         closures.insert("GF_openStore".into(), GF_openStore);
         closures.insert("GF_organizeClan".into(), GF_organizeClan);
+        closures.insert("GF_repair".into(), GF_repair);
 
         /*
         GF_addUserMoney
-        GF_appraisal
         GF_ChangeState
         GF_checkNumOfInvItem
         GF_checkTownItem
@@ -79,7 +80,6 @@ impl Default for LuaGameFunctions {
         GF_putoffItem
         GF_putonItem
         GF_Random
-        GF_repair
         GF_rotateCamera
         GF_setEquipedItem
         GF_SetMotion
@@ -100,6 +100,17 @@ impl Default for LuaGameFunctions {
     }
 }
 
+#[allow(non_snake_case)]
+fn GF_appraisal(
+    _resources: &ScriptFunctionResources,
+    context: &mut ScriptFunctionContext,
+    _parameters: Vec<Lua4Value>,
+) -> Vec<Lua4Value> {
+    context.appraisal_events.send(AppraisalEvent::Open);
+
+    vec![]
+}
+
 #[allow(non_snake_case)]
 fn GF_getVariable(
     _resources: &ScriptFunctionResources,
@@ -182,3 +193,19 @@ fn GF_organizeClan(
 
     vec![]
 }
+
+#[allow(non_snake_case)]
+fn GF_repair(
+    _resources: &ScriptFunctionResources,
+    context: &mut ScriptFunctionContext,
+    parameters: Vec<Lua4Value>,
+) -> Vec<Lua4Value> {
+    (|| -> Option<()> {
+        let client_entity_id = ClientEntityId(parameters.get(0)?.to_usize().ok()?);
+        context
+            .repair_events
+            .send(RepairEvent::OpenRepairFromClientEntity { client_entity_id });
+        Some(())
+    })();
+    vec![]
+}
@@ -0,0 +1,108 @@
+use bevy::prelude::{Entity, EventReader, EventWriter, Local, Query, Res, ResMut, With};
+
+use rose_data::{NpcData, SoundId};
+use rose_game_common::components::{Level, Npc, Team};
+
+use crate::{
+    components::PlayerCharacter,
+    events::{ClientEntityEvent, ToastCategory, ToastEvent},
+    resources::{CharacterSettings, CurrentZone, GameData},
+    ui::UiSoundEvent,
+};
+
+pub const ACHIEVEMENT_LEVEL_MILESTONES: [u32; 7] = [10, 25, 50, 75, 100, 150, 200];
+pub const ACHIEVEMENT_ZONE_MILESTONES: [usize; 4] = [5, 10, 20, 30];
+const ACHIEVEMENT_UNLOCK_SOUND_ID: u16 = 16;
+
+// The NPC data has no boss flag, so monsters drawn at least twice their normal size are bosses
+const BOSS_MIN_SCALE: f32 = 2.0;
+
+pub fn is_boss_npc(npc_data: &NpcData) -> bool {
+    npc_data.scale >= BOSS_MIN_SCALE
+}
+
+/// Unlocks the client side achievements for level milestones, the number of zones visited and
+/// the first kill of each boss, showing a toast and playing a fanfare for each new unlock.
+pub fn achievement_system(
+    mut last_player_entity: Local<Option<Entity>>,
+    mut character_settings: ResMut<CharacterSettings>,
+    mut client_entity_events: EventReader<ClientEntityEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+    mut ui_sound_events: EventWriter<UiSoundEvent>,
+    query_player: Query<(Entity, &Level), With<PlayerCharacter>>,
+    query_npc: Query<(&Npc, &Team)>,
+    current_zone: Option<Res<CurrentZone>>,
+    game_data: Res<GameData>,
+) {
+    let Ok((player_entity, level)) = query_player.get_single() else {
+        client_entity_events.clear();
+        return;
+    };
+
+    // Milestones which were reached before achievements were tracked are unlocked silently
+    let catching_up = *last_player_entity != Some(player_entity);
+    *last_player_entity = Some(player_entity);
+
+    let achievements = &mut character_settings.achievements;
+    let mut unlocked = Vec::new();
+
+    for milestone in ACHIEVEMENT_LEVEL_MILESTONES {
+        if level.level >= milestone
+            && achievements.unlocked.insert(format!("level_{}", milestone))
+            && !catching_up
+        {
+            unlocked.push(format!("Reached level {}", milestone));
+        }
+    }
+
+    if let Some(current_zone) = current_zone {
+        if achievements.visited_zones.insert(current_zone.id.get()) {
+            let num_visited_zones = achievements.visited_zones.len();
+
+            for milestone in ACHIEVEMENT_ZONE_MILESTONES {
+                if num_visited_zones >= milestone
+                    && achievements.unlocked.insert(format!("zones_{}", milestone))
+                {
+                    unlocked.push(format!("Visited {} zones", milestone));
+                }
+            }
+        }
+    }
+
+    for event in client_entity_events.iter() {
+        let ClientEntityEvent::Die(entity) = *event else {
+            continue;
+        };
+        let Ok((npc, team)) = query_npc.get(entity) else {
+            continue;
+        };
+        if team.id == Team::DEFAULT_NPC_TEAM_ID {
+            continue;
+        }
+        let Some(npc_data) = game_data.npcs.get_npc(npc.id) else {
+            continue;
+        };
+
+        if is_boss_npc(npc_data) && achievements.defeated_bosses.insert(npc.id.get()) {
+            achievements
+                .unlocked
+                .insert(format!("boss_{}", npc.id.get()));
+            unlocked.push(format!("Defeated {}", npc_data.name));
+        }
+    }
+
+    if unlocked.is_empty() {
+        return;
+    }
+
+    for name in unlocked {
+        toast_events.send(ToastEvent::new(
+            ToastCategory::Achievement,
+            format!("Achievement unlocked: {}", name),
+        ));
+    }
+
+    if let Some(sound_id) = SoundId::new(ACHIEVEMENT_UNLOCK_SOUND_ID) {
+        ui_sound_events.send(UiSoundEvent::new(sound_id));
+    }
+}
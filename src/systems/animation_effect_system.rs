@@ -238,7 +238,8 @@ pub fn animation_effect_system(
                                 spawn_effect_events.send(SpawnEffectEvent::OnEntity(
                                     event.entity,
                                     Some(skill_data.bullet_link_dummy_bone_id as usize),
-                                    SpawnEffectData::with_file_id(effect_file_id),
+                                    SpawnEffectData::with_file_id(effect_file_id)
+                                        .with_skill_source(event.entity, skill_data.id),
                                 ));
                             }
                         }
@@ -247,7 +248,8 @@ pub fn animation_effect_system(
                             spawn_effect_events.send(SpawnEffectEvent::OnEntity(
                                 event.entity,
                                 skill_data.hit_link_dummy_bone_id,
-                                SpawnEffectData::with_file_id(hit_effect_file_id),
+                                SpawnEffectData::with_file_id(hit_effect_file_id)
+                                    .with_skill_source(event.entity, skill_data.id),
                             ));
                         }
                     }
@@ -365,17 +367,19 @@ pub fn animation_effect_system(
             .flags
             .contains(AnimationEventFlags::EFFECT_SKILL_DUMMY_HIT_0)
         {
-            if let Some(effect_file_id) = event_entity
+            if let Some(skill_data) = event_entity
                 .command
                 .get_skill_id()
                 .and_then(|skill_id| game_data.skills.get_skill(skill_id))
-                .and_then(|skill_data| skill_data.hit_dummy_effect_file_id[0])
             {
-                spawn_effect_events.send(SpawnEffectEvent::OnEntity(
-                    target_entity.unwrap_or(event.entity),
-                    None,
-                    SpawnEffectData::with_file_id(effect_file_id),
-                ));
+                if let Some(effect_file_id) = skill_data.hit_dummy_effect_file_id[0] {
+                    spawn_effect_events.send(SpawnEffectEvent::OnEntity(
+                        target_entity.unwrap_or(event.entity),
+                        None,
+                        SpawnEffectData::with_file_id(effect_file_id)
+                            .with_skill_source(event.entity, skill_data.id),
+                    ));
+                }
             }
         }
 
@@ -383,17 +387,19 @@ pub fn animation_effect_system(
             .flags
             .contains(AnimationEventFlags::EFFECT_SKILL_DUMMY_HIT_1)
         {
-            if let Some(effect_file_id) = event_entity
+            if let Some(skill_data) = event_entity
                 .command
                 .get_skill_id()
                 .and_then(|skill_id| game_data.skills.get_skill(skill_id))
-                .and_then(|skill_data| skill_data.hit_dummy_effect_file_id[1])
             {
-                spawn_effect_events.send(SpawnEffectEvent::OnEntity(
-                    target_entity.unwrap_or(event.entity),
-                    None,
-                    SpawnEffectData::with_file_id(effect_file_id),
-                ));
+                if let Some(effect_file_id) = skill_data.hit_dummy_effect_file_id[1] {
+                    spawn_effect_events.send(SpawnEffectEvent::OnEntity(
+                        target_entity.unwrap_or(event.entity),
+                        None,
+                        SpawnEffectData::with_file_id(effect_file_id)
+                            .with_skill_source(event.entity, skill_data.id),
+                    ));
+                }
             }
         }
 
@@ -501,7 +507,8 @@ fn show_casting_effect(
         spawn_effect_events.send(SpawnEffectEvent::OnEntity(
             entity,
             casting_effect.effect_dummy_bone_id,
-            SpawnEffectData::with_file_id(casting_effect.effect_file_id),
+            SpawnEffectData::with_file_id(casting_effect.effect_file_id)
+                .with_skill_source(entity, skill_data.id),
         ));
     }
 }
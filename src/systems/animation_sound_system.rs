@@ -1,20 +1,20 @@
 use bevy::{
     ecs::query::WorldQuery,
     math::Vec3,
-    prelude::{AssetServer, Assets, Commands, EventReader, GlobalTransform, Query, Res, Transform},
+    prelude::{Assets, EventReader, EventWriter, GlobalTransform, Query, Res},
     render::mesh::skinning::SkinnedMesh,
 };
 
 use rose_data::{
-    AmmoIndex, AnimationEventFlags, EquipmentIndex, ItemClass, SoundId, VehiclePartIndex,
+    AmmoIndex, AnimationEventFlags, EquipmentIndex, ItemClass, SoundData, SoundId, VehiclePartIndex,
 };
 use rose_game_common::components::{Equipment, MoveMode, Npc};
 
 use crate::{
     animation::AnimationFrameEvent,
-    audio::SpatialSound,
     components::{Command, DummyBoneOffset, PlayerCharacter, SoundCategory},
-    resources::{CurrentZone, GameData, SoundCache, SoundSettings},
+    events::SoundEffectEvent,
+    resources::{CurrentZone, GameData},
     zone_loader::ZoneLoaderAsset,
 };
 
@@ -37,18 +37,49 @@ pub struct TargetEntity<'w> {
     player: Option<&'w PlayerCharacter>,
 }
 
+/// Returns the footstep sound for the surface at a translation, which is the step sound of the
+/// terrain tile or the default step sound when above the terrain on an object.
+pub fn get_surface_sound<'a>(
+    game_data: &'a GameData,
+    current_zone: Option<&CurrentZone>,
+    zone_loader_assets: &Assets<ZoneLoaderAsset>,
+    translation: Vec3,
+) -> Option<&'a SoundData> {
+    let default_step_sound_data = game_data.sounds.get_sound(SoundId::new(653).unwrap());
+
+    let Some(current_zone) = current_zone else {
+        return default_step_sound_data;
+    };
+    let Some(current_zone_data) = zone_loader_assets.get(&current_zone.handle) else {
+        return default_step_sound_data;
+    };
+    let position = Vec3::new(translation.x * 100.0, -translation.z * 100.0, translation.y);
+
+    // TODO: Collision system should set a component indicating whether we are standing on object or terrain
+    if current_zone_data.get_terrain_height(position.x, position.y) / 100.0 < (translation.y - 0.05)
+    {
+        // Standing on an object, use default sound
+        default_step_sound_data
+    } else {
+        let tile_number = current_zone_data.get_tile_index(position.x, position.y);
+        let zone_type = game_data
+            .zone_list
+            .get_zone(current_zone.id)
+            .and_then(|zone_data| zone_data.footstep_type)
+            .unwrap_or(0) as usize;
+        game_data.sounds.get_step_sound(tile_number, zone_type)
+    }
+}
+
 pub fn animation_sound_system(
-    mut commands: Commands,
     mut animation_frame_events: EventReader<AnimationFrameEvent>,
+    mut sound_effect_events: EventWriter<SoundEffectEvent>,
     game_data: Res<GameData>,
-    asset_server: Res<AssetServer>,
     current_zone: Option<Res<CurrentZone>>,
     zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
-    sound_settings: Res<SoundSettings>,
     query_event_entity: Query<EventEntity>,
     query_target_entity: Query<TargetEntity>,
     query_global_transform: Query<&GlobalTransform>,
-    sound_cache: Res<SoundCache>,
 ) {
     for event in animation_frame_events.iter() {
         let event_entity = if let Ok(event_entity) = query_event_entity.get(event.entity) {
@@ -66,35 +97,12 @@ pub fn animation_sound_system(
             .map_or(false, |target_entity| target_entity.player.is_some());
 
         if event.flags.contains(AnimationEventFlags::SOUND_FOOTSTEP) {
-            let default_step_sound_data = game_data.sounds.get_sound(SoundId::new(653).unwrap());
-
-            let step_sound_data = if let Some(current_zone) = current_zone.as_ref() {
-                if let Some(current_zone_data) = zone_loader_assets.get(&current_zone.handle) {
-                    let translation = event_entity.global_transform.translation();
-                    let position =
-                        Vec3::new(translation.x * 100.0, -translation.z * 100.0, translation.y);
-
-                    // TODO: Collision system should set a component indicating whether we are standing on object or terrain
-                    if current_zone_data.get_terrain_height(position.x, position.y) / 100.0
-                        < (translation.y - 0.05)
-                    {
-                        // Standing on an object, use default sound
-                        default_step_sound_data
-                    } else {
-                        let tile_number = current_zone_data.get_tile_index(position.x, position.y);
-                        let zone_type = game_data
-                            .zone_list
-                            .get_zone(current_zone.id)
-                            .and_then(|zone_data| zone_data.footstep_type)
-                            .unwrap_or(0) as usize;
-                        game_data.sounds.get_step_sound(tile_number, zone_type)
-                    }
-                } else {
-                    default_step_sound_data
-                }
-            } else {
-                default_step_sound_data
-            };
+            let step_sound_data = get_surface_sound(
+                &game_data,
+                current_zone.as_deref(),
+                &zone_loader_assets,
+                event_entity.global_transform.translation(),
+            );
 
             if let Some(sound_data) = step_sound_data {
                 let sound_category = if event_entity.player.is_some() {
@@ -103,12 +111,10 @@ pub fn animation_sound_system(
                     SoundCategory::OtherFootstep
                 };
 
-                commands.spawn((
+                sound_effect_events.send(SoundEffectEvent::new(
+                    sound_data.id,
                     sound_category,
-                    sound_settings.gain(sound_category),
-                    SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
-                    Transform::from_translation(event_entity.global_transform.translation()),
-                    GlobalTransform::from_translation(event_entity.global_transform.translation()),
+                    event_entity.global_transform.translation(),
                 ));
             }
         }
@@ -143,12 +149,10 @@ pub fn animation_sound_system(
                         .get(event_entity.dummy_bone_offset.index + 1)
                         .and_then(|dummy_entity| query_global_transform.get(*dummy_entity).ok())
                     {
-                        commands.spawn((
+                        sound_effect_events.send(SoundEffectEvent::new(
+                            sound_data.id,
                             sound_category,
-                            sound_settings.gain(sound_category),
-                            SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
-                            Transform::from_translation(dummy_transform.translation()),
-                            GlobalTransform::from_translation(dummy_transform.translation()),
+                            dummy_transform.translation(),
                         ));
                     }
                 }
@@ -163,12 +167,10 @@ pub fn animation_sound_system(
                         .get(event_entity.dummy_bone_offset.index + 2)
                         .and_then(|dummy_entity| query_global_transform.get(*dummy_entity).ok())
                     {
-                        commands.spawn((
+                        sound_effect_events.send(SoundEffectEvent::new(
+                            sound_data.id,
                             sound_category,
-                            sound_settings.gain(sound_category),
-                            SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
-                            Transform::from_translation(dummy_transform.translation()),
-                            GlobalTransform::from_translation(dummy_transform.translation()),
+                            dummy_transform.translation(),
                         ));
                     }
                 }
@@ -204,12 +206,10 @@ pub fn animation_sound_system(
                     SoundCategory::OtherCombat
                 };
 
-                commands.spawn((
+                sound_effect_events.send(SoundEffectEvent::new(
+                    sound_data.id,
                     sound_category,
-                    sound_settings.gain(sound_category),
-                    SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
-                    Transform::from_translation(event_entity.global_transform.translation()),
-                    GlobalTransform::from_translation(event_entity.global_transform.translation()),
+                    event_entity.global_transform.translation(),
                 ));
             }
         }
@@ -264,14 +264,10 @@ pub fn animation_sound_system(
                             SoundCategory::OtherCombat
                         };
 
-                    commands.spawn((
+                    sound_effect_events.send(SoundEffectEvent::new(
+                        sound_data.id,
                         sound_category,
-                        sound_settings.gain(sound_category),
-                        SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
-                        Transform::from_translation(target_entity.global_transform.translation()),
-                        GlobalTransform::from_translation(
-                            target_entity.global_transform.translation(),
-                        ),
+                        target_entity.global_transform.translation(),
                     ));
                 }
             }
@@ -340,14 +336,10 @@ pub fn animation_sound_system(
                             SoundCategory::OtherCombat
                         };
 
-                    commands.spawn((
+                    sound_effect_events.send(SoundEffectEvent::new(
+                        sound_data.id,
                         sound_category,
-                        sound_settings.gain(sound_category),
-                        SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
-                        Transform::from_translation(event_entity.global_transform.translation()),
-                        GlobalTransform::from_translation(
-                            event_entity.global_transform.translation(),
-                        ),
+                        event_entity.global_transform.translation(),
                     ));
                 }
             }
@@ -372,14 +364,10 @@ pub fn animation_sound_system(
                             SoundCategory::OtherCombat
                         };
 
-                    commands.spawn((
+                    sound_effect_events.send(SoundEffectEvent::new(
+                        sound_data.id,
                         sound_category,
-                        sound_settings.gain(sound_category),
-                        SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
-                        Transform::from_translation(event_entity.global_transform.translation()),
-                        GlobalTransform::from_translation(
-                            event_entity.global_transform.translation(),
-                        ),
+                        event_entity.global_transform.translation(),
                     ));
                 }
             }
@@ -401,14 +389,10 @@ pub fn animation_sound_system(
                             SoundCategory::OtherCombat
                         };
 
-                    commands.spawn((
+                    sound_effect_events.send(SoundEffectEvent::new(
+                        sound_data.id,
                         sound_category,
-                        sound_settings.gain(sound_category),
-                        SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
-                        Transform::from_translation(target_entity.global_transform.translation()),
-                        GlobalTransform::from_translation(
-                            target_entity.global_transform.translation(),
-                        ),
+                        target_entity.global_transform.translation(),
                     ));
                 }
             }
@@ -433,14 +417,10 @@ pub fn animation_sound_system(
                             SoundCategory::OtherCombat
                         };
 
-                    commands.spawn((
+                    sound_effect_events.send(SoundEffectEvent::new(
+                        sound_data.id,
                         sound_category,
-                        sound_settings.gain(sound_category),
-                        SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
-                        Transform::from_translation(target_entity.global_transform.translation()),
-                        GlobalTransform::from_translation(
-                            target_entity.global_transform.translation(),
-                        ),
+                        target_entity.global_transform.translation(),
                     ));
                 }
             }
@@ -465,14 +445,10 @@ pub fn animation_sound_system(
                             SoundCategory::OtherCombat
                         };
 
-                    commands.spawn((
+                    sound_effect_events.send(SoundEffectEvent::new(
+                        sound_data.id,
                         sound_category,
-                        sound_settings.gain(sound_category),
-                        SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
-                        Transform::from_translation(target_entity.global_transform.translation()),
-                        GlobalTransform::from_translation(
-                            target_entity.global_transform.translation(),
-                        ),
+                        target_entity.global_transform.translation(),
                     ));
                 }
             }
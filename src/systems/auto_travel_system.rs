@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use crate::{
+    components::{Command, FacingDirection, PlayerCharacter, Position},
+    events::PlayerCommandEvent,
+    resources::{AutoTravel, InputFocus, KeyBindingAction, KeyBindings},
+};
+use bevy::{
+    input::Input,
+    math::{Vec3, Vec3Swizzles},
+    prelude::{EventWriter, KeyCode, Local, MouseButton, Query, Res, ResMut, With},
+    time::Time,
+};
+
+const AUTO_RUN_DESTINATION_DISTANCE: f32 = 500.0;
+const AUTO_RUN_SEND_INTERVAL: Duration = Duration::from_millis(250);
+const WAYPOINT_REACHED_DISTANCE: f32 = 100.0;
+
+#[derive(Default)]
+pub struct AutoTravelState {
+    was_active: bool,
+    current_waypoint: Option<Vec3>,
+    time_since_last_move: Duration,
+}
+
+pub fn auto_travel_system(
+    mut state: Local<AutoTravelState>,
+    mut auto_travel: ResMut<AutoTravel>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    input_focus: Res<InputFocus>,
+    key_bindings: Res<KeyBindings>,
+    query_player: Query<(&Position, &FacingDirection, &Command), With<PlayerCharacter>>,
+    time: Res<Time>,
+) {
+    let Ok((player_position, facing_direction, command)) = query_player.get_single() else {
+        auto_travel.cancel();
+        return;
+    };
+
+    let mut manual_movement = false;
+    if !input_focus.keyboard_captured() {
+        if key_bindings.just_pressed(KeyBindingAction::AutoRun, &keyboard_input, &mouse_input) {
+            let auto_run = !auto_travel.auto_run;
+            auto_travel.cancel();
+            auto_travel.auto_run = auto_run;
+        } else if keyboard_input.just_pressed(KeyCode::Escape) {
+            auto_travel.cancel();
+        } else if [
+            KeyBindingAction::MoveForward,
+            KeyBindingAction::MoveBackward,
+            KeyBindingAction::MoveLeft,
+            KeyBindingAction::MoveRight,
+        ]
+        .into_iter()
+        .any(|action| key_bindings.just_pressed(action, &keyboard_input, &mouse_input))
+        {
+            // Any manual movement input cancels auto travel
+            auto_travel.cancel();
+            manual_movement = true;
+        }
+    }
+
+    // Remove any waypoints we have reached
+    while let Some(waypoint) = auto_travel.waypoints.front() {
+        if waypoint.xy().distance(player_position.position.xy()) > WAYPOINT_REACHED_DISTANCE {
+            break;
+        }
+
+        auto_travel.waypoints.pop_front();
+    }
+
+    state.time_since_last_move += time.delta();
+
+    if !auto_travel.is_active() {
+        if state.was_active {
+            state.was_active = false;
+            state.current_waypoint = None;
+
+            // Stop where we currently are, unless the player has taken over the movement
+            if !manual_movement {
+                player_command_events.send(PlayerCommandEvent::Move(player_position.clone(), None));
+            }
+        }
+
+        return;
+    }
+    state.was_active = true;
+
+    if let Some(waypoint) = auto_travel.waypoints.front().copied() {
+        // Resend the move if we have stopped before reaching the waypoint, e.g. after being
+        // interrupted by an attack or the server rejecting the move
+        let stopped_early =
+            command.is_stop() && state.time_since_last_move >= AUTO_RUN_SEND_INTERVAL;
+
+        if state.current_waypoint != Some(waypoint) || stopped_early {
+            state.current_waypoint = Some(waypoint);
+            state.time_since_last_move = Duration::ZERO;
+            player_command_events.send(PlayerCommandEvent::Move(Position::new(waypoint), None));
+        }
+    } else if auto_travel.auto_run {
+        state.current_waypoint = None;
+
+        if state.time_since_last_move < AUTO_RUN_SEND_INTERVAL {
+            return;
+        }
+        state.time_since_last_move = Duration::ZERO;
+
+        let angle = facing_direction.actual - std::f32::consts::PI;
+        let direction = Vec3::new(angle.cos(), angle.sin(), 0.0);
+        player_command_events.send(PlayerCommandEvent::Move(
+            Position::new(player_position.position + direction * AUTO_RUN_DESTINATION_DISTANCE),
+            None,
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::{
+    ecs::event::Events,
+    input::Input,
+    prelude::{
+        Commands, Entity, EventReader, KeyCode, MouseButton, Query, Res, ResMut, Time, With, World,
+    },
+};
+
+use rose_game_common::messages::client::ClientMessage;
+
+use crate::{
+    components::{AwayFromKeyboard, ClientEntityName, PlayerCharacter},
+    events::ChatboxEvent,
+    resources::{AwayState, GameConnection, IgnoreList},
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn away_system(
+    mut commands: Commands,
+    mut away_state: ResMut<AwayState>,
+    mut query_player: Query<(Entity, &mut ClientEntityName), With<PlayerCharacter>>,
+    mut chatbox_events: EventReader<ChatboxEvent>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    ignore_list: Res<IgnoreList>,
+    game_connection: Option<Res<GameConnection>>,
+    time: Res<Time>,
+) {
+    let Ok((player_entity, mut player_name)) = query_player.get_single_mut() else {
+        chatbox_events.clear();
+        return;
+    };
+
+    let send_system_message = |commands: &mut Commands, message: String| {
+        commands.add(move |world: &mut World| {
+            world
+                .resource_mut::<Events<ChatboxEvent>>()
+                .send(ChatboxEvent::System(message));
+        });
+    };
+
+    if let Some(message) = away_state.requested.take() {
+        send_system_message(
+            &mut commands,
+            format!(
+                "You are now away, whispers will be replied to with: {}",
+                message
+            ),
+        );
+        away_state.is_away = true;
+        away_state.message = message;
+        away_state.last_replies.clear();
+        commands.entity(player_entity).insert(AwayFromKeyboard);
+
+        // Regenerate the name tag to show the AFK tag, input is ignored until next frame as
+        // this frame contains the key press which sent the command
+        player_name.set_changed();
+        chatbox_events.clear();
+        return;
+    }
+
+    if !away_state.is_away {
+        chatbox_events.clear();
+        return;
+    }
+
+    if keyboard_input.get_just_pressed().next().is_some()
+        || mouse_input.get_just_pressed().next().is_some()
+    {
+        away_state.is_away = false;
+        commands.entity(player_entity).remove::<AwayFromKeyboard>();
+        player_name.set_changed();
+        send_system_message(&mut commands, "You are no longer away.".to_string());
+        chatbox_events.clear();
+        return;
+    }
+
+    let now = time.elapsed();
+    for event in chatbox_events.iter() {
+        let ChatboxEvent::Whisper(name, _) = event else {
+            continue;
+        };
+
+        if ignore_list.is_ignored(name) || !away_state.try_auto_reply(name, now) {
+            continue;
+        }
+
+        if let Some(game_connection) = game_connection.as_ref() {
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::Chat {
+                    text: format!("@{} [AFK] {}", name, away_state.message),
+                })
+                .ok();
+        }
+    }
+}
@@ -1,115 +1,117 @@
-use bevy::prelude::{AssetServer, Commands, Entity, Handle, Local, Res};
-use rose_data::ZoneId;
+use bevy::prelude::{AssetServer, Commands, Entity, Query, Res, ResMut, Time};
 
 use crate::{
-    audio::{AudioSource, GlobalSound},
+    audio::{GlobalSound, SoundGain},
     components::SoundCategory,
-    resources::{CurrentZone, GameData, SoundSettings, ZoneTime, ZoneTimeState},
+    resources::{
+        BackgroundMusic, BackgroundMusicTrack, CurrentZone, GameData, SoundSettings, ZoneTime,
+        ZoneTimeState,
+    },
 };
 
-#[derive(Default)]
-pub enum BackgroundMusicState {
-    #[default]
-    None,
-    PlayingDay,
-    PlayingNight,
-}
+fn set_faded_gain(
+    query_sound_gain: &mut Query<&mut SoundGain>,
+    entity: Entity,
+    gain: SoundGain,
+    volume: f32,
+) {
+    let faded_gain = match gain {
+        SoundGain::Ratio(ratio) => SoundGain::Ratio(ratio * volume),
+        SoundGain::Decibel(db) => SoundGain::Ratio(10.0f32.powf(db / 20.0) * volume),
+    };
 
-#[derive(Default)]
-pub struct BackgroundMusic {
-    pub zone: Option<ZoneId>,
-    pub entity: Option<Entity>,
-    pub day_audio_source: Option<Handle<AudioSource>>,
-    pub night_audio_source: Option<Handle<AudioSource>>,
-    pub state: BackgroundMusicState,
+    if let Ok(mut sound_gain) = query_sound_gain.get_mut(entity) {
+        if *sound_gain != faded_gain {
+            *sound_gain = faded_gain;
+        }
+    }
 }
 
 pub fn background_music_system(
     mut commands: Commands,
-    mut background_music: Local<BackgroundMusic>,
+    mut background_music: ResMut<BackgroundMusic>,
+    mut query_sound_gain: Query<&mut SoundGain>,
     asset_server: Res<AssetServer>,
     current_zone: Option<Res<CurrentZone>>,
     game_data: Res<GameData>,
     zone_time: Res<ZoneTime>,
     sound_settings: Res<SoundSettings>,
+    time: Res<Time>,
 ) {
-    if let Some(current_zone) = current_zone {
-        if background_music.zone != Some(current_zone.id) {
-            if let Some(entity) = background_music.entity.take() {
-                commands.entity(entity).despawn();
-            }
-            background_music.state = BackgroundMusicState::None;
+    let zone_id = current_zone.as_ref().map(|current_zone| current_zone.id);
+    let is_night = matches!(
+        zone_time.state,
+        ZoneTimeState::Evening | ZoneTimeState::Night
+    );
 
-            if let Some(zone_data) = game_data.zone_list.get_zone(current_zone.id) {
-                background_music.day_audio_source = zone_data
-                    .background_music_day
-                    .as_ref()
-                    .map(|path| asset_server.load(path.path()));
-                background_music.night_audio_source = zone_data
-                    .background_music_night
-                    .as_ref()
-                    .map(|path| asset_server.load(path.path()));
-            } else {
-                background_music.day_audio_source = None;
-                background_music.night_audio_source = None;
-            }
+    if background_music.zone != zone_id || background_music.is_night != is_night {
+        background_music.zone = zone_id;
+        background_music.is_night = is_night;
 
-            background_music.zone = Some(current_zone.id);
-        }
+        // Zones without a separate night track keep playing their day track
+        let zone_data = zone_id.and_then(|zone_id| game_data.zone_list.get_zone(zone_id));
+        let audio_source = zone_data
+            .and_then(|zone_data| {
+                if is_night {
+                    zone_data
+                        .background_music_night
+                        .as_ref()
+                        .or(zone_data.background_music_day.as_ref())
+                } else {
+                    zone_data
+                        .background_music_day
+                        .as_ref()
+                        .or(zone_data.background_music_night.as_ref())
+                }
+            })
+            .map(|path| asset_server.load(path.path()));
 
-        match zone_time.state {
-            ZoneTimeState::Morning | ZoneTimeState::Day => {
-                match background_music.state {
-                    BackgroundMusicState::None | BackgroundMusicState::PlayingNight => {
-                        // TODO: Should probably cross fade between the tracks
-                        if let Some(entity) = background_music.entity.take() {
-                            commands.entity(entity).despawn();
-                        }
+        if !background_music.is_playing(audio_source.as_ref()) {
+            let track = audio_source.map(|audio_source| BackgroundMusicTrack {
+                entity: commands
+                    .spawn((
+                        GlobalSound::new_repeating(audio_source.clone()),
+                        SoundGain::Ratio(0.0),
+                    ))
+                    .id(),
+                audio_source,
+                volume: 0.0,
+            });
+            background_music.set_track(track);
+        }
+    }
 
-                        if let Some(audio_source) = background_music.night_audio_source.as_ref() {
-                            background_music.entity = Some(
-                                commands
-                                    .spawn((
-                                        SoundCategory::BackgroundMusic,
-                                        GlobalSound::new_repeating(audio_source.clone()),
-                                        sound_settings.gain(SoundCategory::BackgroundMusic),
-                                    ))
-                                    .id(),
-                            );
-                        }
+    let fade_step = if sound_settings.music_cross_fade > 0.0 {
+        time.delta_seconds() / sound_settings.music_cross_fade
+    } else {
+        1.0
+    };
+    let music_gain = sound_settings.gain(SoundCategory::BackgroundMusic);
 
-                        background_music.state = BackgroundMusicState::PlayingDay;
-                    }
-                    BackgroundMusicState::PlayingDay => {}
-                }
-            }
-            ZoneTimeState::Evening | ZoneTimeState::Night => {
-                match background_music.state {
-                    BackgroundMusicState::None | BackgroundMusicState::PlayingDay => {
-                        // TODO: Should probably cross fade between the tracks
-                        if let Some(entity) = background_music.entity.take() {
-                            commands.entity(entity).despawn();
-                        }
+    if let Some(track) = background_music.current.as_mut() {
+        track.volume = (track.volume + fade_step).min(1.0);
+        set_faded_gain(
+            &mut query_sound_gain,
+            track.entity,
+            music_gain,
+            track.volume,
+        );
+    }
 
-                        if let Some(audio_source) = background_music.night_audio_source.as_ref() {
-                            background_music.entity = Some(
-                                commands
-                                    .spawn((
-                                        SoundCategory::BackgroundMusic,
-                                        GlobalSound::new_repeating(audio_source.clone()),
-                                        sound_settings.gain(SoundCategory::BackgroundMusic),
-                                    ))
-                                    .id(),
-                            );
-                        }
+    background_music.fading_out.retain_mut(|track| {
+        track.volume -= fade_step;
 
-                        background_music.state = BackgroundMusicState::PlayingNight;
-                    }
-                    BackgroundMusicState::PlayingNight => {}
-                }
-            }
+        if track.volume <= 0.0 {
+            commands.entity(track.entity).despawn();
+            false
+        } else {
+            set_faded_gain(
+                &mut query_sound_gain,
+                track.entity,
+                music_gain,
+                track.volume,
+            );
+            true
         }
-    } else if let Some(entity) = background_music.entity.take() {
-        commands.entity(entity).despawn();
-    }
+    });
 }
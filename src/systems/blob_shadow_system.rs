@@ -0,0 +1,234 @@
+use bevy::{
+    pbr::{AlphaMode, NotShadowCaster, NotShadowReceiver, PbrBundle, StandardMaterial},
+    prelude::{
+        Assets, Color, Commands, DespawnRecursiveExt, Entity, GlobalTransform, Handle, Image,
+        Local, Mesh, Query, Res, ResMut, Transform, Vec3, With, Without,
+    },
+    render::{
+        mesh::Indices,
+        render_resource::{Extent3d, PrimitiveTopology, TextureDimension, TextureFormat},
+        view::NoFrustumCulling,
+    },
+};
+use bevy_rapier3d::prelude::Collider;
+
+use crate::{
+    components::{BlobShadow, BlobShadowEntity, ClientEntity, ClientEntityType, ColliderEntity},
+    resources::{CurrentZone, RenderConfiguration, ShadowQuality},
+    zone_loader::ZoneLoaderAsset,
+};
+
+const BLOB_SHADOW_TEXTURE_SIZE: u32 = 64;
+const BLOB_SHADOW_MAX_ALPHA: f32 = 0.6;
+
+/// Number of vertices along each side of the decal grid which is conformed to the heightmap
+const BLOB_SHADOW_GRID_SIZE: usize = 5;
+
+/// Offset above the terrain to avoid z-fighting
+const BLOB_SHADOW_HEIGHT_OFFSET: f32 = 0.03;
+
+/// When the owner is further than this above the heightmap it is standing on an object, so the
+/// decal is drawn flat at the owner's feet instead
+const BLOB_SHADOW_MAX_TERRAIN_DISTANCE: f32 = 0.5;
+
+const BLOB_SHADOW_MIN_RADIUS: f32 = 0.3;
+const BLOB_SHADOW_MAX_RADIUS: f32 = 4.0;
+
+fn create_blob_shadow_image() -> Image {
+    let size = BLOB_SHADOW_TEXTURE_SIZE as usize;
+    let mut data = Vec::with_capacity(size * size * 4);
+
+    for y in 0..size {
+        for x in 0..size {
+            let dx = (x as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+            let dy = (y as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+            let falloff = (1.0 - (dx * dx + dy * dy).sqrt()).clamp(0.0, 1.0);
+            let alpha = falloff * falloff * BLOB_SHADOW_MAX_ALPHA;
+            data.extend_from_slice(&[0, 0, 0, (alpha * 255.0) as u8]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: BLOB_SHADOW_TEXTURE_SIZE,
+            height: BLOB_SHADOW_TEXTURE_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8Unorm,
+    )
+}
+
+fn create_blob_shadow_mesh() -> Mesh {
+    let mut positions = Vec::with_capacity(BLOB_SHADOW_GRID_SIZE * BLOB_SHADOW_GRID_SIZE);
+    let mut normals = Vec::with_capacity(BLOB_SHADOW_GRID_SIZE * BLOB_SHADOW_GRID_SIZE);
+    let mut uvs = Vec::with_capacity(BLOB_SHADOW_GRID_SIZE * BLOB_SHADOW_GRID_SIZE);
+    let mut indices = Vec::new();
+    let last = (BLOB_SHADOW_GRID_SIZE - 1) as f32;
+
+    for z in 0..BLOB_SHADOW_GRID_SIZE {
+        for x in 0..BLOB_SHADOW_GRID_SIZE {
+            let u = x as f32 / last;
+            let v = z as f32 / last;
+            positions.push([u * 2.0 - 1.0, 0.0, v * 2.0 - 1.0]);
+            normals.push([0.0, 1.0, 0.0]);
+            uvs.push([u, v]);
+        }
+    }
+
+    for z in 0..BLOB_SHADOW_GRID_SIZE - 1 {
+        for x in 0..BLOB_SHADOW_GRID_SIZE - 1 {
+            let i = (x + z * BLOB_SHADOW_GRID_SIZE) as u16;
+            let row = BLOB_SHADOW_GRID_SIZE as u16;
+            indices.extend_from_slice(&[i, i + row, i + 1, i + 1, i + row, i + row + 1]);
+        }
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.set_indices(Some(Indices::U16(indices)));
+    mesh
+}
+
+/// Moves the decal grid vertices onto the heightmap around the owner translation
+fn conform_blob_shadow_mesh(
+    mesh: &mut Mesh,
+    zone_data: Option<&ZoneLoaderAsset>,
+    translation: Vec3,
+    radius: f32,
+) {
+    let last = (BLOB_SHADOW_GRID_SIZE - 1) as f32;
+    let terrain_height = |x: f32, z: f32| {
+        zone_data.map(|zone_data| zone_data.get_terrain_height(x * 100.0, -z * 100.0) / 100.0)
+    };
+    let on_terrain = terrain_height(translation.x, translation.z).map_or(false, |height| {
+        translation.y - height < BLOB_SHADOW_MAX_TERRAIN_DISTANCE
+    });
+
+    let mut positions = Vec::with_capacity(BLOB_SHADOW_GRID_SIZE * BLOB_SHADOW_GRID_SIZE);
+    for z in 0..BLOB_SHADOW_GRID_SIZE {
+        for x in 0..BLOB_SHADOW_GRID_SIZE {
+            let local_x = (x as f32 / last * 2.0 - 1.0) * radius;
+            let local_z = (z as f32 / last * 2.0 - 1.0) * radius;
+            let height = on_terrain
+                .then(|| terrain_height(translation.x + local_x, translation.z + local_z))
+                .flatten()
+                .unwrap_or(translation.y);
+
+            positions.push([
+                local_x,
+                height - translation.y + BLOB_SHADOW_HEIGHT_OFFSET,
+                local_z,
+            ]);
+        }
+    }
+
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn blob_shadow_system(
+    mut commands: Commands,
+    mut material: Local<Option<Handle<StandardMaterial>>>,
+    query_new: Query<
+        (Entity, &ClientEntity, &ColliderEntity, &Transform),
+        (Without<BlobShadowEntity>, Without<BlobShadow>),
+    >,
+    query_owners: Query<Entity, With<BlobShadowEntity>>,
+    mut query_shadows: Query<(Entity, &mut BlobShadow, &Handle<Mesh>, &mut Transform)>,
+    query_owner_transform: Query<&GlobalTransform, With<BlobShadowEntity>>,
+    query_collider: Query<&Collider>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    render_configuration: Res<RenderConfiguration>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    if render_configuration.shadow_quality != ShadowQuality::Blob {
+        for (shadow_entity, _, _, _) in query_shadows.iter() {
+            commands.entity(shadow_entity).despawn_recursive();
+        }
+        for owner_entity in query_owners.iter() {
+            commands.entity(owner_entity).remove::<BlobShadowEntity>();
+        }
+        return;
+    }
+
+    let material = material
+        .get_or_insert_with(|| {
+            materials.add(StandardMaterial {
+                base_color: Color::WHITE,
+                base_color_texture: Some(images.add(create_blob_shadow_image())),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                depth_bias: 1.0,
+                ..Default::default()
+            })
+        })
+        .clone();
+
+    // Spawn decals for characters and monsters once their collider is known
+    for (entity, client_entity, collider_entity, owner_transform) in query_new.iter() {
+        if matches!(client_entity.entity_type, ClientEntityType::ItemDrop) {
+            continue;
+        }
+
+        let Some(cuboid) = query_collider
+            .get(collider_entity.entity)
+            .ok()
+            .and_then(|collider| collider.as_cuboid())
+        else {
+            continue;
+        };
+        let half_extents = cuboid.half_extents();
+        let radius = (half_extents.x.max(half_extents.z) * owner_transform.scale.x)
+            .clamp(BLOB_SHADOW_MIN_RADIUS, BLOB_SHADOW_MAX_RADIUS);
+
+        let shadow_entity = commands
+            .spawn((
+                BlobShadow::new(entity, radius),
+                PbrBundle {
+                    mesh: meshes.add(create_blob_shadow_mesh()),
+                    material: material.clone(),
+                    ..Default::default()
+                },
+                NotShadowCaster,
+                NotShadowReceiver,
+                NoFrustumCulling,
+            ))
+            .id();
+        commands
+            .entity(entity)
+            .insert(BlobShadowEntity::new(shadow_entity));
+    }
+
+    let zone_data = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle));
+
+    for (shadow_entity, mut blob_shadow, mesh_handle, mut transform) in query_shadows.iter_mut() {
+        let Ok(owner_transform) = query_owner_transform.get(blob_shadow.owner) else {
+            commands.entity(shadow_entity).despawn_recursive();
+            continue;
+        };
+        let translation = owner_transform.translation();
+        transform.translation = translation;
+
+        // Only conform the decal to the heightmap again when the owner has moved
+        if blob_shadow
+            .last_translation
+            .map_or(false, |last| last.distance_squared(translation) < 0.0001)
+        {
+            continue;
+        }
+
+        if let Some(mesh) = meshes.get_mut(mesh_handle) {
+            conform_blob_shadow_mesh(mesh, zone_data, translation, blob_shadow.radius);
+            blob_shadow.last_translation = Some(translation);
+        }
+    }
+}
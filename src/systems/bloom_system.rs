@@ -0,0 +1,28 @@
+use bevy::{
+    core_pipeline::bloom::BloomSettings,
+    prelude::{Camera3d, Commands, Entity, Query, Res, With, Without},
+};
+
+use crate::{components::TargetCamera, resources::RenderConfiguration};
+
+pub fn bloom_system(
+    mut commands: Commands,
+    query_camera: Query<(Entity, Option<&BloomSettings>), (With<Camera3d>, Without<TargetCamera>)>,
+    render_configuration: Res<RenderConfiguration>,
+) {
+    if !render_configuration.is_changed() {
+        return;
+    }
+
+    for (entity, bloom_settings) in query_camera.iter() {
+        match (render_configuration.bloom, bloom_settings.is_some()) {
+            (true, false) => {
+                commands.entity(entity).insert(BloomSettings::NATURAL);
+            }
+            (false, true) => {
+                commands.entity(entity).remove::<BloomSettings>();
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use bevy::{
+    math::Vec3,
+    prelude::{
+        Camera3d, Commands, Entity, EventReader, EventWriter, NextState, Query, Res, ResMut, With,
+        Without,
+    },
+    time::Time,
+};
+
+use crate::{
+    components::{ClientEntity, PlayerCharacter, TargetCamera},
+    events::{GameConnectionEvent, LoadZoneEvent, ToastCategory, ToastEvent},
+    resources::{
+        AppState, ChannelSwitch, ChannelSwitchStage, GameConnection, ServerList, WorldConnection,
+        CHANNEL_SWITCH_TIMEOUT,
+    },
+    systems::{FreeCamera, OrbitCamera},
+};
+
+pub fn channel_switch_system(
+    mut commands: Commands,
+    mut channel_switch: ResMut<ChannelSwitch>,
+    mut game_connection_events: EventReader<GameConnectionEvent>,
+    mut load_zone_events: EventWriter<LoadZoneEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+    mut app_state_next: ResMut<NextState<AppState>>,
+    query_cameras: Query<Entity, (With<Camera3d>, Without<TargetCamera>)>,
+    query_player: Query<Entity, (With<PlayerCharacter>, With<ClientEntity>)>,
+    server_list: Option<Res<ServerList>>,
+    time: Res<Time>,
+) {
+    let Some(stage) = channel_switch.stage else {
+        game_connection_events.clear();
+        return;
+    };
+
+    match stage {
+        ChannelSwitchStage::JoiningChannel | ChannelSwitchStage::ConnectingWorld => {}
+        ChannelSwitchStage::ConnectingGame => {
+            for event in game_connection_events.iter() {
+                let &GameConnectionEvent::Connected(zone_id) = event;
+
+                // The zone loader despawns the old zone entities once the new zone has loaded
+                load_zone_events.send(LoadZoneEvent::new(zone_id));
+                channel_switch.stage = Some(ChannelSwitchStage::LoadingZone);
+            }
+        }
+        ChannelSwitchStage::LoadingZone => {
+            // Once we have joined the zone, our new player entity will have a ClientEntity
+            if let Ok(player_entity) = query_player.get_single() {
+                for entity in query_cameras.iter() {
+                    commands
+                        .entity(entity)
+                        .remove::<FreeCamera>()
+                        .insert(OrbitCamera::new(
+                            player_entity,
+                            Vec3::new(0.0, 1.7, 0.0),
+                            15.0,
+                        ));
+                }
+
+                let channel_name = channel_switch
+                    .target
+                    .and_then(|(server_id, channel_id)| {
+                        server_list
+                            .as_ref()?
+                            .world_servers
+                            .iter()
+                            .find(|world_server| world_server.id == server_id)?
+                            .game_servers
+                            .iter()
+                            .find(|game_server| game_server.id == channel_id)
+                            .map(|game_server| game_server.name.clone())
+                    })
+                    .unwrap_or_default();
+                toast_events.send(ToastEvent::new(
+                    ToastCategory::Connection,
+                    format!("Changed channel to {}", channel_name),
+                ));
+                channel_switch.finish();
+                return;
+            }
+        }
+    }
+
+    if time.elapsed().saturating_sub(channel_switch.started) > CHANNEL_SWITCH_TIMEOUT {
+        toast_events.send(ToastEvent::new(
+            ToastCategory::Connection,
+            "Timed out while changing channel",
+        ));
+
+        // If we have not left the current channel yet, we can just carry on playing
+        if stage != ChannelSwitchStage::JoiningChannel {
+            commands.remove_resource::<WorldConnection>();
+            commands.remove_resource::<GameConnection>();
+            app_state_next.set(AppState::GameLogin);
+        }
+        channel_switch.cancel();
+    }
+}
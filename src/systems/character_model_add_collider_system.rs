@@ -1,14 +1,11 @@
 use bevy::{
     ecs::query::QueryEntityError,
-    math::{Quat, Vec3, Vec3A},
+    math::{Vec3, Vec3A},
     prelude::{
-        Assets, BuildChildren, Commands, Entity, GlobalTransform, Handle, Mesh, Query, Res,
-        Transform, With, Without,
-    },
-    render::{
-        mesh::skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
-        primitives::Aabb,
+        BuildChildren, Commands, Entity, GlobalTransform, Handle, Mesh, Query, Transform, With,
+        Without,
     },
+    render::{mesh::skinning::SkinnedMesh, primitives::Aabb},
 };
 use bevy_rapier3d::prelude::{Collider, CollisionGroups};
 
@@ -21,19 +18,17 @@ use crate::components::{
 pub fn character_model_add_collider_system(
     mut commands: Commands,
     query_add_collider: Query<
+        (Entity, &CharacterModel, Option<&PlayerCharacter>),
         (
-            Entity,
-            &CharacterModel,
-            &SkinnedMesh,
-            Option<&PlayerCharacter>,
+            Without<ColliderEntity>,
+            Without<PersonalStore>,
+            With<SkinnedMesh>,
         ),
-        (Without<ColliderEntity>, Without<PersonalStore>),
     >,
     query_aabb: Query<Option<&Aabb>, With<Handle<Mesh>>>,
-    inverse_bindposes: Res<Assets<SkinnedMeshInverseBindposes>>,
 ) {
     // Add colliders to character models without one
-    for (entity, character_model, skinned_mesh, player_character) in query_add_collider.iter() {
+    for (entity, character_model, player_character) in query_add_collider.iter() {
         let mut min: Option<Vec3A> = None;
         let mut max: Option<Vec3A> = None;
         let mut all_parts_loaded = true;
@@ -66,18 +61,17 @@ pub fn character_model_add_collider_system(
             }
         }
 
-        let inverse_bindpose = inverse_bindposes.get(&skinned_mesh.inverse_bindposes);
-        if min.is_none() || max.is_none() || !all_parts_loaded || inverse_bindpose.is_none() {
+        if min.is_none() || max.is_none() || !all_parts_loaded {
             // Try again next frame
             continue;
         }
+
+        // The collider is sized from the combined AABB of the model parts and offset from the
+        // model root, so it matches the model regardless of the orientation of its root bone
         let min = Vec3::from(min.unwrap());
         let max = Vec3::from(max.unwrap());
-        let root_bone_inverse_bindpose = Transform::from_matrix(inverse_bindpose.unwrap()[0]);
-
         let local_bound_center = 0.5 * (min + max);
         let half_extents = 0.5 * (max - min);
-        let root_bone_offset = root_bone_inverse_bindpose.transform_point(local_bound_center);
 
         let collider_entity = commands
             .spawn((
@@ -93,15 +87,12 @@ pub fn character_model_add_collider_system(
                         | COLLISION_FILTER_CLICKABLE
                         | COLLISION_GROUP_PHYSICS_TOY,
                 ),
-                Transform::from_translation(root_bone_offset)
-                    .with_rotation(Quat::from_axis_angle(Vec3::Z, std::f32::consts::PI / 2.0)),
+                Transform::from_translation(local_bound_center),
                 GlobalTransform::default(),
             ))
             .id();
 
-        commands
-            .entity(skinned_mesh.joints[0])
-            .add_child(collider_entity);
+        commands.entity(entity).add_child(collider_entity);
 
         commands.entity(entity).insert((
             ColliderEntity::new(collider_entity),
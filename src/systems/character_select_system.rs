@@ -35,6 +35,12 @@ pub struct CharacterSelectCharacter {
     pub index: usize,
 }
 
+/// The index of the character model currently under the mouse cursor
+#[derive(Default, Resource)]
+pub struct CharacterSelectHover {
+    pub index: Option<usize>,
+}
+
 #[derive(Resource)]
 pub struct CharacterSelectModelList {
     models: Vec<(Option<String>, Entity)>,
@@ -66,6 +72,7 @@ pub fn character_select_enter_system(
 
     // Reset state
     commands.insert_resource(CharacterSelectState::Entering);
+    commands.init_resource::<CharacterSelectHover>();
 
     // Spawn entities to use for character list models
     let mut models = Vec::with_capacity(game_data.character_select_positions.len());
@@ -99,6 +106,7 @@ pub fn character_select_exit_system(
     commands.remove_resource::<CharacterList>();
     commands.remove_resource::<CharacterSelectState>();
     commands.remove_resource::<CharacterSelectModelList>();
+    commands.remove_resource::<CharacterSelectHover>();
 }
 
 pub fn character_select_models_system(
@@ -381,6 +389,7 @@ pub fn character_select_event_system(
 #[allow(clippy::too_many_arguments)]
 pub fn character_select_input_system(
     mut character_select_state: ResMut<CharacterSelectState>,
+    mut character_select_hover: ResMut<CharacterSelectHover>,
     mut egui_ctx: EguiContexts,
     mouse_button_input: Res<Input<MouseButton>>,
     rapier_context: Res<RapierContext>,
@@ -391,6 +400,8 @@ pub fn character_select_input_system(
     query_window: Query<&Window, With<PrimaryWindow>>,
     mut character_select_events: EventWriter<CharacterSelectEvent>,
 ) {
+    character_select_hover.index = None;
+
     if egui_ctx.ctx_mut().wants_pointer_input() {
         // Mouse is over UI
         return;
@@ -413,40 +424,43 @@ pub fn character_select_input_system(
         return;
     };
 
+    for (camera, camera_transform) in query_camera.iter() {
+        if let Some(ray) = camera.viewport_to_world(camera_transform, cursor_position) {
+            if let Some((collider_entity, _)) = rapier_context.cast_ray(
+                ray.origin,
+                ray.direction,
+                10000000.0,
+                false,
+                QueryFilter::new().groups(CollisionGroups::new(
+                    COLLISION_FILTER_CLICKABLE,
+                    COLLISION_GROUP_CHARACTER | COLLISION_GROUP_PLAYER,
+                )),
+            ) {
+                let hit_entity = query_collider_parent
+                    .get(collider_entity)
+                    .map_or(collider_entity, |collider_parent| collider_parent.entity);
+
+                if let Ok(select_character) = query_select_character.get(hit_entity) {
+                    character_select_hover.index = Some(select_character.index);
+                }
+            }
+        }
+    }
+
     if mouse_button_input.just_pressed(MouseButton::Left) {
-        for (camera, camera_transform) in query_camera.iter() {
-            if let Some(ray) = camera.viewport_to_world(camera_transform, cursor_position) {
-                if let Some((collider_entity, _)) = rapier_context.cast_ray(
-                    ray.origin,
-                    ray.direction,
-                    10000000.0,
-                    false,
-                    QueryFilter::new().groups(CollisionGroups::new(
-                        COLLISION_FILTER_CLICKABLE,
-                        COLLISION_GROUP_CHARACTER | COLLISION_GROUP_PLAYER,
-                    )),
-                ) {
-                    let hit_entity = query_collider_parent
-                        .get(collider_entity)
-                        .map_or(collider_entity, |collider_parent| collider_parent.entity);
-
-                    if let Ok(select_character) = query_select_character.get(hit_entity) {
-                        let now = Instant::now();
-
-                        if *selected_character_index == Some(select_character.index) {
-                            if let Some(last_selected_time) = *last_selected_time {
-                                if now - last_selected_time < Duration::from_millis(250) {
-                                    character_select_events
-                                        .send(CharacterSelectEvent::PlaySelected);
-                                }
-                            }
-                        }
+        if let Some(hover_index) = character_select_hover.index {
+            let now = Instant::now();
 
-                        *selected_character_index = Some(select_character.index);
-                        *last_selected_time = Some(now);
+            if *selected_character_index == Some(hover_index) {
+                if let Some(last_selected_time) = *last_selected_time {
+                    if now - last_selected_time < Duration::from_millis(250) {
+                        character_select_events.send(CharacterSelectEvent::PlaySelected);
                     }
                 }
             }
+
+            *selected_character_index = Some(hover_index);
+            *last_selected_time = Some(now);
         }
     }
 }
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use bevy::{
+    app::AppExit,
+    prelude::{EventReader, Local, Query, Res, ResMut, Time, With},
+};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    components::PlayerCharacter,
+    resources::{
+        Account, CharacterSettings, IgnoreList, KeyBindings, MapExploration, ServerProfiles,
+    },
+    ui::UiStateWindows,
+};
+
+const CHARACTER_SETTINGS_SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+fn store_window_layout(
+    character_settings: &mut CharacterSettings,
+    ui_state_windows: &UiStateWindows,
+) {
+    let windows = &mut character_settings.windows;
+    windows.character_info_open = ui_state_windows.character_info_open;
+    windows.clan_open = ui_state_windows.clan_open;
+    windows.inventory_open = ui_state_windows.inventory_open;
+    windows.party_open = ui_state_windows.party_open;
+    windows.quest_list_open = ui_state_windows.quest_list_open;
+    windows.skill_list_open = ui_state_windows.skill_list_open;
+}
+
+pub fn character_settings_load_system(
+    mut character_settings: ResMut<CharacterSettings>,
+    mut ignore_list: ResMut<IgnoreList>,
+    mut key_bindings: ResMut<KeyBindings>,
+    mut map_exploration: ResMut<MapExploration>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    query_player: Query<&CharacterInfo, With<PlayerCharacter>>,
+    server_profiles: Res<ServerProfiles>,
+    account: Option<Res<Account>>,
+) {
+    let Ok(character_info) = query_player.get_single() else {
+        return;
+    };
+
+    let server_key = server_profiles
+        .selected()
+        .map(|profile| format!("{}_{}", profile.ip, profile.port))
+        .unwrap_or_default();
+
+    let character_profile_directory =
+        CharacterSettings::profile_directory(&server_key, &character_info.name);
+    *character_settings = CharacterSettings::load(&character_profile_directory);
+    key_bindings.apply_overrides(&character_settings.hotkeys);
+    *map_exploration = MapExploration::load(&character_profile_directory);
+    *ignore_list = IgnoreList::load(&IgnoreList::profile_directory(
+        &server_key,
+        account
+            .as_ref()
+            .map_or("", |account| account.username.as_str()),
+    ));
+
+    let windows = &character_settings.windows;
+    ui_state_windows.character_info_open = windows.character_info_open;
+    ui_state_windows.clan_open = windows.clan_open;
+    ui_state_windows.inventory_open = windows.inventory_open;
+    ui_state_windows.party_open = windows.party_open;
+    ui_state_windows.quest_list_open = windows.quest_list_open;
+    ui_state_windows.skill_list_open = windows.skill_list_open;
+}
+
+pub fn character_settings_save_system(
+    mut save_timer: Local<Duration>,
+    mut character_settings: ResMut<CharacterSettings>,
+    mut map_exploration: ResMut<MapExploration>,
+    mut exit_events: EventReader<AppExit>,
+    ui_state_windows: Res<UiStateWindows>,
+    time: Res<Time>,
+) {
+    *save_timer += time.delta();
+
+    let exiting = exit_events.iter().count() > 0;
+    if exiting || *save_timer > CHARACTER_SETTINGS_SAVE_INTERVAL {
+        *save_timer = Duration::ZERO;
+        store_window_layout(&mut character_settings, &ui_state_windows);
+        character_settings.save();
+        map_exploration.save();
+    }
+}
+
+pub fn character_settings_exit_system(
+    mut character_settings: ResMut<CharacterSettings>,
+    mut key_bindings: ResMut<KeyBindings>,
+    mut map_exploration: ResMut<MapExploration>,
+    ui_state_windows: Res<UiStateWindows>,
+) {
+    store_window_layout(&mut character_settings, &ui_state_windows);
+    character_settings.save();
+    *character_settings = CharacterSettings::default();
+    key_bindings.apply_overrides(&character_settings.hotkeys);
+
+    map_exploration.save();
+    *map_exploration = MapExploration::default();
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::{Commands, EventReader, Query, Res, ResMut};
+
+use crate::{
+    components::{CharacterTitle, ClientEntityName},
+    events::CharacterTitleEvent,
+    resources::{CharacterTitles, ClientEntityList},
+};
+
+pub fn character_title_system(
+    mut commands: Commands,
+    mut character_title_events: EventReader<CharacterTitleEvent>,
+    mut character_titles: ResMut<CharacterTitles>,
+    mut query_name: Query<&mut ClientEntityName>,
+    client_entity_list: Res<ClientEntityList>,
+) {
+    for event in character_title_events.iter() {
+        match event {
+            CharacterTitleEvent::UpdateEntity { entity_id, title } => {
+                let Some(entity) = client_entity_list.get(*entity_id) else {
+                    continue;
+                };
+
+                if let Some(title) = title {
+                    commands
+                        .entity(entity)
+                        .insert(CharacterTitle::new(title.clone()));
+                } else {
+                    commands.entity(entity).remove::<CharacterTitle>();
+                }
+
+                // Regenerate the name tag to show the new title
+                if let Ok(mut name) = query_name.get_mut(entity) {
+                    name.set_changed();
+                }
+            }
+            CharacterTitleEvent::UpdateList {
+                titles,
+                active,
+                can_change,
+            } => {
+                character_titles.titles = titles.clone();
+                character_titles.active = active.filter(|index| *index < titles.len());
+                character_titles.can_change = *can_change;
+            }
+        }
+    }
+}
@@ -56,6 +56,33 @@ pub fn client_entity_event_system(
                     }
                 }
             }
+            ClientEntityEvent::JobChanged(entity, job) => {
+                if is_player(entity) {
+                    chatbox_events.send(ChatboxEvent::System(format!(
+                        "Congratulations! You are now a {}!",
+                        game_data.string_database.get_job_name(job)
+                    )));
+                }
+
+                if let Ok(global_transform) = query_global_transform.get(entity) {
+                    if let Some(sound_data) = game_data.sounds.get_sound(SoundId::new(16).unwrap())
+                    {
+                        commands.spawn((
+                            SoundCategory::PlayerCombat,
+                            sound_settings.gain(SoundCategory::PlayerCombat),
+                            SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
+                            Transform::from_translation(global_transform.translation()),
+                            GlobalTransform::from_translation(global_transform.translation()),
+                        ));
+                    }
+                }
+
+                spawn_effect_events.send(SpawnEffectEvent::OnEntity(
+                    entity,
+                    None,
+                    SpawnEffectData::with_path(VfsPathBuf::new("3DDATA/EFFECT/LEVELUP_01.EFT")),
+                ));
+            }
             ClientEntityEvent::LevelUp(entity, level) => {
                 let sound_category = if is_player(entity) {
                     if let Some(level) = level {
@@ -1,7 +1,8 @@
 use bevy::{
     math::{Quat, Vec3},
     prelude::{
-        Assets, Changed, Commands, Entity, EventWriter, Or, Query, Res, Time, Transform, With,
+        Assets, Changed, Commands, Entity, EventWriter, Or, Query, Res, ResMut, Time, Transform,
+        With,
     },
 };
 use bevy_rapier3d::prelude::{Collider, CollisionGroups, Group, QueryFilter, RapierContext};
@@ -16,7 +17,7 @@ use crate::{
         COLLISION_GROUP_ZONE_TERRAIN, COLLISION_GROUP_ZONE_WARP_OBJECT,
     },
     events::QuestTriggerEvent,
-    resources::{CurrentZone, GameConnection},
+    resources::{CurrentZone, GameConnection, Unstick},
     zone_loader::ZoneLoaderAsset,
 };
 
@@ -150,6 +151,7 @@ pub fn collision_player_system(
     rapier_context: Res<RapierContext>,
     time: Res<Time>,
     zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    mut unstick: ResMut<Unstick>,
 ) {
     let current_zone = if let Some(current_zone) = current_zone {
         current_zone
@@ -187,6 +189,13 @@ pub fn collision_player_system(
                     !COLLISION_GROUP_ZONE_TERRAIN & !COLLISION_GROUP_PHYSICS_TOY,
                 )),
             ) {
+                // Colliding without making any progress suggests we are wedged in a collider
+                if distance.toi <= 0.1 {
+                    unstick.set_blocked(time.elapsed());
+                } else {
+                    unstick.set_moving();
+                }
+
                 let collision_translation =
                     cast_origin + translation_delta * (distance.toi - 0.1).max(0.0);
                 position.x = collision_translation.x * 100.0;
@@ -203,6 +212,8 @@ pub fn collision_player_system(
                         })
                         .ok();
                 }
+            } else {
+                unstick.set_moving();
             }
         }
 
@@ -19,9 +19,9 @@ use crate::{
     animation::{SkeletalAnimation, ZmoAsset},
     components::{
         CharacterModel, ClientEntity, ClientEntityType, Command, CommandAttack, CommandCastSkill,
-        CommandCastSkillState, CommandCastSkillTarget, CommandEmote, CommandMove, CommandSit, Dead,
-        FacingDirection, NextCommand, NpcModel, PersonalStore, PlayerCharacter, Position, Vehicle,
-        VehicleModel,
+        CommandCastSkillState, CommandCastSkillTarget, CommandEmote, CommandHitStun, CommandMove,
+        CommandSit, Dead, FacingDirection, NextCommand, NpcModel, PersonalStore, PlayerCharacter,
+        Position, Vehicle, VehicleModel, VehicleMountMotion,
     },
     events::{ClientEntityEvent, ConversationDialogEvent, PersonalStoreEvent},
     resources::{GameConnection, GameData},
@@ -148,6 +148,27 @@ fn get_move_animation(
     }
 }
 
+fn get_hit_animation(
+    character_model: Option<&CharacterModel>,
+    npc_model: Option<&NpcModel>,
+) -> Option<Handle<ZmoAsset>> {
+    if let Some(character_model) = character_model {
+        if character_model.action_motions[CharacterMotionAction::Hit].is_strong() {
+            Some(character_model.action_motions[CharacterMotionAction::Hit].clone())
+        } else {
+            None
+        }
+    } else if let Some(npc_model) = npc_model {
+        if npc_model.action_motions[NpcMotionAction::Hit].is_strong() {
+            Some(npc_model.action_motions[NpcMotionAction::Hit].clone())
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
 fn get_sitting_animation(
     character_model: Option<&CharacterModel>,
     _npc_model: Option<&NpcModel>,
@@ -332,6 +353,7 @@ pub fn command_system(
             &mut NextCommand,
             &mut FacingDirection,
             Option<&Dead>,
+            Option<&VehicleMountMotion>,
         ),
         Or<(With<CharacterModel>, With<NpcModel>)>,
     >,
@@ -365,6 +387,7 @@ pub fn command_system(
         mut next_command,
         mut facing_direction,
         dead,
+        vehicle_mount_motion,
     ) in query.iter_mut()
     {
         let (
@@ -391,8 +414,8 @@ pub fn command_system(
             .as_ref()
             .and_then(|vehicle| query_vehicle_model.get(vehicle.vehicle_model_entity).ok());
 
-        let requires_animation_complete = if command.is_emote() {
-            // Emote has an animation, but can be interrupted by any other command
+        let requires_animation_complete = if command.is_emote() || command.is_hit_stun() {
+            // Emote and hit stun have an animation, but can be interrupted by any other command
             !next_command.is_some()
         } else {
             command.requires_animation_complete()
@@ -555,21 +578,36 @@ pub fn command_system(
                     // If we have completed current command, and there is no next command, then clear current.
                     // This does not apply for some commands which must be manually completed, such as Sit
                     // where you need to stand after.
-                    if !command.is_manual_complete() {
+                    if let Command::HitStun(CommandHitStun {
+                        resume_attack_target: Some(target),
+                    }) = *command
+                    {
+                        // Continue attacking after recovering from hit stun
+                        *next_command = NextCommand::with_attack(target);
+                    } else if !command.is_manual_complete() {
                         *next_command = NextCommand::with_stop();
                     } else {
                         continue;
                     }
                 } else {
-                    // Nothing to do, ensure we are using correct idle animation
-                    if let Some(motion) = get_stop_animation(character_model, npc_model, vehicle) {
-                        update_active_motion(
-                            &mut commands.entity(active_motion_entity),
-                            &mut active_motion,
-                            motion,
-                            1.0,
-                            true,
-                        );
+                    // Nothing to do, ensure we are using correct idle animation once any
+                    // vehicle mount or dismount motion has completed
+                    if vehicle_mount_motion.is_some() && active_motion_completed {
+                        commands.entity(entity).remove::<VehicleMountMotion>();
+                    }
+
+                    if vehicle_mount_motion.is_none() || active_motion_completed {
+                        if let Some(motion) =
+                            get_stop_animation(character_model, npc_model, vehicle)
+                        {
+                            update_active_motion(
+                                &mut commands.entity(active_motion_entity),
+                                &mut active_motion,
+                                motion,
+                                1.0,
+                                true,
+                            );
+                        }
                     }
 
                     if let Some(motion) =
@@ -588,6 +626,11 @@ pub fn command_system(
                 }
             }
 
+            if vehicle_mount_motion.is_some() {
+                // Any new command interrupts the vehicle mount or dismount motion
+                commands.entity(entity).remove::<VehicleMountMotion>();
+            }
+
             if command.is_sit() {
                 // If current command is sit, we must stand before performing NextCommand
                 if let Some(motion) = get_standing_animation(character_model, npc_model) {
@@ -987,6 +1030,22 @@ pub fn command_system(
                 *command = Command::with_personal_store();
                 *next_command = NextCommand::default();
             }
+            &mut Command::HitStun(CommandHitStun {
+                resume_attack_target,
+            }) => {
+                if let Some(motion) = get_hit_animation(character_model, npc_model) {
+                    update_active_motion(
+                        &mut commands.entity(active_motion_entity),
+                        &mut active_motion,
+                        motion,
+                        1.0,
+                        false,
+                    );
+                }
+
+                *command = Command::with_hit_stun(resume_attack_target);
+                *next_command = NextCommand::default();
+            }
             &mut Command::CastSkill(CommandCastSkill {
                 skill_id,
                 skill_target,
@@ -1078,6 +1137,7 @@ pub fn command_system(
                                 | Command::PickupItem(_)
                                 | Command::PersonalStore
                                 | Command::Sit(_)
+                                | Command::HitStun(_)
                                 | Command::CastSkill(_) => *next_command = NextCommand::default(),
                             },
                         }
@@ -0,0 +1,122 @@
+use bevy::{
+    math::Vec3,
+    prelude::{
+        AssetServer, Assets, Commands, ComputedVisibility, DespawnRecursiveExt, Entity,
+        GlobalTransform, Query, Res, ResMut, Time, Transform, Visibility,
+    },
+    render::mesh::skinning::SkinnedMeshInverseBindposes,
+};
+
+use rose_data::NpcMotionAction;
+
+use crate::{
+    animation::SkeletalAnimation,
+    components::{Companion, CompanionModel},
+    model_loader::ModelLoader,
+    render::{EffectMeshMaterial, ObjectMaterial, ParticleMaterial},
+};
+
+const COMPANION_OFFSET: Vec3 = Vec3::new(-0.8, 1.8, 0.6);
+const COMPANION_FOLLOW_SPEED: f32 = 4.0;
+const COMPANION_BOB_SPEED: f32 = 2.5;
+const COMPANION_BOB_HEIGHT: f32 = 0.15;
+
+#[allow(clippy::too_many_arguments)]
+pub fn companion_system(
+    mut commands: Commands,
+    query_owners: Query<(Entity, &Companion, &GlobalTransform)>,
+    mut query_companion_models: Query<(Entity, &mut CompanionModel, &mut Transform)>,
+    asset_server: Res<AssetServer>,
+    model_loader: Res<ModelLoader>,
+    mut effect_mesh_materials: ResMut<Assets<EffectMeshMaterial>>,
+    mut particle_materials: ResMut<Assets<ParticleMaterial>>,
+    mut object_materials: ResMut<Assets<ObjectMaterial>>,
+    mut skinned_mesh_inverse_bindposes_assets: ResMut<Assets<SkinnedMeshInverseBindposes>>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_seconds();
+
+    // Update existing companion models, despawning any whose owner has lost their companion
+    for (companion_model_entity, mut companion_model, mut transform) in
+        query_companion_models.iter_mut()
+    {
+        let Ok((_, companion, owner_transform)) = query_owners.get(companion_model.owner_entity)
+        else {
+            commands.entity(companion_model_entity).despawn_recursive();
+            continue;
+        };
+
+        if companion.npc_id != companion_model.npc_id {
+            commands.entity(companion_model_entity).despawn_recursive();
+            continue;
+        }
+
+        companion_model.bob_time += delta;
+
+        let (_, owner_rotation, owner_translation) =
+            owner_transform.to_scale_rotation_translation();
+        let bob_offset =
+            Vec3::Y * (companion_model.bob_time * COMPANION_BOB_SPEED).sin() * COMPANION_BOB_HEIGHT;
+        let target_translation = owner_translation + owner_rotation * COMPANION_OFFSET + bob_offset;
+
+        let follow_amount = (delta * COMPANION_FOLLOW_SPEED).min(1.0);
+        transform.translation = transform
+            .translation
+            .lerp(target_translation, follow_amount);
+        transform.rotation = transform.rotation.slerp(owner_rotation, follow_amount);
+    }
+
+    // Spawn models for any owners which do not have one yet
+    for (owner_entity, companion, owner_transform) in query_owners.iter() {
+        if query_companion_models
+            .iter()
+            .any(|(_, companion_model, _)| {
+                companion_model.owner_entity == owner_entity
+                    && companion_model.npc_id == companion.npc_id
+            })
+        {
+            continue;
+        }
+
+        let (_, owner_rotation, owner_translation) =
+            owner_transform.to_scale_rotation_translation();
+        let companion_model_entity = commands
+            .spawn((
+                Transform::from_translation(owner_translation + owner_rotation * COMPANION_OFFSET)
+                    .with_rotation(owner_rotation),
+                GlobalTransform::default(),
+                Visibility::default(),
+                ComputedVisibility::default(),
+            ))
+            .id();
+
+        // Idle effects listed for the NPC in the model data are spawned along with the model
+        if let Some((npc_model, skinned_mesh, dummy_bone_offset)) = model_loader.spawn_npc_model(
+            &mut commands,
+            &asset_server,
+            &mut effect_mesh_materials,
+            &mut particle_materials,
+            &mut object_materials,
+            &mut skinned_mesh_inverse_bindposes_assets,
+            companion_model_entity,
+            companion.npc_id,
+        ) {
+            commands.entity(companion_model_entity).insert((
+                SkeletalAnimation::repeat(
+                    npc_model.action_motions[NpcMotionAction::Stop].clone(),
+                    None,
+                ),
+                skinned_mesh,
+                dummy_bone_offset,
+            ));
+        }
+
+        commands
+            .entity(companion_model_entity)
+            .insert(CompanionModel {
+                owner_entity,
+                npc_id: companion.npc_id,
+                bob_time: 0.0,
+            });
+    }
+}
@@ -1,46 +1,99 @@
 use bevy::{
-    hierarchy::DespawnRecursiveExt,
-    math::{Vec3Swizzles, Vec4},
-    prelude::{Commands, Entity, GlobalTransform, Query},
+    asset::LoadState,
+    math::{Vec2, Vec3, Vec4},
+    prelude::{AssetServer, Assets, Res, ResMut, Time},
 };
 
 use crate::{
-    animation::TransformAnimation, components::DamageDigits, render::DamageDigitRenderData,
+    animation::ZmoAsset,
+    render::{
+        DamageDigitRenderData, DAMAGE_DIGIT_TEXTURE_DAMAGE, DAMAGE_DIGIT_TEXTURE_DAMAGE_PLAYER,
+        DAMAGE_DIGIT_TEXTURE_MISS,
+    },
+    resources::{AccessibilitySettings, DamageDigitsSpawner},
 };
 
 pub fn damage_digit_render_system(
-    mut commands: Commands,
-    mut query: Query<(
-        Entity,
-        &GlobalTransform,
-        &TransformAnimation,
-        &DamageDigits,
-        &mut DamageDigitRenderData,
-    )>,
+    mut damage_digits_spawner: ResMut<DamageDigitsSpawner>,
+    mut damage_digit_render_data: ResMut<DamageDigitRenderData>,
+    motion_assets: Res<Assets<ZmoAsset>>,
+    asset_server: Res<AssetServer>,
+    accessibility_settings: Res<AccessibilitySettings>,
+    time: Res<Time>,
 ) {
-    for (entity, global_transform, animation, damage_digits, mut damage_digit_render_data) in
-        query.iter_mut()
-    {
-        damage_digit_render_data.clear();
+    damage_digit_render_data.clear();
 
-        if animation.completed() {
-            // Animation completed, despawn
-            commands.entity(entity).despawn_recursive();
-            continue;
+    let motion = damage_digits_spawner.motion.clone_weak();
+    let Some(zmo_asset) = motion_assets.get(&motion) else {
+        if matches!(
+            asset_server.get_load_state(&motion),
+            LoadState::Failed | LoadState::Unloaded
+        ) {
+            // Without the motion the digits can never finish animating
+            damage_digits_spawner.active.clear();
         }
+        return;
+    };
+
+    // Completed damage numbers are removed before drawing
+    damage_digits_spawner
+        .active
+        .retain_mut(|damage_digits| !damage_digits.animation.advance(zmo_asset, &time));
+
+    let num_frames = zmo_asset.num_frames.max(1) as f32;
+
+    for damage_digits in damage_digits_spawner.active.iter() {
+        let animation = &damage_digits.animation;
+        let current_frame_fract = animation.current_frame_fract();
+        let current_frame_index = animation.current_frame_index();
+        let next_frame_index = animation.next_frame_index();
+
+        let translation = damage_digits.translation
+            + zmo_asset
+                .sample_translation(
+                    0,
+                    current_frame_fract,
+                    current_frame_index,
+                    next_frame_index,
+                )
+                .unwrap_or(Vec3::ZERO);
+        let scale = zmo_asset
+            .sample_scale(
+                0,
+                current_frame_fract,
+                current_frame_index,
+                next_frame_index,
+            )
+            .unwrap_or(1.0);
+        let size = Vec2::splat(0.4 * scale);
+        let age = (current_frame_index as f32 + current_frame_fract) / num_frames;
 
-        let (scale, _, translation) = global_transform.to_scale_rotation_translation();
         if damage_digits.damage == 0 {
             // Miss, split over 4 digits
             for digit in 0..4 {
                 damage_digit_render_data.add(
                     translation,
                     -1.5 + digit as f32,
-                    0.4 * scale.xy(),
+                    size,
                     Vec4::new(digit as f32 / 4.0, 0.0, (digit + 1) as f32 / 4.0, 1.0),
+                    Vec4::ZERO,
+                    DAMAGE_DIGIT_TEXTURE_MISS,
+                    age,
                 );
             }
         } else {
+            // A zero alpha colour keeps the original texture colours
+            let color = accessibility_settings
+                .damage_digit_color(damage_digits.is_damage_player)
+                .map_or(Vec4::ZERO, |color| {
+                    Vec4::from(color.to_array().map(|c| c as f32 / 255.0))
+                });
+            let texture_index = if damage_digits.is_damage_player {
+                DAMAGE_DIGIT_TEXTURE_DAMAGE_PLAYER
+            } else {
+                DAMAGE_DIGIT_TEXTURE_DAMAGE
+            };
+
             // First count the number of digits
             let mut damage = damage_digits.damage;
             let mut digit_count = 0;
@@ -58,8 +111,11 @@ pub fn damage_digit_render_system(
                 damage_digit_render_data.add(
                     translation,
                     number_offset - digit_offset,
-                    0.4 * scale.xy(),
+                    size,
                     Vec4::new(digit as f32 / 10.0, 0.0, (digit + 1) as f32 / 10.0, 1.0),
+                    color,
+                    texture_index,
+                    age,
                 );
                 digit_offset += 1.0;
                 damage /= 10;
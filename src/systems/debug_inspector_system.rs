@@ -2,6 +2,7 @@ use bevy::{
     input::Input,
     prelude::{
         App, Camera, Camera3d, GlobalTransform, KeyCode, Plugin, Query, Res, ResMut, Update, With,
+        Without,
     },
     window::{PrimaryWindow, Window},
 };
@@ -32,6 +33,9 @@ impl Plugin for DebugInspectorPlugin {
         app.register_type::<AbilityValues>()
             .register_type::<AbilityValuesAdjust>()
             .register_type::<BasicStats>()
+            .register_type::<Campfire>()
+            .register_type::<SitPoint>()
+            .register_type::<SitPoints>()
             .register_type::<CharacterBlinkTimer>()
             .register_type::<CharacterGender>()
             .register_type::<CharacterInfo>()
@@ -47,6 +51,7 @@ impl Plugin for DebugInspectorPlugin {
             .register_type::<CommandCastSkillState>()
             .register_type::<CommandCastSkillTarget>()
             .register_type::<CommandEmote>()
+            .register_type::<CommandHitStun>()
             .register_type::<CommandMove>()
             .register_type::<CommandSit>()
             .register_type::<DamageCategory>()
@@ -60,6 +65,7 @@ impl Plugin for DebugInspectorPlugin {
             .register_type::<ExperiencePoints>()
             .register_type::<FacingDirection>()
             .register_type::<HealthPoints>()
+            .register_type::<Knockback>()
             .register_type::<Level>()
             .register_type::<ManaPoints>()
             .register_type::<ModelHeight>()
@@ -75,10 +81,12 @@ impl Plugin for DebugInspectorPlugin {
             .register_type::<PersonalStoreModel>()
             .register_type::<PlayerCharacter>()
             .register_type::<Position>()
+            .register_type::<Resting>()
             .register_type::<SkillPoints>()
             .register_type::<SoundCategory>()
             .register_type::<Stamina>()
             .register_type::<StatPoints>()
+            .register_type::<Stunned>()
             .register_type::<Team>()
             .register_type::<UnionMembership>()
             .register_type::<WarpObject>()
@@ -100,7 +108,7 @@ fn debug_inspector_picking_system(
     key_code_input: Res<Input<KeyCode>>,
     rapier_context: Res<RapierContext>,
     query_window: Query<&Window, With<PrimaryWindow>>,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<TargetCamera>)>,
 ) {
     if !debug_inspector_state.enable_picking {
         // Picking disabled
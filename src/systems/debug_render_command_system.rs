@@ -0,0 +1,121 @@
+use bevy::{
+    prelude::{Color, Entity, Gizmos, Local, Query, Res, Time, Vec3, With},
+    utils::HashMap,
+};
+
+use crate::{
+    components::{ClientEntity, Command, CommandCastSkillTarget, NextCommand, Position},
+    resources::DebugRenderConfig,
+};
+
+/// Velocity vectors are drawn as the distance travelled over this many seconds
+const VELOCITY_VECTOR_SECONDS: f32 = 0.5;
+
+/// Speeds below this in cm/s are not drawn
+const VELOCITY_MIN_SPEED: f32 = 10.0;
+
+fn position_to_world(position: Vec3) -> Vec3 {
+    Vec3::new(
+        position.x / 100.0,
+        position.z / 100.0 + 0.1,
+        -position.y / 100.0,
+    )
+}
+
+fn command_destination(
+    command: &Command,
+    position: &Position,
+    query_position: &Query<&Position>,
+) -> Option<Vec3> {
+    let target_position = |entity: Entity| {
+        query_position
+            .get(entity)
+            .ok()
+            .map(|position| position.position)
+    };
+
+    match command {
+        Command::Move(command_move) => command_move
+            .target
+            .and_then(target_position)
+            .or(Some(command_move.destination)),
+        Command::Attack(command_attack) => target_position(command_attack.target),
+        Command::PickupItem(entity) => target_position(*entity),
+        Command::CastSkill(command_cast_skill) => match command_cast_skill.skill_target {
+            Some(CommandCastSkillTarget::Entity(entity)) => target_position(entity),
+            Some(CommandCastSkillTarget::Position(target)) => {
+                Some(target.extend(position.position.z))
+            }
+            None => None,
+        },
+        _ => None,
+    }
+}
+
+/// Draws the path to each entity's current and next command destination, and the velocity each
+/// entity is moving at measured from its change in position.
+pub fn debug_render_command_system(
+    mut last_positions: Local<HashMap<Entity, Vec3>>,
+    debug_render_config: Res<DebugRenderConfig>,
+    query_entities: Query<(Entity, &Position, &Command, Option<&NextCommand>), With<ClientEntity>>,
+    query_position: Query<&Position>,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+) {
+    if debug_render_config.command_paths {
+        for (entity, position, command, next_command) in query_entities.iter() {
+            let mut path = vec![position_to_world(position.position)];
+            for command in std::iter::once(command)
+                .chain(next_command.and_then(|next_command| next_command.as_ref()))
+            {
+                if let Some(destination) = command_destination(command, position, &query_position) {
+                    path.push(position_to_world(destination));
+                }
+            }
+
+            if path.len() > 1 {
+                let color = debug_render_config.color_for_entity(entity);
+                gizmos.circle(*path.last().unwrap(), Vec3::Y, 0.25, color);
+                gizmos.linestrip(path, color);
+            }
+        }
+    }
+
+    if !debug_render_config.velocities {
+        last_positions.clear();
+        return;
+    }
+
+    let delta_seconds = time.delta_seconds();
+    let mut positions = HashMap::default();
+    for (entity, position, _, _) in query_entities.iter() {
+        positions.insert(entity, position.position);
+
+        let Some(last_position) = last_positions.get(&entity) else {
+            continue;
+        };
+        if delta_seconds <= 0.0 {
+            continue;
+        }
+
+        let velocity = (position.position - *last_position) / delta_seconds;
+        if velocity.length() < VELOCITY_MIN_SPEED {
+            continue;
+        }
+
+        let start = position_to_world(position.position) + Vec3::new(0.0, 1.0, 0.0);
+        let vector =
+            Vec3::new(velocity.x, velocity.z, -velocity.y) / 100.0 * VELOCITY_VECTOR_SECONDS;
+        let end = start + vector;
+        let head_size = vector.length().min(1.0) * 0.25;
+        let back = -vector.normalize() * head_size;
+        let side = vector.cross(Vec3::Y).normalize_or_zero() * head_size;
+
+        gizmos.line(start, end, Color::LIME_GREEN);
+        gizmos.linestrip(
+            [end + back + side, end, end + back - side],
+            Color::LIME_GREEN,
+        );
+    }
+    *last_positions = positions;
+}
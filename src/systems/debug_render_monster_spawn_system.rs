@@ -0,0 +1,161 @@
+use std::{collections::HashMap, time::Duration};
+
+use bevy::{
+    math::Vec3Swizzles,
+    prelude::{
+        Added, Assets, Camera, Camera3d, Color, Gizmos, GlobalTransform, Local, Query, Res, Time,
+        Vec3, With, Without,
+    },
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::ZoneId;
+use rose_game_common::components::Npc;
+
+use crate::{
+    components::{Dead, PlayerCharacter, Position, TargetCamera},
+    resources::{CurrentZone, DebugRenderConfig, GameData},
+    zone_loader::ZoneLoaderAsset,
+};
+
+/// Only spawn points within this distance (in cm) of the player are drawn
+const MONSTER_SPAWN_DRAW_DISTANCE: f32 = 10000.0;
+
+/// Deaths slightly outside the spawn range are still counted, as monsters wander after spawning
+const MONSTER_SPAWN_DEATH_MARGIN: f32 = 2000.0;
+
+/// How long to keep showing a spawn as due after its estimated respawn time has passed
+const MONSTER_SPAWN_DUE_DURATION: Duration = Duration::from_secs(10);
+
+#[derive(Default)]
+pub struct DebugRenderMonsterSpawnState {
+    zone_id: Option<ZoneId>,
+    last_death: HashMap<usize, Duration>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn debug_render_monster_spawn_system(
+    mut state: Local<DebugRenderMonsterSpawnState>,
+    mut egui_context: EguiContexts,
+    mut gizmos: Gizmos,
+    debug_render_config: Res<DebugRenderConfig>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+    query_killed: Query<(&Npc, &Position), Added<Dead>>,
+    query_player: Query<&Position, With<PlayerCharacter>>,
+    query_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<TargetCamera>)>,
+) {
+    if !debug_render_config.monster_spawns {
+        state.zone_id = None;
+        state.last_death.clear();
+        return;
+    }
+
+    let Some(current_zone) = current_zone else {
+        return;
+    };
+    let Some(current_zone_data) = zone_loader_assets.get(&current_zone.handle) else {
+        return;
+    };
+
+    if state.zone_id != Some(current_zone.id) {
+        state.zone_id = Some(current_zone.id);
+        state.last_death.clear();
+    }
+
+    let now = time.elapsed();
+
+    // Assume each killed monster belongs to the closest spawn point which can spawn it
+    for (npc, position) in query_killed.iter() {
+        let closest_spawn = current_zone_data
+            .monster_spawns
+            .iter()
+            .enumerate()
+            .filter(|(_, spawn)| spawn.npc_ids.contains(&npc.id))
+            .map(|(index, spawn)| (index, spawn.position.truncate().distance(position.xy())))
+            .filter(|(index, distance)| {
+                *distance
+                    <= current_zone_data.monster_spawns[*index].range + MONSTER_SPAWN_DEATH_MARGIN
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        if let Some((index, _)) = closest_spawn {
+            state.last_death.insert(index, now);
+        }
+    }
+
+    state.last_death.retain(|index, died| {
+        current_zone_data
+            .monster_spawns
+            .get(*index)
+            .map_or(false, |spawn| {
+                now.saturating_sub(*died) < spawn.interval + MONSTER_SPAWN_DUE_DURATION
+            })
+    });
+
+    let Ok(player_position) = query_player.get_single() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = query_camera.get_single() else {
+        return;
+    };
+    let ctx = egui_context.ctx_mut();
+    let screen_size = ctx.input(|input| input.screen_rect().size());
+    let painter = ctx.debug_painter();
+
+    for (index, spawn) in current_zone_data.monster_spawns.iter().enumerate() {
+        if spawn.position.truncate().distance(player_position.xy()) > MONSTER_SPAWN_DRAW_DISTANCE {
+            continue;
+        }
+
+        let remaining = state
+            .last_death
+            .get(&index)
+            .map(|died| spawn.interval.saturating_sub(now.saturating_sub(*died)));
+        let color = match remaining {
+            Some(remaining) if remaining.is_zero() => Color::GREEN,
+            Some(_) => Color::ORANGE,
+            None => Color::CYAN,
+        };
+
+        let center = Vec3::new(
+            spawn.position.x / 100.0,
+            current_zone_data.get_terrain_height(spawn.position.x, spawn.position.y) / 100.0 + 0.1,
+            -spawn.position.y / 100.0,
+        );
+        gizmos.circle(center, Vec3::Y, (spawn.range / 100.0).max(0.5), color);
+
+        let Some(screen_pos) =
+            camera.world_to_viewport(camera_transform, center + Vec3::new(0.0, 2.0, 0.0))
+        else {
+            continue;
+        };
+
+        let npc_names = spawn
+            .npc_ids
+            .iter()
+            .filter_map(|npc_id| game_data.npcs.get_npc(*npc_id))
+            .map(|npc_data| npc_data.name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let status = match remaining {
+            Some(remaining) if remaining.is_zero() => "Respawn due".to_string(),
+            Some(remaining) => format!("Respawn in {:.0}s", remaining.as_secs_f32().ceil()),
+            None => format!("Interval {}s", spawn.interval.as_secs()),
+        };
+
+        painter.text(
+            egui::pos2(screen_pos.x, screen_size.y - screen_pos.y),
+            egui::Align2::CENTER_BOTTOM,
+            format!("{}\n{}", npc_names, status),
+            egui::FontId::proportional(13.0),
+            egui::Color32::from_rgb(
+                (color.r() * 255.0) as u8,
+                (color.g() * 255.0) as u8,
+                (color.b() * 255.0) as u8,
+            ),
+        );
+    }
+}
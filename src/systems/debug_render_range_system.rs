@@ -0,0 +1,69 @@
+use bevy::prelude::{Color, Entity, Gizmos, Query, Res, Vec3, With, Without};
+
+use rose_game_common::components::{AbilityValues, Npc, Team};
+
+use crate::{
+    components::{Dead, PlayerCharacter, Position},
+    resources::{DebugRenderConfig, GameData},
+};
+
+fn range_center(position: &Position) -> Vec3 {
+    Vec3::new(
+        position.x / 100.0,
+        position.z / 100.0 + 0.1,
+        -position.y / 100.0,
+    )
+}
+
+/// Draws a circle around each monster for its aggro range, and around the player for the cast
+/// range of the skill under the mouse cursor on the hotbar.
+///
+/// The AI scripts which decide when a monster aggros are only known to the server, so the attack
+/// range from the monster's ability values is used as its aggro range.
+pub fn debug_render_range_system(
+    debug_render_config: Res<DebugRenderConfig>,
+    game_data: Res<GameData>,
+    query_npcs: Query<(Entity, &Position, &AbilityValues, &Team), (With<Npc>, Without<Dead>)>,
+    query_player: Query<(&Position, &AbilityValues), With<PlayerCharacter>>,
+    mut gizmos: Gizmos,
+) {
+    if debug_render_config.npc_aggro_ranges {
+        for (entity, position, ability_values, team) in query_npcs.iter() {
+            if team.id == Team::DEFAULT_NPC_TEAM_ID {
+                continue;
+            }
+
+            gizmos.circle(
+                range_center(position),
+                Vec3::Y,
+                (ability_values.get_attack_range() as f32 / 100.0).max(0.5),
+                debug_render_config.color_for_entity(entity),
+            );
+        }
+    }
+
+    if debug_render_config.skill_ranges {
+        let Some(skill_data) = debug_render_config
+            .hovered_hotbar_skill
+            .and_then(|skill_id| game_data.skills.get_skill(skill_id))
+        else {
+            return;
+        };
+        let Ok((position, ability_values)) = query_player.get_single() else {
+            return;
+        };
+
+        // Skills without a cast range use the player's attack range, as in the command system
+        let cast_range = if skill_data.cast_range > 0 {
+            skill_data.cast_range as f32
+        } else {
+            ability_values.get_attack_range() as f32
+        };
+        gizmos.circle(
+            range_center(position),
+            Vec3::Y,
+            cast_range / 100.0,
+            Color::ORANGE,
+        );
+    }
+}
@@ -3,17 +3,30 @@ use bevy::{
     prelude::{Camera, DirectionalLight, Entity, GlobalTransform, Mat4, Query, Res, Vec3, With},
 };
 
-use crate::components::PlayerCharacter;
+use crate::{
+    components::PlayerCharacter,
+    resources::{RenderConfiguration, ShadowQuality},
+};
 
 const PROJECTION_HALF_SIZE: f32 = 40.0;
 const PROJECTION_HALF_DEPTH: f32 = 100.0;
 
 pub fn directional_light_system(
     query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
-    mut query_light: Query<(&GlobalTransform, &mut Cascades), With<DirectionalLight>>,
+    mut query_light: Query<(&GlobalTransform, &mut Cascades, &mut DirectionalLight)>,
     views: Query<(Entity, &GlobalTransform), With<Camera>>,
     shadow_map: Res<DirectionalLightShadowMap>,
+    render_configuration: Res<RenderConfiguration>,
 ) {
+    if render_configuration.is_changed() {
+        let shadows_enabled = render_configuration.shadow_quality == ShadowQuality::ShadowMap;
+        for (_, _, mut directional_light) in query_light.iter_mut() {
+            if directional_light.shadows_enabled != shadows_enabled {
+                directional_light.shadows_enabled = shadows_enabled;
+            }
+        }
+    }
+
     let lookat_position = if let Ok(player_transform) = query_player.get_single() {
         player_transform.translation()
     } else if let Ok((_, camera_transform)) = views.get_single() {
@@ -22,7 +35,7 @@ pub fn directional_light_system(
         return;
     };
 
-    if let Ok((light_transform, mut cascades)) = query_light.get_single_mut() {
+    if let Ok((light_transform, mut cascades, _)) = query_light.get_single_mut() {
         let light_direction = light_transform.forward();
         let view = Mat4::look_at_rh(Vec3::ZERO, light_direction, Vec3::Y);
         let projected = view.mul_vec4(lookat_position.extend(1.0));
@@ -0,0 +1,83 @@
+use bevy::prelude::{EventReader, EventWriter, Query, Res};
+
+use rose_data::SoundId;
+
+use crate::{
+    components::ClientEntityName,
+    events::{ChatboxEvent, ItemAcquiredEvent, ItemAcquiredSource, ToastCategory, ToastEvent},
+    resources::{DropNotificationSettings, GameData},
+    ui::{get_item_rarity, ItemRarity, UiSoundEvent},
+};
+
+const RARE_DROP_FANFARE_SOUND_ID: u16 = 16;
+
+/// Sends the chatbox messages and toasts for items acquired by the player or their party,
+/// rare items play a fanfare and show a golden toast.
+pub fn drop_notification_system(
+    mut item_acquired_events: EventReader<ItemAcquiredEvent>,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+    mut ui_sound_events: EventWriter<UiSoundEvent>,
+    query_name: Query<&ClientEntityName>,
+    drop_notification_settings: Res<DropNotificationSettings>,
+    game_data: Res<GameData>,
+) {
+    for event in item_acquired_events.iter() {
+        let Some(item_data) = game_data
+            .items
+            .get_base_item(event.item.get_item_reference())
+        else {
+            continue;
+        };
+        let rarity = get_item_rarity(&game_data, &event.item);
+
+        let member_name = match event.source {
+            ItemAcquiredSource::PartyMember(entity) => {
+                let Ok(name) = query_name.get(entity) else {
+                    continue;
+                };
+                Some(name.as_str())
+            }
+            _ => None,
+        };
+
+        if !drop_notification_settings.mute_common_drops || rarity >= ItemRarity::Rare {
+            chatbox_events.send(ChatboxEvent::System(match member_name {
+                Some(member_name) => format!("{} has earned {}.", member_name, item_data.name),
+                None => format!("You have earned {}.", item_data.name),
+            }));
+        }
+
+        let fanfare_rarity = if member_name.is_some() {
+            drop_notification_settings.party_fanfare_rarity
+        } else {
+            drop_notification_settings.fanfare_rarity
+        };
+
+        if fanfare_rarity.map_or(false, |fanfare_rarity| rarity >= fanfare_rarity) {
+            toast_events.send(ToastEvent::new(
+                ToastCategory::RareItem,
+                match member_name {
+                    Some(member_name) => format!("{} looted {}!", member_name, item_data.name),
+                    None => format!("You looted {}!", item_data.name),
+                },
+            ));
+
+            if let Some(sound_id) = SoundId::new(RARE_DROP_FANFARE_SOUND_ID) {
+                ui_sound_events.send(UiSoundEvent::new(sound_id));
+            }
+        } else {
+            match event.source {
+                ItemAcquiredSource::Pickup => toast_events.send(ToastEvent::new(
+                    ToastCategory::Item,
+                    format!("Picked up {}", item_data.name),
+                )),
+                ItemAcquiredSource::Reward => toast_events.send(ToastEvent::new(
+                    ToastCategory::Item,
+                    format!("Received {}", item_data.name),
+                )),
+                ItemAcquiredSource::PartyMember(_) => {}
+            }
+        }
+    }
+}
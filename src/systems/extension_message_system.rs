@@ -0,0 +1,97 @@
+use bevy::prelude::{EventWriter, Query, Res, With};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    components::{ClientEntity, PartyInfo, PlayerCharacter},
+    events::{AppraisalEvent, CharacterTitleEvent, ChatboxEvent, FishingEvent, NpcStoreEvent},
+    protocol::ExtensionServerMessage,
+    resources::{GameConnection, WorldConnection},
+};
+
+/// Forwards messages for optional server features to the systems which handle them
+pub fn extension_message_system(
+    game_connection: Option<Res<GameConnection>>,
+    world_connection: Option<Res<WorldConnection>>,
+    mut appraisal_events: EventWriter<AppraisalEvent>,
+    mut character_title_events: EventWriter<CharacterTitleEvent>,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    mut fishing_events: EventWriter<FishingEvent>,
+    mut npc_store_events: EventWriter<NpcStoreEvent>,
+    query_player: Query<(&ClientEntity, &CharacterInfo, Option<&PartyInfo>), With<PlayerCharacter>>,
+) {
+    let game_messages = game_connection
+        .iter()
+        .flat_map(|game_connection| game_connection.extension_message_rx.try_iter());
+    let world_messages = world_connection
+        .iter()
+        .flat_map(|world_connection| world_connection.extension_message_rx.try_iter());
+
+    for message in game_messages.chain(world_messages) {
+        match message {
+            ExtensionServerMessage::AppraisalResult { item_slot, success } => {
+                appraisal_events.send(AppraisalEvent::Result { item_slot, success });
+            }
+            ExtensionServerMessage::FishingCastResult { accepted } => {
+                fishing_events.send(FishingEvent::CastResult { accepted });
+            }
+            ExtensionServerMessage::FishingBite { reel_window } => {
+                fishing_events.send(FishingEvent::Bite { reel_window });
+            }
+            ExtensionServerMessage::FishingResult { caught } => {
+                fishing_events.send(FishingEvent::Result { caught });
+            }
+            ExtensionServerMessage::NpcStoreStock {
+                npc_entity_id,
+                items,
+            } => {
+                npc_store_events.send(NpcStoreEvent::UpdateStock {
+                    npc_entity_id,
+                    items,
+                });
+            }
+            ExtensionServerMessage::CharacterTitle { entity_id, title } => {
+                character_title_events.send(CharacterTitleEvent::UpdateEntity { entity_id, title });
+            }
+            ExtensionServerMessage::CharacterTitleList {
+                titles,
+                active,
+                can_change,
+            } => {
+                character_title_events.send(CharacterTitleEvent::UpdateList {
+                    titles,
+                    active,
+                    can_change,
+                });
+            }
+            ExtensionServerMessage::ClanChat { name, text } => {
+                chatbox_events.send(ChatboxEvent::Clan(name, text));
+            }
+            ExtensionServerMessage::PartyChat { entity_id, text } => {
+                let Ok((player_client_entity, player_info, player_party)) =
+                    query_player.get_single()
+                else {
+                    continue;
+                };
+
+                // Party members may be in another zone, so look up the name from the party
+                let name = if player_client_entity.id == entity_id {
+                    Some(player_info.name.clone())
+                } else {
+                    player_party
+                        .and_then(|party| {
+                            party
+                                .members
+                                .iter()
+                                .find(|member| member.get_client_entity_id() == Some(entity_id))
+                        })
+                        .map(|member| member.get_name().to_string())
+                };
+
+                if let Some(name) = name {
+                    chatbox_events.send(ChatboxEvent::Party(name, text));
+                }
+            }
+        }
+    }
+}
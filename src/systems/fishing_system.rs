@@ -0,0 +1,265 @@
+use std::time::Duration;
+
+use bevy::{
+    math::{Vec2, Vec3, Vec3Swizzles},
+    prelude::{
+        Color, Commands, ComputedVisibility, DespawnRecursiveExt, EventReader, EventWriter, Gizmos,
+        GlobalTransform, Query, Res, ResMut, Transform, Visibility, With,
+    },
+    time::Time,
+};
+use bevy_rapier3d::prelude::{CollisionGroups, QueryFilter, RapierContext};
+
+use rose_file_readers::VfsPathBuf;
+
+use crate::{
+    components::{
+        Command, FacingDirection, PlayerCharacter, Position, ZoneObject,
+        COLLISION_FILTER_INSPECTABLE, COLLISION_GROUP_ZONE_OBJECT, COLLISION_GROUP_ZONE_TERRAIN,
+        COLLISION_GROUP_ZONE_WATER,
+    },
+    events::{ChatboxEvent, FishingEvent, SpawnEffectData, SpawnEffectEvent},
+    protocol::ExtensionClientMessage,
+    resources::{Fishing, FishingPhase, GameConnection},
+};
+
+const FISHING_BOBBER_EFFECT_PATH: &str = "3DDATA/SUPPLEMENTARY/FISHING_BOBBER.EFT";
+const FISHING_CAST_DISTANCE: f32 = 600.0;
+const FISHING_LINE_HEIGHT: f32 = 1.5;
+const FISHING_BOBBER_RADIUS: f32 = 0.1;
+
+/// How long to wait for the server to respond to a cast or reel before assuming it does not
+/// support fishing
+const FISHING_RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn stop_fishing(commands: &mut Commands, fishing: &mut Fishing) {
+    if let Some(bobber_entity) = fishing.bobber_entity.take() {
+        commands.entity(bobber_entity).despawn_recursive();
+    }
+    fishing.bobber_position = None;
+    fishing.phase = FishingPhase::Idle;
+}
+
+fn send_fishing_message(game_connection: Option<&GameConnection>, message: ExtensionClientMessage) {
+    if let Some(game_connection) = game_connection {
+        game_connection.extension_message_tx.send(message).ok();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn fishing_system(
+    mut commands: Commands,
+    mut fishing: ResMut<Fishing>,
+    mut fishing_events: EventReader<FishingEvent>,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    mut spawn_effect_events: EventWriter<SpawnEffectEvent>,
+    game_connection: Option<Res<GameConnection>>,
+    rapier_context: Res<RapierContext>,
+    query_player: Query<(&Command, &Position, &FacingDirection), With<PlayerCharacter>>,
+    query_zone_object: Query<&ZoneObject>,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+) {
+    let now = time.elapsed();
+    if game_connection
+        .as_ref()
+        .map_or(false, |game_connection| game_connection.is_added())
+    {
+        // A new connection may be to a server which does support fishing
+        fishing.unsupported = false;
+    }
+    let game_connection = game_connection.as_deref();
+
+    let Ok((player_command, player_position, player_facing)) = query_player.get_single() else {
+        if fishing.is_active() {
+            stop_fishing(&mut commands, &mut fishing);
+        }
+        return;
+    };
+
+    for event in fishing_events.iter() {
+        match *event {
+            FishingEvent::Cast => {
+                if fishing.unsupported {
+                    chatbox_events.send(ChatboxEvent::System(
+                        "Fishing is not available on this server.".to_string(),
+                    ));
+                    continue;
+                }
+
+                if fishing.is_active() {
+                    continue;
+                }
+
+                if !matches!(player_command, Command::Stop) {
+                    chatbox_events.send(ChatboxEvent::System(
+                        "You must be standing still to fish.".to_string(),
+                    ));
+                    continue;
+                }
+
+                // FacingDirection points away from the direction the character looks
+                let cast_position = player_position.position.xy()
+                    - FISHING_CAST_DISTANCE
+                        * Vec2::new(player_facing.actual.cos(), player_facing.actual.sin());
+                let ray_origin = Vec3::new(
+                    cast_position.x / 100.0,
+                    player_position.position.z / 100.0 + 100.0,
+                    -cast_position.y / 100.0,
+                );
+                let water_hit = rapier_context
+                    .cast_ray(
+                        ray_origin,
+                        Vec3::NEG_Y,
+                        1000.0,
+                        false,
+                        QueryFilter::new().groups(CollisionGroups::new(
+                            COLLISION_FILTER_INSPECTABLE,
+                            COLLISION_GROUP_ZONE_WATER
+                                | COLLISION_GROUP_ZONE_TERRAIN
+                                | COLLISION_GROUP_ZONE_OBJECT,
+                        )),
+                    )
+                    .filter(|(hit_entity, _)| {
+                        matches!(query_zone_object.get(*hit_entity), Ok(ZoneObject::Water))
+                    });
+
+                let Some((_, distance)) = water_hit else {
+                    chatbox_events.send(ChatboxEvent::System(
+                        "You must face open water to fish.".to_string(),
+                    ));
+                    continue;
+                };
+
+                let bobber_position = ray_origin + Vec3::NEG_Y * distance;
+                let bobber_entity = commands
+                    .spawn((
+                        Transform::from_translation(bobber_position),
+                        GlobalTransform::default(),
+                        Visibility::default(),
+                        ComputedVisibility::default(),
+                    ))
+                    .id();
+                spawn_effect_events.send(SpawnEffectEvent::InEntity(
+                    bobber_entity,
+                    SpawnEffectData::with_path(VfsPathBuf::new(FISHING_BOBBER_EFFECT_PATH))
+                        .manual_despawn(true),
+                ));
+
+                send_fishing_message(
+                    game_connection,
+                    ExtensionClientMessage::FishingCast {
+                        x: cast_position.x,
+                        y: cast_position.y,
+                    },
+                );
+                fishing.phase = FishingPhase::Casting { requested: now };
+                fishing.bobber_position = Some(bobber_position);
+                fishing.bobber_entity = Some(bobber_entity);
+            }
+            FishingEvent::CastResult { accepted } => {
+                if matches!(fishing.phase, FishingPhase::Casting { .. }) {
+                    if accepted {
+                        fishing.phase = FishingPhase::Waiting;
+                    } else {
+                        chatbox_events
+                            .send(ChatboxEvent::System("You cannot fish here.".to_string()));
+                        stop_fishing(&mut commands, &mut fishing);
+                    }
+                }
+            }
+            FishingEvent::Bite { reel_window } => {
+                if matches!(fishing.phase, FishingPhase::Waiting) {
+                    fishing.phase = FishingPhase::Bite {
+                        started: now,
+                        reel_window,
+                    };
+                }
+            }
+            FishingEvent::Reel { accuracy } => {
+                if matches!(fishing.phase, FishingPhase::Bite { .. }) {
+                    send_fishing_message(
+                        game_connection,
+                        ExtensionClientMessage::FishingReel { accuracy },
+                    );
+                    fishing.phase = FishingPhase::Reeling { requested: now };
+                }
+            }
+            FishingEvent::Result { caught } => {
+                if matches!(fishing.phase, FishingPhase::Reeling { .. }) {
+                    chatbox_events.send(ChatboxEvent::System(
+                        if caught {
+                            "You caught something!"
+                        } else {
+                            "It got away..."
+                        }
+                        .to_string(),
+                    ));
+                    stop_fishing(&mut commands, &mut fishing);
+                }
+            }
+            FishingEvent::Cancel => {
+                if fishing.is_active() {
+                    send_fishing_message(game_connection, ExtensionClientMessage::FishingCancel);
+                    stop_fishing(&mut commands, &mut fishing);
+                }
+            }
+        }
+    }
+
+    match fishing.phase {
+        FishingPhase::Idle => return,
+        FishingPhase::Casting { requested } | FishingPhase::Reeling { requested }
+            if now.saturating_sub(requested) > FISHING_RESPONSE_TIMEOUT =>
+        {
+            // The server never replied, assume it does not support fishing
+            fishing.unsupported = true;
+            chatbox_events.send(ChatboxEvent::System(
+                "Fishing is not available on this server.".to_string(),
+            ));
+            stop_fishing(&mut commands, &mut fishing);
+            return;
+        }
+        FishingPhase::Bite {
+            started,
+            reel_window,
+        } if now.saturating_sub(started) > reel_window => {
+            // Too slow, let the server decide what happens to a missed catch
+            send_fishing_message(
+                game_connection,
+                ExtensionClientMessage::FishingReel { accuracy: 0.0 },
+            );
+            fishing.phase = FishingPhase::Reeling { requested: now };
+        }
+        _ => {}
+    }
+
+    if !matches!(player_command, Command::Stop) {
+        // Moving, attacking or otherwise doing anything else reels the line back in
+        send_fishing_message(game_connection, ExtensionClientMessage::FishingCancel);
+        stop_fishing(&mut commands, &mut fishing);
+        return;
+    }
+
+    if let Some(bobber_position) = fishing.bobber_position {
+        let bobber_position = if let FishingPhase::Bite { started, .. } = fishing.phase {
+            // Bob the float whilst a fish is biting
+            let elapsed = now.saturating_sub(started).as_secs_f32();
+            bobber_position - Vec3::Y * 0.05 * (1.0 + (elapsed * 20.0).sin())
+        } else {
+            bobber_position
+        };
+        let rod_position = Vec3::new(
+            player_position.position.x / 100.0,
+            player_position.position.z / 100.0 + FISHING_LINE_HEIGHT,
+            -player_position.position.y / 100.0,
+        );
+
+        gizmos.line(
+            rod_position,
+            bobber_position,
+            Color::rgba(1.0, 1.0, 1.0, 0.6),
+        );
+        gizmos.circle(bobber_position, Vec3::Y, FISHING_BOBBER_RADIUS, Color::RED);
+    }
+}
@@ -12,6 +12,8 @@ use bevy::{
 use bevy_egui::EguiContexts;
 use dolly::prelude::{CameraRig, LeftHanded, Position, Smooth, YawPitch};
 
+use crate::resources::InputFocus;
+
 #[derive(Component)]
 pub struct FreeCamera {
     pub rig: CameraRig<LeftHanded>,
@@ -51,6 +53,7 @@ pub fn free_camera_system(
     mouse_buttons: Res<Input<MouseButton>>,
     mut query_window: Query<&mut Window, With<PrimaryWindow>>,
     mut egui_ctx: EguiContexts,
+    input_focus: Res<InputFocus>,
 ) {
     let Ok(mut window) = query_window.get_single_mut() else {
         return;
@@ -74,7 +77,7 @@ pub fn free_camera_system(
     };
 
     let allow_mouse_input = control_state.is_dragging || !egui_ctx.ctx_mut().wants_pointer_input();
-    let allow_keyboard_input = !egui_ctx.ctx_mut().wants_keyboard_input();
+    let allow_keyboard_input = !input_focus.keyboard_captured();
 
     let left_pressed = mouse_buttons.pressed(MouseButton::Left);
     let right_pressed = mouse_buttons.pressed(MouseButton::Right);
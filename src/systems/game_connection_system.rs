@@ -35,15 +35,19 @@ use crate::{
     components::{
         Bank, Clan, ClanMember, ClanMembership, ClientEntity, ClientEntityName, ClientEntityType,
         CollisionHeightOnly, CollisionPlayer, Command, CommandCastSkillTarget, Cooldowns, Dead,
-        FacingDirection, NextCommand, PartyInfo, PartyOwner, PassiveRecoveryTime, PendingDamage,
-        PendingDamageList, PendingSkillEffect, PendingSkillEffectList, PendingSkillTarget,
-        PendingSkillTargetList, PersonalStore, PlayerCharacter, Position, VisibleStatusEffects,
+        FacingDirection, Knockback, NextCommand, PartyInfo, PartyOwner, PassiveRecoveryTime,
+        PendingDamage, PendingDamageList, PendingSkillEffect, PendingSkillEffectList,
+        PendingSkillTarget, PendingSkillTargetList, PersonalStore, PlayerCharacter, Position,
+        VisibleStatusEffects,
     },
     events::{
-        BankEvent, ChatboxEvent, ClientEntityEvent, GameConnectionEvent, LoadZoneEvent,
-        MessageBoxEvent, PartyEvent, PersonalStoreEvent, QuestTriggerEvent, UseItemEvent,
+        BankEvent, ChatboxEvent, ClientEntityEvent, GameConnectionEvent, ItemAcquiredEvent,
+        ItemAcquiredSource, LoadZoneEvent, MessageBoxEvent, PartyEvent, PersonalStoreEvent,
+        QuestTriggerEvent, ToastCategory, ToastEvent, TutorialHint, TutorialHintEvent,
+        UseItemEvent,
     },
     resources::{AppState, ClientEntityList, GameConnection, GameData, WorldRates, WorldTime},
+    ui::format_money,
 };
 
 fn to_next_command(
@@ -78,6 +82,23 @@ fn to_next_command(
     }
 }
 
+// game_connection_system is at the system parameter limit, so toasts are sent via commands
+fn send_toast(commands: &mut Commands, category: ToastCategory, text: String) {
+    commands.add(move |world: &mut World| {
+        world
+            .resource_mut::<Events<ToastEvent>>()
+            .send(ToastEvent::new(category, text));
+    });
+}
+
+fn send_item_acquired(commands: &mut Commands, source: ItemAcquiredSource, item: Item) {
+    commands.add(move |world: &mut World| {
+        world
+            .resource_mut::<Events<ItemAcquiredEvent>>()
+            .send(ItemAcquiredEvent::new(source, item));
+    });
+}
+
 fn update_inventory_and_money(
     world: &mut World,
     player_entity: Entity,
@@ -137,6 +158,7 @@ pub fn game_connection_system(
     mut personal_store_events: EventWriter<PersonalStoreEvent>,
     mut quest_trigger_events: EventWriter<QuestTriggerEvent>,
     mut message_box_events: EventWriter<MessageBoxEvent>,
+    mut tutorial_hint_events: EventWriter<TutorialHintEvent>,
 ) {
     let Some(game_connection) = game_connection else {
         return;
@@ -499,7 +521,7 @@ pub fn game_connection_system(
                             format!("[{:?} {}]", item.get_item_type(), item.get_item_number())
                         }),
                     DroppedItem::Money(money) => {
-                        format!("{} Zuly", money.0)
+                        format!("{} Zuly", format_money(money.0))
                     }
                 };
 
@@ -538,9 +560,26 @@ pub fn game_connection_system(
             }
             Ok(ServerMessage::AdjustPosition { entity_id, position }) => {
                 if let Some(entity) = client_entity_list.get(entity_id) {
-                    commands
-                        .entity(entity)
-                        .insert(NextCommand::with_move(position, None, None));
+                    commands.add(move |world: &mut World| {
+                        let mut entity_mut = world.entity_mut(entity);
+
+                        // Slide entities which are recoiling from a hit, instead of walking
+                        let knockback_start =
+                            match (entity_mut.get::<Command>(), entity_mut.get::<Position>()) {
+                                (Some(command), Some(current_position))
+                                    if command.is_hit_stun() =>
+                                {
+                                    Some(current_position.position)
+                                }
+                                _ => None,
+                            };
+
+                        if let Some(knockback_start) = knockback_start {
+                            entity_mut.insert(Knockback::new(knockback_start, position));
+                        } else {
+                            entity_mut.insert(NextCommand::with_move(position, None, None));
+                        }
+                    });
                 }
             }
             Ok(ServerMessage::StopMoveEntity { entity_id, x: _, y: _, z: _ }) => {
@@ -692,6 +731,13 @@ pub fn game_connection_system(
                         ability_type, value,
                     )));
 
+                    if ability_type == AbilityType::Job {
+                        client_entity_events.send(ClientEntityEvent::JobChanged(
+                            player_entity,
+                            value as u16,
+                        ));
+                    }
+
                     commands.add(move |world: &mut World| {
                         let mut player = world.entity_mut(player_entity);
                         ability_values_set_value_exclusive(
@@ -997,12 +1043,20 @@ pub fn game_connection_system(
                     });
                 }
             }
-            Ok(ServerMessage::UpdateSpeed { entity_id, run_speed, passive_attack_speed: _ }) => {
-                // TODO: Use passive_attack_speed ?
+            Ok(ServerMessage::UpdateSpeed { entity_id, run_speed, passive_attack_speed }) => {
                 if let Some(entity) = client_entity_list.get(entity_id) {
                     commands
                         .entity(entity)
                         .insert(MoveSpeed::new(run_speed as f32));
+
+                    // Attack speed includes the passive bonus and scales attack animation speed
+                    commands.add(move |world: &mut World| {
+                        if let Some(mut ability_values) = world.get_mut::<AbilityValues>(entity) {
+                            ability_values.attack_speed +=
+                                passive_attack_speed - ability_values.passive_attack_speed;
+                            ability_values.passive_attack_speed = passive_attack_speed;
+                        }
+                    });
                 }
             }
             Ok(ServerMessage::UpdateStatusEffects { entity_id, status_effects: update_status_effects, updated_values }) => {
@@ -1089,14 +1143,7 @@ pub fn game_connection_system(
             }
             Ok(ServerMessage::PickupDropItem { drop_entity_id: _, item_slot, item }) => {
                 if let Some(player_entity) = client_entity_list.player_entity {
-                    if let Some(item_data) =
-                        game_data.items.get_base_item(item.get_item_reference())
-                    {
-                        chatbox_events.send(ChatboxEvent::System(format!(
-                            "You have earned {}.",
-                            item_data.name
-                        )));
-                    }
+                    send_item_acquired(&mut commands, ItemAcquiredSource::Pickup, item.clone());
 
                     commands.add(move |world: &mut World| {
                         let mut player = world.entity_mut(player_entity);
@@ -1107,13 +1154,15 @@ pub fn game_connection_system(
                             }
                         }
                     });
+
+                    tutorial_hint_events.send(TutorialHintEvent(TutorialHint::ItemPickup));
                 }
             }
             Ok(ServerMessage::PickupDropMoney { drop_entity_id: _, money }) => {
                 if let Some(player_entity) = client_entity_list.player_entity {
                     chatbox_events.send(ChatboxEvent::System(format!(
                         "You have earned {} Zuly.",
-                        money.0
+                        format_money(money.0)
                     )));
 
                     commands.add(move |world: &mut World| {
@@ -1139,15 +1188,8 @@ pub fn game_connection_system(
             },
             Ok(ServerMessage::RewardItems { items }) => {
                 if let Some(player_entity) = client_entity_list.player_entity {
-                    for (_, item) in items.iter() {
-                        if let Some(item_data) = item.as_ref().and_then(|item| {
-                            game_data.items.get_base_item(item.get_item_reference())
-                        }) {
-                            chatbox_events.send(ChatboxEvent::System(format!(
-                                "You have earned {}.",
-                                item_data.name
-                            )));
-                        }
+                    for item in items.iter().filter_map(|(_, item)| item.as_ref()) {
+                        send_item_acquired(&mut commands, ItemAcquiredSource::Reward, item.clone());
                     }
 
                     commands.add(move |world: &mut World| {
@@ -1167,7 +1209,7 @@ pub fn game_connection_system(
                 if let Some(player_entity) = client_entity_list.player_entity {
                     chatbox_events.send(ChatboxEvent::System(format!(
                         "You have earned {} Zuly.",
-                        money.0
+                        format_money(money.0)
                     )));
 
                     commands.add(move |world: &mut World| {
@@ -1240,6 +1282,8 @@ pub fn game_connection_system(
                     commands
                         .entity(player_entity)
                         .insert(updated_skill_points);
+
+                    tutorial_hint_events.send(TutorialHintEvent(TutorialHint::LearnSkill));
                 }
             }
             Ok(ServerMessage::LearnSkillError { error }) => match error {
@@ -1812,6 +1856,9 @@ pub fn game_connection_system(
                                             name: party_member_online.name.clone(),
                                         });
 
+                                    world.resource_mut::<Events<ToastEvent>>().send(
+                                        ToastEvent::new(ToastCategory::Social, message.clone()),
+                                    );
                                     world
                                         .resource_mut::<Events<ChatboxEvent>>()
                                         .send(ChatboxEvent::System(message));
@@ -1873,7 +1920,17 @@ pub fn game_connection_system(
                                         x.get_character_id() == member_info.character_id
                                     })
                                 {
+                                    let reconnected =
+                                        matches!(party_member, PartyMemberInfo::Offline(_));
+                                    let message =
+                                        format!("{} has come online.", &member_info.name);
                                     *party_member = PartyMemberInfo::Online(member_info);
+
+                                    if reconnected {
+                                        world.resource_mut::<Events<ToastEvent>>().send(
+                                            ToastEvent::new(ToastCategory::Social, message),
+                                        );
+                                    }
                                 }
                             }
                         }
@@ -1884,27 +1941,12 @@ pub fn game_connection_system(
                 client_entity_id,
                 item,
             }) => {
-                let member_entity = client_entity_list.get(client_entity_id);
-                let item_name = game_data
-                    .items
-                    .get_base_item(item.get_item_reference())
-                    .map(|item_data| item_data.name);
-
-                if let (Some(member_entity), Some(item_name)) = (member_entity, item_name) {
-                    commands.add(move |world: &mut World| {
-                        if let Some(member) = world.get_entity(member_entity) {
-                            if let Some(member_entity_name) = member.get::<ClientEntityName>() {
-                                let chat_message = format!(
-                                    "{} has earned {}.",
-                                    member_entity_name.as_str(),
-                                    item_name
-                                );
-                                world
-                                    .resource_mut::<Events<ChatboxEvent>>()
-                                    .send(ChatboxEvent::System(chat_message));
-                            }
-                        }
-                    });
+                if let Some(member_entity) = client_entity_list.get(client_entity_id) {
+                    send_item_acquired(
+                        &mut commands,
+                        ItemAcquiredSource::PartyMember(member_entity),
+                        item,
+                    );
                 }
             }
             Ok(ServerMessage::PartyUpdateRules { item_sharing, xp_sharing }) => {
@@ -2028,7 +2070,7 @@ pub fn game_connection_system(
                         let player = world.entity(player_entity);
 
                         if let Some(inventory) = player.get::<Inventory>() {
-                            let transaction_price = money.0 - inventory.money.0;
+                            let transaction_price = money.0.saturating_sub(inventory.money.0);
 
                             if let Some((item_slot, transaction_item)) = items.first() {
                                 let transaction_item = transaction_item.as_ref();
@@ -2065,7 +2107,7 @@ pub fn game_connection_system(
                                             },
                                             transaction_quantity,
                                             item_data.name,
-                                            transaction_price.abs()
+                                            format_money(transaction_price.saturating_abs())
                                         )
                                     } else {
                                         format!(
@@ -2076,7 +2118,7 @@ pub fn game_connection_system(
                                                 "sold"
                                             },
                                             item_data.name,
-                                            transaction_price.abs()
+                                            format_money(transaction_price.saturating_abs())
                                         )
                                     };
                                     let mut chatbox_events =
@@ -2302,8 +2344,26 @@ pub fn game_connection_system(
             Ok(ServerMessage::CraftInsertGemError { .. }) => {
                 log::warn!("Received unimplemented ServerMessage::CraftInsertGemError");
             }
-            Ok(ServerMessage::RepairedItemUsingNpc { .. }) => {
-                log::warn!("Received unimplemented ServerMessage::RepairedItemUsingNpc");
+            Ok(ServerMessage::RepairedItemUsingNpc { item_slot, item, updated_money }) => {
+                if let Some(player_entity) = client_entity_list.player_entity {
+                    if let Some(item_data) =
+                        game_data.items.get_base_item(item.get_item_reference())
+                    {
+                        chatbox_events.send(ChatboxEvent::System(format!(
+                            "Your {} has been repaired.",
+                            item_data.name
+                        )));
+                    }
+
+                    commands.add(move |world: &mut World| {
+                        update_inventory_and_money(
+                            world,
+                            player_entity,
+                            vec![(item_slot, Some(item))],
+                            Some(updated_money),
+                        );
+                    });
+                }
             }
             Ok(ServerMessage::LogoutSuccess) => {
                 log::warn!("Received unimplemented ServerMessage::LogoutSuccess");
@@ -2340,8 +2400,12 @@ pub fn game_connection_system(
     };
 
     if let Err(error) = result {
-        // TODO: Store error somewhere to display to user
         log::warn!("Game server connection error: {}", error);
+        send_toast(
+            &mut commands,
+            ToastCategory::Connection,
+            format!("Lost connection to game server: {}", error),
+        );
         commands.remove_resource::<GameConnection>();
     }
 }
@@ -1,10 +1,10 @@
 use bevy::{
-    ecs::query::WorldQuery,
+    ecs::{query::WorldQuery, system::SystemParam},
     input::Input,
-    math::Vec3,
+    math::{Vec3, Vec3Swizzles},
     prelude::{
-        Camera, Camera3d, Entity, EventWriter, GlobalTransform, MouseButton, Query, Res, ResMut,
-        With,
+        Camera, Camera3d, Entity, EventWriter, GlobalTransform, KeyCode, MouseButton, Query, Res,
+        ResMut, With, Without,
     },
     window::{CursorGrabMode, PrimaryWindow, Window},
 };
@@ -15,24 +15,39 @@ use rose_game_common::components::{ItemDrop, Team};
 
 use crate::{
     components::{
-        ClientEntity, ClientEntityType, ColliderParent, PlayerCharacter, Position, ZoneObject,
-        COLLISION_FILTER_CLICKABLE, COLLISION_GROUP_PHYSICS_TOY, COLLISION_GROUP_PLAYER,
+        ClientEntity, ClientEntityType, ColliderParent, Command, PlayerCharacter, Position,
+        SitPoints, TargetCamera, ZoneObject, COLLISION_FILTER_CLICKABLE,
+        COLLISION_FILTER_INSPECTABLE, COLLISION_GROUP_PHYSICS_TOY, COLLISION_GROUP_PLAYER,
+        COLLISION_GROUP_ZONE_WARP_OBJECT,
     },
     events::{MoveDestinationEffectEvent, PlayerCommandEvent},
-    resources::{SelectedTarget, UiCursorType, UiRequestedCursor},
+    resources::{
+        AutoTravel, InputFocus, InterfaceSettings, KeyBindingAction, KeyBindingInput, KeyBindings,
+        KeyModifier, SelectedTarget, UiCursorType, UiRequestedCursor,
+    },
 };
 
 #[derive(WorldQuery)]
 pub struct PlayerQuery<'w> {
     entity: Entity,
+    command: &'w Command,
+    position: &'w Position,
     team: &'w Team,
 }
 
+#[derive(SystemParam)]
+pub struct KeyboardInputParams<'w> {
+    keyboard_input: Res<'w, Input<KeyCode>>,
+    key_bindings: Res<'w, KeyBindings>,
+    input_focus: Res<'w, InputFocus>,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn game_mouse_input_system(
     mouse_button_input: Res<Input<MouseButton>>,
+    keyboard: KeyboardInputParams,
     query_window: Query<&Window, With<PrimaryWindow>>,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<TargetCamera>)>,
     rapier_context: Res<RapierContext>,
     mut egui_ctx: EguiContexts,
     query_collider_parent: Query<&ColliderParent>,
@@ -42,16 +57,33 @@ pub fn game_mouse_input_system(
         Option<&ItemDrop>,
         Option<&ZoneObject>,
         Option<&ClientEntity>,
+        Option<(&SitPoints, &GlobalTransform)>,
     )>,
     query_player: Query<PlayerQuery, With<PlayerCharacter>>,
     mut player_command_events: EventWriter<PlayerCommandEvent>,
     mut move_destination_effect_events: EventWriter<MoveDestinationEffectEvent>,
     mut selected_target: ResMut<SelectedTarget>,
     mut ui_requested_cursor: ResMut<UiRequestedCursor>,
+    mut auto_travel: ResMut<AutoTravel>,
+    interface_settings: Res<InterfaceSettings>,
 ) {
     selected_target.hover = None;
     ui_requested_cursor.world_cursor = UiCursorType::Default;
 
+    if selected_target.selected.is_none() {
+        selected_target.locked = false;
+    }
+
+    if !keyboard.input_focus.keyboard_captured()
+        && keyboard.key_bindings.just_pressed(
+            KeyBindingAction::ToggleTargetLock,
+            &keyboard.keyboard_input,
+            &mouse_button_input,
+        )
+    {
+        selected_target.locked = !selected_target.locked && selected_target.selected.is_some();
+    }
+
     let Ok(window) = query_window.get_single() else {
         return;
     };
@@ -70,6 +102,17 @@ pub fn game_mouse_input_system(
         return;
     }
 
+    // Interact may be bound to a key, in which case it acts on whatever is under the cursor
+    let interact_binding = keyboard.key_bindings.get(KeyBindingAction::Interact);
+    let interact_pressed = !(keyboard.input_focus.keyboard_captured()
+        && matches!(interact_binding.input, KeyBindingInput::Key(_)))
+        // Shift may be held to queue a waypoint
+        && interact_binding.just_pressed_with_modifier(
+            Some(KeyModifier::Shift),
+            &keyboard.keyboard_input,
+            &mouse_button_input,
+        );
+
     let player = if let Ok(player) = query_player.get_single() {
         player
     } else {
@@ -79,17 +122,81 @@ pub fn game_mouse_input_system(
         return;
     };
 
+    // Whilst fighting, optionally let clicks pass through friendly entities to what is behind them
+    let click_through_friendly = interface_settings.combat_click_through
+        && matches!(player.command, Command::Attack(_) | Command::CastSkill(_));
+    let is_not_friendly_collider = |collider_entity: Entity| -> bool {
+        let entity = query_collider_parent
+            .get(collider_entity)
+            .map_or(collider_entity, |collider_parent| collider_parent.entity);
+
+        query_hit_entity
+            .get(entity)
+            .map_or(true, |(team, _, _, _, client_entity, _)| {
+                let is_character_or_npc = client_entity.map_or(false, |client_entity| {
+                    matches!(
+                        client_entity.entity_type,
+                        ClientEntityType::Character | ClientEntityType::Npc
+                    )
+                });
+                let is_friendly_team = team.map_or(false, |team| {
+                    team.id == Team::DEFAULT_NPC_TEAM_ID || team.id == player.team.id
+                });
+
+                !(is_character_or_npc && is_friendly_team)
+            })
+    };
+
     if let Some(ray) = camera.viewport_to_world(camera_transform, cursor_position) {
-        if let Some((collider_entity, distance)) = rapier_context.cast_ray(
+        let mut clickable_filter = QueryFilter::new().groups(CollisionGroups::new(
+            COLLISION_FILTER_CLICKABLE,
+            !COLLISION_GROUP_PLAYER & !COLLISION_GROUP_PHYSICS_TOY,
+        ));
+        if click_through_friendly {
+            clickable_filter = clickable_filter.predicate(&is_not_friendly_collider);
+        }
+
+        let clickable_hit = rapier_context.cast_ray(
             ray.origin,
             ray.direction,
             10000000.0,
             false,
-            QueryFilter::new().groups(CollisionGroups::new(
-                COLLISION_FILTER_CLICKABLE,
-                !COLLISION_GROUP_PLAYER & !COLLISION_GROUP_PHYSICS_TOY,
-            )),
-        ) {
+            clickable_filter,
+        );
+
+        // Warp objects are not clickable, so they need their own ray cast to show the warp cursor
+        let warp_hit = rapier_context
+            .cast_ray(
+                ray.origin,
+                ray.direction,
+                10000000.0,
+                false,
+                QueryFilter::new().groups(CollisionGroups::new(
+                    COLLISION_FILTER_INSPECTABLE,
+                    COLLISION_GROUP_ZONE_WARP_OBJECT,
+                )),
+            )
+            .filter(|(_, warp_distance)| {
+                clickable_hit.map_or(true, |(_, distance)| *warp_distance < distance)
+            });
+
+        if let Some((_, distance)) = warp_hit {
+            ui_requested_cursor.world_cursor = UiCursorType::Warp;
+
+            if interface_settings.is_click_to_move_enabled() && interact_pressed {
+                // Walk into the warp gate, the warp itself is triggered by collision
+                let hit_position = ray.get_point(distance);
+                auto_travel.cancel();
+                player_command_events.send(PlayerCommandEvent::Move(
+                    Position::new(Vec3::new(
+                        hit_position.x * 100.0,
+                        -hit_position.z * 100.0,
+                        f32::max(0.0, hit_position.y * 100.0),
+                    )),
+                    None,
+                ));
+            }
+        } else if let Some((collider_entity, distance)) = clickable_hit {
             let hit_position = ray.get_point(distance);
             let hit_entity = query_collider_parent
                 .get(collider_entity)
@@ -101,6 +208,7 @@ pub fn game_mouse_input_system(
                 hit_item_drop,
                 hit_zone_object,
                 hit_client_entity,
+                hit_sit_points,
             )) = query_hit_entity.get(hit_entity)
             {
                 if let Some(hit_client_entity) = hit_client_entity {
@@ -126,16 +234,46 @@ pub fn game_mouse_input_system(
                     }
                 }
 
-                if hit_zone_object.is_some() {
-                    if mouse_button_input.just_pressed(MouseButton::Left) {
-                        player_command_events.send(PlayerCommandEvent::Move(
-                            Position::new(Vec3::new(
-                                hit_position.x * 100.0,
-                                -hit_position.z * 100.0,
-                                f32::max(0.0, hit_position.y * 100.0),
-                            )),
-                            None,
-                        ));
+                if let Some((sit_points, sit_points_transform)) = hit_sit_points {
+                    if interact_pressed {
+                        // Walk to the closest sit point, the sit_point_system will sit down
+                        // once we arrive
+                        if let Some((position, facing)) = sit_points
+                            .world_points(sit_points_transform)
+                            .min_by(|(a, _), (b, _)| {
+                                let player_position = player.position.position.xy();
+                                a.xy()
+                                    .distance_squared(player_position)
+                                    .total_cmp(&b.xy().distance_squared(player_position))
+                            })
+                        {
+                            auto_travel.cancel();
+                            player_command_events.send(PlayerCommandEvent::MoveToSitPoint(
+                                Position::new(position),
+                                facing,
+                            ));
+                        }
+                    }
+                } else if hit_zone_object.is_some() {
+                    if interface_settings.is_click_to_move_enabled() && interact_pressed {
+                        let destination = Vec3::new(
+                            hit_position.x * 100.0,
+                            -hit_position.z * 100.0,
+                            f32::max(0.0, hit_position.y * 100.0),
+                        );
+
+                        if keyboard
+                            .keyboard_input
+                            .any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+                        {
+                            // Shift click queues up a waypoint to travel to
+                            auto_travel.auto_run = false;
+                            auto_travel.waypoints.push_back(destination);
+                        } else {
+                            auto_travel.cancel();
+                            player_command_events
+                                .send(PlayerCommandEvent::Move(Position::new(destination), None));
+                        }
 
                         move_destination_effect_events.send(MoveDestinationEffectEvent::Show {
                             position: hit_position,
@@ -144,7 +282,9 @@ pub fn game_mouse_input_system(
                 } else if hit_item_drop.is_some() {
                     selected_target.hover = Some(hit_entity);
 
-                    if mouse_button_input.just_pressed(MouseButton::Left) {
+                    if interact_pressed {
+                        auto_travel.cancel();
+
                         if let Some(hit_entity_position) = hit_entity_position {
                             // Move to target item drop, once we are close enough the command_system
                             // will send the pickup client message to perform the actual pickup
@@ -157,11 +297,13 @@ pub fn game_mouse_input_system(
                 } else if let Some(hit_team) = hit_team {
                     selected_target.hover = Some(hit_entity);
 
-                    if mouse_button_input.just_pressed(MouseButton::Left) {
+                    if interact_pressed {
                         if selected_target
                             .selected
                             .map_or(false, |selected_entity| selected_entity == hit_entity)
                         {
+                            auto_travel.cancel();
+
                             if hit_team.id == Team::DEFAULT_NPC_TEAM_ID
                                 || hit_team.id == player.team.id
                             {
@@ -176,7 +318,7 @@ pub fn game_mouse_input_system(
                                 // Attack enemy
                                 player_command_events.send(PlayerCommandEvent::Attack(hit_entity));
                             }
-                        } else {
+                        } else if !selected_target.locked {
                             selected_target.selected = Some(hit_entity);
                         }
                     }
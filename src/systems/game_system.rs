@@ -48,6 +48,7 @@ pub fn game_zone_change_system(
                         .ok();
                 }
             }
+            ZoneEvent::LoadingProgress(_) => {}
         }
     }
 }
@@ -0,0 +1,159 @@
+use std::net::SocketAddr;
+
+use bevy::{
+    app::AppExit,
+    prelude::{Commands, Entity, EventWriter, Local, Query, Res},
+};
+
+use rose_data::{QuestTriggerHash, ZoneId};
+use rose_game_common::messages::{client::ClientMessage, server::ServerMessage};
+use rose_network_common::{Packet, PacketWriter};
+
+use crate::{
+    events::{LoadZoneEvent, QuestTriggerEvent},
+    protocol::{irose::GameClient, ExtensionClientMessage, ExtensionServerMessage},
+    resources::{CurrentZone, GameConnection, HeadlessScript},
+};
+
+#[derive(Default)]
+pub struct HeadlessScriptState {
+    frame: u32,
+    next_step: usize,
+    game_client: Option<GameClient>,
+    runtime: Option<tokio::runtime::Runtime>,
+}
+
+fn decode_hex(data: &str) -> Option<Vec<u8>> {
+    let data: String = data.chars().filter(|c| !c.is_whitespace()).collect();
+    if !data.is_ascii() || data.len() % 2 != 0 {
+        return None;
+    }
+
+    data.as_bytes()
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+pub fn headless_script_system(
+    mut commands: Commands,
+    mut state: Local<HeadlessScriptState>,
+    mut app_exit_events: EventWriter<AppExit>,
+    mut load_zone_events: EventWriter<LoadZoneEvent>,
+    mut quest_trigger_events: EventWriter<QuestTriggerEvent>,
+    current_zone: Option<Res<CurrentZone>>,
+    headless_script: Res<HeadlessScript>,
+    query_entities: Query<Entity>,
+) {
+    let state = &mut *state;
+
+    while let Some(step) = headless_script.steps.get(state.next_step) {
+        if step.frame > state.frame {
+            break;
+        }
+        state.next_step += 1;
+
+        if let Some(zone_id) = step.load_zone {
+            if let Some(zone_id) = ZoneId::new(zone_id) {
+                load_zone_events.send(LoadZoneEvent::new(zone_id));
+            } else {
+                headless_script.fail(&format!("Invalid zone id {}", zone_id));
+            }
+        }
+
+        if let Some(script_packet) = step.packet.as_ref() {
+            if state.game_client.is_none() {
+                // Packets are decoded by a real game client, with the resulting server
+                // messages handled by the game connection system as usual
+                let (client_message_tx, client_message_rx) =
+                    tokio::sync::mpsc::unbounded_channel::<ClientMessage>();
+                let (server_message_tx, server_message_rx) =
+                    crossbeam_channel::unbounded::<ServerMessage>();
+                let (extension_server_message_tx, extension_server_message_rx) =
+                    crossbeam_channel::unbounded::<ExtensionServerMessage>();
+                let (extension_client_message_tx, extension_client_message_rx) =
+                    tokio::sync::mpsc::unbounded_channel::<ExtensionClientMessage>();
+
+                state.game_client = Some(GameClient::new(
+                    SocketAddr::from(([127, 0, 0, 1], 0)),
+                    0,
+                    client_message_rx,
+                    server_message_tx,
+                    extension_client_message_rx,
+                    extension_server_message_tx,
+                    true,
+                    None,
+                ));
+                state.runtime = tokio::runtime::Builder::new_current_thread().build().ok();
+                commands.insert_resource(GameConnection {
+                    client_message_tx,
+                    server_message_rx,
+                    extension_message_tx: extension_client_message_tx,
+                    extension_message_rx: extension_server_message_rx,
+                });
+            }
+
+            if let (Some(game_client), Some(runtime), Some(data)) = (
+                state.game_client.as_ref(),
+                state.runtime.as_ref(),
+                decode_hex(&script_packet.data),
+            ) {
+                let mut writer = PacketWriter::new(script_packet.command);
+                for byte in data {
+                    writer.write_u8(byte);
+                }
+                let packet: Packet = writer.into();
+
+                if let Err(error) = runtime.block_on(game_client.handle_packet(&packet)) {
+                    headless_script.fail(&format!(
+                        "Error {} handling packet [{:03X}]",
+                        error, script_packet.command
+                    ));
+                }
+            } else {
+                headless_script.fail(&format!(
+                    "Invalid packet data for packet [{:03X}]",
+                    script_packet.command
+                ));
+            }
+        }
+
+        if let Some(trigger_name) = step.quest_trigger.as_ref() {
+            quest_trigger_events.send(QuestTriggerEvent::DoTrigger(QuestTriggerHash::from(
+                trigger_name.as_str(),
+            )));
+        }
+
+        if let Some(expect_zone) = step.expect_zone {
+            let current_zone_id = current_zone
+                .as_ref()
+                .map(|current_zone| current_zone.id.get());
+            if current_zone_id != Some(expect_zone) {
+                headless_script.fail(&format!(
+                    "Expected zone {} at frame {}, found {:?}",
+                    expect_zone, state.frame, current_zone_id
+                ));
+            }
+        }
+
+        if let Some(expect_entity_count) = step.expect_entity_count {
+            let entity_count = query_entities.iter().count();
+            if entity_count < expect_entity_count {
+                headless_script.fail(&format!(
+                    "Expected at least {} entities at frame {}, found {}",
+                    expect_entity_count, state.frame, entity_count
+                ));
+            }
+        }
+
+        if step.exit {
+            app_exit_events.send(AppExit);
+        }
+    }
+
+    if state.next_step >= headless_script.steps.len() {
+        app_exit_events.send(AppExit);
+    }
+
+    state.frame += 1;
+}
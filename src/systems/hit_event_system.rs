@@ -1,6 +1,8 @@
 use bevy::{
     ecs::query::WorldQuery,
-    prelude::{Commands, Entity, EventReader, EventWriter, GlobalTransform, Query, Res, ResMut},
+    prelude::{
+        Commands, Entity, EventReader, EventWriter, GlobalTransform, Query, Res, ResMut, Without,
+    },
 };
 
 use rose_game_common::{
@@ -9,14 +11,19 @@ use rose_game_common::{
 };
 
 use crate::{
+    animation::SkeletalAnimation,
     components::{
-        ClientEntity, ClientEntityType, Dead, ModelHeight, NextCommand, PendingDamageList,
-        PendingSkillEffectList, PendingSkillTargetList,
+        ClientEntity, ClientEntityType, Command, CommandAttack, Dead, ModelHeight, NextCommand,
+        PendingDamageList, PendingSkillEffectList, PendingSkillTargetList,
     },
     events::{HitEvent, SpawnEffectData, SpawnEffectEvent},
     resources::{ClientEntityList, DamageDigitsSpawner, GameData},
 };
 
+// A tiny pause of the attacker and defender animations when a hit lands to give it some weight
+const HIT_STOP_DURATION: f32 = 0.05;
+const HIT_STOP_CRITICAL_DURATION: f32 = 0.1;
+
 #[derive(WorldQuery)]
 #[world_query(mutable)]
 pub struct HitAttackerQuery<'w> {
@@ -29,6 +36,9 @@ pub struct HitAttackerQuery<'w> {
 pub struct HitDefenderQuery<'w> {
     entity: Entity,
     client_entity: &'w ClientEntity,
+    command: &'w Command,
+    next_command: &'w mut NextCommand,
+    dead: Option<&'w Dead>,
     pending_damage_list: &'w mut PendingDamageList,
     pending_skill_effect_list: &'w mut PendingSkillEffectList,
     ability_values: &'w AbilityValues,
@@ -45,7 +55,7 @@ fn apply_damage(
     defender: &mut HitDefenderQueryItem,
     damage: Damage,
     is_killed: bool,
-    damage_digits_spawner: &DamageDigitsSpawner,
+    damage_digits_spawner: &mut DamageDigitsSpawner,
     client_entity_list: &mut ClientEntityList,
 ) {
     if defender.health_points.hp < damage.amount as i32 {
@@ -55,7 +65,6 @@ fn apply_damage(
     }
 
     damage_digits_spawner.spawn(
-        commands,
         defender.global_transform,
         defender
             .model_height
@@ -82,10 +91,11 @@ fn apply_damage(
 pub fn hit_event_system(
     mut commands: Commands,
     mut query_defender: Query<HitDefenderQuery>,
+    mut query_skeletal_animation: Query<&mut SkeletalAnimation, Without<Dead>>,
     mut hit_events: EventReader<HitEvent>,
     mut spawn_effect_events: EventWriter<SpawnEffectEvent>,
     mut client_entity_list: ResMut<ClientEntityList>,
-    damage_digits_spawner: Res<DamageDigitsSpawner>,
+    mut damage_digits_spawner: ResMut<DamageDigitsSpawner>,
     game_data: Res<GameData>,
 ) {
     for event in hit_events.iter() {
@@ -102,6 +112,8 @@ pub fn hit_event_system(
             apply_hit_stun: false,
         };
 
+        let mut is_miss = false;
+
         if event.apply_damage {
             let mut i = 0;
             let mut is_killed = false;
@@ -124,18 +136,58 @@ pub fn hit_event_system(
                 }
             }
 
+            is_miss = has_damage && damage.amount == 0 && !is_killed;
+
+            if damage.apply_hit_stun
+                && !is_killed
+                && defender.dead.is_none()
+                && defender.next_command.is_none()
+            {
+                // Stagger the defender, resuming their attack afterwards
+                match *defender.command {
+                    Command::Stop => *defender.next_command = NextCommand::with_hit_stun(None),
+                    Command::Attack(CommandAttack { target }) => {
+                        *defender.next_command = NextCommand::with_hit_stun(Some(target))
+                    }
+                    _ => {}
+                }
+            }
+
             if has_damage || !event.ignore_miss {
                 apply_damage(
                     &mut commands,
                     &mut defender,
                     damage,
                     is_killed,
-                    &damage_digits_spawner,
+                    &mut damage_digits_spawner,
                     &mut client_entity_list,
                 );
             }
         }
 
+        if is_miss {
+            // No hit effects when the attack missed
+            continue;
+        }
+
+        if damage.amount > 0 {
+            let hit_stop = if damage.is_critical {
+                HIT_STOP_CRITICAL_DURATION
+            } else {
+                HIT_STOP_DURATION
+            };
+
+            for entity in [event.attacker, defender.entity] {
+                if let Ok(mut skeletal_animation) = query_skeletal_animation.get_mut(entity) {
+                    skeletal_animation.set_hit_stop(hit_stop);
+                }
+            }
+        }
+
+        if !event.spawn_hit_effects {
+            continue;
+        }
+
         if let Some(effect_data) = event
             .effect_id
             .and_then(|id| game_data.effect_database.get_effect(id))
@@ -162,7 +214,8 @@ pub fn hit_event_system(
                 spawn_effect_events.send(SpawnEffectEvent::OnEntity(
                     defender.entity,
                     skill_data.hit_link_dummy_bone_id,
-                    SpawnEffectData::with_file_id(effect_file_id),
+                    SpawnEffectData::with_file_id(effect_file_id)
+                        .with_skill_source(event.attacker, skill_data.id),
                 ));
             }
         }
@@ -0,0 +1,43 @@
+use bevy::prelude::{
+    Changed, Entity, EventReader, Input, KeyCode, MouseButton, Query, Res, ResMut, With,
+};
+
+use crate::{
+    components::{Command, PlayerCharacter},
+    events::HitEvent,
+    resources::{HudSettings, InputFocus, KeyBindingAction, KeyBindings},
+};
+
+pub fn hud_visibility_system(
+    mut hud_settings: ResMut<HudSettings>,
+    mut hit_events: EventReader<HitEvent>,
+    query_player: Query<Entity, With<PlayerCharacter>>,
+    query_player_command: Query<&Command, (With<PlayerCharacter>, Changed<Command>)>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    input_focus: Res<InputFocus>,
+    key_bindings: Res<KeyBindings>,
+) {
+    if !input_focus.keyboard_captured()
+        && key_bindings.just_pressed(KeyBindingAction::ToggleHud, &keyboard_input, &mouse_input)
+    {
+        hud_settings.hidden = !hud_settings.hidden;
+    }
+
+    if !hud_settings.hidden || !hud_settings.restore_on_combat {
+        hit_events.clear();
+        return;
+    }
+
+    let player_entity = query_player.get_single().ok();
+    let player_attacked = hit_events
+        .iter()
+        .any(|hit_event| Some(hit_event.defender) == player_entity);
+    let player_attacking = query_player_command.get_single().map_or(false, |command| {
+        matches!(command, Command::Attack(_) | Command::CastSkill(_))
+    });
+
+    if player_attacked || player_attacking {
+        hud_settings.hidden = false;
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::ResMut;
+use bevy_egui::EguiContexts;
+
+use crate::resources::InputFocus;
+
+pub fn input_focus_system(mut egui_context: EguiContexts, mut input_focus: ResMut<InputFocus>) {
+    // egui keeps widget focus from the previous frame, so this is stable for the whole frame
+    let text_input = egui_context.ctx_mut().wants_keyboard_input();
+    input_focus.update(text_input);
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::{Changed, EventWriter, Local, Query, Res, With};
+use enum_map::EnumMap;
+
+use rose_data::EquipmentIndex;
+use rose_game_common::components::Equipment;
+
+use crate::{
+    components::PlayerCharacter, events::ChatboxEvent, resources::GameData,
+    ui::LOW_DURABILITY_ITEM_LIFE,
+};
+
+/// Warns in the chatbox when an equipped item is about to break or has broken.
+pub fn item_durability_warning_system(
+    mut previous_life: Local<EnumMap<EquipmentIndex, Option<u16>>>,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    query_player: Query<&Equipment, (With<PlayerCharacter>, Changed<Equipment>)>,
+    game_data: Res<GameData>,
+) {
+    let Ok(equipment) = query_player.get_single() else {
+        return;
+    };
+
+    for (equipment_index, equipment_item) in equipment.equipped_items.iter() {
+        let life = equipment_item.as_ref().map(|item| item.life);
+        let previous = std::mem::replace(&mut previous_life[equipment_index], life);
+
+        let (Some(equipment_item), Some(life)) = (equipment_item.as_ref(), life) else {
+            continue;
+        };
+        let Some(item_data) = game_data.items.get_base_item(equipment_item.item) else {
+            continue;
+        };
+
+        if life == 0 {
+            if previous.map_or(true, |previous| previous > 0) {
+                chatbox_events.send(ChatboxEvent::System(format!(
+                    "Your {} has broken and must be repaired.",
+                    item_data.name
+                )));
+            }
+        } else if life <= LOW_DURABILITY_ITEM_LIFE
+            && previous.map_or(true, |previous| previous > LOW_DURABILITY_ITEM_LIFE)
+        {
+            chatbox_events.send(ChatboxEvent::System(format!(
+                "Your {} is about to break, visit a repair NPC.",
+                item_data.name
+            )));
+        }
+    }
+}
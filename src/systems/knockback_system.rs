@@ -0,0 +1,24 @@
+use bevy::prelude::{Commands, Entity, Query, Res, Time};
+
+use crate::components::{Knockback, Position};
+
+pub fn knockback_system(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Knockback, &mut Position)>,
+    time: Res<Time>,
+) {
+    for (entity, mut knockback, mut position) in query.iter_mut() {
+        knockback.time += time.delta_seconds();
+
+        let t = (knockback.time / knockback.duration).min(1.0);
+        let translation = knockback
+            .start
+            .lerp(knockback.end, 1.0 - (1.0 - t) * (1.0 - t));
+        position.x = translation.x;
+        position.y = translation.y;
+
+        if t >= 1.0 {
+            commands.entity(entity).remove::<Knockback>();
+        }
+    }
+}
@@ -7,9 +7,10 @@ use rose_game_common::{
 use rose_network_common::ConnectionError;
 
 use crate::{
-    events::NetworkEvent,
+    events::{NetworkEvent, ToastCategory, ToastEvent},
     resources::{
-        Account, LoginConnection, ServerList, ServerListGameServer, ServerListWorldServer,
+        teardown_game_connection, Account, ChannelQueue, ChannelSwitch, ChannelSwitchStage,
+        ClientEntityList, LoginConnection, ServerList, ServerListGameServer, ServerListWorldServer,
     },
 };
 
@@ -18,7 +19,11 @@ pub fn login_connection_system(
     account: Option<Res<Account>>,
     login_connection: Option<Res<LoginConnection>>,
     mut server_list: Option<ResMut<ServerList>>,
+    mut channel_queue: ResMut<ChannelQueue>,
+    mut channel_switch: ResMut<ChannelSwitch>,
+    mut client_entity_list: ResMut<ClientEntityList>,
     mut network_events: EventWriter<NetworkEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
 ) {
     if login_connection.is_none() {
         return;
@@ -93,6 +98,12 @@ pub fn login_connection_system(
                 port,
             }) => {
                 if let Some(account) = account.as_ref() {
+                    if channel_switch.stage == Some(ChannelSwitchStage::JoiningChannel) {
+                        // The new channel accepted us, only now do we leave the current one
+                        teardown_game_connection(&mut commands, &mut client_entity_list);
+                        channel_switch.stage = Some(ChannelSwitchStage::ConnectingWorld);
+                    }
+
                     network_events.send(NetworkEvent::ConnectWorld {
                         ip,
                         port,
@@ -105,7 +116,19 @@ pub fn login_connection_system(
                 }
             }
             Ok(ServerMessage::JoinServerError { error }) => {
-                break Err(error.into());
+                if channel_switch.stage == Some(ChannelSwitchStage::JoiningChannel) {
+                    toast_events.send(ToastEvent::new(
+                        ToastCategory::Connection,
+                        format!("Unable to change channel: {}", error),
+                    ));
+                    channel_switch.cancel();
+                } else if channel_queue.joining.is_some() {
+                    // Keep the login connection alive so we can try another channel
+                    log::info!("Join channel rejected: {}", error);
+                    channel_queue.rejected = true;
+                } else {
+                    break Err(error.into());
+                }
             }
             Ok(message) => {
                 log::warn!("Received unexpected login server message: {:#?}", message);
@@ -2,6 +2,7 @@ use bevy::{
     prelude::{
         AssetServer, Camera3d, Commands, Entity, EventReader, EventWriter, Query, Res, ResMut, With,
     },
+    time::Time,
     window::{CursorGrabMode, PrimaryWindow, Window},
 };
 use bevy_egui::{egui, EguiContexts};
@@ -12,7 +13,10 @@ use rose_game_common::messages::client::ClientMessage;
 use crate::{
     animation::CameraAnimation,
     events::{LoadZoneEvent, LoginEvent, NetworkEvent},
-    resources::{Account, LoginConnection, LoginState, ServerConfiguration, ServerList},
+    resources::{
+        Account, ChannelQueue, LoginConnection, LoginState, ServerList, ServerProfiles,
+        CHANNEL_QUEUE_RETRY_DELAY,
+    },
     systems::{FreeCamera, OrbitCamera},
 };
 
@@ -42,25 +46,108 @@ pub fn login_state_enter_system(
     }
 
     commands.remove_resource::<Account>();
+    commands.remove_resource::<LoginConnection>();
     commands.insert_resource(LoginState::Input);
 
     loaded_zone.send(LoadZoneEvent::new(ZoneId::new(4).unwrap()));
 }
 
 pub fn login_state_exit_system(mut commands: Commands) {
-    commands.remove_resource::<LoginConnection>();
+    // The login connection is kept alive after login so we can later change channel, the login
+    // server only accepts JoinServer from the session which authenticated the account and will
+    // reject a fresh login for an account which is already in game
     commands.remove_resource::<LoginState>();
 }
 
+fn join_next_channel(
+    channel_queue: &mut ChannelQueue,
+    login_connection: &LoginConnection,
+    server_list: &ServerList,
+) -> bool {
+    let Some((server_id, current_channel_id)) = channel_queue.joining else {
+        return false;
+    };
+
+    // Continue from the channel after the one which failed, rather than from the first channel
+    let next_channel = server_list
+        .world_servers
+        .iter()
+        .find(|world_server| world_server.id == server_id)
+        .and_then(|world_server| {
+            let game_servers = &world_server.game_servers;
+            let start = game_servers
+                .iter()
+                .position(|game_server| game_server.id == current_channel_id)
+                .map_or(0, |index| index + 1);
+
+            game_servers
+                .iter()
+                .cycle()
+                .skip(start)
+                .take(game_servers.len())
+                .find(|game_server| !channel_queue.rejected_channels.contains(&game_server.id))
+        });
+
+    if let Some(game_server) = next_channel {
+        login_connection
+            .client_message_tx
+            .send(ClientMessage::JoinServer {
+                server_id,
+                channel_id: game_server.id,
+            })
+            .ok();
+        channel_queue.joining = Some((server_id, game_server.id));
+        channel_queue.attempts += 1;
+        true
+    } else {
+        false
+    }
+}
+
 pub fn login_system(
     mut egui_context: EguiContexts,
     login_connection: Option<Res<LoginConnection>>,
     mut login_state: ResMut<LoginState>,
+    mut channel_queue: ResMut<ChannelQueue>,
     server_list: Option<Res<ServerList>>,
+    time: Res<Time>,
 ) {
     if !matches!(*login_state, LoginState::Input) && login_connection.is_none() {
         // When we lose login server connection, return to login
         *login_state = LoginState::Input;
+        channel_queue.reset();
+    }
+
+    if matches!(*login_state, LoginState::JoiningServer) {
+        if let (Some(login_connection), Some(server_list)) =
+            (login_connection.as_ref(), server_list.as_ref())
+        {
+            if let Some(retry_remaining) = channel_queue.retry_remaining {
+                let retry_remaining = retry_remaining.saturating_sub(time.delta());
+
+                if retry_remaining.is_zero() {
+                    channel_queue.retry_remaining = None;
+                    channel_queue.rejected_channels.clear();
+                    join_next_channel(&mut channel_queue, login_connection, server_list);
+                } else {
+                    channel_queue.retry_remaining = Some(retry_remaining);
+                }
+            } else if channel_queue.rejected {
+                channel_queue.rejected = false;
+
+                if let Some((_, channel_id)) = channel_queue.joining {
+                    channel_queue.rejected_channels.push(channel_id);
+                }
+
+                if !channel_queue.auto_next_channel {
+                    channel_queue.reset();
+                    *login_state = LoginState::ServerSelect;
+                } else if !join_next_channel(&mut channel_queue, login_connection, server_list) {
+                    // Every channel is full, wait before trying them all again
+                    channel_queue.retry_remaining = Some(CHANNEL_QUEUE_RETRY_DELAY);
+                }
+            }
+        }
     }
 
     if matches!(*login_state, LoginState::WaitServerList) && server_list.is_some() {
@@ -78,12 +165,55 @@ pub fn login_system(
                 });
         }
         LoginState::JoiningServer => {
+            let channel_name = channel_queue.joining.and_then(|(server_id, channel_id)| {
+                server_list.as_ref().and_then(|server_list| {
+                    server_list
+                        .world_servers
+                        .iter()
+                        .find(|world_server| world_server.id == server_id)
+                        .and_then(|world_server| {
+                            world_server
+                                .game_servers
+                                .iter()
+                                .find(|game_server| game_server.id == channel_id)
+                        })
+                        .map(|game_server| game_server.name.clone())
+                })
+            });
+            let mut cancel = false;
+
             egui::Window::new("Connecting...")
                 .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
                 .collapsible(false)
                 .show(egui_context.ctx_mut(), |ui| {
-                    ui.label("Connecting to channel");
+                    if let Some(retry_remaining) = channel_queue.retry_remaining {
+                        ui.label("All channels are full, waiting in queue");
+                        ui.label(format!(
+                            "Retrying in {} seconds",
+                            retry_remaining.as_secs() + 1
+                        ));
+                    } else if let Some(channel_name) = channel_name.as_ref() {
+                        ui.label(format!("Connecting to channel {}", channel_name));
+                    } else {
+                        ui.label("Connecting to channel");
+                    }
+
+                    if channel_queue.attempts > 1 {
+                        ui.label(format!("Attempt {}", channel_queue.attempts));
+                    }
+
+                    ui.checkbox(
+                        &mut channel_queue.auto_next_channel,
+                        "Automatically try next channel",
+                    );
+
+                    cancel = ui.button("Cancel").clicked();
                 });
+
+            if cancel {
+                channel_queue.reset();
+                *login_state = LoginState::ServerSelect;
+            }
         }
         _ => {}
     }
@@ -94,13 +224,26 @@ pub fn login_event_system(
     mut login_state: ResMut<LoginState>,
     mut login_events: EventReader<LoginEvent>,
     login_connection: Option<Res<LoginConnection>>,
-    server_configuration: Res<ServerConfiguration>,
+    mut channel_queue: ResMut<ChannelQueue>,
+    server_profiles: Res<ServerProfiles>,
     mut network_events: EventWriter<NetworkEvent>,
 ) {
     for event in login_events.iter() {
         match event {
             LoginEvent::Login { username, password } => {
                 if matches!(*login_state, LoginState::Input) {
+                    let Some(server_profile) = server_profiles.selected() else {
+                        continue;
+                    };
+
+                    if server_profile.network_version != "irose" {
+                        log::warn!(
+                            "Server profile {} uses unsupported network version {}",
+                            server_profile.name,
+                            server_profile.network_version
+                        );
+                    }
+
                     *login_state = LoginState::WaitServerList;
 
                     commands.insert_resource(Account {
@@ -109,8 +252,8 @@ pub fn login_event_system(
                     });
 
                     network_events.send(NetworkEvent::ConnectLogin {
-                        ip: server_configuration.ip.clone(),
-                        port: server_configuration.port.parse::<u16>().unwrap_or(29000),
+                        ip: server_profile.ip.clone(),
+                        port: server_profile.port,
                     });
                 }
             }
@@ -127,6 +270,7 @@ pub fn login_event_system(
                         })
                         .ok();
                 }
+                channel_queue.start(server_id, channel_id);
                 *login_state = LoginState::JoiningServer;
             }
         }
@@ -0,0 +1,36 @@
+use bevy::{
+    math::Vec3Swizzles,
+    prelude::{Local, Query, Res, ResMut, With},
+};
+
+use rose_data::ZoneId;
+
+use crate::{
+    components::{PlayerCharacter, Position},
+    resources::{CurrentZone, MapExploration},
+};
+
+// Everything within this distance (cm) of the player is revealed on the minimap
+const MAP_EXPLORATION_REVEAL_RADIUS: f32 = 6000.0;
+
+pub fn map_exploration_system(
+    mut last_cell: Local<Option<(ZoneId, (i32, i32))>>,
+    mut map_exploration: ResMut<MapExploration>,
+    current_zone: Option<Res<CurrentZone>>,
+    query_player: Query<&Position, With<PlayerCharacter>>,
+) {
+    let (Some(current_zone), Ok(player_position)) = (current_zone, query_player.get_single())
+    else {
+        return;
+    };
+
+    // Only update exploration when the player moves into a different cell
+    let position = player_position.position.xy();
+    let cell = (current_zone.id, MapExploration::get_cell(position));
+    if *last_cell == Some(cell) {
+        return;
+    }
+    *last_cell = Some(cell);
+
+    map_exploration.explore(current_zone.id, position, MAP_EXPLORATION_REVEAL_RADIUS);
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::{Assets, Color, Gizmos, Res, ResMut, Time, Vec3};
+
+use crate::{
+    resources::{CurrentZone, MapPings},
+    zone_loader::ZoneLoaderAsset,
+};
+
+const MAP_PING_MARKER_RADIUS: f32 = 1.5;
+const MAP_PING_MARKER_HEIGHT: f32 = 20.0;
+
+pub fn map_ping_system(
+    mut map_pings: ResMut<MapPings>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+) {
+    let now = time.elapsed();
+    map_pings.update(now);
+
+    let Some(current_zone) = current_zone else {
+        return;
+    };
+    let Some(current_zone_data) = zone_loader_assets.get(&current_zone.handle) else {
+        return;
+    };
+
+    for ping in map_pings.pings.iter() {
+        if ping.zone_id != current_zone.id {
+            continue;
+        }
+
+        let flash = MapPings::flash(ping, now);
+        let color = Color::rgba(1.0, 0.85, 0.2, 0.25 + 0.75 * flash);
+        let base = Vec3::new(
+            ping.position.x / 100.0,
+            current_zone_data.get_terrain_height(ping.position.x, ping.position.y) / 100.0,
+            -ping.position.y / 100.0,
+        );
+
+        gizmos.line(base, base + Vec3::Y * MAP_PING_MARKER_HEIGHT, color);
+        gizmos.circle(
+            base + Vec3::Y * 0.1,
+            Vec3::Y,
+            MAP_PING_MARKER_RADIUS * (1.0 + flash),
+            color,
+        );
+    }
+}
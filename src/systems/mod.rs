@@ -1,35 +1,58 @@
 mod ability_values_system;
+mod achievement_system;
 mod animation_effect_system;
 mod animation_sound_system;
 mod auto_login_system;
+mod auto_travel_system;
+mod away_system;
 mod background_music_system;
+mod blob_shadow_system;
+mod bloom_system;
+mod channel_switch_system;
 mod character_model_add_collider_system;
 mod character_model_blink_system;
 mod character_model_system;
 mod character_select_system;
+mod character_settings_system;
+mod character_title_system;
 mod clan_system;
 mod client_entity_event_system;
 mod collision_system;
 mod command_system;
+mod companion_system;
 mod conversation_dialog_system;
 mod cooldown_system;
 mod damage_digit_render_system;
 mod debug_inspector_system;
 mod debug_render_collider_system;
+mod debug_render_command_system;
 mod debug_render_directional_light_system;
+mod debug_render_monster_spawn_system;
+mod debug_render_range_system;
 mod debug_render_skeleton_system;
 mod directional_light_system;
+mod drop_notification_system;
 mod effect_system;
+mod extension_message_system;
 mod facing_direction_system;
+mod fishing_system;
 mod free_camera_system;
 mod game_connection_system;
 mod game_mouse_input_system;
 mod game_system;
+mod headless_script_system;
 mod hit_event_system;
+mod hud_visibility_system;
+mod input_focus_system;
 mod item_drop_model_system;
+mod item_durability_warning_system;
+mod knockback_system;
 mod login_connection_system;
 mod login_system;
+mod map_exploration_system;
+mod map_ping_system;
 mod model_viewer_system;
+mod monster_aggro_system;
 mod move_destination_effect_system;
 mod name_tag_system;
 mod name_tag_update_color_system;
@@ -37,6 +60,7 @@ mod name_tag_update_healthbar_system;
 mod name_tag_visibility_system;
 mod network_thread_system;
 mod npc_idle_sound_system;
+mod npc_idle_system;
 mod npc_model_add_collider_system;
 mod npc_model_system;
 mod orbit_camera_system;
@@ -47,63 +71,109 @@ mod pending_skill_effect_system;
 mod personal_store_model_add_collider_system;
 mod personal_store_model_system;
 mod player_command_system;
+mod player_keyboard_move_system;
 mod projectile_system;
 mod quest_trigger_system;
+mod rest_system;
+mod session_stats_system;
+mod sit_point_system;
+mod sound_caption_system;
+mod sound_effect_system;
 mod spawn_effect_system;
 mod spawn_projectile_system;
+mod spectate_camera_system;
 mod status_effect_system;
+mod stun_system;
 mod systemfunc_event_system;
+mod target_camera_system;
+mod terrain_view_distance_system;
+mod tutorial_hint_system;
+mod unstick_system;
 mod update_position_system;
 mod use_item_event_system;
+mod vehicle_exhaust_system;
 mod vehicle_model_system;
 mod vehicle_sound_system;
 mod visible_status_effects_system;
+mod water_splash_system;
+mod weapon_trail_system;
+mod window_focus_system;
 mod world_connection_system;
 mod world_time_system;
+mod zone_preload_system;
 mod zone_time_system;
 mod zone_viewer_system;
 
 pub use ability_values_system::ability_values_system;
+pub use achievement_system::{
+    achievement_system, is_boss_npc, ACHIEVEMENT_LEVEL_MILESTONES, ACHIEVEMENT_ZONE_MILESTONES,
+};
 pub use animation_effect_system::animation_effect_system;
 pub use animation_sound_system::animation_sound_system;
 pub use auto_login_system::auto_login_system;
+pub use auto_travel_system::auto_travel_system;
+pub use away_system::away_system;
 pub use background_music_system::background_music_system;
+pub use blob_shadow_system::blob_shadow_system;
+pub use bloom_system::bloom_system;
+pub use channel_switch_system::channel_switch_system;
 pub use character_model_add_collider_system::character_model_add_collider_system;
 pub use character_model_blink_system::character_model_blink_system;
 pub use character_model_system::character_model_update_system;
 pub use character_select_system::{
     character_select_enter_system, character_select_event_system, character_select_exit_system,
     character_select_input_system, character_select_models_system, character_select_system,
+    CharacterSelectHover,
+};
+pub use character_settings_system::{
+    character_settings_exit_system, character_settings_load_system, character_settings_save_system,
 };
+pub use character_title_system::character_title_system;
 pub use clan_system::clan_system;
 pub use client_entity_event_system::client_entity_event_system;
 pub use collision_system::{
     collision_height_only_system, collision_player_system, collision_player_system_join_zoin,
 };
 pub use command_system::command_system;
+pub use companion_system::companion_system;
 pub use conversation_dialog_system::conversation_dialog_system;
 pub use cooldown_system::cooldown_system;
 pub use damage_digit_render_system::damage_digit_render_system;
 pub use debug_inspector_system::DebugInspectorPlugin;
 pub use debug_render_collider_system::debug_render_collider_system;
+pub use debug_render_command_system::debug_render_command_system;
 pub use debug_render_directional_light_system::debug_render_directional_light_system;
+pub use debug_render_monster_spawn_system::debug_render_monster_spawn_system;
+pub use debug_render_range_system::debug_render_range_system;
 pub use debug_render_skeleton_system::debug_render_skeleton_system;
 pub use directional_light_system::directional_light_system;
+pub use drop_notification_system::drop_notification_system;
 pub use effect_system::effect_system;
+pub use extension_message_system::extension_message_system;
 pub use facing_direction_system::facing_direction_system;
+pub use fishing_system::fishing_system;
 pub use free_camera_system::{free_camera_system, FreeCamera};
 pub use game_connection_system::game_connection_system;
 pub use game_mouse_input_system::game_mouse_input_system;
 pub use game_system::{game_state_enter_system, game_zone_change_system};
+pub use headless_script_system::headless_script_system;
 pub use hit_event_system::hit_event_system;
+pub use hud_visibility_system::hud_visibility_system;
+pub use input_focus_system::input_focus_system;
 pub use item_drop_model_system::{item_drop_model_add_collider_system, item_drop_model_system};
+pub use item_durability_warning_system::item_durability_warning_system;
+pub use knockback_system::knockback_system;
 pub use login_connection_system::login_connection_system;
 pub use login_system::{
     login_event_system, login_state_enter_system, login_state_exit_system, login_system,
 };
+pub use map_exploration_system::map_exploration_system;
+pub use map_ping_system::map_ping_system;
 pub use model_viewer_system::{
-    model_viewer_enter_system, model_viewer_exit_system, model_viewer_system,
+    model_viewer_enter_system, model_viewer_exit_system, model_viewer_retarget_system,
+    model_viewer_scene_system, model_viewer_system, model_viewer_training_dummy_system,
 };
+pub use monster_aggro_system::monster_aggro_system;
 pub use move_destination_effect_system::move_destination_effect_system;
 pub use name_tag_system::name_tag_system;
 pub use name_tag_update_color_system::name_tag_update_color_system;
@@ -111,6 +181,7 @@ pub use name_tag_update_healthbar_system::name_tag_update_healthbar_system;
 pub use name_tag_visibility_system::name_tag_visibility_system;
 pub use network_thread_system::network_thread_system;
 pub use npc_idle_sound_system::npc_idle_sound_system;
+pub use npc_idle_system::npc_idle_system;
 pub use npc_model_add_collider_system::npc_model_add_collider_system;
 pub use npc_model_system::npc_model_update_system;
 pub use orbit_camera_system::{orbit_camera_system, OrbitCamera};
@@ -120,19 +191,38 @@ pub use pending_damage_system::pending_damage_system;
 pub use pending_skill_effect_system::pending_skill_effect_system;
 pub use personal_store_model_add_collider_system::personal_store_model_add_collider_system;
 pub use personal_store_model_system::personal_store_model_system;
-pub use player_command_system::player_command_system;
+pub use player_command_system::{player_command_buffer_system, player_command_system};
+pub use player_keyboard_move_system::player_keyboard_move_system;
 pub use projectile_system::projectile_system;
 pub use quest_trigger_system::quest_trigger_system;
+pub use rest_system::rest_system;
+pub use session_stats_system::session_stats_system;
+pub use sit_point_system::sit_point_system;
+pub use sound_caption_system::sound_caption_system;
+pub use sound_effect_system::sound_effect_system;
 pub use spawn_effect_system::spawn_effect_system;
 pub use spawn_projectile_system::spawn_projectile_system;
+pub use spectate_camera_system::spectate_camera_system;
 pub use status_effect_system::status_effect_system;
+pub use stun_system::stun_system;
 pub use systemfunc_event_system::system_func_event_system;
+pub use target_camera_system::{
+    target_camera_exit_system, target_camera_system, TARGET_CAMERA_IMAGE_SIZE,
+};
+pub use terrain_view_distance_system::terrain_view_distance_system;
+pub use tutorial_hint_system::tutorial_hint_system;
+pub use unstick_system::unstick_system;
 pub use update_position_system::update_position_system;
 pub use use_item_event_system::use_item_event_system;
+pub use vehicle_exhaust_system::vehicle_exhaust_system;
 pub use vehicle_model_system::vehicle_model_system;
 pub use vehicle_sound_system::vehicle_sound_system;
 pub use visible_status_effects_system::visible_status_effects_system;
+pub use water_splash_system::water_splash_system;
+pub use weapon_trail_system::weapon_trail_system;
+pub use window_focus_system::window_focus_system;
 pub use world_connection_system::world_connection_system;
 pub use world_time_system::world_time_system;
+pub use zone_preload_system::zone_preload_system;
 pub use zone_time_system::zone_time_system;
 pub use zone_viewer_system::zone_viewer_enter_system;
@@ -1,27 +1,34 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, path::Path, time::Duration};
 
 use bevy::{
+    asset::LoadState,
     hierarchy::DespawnRecursiveExt,
-    math::Vec3,
+    math::{Quat, Vec3},
     pbr::AmbientLight,
     prelude::{
-        Camera3d, Color, Commands, ComputedVisibility, Entity, GlobalTransform, Query, Res, ResMut,
-        Resource, Transform, Visibility, With,
+        Added, AssetServer, Assets, Camera3d, Color, Commands, ComputedVisibility, Entity,
+        GlobalTransform, Handle, Query, Res, ResMut, Resource, Time, Transform, Visibility, With,
     },
 };
 use bevy_egui::{egui, EguiContexts};
 use enum_map::{enum_map, EnumMap};
 use rand::{prelude::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
 
 use rose_data::{
-    CharacterMotionAction, EquipmentIndex, EquipmentItem, ItemReference, ItemType, NpcMotionAction,
-    ZoneId,
+    CharacterMotionAction, EquipmentIndex, EquipmentItem, ItemReference, ItemType, NpcId,
+    NpcMotionAction, SkillCooldown, SkillId, ZoneId,
+};
+use rose_game_common::components::{
+    AbilityValues, BasicStats, CharacterGender, CharacterInfo, Equipment, Level, Npc, SkillList,
+    StatusEffects,
 };
-use rose_game_common::components::{CharacterGender, CharacterInfo, Equipment, Npc};
 
 use crate::{
-    animation::{CameraAnimation, SkeletalAnimation},
+    animation::{retarget_zmo, CameraAnimation, SkeletalAnimation, ZmoAsset, ZmoRetargetReport},
     components::{CharacterModel, ClientEntityName, ModelHeight, NameTagType, NpcModel},
+    model_loader::ModelLoader,
+    render::ZoneLighting,
     resources::{DamageDigitsSpawner, GameData, NameTagSettings},
     systems::{FreeCamera, OrbitCamera},
     ui::UiStateDebugWindows,
@@ -29,6 +36,83 @@ use crate::{
 
 const CHARACTER_SPACING: f32 = 7.5;
 const NPC_SPACING: f32 = 7.5;
+const TRAINING_DUMMY_DISTANCE: f32 = 3.0;
+
+const ANIMATIONS: [(&str, CharacterMotionAction, NpcMotionAction); 7] = [
+    ("Stop", CharacterMotionAction::Stop1, NpcMotionAction::Stop),
+    ("Walk", CharacterMotionAction::Walk, NpcMotionAction::Move),
+    ("Run", CharacterMotionAction::Run, NpcMotionAction::Run),
+    (
+        "Attack 1",
+        CharacterMotionAction::Attack,
+        NpcMotionAction::Attack,
+    ),
+    (
+        "Attack 2",
+        CharacterMotionAction::Attack2,
+        NpcMotionAction::Attack,
+    ),
+    (
+        "Attack 3",
+        CharacterMotionAction::Attack3,
+        NpcMotionAction::Attack,
+    ),
+    ("Die", CharacterMotionAction::Die, NpcMotionAction::Die),
+];
+
+#[derive(Deserialize, Serialize)]
+struct ModelViewerSceneTransform {
+    translation: [f32; 3],
+    rotation: [f32; 4],
+}
+
+impl From<&Transform> for ModelViewerSceneTransform {
+    fn from(transform: &Transform) -> Self {
+        Self {
+            translation: transform.translation.to_array(),
+            rotation: transform.rotation.to_array(),
+        }
+    }
+}
+
+impl From<&ModelViewerSceneTransform> for Transform {
+    fn from(transform: &ModelViewerSceneTransform) -> Self {
+        Transform::from_translation(Vec3::from_array(transform.translation))
+            .with_rotation(Quat::from_array(transform.rotation))
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct ModelViewerSceneCharacter {
+    character_info: CharacterInfo,
+    equipment: Equipment,
+    transform: ModelViewerSceneTransform,
+}
+
+#[derive(Deserialize, Serialize)]
+struct ModelViewerSceneNpc {
+    npc_id: u16,
+    transform: ModelViewerSceneTransform,
+}
+
+#[derive(Deserialize, Serialize)]
+struct ModelViewerSceneLighting {
+    ambient_color: [f32; 4],
+    ambient_brightness: f32,
+    map_ambient_color: [f32; 3],
+    character_ambient_color: [f32; 3],
+    character_diffuse_color: [f32; 3],
+    light_direction: [f32; 3],
+}
+
+/// A saved model viewer scene, stored as RON so it can be hand edited.
+#[derive(Deserialize, Serialize)]
+struct ModelViewerScene {
+    characters: Vec<ModelViewerSceneCharacter>,
+    npcs: Vec<ModelViewerSceneNpc>,
+    animation: Option<String>,
+    lighting: ModelViewerSceneLighting,
+}
 
 #[derive(Resource)]
 pub struct ModelViewerState {
@@ -42,6 +126,73 @@ pub struct ModelViewerState {
     characters: Vec<Entity>,
     num_characters: usize,
     max_num_characters: usize,
+
+    animation: Option<usize>,
+    scene_path: String,
+    scene_status: Option<String>,
+
+    retarget_path: String,
+    retarget_source_gender: CharacterGender,
+    retarget_motion: Option<Handle<ZmoAsset>>,
+    retarget_report: Option<ZmoRetargetReport>,
+    retarget_status: Option<String>,
+
+    training_dummy: ModelViewerTrainingDummy,
+}
+
+/// An offline combat sandbox where the first model viewer character repeatedly attacks a
+/// target dummy using the same ability value and damage calculations as the server.
+struct ModelViewerTrainingDummy {
+    entity: Option<(Entity, NpcId)>,
+    npc_id: usize,
+    defence: i32,
+    resistance: i32,
+    avoid: i32,
+
+    attacker_level: u32,
+    attacker_stats: i32,
+    skill_id: usize,
+
+    running: bool,
+    elapsed: Duration,
+    next_attack: Duration,
+    total_damage: u64,
+    num_hits: u32,
+    num_critical: u32,
+    num_miss: u32,
+}
+
+impl Default for ModelViewerTrainingDummy {
+    fn default() -> Self {
+        Self {
+            entity: None,
+            npc_id: 1,
+            defence: 0,
+            resistance: 0,
+            avoid: 0,
+            attacker_level: 1,
+            attacker_stats: 15,
+            skill_id: 0,
+            running: false,
+            elapsed: Duration::ZERO,
+            next_attack: Duration::ZERO,
+            total_damage: 0,
+            num_hits: 0,
+            num_critical: 0,
+            num_miss: 0,
+        }
+    }
+}
+
+impl ModelViewerTrainingDummy {
+    fn reset_meter(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.next_attack = Duration::ZERO;
+        self.total_damage = 0;
+        self.num_hits = 0;
+        self.num_critical = 0;
+        self.num_miss = 0;
+    }
 }
 
 pub fn model_viewer_enter_system(
@@ -90,6 +241,18 @@ pub fn model_viewer_enter_system(
         characters: Vec::new(),
         num_characters: 1,
         max_num_characters: 500,
+
+        animation: None,
+        scene_path: "model_viewer_scene.ron".into(),
+        scene_status: None,
+
+        retarget_path: String::new(),
+        retarget_source_gender: CharacterGender::Male,
+        retarget_motion: None,
+        retarget_report: None,
+        retarget_status: None,
+
+        training_dummy: ModelViewerTrainingDummy::default(),
     });
 
     // Reset ambient light
@@ -123,18 +286,149 @@ pub fn model_viewer_exit_system(
         commands.entity(*entity).despawn_recursive();
     }
 
+    if let Some((entity, _)) = model_viewer_state.training_dummy.entity {
+        commands.entity(entity).despawn_recursive();
+    }
+
     // Restore default NameTagSettings
     *name_tag_settings = NameTagSettings::default();
 }
 
+fn save_scene(
+    path: &Path,
+    ui_state: &ModelViewerState,
+    query_character: &Query<(&CharacterInfo, &Equipment, &Transform)>,
+    query_npc: &Query<(&Npc, &Transform)>,
+    ambient_light: &AmbientLight,
+    zone_lighting: &ZoneLighting,
+) -> Result<(), anyhow::Error> {
+    let scene = ModelViewerScene {
+        characters: ui_state
+            .characters
+            .iter()
+            .filter_map(|entity| query_character.get(*entity).ok())
+            .map(
+                |(character_info, equipment, transform)| ModelViewerSceneCharacter {
+                    character_info: character_info.clone(),
+                    equipment: equipment.clone(),
+                    transform: transform.into(),
+                },
+            )
+            .collect(),
+        npcs: ui_state
+            .npcs
+            .iter()
+            .filter_map(|entity| query_npc.get(*entity).ok())
+            .map(|(npc, transform)| ModelViewerSceneNpc {
+                npc_id: npc.id.get(),
+                transform: transform.into(),
+            })
+            .collect(),
+        animation: ui_state
+            .animation
+            .map(|index| ANIMATIONS[index].0.to_string()),
+        lighting: ModelViewerSceneLighting {
+            ambient_color: ambient_light.color.as_rgba_f32(),
+            ambient_brightness: ambient_light.brightness,
+            map_ambient_color: zone_lighting.map_ambient_color.to_array(),
+            character_ambient_color: zone_lighting.character_ambient_color.to_array(),
+            character_diffuse_color: zone_lighting.character_diffuse_color.to_array(),
+            light_direction: zone_lighting.light_direction.to_array(),
+        },
+    };
+
+    let ron_str = ron::ser::to_string_pretty(&scene, ron::ser::PrettyConfig::default())?;
+    std::fs::write(path, ron_str)?;
+    Ok(())
+}
+
+fn load_scene(
+    commands: &mut Commands,
+    path: &Path,
+    ui_state: &mut ModelViewerState,
+    game_data: &GameData,
+    ambient_light: &mut AmbientLight,
+    zone_lighting: &mut ZoneLighting,
+) -> Result<(), anyhow::Error> {
+    let scene: ModelViewerScene = ron::from_str(&std::fs::read_to_string(path)?)?;
+
+    for entity in ui_state.characters.drain(..).chain(ui_state.npcs.drain(..)) {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    for character in scene.characters {
+        let entity = commands
+            .spawn((
+                ClientEntityName {
+                    name: character.character_info.name.clone(),
+                },
+                character.character_info,
+                character.equipment,
+                Visibility::default(),
+                ComputedVisibility::default(),
+                GlobalTransform::default(),
+                Transform::from(&character.transform),
+            ))
+            .id();
+        ui_state.characters.push(entity);
+    }
+
+    for npc in scene.npcs {
+        let Some(npc_data) =
+            NpcId::new(npc.npc_id).and_then(|npc_id| game_data.npcs.get_npc(npc_id))
+        else {
+            log::warn!("Model viewer scene contains invalid npc id {}", npc.npc_id);
+            continue;
+        };
+
+        let entity = commands
+            .spawn((
+                ClientEntityName {
+                    name: npc_data.name.to_string(),
+                },
+                Npc::new(npc_data.id, 0),
+                Visibility::default(),
+                ComputedVisibility::default(),
+                GlobalTransform::default(),
+                Transform::from(&npc.transform),
+            ))
+            .id();
+        ui_state.npcs.push(entity);
+    }
+
+    ui_state.num_characters = ui_state.characters.len();
+    ui_state.num_npcs = ui_state.npcs.len();
+    ui_state.animation = scene
+        .animation
+        .and_then(|name| ANIMATIONS.iter().position(|(x, _, _)| *x == name));
+
+    ambient_light.color = Color::rgba(
+        scene.lighting.ambient_color[0],
+        scene.lighting.ambient_color[1],
+        scene.lighting.ambient_color[2],
+        scene.lighting.ambient_color[3],
+    );
+    ambient_light.brightness = scene.lighting.ambient_brightness;
+    zone_lighting.map_ambient_color = Vec3::from_array(scene.lighting.map_ambient_color);
+    zone_lighting.character_ambient_color =
+        Vec3::from_array(scene.lighting.character_ambient_color);
+    zone_lighting.character_diffuse_color =
+        Vec3::from_array(scene.lighting.character_diffuse_color);
+    zone_lighting.light_direction = Vec3::from_array(scene.lighting.light_direction);
+
+    Ok(())
+}
+
 pub fn model_viewer_system(
     mut commands: Commands,
     mut ui_state: ResMut<ModelViewerState>,
     query_character_model: Query<(Entity, &CharacterModel)>,
     query_npc_model: Query<(Entity, &NpcModel)>,
+    query_added_character_model: Query<(Entity, &CharacterModel), Added<CharacterModel>>,
+    query_added_npc_model: Query<(Entity, &NpcModel), Added<NpcModel>>,
     game_data: Res<GameData>,
     mut egui_context: EguiContexts,
-    damage_digits_spawner: Res<DamageDigitsSpawner>,
+    mut damage_digits_spawner: ResMut<DamageDigitsSpawner>,
     query_damage_character_model: Query<(&GlobalTransform, &ModelHeight), With<CharacterModel>>,
     query_damage_npc_model: Query<(&GlobalTransform, &ModelHeight), With<NpcModel>>,
 ) {
@@ -152,7 +446,6 @@ pub fn model_viewer_system(
 
             for (global_transform, model_height) in query_damage_character_model.iter() {
                 damage_digits_spawner.spawn(
-                    &mut commands,
                     global_transform,
                     model_height.height,
                     rng.gen_range(0..2047),
@@ -162,7 +455,6 @@ pub fn model_viewer_system(
 
             for (global_transform, model_height) in query_damage_npc_model.iter() {
                 damage_digits_spawner.spawn(
-                    &mut commands,
                     global_transform,
                     model_height.height,
                     rng.gen_range(0..2047),
@@ -306,43 +598,521 @@ pub fn model_viewer_system(
     });
 
     egui::Window::new("Animation").show(egui_context.ctx_mut(), |ui| {
-        let mut animation_button =
-            |name: &str, character_action: CharacterMotionAction, npc_action: NpcMotionAction| {
-                if ui.button(name).clicked() {
-                    for (entity, character_model) in query_character_model.iter() {
-                        commands.entity(entity).insert(SkeletalAnimation::repeat(
-                            character_model.action_motions[character_action].clone(),
-                            None,
-                        ));
+        for (index, &(name, character_action, npc_action)) in ANIMATIONS.iter().enumerate() {
+            if ui.button(name).clicked() {
+                ui_state.animation = Some(index);
+
+                for (entity, character_model) in query_character_model.iter() {
+                    commands.entity(entity).insert(SkeletalAnimation::repeat(
+                        character_model.action_motions[character_action].clone(),
+                        None,
+                    ));
+                }
+
+                for (entity, npc_model) in query_npc_model.iter() {
+                    commands.entity(entity).insert(SkeletalAnimation::repeat(
+                        npc_model.action_motions[npc_action].clone(),
+                        None,
+                    ));
+                }
+            }
+        }
+    });
+
+    // Apply the selected animation to newly spawned models
+    if let Some((_, character_action, npc_action)) =
+        ui_state.animation.map(|index| ANIMATIONS[index])
+    {
+        for (entity, character_model) in query_added_character_model.iter() {
+            commands.entity(entity).insert(SkeletalAnimation::repeat(
+                character_model.action_motions[character_action].clone(),
+                None,
+            ));
+        }
+
+        for (entity, npc_model) in query_added_npc_model.iter() {
+            commands.entity(entity).insert(SkeletalAnimation::repeat(
+                npc_model.action_motions[npc_action].clone(),
+                None,
+            ));
+        }
+    }
+}
+
+pub fn model_viewer_scene_system(
+    mut commands: Commands,
+    mut ui_state: ResMut<ModelViewerState>,
+    query_scene_character: Query<(&CharacterInfo, &Equipment, &Transform)>,
+    query_scene_npc: Query<(&Npc, &Transform)>,
+    mut ambient_light: ResMut<AmbientLight>,
+    mut zone_lighting: ResMut<ZoneLighting>,
+    game_data: Res<GameData>,
+    mut egui_context: EguiContexts,
+) {
+    egui::Window::new("Scene").show(egui_context.ctx_mut(), |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Path:");
+            ui.text_edit_singleline(&mut ui_state.scene_path);
+        });
+
+        ui.horizontal(|ui| {
+            if ui.button("Save").clicked() {
+                let path = ui_state.scene_path.clone();
+                ui_state.scene_status = Some(
+                    match save_scene(
+                        Path::new(&path),
+                        &ui_state,
+                        &query_scene_character,
+                        &query_scene_npc,
+                        &ambient_light,
+                        &zone_lighting,
+                    ) {
+                        Ok(_) => format!("Saved scene to {}", path),
+                        Err(error) => format!("Failed to save scene: {}", error),
+                    },
+                );
+            }
+
+            if ui.button("Load").clicked() {
+                let path = ui_state.scene_path.clone();
+                let result = load_scene(
+                    &mut commands,
+                    Path::new(&path),
+                    &mut ui_state,
+                    &game_data,
+                    &mut ambient_light,
+                    &mut zone_lighting,
+                );
+                ui_state.scene_status = Some(match result {
+                    Ok(_) => format!("Loaded scene from {}", path),
+                    Err(error) => format!("Failed to load scene: {}", error),
+                });
+            }
+        });
+
+        if let Some(scene_status) = ui_state.scene_status.as_ref() {
+            ui.label(scene_status);
+        }
+    });
+}
+
+pub fn model_viewer_retarget_system(
+    mut commands: Commands,
+    mut ui_state: ResMut<ModelViewerState>,
+    query_character_model: Query<(Entity, &CharacterModel)>,
+    mut motion_assets: ResMut<Assets<ZmoAsset>>,
+    asset_server: Res<AssetServer>,
+    model_loader: Res<ModelLoader>,
+    mut egui_context: EguiContexts,
+) {
+    // Once the motion to retarget has loaded, play the original on the source gender and
+    // the retargeted motion on the other gender so they can be compared side by side
+    if let Some(retarget_motion) = ui_state.retarget_motion.clone() {
+        if let Some(zmo_asset) = motion_assets.get(&retarget_motion) {
+            let source_gender = ui_state.retarget_source_gender;
+            let target_gender = match source_gender {
+                CharacterGender::Male => CharacterGender::Female,
+                CharacterGender::Female => CharacterGender::Male,
+            };
+            let (retargeted_zmo, report) = retarget_zmo(
+                zmo_asset,
+                model_loader.get_skeleton(source_gender),
+                model_loader.get_skeleton(target_gender),
+            );
+            let retargeted_motion = motion_assets.add(retargeted_zmo);
+
+            for (entity, character_model) in query_character_model.iter() {
+                let motion = if character_model.gender == source_gender {
+                    retarget_motion.clone()
+                } else {
+                    retargeted_motion.clone()
+                };
+                commands
+                    .entity(entity)
+                    .insert(SkeletalAnimation::repeat(motion, None));
+            }
+
+            ui_state.animation = None;
+            ui_state.retarget_motion = None;
+            ui_state.retarget_status = None;
+            ui_state.retarget_report = Some(report);
+        } else if matches!(
+            asset_server.get_load_state(&retarget_motion),
+            LoadState::Failed
+        ) {
+            ui_state.retarget_motion = None;
+            ui_state.retarget_status = Some("Failed to load motion".to_string());
+        }
+    }
+
+    egui::Window::new("Retarget Motion").show(egui_context.ctx_mut(), |ui| {
+        ui.horizontal(|ui| {
+            ui.label("ZMO Path:");
+            ui.text_edit_singleline(&mut ui_state.retarget_path);
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Authored For:");
+            ui.radio_value(
+                &mut ui_state.retarget_source_gender,
+                CharacterGender::Male,
+                "Male",
+            );
+            ui.radio_value(
+                &mut ui_state.retarget_source_gender,
+                CharacterGender::Female,
+                "Female",
+            );
+        });
+
+        if ui
+            .add_enabled(
+                ui_state.retarget_motion.is_none() && !ui_state.retarget_path.is_empty(),
+                egui::Button::new("Retarget"),
+            )
+            .clicked()
+        {
+            ui_state.retarget_motion = Some(asset_server.load(ui_state.retarget_path.clone()));
+            ui_state.retarget_report = None;
+            ui_state.retarget_status = Some("Loading...".to_string());
+        }
+
+        if let Some(retarget_status) = ui_state.retarget_status.as_ref() {
+            ui.label(retarget_status);
+        }
+
+        if let Some(report) = ui_state.retarget_report.as_ref() {
+            ui.label(format!("Mapped {} bones", report.num_mapped_bones));
+
+            if !report.failed_bones.is_empty() {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    format!("{} bones failed to map:", report.failed_bones.len()),
+                );
+
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        for (bone_id, error) in report.failed_bones.iter() {
+                            ui.label(format!("Bone {}: {}", bone_id, error));
+                        }
+                    });
+            }
+        }
+    });
+}
+
+fn get_training_dummy_ability_values(
+    game_data: &GameData,
+    training_dummy: &ModelViewerTrainingDummy,
+) -> Option<AbilityValues> {
+    let npc_id = NpcId::new(training_dummy.npc_id as u16)?;
+    let mut ability_values = game_data.ability_value_calculator.calculate_npc(
+        npc_id,
+        &StatusEffects::default(),
+        None,
+        None,
+    )?;
+    ability_values.defence = training_dummy.defence;
+    ability_values.resistance = training_dummy.resistance;
+    ability_values.avoid = training_dummy.avoid;
+    Some(ability_values)
+}
+
+pub fn model_viewer_training_dummy_system(
+    mut commands: Commands,
+    mut ui_state: ResMut<ModelViewerState>,
+    mut egui_context: EguiContexts,
+    query_attacker: Query<(&CharacterInfo, &Equipment, &CharacterModel, &Transform)>,
+    query_dummy: Query<(&GlobalTransform, &ModelHeight), With<NpcModel>>,
+    mut damage_digits_spawner: ResMut<DamageDigitsSpawner>,
+    motion_assets: Res<Assets<ZmoAsset>>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    let ui_state = &mut *ui_state;
+    let attacker = ui_state
+        .characters
+        .first()
+        .and_then(|entity| query_attacker.get(*entity).ok());
+    let training_dummy = &mut ui_state.training_dummy;
+
+    let attacker_ability_values = attacker.map(|(character_info, equipment, _, _)| {
+        let basic_stats = BasicStats {
+            strength: training_dummy.attacker_stats,
+            dexterity: training_dummy.attacker_stats,
+            intelligence: training_dummy.attacker_stats,
+            concentration: training_dummy.attacker_stats,
+            charm: training_dummy.attacker_stats,
+            sense: training_dummy.attacker_stats,
+        };
+
+        game_data.ability_value_calculator.calculate(
+            character_info,
+            &Level::new(training_dummy.attacker_level),
+            equipment,
+            &basic_stats,
+            &SkillList::default(),
+            &StatusEffects::default(),
+        )
+    });
+    let dummy_ability_values = get_training_dummy_ability_values(&game_data, training_dummy);
+    let skill_data = SkillId::new(training_dummy.skill_id as u16)
+        .and_then(|skill_id| game_data.skills.get_skill(skill_id));
+
+    // The time between basic attacks is the length of the attack animation at the attacker's
+    // attack speed, skills additionally wait for their cooldown to expire
+    let attack_duration = attacker_ability_values.as_ref().zip(attacker).map_or(
+        Duration::from_secs(1),
+        |(ability_values, (_, _, character_model, _))| {
+            let animation_duration = motion_assets
+                .get(&character_model.action_motions[CharacterMotionAction::Attack])
+                .filter(|motion| motion.fps > 0)
+                .map_or(Duration::from_secs(1), |motion| {
+                    Duration::from_secs_f32(motion.num_frames as f32 / motion.fps as f32)
+                });
+            animation_duration
+                .div_f32(i32::max(ability_values.get_attack_speed(), 30) as f32 / 100.0)
+        },
+    );
+    let attack_interval = match skill_data.map(|skill_data| &skill_data.cooldown) {
+        Some(SkillCooldown::Skill { duration }) | Some(SkillCooldown::Group { duration, .. }) => {
+            attack_duration.max(*duration)
+        }
+        None => attack_duration,
+    };
+
+    egui::Window::new("Training Dummy")
+        .default_open(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            egui::Grid::new("training_dummy_settings")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Dummy NPC:");
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut training_dummy.npc_id)
+                                .clamp_range(1..=u16::MAX as usize),
+                        );
+                        if let Some(npc_data) = NpcId::new(training_dummy.npc_id as u16)
+                            .and_then(|npc_id| game_data.npcs.get_npc(npc_id))
+                        {
+                            ui.label(npc_data.name);
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label("Defence:");
+                    ui.add(egui::DragValue::new(&mut training_dummy.defence).clamp_range(0..=9999));
+                    ui.end_row();
+
+                    ui.label("Resistance:");
+                    ui.add(
+                        egui::DragValue::new(&mut training_dummy.resistance).clamp_range(0..=9999),
+                    );
+                    ui.end_row();
+
+                    ui.label("Avoid:");
+                    ui.add(egui::DragValue::new(&mut training_dummy.avoid).clamp_range(0..=9999));
+                    ui.end_row();
+
+                    ui.label("Attacker Level:");
+                    ui.add(
+                        egui::DragValue::new(&mut training_dummy.attacker_level)
+                            .clamp_range(1..=250),
+                    );
+                    ui.end_row();
+
+                    ui.label("Attacker Stats:");
+                    ui.add(
+                        egui::DragValue::new(&mut training_dummy.attacker_stats)
+                            .clamp_range(1..=999),
+                    )
+                    .on_hover_text("Applied to all basic stats");
+                    ui.end_row();
+
+                    ui.label("Skill:");
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut training_dummy.skill_id)
+                                .clamp_range(0..=u16::MAX as usize),
+                        );
+                        if let Some(skill_data) = skill_data {
+                            ui.label(skill_data.name);
+                        } else {
+                            ui.label("Basic Attack");
+                        }
+                    });
+                    ui.end_row();
+                });
+
+            if ui.button("Use NPC Defence Values").clicked() {
+                if let Some(npc_id) = NpcId::new(training_dummy.npc_id as u16) {
+                    if let Some(ability_values) = game_data.ability_value_calculator.calculate_npc(
+                        npc_id,
+                        &StatusEffects::default(),
+                        None,
+                        None,
+                    ) {
+                        training_dummy.defence = ability_values.get_defence();
+                        training_dummy.resistance = ability_values.get_resistance();
+                        training_dummy.avoid = ability_values.get_avoid();
                     }
+                }
+            }
+
+            ui.separator();
+
+            if attacker.is_none() {
+                ui.label("Spawn at least one character to attack the dummy.");
+            }
 
-                    for (entity, npc_model) in query_npc_model.iter() {
-                        commands.entity(entity).insert(SkeletalAnimation::repeat(
-                            npc_model.action_motions[npc_action].clone(),
-                            None,
-                        ));
+            ui.horizontal(|ui| {
+                if training_dummy.running {
+                    if ui.button("Stop").clicked() {
+                        training_dummy.running = false;
                     }
+                } else if ui
+                    .add_enabled(
+                        attacker.is_some() && dummy_ability_values.is_some(),
+                        egui::Button::new("Start"),
+                    )
+                    .clicked()
+                {
+                    training_dummy.running = true;
                 }
+
+                if ui.button("Reset").clicked() {
+                    training_dummy.reset_meter();
+                }
+            });
+
+            let elapsed = training_dummy.elapsed.as_secs_f32();
+            let dps = if elapsed > 0.0 {
+                training_dummy.total_damage as f32 / elapsed
+            } else {
+                0.0
             };
+            let num_attacks = training_dummy.num_hits + training_dummy.num_miss;
 
-        animation_button("Stop", CharacterMotionAction::Stop1, NpcMotionAction::Stop);
-        animation_button("Walk", CharacterMotionAction::Walk, NpcMotionAction::Move);
-        animation_button("Run", CharacterMotionAction::Run, NpcMotionAction::Run);
-        animation_button(
-            "Attack 1",
-            CharacterMotionAction::Attack,
-            NpcMotionAction::Attack,
-        );
-        animation_button(
-            "Attack 2",
-            CharacterMotionAction::Attack2,
-            NpcMotionAction::Attack,
-        );
-        animation_button(
-            "Attack 3",
-            CharacterMotionAction::Attack3,
-            NpcMotionAction::Attack,
-        );
-        animation_button("Die", CharacterMotionAction::Die, NpcMotionAction::Die);
+            egui::Grid::new("training_dummy_meter")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Attack Interval:");
+                    ui.label(format!("{:.2}s", attack_interval.as_secs_f32()));
+                    ui.end_row();
+
+                    ui.label("Time:");
+                    ui.label(format!("{:.1}s", elapsed));
+                    ui.end_row();
+
+                    ui.label("Total Damage:");
+                    ui.label(format!("{}", training_dummy.total_damage));
+                    ui.end_row();
+
+                    ui.label("DPS:");
+                    ui.label(format!("{:.1}", dps));
+                    ui.end_row();
+
+                    ui.label("Hits:");
+                    ui.label(format!("{} / {}", training_dummy.num_hits, num_attacks));
+                    ui.end_row();
+
+                    ui.label("Critical:");
+                    ui.label(format!(
+                        "{} ({:.1}%)",
+                        training_dummy.num_critical,
+                        100.0 * training_dummy.num_critical as f32
+                            / training_dummy.num_hits.max(1) as f32
+                    ));
+                    ui.end_row();
+                });
+        });
+
+    if attacker_ability_values.is_none() || dummy_ability_values.is_none() {
+        training_dummy.running = false;
+    }
+
+    // The dummy only exists whilst running, and is respawned whenever the selected NPC changes
+    let dummy_npc_id = NpcId::new(training_dummy.npc_id as u16);
+    if let Some((entity, npc_id)) = training_dummy.entity {
+        if !training_dummy.running || Some(npc_id) != dummy_npc_id {
+            commands.entity(entity).despawn_recursive();
+            training_dummy.entity = None;
+        }
+    }
+
+    let (
+        true,
+        Some(dummy_npc_id),
+        Some(attacker_ability_values),
+        Some(dummy_ability_values),
+        Some((_, _, _, attacker_transform)),
+    ) = (
+        training_dummy.running,
+        dummy_npc_id,
+        attacker_ability_values,
+        dummy_ability_values,
+        attacker,
+    )
+    else {
+        return;
+    };
+
+    let (dummy_entity, _) = *training_dummy.entity.get_or_insert_with(|| {
+        let entity = commands
+            .spawn((
+                ClientEntityName {
+                    name: "Training Dummy".to_string(),
+                },
+                Npc::new(dummy_npc_id, 0),
+                Visibility::default(),
+                ComputedVisibility::default(),
+                GlobalTransform::default(),
+                Transform::from_translation(
+                    attacker_transform.translation + Vec3::new(0.0, 0.0, -TRAINING_DUMMY_DISTANCE),
+                ),
+            ))
+            .id();
+        (entity, dummy_npc_id)
     });
+
+    training_dummy.elapsed += time.delta();
+    while training_dummy.next_attack <= training_dummy.elapsed {
+        training_dummy.next_attack += attack_interval;
+
+        let damage = if let Some(skill_data) = skill_data {
+            game_data.ability_value_calculator.calculate_skill_damage(
+                &attacker_ability_values,
+                &dummy_ability_values,
+                skill_data,
+                1,
+            )
+        } else {
+            game_data.ability_value_calculator.calculate_damage(
+                &attacker_ability_values,
+                &dummy_ability_values,
+                1,
+            )
+        };
+
+        if damage.amount == 0 {
+            training_dummy.num_miss += 1;
+        } else {
+            training_dummy.num_hits += 1;
+            training_dummy.total_damage += damage.amount as u64;
+            if damage.is_critical {
+                training_dummy.num_critical += 1;
+            }
+        }
+
+        if let Ok((global_transform, model_height)) = query_dummy.get(dummy_entity) {
+            damage_digits_spawner.spawn(
+                global_transform,
+                model_height.height,
+                damage.amount,
+                false,
+            );
+        }
+    }
 }
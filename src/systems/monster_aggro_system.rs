@@ -0,0 +1,139 @@
+use bevy::{
+    hierarchy::BuildChildren,
+    prelude::{
+        AssetServer, Children, Color, Commands, Entity, GlobalTransform, Query, Res, Transform,
+        With, Without,
+    },
+    time::Time,
+};
+
+use rose_game_common::components::{Level, Npc, Team};
+
+use crate::{
+    audio::{SoundRadius, SpatialSound},
+    components::{
+        ClientEntity, ClientEntityType, Command, Dead, MonsterAggro, NameTagEntity, NameTagName,
+        PlayerCharacter, SoundCategory,
+    },
+    render::WorldUiRect,
+    resources::{AccessibilitySettings, GameData, SoundCache, SoundSettings},
+    systems::name_tag_system::get_monster_name_tag_color,
+};
+
+const AGGRO_FLASH_DURATION: f32 = 1.5;
+const AGGRO_FLASH_INTERVAL: f32 = 0.15;
+
+pub fn monster_aggro_system(
+    mut commands: Commands,
+    mut query_monsters: Query<
+        (
+            Entity,
+            &ClientEntity,
+            &Npc,
+            &Command,
+            &GlobalTransform,
+            Option<&Level>,
+            Option<&Team>,
+            Option<&NameTagEntity>,
+            Option<&mut MonsterAggro>,
+        ),
+        (Without<PlayerCharacter>, Without<Dead>),
+    >,
+    query_player: Query<(Entity, &Level), With<PlayerCharacter>>,
+    query_children: Query<&Children>,
+    mut query_name_rects: Query<&mut WorldUiRect, With<NameTagName>>,
+    asset_server: Res<AssetServer>,
+    game_data: Res<GameData>,
+    sound_settings: Res<SoundSettings>,
+    sound_cache: Res<SoundCache>,
+    accessibility_settings: Res<AccessibilitySettings>,
+    time: Res<Time>,
+) {
+    let Ok((player_entity, player_level)) = query_player.get_single() else {
+        return;
+    };
+
+    for (
+        entity,
+        client_entity,
+        npc,
+        command,
+        global_transform,
+        level,
+        team,
+        name_tag_entity,
+        monster_aggro,
+    ) in query_monsters.iter_mut()
+    {
+        if client_entity.entity_type != ClientEntityType::Monster {
+            continue;
+        }
+
+        let is_targeting_player = !matches!(command, Command::PickupItem(_))
+            && command.get_target() == Some(player_entity);
+
+        let Some(mut monster_aggro) = monster_aggro else {
+            if is_targeting_player {
+                commands.entity(entity).insert(MonsterAggro::default());
+
+                if let Some(sound_data) = game_data
+                    .npcs
+                    .get_npc(npc.id)
+                    .and_then(|npc_data| npc_data.normal_effect_sound_id)
+                    .and_then(|sound_id| game_data.sounds.get_sound(sound_id))
+                {
+                    commands.entity(entity).with_children(|builder| {
+                        builder.spawn((
+                            SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
+                            SoundRadius::new(4.0),
+                            SoundCategory::OtherCombat,
+                            sound_settings.gain(SoundCategory::OtherCombat),
+                            Transform::default(),
+                            *global_transform,
+                        ));
+                    });
+                }
+            }
+            continue;
+        };
+
+        if !is_targeting_player {
+            // Finish any active flash this frame so the name tag colour is restored
+            commands.entity(entity).remove::<MonsterAggro>();
+            monster_aggro.flash_timer = monster_aggro.flash_timer.min(AGGRO_FLASH_DURATION);
+        }
+
+        if monster_aggro.flash_timer > AGGRO_FLASH_DURATION {
+            continue;
+        }
+        monster_aggro.flash_timer += time.delta_seconds();
+
+        // Flash the name tag red, then restore it to the usual level difference colour
+        let flash_on = is_targeting_player
+            && monster_aggro.flash_timer <= AGGRO_FLASH_DURATION
+            && (monster_aggro.flash_timer / AGGRO_FLASH_INTERVAL) as i32 % 2 == 0;
+        let color = if flash_on {
+            accessibility_settings.hostile_color()
+        } else {
+            get_monster_name_tag_color(&accessibility_settings, Some(player_level), level, team)
+        }
+        .to_array();
+        let color = Color::rgb_linear(
+            color[0] as f32 / 255.0,
+            color[1] as f32 / 255.0,
+            color[2] as f32 / 255.0,
+        );
+
+        let Some(children) =
+            name_tag_entity.and_then(|name_tag_entity| query_children.get(name_tag_entity.0).ok())
+        else {
+            continue;
+        };
+
+        for &child_entity in children.iter() {
+            if let Ok(mut rect) = query_name_rects.get_mut(child_entity) {
+                rect.color = color;
+            }
+        }
+    }
+}
@@ -5,8 +5,8 @@ use bevy::{
     ecs::query::WorldQuery,
     prelude::{
         Assets, BuildChildren, Changed, Color, Commands, ComputedVisibility, DespawnRecursiveExt,
-        Entity, EventReader, GlobalTransform, Handle, Image, Local, Query, Res, ResMut, Transform,
-        Vec2, Vec3, Visibility, With, Without,
+        Entity, EventReader, GlobalTransform, Handle, Image, Local, Or, Query, Res, ResMut,
+        Transform, Vec2, Vec3, Visibility, With, Without,
     },
     render::{
         render_resource::{Extent3d, TextureDimension, TextureFormat},
@@ -22,19 +22,27 @@ use rose_game_common::components::{Level, Npc, Team};
 
 use crate::{
     components::{
-        ClientEntityName, ModelHeight, NameTag, NameTagEntity, NameTagHealthbarBackground,
-        NameTagHealthbarForeground, NameTagName, NameTagTargetMark, NameTagType, PlayerCharacter,
+        AwayFromKeyboard, CharacterTitle, ClanMembership, ClientEntityName, ModelHeight, NameTag,
+        NameTagEntity, NameTagHealthbarBackground, NameTagHealthbarForeground,
+        NameTagManabarBackground, NameTagManabarForeground, NameTagName, NameTagTargetMark,
+        NameTagType, PlayerCharacter,
     },
     events::LoadZoneEvent,
     render::WorldUiRect,
-    resources::{GameData, NameTagSettings, UiResources, UiSpriteSheetType},
+    resources::{
+        AccessibilitySettings, ColorPalette, GameData, InterfaceSettings, NameTagSettings,
+        UiResources, UiSpriteSheetType,
+    },
 };
 
 const ORDER_HEALTH_BACKGROUND: u8 = 0;
 const ORDER_HEALTH_FOREGROUND: u8 = 1;
 const ORDER_NAME: u8 = 2;
 const ORDER_TARGET_MARK: u8 = 2;
-const MAX_NAME_ROWS: usize = 2;
+const MAX_NAME_ROWS: usize = 3;
+
+const NAME_TAG_COLOR_CLAN: egui::Color32 = egui::Color32::from_rgb(150, 220, 255);
+const NAME_TAG_COLOR_TITLE: egui::Color32 = egui::Color32::from_rgb(255, 220, 120);
 
 pub struct NameTagData {
     pub image: Handle<Image>,
@@ -53,10 +61,13 @@ pub struct NameTagCache {
     pub cache: HashMap<String, NameTagData>,
     pub pending: HashMap<Entity, NameTagPendingData>,
     pub pixels_per_point: f32,
+    pub streamer_mode: bool,
+    pub color_palette: ColorPalette,
 }
 
 #[derive(WorldQuery)]
 pub struct PlayerQuery<'w> {
+    entity: Entity,
     level: &'w Level,
     team: &'w Team,
 }
@@ -69,9 +80,13 @@ pub struct NameTagObjectQuery<'w> {
     npc: Option<&'w Npc>,
     level: Option<&'w Level>,
     team: Option<&'w Team>,
+    away: Option<&'w AwayFromKeyboard>,
+    title: Option<&'w CharacterTitle>,
+    clan_membership: Option<&'w ClanMembership>,
 }
 
 pub fn get_monster_name_tag_color(
+    accessibility_settings: &AccessibilitySettings,
     player_level: Option<&Level>,
     monster_level: Option<&Level>,
     monster_team: Option<&Team>,
@@ -80,7 +95,7 @@ pub fn get_monster_name_tag_color(
         - monster_level.map_or(1, |level| level.level) as i32;
 
     if monster_team.map_or(false, |team| team.id == Team::DEFAULT_NPC_TEAM_ID) {
-        egui::Color32::GREEN
+        accessibility_settings.npc_color()
     } else if level_diff <= -23 {
         egui::Color32::from_rgb(224, 149, 255)
     } else if level_diff <= -16 {
@@ -102,32 +117,95 @@ pub fn get_monster_name_tag_color(
     }
 }
 
+fn character_name_text(object: &NameTagObjectQueryItem) -> String {
+    if object.away.is_some() {
+        format!("[AFK] {}", object.name.name)
+    } else {
+        object.name.name.clone()
+    }
+}
+
+/// The rows shown below a character's name, their clan name followed by their title
+fn character_extra_rows(object: &NameTagObjectQueryItem) -> Vec<(String, egui::Color32)> {
+    let mut rows = Vec::new();
+    if let Some(clan_membership) = object.clan_membership {
+        rows.push((format!("[{}]", clan_membership.name), NAME_TAG_COLOR_CLAN));
+    }
+    if let Some(title) = object.title {
+        rows.push((title.title.clone(), NAME_TAG_COLOR_TITLE));
+    }
+    rows
+}
+
+/// Name tags are cached by their text, so away players use a separate name tag with an AFK tag
+/// and characters with a clan or title use a separate name tag with them on the following rows
+fn name_tag_cache_key(object: &NameTagObjectQueryItem) -> String {
+    let mut key = character_name_text(object);
+    for (text, _) in character_extra_rows(object) {
+        key.push('\n');
+        key.push_str(&text);
+    }
+    key
+}
+
 fn create_pending_nametag(
     name_tag_settings: &NameTagSettings,
+    interface_settings: &InterfaceSettings,
+    accessibility_settings: &AccessibilitySettings,
     egui_context: &mut EguiContexts,
     object: &NameTagObjectQueryItem,
     player: Option<&PlayerQueryItem>,
     name_tag_type: NameTagType,
 ) -> NameTagPendingData {
     let layout_job = match name_tag_type {
-        NameTagType::Character => egui::epaint::text::LayoutJob::single_section(
-            object.name.name.clone(),
-            egui::TextFormat::simple(
-                egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]),
-                if object.team.map_or(false, |team| {
-                    Some(team.id) != player.map(|player| player.team.id)
-                }) {
-                    egui::Color32::RED
-                } else {
-                    egui::Color32::WHITE
-                },
-            ),
-        ),
+        NameTagType::Character => {
+            let mut name = if player.map_or(false, |player| player.entity == object.entity) {
+                character_name_text(object)
+            } else {
+                interface_settings
+                    .other_character_name(&object.name.name)
+                    .to_string()
+            };
+            let extra_rows = character_extra_rows(object);
+            if !extra_rows.is_empty() {
+                name.push('\n');
+            }
+
+            let mut layout_job = egui::epaint::text::LayoutJob::single_section(
+                name,
+                egui::TextFormat::simple(
+                    egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]),
+                    if object.team.map_or(false, |team| {
+                        Some(team.id) != player.map(|player| player.team.id)
+                    }) {
+                        accessibility_settings.hostile_color()
+                    } else {
+                        accessibility_settings.friendly_color()
+                    },
+                ),
+            );
+            let num_extra_rows = extra_rows.len();
+            for (row_index, (mut text, color)) in extra_rows.into_iter().enumerate() {
+                if row_index + 1 < num_extra_rows {
+                    text.push('\n');
+                }
+                layout_job.append(
+                    &text,
+                    0.0,
+                    egui::TextFormat::simple(
+                        egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]),
+                        color,
+                    ),
+                );
+            }
+            layout_job
+        }
         NameTagType::Monster => egui::epaint::text::LayoutJob::single_section(
             object.name.name.clone(),
             egui::TextFormat::simple(
                 egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]),
                 get_monster_name_tag_color(
+                    accessibility_settings,
                     player.map(|player| player.level),
                     object.level,
                     object.team,
@@ -162,7 +240,7 @@ fn create_pending_nametag(
                     object.name.name.clone(),
                     egui::TextFormat::simple(
                         egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]),
-                        egui::Color32::GREEN,
+                        accessibility_settings.npc_color(),
                     ),
                 )
             }
@@ -346,7 +424,7 @@ fn create_nametag_data(
     image.sampler_descriptor = ImageSampler::Descriptor(ImageSampler::nearest_descriptor());
     let image = images.add(image);
 
-    let mut rects: ArrayVec<WorldUiRect, 2> = ArrayVec::new();
+    let mut rects: ArrayVec<WorldUiRect, MAX_NAME_ROWS> = ArrayVec::new();
     let mut row_offset_y = max_bounds.y - 8.0 * (pending_data.colors.len() - 1) as f32;
 
     if matches!(pending_data.name_tag_type, NameTagType::Monster) {
@@ -386,7 +464,10 @@ pub fn name_tag_system(
     mut commands: Commands,
     mut name_tag_cache: Local<NameTagCache>,
     query_add: Query<NameTagObjectQuery, Without<NameTagEntity>>,
-    query_changed: Query<(Entity, Option<&NameTagEntity>), Changed<ClientEntityName>>,
+    query_changed: Query<
+        (Entity, Option<&NameTagEntity>),
+        Or<(Changed<ClientEntityName>, Changed<ClanMembership>)>,
+    >,
     query_player: Query<PlayerQuery, With<PlayerCharacter>>,
     query_nametags: Query<(Entity, &NameTagEntity)>,
     query_window: Query<Entity, With<PrimaryWindow>>,
@@ -396,6 +477,8 @@ pub fn name_tag_system(
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
     name_tag_settings: Res<NameTagSettings>,
+    interface_settings: Res<InterfaceSettings>,
+    accessibility_settings: Res<AccessibilitySettings>,
     mut load_zone_events: EventReader<LoadZoneEvent>,
 ) {
     let player = query_player.get_single().ok();
@@ -406,9 +489,13 @@ pub fn name_tag_system(
 
     if load_zone_events.iter().last().is_some()
         || pixels_per_point != name_tag_cache.pixels_per_point
+        || interface_settings.streamer_mode != name_tag_cache.streamer_mode
+        || accessibility_settings.color_palette != name_tag_cache.color_palette
     {
         // When the zone changes, we flush all cached name tag textures to avoid leaking
         // If pixels_per_point has changed then we need to regenerate name tags using new DPI
+        // If streamer mode has changed then we need to regenerate character names
+        // If the colour palette has changed then we need to regenerate all name colours
         for (entity, name_tag_entity) in query_nametags.iter() {
             commands.entity(entity).remove::<NameTagEntity>();
             commands.entity(name_tag_entity.0).despawn_recursive();
@@ -417,6 +504,8 @@ pub fn name_tag_system(
         name_tag_cache.cache.clear();
         name_tag_cache.pending.clear();
         name_tag_cache.pixels_per_point = pixels_per_point;
+        name_tag_cache.streamer_mode = interface_settings.streamer_mode;
+        name_tag_cache.color_palette = accessibility_settings.color_palette;
         return;
     }
 
@@ -449,8 +538,8 @@ pub fn name_tag_system(
             NameTagType::Character
         };
 
-        let name_tag_data = if let Some(name_tag_data) = name_tag_cache.cache.get(&object.name.name)
-        {
+        let cache_key = name_tag_cache_key(&object);
+        let name_tag_data = if let Some(name_tag_data) = name_tag_cache.cache.get(&cache_key) {
             name_tag_data
         } else if let Some(pending_name_tag_data) = name_tag_cache.pending.remove(&object.entity) {
             if let Some(name_tag_data) = create_nametag_data(
@@ -462,8 +551,8 @@ pub fn name_tag_system(
             ) {
                 name_tag_cache
                     .cache
-                    .insert(object.name.name.clone(), name_tag_data);
-                name_tag_cache.cache.get(&object.name.name).unwrap()
+                    .insert(cache_key.clone(), name_tag_data);
+                name_tag_cache.cache.get(&cache_key).unwrap()
             } else {
                 // Try again next frame
                 continue;
@@ -475,6 +564,8 @@ pub fn name_tag_system(
                 object.entity,
                 create_pending_nametag(
                     &name_tag_settings,
+                    &interface_settings,
+                    &accessibility_settings,
                     &mut egui_context,
                     &object,
                     player.as_ref(),
@@ -542,8 +633,18 @@ pub fn name_tag_system(
             NameTagType::Npc => (None, None),
         };
 
+        let mana_foreground = match name_tag_type {
+            NameTagType::Character => ui_resources
+                .get_sprite(0, "UI00_GUAGE_BLUE")
+                .zip(ui_resources.get_sprite_image(0, "UI00_GUAGE_BLUE")),
+            NameTagType::Monster | NameTagType::Npc => None,
+        };
+
         let mut health_bar_size = Vec2::ZERO;
         let mut health_bar_foreground_uv_x_bounds = (0.0, 0.0);
+        let mut mana_bar_foreground_uv_x_bounds = (0.0, 0.0);
+        let mut manabar_fg_rect = None;
+        let mut manabar_bg_rect = None;
         if let (
             Some((health_foreground_sprite, health_foreground_image)),
             Some((health_background_sprite, health_background_image)),
@@ -596,6 +697,47 @@ pub fn name_tag_system(
                 color: Color::WHITE,
                 order: ORDER_HEALTH_FOREGROUND,
             });
+
+            if let Some((mana_foreground_sprite, mana_foreground_image)) = mana_foreground {
+                // Mana bar directly under the health bar
+                let mana_bar_offset_y = bar_offset_y - bar_height;
+
+                manabar_bg_rect = Some(WorldUiRect {
+                    screen_offset: Vec2::new(-bar_width / 2.0, mana_bar_offset_y),
+                    screen_size: Vec2::new(bar_width, bar_height),
+                    image: health_background_image.clone_weak(),
+                    uv_min: Vec2::new(
+                        health_background_sprite.uv.min.x,
+                        health_background_sprite.uv.min.y,
+                    ),
+                    uv_max: Vec2::new(
+                        health_background_sprite.uv.max.x,
+                        health_background_sprite.uv.max.y,
+                    ),
+                    color: Color::WHITE,
+                    order: ORDER_HEALTH_BACKGROUND,
+                });
+
+                mana_bar_foreground_uv_x_bounds = (
+                    mana_foreground_sprite.uv.min.x,
+                    mana_foreground_sprite.uv.max.x,
+                );
+                manabar_fg_rect = Some(WorldUiRect {
+                    screen_offset: Vec2::new(-bar_width / 2.0, mana_bar_offset_y),
+                    screen_size: Vec2::new(bar_width, bar_height),
+                    image: mana_foreground_image.clone_weak(),
+                    uv_min: Vec2::new(
+                        mana_foreground_sprite.uv.min.x,
+                        mana_foreground_sprite.uv.min.y,
+                    ),
+                    uv_max: Vec2::new(
+                        mana_foreground_sprite.uv.max.x,
+                        mana_foreground_sprite.uv.max.y,
+                    ),
+                    color: Color::WHITE,
+                    order: ORDER_HEALTH_FOREGROUND,
+                });
+            }
         }
 
         let mut target_marks: ArrayVec<WorldUiRect, 2> = ArrayVec::default();
@@ -697,6 +839,38 @@ pub fn name_tag_system(
                 .set_parent(name_tag_entity);
         }
 
+        if let Some(rect) = manabar_bg_rect.take() {
+            commands
+                .spawn((
+                    NameTagManabarBackground,
+                    rect,
+                    Transform::default(),
+                    GlobalTransform::default(),
+                    Visibility::Hidden,
+                    ComputedVisibility::default(),
+                    NoFrustumCulling,
+                ))
+                .set_parent(name_tag_entity);
+        }
+
+        if let Some(rect) = manabar_fg_rect.take() {
+            commands
+                .spawn((
+                    NameTagManabarForeground {
+                        full_width: health_bar_size.x,
+                        uv_min_x: mana_bar_foreground_uv_x_bounds.0,
+                        uv_max_x: mana_bar_foreground_uv_x_bounds.1,
+                    },
+                    rect,
+                    Transform::default(),
+                    GlobalTransform::default(),
+                    Visibility::Hidden,
+                    ComputedVisibility::default(),
+                    NoFrustumCulling,
+                ))
+                .set_parent(name_tag_entity);
+        }
+
         commands
             .entity(object.entity)
             .insert(NameTagEntity(name_tag_entity))
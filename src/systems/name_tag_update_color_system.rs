@@ -1,6 +1,6 @@
 use bevy::{
     ecs::query::WorldQuery,
-    prelude::{Changed, Children, Color, Or, Parent, Query, With},
+    prelude::{Changed, Children, Color, Or, Parent, Query, Res, With},
 };
 
 use rose_game_common::components::{Level, Team};
@@ -8,6 +8,7 @@ use rose_game_common::components::{Level, Team};
 use crate::{
     components::{NameTag, NameTagName, NameTagType, PlayerCharacter},
     render::WorldUiRect,
+    resources::AccessibilitySettings,
     systems::name_tag_system::get_monster_name_tag_color,
 };
 
@@ -23,6 +24,7 @@ pub fn name_tag_update_color_system(
     query_level: Query<&Level>,
     query_team: Query<&Team>,
     mut query_name_rects: Query<&mut WorldUiRect, With<NameTagName>>,
+    accessibility_settings: Res<AccessibilitySettings>,
 ) {
     let player = if let Ok(player) = query_player.get_single() {
         player
@@ -38,26 +40,24 @@ pub fn name_tag_update_color_system(
                     .get(parent.get())
                     .map_or(false, |team| team.id != player.team.id)
                 {
-                    Color::RED
+                    accessibility_settings.hostile_color()
                 } else {
-                    Color::WHITE
+                    accessibility_settings.friendly_color()
                 }
             }
-            NameTagType::Monster => {
-                let color = get_monster_name_tag_color(
-                    Some(player.level),
-                    query_level.get(parent.get()).ok(),
-                    query_team.get(parent.get()).ok(),
-                )
-                .to_array();
-
-                Color::rgb_linear(
-                    color[0] as f32 / 255.0,
-                    color[1] as f32 / 255.0,
-                    color[2] as f32 / 255.0,
-                )
-            }
+            NameTagType::Monster => get_monster_name_tag_color(
+                &accessibility_settings,
+                Some(player.level),
+                query_level.get(parent.get()).ok(),
+                query_team.get(parent.get()).ok(),
+            ),
         };
+        let color = color.to_array();
+        let color = Color::rgb_linear(
+            color[0] as f32 / 255.0,
+            color[1] as f32 / 255.0,
+            color[2] as f32 / 255.0,
+        );
 
         for &child_entity in children.iter() {
             if let Ok(mut rect) = query_name_rects.get_mut(child_entity) {
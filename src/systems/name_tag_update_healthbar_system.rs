@@ -1,13 +1,21 @@
-use bevy::prelude::{Parent, Query};
+use bevy::prelude::{Parent, Query, Without};
 
-use rose_game_common::components::{AbilityValues, HealthPoints};
+use rose_game_common::components::{AbilityValues, HealthPoints, ManaPoints};
 
-use crate::{components::NameTagHealthbarForeground, render::WorldUiRect};
+use crate::{
+    components::{NameTagHealthbarForeground, NameTagManabarForeground},
+    render::WorldUiRect,
+};
 
 pub fn name_tag_update_healthbar_system(
-    mut query_nametag_healthbar: Query<(&Parent, &NameTagHealthbarForeground, &mut WorldUiRect)>,
+    mut query_nametag_healthbar: Query<
+        (&Parent, &NameTagHealthbarForeground, &mut WorldUiRect),
+        Without<NameTagManabarForeground>,
+    >,
+    mut query_nametag_manabar: Query<(&Parent, &NameTagManabarForeground, &mut WorldUiRect)>,
     query_parent: Query<&Parent>,
     query_health: Query<(&HealthPoints, &AbilityValues)>,
+    query_mana: Query<(&ManaPoints, &AbilityValues)>,
 ) {
     for (parent, name_tag_healthbar_fg, mut rect) in query_nametag_healthbar.iter_mut() {
         if let Ok((health_points, ability_values)) = query_parent
@@ -23,4 +31,18 @@ pub fn name_tag_update_healthbar_system(
             rect.screen_size.x = name_tag_healthbar_fg.full_width * health_percent;
         }
     }
+
+    for (parent, name_tag_manabar_fg, mut rect) in query_nametag_manabar.iter_mut() {
+        if let Ok((mana_points, ability_values)) = query_parent
+            .get(parent.get())
+            .and_then(|parent| query_mana.get(parent.get()))
+        {
+            let mana_percent =
+                (mana_points.mp as f32 / ability_values.get_max_mana() as f32).clamp(0.0, 1.0);
+
+            rect.uv_max.x = name_tag_manabar_fg.uv_min_x
+                + mana_percent * (name_tag_manabar_fg.uv_max_x - name_tag_manabar_fg.uv_min_x);
+            rect.screen_size.x = name_tag_manabar_fg.full_width * mana_percent;
+        }
+    }
 }
@@ -1,15 +1,19 @@
 use bevy::{
     ecs::query::WorldQuery,
+    math::Vec3Swizzles,
     prelude::{Children, Entity, Local, Or, Query, Res, ResMut, Visibility, With},
 };
-use rose_game_common::components::Npc;
+use rose_game_common::components::{AbilityValues, HealthPoints, Npc, Team};
 
 use crate::{
     components::{
-        Dead, NameTag, NameTagEntity, NameTagHealthbarBackground, NameTagHealthbarForeground,
-        NameTagTargetMark,
+        ClientEntity, Command, Dead, NameTag, NameTagEntity, NameTagHealthbarBackground,
+        NameTagHealthbarForeground, NameTagManabarBackground, NameTagManabarForeground,
+        NameTagTargetMark, NameTagType, PartyInfo, PlayerCharacter, Position,
+    },
+    resources::{
+        NameTagHealthbarCategory, NameTagHealthbarPolicy, NameTagSettings, SelectedTarget,
     },
-    resources::{NameTagSettings, SelectedTarget},
 };
 
 #[derive(Default)]
@@ -25,6 +29,29 @@ pub struct NameTagQuery<'w> {
     children: &'w Children,
 }
 
+#[derive(WorldQuery)]
+pub struct NameTagHealthbarObjectQuery<'w> {
+    name_tag_entity: &'w NameTagEntity,
+    client_entity: Option<&'w ClientEntity>,
+    position: Option<&'w Position>,
+    team: Option<&'w Team>,
+    health_points: Option<&'w HealthPoints>,
+    ability_values: Option<&'w AbilityValues>,
+    command: Option<&'w Command>,
+}
+
+fn set_visibility(
+    query_visibility: &mut Query<&mut Visibility>,
+    entity: Entity,
+    value: Visibility,
+) {
+    if let Ok(mut visibility) = query_visibility.get_mut(entity) {
+        if *visibility != value {
+            *visibility = value;
+        }
+    }
+}
+
 pub fn name_tag_visibility_system(
     mut state: Local<NameTagVisibility>,
     mut selected_target: ResMut<SelectedTarget>,
@@ -37,8 +64,21 @@ pub fn name_tag_visibility_system(
             With<NameTagTargetMark>,
             With<NameTagHealthbarBackground>,
             With<NameTagHealthbarForeground>,
+            With<NameTagManabarBackground>,
+            With<NameTagManabarForeground>,
         )>,
     >,
+    query_name_tag_bars: Query<
+        Entity,
+        Or<(
+            With<NameTagHealthbarBackground>,
+            With<NameTagHealthbarForeground>,
+            With<NameTagManabarBackground>,
+            With<NameTagManabarForeground>,
+        )>,
+    >,
+    query_healthbar_objects: Query<NameTagHealthbarObjectQuery>,
+    query_player: Query<(&Team, &Position, Option<&PartyInfo>), With<PlayerCharacter>>,
     query_npc_dead: Query<&Dead, With<Npc>>,
     name_tag_settings: Res<NameTagSettings>,
 ) {
@@ -134,4 +174,99 @@ pub fn name_tag_visibility_system(
             }
         }
     }
+
+    // Show health and mana bars depending on the configured policy for each category
+    let player = query_player.get_single().ok();
+    for object in query_healthbar_objects.iter() {
+        let name_tag_entity = object.name_tag_entity.0;
+        if state.selected == Some(name_tag_entity) {
+            // Everything is already visible for the selected target
+            continue;
+        }
+
+        let Ok(name_tag) = query_name_tag.get(name_tag_entity) else {
+            continue;
+        };
+
+        let category = match name_tag.name_tag.name_tag_type {
+            NameTagType::Npc => continue,
+            NameTagType::Character => {
+                let is_party_member = player
+                    .and_then(|(_, _, party_info)| party_info)
+                    .zip(object.client_entity)
+                    .map_or(false, |(party_info, client_entity)| {
+                        party_info.contains_member(client_entity.id)
+                    });
+
+                if is_party_member {
+                    NameTagHealthbarCategory::PartyMember
+                } else {
+                    NameTagHealthbarCategory::Character
+                }
+            }
+            NameTagType::Monster => {
+                if object.team.map(|team| team.id) == player.map(|(team, _, _)| team.id) {
+                    NameTagHealthbarCategory::Pet
+                } else {
+                    NameTagHealthbarCategory::Monster
+                }
+            }
+        };
+
+        // Party members use a longer distance so their bars remain visible across a fight
+        let distance = player
+            .zip(object.position)
+            .map(|((_, player_position, _), position)| {
+                player_position
+                    .position
+                    .xy()
+                    .distance(position.position.xy())
+            });
+        let in_range = distance.map_or(false, |distance| {
+            distance < name_tag_settings.healthbar_distance[category]
+        });
+
+        let show_bars = in_range
+            && match name_tag_settings.healthbar_policy[category] {
+                NameTagHealthbarPolicy::Always => true,
+                NameTagHealthbarPolicy::Never => false,
+                NameTagHealthbarPolicy::InCombat => {
+                    object.command.map_or(false, |command| {
+                        matches!(command, Command::Attack(_) | Command::CastSkill(_))
+                    }) || object.health_points.zip(object.ability_values).map_or(
+                        false,
+                        |(health_points, ability_values)| {
+                            health_points.hp < ability_values.get_max_health()
+                        },
+                    )
+                }
+            };
+
+        let name_tag_visible = show_bars
+            || state.hover == Some(name_tag_entity)
+            || name_tag_settings.show_all[name_tag.name_tag.name_tag_type];
+        set_visibility(
+            &mut query_visibility,
+            name_tag_entity,
+            if name_tag_visible {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            },
+        );
+
+        for &child in name_tag.children.iter() {
+            if query_name_tag_bars.contains(child) {
+                set_visibility(
+                    &mut query_visibility,
+                    child,
+                    if show_bars {
+                        Visibility::Inherited
+                    } else {
+                        Visibility::Hidden
+                    },
+                );
+            }
+        }
+    }
 }
@@ -7,15 +7,18 @@ use rose_game_common::{
 
 use crate::{
     events::NetworkEvent,
-    protocol::irose,
+    protocol::{irose, ExtensionClientMessage, ExtensionServerMessage},
     resources::{
-        GameConnection, LoginConnection, NetworkThread, NetworkThreadMessage, WorldConnection,
+        GameConnection, LoginConnection, NetworkThread, NetworkThreadMessage, ServerConfiguration,
+        ServerProfiles, WorldConnection,
     },
 };
 
 pub fn network_thread_system(
     mut commands: Commands,
     network_thread: Res<NetworkThread>,
+    server_configuration: Res<ServerConfiguration>,
+    server_profiles: Res<ServerProfiles>,
     mut network_events: EventReader<NetworkEvent>,
 ) {
     for event in network_events.iter() {
@@ -52,6 +55,10 @@ pub fn network_thread_system(
                     crossbeam_channel::unbounded::<ServerMessage>();
                 let (client_message_tx, client_message_rx) =
                     tokio::sync::mpsc::unbounded_channel::<ClientMessage>();
+                let (extension_server_message_tx, extension_server_message_rx) =
+                    crossbeam_channel::unbounded::<ExtensionServerMessage>();
+                let (extension_client_message_tx, extension_client_message_rx) =
+                    tokio::sync::mpsc::unbounded_channel::<ExtensionClientMessage>();
                 let server_address = format!("{}:{}", ip, port).parse().unwrap();
 
                 network_thread
@@ -62,6 +69,8 @@ pub fn network_thread_system(
                             packet_codec_seed,
                             client_message_rx,
                             server_message_tx,
+                            extension_client_message_rx,
+                            extension_server_message_tx,
                         ),
                     )))
                     .ok();
@@ -69,6 +78,8 @@ pub fn network_thread_system(
                 commands.insert_resource(WorldConnection::new(
                     client_message_tx,
                     server_message_rx,
+                    extension_client_message_tx,
+                    extension_server_message_rx,
                     login_token,
                     Password::Plaintext(password.clone()),
                 ));
@@ -84,6 +95,10 @@ pub fn network_thread_system(
                     crossbeam_channel::unbounded::<ServerMessage>();
                 let (client_message_tx, client_message_rx) =
                     tokio::sync::mpsc::unbounded_channel::<ClientMessage>();
+                let (extension_server_message_tx, extension_server_message_rx) =
+                    crossbeam_channel::unbounded::<ExtensionServerMessage>();
+                let (extension_client_message_tx, extension_client_message_rx) =
+                    tokio::sync::mpsc::unbounded_channel::<ExtensionClientMessage>();
                 let server_address = format!("{}:{}", ip, port).parse().unwrap();
 
                 network_thread
@@ -94,6 +109,12 @@ pub fn network_thread_system(
                             packet_codec_seed,
                             client_message_rx,
                             server_message_tx,
+                            extension_client_message_rx,
+                            extension_server_message_tx,
+                            server_profiles
+                                .selected()
+                                .map_or(false, |server_profile| server_profile.extensions),
+                            server_configuration.connection_timeout,
                         ),
                     )))
                     .ok();
@@ -101,6 +122,8 @@ pub fn network_thread_system(
                 commands.insert_resource(GameConnection::new(
                     client_message_tx,
                     server_message_rx,
+                    extension_client_message_tx,
+                    extension_server_message_rx,
                     login_token,
                     Password::Plaintext(password.clone()),
                 ));
@@ -0,0 +1,136 @@
+use std::ops::Range;
+
+use bevy::{
+    math::Vec3Swizzles,
+    prelude::{Commands, Component, Entity, EventReader, Query, Res, With, Without},
+    time::Time,
+};
+use rand::Rng;
+
+use rose_data::{MotionId, NpcMotionAction};
+use rose_game_common::components::Npc;
+
+use crate::{
+    components::{
+        ClientEntity, ClientEntityType, Command, Dead, FacingDirection, NextCommand, NpcModel,
+        PlayerCharacter, Position,
+    },
+    events::ConversationDialogEvent,
+};
+
+const IDLE_MOTION_INTERVAL: Range<f32> = 10.0..30.0;
+
+// Once the player has walked this far away the NPC turns back to its original facing
+const LOOK_AT_PLAYER_DISTANCE: f32 = 1000.0;
+
+#[derive(Component)]
+pub struct NpcIdleState {
+    pub timer: f32,
+    pub next_motion_time: f32,
+    pub home_facing: Option<f32>,
+}
+
+pub fn npc_idle_system(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &ClientEntity,
+            &NpcModel,
+            &Position,
+            &Command,
+            &mut NextCommand,
+            &mut FacingDirection,
+            Option<&mut NpcIdleState>,
+        ),
+        (With<Npc>, Without<Dead>),
+    >,
+    query_player: Query<&Position, With<PlayerCharacter>>,
+    mut conversation_dialog_events: EventReader<ConversationDialogEvent>,
+    time: Res<Time>,
+) {
+    let mut rng = rand::thread_rng();
+    let player_position = query_player.get_single().ok();
+
+    for event in conversation_dialog_events.iter() {
+        let &ConversationDialogEvent::OpenNpcDialog(npc_entity, _) = event else {
+            continue;
+        };
+        let Some(player_position) = player_position else {
+            continue;
+        };
+
+        // Only NPCs are given an NpcIdleState
+        if let Ok((_, _, _, npc_position, _, _, mut facing_direction, Some(mut idle_state))) =
+            query.get_mut(npc_entity)
+        {
+            // Remember where the NPC was facing so it can turn back after the conversation
+            if idle_state.home_facing.is_none() {
+                idle_state.home_facing = Some(facing_direction.desired);
+            }
+
+            facing_direction.set_desired_vector(player_position.position - npc_position.position);
+        }
+    }
+
+    for (
+        entity,
+        client_entity,
+        npc_model,
+        position,
+        command,
+        mut next_command,
+        mut facing_direction,
+        idle_state,
+    ) in query.iter_mut()
+    {
+        // Monsters also have the Npc component, but only NPCs should idle and look at the player
+        if client_entity.entity_type != ClientEntityType::Npc {
+            continue;
+        }
+
+        let Some(mut idle_state) = idle_state else {
+            commands.entity(entity).insert(NpcIdleState {
+                timer: 0.0,
+                next_motion_time: rng.gen_range(IDLE_MOTION_INTERVAL),
+                home_facing: None,
+            });
+            continue;
+        };
+
+        if let Some(home_facing) = idle_state.home_facing {
+            let player_nearby = player_position.map_or(false, |player_position| {
+                player_position
+                    .position
+                    .xy()
+                    .distance(position.position.xy())
+                    < LOOK_AT_PLAYER_DISTANCE
+            });
+
+            if !player_nearby {
+                facing_direction.desired = home_facing;
+                idle_state.home_facing = None;
+            }
+        }
+
+        // Only play idle variations when the server is not moving the NPC
+        if !command.is_stop() || next_command.is_some() {
+            idle_state.timer = 0.0;
+            continue;
+        }
+
+        idle_state.timer += time.delta_seconds();
+        if idle_state.timer < idle_state.next_motion_time {
+            continue;
+        }
+
+        idle_state.timer = 0.0;
+        idle_state.next_motion_time = rng.gen_range(IDLE_MOTION_INTERVAL);
+
+        if npc_model.action_motions[NpcMotionAction::Etc].is_strong() {
+            // Once the emote motion completes the command system returns the NPC to stop
+            *next_command =
+                NextCommand::with_emote(MotionId::new(NpcMotionAction::Etc as u16), false);
+        }
+    }
+}
@@ -1,15 +1,12 @@
 use bevy::{
     asset::LoadState,
     ecs::query::QueryEntityError,
-    math::{Quat, Vec3, Vec3A},
+    math::{Vec3, Vec3A},
     prelude::{
         AssetServer, Assets, BuildChildren, Commands, Entity, GlobalTransform, Query, Res,
         Transform, With, Without,
     },
-    render::{
-        mesh::skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
-        primitives::Aabb,
-    },
+    render::{mesh::skinning::SkinnedMesh, primitives::Aabb},
 };
 use bevy_rapier3d::prelude::{Collider, CollisionGroups};
 
@@ -27,7 +24,6 @@ pub fn npc_model_add_collider_system(
     mut commands: Commands,
     query_models: Query<(Entity, &NpcModel, &SkinnedMesh), Without<ColliderEntity>>,
     query_aabb: Query<Option<&Aabb>, With<SkinnedMesh>>,
-    inverse_bindposes: Res<Assets<SkinnedMeshInverseBindposes>>,
     zmo_assets: Res<Assets<ZmoAsset>>,
     asset_server: Res<AssetServer>,
 ) {
@@ -71,23 +67,16 @@ pub fn npc_model_add_collider_system(
             0.0
         };
 
-        let inverse_bindpose = inverse_bindposes.get(&skinned_mesh.inverse_bindposes);
-        if min.is_none()
-            || max.is_none()
-            || !all_parts_loaded
-            || inverse_bindpose.is_none()
-            || skinned_mesh.joints.is_empty()
-        {
+        if min.is_none() || max.is_none() || !all_parts_loaded || skinned_mesh.joints.is_empty() {
             continue;
         }
-        let root_bone_entity = skinned_mesh.joints[0];
-        let root_bone_inverse_bindpose = Transform::from_matrix(inverse_bindpose.unwrap()[0]);
 
+        // The collider is sized from the combined AABB of the model parts and offset from the
+        // model root, so it matches the model regardless of the orientation of its root bone
         let min = Vec3::from(min.unwrap());
         let max = Vec3::from(max.unwrap());
-        let local_bound_center = 0.5 * (min + max);
+        let local_bound_center = 0.5 * (min + max) + Vec3::new(0.0, root_bone_height, 0.0);
         let half_extents = 0.5 * (max - min);
-        let root_bone_offset = root_bone_inverse_bindpose.transform_point(local_bound_center);
 
         let collider_entity = commands
             .spawn((
@@ -99,13 +88,12 @@ pub fn npc_model_add_collider_system(
                         | COLLISION_FILTER_CLICKABLE
                         | COLLISION_GROUP_PHYSICS_TOY,
                 ),
-                Transform::from_translation(root_bone_offset)
-                    .with_rotation(Quat::from_axis_angle(Vec3::Z, std::f32::consts::PI / 2.0)),
+                Transform::from_translation(local_bound_center),
                 GlobalTransform::default(),
             ))
             .id();
 
-        commands.entity(root_bone_entity).add_child(collider_entity);
+        commands.entity(entity).add_child(collider_entity);
 
         commands.entity(entity).insert((
             ColliderEntity::new(collider_entity),
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bevy::{
     input::{
         mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
@@ -5,8 +7,8 @@ use bevy::{
     },
     math::{Quat, Vec2, Vec3},
     prelude::{
-        Component, Entity, EventReader, GlobalTransform, Local, MouseButton, Query, Res, Time,
-        Transform, With,
+        Component, Entity, EventReader, GlobalTransform, KeyCode, Local, MouseButton, Query, Res,
+        Time, Transform, With,
     },
     window::{CursorGrabMode, PrimaryWindow, Window},
 };
@@ -17,10 +19,17 @@ use bevy_rapier3d::{
 };
 use dolly::prelude::{Arm, CameraRig, LeftHanded, Position, Smooth, YawPitch};
 
-use crate::components::{
-    COLLISION_FILTER_COLLIDABLE, COLLISION_FILTER_MOVEABLE, COLLISION_GROUP_PHYSICS_TOY,
+use crate::{
+    components::{
+        COLLISION_FILTER_COLLIDABLE, COLLISION_FILTER_MOVEABLE, COLLISION_GROUP_PHYSICS_TOY,
+    },
+    resources::{ControlScheme, InterfaceSettings, KeyBindingAction, KeyBindings},
+    ui::UiStateWindows,
 };
 
+// Degrees per second to rotate the camera when idle
+const IDLE_CAMERA_ORBIT_SPEED: f32 = 4.0;
+
 #[derive(Component)]
 pub struct OrbitCamera {
     pub rig: CameraRig<LeftHanded>,
@@ -57,6 +66,7 @@ impl OrbitCamera {
 pub struct CameraControlState {
     pub is_dragging: bool,
     pub saved_cursor_position: Option<Vec2>,
+    pub idle_time: Duration,
 }
 
 pub fn orbit_camera_system(
@@ -68,6 +78,10 @@ pub fn orbit_camera_system(
     mut query_window: Query<&mut Window, With<PrimaryWindow>>,
     mut egui_ctx: EguiContexts,
     mouse_buttons: Res<Input<MouseButton>>,
+    keyboard_input: Res<Input<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    interface_settings: Res<InterfaceSettings>,
+    ui_state_windows: Option<Res<UiStateWindows>>,
     time: Res<Time>,
     rapier_context: Res<RapierContext>,
 ) {
@@ -107,12 +121,32 @@ pub fn orbit_camera_system(
         return;
     }
 
-    let allow_mouse_input = control_state.is_dragging || !egui_ctx.ctx_mut().wants_pointer_input();
-    let right_pressed = mouse_buttons.pressed(MouseButton::Right);
+    // Mouse look is paused whilst the escape menu is open
+    let menu_open = ui_state_windows
+        .as_ref()
+        .map_or(false, |ui_state_windows| ui_state_windows.escape_menu_open);
+    let allow_mouse_input =
+        !menu_open && (control_state.is_dragging || !egui_ctx.ctx_mut().wants_pointer_input());
     let mut drag_delta = Vec2::ZERO;
     let mut zoom_multiplier = 1.0;
-
-    if right_pressed {
+    let has_mouse_motion = !mouse_motion_events.is_empty();
+
+    // The modern control scheme also rotates with the left button, but only once the mouse has
+    // moved so that a left click can still be used to select targets
+    let left_dragging = interface_settings.control_scheme == ControlScheme::Modern
+        && mouse_buttons.pressed(MouseButton::Left)
+        && !mouse_buttons.just_pressed(MouseButton::Left)
+        && (control_state.is_dragging || (has_mouse_motion && allow_mouse_input));
+    // Never keep the cursor grabbed whilst the window is unfocused, e.g. after alt-tab
+    let camera_button_pressed = window.focused
+        && !menu_open
+        && (key_bindings.pressed(
+            KeyBindingAction::RotateCamera,
+            &keyboard_input,
+            &mouse_buttons,
+        ) || left_dragging);
+
+    if camera_button_pressed {
         if allow_mouse_input {
             for event in mouse_motion_events.iter() {
                 drag_delta += event.delta;
@@ -174,14 +208,35 @@ pub fn orbit_camera_system(
     }
 
     // Rotate with mouse drag
-    if right_pressed {
-        let sensitivity = 0.1;
+    if camera_button_pressed {
+        let sensitivity = 0.1 * interface_settings.camera_sensitivity;
         orbit_camera
             .rig
             .driver_mut::<YawPitch>()
             .rotate_yaw_pitch(-sensitivity * drag_delta.x, -sensitivity * drag_delta.y);
     }
 
+    // Slowly orbit the camera when there has been no input for a while
+    if has_mouse_motion
+        || mouse_buttons.get_pressed().next().is_some()
+        || keyboard_input.get_pressed().next().is_some()
+        || zoom_multiplier != 1.0
+    {
+        control_state.idle_time = Duration::ZERO;
+    } else {
+        control_state.idle_time += time.delta();
+    }
+
+    if interface_settings
+        .idle_camera_orbit
+        .map_or(false, |idle_delay| control_state.idle_time > idle_delay)
+    {
+        orbit_camera
+            .rig
+            .driver_mut::<YawPitch>()
+            .rotate_yaw_pitch(IDLE_CAMERA_ORBIT_SPEED * time.delta_seconds(), 0.0);
+    }
+
     // Adjust zoom with mouse wheel
     orbit_camera.follow_distance = (orbit_camera.follow_distance * zoom_multiplier)
         .clamp(orbit_camera.min_distance, orbit_camera.max_distance);
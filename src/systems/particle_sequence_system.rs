@@ -9,8 +9,10 @@ use rand::Rng;
 use rose_file_readers::{PtlKeyframeData, PtlUpdateCoords};
 
 use crate::{
+    animation::{AnimationThrottle, AnimationThrottleSettings},
     components::{ActiveParticle, ParticleSequence},
     render::ParticleRenderData,
+    resources::RenderConfiguration,
 };
 
 fn rng_gen_range<R: Rng>(rng: &mut R, range: &RangeInclusive<f32>) -> f32 {
@@ -283,16 +285,29 @@ fn apply_keyframes<R: Rng>(
 
 pub fn particle_sequence_system(
     time: Res<Time>,
+    render_configuration: Res<RenderConfiguration>,
+    throttle_settings: Res<AnimationThrottleSettings>,
     mut query: Query<(
         &GlobalTransform,
         &mut ParticleSequence,
         &mut ParticleRenderData,
+        Option<&mut AnimationThrottle>,
     )>,
 ) {
     let mut rng = rand::thread_rng();
-    let delta_time = time.delta_seconds();
 
-    for (global_transform, mut particle_sequence, mut particle_render_data) in query.iter_mut() {
+    for (global_transform, mut particle_sequence, mut particle_render_data, throttle) in
+        query.iter_mut()
+    {
+        // Far away or off-screen particles are simulated less often using the accumulated time
+        let delta_time = match throttle {
+            Some(mut throttle) => match throttle.tick(&throttle_settings, time.delta_seconds()) {
+                Some(delta_time) => delta_time,
+                None => continue,
+            },
+            None => time.delta_seconds(),
+        };
+
         if particle_sequence.start_delay > 0.0 {
             particle_sequence.start_delay -= delta_time;
             if particle_sequence.start_delay > 0.0 {
@@ -340,9 +355,13 @@ pub fn particle_sequence_system(
                 }
             }
 
-            // Spawn new particles
+            // Spawn new particles, limited by the configured particle density
+            let max_particles = ((particle_sequence.num_particles as f32
+                * render_configuration.particle_density)
+                .ceil() as usize)
+                .max(1);
             while particle_sequence.emit_counter > 1.0
-                && particle_sequence.particles.len() < particle_sequence.num_particles as usize
+                && particle_sequence.particles.len() < max_particles
             {
                 let mut position = Vec3::new(
                     rng_gen_range(&mut rng, &particle_sequence.emit_radius_x),
@@ -3,25 +3,33 @@ use std::time::Duration;
 use bevy::{
     ecs::query::WorldQuery,
     math::Vec3Swizzles,
-    prelude::{Entity, EventReader, EventWriter, Query, Res, With},
+    prelude::{Entity, EventReader, EventWriter, Query, Res, ResMut, With},
+    time::Time,
 };
 
 use rose_data::{
-    AmmoIndex, EquipmentIndex, ItemClass, ItemType, SkillBasicCommand, SkillCooldown,
-    SkillTargetFilter, SkillType, VehiclePartIndex,
+    AmmoIndex, EquipmentIndex, ItemClass, ItemType, SkillBasicCommand, SkillCooldown, SkillType,
+    VehiclePartIndex,
 };
 use rose_game_common::{
-    components::{CharacterInfo, Hotbar, HotbarSlot, Inventory, ItemDrop, SkillList, Team},
+    components::{Hotbar, HotbarSlot, Inventory, ItemDrop, SkillList, SkillSlot, Team},
     messages::client::ClientMessage,
 };
 
 use crate::{
+    bundles::{
+        skill_can_target_entity, skill_can_use, skill_is_beneficial, SkillCasterQuery,
+        SkillTargetQuery, SkillUseError,
+    },
     components::{
-        Bank, Clan, ClientEntity, ClientEntityType, Command, ConsumableCooldownGroup, Cooldowns,
-        PartyInfo, PlayerCharacter, Position,
+        Bank, ClientEntity, Command, CommandSit, ConsumableCooldownGroup, Cooldowns, PartyInfo,
+        PlayerCharacter, Position, Stunned,
+    },
+    events::{ChatboxEvent, PlayerCommandEvent, ZoneEvent},
+    resources::{
+        BufferedPlayerCommand, GameConnection, GameData, InterfaceSettings, PendingSitPoint,
+        PlayerCommandBuffer, SelectedTarget, SitPointTarget, SkillQueue, ZoneLoadTracker,
     },
-    events::{ChatboxEvent, PlayerCommandEvent},
-    resources::{GameConnection, GameData, SelectedTarget},
 };
 
 #[derive(WorldQuery)]
@@ -32,24 +40,16 @@ pub struct PlayerQuery<'w> {
     entity: Entity,
 
     bank: Option<&'w Bank>,
+    command: &'w Command,
     cooldowns: &'w mut Cooldowns,
     hotbar: &'w mut Hotbar,
     inventory: &'w Inventory,
     position: &'w Position,
     skill_list: &'w SkillList,
     team: &'w Team,
-    clan: Option<&'w Clan>,
     party_info: Option<&'w PartyInfo>,
-}
-
-#[derive(WorldQuery)]
-pub struct SkillTargetQuery<'w> {
-    entity: Entity,
-
-    character_info: Option<&'w CharacterInfo>,
-    client_entity: &'w ClientEntity,
-    command: &'w Command,
-    team: &'w Team,
+    stunned: Option<&'w Stunned>,
+    skill_caster: SkillCasterQuery<'w>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -64,6 +64,12 @@ pub fn player_command_system(
     game_connection: Option<Res<GameConnection>>,
     game_data: Res<GameData>,
     selected_target: Res<SelectedTarget>,
+    mut skill_queue: ResMut<SkillQueue>,
+    mut sit_point_target: ResMut<SitPointTarget>,
+    interface_settings: Res<InterfaceSettings>,
+    zone_load_tracker: Res<ZoneLoadTracker>,
+    mut player_command_buffer: ResMut<PlayerCommandBuffer>,
+    time: Res<Time>,
 ) {
     let query_player_result = query_player.get_single_mut();
     if query_player_result.is_err() {
@@ -71,10 +77,46 @@ pub fn player_command_system(
     }
     let mut player = query_player_result.unwrap();
 
-    for event in player_command_events.iter() {
-        let mut event = event.clone();
+    if player.stunned.is_some() {
+        // Cannot perform any actions while stunned
+        player_command_events.clear();
+        skill_queue.clear();
+        return;
+    }
+
+    if zone_load_tracker.is_loading() {
+        // Hold on to commands issued whilst the zone is loading, they are replayed once it has loaded
+        for event in player_command_events.iter() {
+            player_command_buffer.push(event.clone(), player.position.position);
+        }
+        return;
+    }
+
+    let mut queued_event = None;
+    if let Some(skill_slot) = skill_queue.skill_slot {
+        if time.elapsed() > skill_queue.expire_time {
+            skill_queue.clear();
+        } else if !matches!(player.command, Command::CastSkill(_))
+            && get_skill_cooldown_remaining(&player, &game_data, skill_slot).is_none()
+        {
+            queued_event = Some((
+                PlayerCommandEvent::UseSkill(skill_slot),
+                skill_queue.self_cast,
+            ));
+            skill_queue.clear();
+        }
+    }
+
+    for (mut event, mut self_cast) in queued_event.into_iter().chain(
+        player_command_events
+            .iter()
+            .map(|event| (event.clone(), false)),
+    ) {
+        if let PlayerCommandEvent::UseHotbar(page, index)
+        | PlayerCommandEvent::UseHotbarSelfCast(page, index) = event
+        {
+            self_cast = matches!(event, PlayerCommandEvent::UseHotbarSelfCast(..));
 
-        if let PlayerCommandEvent::UseHotbar(page, index) = event {
             if let Some(hotbar_slot) = player
                 .hotbar
                 .pages
@@ -103,17 +145,37 @@ pub fn player_command_system(
                     .get_skill(skill_slot)
                     .and_then(|skill_id| game_data.skills.get_skill(skill_id))
                 {
-                    let has_skill_cooldown = match &skill_data.cooldown {
-                        SkillCooldown::Skill { .. } => {
-                            player.cooldowns.has_skill_cooldown(skill_data.id)
-                        }
-                        SkillCooldown::Group { group, .. } => {
-                            player.cooldowns.has_skill_group_cooldown(group.get())
+                    if let Some(remaining) =
+                        get_skill_cooldown_remaining(&player, &game_data, skill_slot)
+                    {
+                        if remaining <= interface_settings.skill_queue_window {
+                            // Cooldown is almost finished, use the skill once it is ready
+                            skill_queue.queue(
+                                skill_slot,
+                                self_cast,
+                                time.elapsed() + remaining + interface_settings.skill_queue_window,
+                            );
+                        } else {
+                            chatbox_events.send(ChatboxEvent::System("Waiting...".to_string()));
                         }
-                    };
+                        continue;
+                    }
+
+                    if matches!(player.command, Command::CastSkill(_))
+                        && !interface_settings.skill_queue_window.is_zero()
+                    {
+                        // Still casting the previous skill, use the skill once it has finished
+                        skill_queue.queue(
+                            skill_slot,
+                            self_cast,
+                            time.elapsed() + interface_settings.skill_queue_window,
+                        );
+                        continue;
+                    }
 
-                    if has_skill_cooldown || player.cooldowns.has_global_cooldown() {
-                        chatbox_events.send(ChatboxEvent::System("Waiting...".to_string()));
+                    if let Err(error) = skill_can_use(&game_data, &player.skill_caster, skill_data)
+                    {
+                        chatbox_events.send(ChatboxEvent::System(error.get_message(&game_data)));
                         continue;
                     }
 
@@ -296,123 +358,56 @@ pub fn player_command_system(
                         | SkillType::EnforceBullet
                         | SkillType::FireBullet
                         | SkillType::AreaTarget => {
-                            let target_entity_id = {
-                                if let Ok(target) = query_skill_target
-                                    .get(selected_target.selected.unwrap_or(player.entity))
-                                {
-                                    let target_is_alive = !target.command.is_die();
-                                    let target_is_caster = target.entity == player.entity;
-                                    let target_is_valid = match skill_data.target_filter {
-                                        SkillTargetFilter::OnlySelf => {
-                                            target_is_alive && target_is_caster
-                                        }
-                                        SkillTargetFilter::Group => {
-                                            target_is_alive
-                                                && (target_is_caster
-                                                    || player.party_info.map_or(
-                                                        false,
-                                                        |party_info| {
-                                                            party_info.contains_member(
-                                                                target.client_entity.id,
-                                                            )
-                                                        },
-                                                    ))
-                                        }
-                                        SkillTargetFilter::Guild => {
-                                            target_is_alive
-                                                && (target_is_caster
-                                                    || target.character_info.map_or(
-                                                        false,
-                                                        |character_info| {
-                                                            player.clan.map_or(false, |clan| {
-                                                                clan.find_member(
-                                                                    &character_info.name,
-                                                                )
-                                                                .is_some()
-                                                            })
-                                                        },
-                                                    ))
-                                        }
-                                        SkillTargetFilter::Allied => {
-                                            target_is_alive && target.team.id == player.team.id
-                                        }
-                                        SkillTargetFilter::Monster => {
-                                            target_is_alive
-                                                && matches!(
-                                                    target.client_entity.entity_type,
-                                                    ClientEntityType::Monster
-                                                )
-                                        }
-                                        SkillTargetFilter::Enemy => {
-                                            target_is_alive
-                                                && target.team.id != Team::DEFAULT_NPC_TEAM_ID
-                                                && target.team.id != player.team.id
+                            let resolve_target = |target_entity| {
+                                query_skill_target
+                                    .get(target_entity)
+                                    .map_err(|_| SkillUseError::InvalidTarget)
+                                    .and_then(|target| {
+                                        match skill_can_target_entity(
+                                            &player.skill_caster,
+                                            &target,
+                                            skill_data,
+                                        ) {
+                                            // The server will move us into range before casting
+                                            Ok(_) | Err(SkillUseError::OutOfRange) => Ok(target),
+                                            Err(error) => Err(error),
                                         }
-                                        SkillTargetFilter::EnemyCharacter => {
-                                            target_is_alive
-                                                && target.team.id != player.team.id
-                                                && matches!(
-                                                    target.client_entity.entity_type,
-                                                    ClientEntityType::Character
-                                                )
-                                        }
-                                        SkillTargetFilter::Character => {
-                                            target_is_alive
-                                                && matches!(
-                                                    target.client_entity.entity_type,
-                                                    ClientEntityType::Character
-                                                )
-                                        }
-                                        SkillTargetFilter::CharacterOrMonster => {
-                                            target_is_alive
-                                                && matches!(
-                                                    target.client_entity.entity_type,
-                                                    ClientEntityType::Character
-                                                        | ClientEntityType::Monster
-                                                )
-                                        }
-                                        SkillTargetFilter::DeadAlliedCharacter => {
-                                            !target_is_alive
-                                                && target.team.id == player.team.id
-                                                && matches!(
-                                                    target.client_entity.entity_type,
-                                                    ClientEntityType::Character
-                                                )
-                                        }
-                                        SkillTargetFilter::EnemyMonster => {
-                                            target_is_alive
-                                                && target.team.id != player.team.id
-                                                && matches!(
-                                                    target.client_entity.entity_type,
-                                                    ClientEntityType::Monster
-                                                )
-                                        }
-                                    };
+                                    })
+                            };
+                            let is_beneficial = skill_is_beneficial(skill_data);
 
-                                    if target_is_valid {
-                                        Some(target.client_entity.id)
-                                    } else {
-                                        None
-                                    }
-                                } else {
-                                    None
-                                }
+                            let mut target = if self_cast && is_beneficial {
+                                resolve_target(player.entity)
+                            } else {
+                                resolve_target(selected_target.selected.unwrap_or(player.entity))
                             };
 
-                            if let Some(target_entity_id) = target_entity_id {
-                                if let Some(game_connection) = game_connection.as_ref() {
-                                    game_connection
-                                        .client_message_tx
-                                        .send(ClientMessage::CastSkillTargetEntity {
-                                            skill_slot,
-                                            target_entity_id,
-                                        })
-                                        .ok();
+                            if target.is_err()
+                                && is_beneficial
+                                && interface_settings.auto_self_cast
+                                && selected_target.selected.is_some()
+                            {
+                                // Fall back to casting on ourself
+                                target = resolve_target(player.entity);
+                            }
+
+                            match target {
+                                Ok(target) => {
+                                    if let Some(game_connection) = game_connection.as_ref() {
+                                        game_connection
+                                            .client_message_tx
+                                            .send(ClientMessage::CastSkillTargetEntity {
+                                                skill_slot,
+                                                target_entity_id: target.client_entity.id,
+                                            })
+                                            .ok();
+                                    }
+                                }
+                                Err(error) => {
+                                    chatbox_events
+                                        .send(ChatboxEvent::System(error.get_message(&game_data)));
+                                    continue;
                                 }
-                            } else {
-                                chatbox_events
-                                    .send(ChatboxEvent::System("Invalid target".to_string()));
-                                continue;
                             }
                         }
 
@@ -686,6 +681,8 @@ pub fn player_command_system(
                 }
             }
             PlayerCommandEvent::Attack(entity) => {
+                skill_queue.clear();
+
                 if let Ok((target_client_entity, target_team)) = query_team.get(entity) {
                     if target_team.id != Team::DEFAULT_NPC_TEAM_ID
                         && target_team.id != player.team.id
@@ -702,6 +699,22 @@ pub fn player_command_system(
                 }
             }
             PlayerCommandEvent::Move(position, target_entity) => {
+                skill_queue.clear();
+                sit_point_target.pending = None;
+
+                if let Some(game_connection) = game_connection.as_ref() {
+                    // Moving whilst sitting stands the character back up first
+                    if matches!(
+                        player.command,
+                        Command::Sit(CommandSit::Sitting | CommandSit::Sit)
+                    ) {
+                        game_connection
+                            .client_message_tx
+                            .send(ClientMessage::SitToggle)
+                            .ok();
+                    }
+                }
+
                 let target_entity_id = target_entity
                     .and_then(|target_entity| query_client_entity.get(target_entity).ok())
                     .map(|target_client_entity| target_client_entity.id);
@@ -718,6 +731,45 @@ pub fn player_command_system(
                         .ok();
                 }
             }
+            PlayerCommandEvent::MoveToSitPoint(position, facing) => {
+                skill_queue.clear();
+                sit_point_target.pending = Some(PendingSitPoint {
+                    position: position.position,
+                    facing,
+                    started: time.elapsed(),
+                    has_moved: false,
+                });
+
+                if let Some(game_connection) = game_connection.as_ref() {
+                    if matches!(
+                        player.command,
+                        Command::Sit(CommandSit::Sitting | CommandSit::Sit)
+                    ) {
+                        game_connection
+                            .client_message_tx
+                            .send(ClientMessage::SitToggle)
+                            .ok();
+                    }
+
+                    game_connection
+                        .client_message_tx
+                        .send(ClientMessage::Move {
+                            target_entity_id: None,
+                            x: position.x,
+                            y: position.y,
+                            z: position.z as u16,
+                        })
+                        .ok();
+                }
+            }
+            PlayerCommandEvent::Sit => {
+                if let Some(game_connection) = game_connection.as_ref() {
+                    game_connection
+                        .client_message_tx
+                        .send(ClientMessage::SitToggle)
+                        .ok();
+                }
+            }
             PlayerCommandEvent::SetHotbar(page, page_index, hotbar_slot) => {
                 if let Some(hotbar_page) = player.hotbar.pages.get_mut(page) {
                     if let Some(hotbar_page_slot) = hotbar_page.get_mut(page_index) {
@@ -773,3 +825,74 @@ pub fn player_command_system(
         }
     }
 }
+
+/// Replays the commands buffered whilst the zone was loading, movement targets are translated to
+/// the player's new position and commands targeting entities which no longer exist are dropped
+pub fn player_command_buffer_system(
+    mut zone_events: EventReader<ZoneEvent>,
+    mut player_command_buffer: ResMut<PlayerCommandBuffer>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    query_player_position: Query<&Position, With<PlayerCharacter>>,
+    query_client_entity: Query<&ClientEntity>,
+) {
+    if !zone_events
+        .iter()
+        .any(|event| matches!(event, ZoneEvent::Loaded(_)))
+    {
+        return;
+    }
+
+    let Ok(player_position) = query_player_position.get_single() else {
+        player_command_buffer.clear();
+        return;
+    };
+
+    for BufferedPlayerCommand {
+        event,
+        player_position: issued_position,
+    } in player_command_buffer.commands.drain(..)
+    {
+        let event = match event {
+            PlayerCommandEvent::Attack(target_entity) => {
+                if query_client_entity.get(target_entity).is_err() {
+                    continue;
+                }
+                PlayerCommandEvent::Attack(target_entity)
+            }
+            PlayerCommandEvent::Move(position, target_entity) => {
+                if target_entity.map_or(false, |target_entity| {
+                    query_client_entity.get(target_entity).is_err()
+                }) {
+                    continue;
+                }
+                PlayerCommandEvent::Move(
+                    Position::new(player_position.position + (position.position - issued_position)),
+                    target_entity,
+                )
+            }
+            // Sit points belong to the objects of the zone the command was issued in
+            PlayerCommandEvent::MoveToSitPoint(..) => continue,
+            event => event,
+        };
+
+        player_command_events.send(event);
+    }
+}
+
+fn get_skill_cooldown_remaining(
+    player: &PlayerQueryItem,
+    game_data: &GameData,
+    skill_slot: SkillSlot,
+) -> Option<Duration> {
+    let skill_data = player
+        .skill_list
+        .get_skill(skill_slot)
+        .and_then(|skill_id| game_data.skills.get_skill(skill_id))?;
+
+    match &skill_data.cooldown {
+        SkillCooldown::Skill { .. } => player.cooldowns.get_skill_cooldown_remaining(skill_data.id),
+        SkillCooldown::Group { group, .. } => {
+            player.cooldowns.get_skill_group_cooldown_remaining(*group)
+        }
+    }
+}
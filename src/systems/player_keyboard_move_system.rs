@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use crate::{
+    components::{FacingDirection, PlayerCharacter, Position, TargetCamera},
+    events::PlayerCommandEvent,
+    resources::{InputFocus, InterfaceSettings, KeyBindingAction, KeyBindings},
+};
+use bevy::{
+    input::Input,
+    math::{Vec2, Vec3, Vec3Swizzles},
+    prelude::{
+        Camera3d, EventWriter, GlobalTransform, KeyCode, Local, MouseButton, Query, Res, With,
+        Without,
+    },
+    time::Time,
+};
+
+const MOVE_DESTINATION_DISTANCE: f32 = 500.0;
+const MOVE_SEND_INTERVAL: Duration = Duration::from_millis(250);
+const TURN_ANGLE_PER_SECOND: f32 = std::f32::consts::PI;
+
+#[derive(Default)]
+pub struct PlayerKeyboardMoveState {
+    is_moving: bool,
+    last_direction: Vec3,
+    time_since_last_move: Duration,
+    turn_angle: f32,
+}
+
+pub fn player_keyboard_move_system(
+    mut state: Local<PlayerKeyboardMoveState>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    input_focus: Res<InputFocus>,
+    interface_settings: Res<InterfaceSettings>,
+    key_bindings: Res<KeyBindings>,
+    query_camera: Query<&GlobalTransform, (With<Camera3d>, Without<TargetCamera>)>,
+    mut query_player: Query<(&Position, &mut FacingDirection), With<PlayerCharacter>>,
+    time: Res<Time>,
+) {
+    if !interface_settings.is_wasd_movement_enabled() {
+        state.is_moving = false;
+        return;
+    }
+
+    let Ok((player_position, mut facing_direction)) = query_player.get_single_mut() else {
+        return;
+    };
+    let Ok(camera_transform) = query_camera.get_single() else {
+        return;
+    };
+
+    let mut forward = 0.0;
+    let mut turn = 0.0;
+
+    if !input_focus.keyboard_captured() {
+        if key_bindings.pressed(KeyBindingAction::MoveForward, &keyboard_input, &mouse_input) {
+            forward += 1.0;
+        }
+
+        if key_bindings.pressed(
+            KeyBindingAction::MoveBackward,
+            &keyboard_input,
+            &mouse_input,
+        ) {
+            forward -= 1.0;
+        }
+
+        if key_bindings.pressed(KeyBindingAction::MoveLeft, &keyboard_input, &mouse_input) {
+            turn += 1.0;
+        }
+
+        if key_bindings.pressed(KeyBindingAction::MoveRight, &keyboard_input, &mouse_input) {
+            turn -= 1.0;
+        }
+    }
+
+    // Left and right rotate the character rather than strafing
+    let turn_amount = turn * TURN_ANGLE_PER_SECOND * time.delta_seconds();
+
+    state.time_since_last_move += time.delta();
+
+    if forward == 0.0 {
+        state.turn_angle = 0.0;
+
+        if turn_amount != 0.0 {
+            facing_direction.desired =
+                (facing_direction.desired + turn_amount).rem_euclid(std::f32::consts::TAU);
+        }
+
+        if state.is_moving {
+            // Stop where we currently are
+            state.is_moving = false;
+            player_command_events.send(PlayerCommandEvent::Move(player_position.clone(), None));
+        }
+
+        return;
+    }
+
+    // Convert the camera facing into a direction on the ground in game coordinates, turning
+    // whilst moving steers relative to the camera
+    state.turn_angle += turn_amount;
+    let camera_forward = camera_transform.forward().xz().normalize_or_zero();
+    let camera_angle = if camera_forward == Vec2::ZERO {
+        facing_direction.actual - std::f32::consts::PI
+    } else {
+        (-camera_forward.y).atan2(camera_forward.x)
+    };
+    let angle = camera_angle + state.turn_angle;
+    let direction = Vec3::new(angle.cos(), angle.sin(), 0.0) * forward;
+
+    // Whilst turning the direction changes every frame, so it is also limited to the interval
+    if state.is_moving
+        && (turn != 0.0 || direction.distance(state.last_direction) < 0.01)
+        && state.time_since_last_move < MOVE_SEND_INTERVAL
+    {
+        return;
+    }
+
+    state.is_moving = true;
+    state.last_direction = direction;
+    state.time_since_last_move = Duration::ZERO;
+
+    let mut destination = player_position.position + direction * MOVE_DESTINATION_DISTANCE;
+    destination.z = player_position.position.z;
+    player_command_events.send(PlayerCommandEvent::Move(Position::new(destination), None));
+}
@@ -1,23 +1,71 @@
 use bevy::{
     hierarchy::DespawnRecursiveExt,
     math::{Quat, Vec3},
-    prelude::{Commands, Entity, EventWriter, GlobalTransform, Query, Res, Time, Transform},
+    prelude::{
+        Assets, Commands, Entity, EventWriter, GlobalTransform, Query, Res, Time, Transform, With,
+    },
     render::mesh::skinning::SkinnedMesh,
 };
+use bevy_rapier3d::prelude::{CollisionGroups, QueryFilter, RapierContext};
 
-use rose_data::EffectBulletMoveType;
+use rose_data::{EffectBulletMoveType, EffectFileId};
 
 use crate::{
-    components::{DummyBoneOffset, Projectile, ProjectileParabola, ProjectileTarget},
-    events::HitEvent,
+    components::{
+        DummyBoneOffset, PendingDamageList, PlayerCharacter, Projectile, ProjectileParabola,
+        ProjectileTarget, SoundCategory, COLLISION_FILTER_COLLIDABLE, COLLISION_FILTER_INSPECTABLE,
+        COLLISION_GROUP_ZONE_OBJECT, COLLISION_GROUP_ZONE_TERRAIN, COLLISION_GROUP_ZONE_WATER,
+    },
+    events::{HitEvent, SoundEffectEvent, SpawnEffectData, SpawnEffectEvent},
+    resources::{CurrentZone, GameData, RenderConfiguration},
+    systems::animation_sound_system::get_surface_sound,
+    zone_loader::ZoneLoaderAsset,
 };
 
+// How far past the target a projectile flies when the attack missed
+const MISS_FLY_PAST_DISTANCE: f32 = 3.0;
+
+fn projectile_hit_event(projectile: &Projectile, target_entity: Entity) -> HitEvent {
+    if let Some(skill_id) = projectile.skill_id {
+        HitEvent::with_skill_damage(projectile.source, target_entity, skill_id)
+            .apply_damage(projectile.apply_damage)
+    } else {
+        HitEvent::with_weapon(projectile.source, target_entity, projectile.effect_id)
+            .apply_damage(projectile.apply_damage)
+    }
+}
+
+fn projectile_hit_effect_file_id(
+    projectile: &Projectile,
+    game_data: &GameData,
+) -> Option<EffectFileId> {
+    projectile
+        .skill_id
+        .and_then(|id| game_data.skills.get_skill(id))
+        .and_then(|skill_data| skill_data.hit_effect_file_id)
+        .or_else(|| {
+            projectile
+                .effect_id
+                .and_then(|id| game_data.effect_database.get_effect(id))
+                .and_then(|effect_data| effect_data.hit_effect_normal)
+        })
+}
+
 pub fn projectile_system(
     mut commands: Commands,
     mut hit_events: EventWriter<HitEvent>,
+    mut spawn_effect_events: EventWriter<SpawnEffectEvent>,
+    mut sound_effect_events: EventWriter<SoundEffectEvent>,
     mut query_bullets: Query<(Entity, &mut Projectile, &Transform)>,
     query_global_transform: Query<&GlobalTransform>,
     query_skeleton: Query<(&SkinnedMesh, &DummyBoneOffset)>,
+    query_pending_damage: Query<&PendingDamageList>,
+    query_player: Query<(), With<PlayerCharacter>>,
+    rapier_context: Res<RapierContext>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    game_data: Res<GameData>,
+    render_configuration: Res<RenderConfiguration>,
     time: Res<Time>,
 ) {
     for (entity, mut projectile, transform) in query_bullets.iter_mut() {
@@ -92,26 +140,161 @@ pub fn projectile_system(
             EffectBulletMoveType::Immediate => (true, Vec3::default()),
         };
 
-        if complete {
-            // Reached target, send hit event
-            if let ProjectileTarget::Entity {
-                entity: target_entity,
-            } = projectile.target
-            {
-                if let Some(skill_id) = projectile.skill_id {
-                    hit_events.send(
-                        HitEvent::with_skill_damage(projectile.source, target_entity, skill_id)
-                            .apply_damage(projectile.apply_damage),
-                    );
+        // Stop projectiles which hit zone geometry on the way to their target, the hit is still
+        // sent to the target so damage follows the server
+        let move_distance = move_vec.length();
+        if !complete && render_configuration.projectile_collision && move_distance > 0.0 {
+            let move_direction = move_vec / move_distance;
+
+            let geometry_hit = rapier_context
+                .cast_ray(
+                    transform.translation,
+                    move_direction,
+                    move_distance,
+                    false,
+                    QueryFilter::new().groups(CollisionGroups::new(
+                        COLLISION_FILTER_COLLIDABLE,
+                        COLLISION_GROUP_ZONE_OBJECT | COLLISION_GROUP_ZONE_TERRAIN,
+                    )),
+                )
+                .map(|(_, toi)| (toi, false));
+            let water_hit = rapier_context
+                .cast_ray(
+                    transform.translation,
+                    move_direction,
+                    move_distance,
+                    false,
+                    QueryFilter::new().groups(CollisionGroups::new(
+                        COLLISION_FILTER_INSPECTABLE,
+                        COLLISION_GROUP_ZONE_WATER,
+                    )),
+                )
+                .map(|(_, toi)| (toi, true));
+            let hit = match (geometry_hit, water_hit) {
+                (Some(geometry_hit), Some(water_hit)) => Some(if water_hit.0 < geometry_hit.0 {
+                    water_hit
+                } else {
+                    geometry_hit
+                }),
+                (geometry_hit, water_hit) => geometry_hit.or(water_hit),
+            };
+
+            if let Some((toi, is_water)) = hit {
+                let impact_translation = transform.translation + move_direction * toi;
+
+                // Water has no surface of its own, so use the tile beneath it
+                let surface_translation = if is_water {
+                    Vec3::new(impact_translation.x, f32::MIN, impact_translation.z)
                 } else {
+                    impact_translation
+                };
+                if let Some(sound_data) = get_surface_sound(
+                    &game_data,
+                    current_zone.as_deref(),
+                    &zone_loader_assets,
+                    surface_translation,
+                ) {
+                    let sound_category = if query_player.contains(projectile.source) {
+                        SoundCategory::PlayerCombat
+                    } else {
+                        SoundCategory::OtherCombat
+                    };
+                    sound_effect_events.send(SoundEffectEvent::new(
+                        sound_data.id,
+                        sound_category,
+                        impact_translation,
+                    ));
+                }
+
+                if let Some(hit_effect_file_id) =
+                    projectile_hit_effect_file_id(&projectile, &game_data)
+                {
+                    let mut spawn_effect_data = SpawnEffectData::with_file_id(hit_effect_file_id);
+                    if let Some(skill_id) = projectile.skill_id {
+                        spawn_effect_data =
+                            spawn_effect_data.with_skill_source(projectile.source, skill_id);
+                    }
+
+                    spawn_effect_events.send(SpawnEffectEvent::WithTransform(
+                        Transform::from_translation(impact_translation),
+                        spawn_effect_data,
+                    ));
+                }
+
+                if let ProjectileTarget::Entity {
+                    entity: target_entity,
+                } = projectile.target
+                {
                     hit_events.send(
-                        HitEvent::with_weapon(
+                        projectile_hit_event(&projectile, target_entity).spawn_hit_effects(false),
+                    );
+                }
+
+                commands.entity(entity).despawn_recursive();
+                continue;
+            }
+        }
+
+        if complete {
+            match projectile.target {
+                ProjectileTarget::Entity {
+                    entity: target_entity,
+                } if projectile.apply_damage
+                    && !matches!(projectile.move_type, EffectBulletMoveType::Immediate)
+                    && query_pending_damage.get(target_entity).map_or(
+                        false,
+                        |pending_damage_list| {
+                            pending_damage_list.is_miss(projectile.source, projectile.skill_id)
+                        },
+                    ) =>
+                {
+                    // Attack missed, send the hit event now to show the miss and let the
+                    // projectile continue flying past the target
+                    if let Some(skill_id) = projectile.skill_id {
+                        hit_events.send(HitEvent::with_skill_damage(
+                            projectile.source,
+                            target_entity,
+                            skill_id,
+                        ));
+                    } else {
+                        hit_events.send(HitEvent::with_weapon(
                             projectile.source,
                             target_entity,
                             projectile.effect_id,
-                        )
-                        .apply_damage(projectile.apply_damage),
-                    );
+                        ));
+                    }
+
+                    let mut direction =
+                        (target_translation - transform.translation).normalize_or_zero();
+                    direction.y = 0.0;
+                    projectile.target = ProjectileTarget::Position {
+                        position: target_translation + direction * MISS_FLY_PAST_DISTANCE
+                            - Vec3::new(0.0, 0.5, 0.0),
+                    };
+                    projectile.move_type = EffectBulletMoveType::Linear;
+                    projectile.parabola = None;
+                    projectile.is_miss = true;
+                    continue;
+                }
+                ProjectileTarget::Entity {
+                    entity: target_entity,
+                } => {
+                    // Reached target, send hit event
+                    hit_events.send(projectile_hit_event(&projectile, target_entity));
+                }
+                ProjectileTarget::Position { .. } if projectile.is_miss => {}
+                ProjectileTarget::Position { position } => {
+                    // Reached target point, spawn the landing effect
+                    if let Some(hit_effect_file_id) = projectile
+                        .effect_id
+                        .and_then(|id| game_data.effect_database.get_effect(id))
+                        .and_then(|effect_data| effect_data.hit_effect_normal)
+                    {
+                        spawn_effect_events.send(SpawnEffectEvent::WithTransform(
+                            Transform::from_translation(position),
+                            SpawnEffectData::with_file_id(hit_effect_file_id),
+                        ));
+                    }
                 }
             }
 
@@ -0,0 +1,76 @@
+use bevy::prelude::{Commands, Entity, GlobalTransform, Query, Res, With};
+
+use rose_game_common::{components::HealthPoints, messages::client::ClientMessage};
+
+use crate::{
+    components::{Campfire, Command, PlayerCharacter, Resting},
+    resources::GameConnection,
+};
+
+const CAMPFIRE_REST_DISTANCE: f32 = 8.0;
+
+pub fn rest_system(
+    mut commands: Commands,
+    mut query_player: Query<
+        (
+            Entity,
+            &Command,
+            &HealthPoints,
+            &GlobalTransform,
+            Option<&mut Resting>,
+        ),
+        With<PlayerCharacter>,
+    >,
+    query_campfires: Query<&GlobalTransform, With<Campfire>>,
+    game_connection: Option<Res<GameConnection>>,
+) {
+    let Ok((player_entity, command, health_points, player_transform, resting)) =
+        query_player.get_single_mut()
+    else {
+        return;
+    };
+
+    if !command.is_sit() {
+        // Moving or otherwise standing up ends the rest
+        if resting.is_some() {
+            commands.entity(player_entity).remove::<Resting>();
+        }
+        return;
+    }
+
+    // Only used for the status icon, recovery is entirely server side
+    let near_campfire = query_campfires.iter().any(|campfire_transform| {
+        campfire_transform
+            .translation()
+            .distance(player_transform.translation())
+            < CAMPFIRE_REST_DISTANCE
+    });
+
+    let Some(mut resting) = resting else {
+        commands.entity(player_entity).insert(Resting {
+            near_campfire,
+            last_health_points: health_points.hp,
+        });
+        return;
+    };
+
+    if health_points.hp < resting.last_health_points {
+        // Taking damage interrupts the rest
+        if let Some(game_connection) = game_connection.as_ref() {
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::SitToggle)
+                .ok();
+        }
+        commands.entity(player_entity).remove::<Resting>();
+        return;
+    }
+
+    if resting.near_campfire != near_campfire {
+        resting.near_campfire = near_campfire;
+    }
+
+    if resting.last_health_points != health_points.hp {
+        resting.last_health_points = health_points.hp;
+    }
+}
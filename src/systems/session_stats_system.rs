@@ -0,0 +1,79 @@
+use bevy::prelude::{Entity, EventReader, Local, Query, Res, ResMut, Time, With};
+
+use rose_game_common::components::Inventory;
+
+use crate::{
+    components::PlayerCharacter,
+    events::{ChatboxEvent, ClientEntityEvent, ItemAcquiredEvent, ItemAcquiredSource},
+    resources::{GameData, SessionStats},
+};
+
+/// Updates the session statistics from the system chat messages sent for experience and kills,
+/// acquired items, player deaths, and changes to the player's money.
+pub fn session_stats_system(
+    mut last_player_entity: Local<Option<Entity>>,
+    mut session_stats: ResMut<SessionStats>,
+    mut chatbox_events: EventReader<ChatboxEvent>,
+    mut client_entity_events: EventReader<ClientEntityEvent>,
+    mut item_acquired_events: EventReader<ItemAcquiredEvent>,
+    query_player: Query<(Entity, &Inventory), With<PlayerCharacter>>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    let Ok((player_entity, inventory)) = query_player.get_single() else {
+        chatbox_events.clear();
+        client_entity_events.clear();
+        item_acquired_events.clear();
+        return;
+    };
+
+    if *last_player_entity != Some(player_entity) {
+        *last_player_entity = Some(player_entity);
+        session_stats.last_money = None;
+        session_stats.reset(time.elapsed());
+    }
+
+    session_stats.update_money(inventory.money.0);
+
+    for event in client_entity_events.iter() {
+        if let ClientEntityEvent::Die(entity) = *event {
+            if entity == player_entity {
+                session_stats.deaths += 1;
+            }
+        }
+    }
+
+    for event in item_acquired_events.iter() {
+        if matches!(event.source, ItemAcquiredSource::PartyMember(_)) {
+            continue;
+        }
+
+        if let Some(item_data) = game_data
+            .items
+            .get_base_item(event.item.get_item_reference())
+        {
+            *session_stats
+                .items_looted
+                .entry(item_data.name.to_string())
+                .or_default() += 1;
+        }
+    }
+
+    for event in chatbox_events.iter() {
+        let ChatboxEvent::System(text) = event else {
+            continue;
+        };
+
+        if let Some(name) = text.strip_prefix("You have succeeded in hunting ") {
+            *session_stats
+                .monsters_killed
+                .entry(name.to_string())
+                .or_default() += 1;
+        } else if let Some(xp) = text
+            .strip_prefix("You have earned ")
+            .and_then(|reward| reward.strip_suffix(" experience points."))
+        {
+            session_stats.xp_gained += xp.parse::<u64>().unwrap_or(0);
+        }
+    }
+}
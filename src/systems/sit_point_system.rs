@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use bevy::{
+    math::Vec3Swizzles,
+    prelude::{EventWriter, Query, Res, ResMut, With},
+    time::Time,
+};
+
+use crate::{
+    components::{Command, FacingDirection, PlayerCharacter, Position},
+    events::PlayerCommandEvent,
+    resources::SitPointTarget,
+};
+
+const SIT_POINT_REACHED_DISTANCE: f32 = 50.0;
+const SIT_POINT_START_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub fn sit_point_system(
+    mut sit_point_target: ResMut<SitPointTarget>,
+    mut query_player: Query<(&Command, &Position, &mut FacingDirection), With<PlayerCharacter>>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    time: Res<Time>,
+) {
+    let Some(pending) = sit_point_target.pending.as_mut() else {
+        return;
+    };
+
+    let Ok((command, position, mut facing_direction)) = query_player.get_single_mut() else {
+        sit_point_target.pending = None;
+        return;
+    };
+
+    match command {
+        Command::Move(_) => {
+            pending.has_moved = true;
+        }
+        Command::Stop => {
+            if position.position.xy().distance(pending.position.xy()) < SIT_POINT_REACHED_DISTANCE {
+                facing_direction.set_desired_vector(pending.facing.extend(0.0));
+                player_command_events.send(PlayerCommandEvent::Sit);
+                sit_point_target.pending = None;
+            } else if pending.has_moved
+                || time.elapsed().saturating_sub(pending.started) > SIT_POINT_START_TIMEOUT
+            {
+                // We stopped somewhere else, or the server never started moving us
+                sit_point_target.pending = None;
+            }
+        }
+        Command::Sit(_) if !pending.has_moved => {
+            // Still standing up from where we were previously sitting
+            if time.elapsed().saturating_sub(pending.started) > SIT_POINT_START_TIMEOUT {
+                sit_point_target.pending = None;
+            }
+        }
+        _ => {
+            sit_point_target.pending = None;
+        }
+    }
+}
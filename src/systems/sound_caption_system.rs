@@ -0,0 +1,69 @@
+use bevy::prelude::{Added, EventReader, EventWriter, Local, Query, Res, With};
+
+use rose_game_common::components::{AbilityValues, HealthPoints, Npc};
+
+use crate::{
+    components::{MonsterAggro, PlayerCharacter},
+    events::{ChatboxEvent, ToastCategory, ToastEvent},
+    resources::{AccessibilitySettings, GameData, InterfaceSettings},
+};
+
+/// Health below this fraction of maximum health triggers the low health caption
+const LOW_HEALTH_FRACTION: f32 = 0.25;
+
+/// Shows important audio cues as toast captions for players who play muted or are hard of
+/// hearing.
+pub fn sound_caption_system(
+    mut was_low_health: Local<bool>,
+    mut chatbox_events: EventReader<ChatboxEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
+    query_player: Query<(&HealthPoints, &AbilityValues), With<PlayerCharacter>>,
+    query_aggro: Query<&Npc, Added<MonsterAggro>>,
+    accessibility_settings: Res<AccessibilitySettings>,
+    interface_settings: Res<InterfaceSettings>,
+    game_data: Res<GameData>,
+) {
+    if !accessibility_settings.sound_captions {
+        chatbox_events.clear();
+        return;
+    }
+
+    for npc in query_aggro.iter() {
+        let name = game_data
+            .npcs
+            .get_npc(npc.id)
+            .map_or("A monster", |npc_data| npc_data.name);
+        toast_events.send(ToastEvent::new(
+            ToastCategory::SoundCaption,
+            format!("[Growl] {} is attacking you", name),
+        ));
+    }
+
+    for event in chatbox_events.iter() {
+        if let ChatboxEvent::Whisper(name, _) = event {
+            toast_events.send(ToastEvent::new(
+                ToastCategory::SoundCaption,
+                format!(
+                    "[Chime] Whisper from {}",
+                    interface_settings.other_character_name(name)
+                ),
+            ));
+        }
+    }
+
+    if let Ok((health_points, ability_values)) = query_player.get_single() {
+        let max_health = ability_values.get_max_health().max(1);
+        let is_low_health = health_points.hp > 0
+            && (health_points.hp as f32 / max_health as f32) < LOW_HEALTH_FRACTION;
+
+        if is_low_health && !*was_low_health {
+            toast_events.send(ToastEvent::new(
+                ToastCategory::SoundCaption,
+                "[Heartbeat] Low health",
+            ));
+        }
+        *was_low_health = is_low_health;
+    } else {
+        *was_low_health = false;
+    }
+}
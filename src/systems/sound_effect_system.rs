@@ -0,0 +1,69 @@
+use bevy::{
+    math::Vec3,
+    prelude::{
+        AssetServer, Camera3d, Commands, EventReader, GlobalTransform, Query, Res, Transform, With,
+        Without,
+    },
+};
+
+use crate::{
+    audio::SpatialSound,
+    components::{PlayerCharacter, TargetCamera},
+    events::SoundEffectEvent,
+    resources::{GameData, SoundCache, SoundSettings},
+};
+
+/// Sounds further than this from the listener would be inaudible, so are not spawned at all
+const SOUND_EFFECT_MAX_DISTANCE: f32 = 80.0;
+
+/// Identical sounds closer together than this in the same frame are only played once, such as
+/// the hit sounds of a skill which hits many targets at once
+const SOUND_EFFECT_MERGE_DISTANCE: f32 = 2.0;
+
+/// Spawns a spatial sound entity for each sound effect event, using the same listener position as
+/// the spatial sound system.
+pub fn sound_effect_system(
+    mut commands: Commands,
+    mut sound_effect_events: EventReader<SoundEffectEvent>,
+    query_camera: Query<&GlobalTransform, (With<Camera3d>, Without<TargetCamera>)>,
+    query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
+    asset_server: Res<AssetServer>,
+    game_data: Res<GameData>,
+    sound_cache: Res<SoundCache>,
+    sound_settings: Res<SoundSettings>,
+) {
+    let listener_position = query_player
+        .get_single()
+        .or_else(|_| query_camera.get_single())
+        .ok()
+        .map(|transform| transform.translation());
+    let mut played: Vec<(usize, Vec3)> = Vec::new();
+
+    for event in sound_effect_events.iter() {
+        if listener_position.map_or(false, |listener_position| {
+            listener_position.distance(event.position) > SOUND_EFFECT_MAX_DISTANCE
+        }) {
+            continue;
+        }
+
+        let sound_index = event.sound_id.get() as usize;
+        if played.iter().any(|(index, position)| {
+            *index == sound_index && position.distance(event.position) < SOUND_EFFECT_MERGE_DISTANCE
+        }) {
+            continue;
+        }
+
+        let Some(sound_data) = game_data.sounds.get_sound(event.sound_id) else {
+            continue;
+        };
+        played.push((sound_index, event.position));
+
+        commands.spawn((
+            event.category,
+            sound_settings.gain(event.category),
+            SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
+            Transform::from_translation(event.position),
+            GlobalTransform::from_translation(event.position),
+        ));
+    }
+}
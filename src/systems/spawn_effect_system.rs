@@ -1,18 +1,19 @@
 use bevy::{
     hierarchy::BuildChildren,
     prelude::{
-        AssetServer, Assets, Commands, EventReader, GlobalTransform, Query, Res, ResMut, Transform,
+        AssetServer, Assets, Commands, Entity, EventReader, GlobalTransform, Query, Res, ResMut,
+        Transform, With,
     },
     render::mesh::skinning::SkinnedMesh,
 };
 use rose_file_readers::VfsPath;
 
 use crate::{
-    components::DummyBoneOffset,
+    components::{ClientEntity, ClientEntityType, DummyBoneOffset, Effect, PlayerCharacter},
     effect_loader::spawn_effect,
     events::{SpawnEffect, SpawnEffectData, SpawnEffectEvent},
     render::{EffectMeshMaterial, ParticleMaterial},
-    resources::GameData,
+    resources::{EffectSettings, GameData, SCREEN_COVERING_SKILL_SCOPE},
     VfsResource,
 };
 
@@ -29,18 +30,81 @@ fn get_effect_file_path<'a>(
     }
 }
 
+fn is_effect_hidden(
+    spawn_effect_data: &SpawnEffectData,
+    effect_settings: &EffectSettings,
+    player_entity: Option<Entity>,
+    query_client_entity: &Query<&ClientEntity>,
+    game_data: &GameData,
+) -> bool {
+    let Some((source_entity, skill_id)) = spawn_effect_data.skill_source else {
+        return false;
+    };
+
+    if effect_settings.hide_other_player_skill_effects
+        && Some(source_entity) != player_entity
+        && query_client_entity
+            .get(source_entity)
+            .map_or(false, |client_entity| {
+                matches!(client_entity.entity_type, ClientEntityType::Character)
+            })
+    {
+        return true;
+    }
+
+    effect_settings.hide_screen_covering_effects
+        && game_data
+            .skills
+            .get_skill(skill_id)
+            .map_or(false, |skill_data| {
+                skill_data.scope as f32 >= SCREEN_COVERING_SKILL_SCOPE
+            })
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_effect_system(
     mut commands: Commands,
     mut events: EventReader<SpawnEffectEvent>,
     query_transform: Query<&GlobalTransform>,
     query_skeleton: Query<(&SkinnedMesh, &DummyBoneOffset)>,
+    query_client_entity: Query<&ClientEntity>,
+    query_player: Query<Entity, With<PlayerCharacter>>,
+    query_effects: Query<&Effect>,
+    effect_settings: Res<EffectSettings>,
     game_data: Res<GameData>,
     asset_server: Res<AssetServer>,
     vfs_resource: Res<VfsResource>,
     mut effect_mesh_materials: ResMut<Assets<EffectMeshMaterial>>,
     mut particle_materials: ResMut<Assets<ParticleMaterial>>,
 ) {
+    let player_entity = query_player.get_single().ok();
+    let mut num_effects = query_effects
+        .iter()
+        .filter(|effect| !effect.manual_despawn)
+        .count();
+
     for event in events.iter() {
+        let spawn_effect_data = event.spawn_effect_data();
+        if is_effect_hidden(
+            spawn_effect_data,
+            &effect_settings,
+            player_entity,
+            &query_client_entity,
+            &game_data,
+        ) {
+            continue;
+        }
+
+        if !spawn_effect_data.manual_despawn {
+            if effect_settings
+                .max_effects
+                .map_or(false, |max_effects| num_effects >= max_effects)
+            {
+                continue;
+            }
+            num_effects += 1;
+        }
+
         match event {
             SpawnEffectEvent::InEntity(effect_entity, spawn_effect_data) => {
                 if let Some(effect_file_path) = get_effect_file_path(spawn_effect_data, &game_data)
@@ -62,6 +62,7 @@ pub fn spawn_projectile_system(
                     move_type: event.move_type,
                     move_speed: event.move_speed,
                     apply_damage: event.apply_damage,
+                    is_miss: false,
                     parabola: None,
                     target: event.target,
                 },
@@ -77,10 +78,15 @@ pub fn spawn_projectile_system(
             .get_effect(event.effect_id)
             .and_then(|x| x.bullet_effect)
         {
+            let mut spawn_effect_data = SpawnEffectData::with_file_id(projectile_effect_file_id);
+            if let Some(skill_id) = event.source_skill_id {
+                spawn_effect_data = spawn_effect_data.with_skill_source(event.source, skill_id);
+            }
+
             spawn_effect_events.send(SpawnEffectEvent::OnEntity(
                 projectile_entity,
                 None,
-                SpawnEffectData::with_file_id(projectile_effect_file_id),
+                spawn_effect_data,
             ));
         }
     }
@@ -0,0 +1,83 @@
+use bevy::prelude::{Children, Color, Entity, Local, Query, With};
+
+use crate::{
+    components::{ClientEntity, NameTagEntity, NameTagName, PlayerCharacter},
+    render::WorldUiRect,
+    systems::OrbitCamera,
+};
+
+const SPECTATE_NAME_TAG_COLOR: Color = Color::CYAN;
+
+#[derive(Default)]
+pub struct SpectateCameraState {
+    highlighted_entity: Option<Entity>,
+    original_color: Color,
+}
+
+/// When the orbit camera is following an entity other than the player, highlight its name tag
+/// and return the camera to the player once the entity has despawned.
+pub fn spectate_camera_system(
+    mut state: Local<SpectateCameraState>,
+    mut query_camera: Query<&mut OrbitCamera>,
+    query_player: Query<Entity, With<PlayerCharacter>>,
+    query_client_entity: Query<&ClientEntity>,
+    query_name_tag_entity: Query<&NameTagEntity>,
+    query_children: Query<&Children>,
+    mut query_name_rects: Query<&mut WorldUiRect, With<NameTagName>>,
+) {
+    let Ok(player_entity) = query_player.get_single() else {
+        return;
+    };
+
+    let mut spectate_entity = None;
+    for mut orbit_camera in query_camera.iter_mut() {
+        if orbit_camera.follow_entity == player_entity {
+            continue;
+        }
+
+        if query_client_entity.get(orbit_camera.follow_entity).is_err() {
+            orbit_camera.follow_entity = player_entity;
+            continue;
+        }
+
+        spectate_entity = Some(orbit_camera.follow_entity);
+    }
+
+    let mut set_name_tag_color = |entity: Entity, color: Option<Color>| -> Option<Color> {
+        let children = query_name_tag_entity
+            .get(entity)
+            .ok()
+            .and_then(|name_tag_entity| query_children.get(name_tag_entity.0).ok())?;
+
+        let mut previous_color = None;
+        for &child_entity in children.iter() {
+            if let Ok(mut rect) = query_name_rects.get_mut(child_entity) {
+                previous_color = Some(rect.color);
+                if let Some(color) = color {
+                    rect.color = color;
+                }
+            }
+        }
+        previous_color
+    };
+
+    if state.highlighted_entity != spectate_entity {
+        if let Some(highlighted_entity) = state.highlighted_entity.take() {
+            set_name_tag_color(highlighted_entity, Some(state.original_color));
+        }
+    }
+
+    if let Some(spectate_entity) = spectate_entity {
+        if state.highlighted_entity.is_none() {
+            // Wait until the name tag has been spawned before we start highlighting
+            if let Some(original_color) = set_name_tag_color(spectate_entity, None) {
+                state.highlighted_entity = Some(spectate_entity);
+                state.original_color = original_color;
+            }
+        }
+
+        if state.highlighted_entity.is_some() {
+            set_name_tag_color(spectate_entity, Some(SPECTATE_NAME_TAG_COLOR));
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use bevy::{
+    hierarchy::{BuildChildren, DespawnRecursiveExt},
+    math::Vec3,
+    prelude::{
+        Changed, Commands, ComputedVisibility, Entity, EventWriter, GlobalTransform, Query,
+        Transform, Visibility,
+    },
+};
+
+use rose_data::StatusEffectType;
+use rose_file_readers::VfsPathBuf;
+use rose_game_common::components::StatusEffects;
+
+use crate::{
+    components::{Dead, ModelHeight, NextCommand, Stunned},
+    events::{SpawnEffectData, SpawnEffectEvent},
+};
+
+const STUN_EFFECT_PATH: &str = "3DDATA/EFFECT/STUN_01.EFT";
+
+// Used until the model has been loaded and its height is known
+const DEFAULT_MODEL_HEIGHT: f32 = 1.8;
+
+pub fn stun_system(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &StatusEffects,
+            &mut NextCommand,
+            Option<&Stunned>,
+            Option<&ModelHeight>,
+            Option<&Dead>,
+        ),
+        Changed<StatusEffects>,
+    >,
+    mut spawn_effect_events: EventWriter<SpawnEffectEvent>,
+) {
+    for (entity, status_effects, mut next_command, stunned, model_height, dead) in query.iter_mut()
+    {
+        let is_stunned = status_effects.active[StatusEffectType::Fainting].is_some()
+            || status_effects.active[StatusEffectType::Sleep].is_some();
+
+        if is_stunned && stunned.is_none() {
+            let height =
+                model_height.map_or(DEFAULT_MODEL_HEIGHT, |model_height| model_height.height);
+            let effect_entity = commands
+                .spawn((
+                    Transform::from_translation(Vec3::new(0.0, height, 0.0)),
+                    GlobalTransform::default(),
+                    Visibility::default(),
+                    ComputedVisibility::default(),
+                ))
+                .id();
+            spawn_effect_events.send(SpawnEffectEvent::InEntity(
+                effect_entity,
+                SpawnEffectData::with_path(VfsPathBuf::new(STUN_EFFECT_PATH)).manual_despawn(true),
+            ));
+
+            commands
+                .entity(entity)
+                .add_child(effect_entity)
+                .insert(Stunned { effect_entity });
+
+            if dead.is_none() && !next_command.is_die() {
+                // Stagger as the stun begins
+                *next_command = NextCommand::with_hit_stun(None);
+            }
+        } else if !is_stunned {
+            if let Some(stunned) = stunned {
+                commands.entity(stunned.effect_entity).despawn_recursive();
+                commands.entity(entity).remove::<Stunned>();
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use bevy::{
+    math::Vec3,
+    prelude::{
+        Assets, Camera, Camera3d, Camera3dBundle, Commands, DespawnRecursiveExt, Entity,
+        GlobalTransform, Image, Query, Res, ResMut, Transform, With, Without,
+    },
+    render::{
+        camera::RenderTarget,
+        render_resource::{
+            Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+        },
+    },
+};
+
+use crate::{
+    components::{ModelHeight, TargetCamera},
+    resources::{HudSettings, InterfaceSettings, SelectedTarget},
+};
+
+pub const TARGET_CAMERA_IMAGE_SIZE: u32 = 256;
+
+const TARGET_CAMERA_DEFAULT_HEIGHT: f32 = 1.8;
+const TARGET_CAMERA_MIN_DISTANCE: f32 = 2.5;
+
+fn create_target_camera_image() -> Image {
+    let size = Extent3d {
+        width: TARGET_CAMERA_IMAGE_SIZE,
+        height: TARGET_CAMERA_IMAGE_SIZE,
+        depth_or_array_layers: 1,
+    };
+
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: Some("target_camera_image"),
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Bgra8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..Default::default()
+    };
+    image.resize(size);
+    image
+}
+
+pub fn target_camera_system(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut query_target_camera: Query<(Entity, &mut Transform), With<TargetCamera>>,
+    query_camera: Query<&GlobalTransform, (With<Camera3d>, Without<TargetCamera>)>,
+    query_target: Query<(&GlobalTransform, Option<&ModelHeight>)>,
+    selected_target: Res<SelectedTarget>,
+    interface_settings: Res<InterfaceSettings>,
+    hud_settings: Res<HudSettings>,
+) {
+    let target = if interface_settings.target_camera && !hud_settings.hidden {
+        selected_target
+            .selected
+            .and_then(|entity| query_target.get(entity).ok())
+    } else {
+        None
+    };
+
+    let (Some((target_transform, model_height)), Ok(camera_transform)) =
+        (target, query_camera.get_single())
+    else {
+        // Only keep the camera around whilst there is something to look at, to avoid the cost
+        // of rendering the scene a second time
+        for (entity, _) in query_target_camera.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    // Frame the target from the same side as the main camera, so we see what the player sees
+    let height = model_height.map_or(TARGET_CAMERA_DEFAULT_HEIGHT, |model_height| {
+        model_height.height
+    });
+    let look_at = target_transform.translation() + Vec3::new(0.0, height * 0.6, 0.0);
+    let mut direction = camera_transform.translation() - target_transform.translation();
+    direction.y = 0.0;
+    let direction = direction.try_normalize().unwrap_or(Vec3::Z);
+    let distance = (height * 1.5).max(TARGET_CAMERA_MIN_DISTANCE);
+    let transform = Transform::from_translation(
+        look_at + direction * distance + Vec3::new(0.0, height * 0.25, 0.0),
+    )
+    .looking_at(look_at, Vec3::Y);
+
+    if let Ok((_, mut target_camera_transform)) = query_target_camera.get_single_mut() {
+        *target_camera_transform = transform;
+    } else {
+        commands.spawn((
+            Camera3dBundle {
+                camera: Camera {
+                    order: -1,
+                    target: RenderTarget::Image(images.add(create_target_camera_image())),
+                    hdr: false,
+                    ..Default::default()
+                },
+                transform,
+                ..Default::default()
+            },
+            TargetCamera,
+        ));
+    }
+}
+
+pub fn target_camera_exit_system(
+    mut commands: Commands,
+    query_target_camera: Query<Entity, With<TargetCamera>>,
+) {
+    for entity in query_target_camera.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
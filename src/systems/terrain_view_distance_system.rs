@@ -0,0 +1,81 @@
+use bevy::{
+    math::Vec3Swizzles,
+    prelude::{
+        Added, Camera3d, GlobalTransform, Local, Query, RemovedComponents, Res, Visibility, With,
+        Without,
+    },
+    utils::HashSet,
+};
+
+use crate::{
+    components::{TargetCamera, ZoneObject},
+    resources::RenderConfiguration,
+    zone_loader::{zone_block_distance, ZONE_BLOCK_SIZE},
+};
+
+/// Swaps terrain blocks beyond the terrain view distance for their coarse far terrain, only
+/// updating when the camera moves into a different block or terrain is spawned or despawned.
+/// Far terrain is also shown for blocks which have not been spawned by block streaming.
+pub fn terrain_view_distance_system(
+    mut last_camera_block: Local<Option<(i32, i32)>>,
+    render_configuration: Res<RenderConfiguration>,
+    query_camera: Query<&GlobalTransform, (With<Camera3d>, Without<TargetCamera>)>,
+    query_added: Query<(), Added<ZoneObject>>,
+    mut removed_zone_objects: RemovedComponents<ZoneObject>,
+    mut query_terrain: Query<(&ZoneObject, &mut Visibility)>,
+) {
+    let view_distance = render_configuration
+        .terrain_view_distance
+        .unwrap_or(f32::INFINITY);
+    let Ok(camera_transform) = query_camera.get_single() else {
+        return;
+    };
+    let camera_position = camera_transform.translation().xz();
+    let camera_block = (
+        (camera_position.x / ZONE_BLOCK_SIZE).floor() as i32,
+        (camera_position.y / ZONE_BLOCK_SIZE).floor() as i32,
+    );
+    let has_removed = removed_zone_objects.iter().next().is_some();
+    removed_zone_objects.clear();
+
+    if *last_camera_block == Some(camera_block)
+        && query_added.is_empty()
+        && !has_removed
+        && !render_configuration.is_changed()
+    {
+        return;
+    }
+    *last_camera_block = Some(camera_block);
+
+    let spawned_terrain_blocks: HashSet<(u32, u32)> = query_terrain
+        .iter()
+        .filter_map(|(zone_object, _)| match zone_object {
+            ZoneObject::Terrain(terrain) => Some((terrain.block_x, terrain.block_y)),
+            _ => None,
+        })
+        .collect();
+
+    for (zone_object, mut visibility) in query_terrain.iter_mut() {
+        let (terrain, is_far_terrain) = match zone_object {
+            ZoneObject::Terrain(terrain) => (terrain, false),
+            ZoneObject::FarTerrain(terrain) => (terrain, true),
+            _ => continue,
+        };
+
+        let is_far = zone_block_distance(
+            camera_position,
+            terrain.block_x as usize,
+            terrain.block_y as usize,
+        ) > view_distance
+            || !spawned_terrain_blocks.contains(&(terrain.block_x, terrain.block_y));
+        let new_visibility = if is_far == is_far_terrain {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        if *visibility != new_visibility {
+            *visibility = new_visibility;
+        }
+    }
+}
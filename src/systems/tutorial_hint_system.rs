@@ -0,0 +1,54 @@
+use bevy::prelude::{Entity, EventReader, EventWriter, Local, Query, Res, With};
+
+use crate::{
+    components::PlayerCharacter,
+    events::{ClientEntityEvent, TutorialHint, TutorialHintEvent},
+    resources::SelectedTarget,
+};
+
+#[derive(Default)]
+pub struct TutorialHintState {
+    player_entity: Option<Entity>,
+    has_selected_target: bool,
+}
+
+/// Sends tutorial hint events for first time events which are not already triggered elsewhere,
+/// the ui decides whether the hint has been seen before.
+pub fn tutorial_hint_system(
+    mut state: Local<TutorialHintState>,
+    mut client_entity_events: EventReader<ClientEntityEvent>,
+    mut tutorial_hint_events: EventWriter<TutorialHintEvent>,
+    query_player: Query<Entity, With<PlayerCharacter>>,
+    selected_target: Res<SelectedTarget>,
+) {
+    let player_entity = query_player.get_single().ok();
+    if player_entity.is_some() && state.player_entity != player_entity {
+        tutorial_hint_events.send(TutorialHintEvent(TutorialHint::EnterGame));
+    }
+    state.player_entity = player_entity;
+
+    let Some(player_entity) = player_entity else {
+        client_entity_events.clear();
+        return;
+    };
+
+    for event in client_entity_events.iter() {
+        match *event {
+            ClientEntityEvent::Die(entity) if entity == player_entity => {
+                tutorial_hint_events.send(TutorialHintEvent(TutorialHint::PlayerDied));
+            }
+            ClientEntityEvent::LevelUp(entity, _) if entity == player_entity => {
+                tutorial_hint_events.send(TutorialHintEvent(TutorialHint::LevelUp));
+            }
+            _ => {}
+        }
+    }
+
+    let has_selected_target = selected_target
+        .selected
+        .map_or(false, |selected| selected != player_entity);
+    if has_selected_target && !state.has_selected_target {
+        tutorial_hint_events.send(TutorialHintEvent(TutorialHint::SelectTarget));
+    }
+    state.has_selected_target = has_selected_target;
+}
@@ -0,0 +1,148 @@
+use std::f32::consts::TAU;
+
+use bevy::{
+    math::{Quat, Vec3},
+    prelude::{Assets, Commands, Entity, EventWriter, Query, Res, ResMut, Time, Transform, With},
+};
+use bevy_rapier3d::prelude::{Collider, CollisionGroups, QueryFilter, RapierContext};
+
+use rose_game_common::messages::client::ClientMessage;
+
+use crate::{
+    components::{
+        NextCommand, PlayerCharacter, Position, COLLISION_FILTER_COLLIDABLE,
+        COLLISION_GROUP_PHYSICS_TOY, COLLISION_GROUP_ZONE_TERRAIN,
+    },
+    events::ChatboxEvent,
+    resources::{CurrentZone, GameConnection, Unstick},
+    zone_loader::ZoneLoaderAsset,
+};
+
+const UNSTICK_SEARCH_STEP: f32 = 50.0;
+const UNSTICK_SEARCH_RINGS: usize = 10;
+const UNSTICK_SEARCH_DIRECTIONS: usize = 16;
+const UNSTICK_COLLIDER_RADIUS: f32 = 0.4;
+
+fn is_position_clear(rapier_context: &RapierContext, x: f32, y: f32, z: f32) -> bool {
+    let filter = QueryFilter::new().groups(CollisionGroups::new(
+        COLLISION_FILTER_COLLIDABLE,
+        !COLLISION_GROUP_ZONE_TERRAIN & !COLLISION_GROUP_PHYSICS_TOY,
+    ));
+
+    // Check both the feet and head of the player are outside of any colliders
+    [0.5, 1.2].into_iter().all(|offset| {
+        rapier_context
+            .intersection_with_shape(
+                Vec3::new(x / 100.0, z / 100.0 + offset, -y / 100.0),
+                Quat::default(),
+                &Collider::ball(UNSTICK_COLLIDER_RADIUS),
+                filter,
+            )
+            .is_none()
+    })
+}
+
+/// Returns the nearest position on the heightmap where the player is not inside a collider
+fn find_unstick_position(
+    rapier_context: &RapierContext,
+    zone_data: &ZoneLoaderAsset,
+    position: Vec3,
+) -> Option<Vec3> {
+    for ring in 1..=UNSTICK_SEARCH_RINGS {
+        let radius = ring as f32 * UNSTICK_SEARCH_STEP;
+
+        for direction in 0..UNSTICK_SEARCH_DIRECTIONS {
+            let angle = direction as f32 * TAU / UNSTICK_SEARCH_DIRECTIONS as f32;
+            let x = position.x + radius * angle.cos();
+            let y = position.y + radius * angle.sin();
+            if !zone_data.is_on_terrain(x, y) {
+                continue;
+            }
+
+            let z = zone_data.get_terrain_height(x, y);
+            if is_position_clear(rapier_context, x, y, z) {
+                return Some(Vec3::new(x, y, z));
+            }
+        }
+    }
+
+    None
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn unstick_system(
+    mut commands: Commands,
+    mut unstick: ResMut<Unstick>,
+    mut query_player: Query<(Entity, &mut Position, &mut Transform), With<PlayerCharacter>>,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    rapier_context: Res<RapierContext>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    game_connection: Option<Res<GameConnection>>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+
+    if unstick.is_stuck(now) && !unstick.hint_shown {
+        unstick.hint_shown = true;
+        chatbox_events.send(ChatboxEvent::System(
+            "You appear to be stuck, type /unstick to move to a nearby position.".to_string(),
+        ));
+    }
+
+    if !std::mem::take(&mut unstick.requested) {
+        return;
+    }
+
+    if let Some(remaining) = unstick.cooldown_remaining(now) {
+        chatbox_events.send(ChatboxEvent::System(format!(
+            "You must wait {} seconds before using /unstick again.",
+            remaining.as_secs().max(1)
+        )));
+        return;
+    }
+
+    let Some(zone_data) = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+    else {
+        return;
+    };
+    let Ok((player_entity, mut position, mut transform)) = query_player.get_single_mut() else {
+        return;
+    };
+
+    let Some(unstick_position) =
+        find_unstick_position(&rapier_context, zone_data, position.position)
+    else {
+        chatbox_events.send(ChatboxEvent::System(
+            "Unable to find a nearby position to move to.".to_string(),
+        ));
+        return;
+    };
+
+    position.position = unstick_position;
+    transform.translation = Vec3::new(
+        unstick_position.x / 100.0,
+        unstick_position.z / 100.0,
+        -unstick_position.y / 100.0,
+    );
+    commands
+        .entity(player_entity)
+        .insert(NextCommand::with_stop());
+
+    if let Some(game_connection) = game_connection.as_ref() {
+        game_connection
+            .client_message_tx
+            .send(ClientMessage::MoveCollision {
+                position: unstick_position,
+            })
+            .ok();
+    }
+
+    unstick.last_used = Some(now);
+    unstick.set_moving();
+    chatbox_events.send(ChatboxEvent::System(
+        "You have been moved to a nearby position.".to_string(),
+    ));
+}
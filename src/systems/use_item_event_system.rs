@@ -4,26 +4,29 @@ use bevy::{
     ecs::query::WorldQuery,
     prelude::{
         AssetServer, Commands, Entity, EventReader, EventWriter, GlobalTransform, Query, Res,
-        Transform,
+        Transform, Vec3,
     },
     time::Time,
 };
 
-use rose_data::ItemType;
+use rose_data::{EffectBulletMoveType, ItemType, SkillType};
 use rose_game_common::components::{StatusEffects, StatusEffectsRegen};
 
 use crate::{
     audio::SpatialSound,
-    components::{PlayerCharacter, SoundCategory},
-    events::{SpawnEffectData, SpawnEffectEvent, UseItemEvent},
+    components::{FacingDirection, PlayerCharacter, ProjectileTarget, SoundCategory},
+    events::{SpawnEffectData, SpawnEffectEvent, SpawnProjectileEvent, UseItemEvent},
     resources::{GameData, SoundCache, SoundSettings},
 };
 
+const THROW_DEFAULT_DISTANCE: f32 = 5.0;
+
 #[derive(WorldQuery)]
 #[world_query(mutable)]
 pub struct EntityQuery<'w> {
     entity: Entity,
     global_transform: &'w GlobalTransform,
+    facing_direction: Option<&'w FacingDirection>,
     status_effects: &'w mut StatusEffects,
     status_effects_regen: &'w mut StatusEffectsRegen,
     is_player: Option<&'w PlayerCharacter>,
@@ -33,6 +36,7 @@ pub fn use_item_event_system(
     mut commands: Commands,
     mut events: EventReader<UseItemEvent>,
     mut spawn_effect_events: EventWriter<SpawnEffectEvent>,
+    mut spawn_projectile_events: EventWriter<SpawnProjectileEvent>,
     mut query: Query<EntityQuery>,
     asset_server: Res<AssetServer>,
     game_data: Res<GameData>,
@@ -66,6 +70,58 @@ pub fn use_item_event_system(
             ));
         }
 
+        // Thrown items use a skill with a bullet effect, the server does not send a skill cast
+        // for these so we throw the projectile in front of the user here.
+        if let Some((skill_data, effect_data)) = item_data
+            .use_skill_id
+            .and_then(|skill_id| game_data.skills.get_skill(skill_id))
+            .filter(|skill_data| {
+                !matches!(
+                    skill_data.skill_type,
+                    SkillType::FireBullet
+                        | SkillType::TargetBound
+                        | SkillType::TargetBoundDuration
+                        | SkillType::TargetStateDuration
+                )
+            })
+            .and_then(|skill_data| {
+                skill_data
+                    .bullet_effect_id
+                    .and_then(|id| game_data.effect_database.get_effect(id))
+                    .filter(|effect_data| effect_data.bullet_effect.is_some())
+                    .map(|effect_data| (skill_data, effect_data))
+            })
+        {
+            let throw_distance = if skill_data.cast_range > 0 {
+                skill_data.cast_range as f32 / 100.0
+            } else {
+                THROW_DEFAULT_DISTANCE
+            };
+            let throw_angle = user
+                .facing_direction
+                .map_or(0.0, |facing_direction| facing_direction.actual)
+                - std::f32::consts::PI;
+            let throw_direction = Vec3::new(throw_angle.cos(), 0.0, -throw_angle.sin());
+
+            spawn_projectile_events.send(SpawnProjectileEvent {
+                effect_id: effect_data.id,
+                source: user.entity,
+                source_dummy_bone_id: Some(skill_data.bullet_link_dummy_bone_id as usize),
+                source_skill_id: None,
+                target: ProjectileTarget::Position {
+                    position: user.global_transform.translation()
+                        + throw_direction * throw_distance,
+                },
+                move_type: effect_data
+                    .bullet_move_type
+                    .as_ref()
+                    .cloned()
+                    .unwrap_or(EffectBulletMoveType::Parabola),
+                move_speed: effect_data.bullet_speed / 100.0,
+                apply_damage: false,
+            });
+        }
+
         if let Some(sound_data) = item_data
             .effect_sound_id
             .and_then(|id| game_data.sounds.get_sound(id))
@@ -0,0 +1,26 @@
+use bevy::prelude::{Changed, Or, Query, Visibility};
+
+use crate::components::{Command, Vehicle, VehicleModel};
+
+pub fn vehicle_exhaust_system(
+    query: Query<(&Command, &Vehicle), Or<(Changed<Command>, Changed<Vehicle>)>>,
+    query_vehicle_model: Query<&VehicleModel>,
+    mut query_visibility: Query<&mut Visibility>,
+) {
+    for (command, vehicle) in query.iter() {
+        let Ok(vehicle_model) = query_vehicle_model.get(vehicle.vehicle_model_entity) else {
+            continue;
+        };
+
+        let exhaust_visibility = match command {
+            Command::Move(_) => Visibility::Inherited,
+            _ => Visibility::Hidden,
+        };
+
+        for effect_entity in vehicle_model.exhaust_effects.iter() {
+            if let Ok(mut visibility) = query_visibility.get_mut(*effect_entity) {
+                *visibility = exhaust_visibility;
+            }
+        }
+    }
+}
@@ -7,11 +7,15 @@ use bevy::{
     render::mesh::skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
 };
 
+use rose_data::VehicleMotionAction;
 use rose_game_common::components::{Equipment, MoveMode};
 
 use crate::{
     animation::SkeletalAnimation,
-    components::{CharacterModel, CharacterModelPart, DummyBoneOffset, Vehicle, VehicleModel},
+    components::{
+        CharacterModel, CharacterModelPart, DummyBoneOffset, Vehicle, VehicleModel,
+        VehicleMountMotion,
+    },
     model_loader::ModelLoader,
     render::{EffectMeshMaterial, ObjectMaterial, ParticleMaterial},
 };
@@ -109,6 +113,15 @@ pub fn vehicle_model_system(
                 .entity(vehicle.vehicle_model_entity)
                 .despawn_recursive();
             commands.entity(entity).remove::<Vehicle>();
+
+            // Play the vehicle's get off motion, this must be inserted after the above command
+            // which moves the driver animation back to the root entity
+            let motion = &vehicle.action_motions[VehicleMotionAction::Special2];
+            if motion.is_strong() {
+                commands
+                    .entity(entity)
+                    .insert((SkeletalAnimation::once(motion.clone()), VehicleMountMotion));
+            }
         } else if matches!(move_mode, MoveMode::Drive) {
             let driver_model_entity = skinned_mesh.joints[0];
             let vehicle_model_entity = commands
@@ -134,6 +147,8 @@ pub fn vehicle_model_system(
                     equipment,
                 );
 
+            let mount_motion =
+                vehicle_model.character_action_motions[VehicleMotionAction::Special1].clone();
             commands
                 .entity(entity)
                 .add_child(vehicle_model_entity)
@@ -203,6 +218,15 @@ pub fn vehicle_model_system(
                 let mut root_entity_mut = world.entity_mut(entity);
                 root_entity_mut.insert((vehicle_skinned_mesh, vehicle_dummy_bone_offset));
             });
+
+            // Play the vehicle's get on motion, this must be inserted after the above command
+            // which moves the character animation to the driver model
+            if mount_motion.is_strong() {
+                commands
+                    .entity(driver_model_entity)
+                    .insert(SkeletalAnimation::once(mount_motion));
+                commands.entity(entity).insert(VehicleMountMotion);
+            }
         }
     }
 }
@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use bevy::{
+    math::{Quat, Vec2},
+    pbr::{AlphaMode, NotShadowCaster, NotShadowReceiver, PbrBundle, StandardMaterial},
+    prelude::{
+        shape, Assets, Color, Commands, DespawnRecursiveExt, Entity, EventWriter, GlobalTransform,
+        Handle, Image, Local, Mesh, Query, Res, ResMut, Time, Transform, Vec3,
+    },
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use rose_file_readers::VfsPathBuf;
+
+use crate::{
+    components::{ClientEntity, ClientEntityType, WaterContact, WaterPlane, WaterRipple},
+    events::{SpawnEffectData, SpawnEffectEvent},
+};
+
+const WATER_SPLASH_EFFECT_PATH: &str = "3DDATA/SUPPLEMENTARY/WATER_SPLASH.EFT";
+
+const WATER_RIPPLE_TEXTURE_SIZE: u32 = 64;
+const WATER_RIPPLE_MAX_ALPHA: f32 = 0.5;
+
+/// Offset above the water surface to avoid z-fighting
+const WATER_RIPPLE_HEIGHT_OFFSET: f32 = 0.02;
+
+const WATER_SPLASH_RIPPLE_RADIUS: f32 = 2.0;
+const WATER_SPLASH_RIPPLE_LIFETIME: f32 = 1.2;
+
+const WATER_WADING_RIPPLE_RADIUS: f32 = 0.8;
+const WATER_WADING_RIPPLE_LIFETIME: f32 = 0.8;
+const WATER_WADING_RIPPLE_INTERVAL: Duration = Duration::from_millis(400);
+
+/// Entities which have moved less than this since the last frame are not wading
+const WATER_WADING_MIN_MOVE_DISTANCE: f32 = 0.001;
+
+fn create_water_ripple_image() -> Image {
+    let size = WATER_RIPPLE_TEXTURE_SIZE as usize;
+    let mut data = Vec::with_capacity(size * size * 4);
+
+    for y in 0..size {
+        for x in 0..size {
+            let dx = (x as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+            let dy = (y as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+            let distance = (dx * dx + dy * dy).sqrt();
+            // A soft ring which peaks near the edge of the decal
+            let ring = (1.0 - (distance - 0.85).abs() / 0.15).clamp(0.0, 1.0);
+            let alpha = ring * ring * WATER_RIPPLE_MAX_ALPHA;
+            data.extend_from_slice(&[255, 255, 255, (alpha * 255.0) as u8]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: WATER_RIPPLE_TEXTURE_SIZE,
+            height: WATER_RIPPLE_TEXTURE_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8Unorm,
+    )
+}
+
+fn find_water_surface(query_water_planes: &Query<&WaterPlane>, translation: Vec3) -> Option<f32> {
+    query_water_planes
+        .iter()
+        .filter(|water_plane| {
+            water_plane.contains(translation) && translation.y < water_plane.height
+        })
+        .map(|water_plane| water_plane.height)
+        .reduce(f32::max)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn water_splash_system(
+    mut commands: Commands,
+    mut ripple_assets: Local<Option<(Handle<Mesh>, Handle<Image>)>>,
+    mut spawn_effect_events: EventWriter<SpawnEffectEvent>,
+    mut query_entities: Query<(
+        Entity,
+        &ClientEntity,
+        &GlobalTransform,
+        Option<&mut WaterContact>,
+    )>,
+    mut query_ripples: Query<(
+        Entity,
+        &mut WaterRipple,
+        &mut Transform,
+        &Handle<StandardMaterial>,
+    )>,
+    query_water_planes: Query<&WaterPlane>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+    let delta = time.delta_seconds();
+
+    // Expand and fade out existing ripples
+    for (entity, mut ripple, mut transform, material_handle) in query_ripples.iter_mut() {
+        ripple.age += delta;
+        if ripple.age >= ripple.lifetime {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let t = ripple.age / ripple.lifetime;
+        transform.scale = Vec3::splat(ripple.radius * (0.3 + 0.7 * t));
+        if let Some(material) = materials.get_mut(material_handle) {
+            material.base_color.set_a(1.0 - t);
+        }
+    }
+
+    let (ripple_mesh, ripple_image) = ripple_assets
+        .get_or_insert_with(|| {
+            (
+                meshes.add(Mesh::from(shape::Quad::new(Vec2::splat(2.0)))),
+                images.add(create_water_ripple_image()),
+            )
+        })
+        .clone();
+    let mut spawn_ripple = |commands: &mut Commands, translation: Vec3, lifetime, radius| {
+        commands.spawn((
+            WaterRipple::new(lifetime, radius),
+            PbrBundle {
+                mesh: ripple_mesh.clone(),
+                material: materials.add(StandardMaterial {
+                    base_color: Color::WHITE,
+                    base_color_texture: Some(ripple_image.clone()),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..Default::default()
+                }),
+                transform: Transform::from_translation(translation)
+                    .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+                    .with_scale(Vec3::splat(radius * 0.3)),
+                ..Default::default()
+            },
+            NotShadowCaster,
+            NotShadowReceiver,
+        ));
+    };
+
+    for (entity, client_entity, global_transform, water_contact) in query_entities.iter_mut() {
+        if matches!(client_entity.entity_type, ClientEntityType::ItemDrop) {
+            continue;
+        }
+
+        let translation = global_transform.translation();
+        let surface_height = find_water_surface(&query_water_planes, translation);
+
+        let Some(mut water_contact) = water_contact else {
+            // Entities which spawn in water do not splash
+            commands.entity(entity).insert(WaterContact {
+                surface_height,
+                last_translation: translation,
+                last_ripple: now,
+            });
+            continue;
+        };
+
+        if let Some(surface_height) = surface_height {
+            let surface_translation = Vec3::new(
+                translation.x,
+                surface_height + WATER_RIPPLE_HEIGHT_OFFSET,
+                translation.z,
+            );
+
+            if water_contact.surface_height.is_none() {
+                spawn_effect_events.send(SpawnEffectEvent::WithTransform(
+                    Transform::from_translation(surface_translation),
+                    SpawnEffectData::with_path(VfsPathBuf::new(WATER_SPLASH_EFFECT_PATH)),
+                ));
+                spawn_ripple(
+                    &mut commands,
+                    surface_translation,
+                    WATER_SPLASH_RIPPLE_LIFETIME,
+                    WATER_SPLASH_RIPPLE_RADIUS,
+                );
+                water_contact.last_ripple = now;
+            } else if now - water_contact.last_ripple >= WATER_WADING_RIPPLE_INTERVAL
+                && water_contact.last_translation.distance_squared(translation)
+                    > WATER_WADING_MIN_MOVE_DISTANCE * WATER_WADING_MIN_MOVE_DISTANCE
+            {
+                spawn_ripple(
+                    &mut commands,
+                    surface_translation,
+                    WATER_WADING_RIPPLE_LIFETIME,
+                    WATER_WADING_RIPPLE_RADIUS,
+                );
+                water_contact.last_ripple = now;
+            }
+        }
+
+        water_contact.surface_height = surface_height;
+        water_contact.last_translation = translation;
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::{Query, Res};
+
+use crate::{
+    components::{
+        CharacterModel, CharacterModelPart, Command, CommandCastSkill, CommandCastSkillState,
+    },
+    render::TrailEffect,
+    resources::RenderConfiguration,
+};
+
+pub fn weapon_trail_system(
+    query_characters: Query<(&CharacterModel, &Command)>,
+    mut query_trail_effects: Query<&mut TrailEffect>,
+    render_configuration: Res<RenderConfiguration>,
+) {
+    for (character_model, command) in query_characters.iter() {
+        // Trails are only drawn for the weapon swing of attacks and skills
+        let emitting = render_configuration.weapon_trails
+            && matches!(
+                command,
+                Command::Attack(_)
+                    | Command::CastSkill(CommandCastSkill {
+                        cast_skill_state: CommandCastSkillState::Action,
+                        ..
+                    })
+            );
+
+        for part_entity in character_model.model_parts[CharacterModelPart::Weapon]
+            .1
+            .iter()
+        {
+            if let Ok(mut trail_effect) = query_trail_effects.get_mut(*part_entity) {
+                if trail_effect.emitting != emitting {
+                    trail_effect.emitting = emitting;
+                }
+            }
+        }
+    }
+}
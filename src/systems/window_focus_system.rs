@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use bevy::{
+    input::Input,
+    prelude::{EventReader, KeyCode, MouseButton, Query, Res, ResMut},
+    window::WindowFocused,
+    winit::{UpdateMode, WinitSettings},
+};
+
+use crate::{
+    audio::SoundGain,
+    components::SoundCategory,
+    resources::{AutoTravel, InterfaceSettings, SoundSettings},
+};
+
+// Frame time to wait for whilst unfocused when the reduced frame rate option is enabled
+const UNFOCUSED_FRAME_TIME: Duration = Duration::from_millis(1000 / 15);
+
+pub fn window_focus_system(
+    mut window_focused_events: EventReader<WindowFocused>,
+    mut keyboard_input: ResMut<Input<KeyCode>>,
+    mut mouse_button_input: ResMut<Input<MouseButton>>,
+    mut sound_settings: ResMut<SoundSettings>,
+    mut auto_travel: ResMut<AutoTravel>,
+    mut query_sounds: Query<(&SoundCategory, &mut SoundGain)>,
+    winit_settings: Option<ResMut<WinitSettings>>,
+    interface_settings: Res<InterfaceSettings>,
+) {
+    if let Some(mut winit_settings) = winit_settings {
+        let unfocused_mode = if interface_settings.focus_loss_reduce_fps {
+            UpdateMode::ReactiveLowPower {
+                max_wait: UNFOCUSED_FRAME_TIME,
+            }
+        } else {
+            UpdateMode::Continuous
+        };
+
+        if winit_settings.unfocused_mode != unfocused_mode {
+            winit_settings.unfocused_mode = unfocused_mode;
+        }
+    }
+
+    let Some(focused) = window_focused_events
+        .iter()
+        .last()
+        .map(|event| event.focused)
+    else {
+        return;
+    };
+
+    if !focused {
+        // Keys and buttons released whilst unfocused are never seen, so clear them now to avoid
+        // moving or rotating the camera forever when we regain focus
+        keyboard_input.reset_all();
+        mouse_button_input.reset_all();
+
+        if interface_settings.focus_loss_stop_auto_run {
+            auto_travel.cancel();
+        }
+    }
+
+    let muted = !focused && interface_settings.focus_loss_mute_audio;
+    if sound_settings.muted != muted {
+        sound_settings.muted = muted;
+
+        for (category, mut gain) in query_sounds.iter_mut() {
+            let target_gain = sound_settings.gain(*category);
+
+            if target_gain != *gain {
+                *gain = target_gain;
+            }
+        }
+    }
+}
@@ -4,8 +4,11 @@ use rose_game_common::messages::{client::ClientMessage, server::ServerMessage};
 use rose_network_common::ConnectionError;
 
 use crate::{
-    events::{NetworkEvent, WorldConnectionEvent},
-    resources::{Account, AppState, CharacterList, WorldConnection},
+    events::{NetworkEvent, ToastCategory, ToastEvent, WorldConnectionEvent},
+    resources::{
+        Account, AppState, ChannelQueue, ChannelSwitch, ChannelSwitchStage, CharacterList,
+        WorldConnection,
+    },
 };
 
 pub fn world_connection_system(
@@ -14,8 +17,11 @@ pub fn world_connection_system(
     account: Option<Res<Account>>,
     app_state_current: Res<State<AppState>>,
     mut app_state_next: ResMut<NextState<AppState>>,
+    mut channel_queue: ResMut<ChannelQueue>,
+    mut channel_switch: ResMut<ChannelSwitch>,
     mut network_events: EventWriter<NetworkEvent>,
     mut world_connection_events: EventWriter<WorldConnectionEvent>,
+    mut toast_events: EventWriter<ToastEvent>,
 ) {
     let world_connection = if let Some(world_connection) = world_connection {
         world_connection
@@ -45,9 +51,34 @@ pub fn world_connection_system(
             Ok(ServerMessage::CharacterList {
                 character_list: characters,
             }) => {
-                if !matches!(app_state_current.get(), AppState::GameCharacterSelect) {
-                    app_state_next.set(AppState::GameCharacterSelect);
+                if channel_switch.stage == Some(ChannelSwitchStage::ConnectingWorld) {
+                    // Changing channel, reselect the character we were already playing
+                    if let Some((slot, character)) = characters
+                        .iter()
+                        .enumerate()
+                        .find(|(_, character)| character.info.name == channel_switch.character_name)
+                    {
+                        world_connection
+                            .client_message_tx
+                            .send(ClientMessage::SelectCharacter {
+                                slot: slot as u8,
+                                name: character.info.name.clone(),
+                            })
+                            .ok();
+                        channel_switch.stage = Some(ChannelSwitchStage::ConnectingGame);
+                    } else {
+                        channel_switch.cancel();
+                        app_state_next.set(AppState::GameCharacterSelect);
+                    }
+                } else {
+                    if !matches!(app_state_current.get(), AppState::GameCharacterSelect) {
+                        app_state_next.set(AppState::GameCharacterSelect);
+                    }
+                    if channel_queue.joining.is_some() {
+                        channel_switch.current = channel_queue.joining;
+                    }
                 }
+                channel_queue.reset();
 
                 commands.insert_resource(CharacterList { characters });
             }
@@ -97,8 +128,20 @@ pub fn world_connection_system(
     };
 
     if let Err(error) = result {
-        // TODO: Store error somewhere to display to user
         log::warn!("World server connection error: {}", error);
+
+        if matches!(app_state_current.get(), AppState::Game) {
+            toast_events.send(ToastEvent::new(
+                ToastCategory::Connection,
+                format!("Lost connection to world server: {}", error),
+            ));
+        }
         commands.remove_resource::<WorldConnection>();
+
+        if matches!(app_state_current.get(), AppState::GameLogin) && channel_queue.joining.is_some()
+        {
+            // The channel rejected us before we received the character list
+            channel_queue.rejected = true;
+        }
     }
 }
@@ -0,0 +1,126 @@
+use bevy::{
+    asset::LoadState,
+    math::Vec3,
+    prelude::{AssetServer, Assets, GlobalTransform, Handle, Image, Local, Query, Res, With},
+};
+
+use rose_data::ZoneId;
+
+use crate::{
+    components::{PlayerCharacter, WarpObject},
+    resources::{CurrentZone, GameData},
+    zone_loader::ZoneLoaderAsset,
+};
+
+// Start preloading the destination zone when the player is this close to a warp gate
+const ZONE_PRELOAD_DISTANCE: f32 = 40.0;
+
+// Preloaded zones are released once the player has moved this far away from the warp gate
+const ZONE_PRELOAD_RELEASE_DISTANCE: f32 = 80.0;
+
+// Only preload a single zone at a time so we do not slow down loading of the current zone
+const ZONE_PRELOAD_MAX_CONCURRENT: usize = 1;
+
+struct PreloadingZone {
+    zone_id: ZoneId,
+    warp_position: Vec3,
+    handle: Handle<ZoneLoaderAsset>,
+    tile_textures: Vec<Handle<Image>>,
+}
+
+#[derive(Default)]
+pub struct ZonePreloadState {
+    zones: Vec<PreloadingZone>,
+}
+
+/// Preloads the destination zone of nearby warp gates in the background, the zone loader
+/// reuses the already loaded asset and tile textures when the zone change happens.
+pub fn zone_preload_system(
+    mut preload_state: Local<ZonePreloadState>,
+    asset_server: Res<AssetServer>,
+    current_zone: Option<Res<CurrentZone>>,
+    game_data: Res<GameData>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
+    query_warp_objects: Query<(&WarpObject, &GlobalTransform)>,
+) {
+    let Ok(player_transform) = query_player.get_single() else {
+        preload_state.zones.clear();
+        return;
+    };
+    let player_position = player_transform.translation();
+    let current_zone_id = current_zone.map(|current_zone| current_zone.id);
+
+    // Release preloaded zones we have walked away from, or have now arrived in
+    preload_state.zones.retain(|zone| {
+        Some(zone.zone_id) != current_zone_id
+            && zone.warp_position.distance(player_position) < ZONE_PRELOAD_RELEASE_DISTANCE
+    });
+
+    // Once the zone data has loaded we can start loading its tile textures
+    for zone in preload_state.zones.iter_mut() {
+        if !zone.tile_textures.is_empty() {
+            continue;
+        }
+
+        if let Some(zone_data) = zone_loader_assets.get(&zone.handle) {
+            zone.tile_textures = zone_data
+                .zon
+                .tile_textures
+                .iter()
+                .take_while(|path| path.as_str() != "end")
+                .map(|path| asset_server.load(path))
+                .collect();
+        }
+    }
+
+    let num_loading = preload_state
+        .zones
+        .iter()
+        .filter(|zone| {
+            matches!(
+                asset_server.get_load_state(&zone.handle),
+                LoadState::NotLoaded | LoadState::Loading
+            ) || zone.tile_textures.is_empty()
+        })
+        .count();
+    if num_loading >= ZONE_PRELOAD_MAX_CONCURRENT {
+        return;
+    }
+
+    for (warp_object, warp_transform) in query_warp_objects.iter() {
+        let warp_position = warp_transform.translation();
+        if warp_position.distance(player_position) > ZONE_PRELOAD_DISTANCE {
+            continue;
+        }
+
+        // WARP.STB column 1 is the destination zone of the warp gate
+        let Some(zone_id) = game_data
+            .stb_warp
+            .get(warp_object.warp_id.get() as usize, 1)
+            .parse::<u16>()
+            .ok()
+            .and_then(ZoneId::new)
+        else {
+            continue;
+        };
+
+        if Some(zone_id) == current_zone_id
+            || game_data.zone_list.get_zone(zone_id).is_none()
+            || preload_state
+                .zones
+                .iter()
+                .any(|zone| zone.zone_id == zone_id)
+        {
+            continue;
+        }
+
+        preload_state.zones.push(PreloadingZone {
+            zone_id,
+            warp_position,
+            handle: asset_server.load(format!("{}.zone_loader", zone_id.get())),
+            tile_textures: Vec::new(),
+        });
+        break;
+    }
+}
@@ -82,7 +82,10 @@ pub fn zone_time_system(
 
     let world_day_time = world_time.ticks.get_world_time();
     let (day_time, partial_tick) = if let Some(overwrite_time) = zone_time.debug_overwrite_time {
-        (overwrite_time, 0.0)
+        (
+            (overwrite_time as u32).min(zone_data.day_cycle.saturating_sub(1)),
+            overwrite_time.fract(),
+        )
     } else {
         (
             world_day_time % zone_data.day_cycle,
@@ -48,11 +48,15 @@ impl DialogInstance {
         dialog_assets: &Assets<Dialog>,
         ui_resources: &UiResources,
     ) -> Option<&mut Dialog> {
-        if self.instance.is_none() {
-            if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_files[&self.filename]) {
-                if dialog.loaded {
-                    self.instance = Some(dialog.clone());
-                }
+        if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_files[&self.filename]) {
+            if dialog.loaded
+                && self
+                    .instance
+                    .as_ref()
+                    .map_or(true, |instance| instance.revision != dialog.revision)
+            {
+                // Dialog has been loaded for the first time, or has been hot reloaded
+                self.instance = Some(dialog.clone());
             }
         }
 
@@ -63,6 +67,7 @@ impl DialogInstance {
 #[derive(Default)]
 pub struct DialogsLoadState {
     pending_dialogs: Vec<Handle<Dialog>>,
+    revision: u32,
 }
 
 pub fn load_dialog_sprites_system(
@@ -81,10 +86,14 @@ pub fn load_dialog_sprites_system(
     }
 
     if ui_resources.loaded_all_textures {
+        let load_state = &mut *load_state;
         for handle in load_state.pending_dialogs.drain(..) {
             if let Some(dialog) = assets.get_mut(&handle) {
                 dialog.widgets.load_widget(&ui_resources);
                 dialog.loaded = true;
+
+                load_state.revision += 1;
+                dialog.revision = load_state.revision;
             }
         }
     }
@@ -1,13 +1,21 @@
+use std::time::Duration;
+
 use bevy_egui::egui;
 
-use rose_data::{Item, ItemClass, ItemType, SkillCooldown, SkillId, StatusEffectType};
+use rose_data::{Item, SkillCooldown, SkillId};
 use rose_game_common::components::{ItemSlot, SkillSlot};
 
 use crate::{
     components::{ConsumableCooldownGroup, Cooldowns},
     resources::{GameData, UiResources, UiSprite, UiSpriteSheetType},
+    ui::ui_high_contrast,
 };
 
+const COOLDOWN_READY_FLASH_DURATION: f32 = 0.5;
+
+/// Equipment at or below this life is about to break, out of a maximum of 1000
+pub const LOW_DURABILITY_ITEM_LIFE: u16 = 100;
+
 #[derive(Copy, Clone, Debug)]
 pub enum DragAndDropId {
     NotDraggable,
@@ -28,7 +36,12 @@ pub struct DragAndDropSlot<'a> {
     sprite: Option<UiSprite>,
     socket_sprite: Option<UiSprite>,
     broken: bool,
+    low_durability: bool,
+    unusable: bool,
     cooldown_percent: Option<f32>,
+    cooldown_remaining: Option<Duration>,
+    show_cooldown_text: bool,
+    show_cooldown_ready_flash: bool,
     quantity: Option<usize>,
     quantity_margin: f32,
     accepts: fn(&DragAndDropId) -> bool,
@@ -56,7 +69,12 @@ impl<'a> DragAndDropSlot<'a> {
             sprite,
             socket_sprite,
             broken,
+            low_durability: false,
+            unusable: false,
             cooldown_percent,
+            cooldown_remaining: None,
+            show_cooldown_text: false,
+            show_cooldown_ready_flash: false,
             quantity,
             quantity_margin: 2.0,
             accepts,
@@ -102,52 +120,27 @@ impl<'a> DragAndDropSlot<'a> {
         let broken = item
             .and_then(|item| item.as_equipment())
             .map_or(false, |item| item.life == 0);
+        let low_durability = item
+            .and_then(|item| item.as_equipment())
+            .map_or(false, |item| {
+                item.life > 0 && item.life <= LOW_DURABILITY_ITEM_LIFE
+            });
         let quantity = match item {
             Some(Item::Stackable(stackable_item)) => Some(stackable_item.quantity as usize),
             _ => None,
         };
-        let mut cooldown_percent = None;
-        if let Some(cooldowns) = cooldowns {
-            if let Some(item) = item.as_ref() {
-                if item.get_item_type() == ItemType::Consumable {
-                    if let Some(consumable_item_data) =
-                        game_data.items.get_consumable_item(item.get_item_number())
-                    {
-                        if matches!(consumable_item_data.item_data.class, ItemClass::MagicItem) {
-                            cooldown_percent = cooldowns.get_consumable_cooldown_percent(
-                                ConsumableCooldownGroup::MagicItem,
-                            );
-                        } else if let Some(status_effect) = consumable_item_data
-                            .apply_status_effect
-                            .and_then(|(status_effect_id, _)| {
-                                game_data.status_effects.get_status_effect(status_effect_id)
-                            })
-                        {
-                            match status_effect.status_effect_type {
-                                StatusEffectType::IncreaseHp => {
-                                    cooldown_percent = cooldowns.get_consumable_cooldown_percent(
-                                        ConsumableCooldownGroup::HealthRecovery,
-                                    )
-                                }
-                                StatusEffectType::IncreaseMp => {
-                                    cooldown_percent = cooldowns.get_consumable_cooldown_percent(
-                                        ConsumableCooldownGroup::ManaRecovery,
-                                    )
-                                }
-                                _ => {
-                                    cooldown_percent = cooldowns.get_consumable_cooldown_percent(
-                                        ConsumableCooldownGroup::Others,
-                                    )
-                                }
-                            }
-                        } else {
-                            cooldown_percent = cooldowns
-                                .get_consumable_cooldown_percent(ConsumableCooldownGroup::Others);
-                        }
-                    }
-                }
-            }
-        }
+        let cooldown_group = item.and_then(|item| {
+            ConsumableCooldownGroup::from_item(&item.get_item_reference(), game_data)
+        });
+        let (cooldown_percent, cooldown_remaining) =
+            cooldowns
+                .zip(cooldown_group)
+                .map_or((None, None), |(cooldowns, cooldown_group)| {
+                    (
+                        cooldowns.get_consumable_cooldown_percent(cooldown_group),
+                        cooldowns.get_consumable_cooldown_remaining(cooldown_group),
+                    )
+                });
 
         Self {
             dnd_id,
@@ -156,7 +149,12 @@ impl<'a> DragAndDropSlot<'a> {
             sprite,
             socket_sprite,
             broken,
+            low_durability,
+            unusable: false,
             cooldown_percent,
+            cooldown_remaining,
+            show_cooldown_text: false,
+            show_cooldown_ready_flash: false,
             quantity,
             quantity_margin: 2.0,
             accepts,
@@ -183,16 +181,21 @@ impl<'a> DragAndDropSlot<'a> {
                 .get_sprite_by_index(UiSpriteSheetType::Skill, skill_data.icon_number as usize)
         });
 
-        let cooldown_percent = if let Some(cooldowns) = cooldowns {
-            skill_data.and_then(|skill_data| match &skill_data.cooldown {
-                SkillCooldown::Skill { .. } => cooldowns.get_skill_cooldown_percent(skill_data.id),
-                SkillCooldown::Group { group, .. } => {
-                    cooldowns.get_skill_group_cooldown_percent(*group)
-                }
-            })
-        } else {
-            None
-        };
+        let (cooldown_percent, cooldown_remaining) =
+            cooldowns
+                .zip(skill_data)
+                .map_or((None, None), |(cooldowns, skill_data)| {
+                    match &skill_data.cooldown {
+                        SkillCooldown::Skill { .. } => (
+                            cooldowns.get_skill_cooldown_percent(skill_data.id),
+                            cooldowns.get_skill_cooldown_remaining(skill_data.id),
+                        ),
+                        SkillCooldown::Group { group, .. } => (
+                            cooldowns.get_skill_group_cooldown_percent(*group),
+                            cooldowns.get_skill_group_cooldown_remaining(*group),
+                        ),
+                    }
+                });
 
         Self {
             dnd_id,
@@ -201,7 +204,12 @@ impl<'a> DragAndDropSlot<'a> {
             sprite,
             socket_sprite: None,
             broken: false,
+            low_durability: false,
+            unusable: false,
             cooldown_percent,
+            cooldown_remaining,
+            show_cooldown_text: false,
+            show_cooldown_ready_flash: false,
             quantity: None,
             quantity_margin: 2.0,
             accepts,
@@ -211,6 +219,19 @@ impl<'a> DragAndDropSlot<'a> {
     }
 }
 
+impl<'a> DragAndDropSlot<'a> {
+    pub fn with_cooldown_display(mut self, show_text: bool, show_ready_flash: bool) -> Self {
+        self.show_cooldown_text = show_text;
+        self.show_cooldown_ready_flash = show_ready_flash;
+        self
+    }
+
+    pub fn with_unusable(mut self, unusable: bool) -> Self {
+        self.unusable = unusable;
+        self
+    }
+}
+
 fn generate_cooldown_mesh(cooldown: f32, content_rect: egui::Rect) -> egui::epaint::Mesh {
     use egui::epaint::*;
 
@@ -315,6 +336,7 @@ impl<'w> DragAndDropSlot<'w> {
 
         if ui.is_rect_visible(rect) {
             use egui::epaint::*;
+            let high_contrast = ui_high_contrast(ui.ctx());
 
             // For some reason, we must do manual implementation of response.hovered
             let is_active = ui.ctx().input(|input| {
@@ -341,10 +363,20 @@ impl<'w> DragAndDropSlot<'w> {
                 mesh.add_rect_with_uv(
                     content_rect,
                     sprite.uv,
-                    if !self.broken {
-                        egui::Color32::WHITE
+                    if self.broken {
+                        if high_contrast {
+                            egui::Color32::RED
+                        } else {
+                            egui::Color32::LIGHT_RED
+                        }
+                    } else if self.unusable {
+                        if high_contrast {
+                            egui::Color32::from_gray(40)
+                        } else {
+                            egui::Color32::DARK_GRAY
+                        }
                     } else {
-                        egui::Color32::LIGHT_RED
+                        egui::Color32::WHITE
                     },
                 );
                 ui.painter().add(Shape::mesh(mesh));
@@ -369,6 +401,73 @@ impl<'w> DragAndDropSlot<'w> {
                     )));
                 }
 
+                if self.show_cooldown_text {
+                    if let Some(remaining) = self
+                        .cooldown_remaining
+                        .filter(|remaining| *remaining > Duration::from_secs(1))
+                    {
+                        let remaining = remaining.as_secs_f32();
+                        let text = if remaining < 3.0 {
+                            format!("{:.1}", remaining)
+                        } else {
+                            format!("{}", remaining.ceil() as u32)
+                        };
+
+                        let text_galley = ui.fonts(|fonts| {
+                            fonts.layout_no_wrap(text, FontId::proportional(16.0), Color32::WHITE)
+                        });
+                        let text_pos = content_rect.center() - text_galley.rect.center().to_vec2();
+                        ui.painter().add(Shape::galley_with_color(
+                            text_pos + egui::vec2(1.0, 1.0),
+                            text_galley.clone(),
+                            Color32::BLACK,
+                        ));
+                        ui.painter().add(Shape::galley(text_pos, text_galley));
+                    }
+                }
+
+                if self.low_durability {
+                    // Pulse the slot red while the item is about to break
+                    let current_time = ui.input(|input| input.time);
+                    let alpha = ((current_time * 4.0).sin() * 0.5 + 0.5) as f32;
+                    let max_alpha = if high_contrast { 200.0 } else { 100.0 };
+                    ui.painter().rect_filled(
+                        content_rect,
+                        egui::Rounding::none(),
+                        Color32::from_rgba_unmultiplied(255, 0, 0, (alpha * max_alpha) as u8),
+                    );
+                    ui.ctx().request_repaint();
+                }
+
+                if self.show_cooldown_ready_flash {
+                    // Flash the slot when its cooldown has completed
+                    let flash_id = response.id.with("cooldown_ready_flash");
+                    let on_cooldown = self.cooldown_remaining.is_some();
+                    let current_time = ui.input(|input| input.time);
+                    let flash_start_time = ui.ctx().data_mut(|data| {
+                        let (was_on_cooldown, flash_start_time) =
+                            data.get_temp_mut_or_default::<(bool, Option<f64>)>(flash_id);
+                        if *was_on_cooldown && !on_cooldown {
+                            *flash_start_time = Some(current_time);
+                        }
+                        *was_on_cooldown = on_cooldown;
+                        *flash_start_time
+                    });
+
+                    if let Some(flash_start_time) = flash_start_time {
+                        let flash_time = (current_time - flash_start_time) as f32;
+                        if flash_time < COOLDOWN_READY_FLASH_DURATION {
+                            let alpha = 1.0 - flash_time / COOLDOWN_READY_FLASH_DURATION;
+                            ui.painter().rect_filled(
+                                content_rect,
+                                egui::Rounding::none(),
+                                Color32::from_white_alpha((alpha * 160.0) as u8),
+                            );
+                            ui.ctx().request_repaint();
+                        }
+                    }
+                }
+
                 if let Some(quantity) = self.quantity {
                     let text_galley = ui.fonts(|fonts| {
                         fonts.layout_no_wrap(
@@ -430,13 +529,19 @@ impl<'w> DragAndDropSlot<'w> {
             }
 
             if is_active {
+                let (border_width, border_color) = if high_contrast {
+                    (self.border_width * 3.0, egui::Color32::WHITE)
+                } else {
+                    (self.border_width, egui::Color32::YELLOW)
+                };
+
                 ui.painter().add(egui::Shape::Rect(egui::epaint::RectShape {
-                    rect: rect.shrink(self.border_width),
+                    rect: rect.shrink(border_width),
                     rounding: egui::Rounding::none(),
                     fill: Default::default(),
                     stroke: egui::Stroke {
-                        width: self.border_width,
-                        color: egui::Color32::YELLOW,
+                        width: border_width,
+                        color: border_color,
                     },
                 }));
             }
@@ -0,0 +1,46 @@
+use rose_data::{Item, ItemType};
+
+use crate::resources::GameData;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ItemRarity {
+    Junk,
+    Common,
+    Rare,
+    Unique,
+}
+
+/// Classify an item for loot filtering and bulk selling.
+///
+/// Junk is plain equipment with no refine, gem or socket, which is only worth selling.
+/// Consumables and materials are never considered junk.
+pub fn get_item_rarity(game_data: &GameData, item: &Item) -> ItemRarity {
+    let Some(item_data) = game_data.items.get_base_item(item.get_item_reference()) else {
+        return ItemRarity::Common;
+    };
+
+    match item.get_item_type() {
+        ItemType::Face
+        | ItemType::Head
+        | ItemType::Body
+        | ItemType::Hands
+        | ItemType::Feet
+        | ItemType::Back
+        | ItemType::Weapon
+        | ItemType::SubWeapon => match item_data.rare_type {
+            1..=20 => ItemRarity::Rare,
+            21 => ItemRarity::Unique,
+            _ => match item.as_equipment() {
+                Some(equipment_item)
+                    if equipment_item.grade == 0
+                        && equipment_item.gem == 0
+                        && !equipment_item.has_socket =>
+                {
+                    ItemRarity::Junk
+                }
+                _ => ItemRarity::Common,
+            },
+        },
+        _ => ItemRarity::Common,
+    }
+}
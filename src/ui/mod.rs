@@ -1,13 +1,23 @@
 mod dialog_loader;
 mod drag_and_drop_slot;
+mod item_rarity;
+mod money;
 mod tooltips;
+mod ui_accessibility;
+mod ui_achievements_system;
+mod ui_aggro_indicator_system;
+mod ui_announcement_banner_system;
+mod ui_appraisal_system;
 mod ui_bank_system;
+mod ui_bug_report_system;
+mod ui_channel_list_system;
 mod ui_character_create_system;
 mod ui_character_info_system;
 mod ui_character_select_name_tag_system;
 mod ui_character_select_system;
 mod ui_chatbox_system;
 mod ui_clan_system;
+mod ui_clock_system;
 mod ui_create_clan;
 mod ui_debug_camera_info_system;
 mod ui_debug_client_entity_list_system;
@@ -26,13 +36,18 @@ mod ui_debug_zone_lighting_system;
 mod ui_debug_zone_list_system;
 mod ui_debug_zone_time_system;
 mod ui_drag_and_drop_system;
+mod ui_escape_menu_system;
+mod ui_fishing_system;
 mod ui_game_menu_system;
+mod ui_help_system;
 mod ui_hotbar_system;
+mod ui_ignore_list_system;
 mod ui_inventory_system;
 mod ui_item_drop_name_system;
 mod ui_login_system;
 mod ui_message_box_system;
 mod ui_minimap_system;
+mod ui_monster_info_system;
 mod ui_npc_store_system;
 mod ui_number_input_dialog_system;
 mod ui_party_option_system;
@@ -40,21 +55,30 @@ mod ui_party_system;
 mod ui_personal_store_system;
 mod ui_player_info_system;
 mod ui_quest_list_system;
+mod ui_repair_system;
+mod ui_request_popup_system;
 mod ui_respawn_system;
 mod ui_selected_target_system;
 mod ui_server_select_system;
+mod ui_session_stats_system;
 mod ui_settings_system;
 mod ui_skill_list_system;
 mod ui_skill_tree_system;
 mod ui_sound_event_system;
 mod ui_status_effects_system;
+mod ui_target_camera_system;
+mod ui_toast_system;
+mod ui_tutorial_hint_system;
 mod ui_window_sound_system;
+mod ui_zone_loading_system;
 pub mod widgets;
 
 #[derive(Default, Resource)]
 pub struct UiStateWindows {
+    pub channel_list_open: bool,
     pub character_info_open: bool,
     pub clan_open: bool,
+    pub escape_menu_open: bool,
     pub inventory_open: bool,
     pub skill_list_open: bool,
     pub skill_tree_open: bool,
@@ -63,10 +87,18 @@ pub struct UiStateWindows {
     pub menu_open: bool,
     pub party_open: bool,
     pub party_options_open: bool,
+    pub bug_report_open: bool,
+    pub help_open: bool,
+    pub ignore_list_open: bool,
+    pub monster_info_open: bool,
+    pub session_stats_open: bool,
+    pub achievements_open: bool,
 
     // Below are only opened via in game events rather than directly
+    pub appraisal_open: bool,
     pub bank_open: bool,
     pub create_clan_open: bool,
+    pub repair_open: bool,
 
     // Test ui
     pub selected_target_ui_open: bool,
@@ -74,15 +106,31 @@ pub struct UiStateWindows {
 
 use bevy::prelude::Resource;
 pub use dialog_loader::{load_dialog_sprites_system, DialogInstance, DialogLoader};
-pub use drag_and_drop_slot::{DragAndDropId, DragAndDropSlot};
-pub use tooltips::{get_item_name_color, ui_add_item_tooltip, ui_add_skill_tooltip};
+pub use drag_and_drop_slot::{DragAndDropId, DragAndDropSlot, LOW_DURABILITY_ITEM_LIFE};
+pub use item_rarity::{get_item_rarity, ItemRarity};
+pub use money::format_money;
+pub use tooltips::{
+    get_item_name_color, item_requires_appraisal, ui_add_item_tooltip, ui_add_monster_tooltip,
+    ui_add_skill_tooltip,
+};
+pub use ui_accessibility::{
+    ui_accessibility_system, ui_apply_minimum_font_size, ui_high_contrast, ui_minimum_font_size,
+    ui_scaled_font_id,
+};
+pub use ui_achievements_system::ui_achievements_system;
+pub use ui_aggro_indicator_system::ui_aggro_indicator_system;
+pub use ui_announcement_banner_system::ui_announcement_banner_system;
+pub use ui_appraisal_system::ui_appraisal_system;
 pub use ui_bank_system::ui_bank_system;
+pub use ui_bug_report_system::ui_bug_report_system;
+pub use ui_channel_list_system::ui_channel_list_system;
 pub use ui_character_create_system::ui_character_create_system;
 pub use ui_character_info_system::ui_character_info_system;
 pub use ui_character_select_name_tag_system::ui_character_select_name_tag_system;
 pub use ui_character_select_system::ui_character_select_system;
 pub use ui_chatbox_system::ui_chatbox_system;
 pub use ui_clan_system::ui_clan_system;
+pub use ui_clock_system::ui_clock_system;
 pub use ui_create_clan::ui_create_clan_system;
 pub use ui_debug_camera_info_system::ui_debug_camera_info_system;
 pub use ui_debug_client_entity_list_system::ui_debug_client_entity_list_system;
@@ -101,13 +149,18 @@ pub use ui_debug_zone_lighting_system::ui_debug_zone_lighting_system;
 pub use ui_debug_zone_list_system::ui_debug_zone_list_system;
 pub use ui_debug_zone_time_system::ui_debug_zone_time_system;
 pub use ui_drag_and_drop_system::{ui_drag_and_drop_system, UiStateDragAndDrop};
+pub use ui_escape_menu_system::ui_escape_menu_system;
+pub use ui_fishing_system::ui_fishing_system;
 pub use ui_game_menu_system::ui_game_menu_system;
+pub use ui_help_system::ui_help_system;
 pub use ui_hotbar_system::ui_hotbar_system;
+pub use ui_ignore_list_system::ui_ignore_list_system;
 pub use ui_inventory_system::ui_inventory_system;
 pub use ui_item_drop_name_system::ui_item_drop_name_system;
 pub use ui_login_system::ui_login_system;
 pub use ui_message_box_system::ui_message_box_system;
 pub use ui_minimap_system::ui_minimap_system;
+pub use ui_monster_info_system::ui_monster_info_system;
 pub use ui_npc_store_system::ui_npc_store_system;
 pub use ui_number_input_dialog_system::ui_number_input_dialog_system;
 pub use ui_party_option_system::ui_party_option_system;
@@ -115,13 +168,20 @@ pub use ui_party_system::ui_party_system;
 pub use ui_personal_store_system::ui_personal_store_system;
 pub use ui_player_info_system::ui_player_info_system;
 pub use ui_quest_list_system::ui_quest_list_system;
+pub use ui_repair_system::ui_repair_system;
+pub use ui_request_popup_system::ui_request_popup_system;
 pub use ui_respawn_system::ui_respawn_system;
 pub use ui_selected_target_system::ui_selected_target_system;
 pub use ui_server_select_system::ui_server_select_system;
+pub use ui_session_stats_system::ui_session_stats_system;
 pub use ui_settings_system::ui_settings_system;
 pub use ui_skill_list_system::ui_skill_list_system;
 pub use ui_skill_tree_system::ui_skill_tree_system;
 pub use ui_sound_event_system::{ui_sound_event_system, UiSoundEvent};
 pub use ui_status_effects_system::ui_status_effects_system;
+pub use ui_target_camera_system::ui_target_camera_system;
+pub use ui_toast_system::ui_toast_system;
+pub use ui_tutorial_hint_system::ui_tutorial_hint_system;
 pub use ui_window_sound_system::ui_window_sound_system;
+pub use ui_zone_loading_system::ui_zone_loading_system;
 pub use widgets::DataBindings;
@@ -0,0 +1,18 @@
+/// Formats an amount of zuly with thousands separators, e.g. 1234567 as "1,234,567"
+pub fn format_money(value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let mut result = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+
+    if value < 0 {
+        result.push('-');
+    }
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            result.push(',');
+        }
+        result.push(digit);
+    }
+
+    result
+}
@@ -6,14 +6,18 @@ use bevy_egui::egui;
 
 use rose_data::{
     AbilityType, BaseItemData, EquipmentItem, Item, ItemClass, ItemGradeData, ItemType, JobId,
-    SkillAddAbility, SkillData, SkillId, SkillType, StackableItem, StatusEffectType,
+    NpcId, SkillAddAbility, SkillData, SkillId, SkillType, StackableItem, StatusEffectType,
 };
 use rose_game_common::components::{
     AbilityValues, CharacterInfo, Equipment, ExperiencePoints, HealthPoints, Inventory, Level,
     ManaPoints, MoveSpeed, SkillList, SkillPoints, Stamina, StatPoints, Team, UnionMembership,
 };
 
-use crate::{bundles::ability_values_get_value, resources::GameData};
+use crate::{
+    bundles::ability_values_get_value,
+    resources::GameData,
+    ui::{ui_apply_minimum_font_size, ui_scaled_font_id},
+};
 
 const TOOLTIP_MAX_WIDTH: f32 = 300.0;
 
@@ -36,6 +40,13 @@ pub struct PlayerTooltipQuery<'w> {
     pub union_membership: &'w UnionMembership,
 }
 
+fn tooltip_header_font_id(ui: &egui::Ui) -> egui::FontId {
+    ui_scaled_font_id(
+        ui.ctx(),
+        egui::FontId::new(16.0, egui::FontFamily::Name("Ubuntu-M".into())),
+    )
+}
+
 pub fn get_item_name_color(item_type: ItemType, item_data: &BaseItemData) -> egui::Color32 {
     match item_type {
         ItemType::Head
@@ -69,10 +80,7 @@ fn add_equipment_item_name(
                 equipment_item.item.item_type,
                 item_data,
             ))
-            .font(egui::FontId::new(
-                16.0,
-                egui::FontFamily::Name("Ubuntu-M".into()),
-            )),
+            .font(tooltip_header_font_id(ui)),
     ));
 }
 
@@ -87,10 +95,7 @@ fn add_stackable_item_name(
                 stackable_item.item.item_type,
                 item_data,
             ))
-            .font(egui::FontId::new(
-                16.0,
-                egui::FontFamily::Name("Ubuntu-M".into()),
-            )),
+            .font(tooltip_header_font_id(ui)),
     ));
 }
 
@@ -151,6 +156,11 @@ fn add_item_add_ability(ui: &mut egui::Ui, game_data: &GameData, item_data: &Bas
     }
 }
 
+/// Items with hidden stats which have not yet been revealed by appraisal
+pub fn item_requires_appraisal(equipment_item: &EquipmentItem) -> bool {
+    equipment_item.gem != 0 && equipment_item.gem <= 300 && !equipment_item.is_appraised
+}
+
 fn add_equipment_item_add_appraisal(
     ui: &mut egui::Ui,
     game_data: &GameData,
@@ -161,7 +171,8 @@ fn add_equipment_item_add_appraisal(
     }
 
     let is_gem = equipment_item.gem > 300;
-    if !is_gem && !equipment_item.is_appraised {
+    if item_requires_appraisal(equipment_item) {
+        ui.colored_label(egui::Color32::from_rgb(100, 200, 255), "[???]");
         ui.colored_label(
             egui::Color32::RED,
             game_data.client_strings.item_requires_appraisal,
@@ -298,6 +309,7 @@ pub fn ui_add_item_tooltip(
     ui.set_max_width(TOOLTIP_MAX_WIDTH);
     ui.style_mut().visuals.widgets.noninteractive.fg_stroke =
         egui::Stroke::new(1.0, egui::Color32::WHITE);
+    ui_apply_minimum_font_size(ui);
 
     let item_data = game_data.items.get_base_item(item.get_item_reference());
     if item_data.is_none() {
@@ -631,10 +643,7 @@ fn add_skill_name(ui: &mut egui::Ui, game_data: &GameData, skill_data: &SkillDat
     ui.add(egui::Label::new(
         egui::RichText::new(text)
             .color(egui::Color32::YELLOW)
-            .font(egui::FontId::new(
-                16.0,
-                egui::FontFamily::Name("Ubuntu-M".into()),
-            )),
+            .font(tooltip_header_font_id(ui)),
     ));
 }
 
@@ -672,10 +681,7 @@ fn add_skill_next_level<'a>(
             game_data.client_strings.skill_next_level_info, name
         ))
         .color(egui::Color32::YELLOW)
-        .font(egui::FontId::new(
-            16.0,
-            egui::FontFamily::Name("Ubuntu-M".into()),
-        )),
+        .font(tooltip_header_font_id(ui)),
     );
 
     Some(next_level_skill_data)
@@ -1160,6 +1166,7 @@ pub fn ui_add_skill_tooltip(
     ui.set_max_width(TOOLTIP_MAX_WIDTH);
     ui.style_mut().visuals.widgets.noninteractive.fg_stroke =
         egui::Stroke::new(1.0, egui::Color32::WHITE);
+    ui_apply_minimum_font_size(ui);
 
     let skill_data = game_data.skills.get_skill(skill_id);
     if skill_data.is_none() {
@@ -1556,3 +1563,85 @@ pub fn ui_add_skill_tooltip(
         }
     }
 }
+
+pub fn ui_add_monster_tooltip(
+    ui: &mut egui::Ui,
+    game_data: &GameData,
+    npc_id: NpcId,
+    ability_values: &AbilityValues,
+    health_points: &HealthPoints,
+) {
+    ui.set_max_width(TOOLTIP_MAX_WIDTH);
+    ui.style_mut().visuals.widgets.noninteractive.fg_stroke =
+        egui::Stroke::new(1.0, egui::Color32::WHITE);
+    ui_apply_minimum_font_size(ui);
+
+    let npc_data = game_data.npcs.get_npc(npc_id);
+    if npc_data.is_none() {
+        ui.label(format!("Unknown Monster\nNPC ID: {}", npc_id.get()));
+        return;
+    }
+    let npc_data = npc_data.unwrap();
+
+    ui.colored_label(egui::Color32::YELLOW, npc_data.name);
+
+    egui::Grid::new("monster_tooltip_stats")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Level:");
+            ui.label(format!("{}", ability_values.get_level()));
+            ui.end_row();
+
+            ui.label("HP:");
+            ui.label(format!(
+                "{} / {}",
+                health_points.hp,
+                ability_values.get_max_health()
+            ));
+            ui.end_row();
+
+            ui.label("Attack:");
+            ui.label(format!("{}", ability_values.get_attack_power()));
+            ui.end_row();
+
+            ui.label("Defence:");
+            ui.label(format!("{}", ability_values.get_defence()));
+            ui.end_row();
+
+            ui.label("Resistance:");
+            ui.label(format!("{}", ability_values.get_resistance()));
+            ui.end_row();
+
+            ui.label("Damage Type:");
+            if npc_data.is_attack_magic_damage {
+                ui.colored_label(egui::Color32::from_rgb(151, 221, 241), "Magic");
+            } else {
+                ui.label("Physical");
+            }
+            ui.end_row();
+        });
+
+    // Drop tables are only known by the server, so all we can show are the NPC STB values
+    if cfg!(debug_assertions) {
+        ui.separator();
+        egui::Grid::new("monster_tooltip_drops")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("NPC ID:");
+                ui.label(format!("{}", npc_id.get()));
+                ui.end_row();
+
+                ui.label("Drop Table:");
+                ui.label(format!("{}", npc_data.drop_table_index));
+                ui.end_row();
+
+                ui.label("Drop Money Rate:");
+                ui.label(format!("{}%", npc_data.drop_money_rate));
+                ui.end_row();
+
+                ui.label("Drop Item Rate:");
+                ui.label(format!("{}%", npc_data.drop_item_rate));
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::Res;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::resources::AccessibilitySettings;
+
+#[derive(Copy, Clone, Default)]
+struct UiAccessibility {
+    minimum_font_size: f32,
+    high_contrast: bool,
+}
+
+fn get_ui_accessibility(ctx: &egui::Context) -> UiAccessibility {
+    ctx.data(|data| data.get_temp(egui::Id::new("ui_accessibility")))
+        .unwrap_or_default()
+}
+
+/// Copies the accessibility settings into egui memory so that shared widgets
+/// and tooltips can access them without needing extra system parameters.
+pub fn ui_accessibility_system(
+    mut egui_context: EguiContexts,
+    accessibility_settings: Res<AccessibilitySettings>,
+) {
+    egui_context.ctx_mut().data_mut(|data| {
+        data.insert_temp(
+            egui::Id::new("ui_accessibility"),
+            UiAccessibility {
+                minimum_font_size: accessibility_settings.minimum_font_size,
+                high_contrast: accessibility_settings.high_contrast,
+            },
+        )
+    });
+}
+
+pub fn ui_minimum_font_size(ctx: &egui::Context) -> f32 {
+    get_ui_accessibility(ctx).minimum_font_size
+}
+
+pub fn ui_high_contrast(ctx: &egui::Context) -> bool {
+    get_ui_accessibility(ctx).high_contrast
+}
+
+/// Returns the font with its size raised to the minimum font size
+pub fn ui_scaled_font_id(ctx: &egui::Context, mut font_id: egui::FontId) -> egui::FontId {
+    font_id.size = font_id.size.max(ui_minimum_font_size(ctx));
+    font_id
+}
+
+/// Raises all text styles of this ui to the minimum font size
+pub fn ui_apply_minimum_font_size(ui: &mut egui::Ui) {
+    let minimum_font_size = ui_minimum_font_size(ui.ctx());
+    if minimum_font_size <= 0.0 {
+        return;
+    }
+
+    for font_id in ui.style_mut().text_styles.values_mut() {
+        font_id.size = font_id.size.max(minimum_font_size);
+    }
+}
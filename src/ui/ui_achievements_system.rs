@@ -0,0 +1,122 @@
+use bevy::prelude::{Query, Res, ResMut, With};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::{NpcId, ZoneId};
+use rose_game_common::components::Level;
+
+use crate::{
+    components::PlayerCharacter,
+    resources::{CharacterSettings, GameData},
+    systems::{ACHIEVEMENT_LEVEL_MILESTONES, ACHIEVEMENT_ZONE_MILESTONES},
+    ui::UiStateWindows,
+};
+
+fn achievement_row(ui: &mut egui::Ui, name: &str, unlocked: bool, current: usize, target: usize) {
+    if unlocked {
+        ui.colored_label(egui::Color32::from_rgb(255, 220, 120), name);
+    } else {
+        ui.label(name);
+    }
+    ui.add(
+        egui::ProgressBar::new(current.min(target) as f32 / target as f32)
+            .desired_width(120.0)
+            .text(format!("{} / {}", current.min(target), target)),
+    );
+    ui.end_row();
+}
+
+pub fn ui_achievements_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    query_player: Query<&Level, With<PlayerCharacter>>,
+    character_settings: Res<CharacterSettings>,
+    game_data: Res<GameData>,
+) {
+    if !ui_state_windows.achievements_open {
+        return;
+    }
+
+    let Ok(level) = query_player.get_single() else {
+        return;
+    };
+    let achievements = &character_settings.achievements;
+
+    egui::Window::new("Achievements")
+        .open(&mut ui_state_windows.achievements_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.strong("Level");
+            egui::Grid::new("achievements_level_grid")
+                .num_columns(2)
+                .striped(true)
+                .show(ui, |ui| {
+                    for milestone in ACHIEVEMENT_LEVEL_MILESTONES {
+                        achievement_row(
+                            ui,
+                            &format!("Reach level {}", milestone),
+                            achievements
+                                .unlocked
+                                .contains(&format!("level_{}", milestone)),
+                            level.level as usize,
+                            milestone as usize,
+                        );
+                    }
+                });
+
+            ui.separator();
+            ui.strong("Exploration");
+            egui::Grid::new("achievements_zone_grid")
+                .num_columns(2)
+                .striped(true)
+                .show(ui, |ui| {
+                    for milestone in ACHIEVEMENT_ZONE_MILESTONES {
+                        achievement_row(
+                            ui,
+                            &format!("Visit {} zones", milestone),
+                            achievements
+                                .unlocked
+                                .contains(&format!("zones_{}", milestone)),
+                            achievements.visited_zones.len(),
+                            milestone,
+                        );
+                    }
+                });
+            egui::CollapsingHeader::new(format!(
+                "Visited Zones ({})",
+                achievements.visited_zones.len()
+            ))
+            .id_source("achievements_visited_zones")
+            .show(ui, |ui| {
+                for zone_id in achievements.visited_zones.iter() {
+                    ui.label(
+                        ZoneId::new(*zone_id)
+                            .and_then(|zone_id| game_data.zone_list.get_zone(zone_id))
+                            .map_or("?", |zone_data| zone_data.name),
+                    );
+                }
+            });
+
+            ui.separator();
+            ui.strong(format!(
+                "Bosses Defeated ({})",
+                achievements.defeated_bosses.len()
+            ));
+            if achievements.defeated_bosses.is_empty() {
+                ui.label("No bosses defeated.");
+            } else {
+                egui::ScrollArea::vertical()
+                    .id_source("achievements_bosses")
+                    .max_height(150.0)
+                    .show(ui, |ui| {
+                        for npc_id in achievements.defeated_bosses.iter() {
+                            ui.colored_label(
+                                egui::Color32::from_rgb(255, 220, 120),
+                                NpcId::new(*npc_id)
+                                    .and_then(|npc_id| game_data.npcs.get_npc(npc_id))
+                                    .map_or("?", |npc_data| npc_data.name),
+                            );
+                        }
+                    });
+            }
+        });
+}
@@ -0,0 +1,78 @@
+use bevy::{
+    math::Vec3Swizzles,
+    prelude::{Camera, Camera3d, GlobalTransform, Query, Res, Vec2, With, Without},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    components::{MonsterAggro, PlayerCharacter, TargetCamera},
+    resources::InterfaceSettings,
+};
+
+const INDICATOR_EDGE_MARGIN: f32 = 40.0;
+const INDICATOR_SIZE: f32 = 16.0;
+
+pub fn ui_aggro_indicator_system(
+    mut egui_context: EguiContexts,
+    query_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<TargetCamera>)>,
+    query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
+    query_aggro: Query<&GlobalTransform, (With<MonsterAggro>, Without<PlayerCharacter>)>,
+    interface_settings: Res<InterfaceSettings>,
+) {
+    if !interface_settings.aggro_indicator {
+        return;
+    }
+
+    let Ok((camera, camera_transform)) = query_camera.get_single() else {
+        return;
+    };
+    let Ok(player_transform) = query_player.get_single() else {
+        return;
+    };
+
+    let ctx = egui_context.ctx_mut();
+    let screen_rect = ctx.input(|input| input.screen_rect());
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("aggro_indicators"),
+    ));
+
+    let camera_forward = camera_transform.forward().xz().normalize_or_zero();
+    let camera_right = camera_transform.right().xz().normalize_or_zero();
+
+    for global_transform in query_aggro.iter() {
+        if let Some(ndc) = camera.world_to_ndc(camera_transform, global_transform.translation()) {
+            if (0.0..=1.0).contains(&ndc.z) && ndc.x.abs() <= 1.0 && ndc.y.abs() <= 1.0 {
+                // Attacker is already visible on screen
+                continue;
+            }
+        }
+
+        // Direction to the attacker relative to the camera, in screen space where +y is down
+        let offset = (global_transform.translation() - player_transform.translation()).xz();
+        let direction =
+            Vec2::new(offset.dot(camera_right), -offset.dot(camera_forward)).normalize_or_zero();
+        if direction == Vec2::ZERO {
+            continue;
+        }
+
+        // Place the indicator where the direction meets the screen edge
+        let half_size =
+            screen_rect.size() / 2.0 - egui::vec2(INDICATOR_EDGE_MARGIN, INDICATOR_EDGE_MARGIN);
+        let scale = (half_size.x / direction.x.abs()).min(half_size.y / direction.y.abs());
+        let tip = screen_rect.center() + egui::vec2(direction.x, direction.y) * scale;
+
+        let forward = egui::vec2(direction.x, direction.y);
+        let side = egui::vec2(-direction.y, direction.x);
+        let base = tip - forward * INDICATOR_SIZE;
+        painter.add(egui::Shape::convex_polygon(
+            vec![
+                tip,
+                base + side * INDICATOR_SIZE * 0.6,
+                base - side * INDICATOR_SIZE * 0.6,
+            ],
+            egui::Color32::from_rgba_unmultiplied(255, 40, 40, 200),
+            egui::Stroke::new(1.0, egui::Color32::BLACK),
+        ));
+    }
+}
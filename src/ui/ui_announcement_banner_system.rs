@@ -0,0 +1,142 @@
+use std::{collections::VecDeque, time::Duration};
+
+use bevy::prelude::{EventReader, Local, Res, Time};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    events::ChatboxEvent,
+    resources::{IgnoreList, InterfaceSettings, UiResources, UiSprite},
+};
+
+const BANNER_WIDTH: f32 = 600.0;
+const BANNER_HEIGHT: f32 = 32.0;
+const BANNER_OFFSET_Y: f32 = 80.0;
+const BANNER_MAX_QUEUED: usize = 10;
+
+const BANNER_COLOR_ANNOUNCE: egui::Color32 = egui::Color32::from_rgb(255, 188, 172);
+const BANNER_COLOR_SHOUT: egui::Color32 = egui::Color32::from_rgb(189, 250, 255);
+
+struct BannerMessage {
+    text: String,
+    color: egui::Color32,
+}
+
+#[derive(Default)]
+pub struct UiStateAnnouncementBanner {
+    frame_sprite: Option<UiSprite>,
+    queue: VecDeque<BannerMessage>,
+    current: Option<(BannerMessage, Duration)>,
+}
+
+pub fn ui_announcement_banner_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateAnnouncementBanner>,
+    mut chatbox_events: EventReader<ChatboxEvent>,
+    interface_settings: Res<InterfaceSettings>,
+    ignore_list: Res<IgnoreList>,
+    ui_resources: Res<UiResources>,
+    time: Res<Time>,
+) {
+    let ui_state = &mut *ui_state;
+
+    if ui_state.frame_sprite.is_none() {
+        ui_state.frame_sprite = ui_resources.get_sprite(0, "UI13_NPC_SCRIPT_IMAGE_MIDDLE");
+    }
+
+    let Some(banner_duration) = interface_settings.announcement_banner_duration else {
+        chatbox_events.clear();
+        ui_state.queue.clear();
+        ui_state.current = None;
+        return;
+    };
+
+    for event in chatbox_events.iter() {
+        let message = match event {
+            ChatboxEvent::Announce(Some(name), text) => BannerMessage {
+                text: format!("{}> {}", name, text),
+                color: BANNER_COLOR_ANNOUNCE,
+            },
+            ChatboxEvent::Announce(None, text) => BannerMessage {
+                text: text.clone(),
+                color: BANNER_COLOR_ANNOUNCE,
+            },
+            ChatboxEvent::Shout(name, text)
+                if interface_settings.shout_banners && !ignore_list.is_ignored(name) =>
+            {
+                BannerMessage {
+                    text: format!(
+                        "{}> {}",
+                        interface_settings.other_character_name(name),
+                        text
+                    ),
+                    color: BANNER_COLOR_SHOUT,
+                }
+            }
+            _ => continue,
+        };
+
+        // Drop the oldest messages rather than falling further behind during shout spam
+        if ui_state.queue.len() == BANNER_MAX_QUEUED {
+            ui_state.queue.pop_front();
+        }
+        ui_state.queue.push_back(message);
+    }
+
+    let now = time.elapsed();
+    if ui_state.current.as_ref().map_or(true, |(_, started)| {
+        now.saturating_sub(*started) > banner_duration
+    }) {
+        ui_state.current = ui_state.queue.pop_front().map(|message| (message, now));
+    }
+
+    let Some((message, started)) = ui_state.current.as_ref() else {
+        return;
+    };
+    let progress = now.saturating_sub(*started).as_secs_f32() / banner_duration.as_secs_f32();
+
+    let mut dismissed = false;
+    egui::Area::new("announcement_banner")
+        .anchor(egui::Align2::CENTER_TOP, [0.0, BANNER_OFFSET_Y])
+        .order(egui::Order::Foreground)
+        .show(egui_context.ctx_mut(), |ui| {
+            let (rect, response) = ui.allocate_exact_size(
+                egui::vec2(BANNER_WIDTH, BANNER_HEIGHT),
+                egui::Sense::click(),
+            );
+
+            if let Some(frame_sprite) = ui_state.frame_sprite.as_ref() {
+                frame_sprite.draw_stretched(ui, rect);
+            } else {
+                ui.painter().rect_filled(
+                    rect,
+                    4.0,
+                    egui::Color32::from_rgba_unmultiplied(0, 0, 0, 180),
+                );
+            }
+
+            let galley = ui.painter().layout_no_wrap(
+                message.text.clone(),
+                egui::FontId::proportional(16.0),
+                message.color,
+            );
+            let text_rect = rect.shrink2(egui::vec2(8.0, 0.0));
+            let text_y = rect.center().y - galley.size().y / 2.0;
+
+            // Text which does not fit scrolls from right to left over the banner duration
+            let text_x = if galley.size().x <= text_rect.width() {
+                rect.center().x - galley.size().x / 2.0
+            } else {
+                text_rect.right() - progress * (text_rect.width() + galley.size().x)
+            };
+
+            ui.painter()
+                .with_clip_rect(text_rect)
+                .galley(egui::pos2(text_x, text_y), galley);
+
+            dismissed = response.on_hover_text("Click to dismiss").clicked();
+        });
+
+    if dismissed {
+        ui_state.current = None;
+    }
+}
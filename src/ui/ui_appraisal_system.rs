@@ -0,0 +1,166 @@
+use bevy::prelude::{EventReader, EventWriter, Local, Query, Res, ResMut, With};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::{AbilityType, EquipmentItem, Item};
+use rose_game_common::components::{Inventory, InventoryPageType, ItemSlot, INVENTORY_PAGE_SIZE};
+
+use crate::{
+    components::PlayerCharacter,
+    events::{AppraisalEvent, ChatboxEvent},
+    protocol::ExtensionClientMessage,
+    resources::{GameConnection, GameData},
+    ui::{item_requires_appraisal, UiStateWindows},
+};
+
+#[derive(Default)]
+pub struct UiStateAppraisal {
+    pending_item_slot: Option<ItemSlot>,
+    revealed_item: Option<EquipmentItem>,
+}
+
+fn get_equipment_page_item(inventory: &Inventory, index: usize) -> Option<&EquipmentItem> {
+    inventory
+        .get_item(ItemSlot::Inventory(InventoryPageType::Equipment, index))
+        .and_then(|item| item.as_equipment())
+}
+
+pub fn ui_appraisal_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateAppraisal>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut appraisal_events: EventReader<AppraisalEvent>,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    mut query_player: Query<&mut Inventory, With<PlayerCharacter>>,
+    game_connection: Option<Res<GameConnection>>,
+    game_data: Res<GameData>,
+) {
+    let ui_state = &mut *ui_state;
+    let Ok(mut inventory) = query_player.get_single_mut() else {
+        return;
+    };
+
+    for event in appraisal_events.iter() {
+        match *event {
+            AppraisalEvent::Open => {
+                ui_state_windows.appraisal_open = true;
+            }
+            AppraisalEvent::Result { item_slot, success } => {
+                if ui_state.pending_item_slot == Some(item_slot) {
+                    ui_state.pending_item_slot = None;
+                }
+
+                if !success {
+                    chatbox_events.send(ChatboxEvent::System("Appraisal failed.".to_string()));
+                    continue;
+                }
+
+                // The server does not resend the item, so reveal its stats locally
+                if let Some(Item::Equipment(equipment_item)) = inventory.get_item_mut(item_slot) {
+                    equipment_item.is_appraised = true;
+                    ui_state.revealed_item = Some(equipment_item.clone());
+                }
+            }
+        }
+    }
+
+    let inventory = &*inventory;
+
+    if ui_state_windows.appraisal_open {
+        egui::Window::new("Appraisal")
+            .open(&mut ui_state_windows.appraisal_open)
+            .resizable(false)
+            .show(egui_context.ctx_mut(), |ui| {
+                let mut num_unappraised = 0;
+
+                egui::Grid::new("appraisal_items_grid")
+                    .num_columns(2)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for index in 0..INVENTORY_PAGE_SIZE {
+                            let Some(item) = get_equipment_page_item(inventory, index)
+                                .filter(|item| item_requires_appraisal(item))
+                            else {
+                                continue;
+                            };
+                            num_unappraised += 1;
+
+                            ui.label(
+                                game_data
+                                    .items
+                                    .get_base_item(item.item)
+                                    .map_or("?", |item_data| item_data.name),
+                            );
+
+                            let item_slot =
+                                ItemSlot::Inventory(InventoryPageType::Equipment, index);
+                            if ui
+                                .add_enabled(
+                                    ui_state.pending_item_slot.is_none(),
+                                    egui::Button::new("Appraise"),
+                                )
+                                .clicked()
+                            {
+                                if let Some(game_connection) = game_connection.as_ref() {
+                                    game_connection
+                                        .extension_message_tx
+                                        .send(ExtensionClientMessage::AppraisalRequest {
+                                            item_slot,
+                                        })
+                                        .ok();
+                                    ui_state.pending_item_slot = Some(item_slot);
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                if num_unappraised == 0 {
+                    ui.label("You have no items which require appraisal.");
+                }
+            });
+    }
+
+    if let Some(revealed_item) = ui_state.revealed_item.as_ref() {
+        let mut open = true;
+        let mut close_clicked = false;
+
+        egui::Window::new("Appraisal Result")
+            .open(&mut open)
+            .resizable(false)
+            .collapsible(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(egui_context.ctx_mut(), |ui| {
+                if let Some(item_data) = game_data.items.get_base_item(revealed_item.item) {
+                    ui.heading(item_data.name);
+                }
+
+                if let Some(gem_item_data) =
+                    game_data.items.get_gem_item(revealed_item.gem as usize)
+                {
+                    for &(ability_type, value) in gem_item_data.gem_add_ability.iter() {
+                        ui.colored_label(
+                            egui::Color32::from_rgb(100, 200, 255),
+                            format!(
+                                "[{} {}{}]",
+                                game_data.string_database.get_ability_type(ability_type),
+                                value,
+                                if matches!(ability_type, AbilityType::SaveMana) {
+                                    "%"
+                                } else {
+                                    ""
+                                }
+                            ),
+                        );
+                    }
+                }
+
+                if ui.button("OK").clicked() {
+                    close_clicked = true;
+                }
+            });
+
+        if !open || close_clicked {
+            ui_state.revealed_item = None;
+        }
+    }
+}
@@ -98,6 +98,10 @@ fn ui_add_bank_slot(
         )
         .inner;
 
+    if item.is_some() {
+        ui_state_dnd.handle_quick_move(&response, DragAndDropId::Bank(bank_slot_index));
+    }
+
     if let Some(item) = item {
         response.on_hover_ui(|ui| {
             ui_add_item_tooltip(ui, game_data, player_tooltip_data, item);
@@ -0,0 +1,280 @@
+use std::{collections::VecDeque, fmt::Write, thread::JoinHandle};
+
+use bevy::{
+    prelude::{Entity, EventReader, Local, Query, Res, ResMut, With},
+    render::{renderer::RenderAdapterInfo, view::screenshot::ScreenshotManager},
+    window::PrimaryWindow,
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    bug_report::{save_bug_report, upload_bug_report, BugReport},
+    components::{PlayerCharacter, Position},
+    events::ChatboxEvent,
+    log_history::log_history,
+    protocol::packet_history,
+    resources::{BugReportConfiguration, CurrentZone, GameData, InterfaceSettings, ServerProfiles},
+    ui::UiStateWindows,
+};
+
+const CHAT_HISTORY_SIZE: usize = 200;
+
+pub struct UiStateBugReport {
+    description: String,
+    include_network_messages: bool,
+    chat_history: VecDeque<String>,
+    pending_report: Option<BugReport>,
+    screenshot_rx: Option<crossbeam_channel::Receiver<Option<Vec<u8>>>>,
+    submit_thread: Option<JoinHandle<String>>,
+    submit_result: Option<String>,
+}
+
+impl Default for UiStateBugReport {
+    fn default() -> Self {
+        Self {
+            description: String::new(),
+            include_network_messages: true,
+            chat_history: VecDeque::with_capacity(CHAT_HISTORY_SIZE),
+            pending_report: None,
+            screenshot_rx: None,
+            submit_thread: None,
+            submit_result: None,
+        }
+    }
+}
+
+fn format_chatbox_event(event: &ChatboxEvent) -> String {
+    match event {
+        ChatboxEvent::Say(name, text) => format!("{}> {}", name, text),
+        ChatboxEvent::Shout(name, text) => format!("[Shout] {}> {}", name, text),
+        ChatboxEvent::Whisper(name, text) => format!("[Whisper] {}> {}", name, text),
+        ChatboxEvent::Clan(name, text) => format!("[Clan] {}> {}", name, text),
+        ChatboxEvent::Party(name, text) => format!("[Party] {}> {}", name, text),
+        ChatboxEvent::Announce(Some(name), text) => format!("[Announce] {}> {}", name, text),
+        ChatboxEvent::Announce(None, text) => format!("[Announce] {}", text),
+        ChatboxEvent::System(text) => format!("[System] {}", text),
+        ChatboxEvent::Quest(text) => format!("[Quest] {}", text),
+    }
+}
+
+pub fn ui_bug_report_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut ui_state: Local<UiStateBugReport>,
+    mut chatbox_events: EventReader<ChatboxEvent>,
+    screenshot_manager: Option<ResMut<ScreenshotManager>>,
+    bug_report_configuration: Res<BugReportConfiguration>,
+    current_zone: Option<Res<CurrentZone>>,
+    game_data: Res<GameData>,
+    query_player: Query<(&CharacterInfo, &Position), With<PlayerCharacter>>,
+    query_window: Query<Entity, With<PrimaryWindow>>,
+    render_adapter_info: Option<Res<RenderAdapterInfo>>,
+    server_profiles: Res<ServerProfiles>,
+    interface_settings: Res<InterfaceSettings>,
+) {
+    let ui_state = &mut *ui_state;
+
+    for event in chatbox_events.iter() {
+        if ui_state.chat_history.len() >= CHAT_HISTORY_SIZE {
+            ui_state.chat_history.pop_front();
+        }
+
+        ui_state.chat_history.push_back(format!(
+            "{} {}",
+            chrono::Local::now().format("%H:%M:%S"),
+            format_chatbox_event(event)
+        ));
+    }
+
+    if ui_state
+        .submit_thread
+        .as_ref()
+        .map_or(false, |thread| thread.is_finished())
+    {
+        let thread = ui_state.submit_thread.take().unwrap();
+        ui_state.submit_result = Some(
+            thread
+                .join()
+                .unwrap_or_else(|_| "Failed to create bug report".to_string()),
+        );
+    }
+
+    // Wait for the screenshot before submitting the report
+    if let Some(screenshot_rx) = ui_state.screenshot_rx.as_ref() {
+        if let Ok(screenshot_png) = screenshot_rx.try_recv() {
+            ui_state.screenshot_rx = None;
+
+            if let Some(mut report) = ui_state.pending_report.take() {
+                report.screenshot_png = screenshot_png;
+
+                let upload_url = bug_report_configuration.upload_url.clone();
+                let directory = bug_report_configuration.directory.clone();
+                ui_state.submit_thread = Some(std::thread::spawn(move || {
+                    if let Some(upload_url) = upload_url {
+                        match upload_bug_report(&upload_url, &report) {
+                            Ok(_) => return "Bug report sent, thank you!".to_string(),
+                            Err(error) => {
+                                log::error!("Failed to upload bug report: {}", error)
+                            }
+                        }
+                    }
+
+                    match save_bug_report(&directory, &report) {
+                        Ok(path) => format!("Bug report saved to {}", path.to_string_lossy()),
+                        Err(error) => format!("Failed to save bug report: {}", error),
+                    }
+                }));
+            }
+        }
+    }
+
+    let submitting = ui_state.pending_report.is_some() || ui_state.submit_thread.is_some();
+    let mut submit = false;
+
+    egui::Window::new("Report Bug")
+        .open(&mut ui_state_windows.bug_report_open)
+        .resizable(false)
+        .default_width(350.0)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.label("Please describe the problem and what you were doing when it happened:");
+            ui.add_enabled(
+                !submitting,
+                egui::TextEdit::multiline(&mut ui_state.description)
+                    .desired_rows(6)
+                    .desired_width(f32::INFINITY),
+            );
+            ui.add_enabled(
+                !submitting,
+                egui::Checkbox::new(
+                    &mut ui_state.include_network_messages,
+                    "Include recent network messages",
+                ),
+            );
+            ui.label("A screenshot, your current zone and position will also be included.");
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(
+                        !submitting && !ui_state.description.trim().is_empty(),
+                        egui::Button::new(if bug_report_configuration.upload_url.is_some() {
+                            "Send Report"
+                        } else {
+                            "Save Report"
+                        }),
+                    )
+                    .clicked()
+                {
+                    submit = true;
+                }
+
+                if submitting {
+                    ui.spinner();
+                } else if let Some(result) = ui_state.submit_result.as_ref() {
+                    ui.label(result);
+                }
+            });
+        });
+
+    if !submit {
+        return;
+    }
+
+    let mut snapshot = String::new();
+    writeln!(snapshot, "Client Version: {}", env!("CARGO_PKG_VERSION")).ok();
+    writeln!(snapshot, "Time: {}", chrono::Local::now().to_rfc3339()).ok();
+
+    if let Some(render_adapter_info) = render_adapter_info.as_ref() {
+        writeln!(
+            snapshot,
+            "Renderer: {} ({:?})",
+            render_adapter_info.name, render_adapter_info.backend
+        )
+        .ok();
+    }
+
+    if let Some(server_profile) = server_profiles.selected() {
+        if interface_settings.streamer_mode {
+            writeln!(
+                snapshot,
+                "Server: {} ({})",
+                server_profile.name, server_profile.network_version
+            )
+            .ok();
+        } else {
+            writeln!(
+                snapshot,
+                "Server: {} ({}:{}, {})",
+                server_profile.name,
+                server_profile.ip,
+                server_profile.port,
+                server_profile.network_version
+            )
+            .ok();
+        }
+    }
+
+    if let Some(current_zone) = current_zone.as_ref() {
+        writeln!(
+            snapshot,
+            "Zone: {} ({})",
+            current_zone.id.get(),
+            game_data
+                .zone_list
+                .get_zone(current_zone.id)
+                .map_or("unknown", |zone_data| zone_data.name)
+        )
+        .ok();
+    }
+
+    if let Ok((character_info, position)) = query_player.get_single() {
+        writeln!(snapshot, "Character: {}", character_info.name).ok();
+        writeln!(
+            snapshot,
+            "Position: {:.2}, {:.2}, {:.2}",
+            position.position.x, position.position.y, position.position.z
+        )
+        .ok();
+    }
+
+    ui_state.submit_result = None;
+    ui_state.pending_report = Some(BugReport {
+        description: std::mem::take(&mut ui_state.description),
+        snapshot,
+        chat_history: ui_state.chat_history.iter().cloned().collect(),
+        log_history: log_history(),
+        packet_history: ui_state.include_network_messages.then(packet_history),
+        screenshot_png: None,
+    });
+
+    let (screenshot_tx, screenshot_rx) = crossbeam_channel::bounded(1);
+    ui_state.screenshot_rx = Some(screenshot_rx);
+
+    let window = query_window.get_single().ok();
+    let requested_screenshot =
+        window
+            .zip(screenshot_manager)
+            .map_or(false, |(window, mut screenshot_manager)| {
+                let screenshot_tx = screenshot_tx.clone();
+                screenshot_manager
+                    .take_screenshot(window, move |image| {
+                        let screenshot_png = image.try_into_dynamic().ok().and_then(|image| {
+                            let mut png = std::io::Cursor::new(Vec::new());
+                            image
+                                .to_rgb8()
+                                .write_to(&mut png, image::ImageOutputFormat::Png)
+                                .ok()
+                                .map(|_| png.into_inner())
+                        });
+                        screenshot_tx.send(screenshot_png).ok();
+                    })
+                    .is_ok()
+            });
+
+    if !requested_screenshot {
+        screenshot_tx.send(None).ok();
+    }
+}
@@ -0,0 +1,114 @@
+use bevy::{
+    prelude::{Local, Query, Res, ResMut, With},
+    time::Time,
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::{components::CharacterInfo, messages::client::ClientMessage};
+
+use crate::{
+    components::PlayerCharacter,
+    resources::{ChannelSwitch, LoginConnection, ServerList},
+    ui::UiStateWindows,
+};
+
+#[derive(Default)]
+pub struct UiStateChannelList {
+    was_open: bool,
+}
+
+pub fn ui_channel_list_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateChannelList>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut channel_switch: ResMut<ChannelSwitch>,
+    login_connection: Option<Res<LoginConnection>>,
+    server_list: Option<Res<ServerList>>,
+    query_player: Query<&CharacterInfo, With<PlayerCharacter>>,
+    time: Res<Time>,
+) {
+    let Some((server_id, current_channel_id)) = channel_switch.current else {
+        ui_state_windows.channel_list_open = false;
+        return;
+    };
+
+    if ui_state_windows.channel_list_open && !ui_state.was_open {
+        // Refresh the channel list each time the window is opened
+        if let Some(login_connection) = login_connection.as_ref() {
+            login_connection
+                .client_message_tx
+                .send(ClientMessage::GetChannelList { server_id })
+                .ok();
+        }
+    }
+    ui_state.was_open = ui_state_windows.channel_list_open;
+
+    let world_server = server_list.as_ref().and_then(|server_list| {
+        server_list
+            .world_servers
+            .iter()
+            .find(|world_server| world_server.id == server_id)
+    });
+    let mut join_channel_id = None;
+
+    egui::Window::new("Change Channel")
+        .open(&mut ui_state_windows.channel_list_open)
+        .collapsible(false)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            if login_connection.is_none() {
+                ui.label("Lost connection to the login server, relog to change channel.");
+                return;
+            }
+
+            if channel_switch.is_switching() {
+                ui.label("Changing channel...");
+                return;
+            }
+
+            let Some(world_server) = world_server else {
+                ui.label("Waiting for channel list...");
+                return;
+            };
+
+            ui.label(&world_server.name);
+            ui.separator();
+
+            egui::Grid::new("channel_list_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    for game_server in world_server.game_servers.iter() {
+                        ui.label(&game_server.name);
+
+                        if game_server.id == current_channel_id {
+                            ui.label("Current");
+                        } else if ui.button("Join").clicked() {
+                            join_channel_id = Some(game_server.id);
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+
+    if let Some(channel_id) = join_channel_id {
+        let (Some(login_connection), Ok(character_info)) =
+            (login_connection.as_ref(), query_player.get_single())
+        else {
+            return;
+        };
+
+        login_connection
+            .client_message_tx
+            .send(ClientMessage::JoinServer {
+                server_id,
+                channel_id,
+            })
+            .ok();
+        channel_switch.start(
+            server_id,
+            channel_id,
+            character_info.name.clone(),
+            time.elapsed(),
+        );
+    }
+}
@@ -6,15 +6,17 @@ use bevy_egui::{egui, EguiContexts};
 
 use rose_game_common::{
     components::{
-        AbilityValues, BasicStatType, BasicStats, CharacterInfo, ExperiencePoints, Level,
-        MoveSpeed, Stamina, StatPoints, MAX_STAMINA,
+        AbilityValues, BasicStatType, BasicStats, CharacterInfo, Equipment, ExperiencePoints,
+        Level, MoveMode, MoveSpeed, SkillList, Stamina, StatPoints, StatusEffects, UnionMembership,
+        MAX_STAMINA,
     },
     messages::client::ClientMessage,
 };
 
 use crate::{
-    components::PlayerCharacter,
-    resources::{GameConnection, GameData, UiResources},
+    components::{ClanMembership, PlayerCharacter},
+    protocol::ExtensionClientMessage,
+    resources::{CharacterTitles, GameConnection, GameData, UiResources},
     ui::{
         widgets::{DataBindings, Dialog, DrawText},
         UiSoundEvent, UiStateWindows,
@@ -58,11 +60,116 @@ pub struct PlayerQuery<'w> {
     ability_values: &'w AbilityValues,
     basic_stats: &'w BasicStats,
     character_info: &'w CharacterInfo,
+    equipment: &'w Equipment,
     experience_points: &'w ExperiencePoints,
     level: &'w Level,
     move_speed: &'w MoveSpeed,
+    skill_list: &'w SkillList,
     stamina: &'w Stamina,
     stat_points: &'w StatPoints,
+    status_effects: &'w StatusEffects,
+    union_membership: &'w UnionMembership,
+    clan_membership: Option<&'w ClanMembership>,
+}
+
+/// Ability values calculated with each source of bonuses added in turn, the difference between
+/// consecutive steps is the contribution of that source.
+struct AbilityValuesBreakdown {
+    base: AbilityValues,
+    equipment: AbilityValues,
+    passive_skills: AbilityValues,
+    total: AbilityValues,
+}
+
+impl AbilityValuesBreakdown {
+    fn new(game_data: &GameData, player: &PlayerQueryItem) -> Self {
+        let calculator = &game_data.ability_value_calculator;
+        let no_equipment = Equipment::default();
+        let no_skills = SkillList::default();
+        let no_status_effects = StatusEffects::default();
+
+        Self {
+            base: calculator.calculate(
+                player.character_info,
+                player.level,
+                &no_equipment,
+                player.basic_stats,
+                &no_skills,
+                &no_status_effects,
+            ),
+            equipment: calculator.calculate(
+                player.character_info,
+                player.level,
+                player.equipment,
+                player.basic_stats,
+                &no_skills,
+                &no_status_effects,
+            ),
+            passive_skills: calculator.calculate(
+                player.character_info,
+                player.level,
+                player.equipment,
+                player.basic_stats,
+                player.skill_list,
+                &no_status_effects,
+            ),
+            total: calculator.calculate(
+                player.character_info,
+                player.level,
+                player.equipment,
+                player.basic_stats,
+                player.skill_list,
+                player.status_effects,
+            ),
+        }
+    }
+}
+
+fn get_run_speed(ability_values: &AbilityValues) -> i32 {
+    ability_values.get_move_speed(&MoveMode::Run) as i32
+}
+
+fn ui_add_ability_breakdown(
+    response: egui::Response,
+    breakdown: &AbilityValuesBreakdown,
+    get_value: fn(&AbilityValues) -> i32,
+) {
+    let base = get_value(&breakdown.base);
+    let equipment = get_value(&breakdown.equipment);
+    let passive_skills = get_value(&breakdown.passive_skills);
+    let total = get_value(&breakdown.total);
+
+    response.on_hover_ui(|ui| {
+        let add_row = |ui: &mut egui::Ui, label: &str, value: i32| {
+            ui.label(label);
+            match value.cmp(&0) {
+                std::cmp::Ordering::Greater => {
+                    ui.colored_label(egui::Color32::GREEN, format!("+{}", value))
+                }
+                std::cmp::Ordering::Less => {
+                    ui.colored_label(egui::Color32::RED, format!("{}", value))
+                }
+                std::cmp::Ordering::Equal => ui.label("0"),
+            };
+            ui.end_row();
+        };
+
+        egui::Grid::new("ability_breakdown_grid")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Base");
+                ui.label(format!("{}", base));
+                ui.end_row();
+
+                add_row(ui, "Equipment", equipment - base);
+                add_row(ui, "Passive Skills", passive_skills - equipment);
+                add_row(ui, "Buffs", total - passive_skills);
+
+                ui.strong("Total");
+                ui.strong(format!("{}", total));
+                ui.end_row();
+            });
+    });
 }
 
 pub fn ui_character_info_system(
@@ -75,6 +182,7 @@ pub fn ui_character_info_system(
     dialog_assets: Res<Assets<Dialog>>,
     game_connection: Option<Res<GameConnection>>,
     game_data: Res<GameData>,
+    mut character_titles: ResMut<CharacterTitles>,
 ) {
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_character_info) {
         dialog
@@ -96,6 +204,7 @@ pub fn ui_character_info_system(
     let mut response_raise_con_button = None;
     let mut response_raise_cha_button = None;
     let mut response_raise_sen_button = None;
+    let mut selected_title = None;
 
     egui::Window::new("Character Info")
         .frame(egui::Frame::none())
@@ -109,6 +218,8 @@ pub fn ui_character_info_system(
                 .ability_value_calculator
                 .calculate_levelup_require_xp(player.level.level);
             let stamina = player.stamina.stamina as f32 / MAX_STAMINA as f32;
+            let breakdown = (ui_state.current_tab == IID_TAB_ABILITY)
+                .then(|| AbilityValuesBreakdown::new(&game_data, &player));
 
             dialog.draw(
                 ui,
@@ -133,14 +244,70 @@ pub fn ui_character_info_system(
                 },
                 |ui, bindings| match bindings.get_tab(IID_TABBEDPANE) {
                     Some(&mut IID_TAB_BASICINFO) => {
-                        ui.add_label_at(egui::pos2(59.0, 67.0), &player.character_info.name);
-                        ui.add_label_at(
+                        let name_response =
+                            ui.add_label_at(egui::pos2(59.0, 67.0), &player.character_info.name);
+                        let title_rect = egui::Rect::from_min_max(
+                            name_response.rect.right_top() + egui::vec2(6.0, 0.0),
+                            egui::pos2(
+                                ui.min_rect().left() + dialog.width - 16.0,
+                                name_response.rect.bottom(),
+                            ),
+                        );
+                        let active_title = character_titles.active_title().unwrap_or("No Title");
+                        if character_titles.can_change && !character_titles.titles.is_empty() {
+                            ui.put(title_rect, |ui: &mut egui::Ui| {
+                                egui::ComboBox::from_id_source("character_info_title")
+                                    .width(title_rect.width())
+                                    .selected_text(active_title)
+                                    .show_ui(ui, |ui| {
+                                        if ui
+                                            .selectable_label(
+                                                character_titles.active.is_none(),
+                                                "No Title",
+                                            )
+                                            .clicked()
+                                        {
+                                            selected_title = Some(None);
+                                        }
+
+                                        for (index, title) in
+                                            character_titles.titles.iter().enumerate()
+                                        {
+                                            if ui
+                                                .selectable_label(
+                                                    character_titles.active == Some(index),
+                                                    title,
+                                                )
+                                                .clicked()
+                                            {
+                                                selected_title = Some(Some(index));
+                                            }
+                                        }
+                                    })
+                                    .response
+                            });
+                        } else if character_titles.active.is_some() {
+                            ui.put(
+                                title_rect,
+                                egui::Label::new(
+                                    egui::RichText::new(active_title)
+                                        .color(egui::Color32::from_rgb(255, 220, 120)),
+                                ),
+                            );
+                        }
+                        let job_response = ui.add_label_at(
                             egui::pos2(59.0, 88.0),
                             game_data
                                 .string_database
                                 .get_job_name(player.character_info.job),
                         );
-                        // ui.add_label_at(egui::pos2(59.0, 109.0), ""); // TODO: Clan name
+                        if let Some(job_icon) = ui_resources.get_job_icon(player.character_info.job)
+                        {
+                            job_icon.draw(ui, job_response.rect.right_top() + egui::vec2(4.0, 0.0));
+                        }
+                        if let Some(clan_membership) = player.clan_membership {
+                            ui.add_label_at(egui::pos2(59.0, 109.0), &clan_membership.name);
+                        }
                         ui.add_label_at(
                             egui::pos2(59.0, 172.0),
                             &format!("{}", player.level.level),
@@ -151,69 +318,174 @@ pub fn ui_character_info_system(
                         );
                     }
                     Some(&mut IID_TAB_ABILITY) => {
-                        ui.add_label_at(
+                        let response = ui.add_label_at(
                             egui::pos2(58.0, 67.0),
                             &format!("{}", player.ability_values.get_strength()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(
+                                response,
+                                breakdown,
+                                AbilityValues::get_strength,
+                            );
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(58.0, 88.0),
                             &format!("{}", player.ability_values.get_dexterity()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(
+                                response,
+                                breakdown,
+                                AbilityValues::get_dexterity,
+                            );
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(58.0, 109.0),
                             &format!("{}", player.ability_values.get_intelligence()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(
+                                response,
+                                breakdown,
+                                AbilityValues::get_intelligence,
+                            );
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(58.0, 130.0),
                             &format!("{}", player.ability_values.get_concentration()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(
+                                response,
+                                breakdown,
+                                AbilityValues::get_concentration,
+                            );
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(58.0, 151.0),
                             &format!("{}", player.ability_values.get_charm()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(response, breakdown, AbilityValues::get_charm);
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(58.0, 172.0),
                             &format!("{}", player.ability_values.get_sense()),
                         );
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(response, breakdown, AbilityValues::get_sense);
+                        }
                         ui.add_label_at(
                             egui::pos2(69.0, 211.0),
                             &format!("{}", player.stat_points.points),
                         );
 
-                        ui.add_label_at(
+                        let response = ui.add_label_at(
                             egui::pos2(171.0, 67.0),
                             &format!("{}", player.ability_values.get_attack_power()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(
+                                response,
+                                breakdown,
+                                AbilityValues::get_attack_power,
+                            );
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(171.0, 88.0),
                             &format!("{}", player.ability_values.get_defence()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(
+                                response,
+                                breakdown,
+                                AbilityValues::get_defence,
+                            );
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(171.0, 109.0),
                             &format!("{}", player.ability_values.get_resistance()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(
+                                response,
+                                breakdown,
+                                AbilityValues::get_resistance,
+                            );
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(171.0, 130.0),
                             &format!("{}", player.ability_values.get_hit()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(response, breakdown, AbilityValues::get_hit);
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(171.0, 151.0),
                             &format!("{}", player.ability_values.get_critical()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(
+                                response,
+                                breakdown,
+                                AbilityValues::get_critical,
+                            );
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(171.0, 172.0),
                             &format!("{}", player.ability_values.get_avoid()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(response, breakdown, AbilityValues::get_avoid);
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(171.0, 193.0),
                             &format!("{}", player.ability_values.get_attack_speed()),
                         );
-                        ui.add_label_at(
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(
+                                response,
+                                breakdown,
+                                AbilityValues::get_attack_speed,
+                            );
+                        }
+                        let response = ui.add_label_at(
                             egui::pos2(171.0, 214.0),
                             &format!("{}", player.move_speed.speed),
                         );
+                        if let Some(breakdown) = breakdown.as_ref() {
+                            ui_add_ability_breakdown(response, breakdown, get_run_speed);
+                        }
+                    }
+                    Some(&mut IID_TAB_UNION) => {
+                        ui.add_label_at(
+                            egui::pos2(59.0, 67.0),
+                            &player
+                                .union_membership
+                                .current_union
+                                .map_or_else(|| "None".to_string(), |x| format!("Union {}", x)),
+                        );
+
+                        for (index, points) in player.union_membership.points.iter().enumerate() {
+                            let is_current_union = player
+                                .union_membership
+                                .current_union
+                                .map_or(false, |x| x.get() == index + 1);
+                            let column = (index / 5) as f32;
+                            let row = (index % 5) as f32;
+
+                            ui.add_label_at(
+                                egui::pos2(20.0 + column * 105.0, 109.0 + row * 21.0),
+                                egui::RichText::new(format!("Union {}: {}", index + 1, points))
+                                    .color(if is_current_union {
+                                        egui::Color32::YELLOW
+                                    } else {
+                                        egui::Color32::WHITE
+                                    }),
+                            );
+                        }
                     }
-                    Some(&mut IID_TAB_UNION) => {}
                     _ => {}
                 },
             );
@@ -223,6 +495,18 @@ pub fn ui_character_info_system(
         ui_state_windows.character_info_open = false;
     }
 
+    if let Some(index) = selected_title {
+        if index != character_titles.active {
+            if let Some(game_connection) = game_connection.as_ref() {
+                character_titles.active = index;
+                game_connection
+                    .extension_message_tx
+                    .send(ExtensionClientMessage::SetActiveTitle { index })
+                    .ok();
+            }
+        }
+    }
+
     let stat_button_response = |basic_stat_type: BasicStatType,
                                 response: Option<egui::Response>| {
         if let Some(response) = response {
@@ -1,15 +1,63 @@
 use bevy::prelude::{Camera, Camera3d, GlobalTransform, Query, Res, Vec3, With};
 use bevy_egui::{egui, EguiContexts};
 
-use crate::resources::{CharacterList, CharacterSelectState, GameData};
+use crate::{
+    resources::{CharacterList, CharacterSelectState, GameData},
+    systems::CharacterSelectHover,
+};
 
 pub fn ui_character_select_name_tag_system(
     mut egui_context: EguiContexts,
     query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
     character_list: Option<Res<CharacterList>>,
+    character_select_hover: Option<Res<CharacterSelectHover>>,
     character_select_state: Res<CharacterSelectState>,
     game_data: Res<GameData>,
 ) {
+    if let Some(hovered_character) = character_select_hover
+        .as_ref()
+        .and_then(|character_select_hover| character_select_hover.index)
+        .and_then(|index| {
+            character_list
+                .as_ref()
+                .and_then(|character_list| character_list.characters.get(index))
+        })
+    {
+        let ctx = egui_context.ctx_mut();
+        if let Some(pointer_pos) = ctx.input(|input| input.pointer.hover_pos()) {
+            egui::containers::popup::show_tooltip_at(
+                ctx,
+                egui::Id::new("hovered_character_equipment"),
+                Some(pointer_pos + egui::vec2(16.0, 16.0)),
+                |ui| {
+                    ui.label(egui::RichText::new("Equipment").color(egui::Color32::YELLOW));
+
+                    let mut has_equipment = false;
+                    for equipment_item in hovered_character
+                        .equipment
+                        .equipped_items
+                        .values()
+                        .filter_map(|equipment_item| equipment_item.as_ref())
+                    {
+                        if let Some(item_data) = game_data.items.get_base_item(equipment_item.item)
+                        {
+                            has_equipment = true;
+                            if equipment_item.grade > 0 {
+                                ui.label(format!("{} ({})", item_data.name, equipment_item.grade));
+                            } else {
+                                ui.label(item_data.name);
+                            }
+                        }
+                    }
+
+                    if !has_equipment {
+                        ui.label("Nothing equipped");
+                    }
+                },
+            );
+        }
+    }
+
     for (camera, camera_transform) in query_camera.iter() {
         if let CharacterSelectState::CharacterSelect(Some(index)) = *character_select_state {
             if let Some(selected_character) = character_list
@@ -51,11 +99,26 @@ pub fn ui_character_select_name_tag_system(
                                     .get_job_name(selected_character.info.job)
                             ));
 
+                            if let Some(zone_data) = game_data
+                                .zone_list
+                                .get_zone(selected_character.info.revive_zone_id)
+                            {
+                                ui.label(format!("Location: {}", zone_data.name));
+                            }
+
                             if let Some(delete_time) = selected_character.delete_time.as_ref() {
                                 let duration = delete_time.get_time_until_delete();
                                 let seconds = duration.as_secs() % 60;
                                 let minutes = (duration.as_secs() / 60) % 60;
-                                ui.label(format!("Deleted in {:02}m {:02}s", minutes, seconds));
+                                let hours = duration.as_secs() / 3600;
+                                if hours > 0 {
+                                    ui.label(format!(
+                                        "Deleted in {}h {:02}m {:02}s",
+                                        hours, minutes, seconds
+                                    ));
+                                } else {
+                                    ui.label(format!("Deleted in {:02}m {:02}s", minutes, seconds));
+                                }
                             }
                         },
                     );
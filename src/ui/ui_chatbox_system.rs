@@ -1,12 +1,32 @@
-use bevy::prelude::{Assets, EventReader, EventWriter, Local, Res};
-use bevy_egui::{egui, EguiContexts};
+use std::{collections::VecDeque, time::Duration};
 
-use rose_game_common::messages::client::ClientMessage;
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::{
+        Assets, Commands, EventReader, EventWriter, Events, Local, Query, Res, ResMut, Time, World,
+    },
+};
+use bevy_egui::{
+    egui,
+    egui::{
+        epaint::text::cursor::{CCursor, Cursor, PCursor, RCursor},
+        text_edit::CursorRange,
+    },
+    EguiContexts,
+};
+
+use rose_game_common::{components::CharacterInfo, messages::client::ClientMessage};
 
 use crate::{
-    events::ChatboxEvent,
-    resources::{GameConnection, UiResources},
+    components::{PlayerCharacter, Position},
+    events::{ChatboxEvent, FishingEvent, PlayerCommandEvent},
+    protocol::ExtensionClientMessage,
+    resources::{
+        AwayState, CharacterSettings, ChatChannelFilter, ChatMacro, GameConnection, HudSettings,
+        HudWidget, IgnoreList, InterfaceSettings, MapPings, UiResources, Unstick, WorldConnection,
+    },
     ui::{
+        ui_minimum_font_size,
         widgets::{DataBindings, Dialog},
         UiSoundEvent,
     },
@@ -14,6 +34,10 @@ use crate::{
 
 const MAX_CHATBOX_ENTRIES: usize = 100;
 
+// Outgoing chat is rate limited to stay within the server flood protection
+const CHAT_RATE_LIMIT_MESSAGES: usize = 5;
+const CHAT_RATE_LIMIT_PERIOD: Duration = Duration::from_secs(5);
+
 // TODO: Implement the chat filters
 // const IID_BTN_FILTER: i32 = 10;
 const IID_EDITBOX: i32 = 15;
@@ -46,22 +70,221 @@ const IID_BTN_PARTY: i32 = 54;
 const IID_BTN_CLAN: i32 = 55;
 const IID_BTN_ALLIED: i32 = 56;
 
+fn chat_channel_filter_to_iid(filter: ChatChannelFilter) -> i32 {
+    match filter {
+        ChatChannelFilter::All => IID_BTN_ALL,
+        ChatChannelFilter::Whisper => IID_BTN_WHISPER,
+        ChatChannelFilter::Trade => IID_BTN_TRADE,
+        ChatChannelFilter::Party => IID_BTN_PARTY,
+        ChatChannelFilter::Clan => IID_BTN_CLAN,
+        ChatChannelFilter::Allied => IID_BTN_ALLIED,
+    }
+}
+
+fn chat_channel_filter_from_iid(iid: i32) -> ChatChannelFilter {
+    match iid {
+        IID_BTN_WHISPER => ChatChannelFilter::Whisper,
+        IID_BTN_TRADE => ChatChannelFilter::Trade,
+        IID_BTN_PARTY => ChatChannelFilter::Party,
+        IID_BTN_CLAN => ChatChannelFilter::Clan,
+        IID_BTN_ALLIED => ChatChannelFilter::Allied,
+        _ => ChatChannelFilter::All,
+    }
+}
+
 const CHAT_COLOR_TIMESTAMP: egui::Color32 = egui::Color32::from_rgb(150, 150, 150);
 const CHAT_COLOR_NORMAL: egui::Color32 = egui::Color32::from_rgb(255, 255, 255);
 const CHAT_COLOR_SHOUT: egui::Color32 = egui::Color32::from_rgb(189, 250, 255);
 const CHAT_COLOR_WHISPER: egui::Color32 = egui::Color32::from_rgb(201, 255, 144);
+const CHAT_COLOR_WHISPER_HIDDEN: egui::Color32 = egui::Color32::from_rgb(60, 70, 50);
 const CHAT_COLOR_ANNOUNCE: egui::Color32 = egui::Color32::from_rgb(255, 188, 172);
 const CHAT_COLOR_PARTY: egui::Color32 = egui::Color32::from_rgb(255, 237, 140);
 const CHAT_COLOR_SYSTEM: egui::Color32 = egui::Color32::from_rgb(255, 224, 229);
 const CHAT_COLOR_QUEST: egui::Color32 = egui::Color32::from_rgb(151, 221, 241);
 const CHAT_COLOR_ALLIED: egui::Color32 = egui::Color32::from_rgb(255, 228, 122);
 const CHAT_COLOR_CLAN: egui::Color32 = egui::Color32::from_rgb(255, 228, 122);
+const CHAT_COLOR_PING_LINK: egui::Color32 = egui::Color32::from_rgb(255, 216, 50);
+
+struct ChatCommand {
+    name: &'static str,
+    arguments: &'static str,
+    description: &'static str,
+    /// Handled by the server for GMs, only suggested in debug builds
+    gm_only: bool,
+}
+
+impl ChatCommand {
+    const fn new(name: &'static str, arguments: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            arguments,
+            description,
+            gm_only: false,
+        }
+    }
+
+    const fn gm(name: &'static str, arguments: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            arguments,
+            description,
+            gm_only: true,
+        }
+    }
+}
+
+const CHAT_COMMANDS: &[ChatCommand] = &[
+    ChatCommand::new(
+        "/afk",
+        "[message]",
+        "Auto-reply to whispers until you return",
+    ),
+    ChatCommand::new("/fish", "", "Cast your fishing rod"),
+    ChatCommand::new("/ignore", "<name>", "Hide chat messages from a player"),
+    ChatCommand::gm("/item", "<item type> <item id> [quantity]", "Spawn an item"),
+    ChatCommand::gm("/level", "<level>", "Set your character level"),
+    ChatCommand::new("/m", "<name>", "Send a saved chat macro"),
+    ChatCommand::new(
+        "/macro",
+        "<name> [text]",
+        "Save a chat macro for this character, without text it is removed",
+    ),
+    ChatCommand::gm("/mm", "<zone id> [x] [y]", "Teleport to a zone"),
+    ChatCommand::gm("/money", "<amount>", "Add money to your inventory"),
+    ChatCommand::new("/rest", "", "Sit down to recover faster"),
+    ChatCommand::new("/sit", "", "Sit down to recover faster"),
+    ChatCommand::gm("/speed", "<speed>", "Set your movement speed"),
+    ChatCommand::new(
+        "/unignore",
+        "<name>",
+        "Show chat messages from a player again",
+    ),
+    ChatCommand::new("/unstick", "", "Move to a nearby position when stuck"),
+    ChatCommand::new("/w", "<name> <message>", "Whisper to a player"),
+    ChatCommand::gm("/where", "", "Show your current zone and position"),
+];
+
+fn matching_chat_commands(command: &str) -> impl Iterator<Item = &'static ChatCommand> + '_ {
+    CHAT_COMMANDS.iter().filter(move |chat_command| {
+        (!chat_command.gm_only || cfg!(debug_assertions)) && chat_command.name.starts_with(command)
+    })
+}
+
+/// Splits a whisper being typed as `/w name` or `@name` into the command and partial name
+fn split_whisper_name(text: &str) -> Option<(&str, &str)> {
+    let (command, name) = if let Some(name) = text.strip_prefix('@') {
+        ("@", name)
+    } else if text
+        .get(..3)
+        .map_or(false, |command| command.eq_ignore_ascii_case("/w "))
+    {
+        text.split_at(3)
+    } else {
+        return None;
+    };
+
+    (!name.contains(' ')).then_some((command, name))
+}
+
+struct NameCompletion {
+    command: String,
+    prefix: String,
+    index: usize,
+    completed_text: String,
+}
+
+/// Completes the command or whisper target name being typed, pressing tab again after
+/// completing a name cycles through the other matching names ordered by distance.
+fn tab_complete_chat_text(
+    text: &str,
+    nearby_names: impl FnOnce() -> Vec<String>,
+    name_completion: &mut Option<NameCompletion>,
+) -> Option<String> {
+    let name_target = match name_completion.take() {
+        Some(completion) if completion.completed_text == text => {
+            Some((completion.command, completion.prefix, completion.index + 1))
+        }
+        _ => split_whisper_name(text)
+            .map(|(command, name)| (command.to_string(), name.to_lowercase(), 0)),
+    };
+
+    if let Some((command, prefix, index)) = name_target {
+        let matches: Vec<String> = nearby_names()
+            .into_iter()
+            .filter(|name| name.to_lowercase().starts_with(&prefix))
+            .collect();
+        if matches.is_empty() {
+            return None;
+        }
+
+        let completed_text = format!("{}{} ", command, matches[index % matches.len()]);
+        *name_completion = Some(NameCompletion {
+            command,
+            prefix,
+            index,
+            completed_text: completed_text.clone(),
+        });
+        return Some(completed_text);
+    }
+
+    if !text.starts_with('/') || text.contains(' ') {
+        return None;
+    }
+
+    let command = text.to_lowercase();
+    let mut matches = matching_chat_commands(&command);
+    let first = matches.next()?;
+    let mut common_prefix = first.name;
+    let mut num_matches = 1;
+    for chat_command in matches {
+        num_matches += 1;
+        let common_length = common_prefix
+            .bytes()
+            .zip(chat_command.name.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        common_prefix = &common_prefix[..common_length];
+    }
+
+    if num_matches == 1 {
+        Some(format!("{} ", first.name))
+    } else if common_prefix.len() > text.len() {
+        Some(common_prefix.to_string())
+    } else {
+        None
+    }
+}
+
+fn move_cursor_to_end(response: &egui::Response, text_length: usize) {
+    if let Some(mut state) = egui::text_edit::TextEditState::load(&response.ctx, response.id) {
+        state.set_cursor_range(Some(CursorRange::one(Cursor {
+            ccursor: CCursor {
+                index: text_length,
+                prefer_next_row: false,
+            },
+            rcursor: RCursor {
+                row: 0,
+                column: text_length,
+            },
+            pcursor: PCursor {
+                paragraph: 0,
+                offset: text_length,
+                prefer_next_row: false,
+            },
+        })));
+        state.store(&response.ctx, response.id);
+    }
+}
 
 pub struct UiStateChatbox {
     textbox_text: String,
     textbox_layout_job: egui::text::LayoutJob,
     cleanup_layout_text_counter: usize,
-    selected_channel: i32,
+    whispers_revealed: bool,
+    last_message: Option<(String, egui::Color32, usize)>,
+    last_message_sections: usize,
+    sent_message_times: VecDeque<Duration>,
+    name_completion: Option<NameCompletion>,
 }
 
 impl Default for UiStateChatbox {
@@ -70,20 +293,59 @@ impl Default for UiStateChatbox {
             textbox_text: Default::default(),
             textbox_layout_job: Default::default(),
             cleanup_layout_text_counter: 0,
-            selected_channel: IID_BTN_ALL,
+            whispers_revealed: false,
+            last_message: None,
+            last_message_sections: 0,
+            sent_message_times: VecDeque::with_capacity(CHAT_RATE_LIMIT_MESSAGES),
+            name_completion: None,
         }
     }
 }
 
+/// Remove control and bidirectional override characters which break chatbox rendering
+fn sanitise_chat_text(text: &str) -> String {
+    text.chars()
+        .filter_map(|c| match c {
+            '\t' | '\n' | '\r' => Some(' '),
+            '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}' => None,
+            c if c.is_control() => None,
+            c => Some(c),
+        })
+        .collect()
+}
+
+#[derive(SystemParam)]
+pub struct ChatboxConnections<'w> {
+    game_connection: Option<Res<'w, GameConnection>>,
+    world_connection: Option<Res<'w, WorldConnection>>,
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn ui_chatbox_system(
+    mut commands: Commands,
     mut egui_context: EguiContexts,
     mut ui_state_chatbox: Local<UiStateChatbox>,
     mut chatbox_events: EventReader<ChatboxEvent>,
-    game_connection: Option<Res<GameConnection>>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    connections: ChatboxConnections,
+    mut character_settings: ResMut<CharacterSettings>,
+    interface_settings: Res<InterfaceSettings>,
+    mut ignore_list: ResMut<IgnoreList>,
+    hud_settings: Res<HudSettings>,
+    time: Res<Time>,
+    query_characters: Query<(&CharacterInfo, &Position, Option<&PlayerCharacter>)>,
     ui_resources: Res<UiResources>,
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     dialog_assets: Res<Assets<Dialog>>,
+    mut map_pings: ResMut<MapPings>,
 ) {
+    let ChatboxConnections {
+        game_connection,
+        world_connection,
+    } = connections;
     let ui_state_chatbox = &mut *ui_state_chatbox;
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_chatbox) {
         dialog
@@ -94,8 +356,91 @@ pub fn ui_chatbox_system(
     let local_time = chrono::Local::now();
     let timestamp = local_time.format("%H:%M:%S");
 
+    let player = query_characters
+        .iter()
+        .find(|(_, _, player_character)| player_character.is_some());
+    let player_name = player.map(|(character_info, _, _)| character_info.name.as_str());
+    let display_name = |name: &str| -> String {
+        if Some(name) == player_name {
+            name.to_string()
+        } else {
+            interface_settings.other_character_name(name).to_string()
+        }
+    };
+
     for event in chatbox_events.iter() {
-        if ui_state_chatbox.textbox_layout_job.sections.len() == MAX_CHATBOX_ENTRIES {
+        if let ChatboxEvent::Say(name, _)
+        | ChatboxEvent::Shout(name, _)
+        | ChatboxEvent::Whisper(name, _)
+        | ChatboxEvent::Clan(name, _)
+        | ChatboxEvent::Party(name, _) = event
+        {
+            if ignore_list.is_ignored(name) {
+                continue;
+            }
+        }
+
+        let (message, color) = match event {
+            ChatboxEvent::Say(name, text) => (
+                format!("{}> {}", display_name(name), text),
+                CHAT_COLOR_NORMAL,
+            ),
+            ChatboxEvent::Shout(name, text) => (
+                format!("{}> {}", display_name(name), text),
+                CHAT_COLOR_SHOUT,
+            ),
+            ChatboxEvent::Whisper(name, text) => (
+                format!("{}> {}", display_name(name), text),
+                CHAT_COLOR_WHISPER,
+            ),
+            ChatboxEvent::Clan(name, text) => {
+                (format!("{}> {}", display_name(name), text), CHAT_COLOR_CLAN)
+            }
+            ChatboxEvent::Party(name, text) => (
+                format!("{}> {}", display_name(name), text),
+                CHAT_COLOR_PARTY,
+            ),
+            ChatboxEvent::Announce(Some(name), text) => {
+                (format!("{}> {}", name, text), CHAT_COLOR_ANNOUNCE)
+            }
+            ChatboxEvent::Announce(None, text) => (text.clone(), CHAT_COLOR_ANNOUNCE),
+            ChatboxEvent::System(text) => (text.clone(), CHAT_COLOR_SYSTEM),
+            ChatboxEvent::Quest(text) => (text.clone(), CHAT_COLOR_QUEST),
+        };
+        let message = sanitise_chat_text(&message);
+
+        // Collapse repeated identical lines into a single line with a repeat count
+        let repeat_count = match ui_state_chatbox.last_message.as_mut() {
+            Some((last_message, last_color, count))
+                if *last_message == message && *last_color == color =>
+            {
+                *count += 1;
+                *count
+            }
+            _ => {
+                ui_state_chatbox.last_message = Some((message.clone(), color, 1));
+                1
+            }
+        };
+
+        if repeat_count > 1 {
+            // Remove the timestamp and text of the previous line so it can be replaced
+            let layout_job = &mut ui_state_chatbox.textbox_layout_job;
+            let num_sections = layout_job.sections.len();
+            let last_message_sections = ui_state_chatbox.last_message_sections;
+            if last_message_sections > 0 && num_sections >= last_message_sections {
+                let offset = layout_job.sections[num_sections - last_message_sections]
+                    .byte_range
+                    .start;
+                layout_job
+                    .sections
+                    .truncate(num_sections - last_message_sections);
+                layout_job.text.truncate(offset);
+            }
+        }
+
+        // Messages containing pings are split over several sections
+        while ui_state_chatbox.textbox_layout_job.sections.len() >= MAX_CHATBOX_ENTRIES {
             ui_state_chatbox.textbox_layout_job.sections.remove(0);
             ui_state_chatbox.cleanup_layout_text_counter += 1;
 
@@ -124,78 +469,53 @@ pub fn ui_chatbox_system(
             },
         );
 
-        match event {
-            ChatboxEvent::Say(name, text) => {
-                ui_state_chatbox.textbox_layout_job.append(
-                    &format!("{}> {}\n", name, text),
-                    0.0,
-                    egui::TextFormat {
-                        color: CHAT_COLOR_NORMAL,
-                        ..Default::default()
-                    },
-                );
-            }
-            ChatboxEvent::Shout(name, text) => {
-                ui_state_chatbox.textbox_layout_job.append(
-                    &format!("{}> {}\n", name, text),
-                    0.0,
-                    egui::TextFormat {
-                        color: CHAT_COLOR_SHOUT,
-                        ..Default::default()
-                    },
-                );
-            }
-            ChatboxEvent::Whisper(name, text) => {
-                ui_state_chatbox.textbox_layout_job.append(
-                    &format!("{}> {}\n", name, text),
-                    0.0,
-                    egui::TextFormat {
-                        color: CHAT_COLOR_WHISPER,
-                        ..Default::default()
-                    },
-                );
-            }
-            ChatboxEvent::Announce(Some(name), text) => {
-                ui_state_chatbox.textbox_layout_job.append(
-                    &format!("{}> {}\n", name, text),
-                    0.0,
-                    egui::TextFormat {
-                        color: CHAT_COLOR_ANNOUNCE,
-                        ..Default::default()
-                    },
-                );
-            }
-            ChatboxEvent::Announce(None, text) => {
-                ui_state_chatbox.textbox_layout_job.append(
-                    &format!("{}\n", text),
-                    0.0,
-                    egui::TextFormat {
-                        color: CHAT_COLOR_ANNOUNCE,
-                        ..Default::default()
-                    },
-                );
-            }
-            ChatboxEvent::System(text) => {
-                ui_state_chatbox.textbox_layout_job.append(
-                    &format!("{}\n", text),
-                    0.0,
-                    egui::TextFormat {
-                        color: CHAT_COLOR_SYSTEM,
-                        ..Default::default()
-                    },
-                );
-            }
-            ChatboxEvent::Quest(text) => {
+        // Pings are split into their own underlined sections so they can be clicked
+        let num_sections = ui_state_chatbox.textbox_layout_job.sections.len();
+        let mut text_start = 0;
+        for (ping_range, _, _) in MapPings::find_chat_pings(&message) {
+            if ping_range.start > text_start {
                 ui_state_chatbox.textbox_layout_job.append(
-                    &format!("{}\n", text),
+                    &message[text_start..ping_range.start],
                     0.0,
                     egui::TextFormat {
-                        color: CHAT_COLOR_QUEST,
+                        color,
                         ..Default::default()
                     },
                 );
             }
+
+            ui_state_chatbox.textbox_layout_job.append(
+                &message[ping_range.clone()],
+                0.0,
+                egui::TextFormat {
+                    color: CHAT_COLOR_PING_LINK,
+                    underline: egui::Stroke::new(1.0, CHAT_COLOR_PING_LINK),
+                    ..Default::default()
+                },
+            );
+            text_start = ping_range.end;
         }
+
+        let text = if repeat_count > 1 {
+            format!("{} (x{})\n", &message[text_start..], repeat_count)
+        } else {
+            format!("{}\n", &message[text_start..])
+        };
+        ui_state_chatbox.textbox_layout_job.append(
+            &text,
+            0.0,
+            egui::TextFormat {
+                color,
+                ..Default::default()
+            },
+        );
+        ui_state_chatbox.last_message_sections =
+            ui_state_chatbox.textbox_layout_job.sections.len() - num_sections + 1;
+    }
+
+    // Keep receiving messages whilst hidden so they are not missing from the history
+    if !hud_settings.is_visible(HudWidget::Chatbox) {
+        return;
     }
 
     let mut chatbox_style = (*egui_context.ctx_mut().style()).clone();
@@ -218,6 +538,7 @@ pub fn ui_chatbox_system(
     let mut response_party_button = None;
     let mut response_clan_button = None;
     let mut response_allied_button = None;
+    let mut selected_channel = chat_channel_filter_to_iid(character_settings.chat.channel_filter);
 
     egui::Window::new("Chat Box")
         .anchor(egui::Align2::LEFT_BOTTOM, [0.0, 0.0])
@@ -242,7 +563,7 @@ pub fn ui_chatbox_system(
                 DataBindings {
                     sound_events: Some(&mut ui_sound_events),
                     text: &mut [(IID_EDITBOX, &mut ui_state_chatbox.textbox_text)],
-                    radio: &mut [(IID_RADIOBOX, &mut ui_state_chatbox.selected_channel)],
+                    radio: &mut [(IID_RADIOBOX, &mut selected_channel)],
                     response: &mut [
                         (IID_EDITBOX, &mut response_editbox),
                         (IID_BTN_ALL, &mut response_all_button),
@@ -280,7 +601,64 @@ pub fn ui_chatbox_system(
                                 .auto_shrink([false; 2])
                                 .stick_to_bottom(true)
                                 .show(ui, |ui| {
-                                    ui.label(ui_state_chatbox.textbox_layout_job.clone());
+                                    let mut layout_job =
+                                        ui_state_chatbox.textbox_layout_job.clone();
+
+                                    if interface_settings.streamer_mode
+                                        && !ui_state_chatbox.whispers_revealed
+                                    {
+                                        // Obscure whispers until the chat is hovered
+                                        for section in layout_job.sections.iter_mut() {
+                                            if section.format.color == CHAT_COLOR_WHISPER {
+                                                section.format.color = CHAT_COLOR_WHISPER_HIDDEN;
+                                                section.format.background =
+                                                    CHAT_COLOR_WHISPER_HIDDEN;
+                                            }
+                                        }
+                                    }
+
+                                    let minimum_font_size = ui_minimum_font_size(ui.ctx());
+                                    for section in layout_job.sections.iter_mut() {
+                                        section.format.font_id.size =
+                                            section.format.font_id.size.max(minimum_font_size);
+                                    }
+
+                                    layout_job.wrap.max_width = ui.available_width();
+                                    let galley = ui.fonts(|fonts| fonts.layout_job(layout_job));
+                                    let response = ui.add(
+                                        egui::Label::new(galley.clone())
+                                            .sense(egui::Sense::click()),
+                                    );
+                                    ui_state_chatbox.whispers_revealed = response.hovered();
+
+                                    // Clicking a ping link flashes the location on the map
+                                    if let Some(pointer_pos) = response
+                                        .clicked()
+                                        .then(|| response.interact_pointer_pos())
+                                        .flatten()
+                                    {
+                                        let cursor =
+                                            galley.cursor_from_pos(pointer_pos - response.rect.min);
+                                        let byte_index = galley
+                                            .job
+                                            .text
+                                            .char_indices()
+                                            .nth(cursor.ccursor.index)
+                                            .map(|(index, _)| index);
+
+                                        if let Some(section) = byte_index.and_then(|byte_index| {
+                                            galley.job.sections.iter().find(|section| {
+                                                section.byte_range.contains(&byte_index)
+                                                    && section.format.color == CHAT_COLOR_PING_LINK
+                                            })
+                                        }) {
+                                            for (_, zone_id, position) in MapPings::find_chat_pings(
+                                                &galley.job.text[section.byte_range.clone()],
+                                            ) {
+                                                map_pings.add(zone_id, position, time.elapsed());
+                                            }
+                                        }
+                                    }
                                 });
                         },
                     );
@@ -288,22 +666,288 @@ pub fn ui_chatbox_system(
             );
         });
 
+    if let Some(response) = response_editbox.as_ref() {
+        if response.has_focus() {
+            // Tab is used for completion rather than moving focus out of the chatbox
+            response
+                .ctx
+                .memory_mut(|memory| memory.lock_focus(response.id, true));
+
+            if response
+                .ctx
+                .input(|input| input.key_pressed(egui::Key::Tab))
+            {
+                let nearby_names = || {
+                    let player_position = player.map(|(_, position, _)| position.position);
+                    let mut characters: Vec<(f32, String)> = query_characters
+                        .iter()
+                        .filter(|(_, _, player_character)| player_character.is_none())
+                        .map(|(character_info, position, _)| {
+                            (
+                                player_position.map_or(0.0, |player_position| {
+                                    player_position.xy().distance(position.position.xy())
+                                }),
+                                character_info.name.clone(),
+                            )
+                        })
+                        .collect();
+                    characters.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+                    characters.into_iter().map(|(_, name)| name).collect()
+                };
+
+                if let Some(text) = tab_complete_chat_text(
+                    &ui_state_chatbox.textbox_text,
+                    nearby_names,
+                    &mut ui_state_chatbox.name_completion,
+                ) {
+                    ui_state_chatbox.textbox_text = text;
+                    move_cursor_to_end(response, ui_state_chatbox.textbox_text.chars().count());
+                }
+            }
+        }
+
+        // Suggest matching commands above the chatbox, or the arguments once a command is typed
+        if ui_state_chatbox.textbox_text.starts_with('/') {
+            let text = ui_state_chatbox.textbox_text.to_lowercase();
+            let (command, has_arguments) = match text.split_once(' ') {
+                Some((command, _)) => (command, true),
+                None => (text.as_str(), false),
+            };
+            let suggestions: Vec<&ChatCommand> = matching_chat_commands(command)
+                .filter(|chat_command| !has_arguments || chat_command.name == command)
+                .collect();
+
+            if !suggestions.is_empty() {
+                let mut selected_command = None;
+
+                egui::Area::new("chatbox_autocomplete")
+                    .order(egui::Order::Foreground)
+                    .pivot(egui::Align2::LEFT_BOTTOM)
+                    .fixed_pos(response.rect.left_top())
+                    .show(&response.ctx, |ui| {
+                        egui::Frame::popup(ui.style()).show(ui, |ui| {
+                            egui::Grid::new("chatbox_autocomplete_grid")
+                                .num_columns(3)
+                                .show(ui, |ui| {
+                                    for chat_command in suggestions.iter() {
+                                        if ui.selectable_label(false, chat_command.name).clicked() {
+                                            selected_command = Some(chat_command.name);
+                                        }
+                                        ui.weak(chat_command.arguments);
+                                        ui.label(chat_command.description);
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                    });
+
+                if let Some(name) = selected_command {
+                    ui_state_chatbox.textbox_text = format!("{} ", name);
+                    response.request_focus();
+                    move_cursor_to_end(response, ui_state_chatbox.textbox_text.chars().count());
+                }
+            }
+        }
+    }
+
+    let channel_filter = chat_channel_filter_from_iid(selected_channel);
+    if character_settings.chat.channel_filter != channel_filter {
+        character_settings.chat.channel_filter = channel_filter;
+    }
+
     if let Some(response) = response_editbox {
         if response
             .ctx
             .input(|input| input.key_pressed(egui::Key::Enter))
         {
             if response.lost_focus() {
-                if !ui_state_chatbox.textbox_text.is_empty() {
-                    // TODO: Parse text line to decide whether its chat, shout, etc
-                    if let Some(game_connection) = game_connection.as_ref() {
-                        game_connection
-                            .client_message_tx
-                            .send(ClientMessage::Chat {
-                                text: ui_state_chatbox.textbox_text.clone(),
-                            })
-                            .ok();
+                // Macros are expanded first so they can use any of the chat prefixes and commands
+                if let Some(name) = ui_state_chatbox
+                    .textbox_text
+                    .trim()
+                    .strip_prefix("/m ")
+                    .map(|name| name.trim().to_string())
+                {
+                    if let Some(chat_macro) = character_settings
+                        .macros
+                        .iter()
+                        .find(|chat_macro| chat_macro.name.eq_ignore_ascii_case(&name))
+                    {
+                        ui_state_chatbox.textbox_text = chat_macro.text.clone();
+                    } else {
+                        let message = format!("There is no macro named {}.", name);
+                        commands.add(move |world: &mut World| {
+                            world
+                                .resource_mut::<Events<ChatboxEvent>>()
+                                .send(ChatboxEvent::System(message));
+                        });
+                        ui_state_chatbox.textbox_text.clear();
+                    }
+                }
+
+                // Whispers are sent using the same @name prefix as the whisper tab
+                if ui_state_chatbox
+                    .textbox_text
+                    .get(..3)
+                    .map_or(false, |command| command.eq_ignore_ascii_case("/w "))
+                {
+                    ui_state_chatbox.textbox_text =
+                        format!("@{}", ui_state_chatbox.textbox_text[3..].trim_start());
+                }
+
+                let text = ui_state_chatbox.textbox_text.trim().to_string();
+                let command = text.to_lowercase();
+                if command == "/sit" || command == "/rest" {
+                    player_command_events.send(PlayerCommandEvent::Sit);
+                    ui_state_chatbox.textbox_text.clear();
+                } else if command == "/unstick" {
+                    commands.add(|world: &mut World| {
+                        world.resource_mut::<Unstick>().requested = true;
+                    });
+                    ui_state_chatbox.textbox_text.clear();
+                } else if command == "/afk" || command.starts_with("/afk ") {
+                    let message = text["/afk".len()..].trim().to_string();
+                    commands.add(move |world: &mut World| {
+                        world.resource_mut::<AwayState>().request(&message);
+                    });
+                    ui_state_chatbox.textbox_text.clear();
+                } else if command == "/fish" {
+                    commands.add(|world: &mut World| {
+                        world
+                            .resource_mut::<Events<FishingEvent>>()
+                            .send(FishingEvent::Cast);
+                    });
+                    ui_state_chatbox.textbox_text.clear();
+                } else if command == "/macro" || command.starts_with("/macro ") {
+                    let mut arguments = text["/macro".len()..].trim().splitn(2, ' ');
+                    let name = arguments.next().unwrap_or_default().to_string();
+                    let macro_text = arguments.next().unwrap_or_default().trim().to_string();
+                    let macros = &mut character_settings.macros;
+
+                    let messages = if name.is_empty() {
+                        if macros.is_empty() {
+                            vec!["You have no macros.".to_string()]
+                        } else {
+                            macros
+                                .iter()
+                                .map(|chat_macro| {
+                                    format!("{}: {}", chat_macro.name, chat_macro.text)
+                                })
+                                .collect()
+                        }
+                    } else if macro_text.is_empty() {
+                        let num_macros = macros.len();
+                        macros.retain(|chat_macro| !chat_macro.name.eq_ignore_ascii_case(&name));
+                        if macros.len() != num_macros {
+                            vec![format!("Macro {} has been removed.", name)]
+                        } else {
+                            vec![format!("There is no macro named {}.", name)]
+                        }
+                    } else {
+                        if let Some(chat_macro) = macros
+                            .iter_mut()
+                            .find(|chat_macro| chat_macro.name.eq_ignore_ascii_case(&name))
+                        {
+                            chat_macro.text = macro_text;
+                        } else {
+                            macros.push(ChatMacro {
+                                name: name.clone(),
+                                text: macro_text,
+                            });
+                        }
+                        vec![format!(
+                            "Macro {} has been saved, send it with /m {}.",
+                            name, name
+                        )]
+                    };
+
+                    commands.add(move |world: &mut World| {
+                        let mut chatbox_events = world.resource_mut::<Events<ChatboxEvent>>();
+                        for message in messages {
+                            chatbox_events.send(ChatboxEvent::System(message));
+                        }
+                    });
+                    ui_state_chatbox.textbox_text.clear();
+                } else if command.starts_with("/ignore ") {
+                    let name = text["/ignore ".len()..].trim();
+                    let message = if ignore_list.add(name) {
+                        format!("{} has been added to your ignore list.", name)
+                    } else {
+                        format!("{} is already on your ignore list.", name)
+                    };
+                    commands.add(move |world: &mut World| {
+                        world
+                            .resource_mut::<Events<ChatboxEvent>>()
+                            .send(ChatboxEvent::System(message));
+                    });
+                    ui_state_chatbox.textbox_text.clear();
+                } else if command.starts_with("/unignore ") {
+                    let name = text["/unignore ".len()..].trim();
+                    let message = if ignore_list.remove(name) {
+                        format!("{} has been removed from your ignore list.", name)
+                    } else {
+                        format!("{} is not on your ignore list.", name)
+                    };
+                    commands.add(move |world: &mut World| {
+                        world
+                            .resource_mut::<Events<ChatboxEvent>>()
+                            .send(ChatboxEvent::System(message));
+                    });
+                    ui_state_chatbox.textbox_text.clear();
+                } else if !ui_state_chatbox.textbox_text.is_empty() {
+                    let now = time.elapsed();
+                    while ui_state_chatbox
+                        .sent_message_times
+                        .front()
+                        .map_or(false, |sent| {
+                            now.saturating_sub(*sent) > CHAT_RATE_LIMIT_PERIOD
+                        })
+                    {
+                        ui_state_chatbox.sent_message_times.pop_front();
+                    }
+
+                    if ui_state_chatbox.sent_message_times.len() >= CHAT_RATE_LIMIT_MESSAGES {
+                        // Keep the text so it can be sent again once the limit has passed
+                        let wait =
+                            ui_state_chatbox.sent_message_times[0] + CHAT_RATE_LIMIT_PERIOD - now;
+                        let message = format!(
+                            "You are sending messages too quickly, please wait {:.1} seconds.",
+                            wait.as_secs_f32()
+                        );
+                        commands.add(move |world: &mut World| {
+                            world
+                                .resource_mut::<Events<ChatboxEvent>>()
+                                .send(ChatboxEvent::System(message));
+                        });
+                    } else if let Some(game_connection) = game_connection.as_ref() {
+                        if let Some(text) = ui_state_chatbox.textbox_text.strip_prefix('&') {
+                            // Clan chat is handled by the world server
+                            if let Some(world_connection) = world_connection.as_ref() {
+                                world_connection
+                                    .extension_message_tx
+                                    .send(ExtensionClientMessage::ClanChat {
+                                        text: sanitise_chat_text(text.trim()),
+                                    })
+                                    .ok();
+                            }
+                        } else if let Some(text) = ui_state_chatbox.textbox_text.strip_prefix('#') {
+                            game_connection
+                                .extension_message_tx
+                                .send(ExtensionClientMessage::PartyChat {
+                                    text: sanitise_chat_text(text.trim()),
+                                })
+                                .ok();
+                        } else {
+                            // TODO: Parse text line to decide whether its chat, shout, etc
+                            game_connection
+                                .client_message_tx
+                                .send(ClientMessage::Chat {
+                                    text: sanitise_chat_text(&ui_state_chatbox.textbox_text),
+                                })
+                                .ok();
+                        }
                         ui_state_chatbox.textbox_text.clear();
+                        ui_state_chatbox.sent_message_times.push_back(now);
                     }
                 }
             } else {
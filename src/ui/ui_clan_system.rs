@@ -1,11 +1,13 @@
 use bevy::prelude::{Assets, EventWriter, Local, Query, Res, ResMut, With};
 use bevy_egui::{egui, EguiContexts};
 use rose_data::ClanMemberPosition;
+use rose_game_common::components::ClanMark;
 
 use crate::{
     components::{Clan, ClanMembership, PlayerCharacter},
-    resources::{GameData, UiResources},
+    resources::{GameData, UiResources, UiSpriteSheetType},
     ui::{
+        format_money,
         widgets::{DataBindings, Dialog, DrawText},
         UiSoundEvent, UiStateWindows,
     },
@@ -121,13 +123,16 @@ pub fn ui_clan_system(
                                 );
 
                                 if let Some(member) = clan.members.get(index as usize) {
-                                    if matches!(member.position, ClanMemberPosition::Penalty) {
-                                        egui::Color32::RED
-                                    } else if is_selected {
-                                        egui::Color32::YELLOW
-                                    } else {
-                                        egui::Color32::BLACK
-                                    };
+                                    let color =
+                                        if matches!(member.position, ClanMemberPosition::Penalty) {
+                                            egui::Color32::RED
+                                        } else if is_selected {
+                                            egui::Color32::from_rgb(0, 0, 160)
+                                        } else if member.channel_id.is_none() {
+                                            egui::Color32::GRAY
+                                        } else {
+                                            egui::Color32::BLACK
+                                        };
 
                                     ui.add_label_at(
                                         egui::pos2(2.0, 2.0),
@@ -138,11 +143,21 @@ pub fn ui_clan_system(
                                                 .string_database
                                                 .get_clan_member_position(member.position)
                                         ))
-                                        .color(egui::Color32::BLACK),
+                                        .color(color),
                                     );
-                                }
 
-                                response
+                                    response.on_hover_text(format!(
+                                        "Level {} {}\n{}",
+                                        member.level.level,
+                                        game_data.string_database.get_job_name(member.job),
+                                        member.channel_id.map_or_else(
+                                            || "Offline".to_string(),
+                                            |channel_id| format!("Online (channel {})", channel_id)
+                                        ),
+                                    ))
+                                } else {
+                                    response
+                                }
                             },
                         ),
                     )],
@@ -157,6 +172,25 @@ pub fn ui_clan_system(
                         );
                         ui.add_label_at(egui::pos2(88.0, 73.0), &clan.name);
 
+                        // Custom uploaded clan marks are not supported yet
+                        if let ClanMark::Premade {
+                            background,
+                            foreground,
+                        } = clan.mark
+                        {
+                            let mark_pos = ui.min_rect().min + egui::vec2(190.0, 70.0);
+                            for (sprite_sheet_type, index) in [
+                                (UiSpriteSheetType::ClanMarkBackground, background),
+                                (UiSpriteSheetType::ClanMarkForeground, foreground),
+                            ] {
+                                if let Some(sprite) = ui_resources
+                                    .get_sprite_by_index(sprite_sheet_type, index.get() as usize)
+                                {
+                                    sprite.draw(ui, mark_pos);
+                                }
+                            }
+                        }
+
                         ui.add_label_at(
                             egui::pos2(15.0, 94.0),
                             egui::RichText::new(game_data.client_strings.clan_level)
@@ -189,7 +223,7 @@ pub fn ui_clan_system(
                             egui::RichText::new(game_data.client_strings.clan_money)
                                 .color(egui::Color32::BLACK),
                         );
-                        ui.add_label_at(egui::pos2(88.0, 187.0), format!("{}", clan.money.0));
+                        ui.add_label_at(egui::pos2(88.0, 187.0), format_money(clan.money.0));
 
                         let max_members = game_data
                             .ability_value_calculator
@@ -0,0 +1,95 @@
+use bevy::prelude::{Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    resources::{
+        CurrentZone, GameData, HudSettings, HudWidget, InterfaceSettings, ZoneTime, ZoneTimeState,
+    },
+    ui::UiStateDebugWindows,
+};
+
+fn format_clock_time(hours: u32, minutes: u32, use_24_hour: bool) -> String {
+    if use_24_hour {
+        format!("{:02}:{:02}", hours, minutes)
+    } else {
+        let suffix = if hours < 12 { "AM" } else { "PM" };
+        let hours = match hours % 12 {
+            0 => 12,
+            hours => hours,
+        };
+        format!("{}:{:02} {}", hours, minutes, suffix)
+    }
+}
+
+pub fn ui_clock_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    current_zone: Option<Res<CurrentZone>>,
+    game_data: Res<GameData>,
+    interface_settings: Res<InterfaceSettings>,
+    hud_settings: Res<HudSettings>,
+    zone_time: Res<ZoneTime>,
+) {
+    if !interface_settings.show_clock || !hud_settings.is_visible(HudWidget::Clock) {
+        return;
+    }
+
+    let Some(zone_data) = current_zone
+        .as_ref()
+        .and_then(|current_zone| game_data.zone_list.get_zone(current_zone.id))
+    else {
+        return;
+    };
+
+    let (zone_hours, zone_minutes) = if zone_data.day_cycle > 0 {
+        let day_minutes =
+            (zone_time.time as u64 * 24 * 60 / zone_data.day_cycle as u64) as u32 % (24 * 60);
+        (day_minutes / 60, day_minutes % 60)
+    } else {
+        (0, 0)
+    };
+
+    let (icon, icon_colour) = match zone_time.state {
+        ZoneTimeState::Morning => ("🌅", egui::Color32::from_rgb(255, 200, 120)),
+        ZoneTimeState::Day => ("☀", egui::Color32::from_rgb(255, 230, 80)),
+        ZoneTimeState::Evening => ("🌇", egui::Color32::from_rgb(255, 150, 80)),
+        ZoneTimeState::Night => ("🌙", egui::Color32::from_rgb(180, 200, 255)),
+    };
+
+    let local_time = chrono::Local::now();
+    let real_time = if interface_settings.clock_use_24_hour {
+        local_time.format("%H:%M").to_string()
+    } else {
+        local_time.format("%-I:%M %p").to_string()
+    };
+
+    egui::Window::new("Clock")
+        .anchor(egui::Align2::LEFT_TOP, [250.0, 0.0])
+        .frame(egui::Frame::none().fill(egui::Color32::from_black_alpha(128)))
+        .title_bar(false)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            let response = ui
+                .horizontal(|ui| {
+                    ui.label(egui::RichText::new(icon).color(icon_colour).size(16.0));
+                    ui.label(
+                        egui::RichText::new(format_clock_time(
+                            zone_hours,
+                            zone_minutes,
+                            interface_settings.clock_use_24_hour,
+                        ))
+                        .color(egui::Color32::WHITE),
+                    );
+                    ui.separator();
+                    ui.label(egui::RichText::new(real_time).color(egui::Color32::LIGHT_GRAY));
+                })
+                .response
+                .interact(egui::Sense::click())
+                .on_hover_text(format!("{:?}", zone_time.state));
+
+            if cfg!(debug_assertions) && response.clicked() {
+                ui_state_debug_windows.debug_ui_open = true;
+                ui_state_debug_windows.zone_time_open = true;
+            }
+        });
+}
@@ -1,13 +1,17 @@
-use bevy::prelude::{Local, Query, Res, ResMut};
+use bevy::{
+    math::Vec3Swizzles,
+    prelude::{Entity, Local, Query, Res, ResMut, With},
+};
 use bevy_egui::{egui, EguiContexts};
 use rose_game_common::{
     components::{CharacterInfo, DroppedItem, ItemDrop, Npc},
-    messages::ClientEntityId,
+    messages::{client::ClientMessage, ClientEntityId},
 };
 
 use crate::{
-    components::{ClientEntity, ClientEntityType, Command, PlayerCharacter},
-    resources::{ClientEntityList, GameData},
+    components::{ClientEntity, ClientEntityType, Command, PlayerCharacter, Position},
+    resources::{ClientEntityList, CurrentZone, GameConnection, GameData, SelectedTarget},
+    systems::OrbitCamera,
     ui::UiStateDebugWindows,
 };
 
@@ -16,6 +20,7 @@ pub struct UiStateDebugClientEntityList {
     filter_npcs: bool,
     filter_monsters: bool,
     filter_item_drops: bool,
+    sort_by_distance: bool,
     num_characters: usize,
     num_npcs: usize,
     num_monsters: usize,
@@ -29,6 +34,7 @@ impl Default for UiStateDebugClientEntityList {
             filter_monsters: true,
             filter_npcs: true,
             filter_item_drops: true,
+            sort_by_distance: false,
             num_characters: 0,
             num_npcs: 0,
             num_monsters: 0,
@@ -45,12 +51,18 @@ pub fn ui_debug_client_entity_list_system(
     game_data: Res<GameData>,
     query_client_entity: Query<(
         &ClientEntity,
+        &Position,
         Option<&Command>,
         Option<&CharacterInfo>,
         Option<&ItemDrop>,
         Option<&Npc>,
         Option<&PlayerCharacter>,
     )>,
+    query_player: Query<Entity, With<PlayerCharacter>>,
+    mut query_orbit_camera: Query<&mut OrbitCamera>,
+    mut selected_target: ResMut<SelectedTarget>,
+    current_zone: Option<Res<CurrentZone>>,
+    game_connection: Option<Res<GameConnection>>,
 ) {
     if !ui_state_debug_windows.debug_ui_open {
         return;
@@ -104,6 +116,11 @@ pub fn ui_debug_client_entity_list_system(
                     ui.end_row();
                 });
 
+            ui.checkbox(
+                &mut ui_state_debug_client_entity_list.sort_by_distance,
+                "Sort by distance to player",
+            );
+
             ui.separator();
 
             ui_state_debug_client_entity_list.num_characters = 0;
@@ -111,116 +128,220 @@ pub fn ui_debug_client_entity_list_system(
             ui_state_debug_client_entity_list.num_npcs = 0;
             ui_state_debug_client_entity_list.num_item_drops = 0;
 
+            let player_position = query_player
+                .get_single()
+                .ok()
+                .and_then(|player_entity| query_client_entity.get(player_entity).ok())
+                .map(|(_, position, ..)| position.position);
+
+            let mut rows = Vec::new();
+            for (client_entity_id, entity) in client_entity_list
+                .client_entities
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| e.is_some())
+                .map(|(id, entity)| (ClientEntityId(id), entity.unwrap()))
+            {
+                let Ok((client_entity, position, ..)) = query_client_entity.get(entity) else {
+                    continue;
+                };
+
+                match client_entity.entity_type {
+                    ClientEntityType::Character => {
+                        ui_state_debug_client_entity_list.num_characters += 1;
+                        if !ui_state_debug_client_entity_list.filter_characters {
+                            continue;
+                        }
+                    }
+                    ClientEntityType::Monster => {
+                        ui_state_debug_client_entity_list.num_monsters += 1;
+                        if !ui_state_debug_client_entity_list.filter_monsters {
+                            continue;
+                        }
+                    }
+                    ClientEntityType::Npc => {
+                        ui_state_debug_client_entity_list.num_npcs += 1;
+                        if !ui_state_debug_client_entity_list.filter_npcs {
+                            continue;
+                        }
+                    }
+                    ClientEntityType::ItemDrop => {
+                        ui_state_debug_client_entity_list.num_item_drops += 1;
+                        if !ui_state_debug_client_entity_list.filter_item_drops {
+                            continue;
+                        }
+                    }
+                }
+
+                let distance = player_position.map(|player_position| {
+                    player_position.xy().distance(position.position.xy()) / 100.0
+                });
+                rows.push((client_entity_id, entity, distance));
+            }
+
+            if ui_state_debug_client_entity_list.sort_by_distance {
+                rows.sort_by(|(_, _, a), (_, _, b)| {
+                    a.unwrap_or(f32::MAX).total_cmp(&b.unwrap_or(f32::MAX))
+                });
+            }
+
             egui::ScrollArea::vertical()
                 .auto_shrink([false, false])
                 .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysVisible)
                 .show(ui, |ui| {
                     egui::Grid::new("client_entity_list_grid")
-                        .num_columns(4)
+                        .num_columns(7)
                         .striped(true)
                         .show(ui, |ui| {
-                            for (client_entity_id, entity) in client_entity_list
-                                .client_entities
-                                .iter()
-                                .enumerate()
-                                .filter(|(_, e)| e.is_some())
-                                .map(|(id, entity)| (ClientEntityId(id), entity.unwrap()))
-                            {
-                                if let Ok((
+                            ui.strong("ID");
+                            ui.strong("Type");
+                            ui.strong("Name");
+                            ui.strong("Command");
+                            ui.strong("Distance");
+                            ui.strong("Position");
+                            ui.strong("Action");
+                            ui.end_row();
+
+                            for (client_entity_id, entity, distance) in rows {
+                                let Ok((
                                     client_entity,
+                                    position,
                                     command,
                                     character_info,
                                     item_drop,
                                     npc,
                                     player_character,
                                 )) = query_client_entity.get(entity)
-                                {
-                                    match client_entity.entity_type {
-                                        ClientEntityType::Character => {
-                                            ui_state_debug_client_entity_list.num_characters += 1;
-                                            if !ui_state_debug_client_entity_list.filter_characters
-                                            {
-                                                continue;
-                                            }
-                                        }
-                                        ClientEntityType::Monster => {
-                                            ui_state_debug_client_entity_list.num_monsters += 1;
-                                            if !ui_state_debug_client_entity_list.filter_monsters {
-                                                continue;
-                                            }
-                                        }
-                                        ClientEntityType::Npc => {
-                                            ui_state_debug_client_entity_list.num_npcs += 1;
-                                            if !ui_state_debug_client_entity_list.filter_npcs {
-                                                continue;
-                                            }
-                                        }
-                                        ClientEntityType::ItemDrop => {
-                                            ui_state_debug_client_entity_list.num_item_drops += 1;
-                                            if !ui_state_debug_client_entity_list.filter_item_drops
-                                            {
-                                                continue;
-                                            }
-                                        }
-                                    }
+                                else {
+                                    continue;
+                                };
 
-                                    ui.label(format!("{}", client_entity_id.0));
+                                let is_spectating = query_orbit_camera
+                                    .iter()
+                                    .any(|orbit_camera| orbit_camera.follow_entity == entity);
+                                let response = if is_spectating && player_character.is_none() {
+                                    ui.strong(format!("{} (Spectating)", client_entity_id.0))
+                                } else {
+                                    ui.label(format!("{}", client_entity_id.0))
+                                };
 
-                                    if let Some(character_info) = character_info {
-                                        if player_character.is_some() {
-                                            ui.label("Player");
+                                if cfg!(debug_assertions) && player_character.is_none() {
+                                    response.context_menu(|ui| {
+                                        let follow_entity = if is_spectating {
+                                            ui.button("Stop Spectating")
+                                                .clicked()
+                                                .then(|| query_player.get_single().ok())
+                                                .flatten()
                                         } else {
-                                            ui.label("Character");
-                                        }
-                                        ui.label(&character_info.name);
-                                    } else if let Some(npc) = npc {
-                                        if client_entity.entity_type == ClientEntityType::Monster {
-                                            ui.label("Monster");
-                                        } else {
-                                            ui.label("NPC");
-                                        }
+                                            ui.button("Spectate").clicked().then_some(entity)
+                                        };
 
-                                        if let Some(npc_data) = game_data.npcs.get_npc(npc.id) {
-                                            ui.label(npc_data.name);
-                                        } else {
-                                            ui.label(format!("? [{}]", npc.id.get()));
-                                        }
-                                    } else if let Some(item_drop) = item_drop {
-                                        ui.label("Item Drop");
-                                        match item_drop.item.as_ref() {
-                                            Some(DroppedItem::Money(money)) => {
-                                                ui.label(format!("Money: {}", money.0))
+                                        if let Some(follow_entity) = follow_entity {
+                                            for mut orbit_camera in query_orbit_camera.iter_mut() {
+                                                orbit_camera.follow_entity = follow_entity;
                                             }
-                                            Some(DroppedItem::Item(item)) => ui.label(format!(
-                                                "Item: {:?} {}",
-                                                item.get_item_type(),
-                                                item.get_item_number()
-                                            )),
-                                            None => ui.label("?"),
-                                        };
+                                            ui.close_menu();
+                                        }
+                                    });
+                                }
+
+                                if let Some(character_info) = character_info {
+                                    if player_character.is_some() {
+                                        ui.label("Player");
+                                    } else {
+                                        ui.label("Character");
+                                    }
+                                    ui.label(&character_info.name);
+                                } else if let Some(npc) = npc {
+                                    if client_entity.entity_type == ClientEntityType::Monster {
+                                        ui.label("Monster");
                                     } else {
-                                        ui.label("Unknown");
-                                        ui.label(" ");
+                                        ui.label("NPC");
                                     }
 
-                                    if let Some(command) = command {
-                                        match command {
-                                            Command::Stop => ui.label("Idle"),
-                                            Command::Move(_) => ui.label("Moving"),
-                                            Command::Attack(_) => ui.label("Attacking"),
-                                            Command::Die => ui.label("Dead"),
-                                            Command::PersonalStore => ui.label("Personal Store"),
-                                            Command::PickupItem(_) => ui.label("Pickup Item"),
-                                            Command::Emote(_) => ui.label("Emote"),
-                                            Command::Sit(_) => ui.label("Sitting"),
-                                            Command::CastSkill(_) => ui.label("Casting Skill"),
-                                        };
+                                    if let Some(npc_data) = game_data.npcs.get_npc(npc.id) {
+                                        ui.label(npc_data.name);
                                     } else {
-                                        ui.label(" ");
+                                        ui.label(format!("? [{}]", npc.id.get()));
                                     }
+                                } else if let Some(item_drop) = item_drop {
+                                    ui.label("Item Drop");
+                                    match item_drop.item.as_ref() {
+                                        Some(DroppedItem::Money(money)) => {
+                                            ui.label(format!("Money: {}", money.0))
+                                        }
+                                        Some(DroppedItem::Item(item)) => ui.label(format!(
+                                            "Item: {:?} {}",
+                                            item.get_item_type(),
+                                            item.get_item_number()
+                                        )),
+                                        None => ui.label("?"),
+                                    };
+                                } else {
+                                    ui.label("Unknown");
+                                    ui.label(" ");
+                                }
+
+                                if let Some(command) = command {
+                                    match command {
+                                        Command::Stop => ui.label("Idle"),
+                                        Command::Move(_) => ui.label("Moving"),
+                                        Command::Attack(_) => ui.label("Attacking"),
+                                        Command::Die => ui.label("Dead"),
+                                        Command::PersonalStore => ui.label("Personal Store"),
+                                        Command::PickupItem(_) => ui.label("Pickup Item"),
+                                        Command::Emote(_) => ui.label("Emote"),
+                                        Command::Sit(_) => ui.label("Sitting"),
+                                        Command::CastSkill(_) => ui.label("Casting Skill"),
+                                        Command::HitStun(_) => ui.label("Hit Stun"),
+                                    };
+                                } else {
+                                    ui.label(" ");
+                                }
 
-                                    ui.end_row();
+                                if let Some(distance) = distance {
+                                    ui.label(format!("{:.1}m", distance));
+                                } else {
+                                    ui.label(" ");
                                 }
+
+                                ui.label(format!(
+                                    "{:.0}, {:.0}, {:.0}",
+                                    position.position.x, position.position.y, position.position.z
+                                ));
+
+                                ui.horizontal(|ui| {
+                                    if ui
+                                        .add_enabled(
+                                            selected_target.selected != Some(entity),
+                                            egui::Button::new("Select"),
+                                        )
+                                        .clicked()
+                                    {
+                                        selected_target.selected = Some(entity);
+                                    }
+
+                                    if player_character.is_none() && ui.button("Teleport").clicked()
+                                    {
+                                        if let (Some(game_connection), Some(current_zone)) =
+                                            (game_connection.as_ref(), current_zone.as_ref())
+                                        {
+                                            game_connection
+                                                .client_message_tx
+                                                .send(ClientMessage::Chat {
+                                                    text: format!(
+                                                        "/mm {} {} {}",
+                                                        current_zone.id.get(),
+                                                        position.position.x / 100.0,
+                                                        position.position.y / 100.0
+                                                    ),
+                                                })
+                                                .ok();
+                                        }
+                                    }
+                                });
+
+                                ui.end_row();
                             }
                         });
                 });
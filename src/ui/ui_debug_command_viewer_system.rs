@@ -52,6 +52,9 @@ pub fn format_command(ui: &mut egui::Ui, command: &Command) {
         Command::PersonalStore => {
             ui.label("Personal Store");
         }
+        Command::HitStun(_) => {
+            ui.label("Hit Stun");
+        }
         Command::Sit(CommandSit::Sit) => {
             ui.label("Sit");
         }
@@ -4,12 +4,17 @@ use bevy::{
 };
 use bevy_egui::{egui, EguiContexts};
 
-use crate::ui::UiStateDebugWindows;
+use crate::{
+    animation::{AnimationThrottleSettings, AnimationThrottleStats},
+    ui::UiStateDebugWindows,
+};
 
 pub fn ui_debug_diagnostics_system(
     mut egui_context: EguiContexts,
     ui_state_debug_windows: ResMut<UiStateDebugWindows>,
     diagnostics: Res<DiagnosticsStore>,
+    mut animation_throttle_settings: ResMut<AnimationThrottleSettings>,
+    animation_throttle_stats: Res<AnimationThrottleStats>,
 ) {
     if !ui_state_debug_windows.debug_ui_open {
         return;
@@ -43,5 +48,26 @@ pub fn ui_debug_diagnostics_system(
                         }
                     }
                 });
+
+            ui.separator();
+            ui.checkbox(
+                &mut animation_throttle_settings.enabled,
+                "Animation Throttling",
+            );
+            egui::Grid::new("animation_throttle_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Full Rate Animations");
+                    ui.label(format!("{}", animation_throttle_stats.full));
+                    ui.end_row();
+
+                    ui.label("Reduced Rate Animations");
+                    ui.label(format!("{}", animation_throttle_stats.reduced));
+                    ui.end_row();
+
+                    ui.label("Off-screen Animations");
+                    ui.label(format!("{}", animation_throttle_stats.skipped));
+                    ui.end_row();
+                });
         });
 }
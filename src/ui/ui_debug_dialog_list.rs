@@ -1,20 +1,103 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
 use bevy::{
     asset::HandleId,
     prelude::{AssetServer, Assets, Handle, Local, Res, ResMut},
+    time::Time,
 };
 use bevy_egui::{egui, EguiContexts};
 use regex::Regex;
 
-use crate::ui::{
-    widgets::{DataBindings, Dialog},
-    UiStateDebugWindows,
+use rose_file_readers::VfsFile;
+
+use crate::{
+    resources::{DataOverlayConfiguration, VfsResource},
+    ui::{
+        widgets::{DataBindings, Dialog, Widget},
+        UiStateDebugWindows,
+    },
 };
 
+const HOT_RELOAD_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const EXPORT_DIRECTORY: &str = "exported_dialogs";
+
 #[derive(Default)]
 pub struct UiStateDebugDialogs {
     draw_dialog: Option<Handle<Dialog>>,
     filter_name: String,
     filtered_dialogs: Vec<(String, HandleId)>,
+
+    hot_reload: bool,
+    hot_reload_timer: Duration,
+    hot_reload_modified_times: HashMap<String, SystemTime>,
+
+    edit_dialog: Option<Dialog>,
+    edit_dialog_path: String,
+    edit_show_bounds: bool,
+    edit_selected_widget: Option<i32>,
+    edit_modified_widgets: HashSet<i32>,
+    edit_status: String,
+}
+
+fn for_each_widget_rect(
+    widgets: &[Widget],
+    min: egui::Pos2,
+    f: &mut impl FnMut(&Widget, egui::Rect),
+) {
+    for widget in widgets.iter() {
+        if let Some(rect) = widget.debug_rect(min) {
+            f(widget, rect);
+
+            if let Widget::Pane(pane) = widget {
+                for_each_widget_rect(&pane.widgets, rect.min, f);
+            }
+        }
+    }
+}
+
+fn export_dialog_xml(
+    vfs_resource: &VfsResource,
+    path: &str,
+    dialog: &mut Dialog,
+    modified_widgets: &HashSet<i32>,
+) -> Result<PathBuf, anyhow::Error> {
+    let mut xml = match vfs_resource.vfs.open_file(path)? {
+        VfsFile::Buffer(buffer) => String::from_utf8(buffer)?,
+        VfsFile::View(view) => String::from_utf8(view.into())?,
+    };
+
+    // Patch the X and Y attributes of each modified widget in the original XML, so everything
+    // else in the file is left untouched
+    let x_re = Regex::new(r#"(\s)X="[^"]*""#)?;
+    let y_re = Regex::new(r#"(\s)Y="[^"]*""#)?;
+    for &id in modified_widgets.iter() {
+        let Some((x, y)) = dialog
+            .get_widget_mut(id)
+            .and_then(|widget| widget.position_mut())
+            .map(|(x, y)| (*x, *y))
+        else {
+            continue;
+        };
+
+        let tag_re = Regex::new(&format!(r#"<\w+[^>]*\sID="{}"[^>]*>"#, id))?;
+        xml = tag_re
+            .replace_all(&xml, |captures: &regex::Captures| {
+                let tag = x_re.replace(&captures[0], format!(r#"${{1}}X="{}""#, x));
+                y_re.replace(&tag, format!(r#"${{1}}Y="{}""#, y))
+                    .to_string()
+            })
+            .to_string();
+    }
+
+    let filename = path.rsplit('/').next().unwrap_or(path);
+    let export_path = PathBuf::from(EXPORT_DIRECTORY).join(filename);
+    std::fs::create_dir_all(EXPORT_DIRECTORY)?;
+    std::fs::write(&export_path, xml)?;
+    Ok(export_path)
 }
 
 pub fn ui_debug_dialog_list_system(
@@ -23,8 +106,47 @@ pub fn ui_debug_dialog_list_system(
     mut ui_state: Local<UiStateDebugDialogs>,
     asset_server: Res<AssetServer>,
     dialog_assets: Res<Assets<Dialog>>,
+    data_overlay_configuration: Res<DataOverlayConfiguration>,
+    vfs_resource: Res<VfsResource>,
+    time: Res<Time>,
 ) {
     let ui_state = &mut *ui_state;
+
+    if ui_state.hot_reload {
+        ui_state.hot_reload_timer += time.delta();
+
+        if ui_state.hot_reload_timer > HOT_RELOAD_POLL_INTERVAL {
+            ui_state.hot_reload_timer = Duration::ZERO;
+
+            // Our VFS asset io cannot watch for changes, so poll the host data directory
+            if let Some(data_path) = data_overlay_configuration.data_path.as_ref() {
+                for (handle_id, _) in dialog_assets.iter() {
+                    let Some(asset_path) = asset_server.get_handle_path(handle_id) else {
+                        continue;
+                    };
+                    let asset_path = asset_path.path().to_string_lossy().to_string();
+                    let Ok(modified_time) = std::fs::metadata(data_path.join(&asset_path))
+                        .and_then(|metadata| metadata.modified())
+                    else {
+                        continue;
+                    };
+
+                    match ui_state.hot_reload_modified_times.get(&asset_path) {
+                        Some(previous_time) if *previous_time != modified_time => {
+                            log::info!("Hot reloading dialog {}", asset_path);
+                            asset_server.reload_asset(asset_path.as_str());
+                        }
+                        _ => {}
+                    }
+
+                    ui_state
+                        .hot_reload_modified_times
+                        .insert(asset_path, modified_time);
+                }
+            }
+        }
+    }
+
     if !ui_state_debug_windows.debug_ui_open {
         return;
     }
@@ -44,6 +166,14 @@ pub fn ui_debug_dialog_list_system(
                         filter_changed = true;
                     }
                     ui.end_row();
+
+                    ui.label("Hot Reload:");
+                    ui.add_enabled(
+                        data_overlay_configuration.data_path.is_some(),
+                        egui::Checkbox::new(&mut ui_state.hot_reload, "Enabled"),
+                    )
+                    .on_disabled_hover_text("Requires a host directory filesystem device");
+                    ui.end_row();
                 });
 
             if ui_state.filter_name.is_empty() && ui_state.filtered_dialogs.is_empty() {
@@ -107,6 +237,8 @@ pub fn ui_debug_dialog_list_system(
                             row.col(|ui| {
                                 if ui.button("View").clicked() {
                                     ui_state.draw_dialog = Some(Handle::weak(*handle_id));
+                                    ui_state.edit_dialog = None;
+                                    ui_state.edit_dialog_path = path.clone();
                                 }
                             });
                         },
@@ -118,20 +250,176 @@ pub fn ui_debug_dialog_list_system(
         return;
     }
 
-    if let Some(dialog) = ui_state
-        .draw_dialog
-        .as_ref()
-        .and_then(|handle| dialog_assets.get(handle))
-    {
-        egui::Window::new("DebugDialogViewer")
-            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-            .frame(egui::Frame::none())
-            .title_bar(false)
-            .resizable(false)
-            .default_width(dialog.width)
-            .default_height(dialog.height)
-            .show(egui_context.ctx_mut(), |ui| {
-                dialog.draw(ui, DataBindings::default(), |_, _| {})
+    if ui_state.edit_dialog.is_none() {
+        // Take a copy of the dialog for editing once it has been loaded
+        ui_state.edit_dialog = ui_state
+            .draw_dialog
+            .as_ref()
+            .and_then(|handle| dialog_assets.get(handle))
+            .filter(|dialog| dialog.loaded)
+            .cloned();
+        ui_state.edit_selected_widget = None;
+        ui_state.edit_modified_widgets.clear();
+    }
+
+    let Some(dialog) = ui_state.edit_dialog.as_mut() else {
+        return;
+    };
+
+    let show_bounds = ui_state.edit_show_bounds;
+    let mut selected_widget = ui_state.edit_selected_widget;
+    egui::Window::new("DebugDialogViewer")
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .frame(egui::Frame::none())
+        .title_bar(false)
+        .resizable(false)
+        .default_width(dialog.width)
+        .default_height(dialog.height)
+        .show(egui_context.ctx_mut(), |ui| {
+            dialog.draw(ui, DataBindings::default(), |_, _| {});
+
+            if !show_bounds {
+                return;
+            }
+
+            let clicked_pos = ui.input(|input| {
+                input
+                    .pointer
+                    .primary_clicked()
+                    .then(|| input.pointer.interact_pos())
+                    .flatten()
             });
+            let mut clicked_widget: Option<(i32, f32)> = None;
+            let painter = ui.painter();
+            for_each_widget_rect(&dialog.widgets, ui.min_rect().min, &mut |widget, rect| {
+                let id = widget.id();
+                let color = if selected_widget == Some(id) {
+                    egui::Color32::RED
+                } else {
+                    egui::Color32::YELLOW
+                };
+                painter.rect_stroke(rect, 0.0, egui::Stroke::new(1.0, color));
+                painter.text(
+                    rect.left_top(),
+                    egui::Align2::LEFT_TOP,
+                    format!("{}", id),
+                    egui::FontId::monospace(10.0),
+                    color,
+                );
+
+                // Select the smallest widget under the cursor
+                if clicked_pos.map_or(false, |pos| rect.contains(pos))
+                    && clicked_widget.map_or(true, |(_, area)| rect.area() < area)
+                {
+                    clicked_widget = Some((id, rect.area()));
+                }
+            });
+
+            if let Some((id, _)) = clicked_widget {
+                selected_widget = Some(id);
+            }
+        });
+    ui_state.edit_selected_widget = selected_widget;
+
+    let mut revert = false;
+    let mut close = false;
+    egui::Window::new("Dialog Editor")
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.label(&ui_state.edit_dialog_path);
+            ui.checkbox(&mut ui_state.edit_show_bounds, "Show widget bounds");
+            ui.separator();
+
+            let mut moved = false;
+            if let Some(id) = ui_state.edit_selected_widget {
+                if let Some((x, y)) = dialog
+                    .get_widget_mut(id)
+                    .and_then(|widget| widget.position_mut())
+                {
+                    egui::Grid::new("dialog_editor_grid")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Widget ID:");
+                            ui.label(format!("{}", id));
+                            ui.end_row();
+
+                            ui.label("X:");
+                            moved |= ui.add(egui::DragValue::new(x).speed(1.0)).changed();
+                            ui.end_row();
+
+                            ui.label("Y:");
+                            moved |= ui.add(egui::DragValue::new(y).speed(1.0)).changed();
+                            ui.end_row();
+                        });
+
+                    // Nudge with the arrow keys, hold shift to move further
+                    if !ui.ctx().wants_keyboard_input() {
+                        ui.input(|input| {
+                            let step = if input.modifiers.shift { 10.0 } else { 1.0 };
+                            if input.key_pressed(egui::Key::ArrowLeft) {
+                                *x -= step;
+                                moved = true;
+                            }
+                            if input.key_pressed(egui::Key::ArrowRight) {
+                                *x += step;
+                                moved = true;
+                            }
+                            if input.key_pressed(egui::Key::ArrowUp) {
+                                *y -= step;
+                                moved = true;
+                            }
+                            if input.key_pressed(egui::Key::ArrowDown) {
+                                *y += step;
+                                moved = true;
+                            }
+                        });
+                    }
+                } else {
+                    ui.label(format!("Widget {} cannot be moved", id));
+                }
+
+                if moved {
+                    ui_state.edit_modified_widgets.insert(id);
+                }
+            } else {
+                ui.label("Click a widget to select it");
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(
+                        !ui_state.edit_modified_widgets.is_empty(),
+                        egui::Button::new("Export XML"),
+                    )
+                    .clicked()
+                {
+                    ui_state.edit_status = match export_dialog_xml(
+                        &vfs_resource,
+                        &ui_state.edit_dialog_path,
+                        dialog,
+                        &ui_state.edit_modified_widgets,
+                    ) {
+                        Ok(path) => format!("Exported to {}", path.to_string_lossy()),
+                        Err(error) => format!("Export failed: {}", error),
+                    };
+                }
+
+                revert = ui.button("Revert").clicked();
+                close = ui.button("Close").clicked();
+            });
+
+            if !ui_state.edit_status.is_empty() {
+                ui.label(&ui_state.edit_status);
+            }
+        });
+
+    if close {
+        ui_state.draw_dialog = None;
+    }
+
+    if revert || close {
+        ui_state.edit_dialog = None;
+        ui_state.edit_status.clear();
     }
 }
@@ -1,10 +1,14 @@
 use bevy::{
-    prelude::{Camera3d, DirectionalLight, Entity, Mut, With, World},
+    prelude::{Camera3d, DirectionalLight, Entity, Mut, With, Without, World},
     window::PrimaryWindow,
 };
 use bevy_egui::EguiContext;
 
-use crate::{components::PlayerCharacter, resources::DebugInspector, ui::UiStateDebugWindows};
+use crate::{
+    components::{PlayerCharacter, TargetCamera},
+    resources::DebugInspector,
+    ui::UiStateDebugWindows,
+};
 
 pub fn ui_debug_entity_inspector_system(world: &mut World) {
     let mut egui_context = world
@@ -30,7 +34,7 @@ pub fn ui_debug_entity_inspector_system(world: &mut World) {
                             if ui.button("Camera").clicked() {
                                 debug_inspector_state.entity = Some(
                                     world
-                                        .query_filtered::<Entity, With<Camera3d>>()
+                                        .query_filtered::<Entity, (With<Camera3d>, Without<TargetCamera>)>()
                                         .single(world),
                                 );
                             }
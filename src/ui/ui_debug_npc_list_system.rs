@@ -1,4 +1,4 @@
-use bevy::prelude::{Local, Query, Res, ResMut, State};
+use bevy::prelude::{Commands, Entity, Local, Query, Res, ResMut, State, With};
 use bevy_egui::{egui, EguiContexts};
 use rand::Rng;
 use regex::Regex;
@@ -10,6 +10,7 @@ use rose_game_common::{
 };
 
 use crate::{
+    components::{Companion, PlayerCharacter},
     resources::{AppState, GameConnection, GameData},
     ui::UiStateDebugWindows,
 };
@@ -41,7 +42,9 @@ impl Default for UiStateDebugNpcList {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn ui_debug_npc_list_system(
+    mut commands: Commands,
     mut egui_context: EguiContexts,
     mut ui_state_debug_npc_list: Local<UiStateDebugNpcList>,
     mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
@@ -49,6 +52,7 @@ pub fn ui_debug_npc_list_system(
     game_connection: Option<Res<GameConnection>>,
     game_data: Res<GameData>,
     mut query_npc: Query<&mut Npc>,
+    query_player: Query<Entity, With<PlayerCharacter>>,
 ) {
     if !ui_state_debug_windows.debug_ui_open {
         return;
@@ -150,7 +154,7 @@ pub fn ui_debug_npc_list_system(
                 .column(egui_extras::Column::initial(50.0).at_least(50.0))
                 .column(egui_extras::Column::remainder().at_least(80.0))
                 .column(egui_extras::Column::initial(50.0).at_least(50.0))
-                .column(egui_extras::Column::initial(60.0).at_least(60.0))
+                .column(egui_extras::Column::initial(130.0).at_least(60.0))
                 .header(20.0, |mut header| {
                     header.col(|ui| {
                         ui.heading("ID");
@@ -228,6 +232,14 @@ pub fn ui_debug_npc_list_system(
                                                     .ok();
                                             }
                                         }
+
+                                        if ui.button("Companion").clicked() {
+                                            if let Ok(player_entity) = query_player.get_single() {
+                                                commands.entity(player_entity).insert(Companion {
+                                                    npc_id: npc_data.id,
+                                                });
+                                            }
+                                        }
                                     }
                                     AppState::ModelViewer => {
                                         if ui.button("View").clicked() {
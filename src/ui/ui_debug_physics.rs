@@ -6,6 +6,7 @@ use bevy::{
     prelude::{
         shape, Assets, Camera, Camera3d, Color, Commands, ComputedVisibility, GlobalTransform,
         Handle, KeyCode, Local, Mesh, Query, Res, ResMut, Time, Transform, Visibility, With,
+        Without,
     },
     window::{PrimaryWindow, Window},
 };
@@ -19,7 +20,9 @@ use rose_data::NpcId;
 use rose_game_common::components::Npc;
 
 use crate::{
-    components::{ColliderEntity, COLLISION_FILTER_CLICKABLE, COLLISION_GROUP_PHYSICS_TOY},
+    components::{
+        ColliderEntity, TargetCamera, COLLISION_FILTER_CLICKABLE, COLLISION_GROUP_PHYSICS_TOY,
+    },
     ui::UiStateDebugWindows,
 };
 
@@ -102,7 +105,7 @@ pub fn ui_debug_physics_system(
     rapier_context: Res<RapierContext>,
     time: Res<Time>,
     query_primary_window: Query<&Window, With<PrimaryWindow>>,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<TargetCamera>)>,
 ) {
     if !ui_state_debug_windows.debug_ui_open {
         return;
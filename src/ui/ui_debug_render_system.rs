@@ -50,6 +50,20 @@ pub fn ui_debug_render_system(
                 &mut debug_render_config.directional_light_frustum_freeze,
                 "Freeze Render Directional Light Frustum",
             );
+            ui.checkbox(
+                &mut debug_render_config.monster_spawns,
+                "Show Monster Spawns and Respawn Timers",
+            );
+            ui.checkbox(&mut debug_render_config.command_paths, "Show Command Paths");
+            ui.checkbox(&mut debug_render_config.velocities, "Show Velocities");
+            ui.checkbox(
+                &mut debug_render_config.npc_aggro_ranges,
+                "Show NPC Aggro Ranges",
+            );
+            ui.checkbox(
+                &mut debug_render_config.skill_ranges,
+                "Show Hovered Hotbar Skill Range",
+            );
 
             if ui
                 .checkbox(
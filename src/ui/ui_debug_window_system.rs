@@ -2,16 +2,18 @@ use bevy::{
     input::Input,
     math::{EulerRot, Vec3},
     prelude::{
-        Camera3d, Commands, Entity, KeyCode, Local, NextState, Query, Res, ResMut, Resource,
-        Transform, With,
+        Camera3d, Commands, Entity, KeyCode, Local, MouseButton, NextState, Query, Res, ResMut,
+        Resource, Transform, With, Without,
     },
 };
 use bevy_egui::{egui, EguiContexts};
 use rose_game_common::messages::client::ClientMessage;
 
 use crate::{
-    components::PlayerCharacter,
-    resources::{AppState, DebugInspector, GameConnection, WorldConnection},
+    components::{PlayerCharacter, TargetCamera},
+    resources::{
+        AppState, DebugInspector, GameConnection, KeyBindingAction, KeyBindings, WorldConnection,
+    },
     systems::{FreeCamera, OrbitCamera},
 };
 
@@ -58,15 +60,17 @@ pub fn ui_debug_menu_system(
     mut egui_context: EguiContexts,
     mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
     mut ui_state_debug_menu: Local<UiStateDebugMenu>,
-    query_cameras: Query<(Entity, &Transform), With<Camera3d>>,
+    query_cameras: Query<(Entity, &Transform), (With<Camera3d>, Without<TargetCamera>)>,
     query_player: Query<Entity, With<PlayerCharacter>>,
     game_connection: Option<Res<GameConnection>>,
     world_connection: Option<Res<WorldConnection>>,
     keyboard: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
     mut debug_inspector: ResMut<DebugInspector>,
     mut app_state_next: ResMut<NextState<AppState>>,
 ) {
-    if keyboard.pressed(KeyCode::ControlLeft) && keyboard.just_pressed(KeyCode::D) {
+    if key_bindings.just_pressed(KeyBindingAction::ToggleDebugUi, &keyboard, &mouse) {
         ui_state_debug_windows.debug_ui_open = !ui_state_debug_windows.debug_ui_open;
     }
 
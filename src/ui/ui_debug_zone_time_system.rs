@@ -1,4 +1,7 @@
-use bevy::prelude::{Local, Res, ResMut};
+use bevy::{
+    prelude::{Local, Res, ResMut},
+    time::Time,
+};
 use bevy_egui::{egui, EguiContexts};
 use rose_data::WORLD_TICK_DURATION;
 
@@ -10,7 +13,8 @@ use crate::{
 #[derive(Default)]
 pub struct UiStateDebugZoneTime {
     pub overwrite_time_enabled: bool,
-    pub overwrite_time_value: u32,
+    pub overwrite_time_value: f32,
+    pub overwrite_time_speed: f32,
 }
 
 pub fn ui_debug_zone_time_system(
@@ -21,6 +25,7 @@ pub fn ui_debug_zone_time_system(
     game_data: Res<GameData>,
     world_time: Res<WorldTime>,
     mut zone_time: ResMut<ZoneTime>,
+    time: Res<Time>,
 ) {
     if !ui_state_debug_windows.debug_ui_open {
         return;
@@ -57,20 +62,63 @@ pub fn ui_debug_zone_time_system(
                     ui.label(format!("{}", zone_time.time));
                     ui.end_row();
 
-                    ui.checkbox(
-                        &mut ui_state_debug_zone_time.overwrite_time_enabled,
-                        "Overwrite Time",
-                    );
+                    if ui
+                        .checkbox(
+                            &mut ui_state_debug_zone_time.overwrite_time_enabled,
+                            "Overwrite Time",
+                        )
+                        .changed()
+                    {
+                        // Start from the current time so enabling does not cause a jump
+                        ui_state_debug_zone_time.overwrite_time_value = zone_time.time as f32;
+                    }
                     ui.add(
                         egui::Slider::new(
                             &mut ui_state_debug_zone_time.overwrite_time_value,
-                            0..=zone_data.day_cycle,
+                            0.0..=zone_data.day_cycle as f32,
                         )
+                        .step_by(1.0)
                         .drag_value_speed(0.25),
                     );
                     ui.end_row();
 
+                    ui.label("Exact Tick:");
+                    let mut exact_tick = ui_state_debug_zone_time.overwrite_time_value as u32;
+                    if ui
+                        .add(
+                            egui::DragValue::new(&mut exact_tick)
+                                .clamp_range(0..=zone_data.day_cycle),
+                        )
+                        .changed()
+                    {
+                        ui_state_debug_zone_time.overwrite_time_enabled = true;
+                        ui_state_debug_zone_time.overwrite_time_value = exact_tick as f32;
+                    }
+                    ui.end_row();
+
+                    ui.label("Speed:");
+                    ui.horizontal(|ui| {
+                        for (speed, label) in
+                            [(0.0, "Frozen"), (1.0, "1x"), (10.0, "10x"), (100.0, "100x")]
+                        {
+                            ui.selectable_value(
+                                &mut ui_state_debug_zone_time.overwrite_time_speed,
+                                speed,
+                                label,
+                            );
+                        }
+                    });
+                    ui.end_row();
+
                     if ui_state_debug_zone_time.overwrite_time_enabled {
+                        // Advance the overwritten time client side, wrapping around the day cycle
+                        let day_cycle = zone_data.day_cycle.max(1) as f32;
+                        ui_state_debug_zone_time.overwrite_time_value = (ui_state_debug_zone_time
+                            .overwrite_time_value
+                            + ui_state_debug_zone_time.overwrite_time_speed * time.delta_seconds()
+                                / WORLD_TICK_DURATION.as_secs_f32())
+                            % day_cycle;
+
                         zone_time.debug_overwrite_time =
                             Some(ui_state_debug_zone_time.overwrite_time_value);
                     } else {
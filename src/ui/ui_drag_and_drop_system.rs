@@ -1,27 +1,89 @@
-use bevy::prelude::{EventWriter, Local, ResMut, Resource};
+use bevy::prelude::{EventWriter, Local, Res, ResMut, Resource};
 use bevy_egui::{egui, EguiContexts};
 
 use rose_game_common::components::ItemSlot;
 
 use crate::{
     events::{NpcStoreEvent, PlayerCommandEvent},
-    ui::DragAndDropId,
+    ui::{DragAndDropId, UiStateWindows},
 };
 
 #[derive(Default, Resource)]
 pub struct UiStateDragAndDrop {
     pub dragged_item: Option<DragAndDropId>,
+
+    /// An item which was shift clicked, to be moved to whichever other container is open
+    pub quick_move_item: Option<DragAndDropId>,
+
+    /// Set by the npc store window whilst it is open, as it is not tracked in UiStateWindows
+    pub npc_store_open: bool,
+}
+
+impl UiStateDragAndDrop {
+    pub fn handle_quick_move(&mut self, response: &egui::Response, dnd_id: DragAndDropId) {
+        if response.clicked() && response.ctx.input(|input| input.modifiers.shift) {
+            self.quick_move_item = Some(dnd_id);
+        }
+    }
+}
+
+fn handle_quick_move(
+    dnd_id: DragAndDropId,
+    ui_state_dnd: &UiStateDragAndDrop,
+    ui_state_windows: &UiStateWindows,
+    player_command_events: &mut EventWriter<PlayerCommandEvent>,
+    npc_store_events: &mut EventWriter<NpcStoreEvent>,
+) {
+    match dnd_id {
+        DragAndDropId::Inventory(item_slot @ ItemSlot::Inventory(_, _)) => {
+            if ui_state_windows.bank_open {
+                player_command_events.send(PlayerCommandEvent::BankDepositItem(item_slot));
+            } else if ui_state_dnd.npc_store_open {
+                npc_store_events.send(NpcStoreEvent::AddToSellList(item_slot));
+            }
+        }
+        DragAndDropId::Bank(bank_slot_index) => {
+            if ui_state_windows.inventory_open {
+                player_command_events.send(PlayerCommandEvent::BankWithdrawItem(bank_slot_index));
+            }
+        }
+        DragAndDropId::NpcStore(store_tab_index, store_tab_slot) => {
+            npc_store_events.send(NpcStoreEvent::AddToBuyList {
+                store_tab_index,
+                store_tab_slot,
+                quantity: 1,
+            });
+        }
+        DragAndDropId::NpcStoreBuyList(index) => {
+            npc_store_events.send(NpcStoreEvent::RemoveFromBuyList(index));
+        }
+        DragAndDropId::NpcStoreSellList(index) => {
+            npc_store_events.send(NpcStoreEvent::RemoveFromSellList(index));
+        }
+        _ => {}
+    }
 }
 
 pub fn ui_drag_and_drop_system(
     mut egui_context: EguiContexts,
     mut ui_state_dnd: ResMut<UiStateDragAndDrop>,
+    ui_state_windows: Res<UiStateWindows>,
     mut last_dropped_item: Local<Option<DragAndDropId>>,
     mut player_command_events: EventWriter<PlayerCommandEvent>,
     mut npc_store_events: EventWriter<NpcStoreEvent>,
 ) {
     let ctx = egui_context.ctx_mut();
 
+    if let Some(quick_move_item) = ui_state_dnd.quick_move_item.take() {
+        handle_quick_move(
+            quick_move_item,
+            &ui_state_dnd,
+            &ui_state_windows,
+            &mut player_command_events,
+            &mut npc_store_events,
+        );
+    }
+
     // Handle a drag and drop which was dropped on nothing
     if let Some(last_dropped_item) = last_dropped_item.take() {
         if !ctx.is_pointer_over_area() {
@@ -0,0 +1,113 @@
+use bevy::{
+    app::AppExit,
+    prelude::{Commands, EventWriter, NextState, Res, ResMut},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::ZoneId;
+use rose_game_common::messages::client::ClientMessage;
+
+use crate::{
+    events::LoadZoneEvent,
+    resources::{
+        teardown_game_connection, AppState, ChannelSwitch, ClientEntityList, InputFocus,
+        WorldConnection,
+    },
+    ui::UiStateWindows,
+};
+
+const ESCAPE_MENU_BUTTON_SIZE: egui::Vec2 = egui::vec2(160.0, 24.0);
+
+pub fn ui_escape_menu_system(
+    mut commands: Commands,
+    mut egui_context: EguiContexts,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut app_state_next: ResMut<NextState<AppState>>,
+    mut channel_switch: ResMut<ChannelSwitch>,
+    mut client_entity_list: ResMut<ClientEntityList>,
+    mut load_zone_events: EventWriter<LoadZoneEvent>,
+    mut exit_events: EventWriter<AppExit>,
+    mut input_focus: ResMut<InputFocus>,
+    world_connection: Option<Res<WorldConnection>>,
+) {
+    let ctx = egui_context.ctx_mut();
+
+    if !input_focus.text_input
+        && ctx.input_mut(|input| input.consume_key(egui::Modifiers::NONE, egui::Key::Escape))
+    {
+        ui_state_windows.escape_menu_open = !ui_state_windows.escape_menu_open;
+    }
+
+    if !ui_state_windows.escape_menu_open {
+        return;
+    }
+    input_focus.request_modal();
+
+    // Dim the game behind the menu
+    ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("escape_menu_background"),
+    ))
+    .rect_filled(ctx.screen_rect(), 0.0, egui::Color32::from_black_alpha(128));
+
+    let mut resume = false;
+    let mut open_settings = false;
+    let mut change_character = false;
+    let mut exit = false;
+
+    egui::Window::new("Menu")
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .collapsible(false)
+        .resizable(false)
+        .title_bar(false)
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                resume = ui
+                    .add_sized(ESCAPE_MENU_BUTTON_SIZE, egui::Button::new("Resume"))
+                    .clicked();
+                open_settings = ui
+                    .add_sized(ESCAPE_MENU_BUTTON_SIZE, egui::Button::new("Settings"))
+                    .clicked();
+                change_character = ui
+                    .add_enabled(
+                        world_connection.is_some() && !channel_switch.is_switching(),
+                        egui::Button::new("Change Character").min_size(ESCAPE_MENU_BUTTON_SIZE),
+                    )
+                    .clicked();
+                exit = ui
+                    .add_sized(ESCAPE_MENU_BUTTON_SIZE, egui::Button::new("Exit"))
+                    .clicked();
+            });
+        });
+
+    if resume {
+        ui_state_windows.escape_menu_open = false;
+    }
+
+    if open_settings {
+        ui_state_windows.settings_open = true;
+        ui_state_windows.escape_menu_open = false;
+    }
+
+    if change_character {
+        if let Some(world_connection) = world_connection.as_ref() {
+            // Leave the game server but keep the world connection, which we use to fetch the
+            // character list again for character select
+            teardown_game_connection(&mut commands, &mut client_entity_list);
+            world_connection
+                .client_message_tx
+                .send(ClientMessage::GetCharacterList)
+                .ok();
+
+            channel_switch.cancel();
+            load_zone_events.send(LoadZoneEvent::new(ZoneId::new(4).unwrap()));
+            app_state_next.set(AppState::GameCharacterSelect);
+        }
+
+        ui_state_windows.escape_menu_open = false;
+    }
+
+    if exit {
+        exit_events.send(AppExit);
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::{EventWriter, Res, Time};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    events::FishingEvent,
+    resources::{Fishing, FishingPhase, InputFocus},
+};
+
+const FISHING_BAR_SIZE: egui::Vec2 = egui::vec2(240.0, 16.0);
+const FISHING_BAR_TARGET_WIDTH: f32 = 0.2;
+const FISHING_MARKER_PERIOD: f32 = 1.2;
+
+const FISHING_COLOR_BAR: egui::Color32 = egui::Color32::from_rgb(40, 60, 90);
+const FISHING_COLOR_TARGET: egui::Color32 = egui::Color32::from_rgb(90, 200, 120);
+const FISHING_COLOR_MARKER: egui::Color32 = egui::Color32::from_rgb(255, 230, 120);
+
+pub fn ui_fishing_system(
+    mut egui_context: EguiContexts,
+    mut fishing_events: EventWriter<FishingEvent>,
+    fishing: Res<Fishing>,
+    input_focus: Res<InputFocus>,
+    time: Res<Time>,
+) {
+    if !fishing.is_active() {
+        return;
+    }
+
+    let now = time.elapsed();
+    let mut event = None;
+
+    egui::Area::new("fishing")
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -160.0])
+        .show(egui_context.ctx_mut(), |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_width(FISHING_BAR_SIZE.x);
+
+                match fishing.phase {
+                    FishingPhase::Casting { .. } => {
+                        ui.label("Casting...");
+                    }
+                    FishingPhase::Waiting => {
+                        ui.label("Waiting for a bite...");
+                    }
+                    FishingPhase::Bite { started, .. } => {
+                        ui.label("Something is biting! Reel in when the marker is in the green.");
+
+                        // The marker sweeps back and forth, stopping it in the centre is best
+                        let phase = (now.saturating_sub(started).as_secs_f32()
+                            / FISHING_MARKER_PERIOD)
+                            .fract();
+                        let marker = 1.0 - (phase * 2.0 - 1.0).abs();
+
+                        let (rect, _) =
+                            ui.allocate_exact_size(FISHING_BAR_SIZE, egui::Sense::hover());
+                        let painter = ui.painter();
+                        painter.rect_filled(rect, 2.0, FISHING_COLOR_BAR);
+                        painter.rect_filled(
+                            egui::Rect::from_center_size(
+                                rect.center(),
+                                egui::vec2(rect.width() * FISHING_BAR_TARGET_WIDTH, rect.height()),
+                            ),
+                            0.0,
+                            FISHING_COLOR_TARGET,
+                        );
+                        let marker_x = rect.left() + marker * rect.width();
+                        painter.line_segment(
+                            [
+                                egui::pos2(marker_x, rect.top() - 2.0),
+                                egui::pos2(marker_x, rect.bottom() + 2.0),
+                            ],
+                            egui::Stroke::new(3.0, FISHING_COLOR_MARKER),
+                        );
+
+                        let reel_pressed = !input_focus.keyboard_captured()
+                            && ui.input(|input| input.key_pressed(egui::Key::Space));
+                        if ui.button("Reel In (Space)").clicked() || reel_pressed {
+                            event = Some(FishingEvent::Reel {
+                                accuracy: 1.0 - (marker - 0.5).abs() * 2.0,
+                            });
+                        }
+                    }
+                    FishingPhase::Reeling { .. } => {
+                        ui.label("Reeling in...");
+                    }
+                    FishingPhase::Idle => {}
+                }
+
+                if !matches!(fishing.phase, FishingPhase::Bite { .. })
+                    && ui.button("Stop Fishing").clicked()
+                {
+                    event = Some(FishingEvent::Cancel);
+                }
+            });
+        });
+
+    if let Some(event) = event {
+        fishing_events.send(event);
+    }
+}
@@ -1,8 +1,8 @@
-use bevy::prelude::{Assets, EventWriter, Local, Res, ResMut};
+use bevy::prelude::{Assets, EventWriter, Input, KeyCode, Local, MouseButton, Res, ResMut};
 use bevy_egui::{egui, EguiContexts};
 
 use crate::{
-    resources::UiResources,
+    resources::{InputFocus, KeyBindingAction, KeyBindings, UiResources},
     ui::{
         widgets::{DataBindings, Dialog},
         UiSoundEvent, UiStateWindows,
@@ -33,6 +33,10 @@ pub fn ui_game_menu_system(
     ui_resources: Res<UiResources>,
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     dialog_assets: Res<Assets<Dialog>>,
+    input_focus: Res<InputFocus>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
 ) {
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_game_menu) {
         dialog
@@ -50,6 +54,8 @@ pub fn ui_game_menu_system(
     let mut response_button_clan = None;
     let mut response_button_help = None;
     let mut response_button_info = None;
+    let mut response_button_change_channel = None;
+    let mut response_button_achievements = None;
 
     let response = egui::Window::new("Game Menu")
         .frame(egui::Frame::none())
@@ -80,6 +86,9 @@ pub fn ui_game_menu_system(
                 },
                 |_, _| {},
             );
+
+            response_button_change_channel = Some(ui.button("Change Channel"));
+            response_button_achievements = Some(ui.button("Achievements"));
         });
 
     if let Some(response) = response {
@@ -142,42 +151,60 @@ pub fn ui_game_menu_system(
     }
 
     if response_button_info.map_or(false, |r| r.clicked()) {
-        // TODO: Info dialog
+        ui_state_windows.session_stats_open = !ui_state_windows.session_stats_open;
         ui_state_windows.menu_open = false;
     }
 
-    if response_button_exit.map_or(false, |r| r.clicked()) {
-        // TODO: Exit dialog
+    if response_button_change_channel.map_or(false, |r| r.clicked()) {
+        ui_state_windows.channel_list_open = !ui_state_windows.channel_list_open;
         ui_state_windows.menu_open = false;
     }
 
-    if !egui_context.ctx_mut().wants_keyboard_input() {
-        egui_context.ctx_mut().input_mut(|input| {
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::A) {
-                ui_state_windows.character_info_open = !ui_state_windows.character_info_open;
-            }
-
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::I)
-                || input.consume_key(egui::Modifiers::ALT, egui::Key::V)
-            {
-                ui_state_windows.inventory_open = !ui_state_windows.inventory_open;
-            }
-
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::N) {
-                ui_state_windows.clan_open = !ui_state_windows.clan_open;
-            }
-
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::S) {
-                ui_state_windows.skill_list_open = !ui_state_windows.skill_list_open;
-            }
+    if response_button_achievements.map_or(false, |r| r.clicked()) {
+        ui_state_windows.achievements_open = !ui_state_windows.achievements_open;
+        ui_state_windows.menu_open = false;
+    }
 
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::Q) {
-                ui_state_windows.quest_list_open = !ui_state_windows.quest_list_open;
-            }
+    if response_button_exit.map_or(false, |r| r.clicked()) {
+        // TODO: Exit dialog
+        ui_state_windows.menu_open = false;
+    }
 
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::O) {
-                ui_state_windows.settings_open = !ui_state_windows.settings_open;
+    if !input_focus.keyboard_captured() {
+        let ui_state_windows = &mut *ui_state_windows;
+        for (action, open) in [
+            (
+                KeyBindingAction::ToggleCharacterInfo,
+                &mut ui_state_windows.character_info_open,
+            ),
+            (
+                KeyBindingAction::ToggleInventory,
+                &mut ui_state_windows.inventory_open,
+            ),
+            (
+                KeyBindingAction::ToggleClan,
+                &mut ui_state_windows.clan_open,
+            ),
+            (
+                KeyBindingAction::ToggleSkillList,
+                &mut ui_state_windows.skill_list_open,
+            ),
+            (
+                KeyBindingAction::ToggleQuestList,
+                &mut ui_state_windows.quest_list_open,
+            ),
+            (
+                KeyBindingAction::ToggleSettings,
+                &mut ui_state_windows.settings_open,
+            ),
+            (
+                KeyBindingAction::ToggleAchievements,
+                &mut ui_state_windows.achievements_open,
+            ),
+        ] {
+            if key_bindings.just_pressed(action, &keyboard_input, &mouse_input) {
+                *open = !*open;
             }
-        });
+        }
     }
 }
@@ -0,0 +1,304 @@
+use std::path::Path;
+
+use bevy::prelude::{Input, KeyCode, Local, MouseButton, Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    resources::{
+        CharacterSettings, DataOverlayConfiguration, InputFocus, KeyBindingAction, KeyBindings,
+    },
+    ui::UiStateWindows,
+};
+
+const HELP_TOPICS_DIRECTORY: &str = "HELP";
+
+const MOUSE_CONTROLS: [(&str, &str); 4] = [
+    ("Mouse Wheel", "Zoom camera"),
+    ("Alt + Hotbar", "Cast beneficial skill on self"),
+    ("Shift + Click Minimap", "Add an auto travel waypoint"),
+    ("Ctrl + Click Minimap", "Ping a location in chat"),
+];
+
+struct WindowTip {
+    key: &'static str,
+    is_open: fn(&UiStateWindows) -> bool,
+    text: &'static str,
+}
+
+const WINDOW_TIPS: [WindowTip; 7] = [
+    WindowTip {
+        key: "inventory",
+        is_open: |windows| windows.inventory_open,
+        text:
+            "Drag items onto your character to equip them, or onto the hotbar to use them quickly.",
+    },
+    WindowTip {
+        key: "character_info",
+        is_open: |windows| windows.character_info_open,
+        text: "Each level grants stat points which can be spent here to increase your abilities.",
+    },
+    WindowTip {
+        key: "skill_list",
+        is_open: |windows| windows.skill_list_open,
+        text: "Drag skills onto the hotbar, then press the key shown on the slot to use them.",
+    },
+    WindowTip {
+        key: "skill_tree",
+        is_open: |windows| windows.skill_tree_open,
+        text: "Skill points earned from levelling are spent here to learn and upgrade skills.",
+    },
+    WindowTip {
+        key: "quest_list",
+        is_open: |windows| windows.quest_list_open,
+        text: "Tracked quests are shown on screen, talk to NPCs to find new quests.",
+    },
+    WindowTip {
+        key: "party",
+        is_open: |windows| windows.party_open,
+        text: "Party members share experience and can target each other with group skills.",
+    },
+    WindowTip {
+        key: "clan",
+        is_open: |windows| windows.clan_open,
+        text: "Clan members can chat together and use clan skills once the clan has levelled.",
+    },
+];
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum HelpPage {
+    Controls,
+    Guide,
+}
+
+struct HelpTopic {
+    title: String,
+    lines: Vec<String>,
+}
+
+pub struct UiStateHelp {
+    page: HelpPage,
+    topics: Option<Vec<HelpTopic>>,
+    selected_topic: usize,
+    previous_open: [bool; WINDOW_TIPS.len()],
+    active_tip: Option<usize>,
+}
+
+impl Default for UiStateHelp {
+    fn default() -> Self {
+        Self {
+            page: HelpPage::Controls,
+            topics: None,
+            selected_topic: 0,
+            previous_open: [false; WINDOW_TIPS.len()],
+            active_tip: None,
+        }
+    }
+}
+
+fn load_help_topics(data_path: Option<&Path>) -> Vec<HelpTopic> {
+    let Some(read_dir) = data_path
+        .and_then(|data_path| std::fs::read_dir(data_path.join(HELP_TOPICS_DIRECTORY)).ok())
+    else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<_> = read_dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .map_or(false, |extension| extension.eq_ignore_ascii_case("md"))
+        })
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let text = std::fs::read_to_string(&path).ok()?;
+            let lines: Vec<String> = text.lines().map(|line| line.to_string()).collect();
+            let title = lines
+                .iter()
+                .find_map(|line| line.strip_prefix("# "))
+                .map(|title| title.trim().to_string())
+                .or_else(|| {
+                    path.file_stem()
+                        .map(|stem| stem.to_string_lossy().to_string())
+                })?;
+            Some(HelpTopic { title, lines })
+        })
+        .collect()
+}
+
+fn ui_add_help_topic(ui: &mut egui::Ui, topic: &HelpTopic) {
+    for line in topic.lines.iter() {
+        if let Some(heading) = line.strip_prefix("# ") {
+            ui.heading(heading);
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            ui.strong(heading);
+        } else if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+            ui.label(format!("  • {}", item));
+        } else if line.trim().is_empty() {
+            ui.add_space(4.0);
+        } else {
+            ui.label(line);
+        }
+    }
+}
+
+pub fn ui_help_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_help: Local<UiStateHelp>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut character_settings: ResMut<CharacterSettings>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    input_focus: Res<InputFocus>,
+    key_bindings: Res<KeyBindings>,
+    data_overlay_configuration: Res<DataOverlayConfiguration>,
+) {
+    let ui_state_help = &mut *ui_state_help;
+
+    if !input_focus.keyboard_captured()
+        && key_bindings.just_pressed(KeyBindingAction::Help, &keyboard_input, &mouse_input)
+    {
+        ui_state_windows.help_open = !ui_state_windows.help_open;
+    }
+
+    // Show a contextual tip the first time each window is opened
+    for (index, tip) in WINDOW_TIPS.iter().enumerate() {
+        let is_open = (tip.is_open)(&ui_state_windows);
+        if is_open
+            && !ui_state_help.previous_open[index]
+            && !character_settings.seen_help_tips.contains(tip.key)
+        {
+            character_settings
+                .seen_help_tips
+                .insert(tip.key.to_string());
+            ui_state_help.active_tip = Some(index);
+        }
+        ui_state_help.previous_open[index] = is_open;
+    }
+
+    if let Some(tip_index) = ui_state_help.active_tip {
+        let mut tip_open = true;
+        let mut dismissed = false;
+        egui::Window::new("Tip")
+            .id(egui::Id::new("help_window_tip"))
+            .open(&mut tip_open)
+            .resizable(false)
+            .collapsible(false)
+            .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 80.0))
+            .show(egui_context.ctx_mut(), |ui| {
+                ui.set_max_width(300.0);
+                ui.label(WINDOW_TIPS[tip_index].text);
+                ui.horizontal(|ui| {
+                    if ui.button("Got it").clicked() {
+                        dismissed = true;
+                    }
+
+                    if ui.button("Open Guide").clicked() {
+                        ui_state_windows.help_open = true;
+                        ui_state_help.page = HelpPage::Guide;
+                        dismissed = true;
+                    }
+                });
+            });
+
+        if !tip_open || dismissed {
+            ui_state_help.active_tip = None;
+        }
+    }
+
+    if !ui_state_windows.help_open {
+        return;
+    }
+
+    egui::Window::new("Game Guide")
+        .open(&mut ui_state_windows.help_open)
+        .default_width(450.0)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut ui_state_help.page, HelpPage::Controls, "Controls");
+                ui.selectable_value(&mut ui_state_help.page, HelpPage::Guide, "Guide");
+            });
+            ui.separator();
+
+            match ui_state_help.page {
+                HelpPage::Controls => {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        egui::Grid::new("help_controls_grid")
+                            .num_columns(2)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for (action, key_binding) in key_bindings.bindings.iter() {
+                                    if matches!(action, KeyBindingAction::ToggleDebugUi)
+                                        && !cfg!(debug_assertions)
+                                    {
+                                        continue;
+                                    }
+
+                                    ui.strong(key_binding.display_name());
+                                    ui.label(action.description());
+                                    ui.end_row();
+                                }
+
+                                for (input, description) in MOUSE_CONTROLS {
+                                    ui.strong(input);
+                                    ui.label(description);
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                }
+                HelpPage::Guide => {
+                    let topics = ui_state_help.topics.get_or_insert_with(|| {
+                        load_help_topics(data_overlay_configuration.data_path.as_deref())
+                    });
+
+                    if topics.is_empty() {
+                        ui.label(format!(
+                            "No guide topics found, add markdown files to the {} directory of the data path.",
+                            HELP_TOPICS_DIRECTORY
+                        ));
+                        return;
+                    }
+
+                    ui_state_help.selected_topic =
+                        ui_state_help.selected_topic.min(topics.len() - 1);
+
+                    let mut reload_topics = false;
+                    ui.horizontal_top(|ui| {
+                        ui.vertical(|ui| {
+                            ui.set_width(120.0);
+                            for (index, topic) in topics.iter().enumerate() {
+                                ui.selectable_value(
+                                    &mut ui_state_help.selected_topic,
+                                    index,
+                                    &topic.title,
+                                );
+                            }
+
+                            if ui.button("Reload").clicked() {
+                                reload_topics = true;
+                            }
+                        });
+
+                        ui.separator();
+
+                        if let Some(topic) = topics.get(ui_state_help.selected_topic) {
+                            egui::ScrollArea::vertical()
+                                .max_height(400.0)
+                                .show(ui, |ui| {
+                                    ui_add_help_topic(ui, topic);
+                                });
+                        }
+                    });
+
+                    if reload_topics {
+                        ui_state_help.topics = None;
+                    }
+                }
+            }
+        });
+}
@@ -1,18 +1,27 @@
 use bevy::{
-    ecs::query::WorldQuery,
+    ecs::{query::WorldQuery, system::SystemParam},
     input::Input,
-    prelude::{Assets, EventWriter, KeyCode, Local, Query, Res, ResMut, With},
+    prelude::{Assets, EventWriter, KeyCode, Local, MouseButton, Query, Res, ResMut, With},
 };
 use bevy_egui::{egui, EguiContexts};
 
+use rose_data::SkillId;
 use rose_game_common::components::{
-    Equipment, Hotbar, HotbarSlot, Inventory, SkillList, HOTBAR_NUM_PAGES, HOTBAR_PAGE_SIZE,
+    Equipment, Hotbar, HotbarSlot, Inventory, SkillList, SkillSlot, HOTBAR_NUM_PAGES,
+    HOTBAR_PAGE_SIZE,
 };
 
 use crate::{
+    bundles::{
+        skill_can_target_entity, skill_can_use, skill_has_target_entity, skill_is_beneficial,
+        SkillCasterQuery, SkillTargetQuery, SkillTargetQueryItem,
+    },
     components::{Cooldowns, PlayerCharacter},
     events::PlayerCommandEvent,
-    resources::{GameData, UiResources},
+    resources::{
+        CharacterSettings, DebugRenderConfig, GameData, HudSettings, HudWidget, InputFocus,
+        InterfaceSettings, KeyBindings, SelectedTarget, SkillQueue, UiResources,
+    },
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem, SkillTooltipType},
         ui_add_item_tooltip, ui_add_skill_tooltip,
@@ -33,16 +42,14 @@ const IID_NUMBER: i32 = 20;
 
 pub struct UiStateHotBar {
     dialog_instance: DialogInstance,
-    current_page: usize,
-    is_vertical: bool,
+    applied_layout: Option<(usize, bool)>,
 }
 
 impl Default for UiStateHotBar {
     fn default() -> Self {
         Self {
             dialog_instance: DialogInstance::new("DLGQUICKBAR.XML"),
-            current_page: 0,
-            is_vertical: false,
+            applied_layout: None,
         }
     }
 }
@@ -55,6 +62,7 @@ pub struct PlayerQuery<'w> {
     equipment: &'w Equipment,
     inventory: &'w Inventory,
     skill_list: &'w SkillList,
+    skill_caster: SkillCasterQuery<'w>,
 }
 
 fn hotbar_drag_accepts(drag_source: &DragAndDropId) -> bool {
@@ -72,15 +80,41 @@ fn ui_add_hotbar_slot(
     player_tooltip_data: Option<&PlayerTooltipQueryItem>,
     game_data: &GameData,
     ui_resources: &UiResources,
+    interface_settings: &InterfaceSettings,
+    queued_skill: Option<SkillSlot>,
+    selected_skill_target: Option<&SkillTargetQueryItem>,
+    self_skill_target: Option<&SkillTargetQueryItem>,
     ui_state_dnd: &mut UiStateDragAndDrop,
     use_slot: bool,
+    self_cast: bool,
     player_command_events: &mut EventWriter<PlayerCommandEvent>,
-) {
+) -> Option<SkillId> {
     let hotbar_slot = player.hotbar.pages[hotbar_index.0][hotbar_index.1].as_ref();
     let mut dropped_item = None;
     let drag_and_drop_slot = match hotbar_slot {
         Some(HotbarSlot::Skill(skill_slot)) => {
             let skill = player.skill_list.get_skill(*skill_slot);
+            let unusable = skill
+                .and_then(|skill_id| game_data.skills.get_skill(skill_id))
+                .map_or(false, |skill_data| {
+                    let is_valid_target = |skill_target: Option<&SkillTargetQueryItem>| {
+                        skill_target.map_or(false, |skill_target| {
+                            skill_can_target_entity(&player.skill_caster, skill_target, skill_data)
+                                .is_ok()
+                        })
+                    };
+                    let has_valid_target = if skill_is_beneficial(skill_data)
+                        && (self_cast || interface_settings.auto_self_cast)
+                    {
+                        (!self_cast && is_valid_target(selected_skill_target))
+                            || is_valid_target(self_skill_target)
+                    } else {
+                        is_valid_target(selected_skill_target)
+                    };
+
+                    skill_can_use(game_data, &player.skill_caster, skill_data).is_err()
+                        || (skill_has_target_entity(skill_data) && !has_valid_target)
+                });
 
             DragAndDropSlot::with_skill(
                 DragAndDropId::Hotbar(hotbar_index.0, hotbar_index.1),
@@ -93,6 +127,7 @@ fn ui_add_hotbar_slot(
                 &mut dropped_item,
                 [40.0, 40.0],
             )
+            .with_unusable(unusable)
         }
         Some(HotbarSlot::Inventory(item_slot)) => {
             let item = (player.equipment, player.inventory).get_item(*item_slot);
@@ -126,17 +161,50 @@ fn ui_add_hotbar_slot(
     let response = ui
         .allocate_ui_at_rect(
             egui::Rect::from_min_size(pos, egui::vec2(40.0, 40.0)),
-            |ui| egui::Widget::ui(drag_and_drop_slot, ui),
+            |ui| {
+                egui::Widget::ui(
+                    drag_and_drop_slot.with_cooldown_display(
+                        interface_settings.cooldown_text,
+                        interface_settings.cooldown_ready_flash,
+                    ),
+                    ui,
+                )
+            },
         )
         .inner;
 
+    if let (Some(HotbarSlot::Skill(skill_slot)), Some(queued_skill)) = (hotbar_slot, queued_skill) {
+        if *skill_slot == queued_skill {
+            // Dim the skill which is queued to be used next
+            ui.painter().rect_filled(
+                response.rect,
+                egui::Rounding::none(),
+                egui::Color32::from_black_alpha(120),
+            );
+        }
+    }
+
     if use_slot || response.double_clicked() {
-        player_command_events.send(PlayerCommandEvent::UseHotbar(
-            hotbar_index.0,
-            hotbar_index.1,
-        ));
+        if self_cast {
+            player_command_events.send(PlayerCommandEvent::UseHotbarSelfCast(
+                hotbar_index.0,
+                hotbar_index.1,
+            ));
+        } else {
+            player_command_events.send(PlayerCommandEvent::UseHotbar(
+                hotbar_index.0,
+                hotbar_index.1,
+            ));
+        }
     }
 
+    let hovered_skill = match hotbar_slot {
+        Some(HotbarSlot::Skill(skill_slot)) if response.hovered() => {
+            player.skill_list.get_skill(*skill_slot)
+        }
+        _ => None,
+    };
+
     if hotbar_slot.is_some() {
         response.on_hover_ui(|ui| match hotbar_slot {
             Some(HotbarSlot::Inventory(item_slot)) => {
@@ -194,8 +262,26 @@ fn ui_add_hotbar_slot(
         }
         _ => {}
     }
+
+    hovered_skill
 }
 
+#[derive(SystemParam)]
+pub struct HotbarInputParams<'w> {
+    keyboard_input: Res<'w, Input<KeyCode>>,
+    mouse_input: Res<'w, Input<MouseButton>>,
+    key_bindings: Res<'w, KeyBindings>,
+    input_focus: Res<'w, InputFocus>,
+}
+
+#[derive(SystemParam)]
+pub struct HotbarSettingsParams<'w> {
+    interface_settings: Res<'w, InterfaceSettings>,
+    hud_settings: Res<'w, HudSettings>,
+    debug_render_config: ResMut<'w, DebugRenderConfig>,
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn ui_hotbar_system(
     mut egui_context: EguiContexts,
     mut ui_state_hot_bar: Local<UiStateHotBar>,
@@ -203,10 +289,15 @@ pub fn ui_hotbar_system(
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     mut query_player: Query<PlayerQuery, With<PlayerCharacter>>,
     query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
+    query_skill_target: Query<SkillTargetQuery>,
     mut player_command_events: EventWriter<PlayerCommandEvent>,
-    keyboard_input: Res<Input<KeyCode>>,
+    input: HotbarInputParams,
+    mut character_settings: ResMut<CharacterSettings>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
+    mut settings: HotbarSettingsParams,
+    selected_target: Res<SelectedTarget>,
+    skill_queue: Res<SkillQueue>,
     dialog_assets: Res<Assets<Dialog>>,
 ) {
     let ui_state_hot_bar = &mut *ui_state_hot_bar;
@@ -225,27 +316,38 @@ pub fn ui_hotbar_system(
         return;
     };
     let player_tooltip_data = query_player_tooltip.get_single().ok();
+    let self_skill_target = query_skill_target.get(player.skill_caster.entity).ok();
+    let selected_skill_target = query_skill_target
+        .get(
+            selected_target
+                .selected
+                .unwrap_or(player.skill_caster.entity),
+        )
+        .ok();
 
-    let use_hotbar_index = if !egui_context.ctx_mut().wants_keyboard_input() {
-        if keyboard_input.just_pressed(KeyCode::F1) {
-            Some(0)
-        } else if keyboard_input.just_pressed(KeyCode::F2) {
-            Some(1)
-        } else if keyboard_input.just_pressed(KeyCode::F3) {
-            Some(2)
-        } else if keyboard_input.just_pressed(KeyCode::F4) {
-            Some(3)
-        } else if keyboard_input.just_pressed(KeyCode::F5) {
-            Some(4)
-        } else if keyboard_input.just_pressed(KeyCode::F6) {
-            Some(5)
-        } else if keyboard_input.just_pressed(KeyCode::F7) {
-            Some(6)
-        } else if keyboard_input.just_pressed(KeyCode::F8) {
-            Some(7)
-        } else {
-            None
+    let hotbar_settings = &mut character_settings.hotbar;
+    let previous_hotbar_page = hotbar_settings.page;
+    let previous_hotbar_vertical = hotbar_settings.vertical;
+    hotbar_settings.page = hotbar_settings.page.min(HOTBAR_NUM_PAGES - 1);
+
+    if !input.input_focus.keyboard_captured() {
+        if let Some(page) = input
+            .key_bindings
+            .hotbar_page_just_pressed(&input.keyboard_input, &input.mouse_input)
+            .filter(|page| *page < HOTBAR_NUM_PAGES)
+        {
+            hotbar_settings.page = page;
         }
+    }
+
+    // Holding alt forces beneficial skills to be cast on ourself
+    let alt_pressed = input
+        .keyboard_input
+        .any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+    let use_hotbar_index = if !input.input_focus.keyboard_captured() {
+        input
+            .key_bindings
+            .hotbar_slot_just_pressed(&input.keyboard_input, &input.mouse_input)
     } else {
         None
     };
@@ -255,7 +357,8 @@ pub fn ui_hotbar_system(
     let mut response_hnext_button = None;
     let mut response_vprev_button = None;
     let mut response_vnext_button = None;
-    let is_vertical = ui_state_hot_bar.is_vertical;
+    let is_vertical = hotbar_settings.vertical;
+    let current_page = hotbar_settings.page;
 
     let screen_size = egui_context
         .ctx_mut()
@@ -265,14 +368,20 @@ pub fn ui_hotbar_system(
         screen_size.y - dialog.height,
     );
 
-    egui::Window::new("Hot Bar")
+    // Whilst the HUD is hidden the hotbar is still drawn invisibly so its hotkeys keep working
+    let is_visible = settings.hud_settings.is_visible(HudWidget::Hotbar);
+
+    let mut hovered_skill = None;
+    let hotbar_response = egui::Window::new("Hot Bar")
         .frame(egui::Frame::none())
+        .interactable(is_visible)
         .title_bar(false)
         .resizable(false)
         .default_width(dialog.width)
         .default_height(dialog.height)
         .default_pos(default_position)
         .show(egui_context.ctx_mut(), |ui| {
+            ui.set_visible(is_visible);
             dialog.draw(
                 ui,
                 DataBindings {
@@ -295,16 +404,14 @@ pub fn ui_hotbar_system(
                     ..Default::default()
                 },
                 |ui, _bindings| {
-                    let current_page = ui_state_hot_bar.current_page;
-
                     for i in 0..HOTBAR_PAGE_SIZE {
                         let hotbar_index = (current_page, i);
-                        let pos = if ui_state_hot_bar.is_vertical {
+                        let pos = if is_vertical {
                             egui::vec2(2.0, 39.0 + (41.0) * i as f32 + (2 * i / 8) as f32 * 10.0)
                         } else {
                             egui::vec2(39.0 + (41.0) * i as f32 + (2 * i / 8) as f32 * 9.0, 20.0)
                         };
-                        ui_add_hotbar_slot(
+                        if let Some(skill_id) = ui_add_hotbar_slot(
                             ui,
                             ui.min_rect().min + pos,
                             hotbar_index,
@@ -312,38 +419,69 @@ pub fn ui_hotbar_system(
                             player_tooltip_data.as_ref(),
                             &game_data,
                             &ui_resources,
+                            &settings.interface_settings,
+                            skill_queue.skill_slot,
+                            selected_skill_target.as_ref(),
+                            self_skill_target.as_ref(),
                             &mut ui_state_dnd,
                             use_hotbar_index.map_or(false, |use_index| use_index == i),
+                            alt_pressed,
                             &mut player_command_events,
-                        );
+                        ) {
+                            hovered_skill = Some(skill_id);
+                        }
                     }
                 },
             );
         });
+    settings.debug_render_config.hovered_hotbar_skill = hovered_skill;
 
-    let previous_page = ui_state_hot_bar.current_page;
+    // Show the active page number prominently above the hotbar
+    if let Some(hotbar_response) = hotbar_response.filter(|_| is_visible) {
+        egui::Area::new("hotbar_page_label")
+            .fixed_pos(hotbar_response.response.rect.left_top() + egui::vec2(4.0, -24.0))
+            .interactable(false)
+            .show(egui_context.ctx_mut(), |ui| {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Page {} / {}",
+                        current_page + 1,
+                        HOTBAR_NUM_PAGES
+                    ))
+                    .color(egui::Color32::WHITE)
+                    .background_color(egui::Color32::from_black_alpha(160))
+                    .size(16.0)
+                    .strong(),
+                );
+            });
+    }
 
     if response_hnext_button.map_or(false, |r| r.clicked())
         || response_vnext_button.map_or(false, |r| r.clicked())
     {
-        ui_state_hot_bar.current_page = (ui_state_hot_bar.current_page + 1) % HOTBAR_NUM_PAGES;
+        hotbar_settings.page = (hotbar_settings.page + 1) % HOTBAR_NUM_PAGES;
     }
 
     if response_hprev_button.map_or(false, |r| r.clicked())
         || response_vprev_button.map_or(false, |r| r.clicked())
     {
-        if ui_state_hot_bar.current_page == 0 {
-            ui_state_hot_bar.current_page = HOTBAR_NUM_PAGES - 1;
+        if hotbar_settings.page == 0 {
+            hotbar_settings.page = HOTBAR_NUM_PAGES - 1;
         } else {
-            ui_state_hot_bar.current_page -= 1;
+            hotbar_settings.page -= 1;
         }
     }
 
     if response_rotate_button.map_or(false, |r| r.clicked()) {
-        ui_state_hot_bar.is_vertical = !ui_state_hot_bar.is_vertical;
+        hotbar_settings.vertical = !hotbar_settings.vertical;
+    }
+
+    let layout = (hotbar_settings.page, hotbar_settings.vertical);
+    if ui_state_hot_bar.applied_layout != Some(layout) {
+        let (page, vertical) = layout;
 
         if let Some(Widget::Button(button)) = dialog.get_widget_mut(IID_BTN_ROTATE) {
-            if ui_state_hot_bar.is_vertical {
+            if vertical {
                 button.x = 17.0;
                 button.y = 377.0;
             } else {
@@ -353,19 +491,15 @@ pub fn ui_hotbar_system(
         }
 
         if let Some(Widget::Image(sprite)) = dialog.get_widget_mut(IID_NUMBER) {
-            if ui_state_hot_bar.is_vertical {
+            if vertical {
                 sprite.x = 21.0;
                 sprite.y = 20.0;
             } else {
                 sprite.x = 19.0;
                 sprite.y = 24.0;
             }
-        }
-    }
 
-    if ui_state_hot_bar.current_page != previous_page {
-        if let Some(Widget::Image(sprite)) = dialog.get_widget_mut(IID_NUMBER) {
-            sprite.sprite = match ui_state_hot_bar.current_page {
+            sprite.sprite = match page {
                 0 => ui_resources.get_sprite(0, "UI21_NUMBER_1"),
                 1 => ui_resources.get_sprite(0, "UI21_NUMBER_2"),
                 2 => ui_resources.get_sprite(0, "UI21_NUMBER_3"),
@@ -373,5 +507,13 @@ pub fn ui_hotbar_system(
                 _ => None,
             };
         }
+
+        ui_state_hot_bar.applied_layout = Some(layout);
+    }
+
+    if hotbar_settings.page != previous_hotbar_page
+        || hotbar_settings.vertical != previous_hotbar_vertical
+    {
+        character_settings.save();
     }
 }
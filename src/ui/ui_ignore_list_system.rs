@@ -0,0 +1,68 @@
+use bevy::prelude::{Local, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{resources::IgnoreList, ui::UiStateWindows};
+
+#[derive(Default)]
+pub struct UiStateIgnoreList {
+    add_name: String,
+}
+
+pub fn ui_ignore_list_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateIgnoreList>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut ignore_list: ResMut<IgnoreList>,
+) {
+    if !ui_state_windows.ignore_list_open {
+        return;
+    }
+
+    let mut remove_name = None;
+
+    egui::Window::new("Ignore List")
+        .open(&mut ui_state_windows.ignore_list_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.label("Chat, party invites and other requests from these players are ignored.");
+            ui.separator();
+
+            if ignore_list.names.is_empty() {
+                ui.label("You are not ignoring anyone.");
+            } else {
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("ignore_list_grid")
+                            .num_columns(2)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for name in ignore_list.names.iter() {
+                                    ui.label(name);
+                                    if ui.button("Remove").clicked() {
+                                        remove_name = Some(name.clone());
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                let response = ui.text_edit_singleline(&mut ui_state.add_name);
+                let submitted =
+                    response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+
+                if (ui.button("Ignore").clicked() || submitted)
+                    && ignore_list.add(&ui_state.add_name)
+                {
+                    ui_state.add_name.clear();
+                }
+            });
+        });
+
+    if let Some(name) = remove_name {
+        ignore_list.remove(&name);
+    }
+}
@@ -13,8 +13,9 @@ use rose_game_common::components::{
 use crate::{
     components::{Cooldowns, PlayerCharacter},
     events::{NumberInputDialogEvent, PlayerCommandEvent},
-    resources::{GameData, UiResources},
+    resources::{GameData, InterfaceSettings, UiResources},
     ui::{
+        format_money,
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
         ui_add_item_tooltip,
         widgets::{DataBindings, Dialog, Widget},
@@ -222,6 +223,7 @@ fn ui_add_inventory_slot(
     player_tooltip_data: Option<&PlayerTooltipQueryItem>,
     game_data: &GameData,
     ui_resources: &UiResources,
+    interface_settings: &InterfaceSettings,
     item_slot_map: &mut EnumMap<InventoryPageType, Vec<ItemSlot>>,
     ui_state_dnd: &mut UiStateDragAndDrop,
     player_command_events: &mut EventWriter<PlayerCommandEvent>,
@@ -255,6 +257,10 @@ fn ui_add_inventory_slot(
                         &mut ui_state_dnd.dragged_item,
                         &mut dropped_item,
                         [40.0, 40.0],
+                    )
+                    .with_cooldown_display(
+                        interface_settings.cooldown_text,
+                        interface_settings.cooldown_ready_flash,
                     ),
                     ui,
                 )
@@ -272,6 +278,8 @@ fn ui_add_inventory_slot(
     let mut drop_inventory_slot = None;
     let mut swap_inventory_slots = None;
 
+    ui_state_dnd.handle_quick_move(&response, DragAndDropId::Inventory(inventory_slot));
+
     if response.double_clicked() {
         match inventory_slot {
             ItemSlot::Inventory(InventoryPageType::Equipment, _) => {
@@ -466,6 +474,7 @@ pub fn ui_inventory_system(
     dialog_assets: Res<Assets<Dialog>>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
+    interface_settings: Res<InterfaceSettings>,
     mut player_command_events: EventWriter<PlayerCommandEvent>,
     mut number_input_dialog_events: EventWriter<NumberInputDialogEvent>,
 ) {
@@ -547,6 +556,7 @@ pub fn ui_inventory_system(
                                         player_tooltip_data.as_ref(),
                                         &game_data,
                                         &ui_resources,
+                                        &interface_settings,
                                         &mut ui_state_inventory.item_slot_map,
                                         &mut ui_state_dnd,
                                         &mut player_command_events,
@@ -578,6 +588,7 @@ pub fn ui_inventory_system(
                                         player_tooltip_data.as_ref(),
                                         &game_data,
                                         &ui_resources,
+                                        &interface_settings,
                                         &mut ui_state_inventory.item_slot_map,
                                         &mut ui_state_dnd,
                                         &mut player_command_events,
@@ -612,6 +623,7 @@ pub fn ui_inventory_system(
                                 player_tooltip_data.as_ref(),
                                 &game_data,
                                 &ui_resources,
+                                &interface_settings,
                                 &mut ui_state_inventory.item_slot_map,
                                 &mut ui_state_dnd,
                                 &mut player_command_events,
@@ -628,7 +640,7 @@ pub fn ui_inventory_system(
                         )),
                         |ui| {
                             ui.horizontal_top(|ui| {
-                                ui.add(egui::Label::new(format!("{}", player.inventory.money.0)))
+                                ui.add(egui::Label::new(format_money(player.inventory.money.0)))
                             })
                             .inner
                         },
@@ -659,7 +671,7 @@ pub fn ui_inventory_system(
 
     if response_drop_money_button.map_or(false, |r| r.clicked()) && player.inventory.money.0 > 0 {
         number_input_dialog_events.send(NumberInputDialogEvent::Show {
-            max_value: Some(player.inventory.money.0 as usize),
+            max_value: Some(player.inventory.money.0.clamp(0, i64::MAX) as usize),
             modal: false,
             ok: Some(Box::new(move |commands, amount| {
                 commands.add(move |world: &mut World| {
@@ -1,12 +1,16 @@
 use std::sync::Arc;
 
-use bevy::prelude::{Camera, Camera3d, GlobalTransform, Local, Query, Res, Vec2, With};
+use bevy::prelude::{Camera, Camera3d, GlobalTransform, Local, Query, Res, Vec2, With, Without};
 use bevy_egui::{egui, EguiContexts};
 
 use rose_data::Item;
 use rose_game_common::components::{DroppedItem, ItemDrop};
 
-use crate::{resources::GameData, ui::get_item_name_color};
+use crate::{
+    components::TargetCamera,
+    resources::GameData,
+    ui::{format_money, get_item_name_color, item_requires_appraisal},
+};
 
 pub struct ItemDropName {
     screen_z: f32,
@@ -17,7 +21,7 @@ pub struct ItemDropName {
 
 pub fn ui_item_drop_name_system(
     mut egui_context: EguiContexts,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<TargetCamera>)>,
     query_item_drop: Query<(&ItemDrop, &GlobalTransform)>,
     game_data: Res<GameData>,
     mut visible_names: Local<Vec<ItemDropName>>,
@@ -60,8 +64,12 @@ pub fn ui_item_drop_name_system(
                     .unwrap();
 
                 match item {
-                    Item::Equipment(_) => (
-                        item_data.name.to_string(),
+                    Item::Equipment(equipment_item) => (
+                        if item_requires_appraisal(equipment_item) {
+                            format!("{} ???", item_data.name)
+                        } else {
+                            item_data.name.to_string()
+                        },
                         get_item_name_color(item.get_item_type(), item_data),
                     ),
                     Item::Stackable(stackable_item) => (
@@ -70,7 +78,10 @@ pub fn ui_item_drop_name_system(
                     ),
                 }
             }
-            DroppedItem::Money(money) => (format!("{} Zuly", money.0), egui::Color32::YELLOW),
+            DroppedItem::Money(money) => (
+                format!("{} Zuly", format_money(money.0)),
+                egui::Color32::YELLOW,
+            ),
         };
 
         let galley = ctx.fonts(|fonts| {
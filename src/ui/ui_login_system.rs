@@ -1,12 +1,15 @@
 use bevy::{
     app::AppExit,
-    prelude::{Assets, EventWriter, Local, Res},
+    prelude::{Assets, EventWriter, Local, Res, ResMut},
 };
 use bevy_egui::{egui, EguiContexts};
 
 use crate::{
     events::LoginEvent,
-    resources::{LoginState, ServerConfiguration, UiResources},
+    resources::{
+        InterfaceSettings, LoginState, ServerConfiguration, ServerProfile, ServerProfiles,
+        UiResources,
+    },
     ui::{
         widgets::{DataBindings, Dialog},
         UiSoundEvent,
@@ -25,6 +28,22 @@ pub struct UiStateLogin {
     password: String,
     remember_details: bool,
     initial_focus_set: bool,
+    loaded_profile: Option<usize>,
+    add_profile_open: bool,
+    add_profile: ServerProfile,
+    add_profile_port: String,
+}
+
+fn load_profile_details(ui_state: &mut UiStateLogin, server_profile: &ServerProfile) {
+    ui_state.remember_details = server_profile.remember_me;
+
+    if server_profile.remember_me {
+        ui_state.username = server_profile.username.clone();
+        ui_state.password = server_profile.load_password().unwrap_or_default();
+    } else {
+        ui_state.username.clear();
+        ui_state.password.clear();
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -35,6 +54,8 @@ pub fn ui_login_system(
     dialog_assets: Res<Assets<Dialog>>,
     login_state: Res<LoginState>,
     server_configuration: Res<ServerConfiguration>,
+    mut server_profiles: ResMut<ServerProfiles>,
+    interface_settings: Res<InterfaceSettings>,
     ui_resources: Res<UiResources>,
     mut exit_events: EventWriter<AppExit>,
     mut login_events: EventWriter<LoginEvent>,
@@ -62,16 +83,121 @@ pub fn ui_login_system(
         .input(|input| input.screen_rect().size());
     let position = egui::pos2(screen_size.x - dialog.width - 100.0, 100.0);
 
-    if !ui_state.initial_focus_set {
-        if let Some(username) = server_configuration.preset_username.as_ref() {
-            ui_state.username = username.clone();
+    if ui_state.loaded_profile != Some(server_profiles.selected) {
+        if let Some(server_profile) = server_profiles.selected() {
+            load_profile_details(ui_state, server_profile);
         }
 
-        if let Some(password) = server_configuration.preset_password.as_ref() {
-            ui_state.password = password.clone();
+        if ui_state.loaded_profile.is_none() {
+            if let Some(username) = server_configuration
+                .preset_username
+                .as_ref()
+                .filter(|username| !username.is_empty())
+            {
+                ui_state.username = username.clone();
+
+                if let Some(password) = server_configuration.preset_password.as_ref() {
+                    ui_state.password = password.clone();
+                }
+            }
         }
+
+        ui_state.loaded_profile = Some(server_profiles.selected);
     }
 
+    egui::Window::new("Server")
+        .title_bar(false)
+        .resizable(false)
+        .fixed_pos(egui::pos2(position.x, position.y - 36.0))
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                let profile_label = |profile: &ServerProfile| {
+                    if interface_settings.streamer_mode {
+                        profile.name.clone()
+                    } else {
+                        format!("{} ({}:{})", profile.name, profile.ip, profile.port)
+                    }
+                };
+                let selected_text = server_profiles
+                    .selected()
+                    .map_or_else(String::new, profile_label);
+                let mut selected = server_profiles.selected;
+
+                ui.label("Server:");
+                egui::ComboBox::from_id_source("login_server_profile")
+                    .width(dialog.width - 100.0)
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui| {
+                        for (index, profile) in server_profiles.profiles.iter().enumerate() {
+                            ui.selectable_value(&mut selected, index, profile_label(profile));
+                        }
+                    });
+
+                if selected != server_profiles.selected {
+                    server_profiles.selected = selected;
+                    server_profiles.save();
+                }
+
+                if ui.button("+").clicked() {
+                    ui_state.add_profile_open = !ui_state.add_profile_open;
+                    ui_state.add_profile = ServerProfile::default();
+                    ui_state.add_profile_port = ui_state.add_profile.port.to_string();
+                }
+            });
+
+            if ui_state.add_profile_open {
+                egui::Grid::new("login_add_server_profile")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Name:");
+                        ui.text_edit_singleline(&mut ui_state.add_profile.name);
+                        ui.end_row();
+
+                        ui.label("IP:");
+                        ui.text_edit_singleline(&mut ui_state.add_profile.ip);
+                        ui.end_row();
+
+                        ui.label("Port:");
+                        ui.text_edit_singleline(&mut ui_state.add_profile_port);
+                        ui.end_row();
+
+                        ui.label("Protocol:");
+                        egui::ComboBox::from_id_source("login_add_server_protocol")
+                            .selected_text(&ui_state.add_profile.network_version)
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(
+                                    &mut ui_state.add_profile.network_version,
+                                    "irose".to_string(),
+                                    "irose",
+                                );
+                            });
+                        ui.end_row();
+
+                        ui.label("Extensions:");
+                        ui.checkbox(&mut ui_state.add_profile.extensions, "Enabled")
+                            .on_hover_text(
+                                "Only enable for servers which support fishing, store stock and titles",
+                            );
+                        ui.end_row();
+                    });
+
+                let port = ui_state.add_profile_port.parse::<u16>().ok();
+                if ui
+                    .add_enabled(
+                        port.is_some() && !ui_state.add_profile.name.is_empty(),
+                        egui::Button::new("Add Server"),
+                    )
+                    .clicked()
+                {
+                    ui_state.add_profile.port = port.unwrap();
+                    server_profiles.profiles.push(ui_state.add_profile.clone());
+                    server_profiles.selected = server_profiles.profiles.len() - 1;
+                    server_profiles.save();
+                    ui_state.add_profile_open = false;
+                }
+            }
+        });
+
     egui::Window::new("Login")
         .frame(egui::Frame::none())
         .title_bar(false)
@@ -123,6 +249,19 @@ pub fn ui_login_system(
                 r.request_focus();
             }
         } else {
+            let remember_details = ui_state.remember_details;
+            if let Some(server_profile) = server_profiles.selected_mut() {
+                if remember_details {
+                    server_profile.username = ui_state.username.clone();
+                    server_profile.store_password(&ui_state.password);
+                } else {
+                    server_profile.delete_password();
+                    server_profile.username.clear();
+                }
+                server_profile.remember_me = remember_details;
+            }
+            server_profiles.save();
+
             login_events.send(LoginEvent::Login {
                 username: ui_state.username.clone(),
                 password: ui_state.password.clone(),
@@ -4,7 +4,7 @@ use bevy_inspector_egui::egui::text::LayoutJob;
 
 use crate::{
     events::MessageBoxEvent,
-    resources::UiResources,
+    resources::{InputFocus, UiResources},
     ui::{
         widgets::{Dialog, DrawWidget, Widget},
         DataBindings, DialogInstance, UiSoundEvent,
@@ -42,6 +42,7 @@ pub fn ui_message_box_system(
     mut message_box_events: ResMut<Events<MessageBoxEvent>>,
     dialog_assets: Res<Assets<Dialog>>,
     ui_resources: Res<UiResources>,
+    mut input_focus: ResMut<InputFocus>,
 ) {
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_message_box) {
         dialog
@@ -108,6 +109,7 @@ pub fn ui_message_box_system(
     }
 
     if ui_state.active.iter().any(|x| x.modal) {
+        input_focus.request_modal();
         egui::Area::new("modal_msgbox")
             .interactable(true)
             .fixed_pos(egui::Pos2::ZERO)
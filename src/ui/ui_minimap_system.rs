@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use bevy::{
+    ecs::system::SystemParam,
     math::{Vec2, Vec3Swizzles},
     prelude::{
-        AssetServer, Assets, Camera3d, EventWriter, Handle, Image, Local, Query, Res, Transform,
-        Vec3, With, Without,
+        AssetServer, Assets, Camera3d, EventWriter, Handle, Image, Local, Query, Res, ResMut, Time,
+        Transform, Vec3, With, Without,
     },
 };
 use bevy_egui::{egui, EguiContexts};
@@ -13,8 +14,13 @@ use rose_data::ZoneId;
 use rose_game_common::components::{CharacterInfo, Team};
 
 use crate::{
-    components::{PartyInfo, PlayerCharacter, Position},
-    resources::{CurrentZone, GameData, UiResources, UiSpriteSheetType},
+    components::{PartyInfo, PlayerCharacter, Position, TargetCamera},
+    protocol::ExtensionClientMessage,
+    resources::{
+        AutoTravel, CurrentZone, GameConnection, GameData, HudSettings, HudWidget,
+        InterfaceSettings, MapExploration, MapPings, UiResources, UiSpriteSheetType,
+        MAP_EXPLORATION_CELL_SIZE,
+    },
     ui::{
         widgets::{DataBindings, Dialog, Widget},
         UiSoundEvent,
@@ -25,6 +31,8 @@ use crate::{
 const MAP_BLOCK_PIXELS: f32 = 64.0;
 const MAP_OUTLINE_PIXELS: f32 = MAP_BLOCK_PIXELS;
 
+const MAP_UNEXPLORED_COLOR: egui::Color32 = egui::Color32::from_rgba_premultiplied(0, 0, 0, 170);
+
 const ZONE_NAME_WIDTH: f32 = 102.0;
 const ZONE_NAME_EXPANDED_WIDTH: f32 = 172.0;
 
@@ -83,6 +91,20 @@ fn generate_text_galley(
     ctx.fonts(|fonts| fonts.layout_job(text_job))
 }
 
+#[derive(SystemParam)]
+pub struct MinimapSettingsParams<'w> {
+    interface_settings: ResMut<'w, InterfaceSettings>,
+    hud_settings: Res<'w, HudSettings>,
+    map_exploration: Res<'w, MapExploration>,
+}
+
+#[derive(SystemParam)]
+pub struct MinimapPingParams<'w> {
+    game_connection: Option<Res<'w, GameConnection>>,
+    map_pings: ResMut<'w, MapPings>,
+    time: Res<'w, Time>,
+}
+
 pub fn ui_minimap_system(
     mut egui_context: EguiContexts,
     mut ui_state: Local<UiStateMinimap>,
@@ -90,14 +112,21 @@ pub fn ui_minimap_system(
     query_player: Query<(&Position, &Team, Option<&PartyInfo>), With<PlayerCharacter>>,
     query_characters: Query<(&CharacterInfo, &Position, &Team), Without<PlayerCharacter>>,
     asset_server: Res<AssetServer>,
-    query_camera: Query<&Transform, With<Camera3d>>,
+    query_camera: Query<&Transform, (With<Camera3d>, Without<TargetCamera>)>,
     images: Res<Assets<Image>>,
     current_zone: Option<Res<CurrentZone>>,
     zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
+    mut auto_travel: ResMut<AutoTravel>,
+    mut settings: MinimapSettingsParams,
+    mut pings: MinimapPingParams,
 ) {
+    if !settings.hud_settings.is_visible(HudWidget::Minimap) {
+        return;
+    }
+
     let ui_state = &mut *ui_state;
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_minimap) {
         dialog
@@ -216,6 +245,9 @@ pub fn ui_minimap_system(
     let mut response_big_minimise_button = None;
     let mut response_small_minimise_button = None;
     let minimised = ui_state.minimap_image_size.is_none() || ui_state.is_minimised;
+    let rotate_map = settings.interface_settings.minimap_rotate;
+    let mut hover_world_position = None;
+    let mut toggle_north_lock = false;
 
     let map_relative_position = |ui_state: &mut UiStateMinimap, position: Vec3| -> Vec2 {
         let minimap_player_x = MAP_OUTLINE_PIXELS
@@ -247,16 +279,88 @@ pub fn ui_minimap_system(
             );
             let minimap_player_pos =
                 player_position.map(|p| map_relative_position(ui_state, p.position));
+
+            // In rotate mode the map is rotated around the player at the center of the minimap
+            let minimap_center = Vec2::new(minimap_rect.center().x, minimap_rect.center().y);
+            let rotation_center = minimap_player_pos.filter(|_| rotate_map);
+            let map_rotation = Vec2::from_angle(-camera_angle);
+            let map_to_screen = |scroll: Vec2, map_position: Vec2| -> Vec2 {
+                if let Some(rotation_center) = rotation_center {
+                    minimap_center + map_rotation.rotate(map_position - rotation_center)
+                } else {
+                    Vec2::new(minimap_rect.min.x, minimap_rect.min.y) + map_position - scroll
+                }
+            };
+            let screen_to_map = |scroll: Vec2, screen_position: Vec2| -> Vec2 {
+                if let Some(rotation_center) = rotation_center {
+                    rotation_center
+                        + Vec2::new(map_rotation.x, -map_rotation.y)
+                            .rotate(screen_position - minimap_center)
+                } else {
+                    screen_position - Vec2::new(minimap_rect.min.x, minimap_rect.min.y) + scroll
+                }
+            };
+            let map_to_world = |ui_state: &UiStateMinimap, map_position: Vec2| -> Vec2 {
+                let map_position = map_position - Vec2::splat(MAP_OUTLINE_PIXELS);
+                Vec2::new(
+                    ui_state.min_world_pos.x + map_position.x * ui_state.distance_per_pixel,
+                    ui_state.min_world_pos.y - map_position.y * ui_state.distance_per_pixel,
+                )
+            };
             let map_absolute_position = |ui_state: &mut UiStateMinimap, position: Vec3| -> Vec2 {
-                Vec2::new(minimap_rect.min.x, minimap_rect.min.y)
-                    + map_relative_position(ui_state, position)
-                    - ui_state.scroll
+                map_to_screen(ui_state.scroll, map_relative_position(ui_state, position))
             };
 
             if !minimised {
                 let response = ui.allocate_rect(minimap_rect, egui::Sense::click_and_drag());
 
-                if response.dragged() {
+                hover_world_position = response.hover_pos().map(|hover_pos| {
+                    map_to_world(
+                        ui_state,
+                        screen_to_map(ui_state.scroll, Vec2::new(hover_pos.x, hover_pos.y)),
+                    )
+                });
+
+                if response.clicked() && ui.input(|input| input.modifiers.ctrl) {
+                    // Ctrl click on the minimap pings the location to the party, pings are never
+                    // sent to public chat as that would reveal the location to everyone nearby
+                    if let (Some(pointer_pos), Some(game_connection), Some(_)) = (
+                        response.interact_pointer_pos(),
+                        pings.game_connection.as_ref(),
+                        player_party.filter(|party| !party.members.is_empty()),
+                    ) {
+                        let world_position = map_to_world(
+                            ui_state,
+                            screen_to_map(ui_state.scroll, Vec2::new(pointer_pos.x, pointer_pos.y)),
+                        );
+
+                        game_connection
+                            .extension_message_tx
+                            .send(ExtensionClientMessage::PartyChat {
+                                text: MapPings::chat_text(current_zone.id, world_position),
+                            })
+                            .ok();
+
+                        pings
+                            .map_pings
+                            .add(current_zone.id, world_position, pings.time.elapsed());
+                    }
+                } else if response.clicked() && ui.input(|input| input.modifiers.shift) {
+                    // Shift click on the minimap queues up a waypoint to travel to
+                    if let Some(pointer_pos) = response.interact_pointer_pos() {
+                        let world_position = map_to_world(
+                            ui_state,
+                            screen_to_map(ui_state.scroll, Vec2::new(pointer_pos.x, pointer_pos.y)),
+                        );
+
+                        auto_travel.auto_run = false;
+                        auto_travel.waypoints.push_back(Vec3::new(
+                            world_position.x,
+                            world_position.y,
+                            player_position.map_or(0.0, |position| position.z),
+                        ));
+                    }
+                } else if response.dragged() && !rotate_map {
                     let delta = ui.input(|input| input.pointer.delta());
                     ui_state.scroll.x -= delta.x;
                     ui_state.scroll.y -= delta.y;
@@ -276,21 +380,102 @@ pub fn ui_minimap_system(
                     .y
                     .clamp(0.0, (image_size.y - minimap_size.y).max(0.0));
 
-                let minimap_uv = egui::Rect::from_min_max(
-                    egui::pos2(
-                        ui_state.scroll.x / image_size.x,
-                        ui_state.scroll.y / image_size.y,
-                    ),
-                    egui::pos2(
-                        (ui_state.scroll.x + minimap_size.x) / image_size.x,
-                        (ui_state.scroll.y + minimap_size.y) / image_size.y,
-                    ),
-                );
-
                 if ui.is_rect_visible(minimap_rect) {
-                    let mut mesh = egui::epaint::Mesh::with_texture(ui_state.minimap_texture);
-                    mesh.add_rect_with_uv(minimap_rect, minimap_uv, egui::Color32::WHITE);
-                    ui.painter().add(egui::epaint::Shape::mesh(mesh));
+                    let painter = ui.painter().with_clip_rect(minimap_rect);
+
+                    if rotation_center.is_some() {
+                        painter.rect_filled(minimap_rect, 0.0, egui::Color32::BLACK);
+
+                        let mut mesh = egui::epaint::Mesh::with_texture(ui_state.minimap_texture);
+                        mesh.add_triangle(0, 1, 2);
+                        mesh.add_triangle(2, 1, 3);
+                        for (corner, uv) in [
+                            (Vec2::ZERO, egui::pos2(0.0, 0.0)),
+                            (Vec2::new(image_size.x, 0.0), egui::pos2(1.0, 0.0)),
+                            (Vec2::new(0.0, image_size.y), egui::pos2(0.0, 1.0)),
+                            (image_size, egui::pos2(1.0, 1.0)),
+                        ] {
+                            let pos = map_to_screen(ui_state.scroll, corner);
+                            mesh.vertices.push(egui::epaint::Vertex {
+                                pos: egui::pos2(pos.x, pos.y),
+                                uv,
+                                color: egui::Color32::WHITE,
+                            });
+                        }
+                        painter.add(egui::epaint::Shape::mesh(mesh));
+                    } else {
+                        let minimap_uv = egui::Rect::from_min_max(
+                            egui::pos2(
+                                ui_state.scroll.x / image_size.x,
+                                ui_state.scroll.y / image_size.y,
+                            ),
+                            egui::pos2(
+                                (ui_state.scroll.x + minimap_size.x) / image_size.x,
+                                (ui_state.scroll.y + minimap_size.y) / image_size.y,
+                            ),
+                        );
+
+                        let mut mesh = egui::epaint::Mesh::with_texture(ui_state.minimap_texture);
+                        mesh.add_rect_with_uv(minimap_rect, minimap_uv, egui::Color32::WHITE);
+                        painter.add(egui::epaint::Shape::mesh(mesh));
+                    }
+
+                    // Darken the areas of the map which have not been explored yet
+                    let (visible_map_min, visible_map_max) =
+                        if let Some(rotation_center) = rotation_center {
+                            let radius = minimap_size.length() / 2.0;
+                            (
+                                rotation_center - Vec2::splat(radius),
+                                rotation_center + Vec2::splat(radius),
+                            )
+                        } else {
+                            (ui_state.scroll, ui_state.scroll + minimap_size)
+                        };
+                    let (min_cell_x, min_cell_y) = MapExploration::get_cell(map_to_world(
+                        ui_state,
+                        Vec2::new(visible_map_min.x, visible_map_max.y),
+                    ));
+                    let (max_cell_x, max_cell_y) = MapExploration::get_cell(map_to_world(
+                        ui_state,
+                        Vec2::new(visible_map_max.x, visible_map_min.y),
+                    ));
+                    let explored_cells =
+                        settings.map_exploration.get_explored_cells(current_zone.id);
+                    let cell_pixels = MAP_EXPLORATION_CELL_SIZE / ui_state.distance_per_pixel;
+                    let mut mesh = egui::epaint::Mesh::default();
+
+                    for cell_y in min_cell_y..=max_cell_y {
+                        for cell_x in min_cell_x..=max_cell_x {
+                            if explored_cells.map_or(false, |explored_cells| {
+                                MapExploration::is_explored(explored_cells, cell_x, cell_y)
+                            }) {
+                                continue;
+                            }
+
+                            let cell_min = Vec2::splat(MAP_OUTLINE_PIXELS)
+                                + Vec2::new(
+                                    cell_x as f32 * MAP_EXPLORATION_CELL_SIZE
+                                        - ui_state.min_world_pos.x,
+                                    ui_state.min_world_pos.y
+                                        - (cell_y + 1) as f32 * MAP_EXPLORATION_CELL_SIZE,
+                                ) / ui_state.distance_per_pixel;
+
+                            let idx = mesh.vertices.len() as u32;
+                            mesh.add_triangle(idx, idx + 1, idx + 2);
+                            mesh.add_triangle(idx + 2, idx + 1, idx + 3);
+                            for corner in [
+                                cell_min,
+                                cell_min + Vec2::new(cell_pixels, 0.0),
+                                cell_min + Vec2::new(0.0, cell_pixels),
+                                cell_min + Vec2::splat(cell_pixels),
+                            ] {
+                                let pos = map_to_screen(ui_state.scroll, corner);
+                                mesh.colored_vertex(egui::pos2(pos.x, pos.y), MAP_UNEXPLORED_COLOR);
+                            }
+                        }
+                    }
+
+                    painter.add(egui::epaint::Shape::mesh(mesh));
                 }
             }
 
@@ -417,14 +602,55 @@ pub fn ui_minimap_system(
                     }
                 }
 
+                // Draw queued waypoints as a path from the player
+                let mut path_start = minimap_player_pos
+                    .map(|minimap_player_pos| map_to_screen(ui_state.scroll, minimap_player_pos));
+                let painter = ui.painter().with_clip_rect(minimap_rect);
+                for waypoint in auto_travel.waypoints.iter() {
+                    let waypoint_minimap_position = map_absolute_position(ui_state, *waypoint);
+                    let waypoint_pos =
+                        egui::pos2(waypoint_minimap_position.x, waypoint_minimap_position.y);
+
+                    if let Some(path_start) = path_start {
+                        painter.line_segment(
+                            [egui::pos2(path_start.x, path_start.y), waypoint_pos],
+                            egui::Stroke::new(1.5, egui::Color32::YELLOW),
+                        );
+                    }
+
+                    painter.circle_filled(waypoint_pos, 3.0, egui::Color32::YELLOW);
+                    path_start = Some(waypoint_minimap_position);
+                }
+
+                // Draw flashing rings at pinged locations
+                let now = pings.time.elapsed();
+                for ping in pings.map_pings.pings.iter() {
+                    if ping.zone_id != current_zone.id {
+                        continue;
+                    }
+
+                    let ping_minimap_position = map_absolute_position(
+                        ui_state,
+                        Vec3::new(ping.position.x, ping.position.y, 0.0),
+                    );
+                    let flash = MapPings::flash(ping, now);
+                    painter.circle_stroke(
+                        egui::pos2(ping_minimap_position.x, ping_minimap_position.y),
+                        4.0 + 6.0 * flash,
+                        egui::Stroke::new(
+                            2.0,
+                            egui::Color32::from_rgb(255, 216, 50)
+                                .linear_multiply(1.0 - 0.6 * flash),
+                        ),
+                    );
+                }
+
                 // Draw player position arrow texture on a rotated rectangle to face camera position
                 if let Some(minimap_player_pos) = minimap_player_pos {
                     let minimap_player_sprite = ui_resources.get_minimap_player_sprite().unwrap();
                     let player_icon_size =
                         Vec2::new(minimap_player_sprite.width, minimap_player_sprite.height);
-                    let minimap_player_pos = Vec2::new(minimap_rect.min.x, minimap_rect.min.y)
-                        + minimap_player_pos
-                        - ui_state.scroll;
+                    let minimap_player_pos = map_to_screen(ui_state.scroll, minimap_player_pos);
                     let widget_rect = egui::Rect::from_min_size(
                         (minimap_player_pos - player_icon_size / 2.0)
                             .to_array()
@@ -440,9 +666,11 @@ pub fn ui_minimap_system(
                                     egui::Sense::hover(),
                                 );
 
-                                // Calculate rotated rectangle from camera angle
-                                let sin_a = camera_angle.sin();
-                                let cos_a = camera_angle.cos();
+                                // Calculate rotated rectangle from camera angle, when the map
+                                // rotates with the camera the arrow always points up
+                                let arrow_angle = if rotate_map { 0.0 } else { camera_angle };
+                                let sin_a = arrow_angle.sin();
+                                let cos_a = arrow_angle.cos();
 
                                 let mut corners = [
                                     [-player_icon_size.x / 2.0, -player_icon_size.y / 2.0],
@@ -520,13 +748,34 @@ pub fn ui_minimap_system(
                 ui.painter().add(egui::epaint::Shape::mesh(mesh));
 
                 ui.allocate_ui_at_rect(player_xy_rect.shrink(2.0), |ui| {
-                    if let Some(player_position) = player_position {
-                        ui.label(format!(
-                            "{:0>4}, {:0>4}",
-                            (player_position.position.x / 100.0) as i32,
-                            (player_position.position.y / 100.0) as i32
-                        ));
-                    }
+                    ui.horizontal(|ui| {
+                        if let Some(player_position) = player_position {
+                            ui.label(format!(
+                                "{:0>4}, {:0>4}",
+                                (player_position.position.x / 100.0) as i32,
+                                (player_position.position.y / 100.0) as i32
+                            ));
+                        }
+
+                        if let Some(hover_world_position) = hover_world_position {
+                            ui.weak(format!(
+                                "({:0>4}, {:0>4})",
+                                (hover_world_position.x / 100.0) as i32,
+                                (hover_world_position.y / 100.0) as i32
+                            ));
+                        }
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            toggle_north_lock = ui
+                                .selectable_label(!rotate_map, "N")
+                                .on_hover_text(if rotate_map {
+                                    "Lock the minimap to north up"
+                                } else {
+                                    "Rotate the minimap with the camera"
+                                })
+                                .clicked();
+                        });
+                    });
                 });
             }
         });
@@ -544,4 +793,8 @@ pub fn ui_minimap_system(
     {
         ui_state.is_minimised = !ui_state.is_minimised;
     }
+
+    if toggle_north_lock {
+        settings.interface_settings.minimap_rotate = !rotate_map;
+    }
 }
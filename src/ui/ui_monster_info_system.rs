@@ -0,0 +1,61 @@
+use std::num::NonZeroU16;
+
+use bevy::prelude::{Entity, Query, Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::{AbilityValues, HealthPoints, Npc, Team};
+
+use crate::{
+    components::Dead,
+    resources::{GameData, SelectedTarget},
+    ui::{ui_add_monster_tooltip, UiStateWindows},
+};
+
+fn is_monster(game_data: &GameData, npc: &Npc, team: &Team) -> bool {
+    team.id != Team::DEFAULT_NPC_TEAM_ID
+        && game_data.npcs.get_npc(npc.id).map_or(true, |npc_data| {
+            npc_data.npc_type_index != NonZeroU16::new(999)
+        })
+}
+
+pub fn ui_monster_info_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    query_monster: Query<(&AbilityValues, &HealthPoints, &Npc, &Team, Option<&Dead>)>,
+    game_data: Res<GameData>,
+    selected_target: Res<SelectedTarget>,
+) {
+    let get_monster = |entity: Option<Entity>| {
+        entity
+            .and_then(|entity| query_monster.get(entity).ok())
+            .filter(|(_, _, npc, team, dead)| dead.is_none() && is_monster(&game_data, npc, team))
+    };
+    let ctx = egui_context.ctx_mut();
+
+    if let Some((ability_values, health_points, npc, _, _)) = get_monster(selected_target.hover) {
+        if !ctx.is_pointer_over_area() {
+            egui::show_tooltip_at_pointer(ctx, egui::Id::new("monster_hover_tooltip"), |ui| {
+                ui_add_monster_tooltip(ui, &game_data, npc.id, ability_values, health_points);
+            });
+        }
+    }
+
+    if !ui_state_windows.monster_info_open {
+        return;
+    }
+
+    let Some((ability_values, health_points, npc, _, _)) = get_monster(selected_target.selected)
+    else {
+        ui_state_windows.monster_info_open = false;
+        return;
+    };
+
+    egui::Window::new("Monster Info")
+        .anchor(egui::Align2::CENTER_TOP, [0.0, 90.0])
+        .resizable(false)
+        .collapsible(false)
+        .open(&mut ui_state_windows.monster_info_open)
+        .show(ctx, |ui| {
+            ui_add_monster_tooltip(ui, &game_data, npc.id, ability_values, health_points);
+        });
+}
@@ -1,3 +1,5 @@
+use std::{collections::HashMap, time::Instant};
+
 use bevy::{
     ecs::query::WorldQuery,
     math::Vec3Swizzles,
@@ -7,9 +9,12 @@ use bevy::{
 };
 use bevy_egui::{egui, EguiContexts};
 
-use rose_data::{Item, NpcData, NpcStoreTabData, NpcStoreTabId};
+use rose_data::{Item, ItemReference, NpcData, NpcStoreTabData, NpcStoreTabId};
 use rose_game_common::{
-    components::{AbilityValues, Inventory, ItemSlot, Npc},
+    components::{
+        AbilityValues, Inventory, InventoryPageType, ItemSlot, Npc, UnionMembership,
+        INVENTORY_PAGE_SIZE,
+    },
     messages::{
         client::{ClientMessage, NpcStoreBuyItem},
         ClientEntityId,
@@ -19,15 +24,17 @@ use rose_game_common::{
 use crate::{
     components::{PlayerCharacter, Position},
     events::{MessageBoxEvent, NpcStoreEvent, NumberInputDialogEvent},
+    protocol::ExtensionClientMessage,
     resources::{
         ClientEntityList, GameConnection, GameData, UiResources, UiSpriteSheetType, WorldRates,
     },
     ui::{
+        format_money, get_item_rarity,
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
         ui_add_item_tooltip,
         ui_drag_and_drop_system::UiStateDragAndDrop,
         widgets::{DataBindings, Dialog, DrawText},
-        DragAndDropId, DragAndDropSlot, UiSoundEvent,
+        DragAndDropId, DragAndDropSlot, ItemRarity, UiSoundEvent,
     },
 };
 
@@ -58,12 +65,26 @@ struct PendingSellItem {
     quantity: usize,
 }
 
+/// Remaining stock of a limited-quantity store item, as last sent by the server
+struct StoreItemStock {
+    remaining: usize,
+    restock_at: Option<Instant>,
+}
+
+impl StoreItemStock {
+    fn is_sold_out(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
 pub struct UiNpcStoreState {
     owner_entity: Option<(Entity, ClientEntityId)>,
     current_tab_index: i32,
     store_tabs: [Option<(NpcStoreTabId, String)>; 4],
     buy_list: [Option<PendingBuyItem>; NUM_BUY_ITEMS],
     sell_list: [Option<PendingSellItem>; NUM_SELL_ITEMS],
+    /// Stock of limited-quantity items by (tab index, slot), other items have unlimited stock
+    stock: HashMap<(usize, usize), StoreItemStock>,
 }
 
 impl Default for UiNpcStoreState {
@@ -74,10 +95,119 @@ impl Default for UiNpcStoreState {
             store_tabs: Default::default(),
             buy_list: Default::default(),
             sell_list: Default::default(),
+            stock: HashMap::new(),
         }
     }
 }
 
+/// Union stores sell items for points of the store's union rather than zuly
+fn get_store_union_index(npc_data: &NpcData) -> Option<usize> {
+    npc_data
+        .store_union_number
+        .map(|store_union_number| store_union_number.get() - 1)
+}
+
+fn get_store_item_buy_price(
+    item_reference: ItemReference,
+    store_union_index: Option<usize>,
+    player: Option<&NpcStorePlayerWorldQueryItem>,
+    game_data: &GameData,
+    world_rates: Option<&Res<WorldRates>>,
+) -> i64 {
+    if store_union_index.is_some() {
+        game_data
+            .items
+            .get_base_item(item_reference)
+            .map_or(0, |item_data| item_data.base_price as i64)
+    } else {
+        game_data
+            .ability_value_calculator
+            .calculate_npc_store_item_buy_price(
+                &game_data.items,
+                item_reference,
+                player.map_or(0, |player| player.ability_values.get_npc_store_buy_rate()),
+                world_rates.map_or(100, |x| x.item_price_rate),
+                world_rates.map_or(100, |x| x.town_price_rate),
+            )
+            .unwrap_or(0) as i64
+    }
+}
+
+/// The listed price of an item before any store rates or discounts are applied
+fn get_store_item_base_price(item_reference: ItemReference, game_data: &GameData) -> i64 {
+    game_data
+        .items
+        .get_base_item(item_reference)
+        .map_or(0, |item_data| item_data.base_price as i64)
+}
+
+fn get_store_item_sell_price(
+    item: &Item,
+    player: Option<&NpcStorePlayerWorldQueryItem>,
+    game_data: &GameData,
+    world_rates: Option<&Res<WorldRates>>,
+) -> i64 {
+    (game_data
+        .ability_value_calculator
+        .calculate_npc_store_item_sell_price(
+            &game_data.items,
+            item,
+            player.map_or(0, |player| player.ability_values.get_npc_store_sell_rate()),
+            world_rates.map_or(0, |x| x.world_price_rate),
+            world_rates.map_or(0, |x| x.item_price_rate),
+            world_rates.map_or(0, |x| x.town_price_rate),
+        )
+        .unwrap_or(0) as i64)
+        .saturating_mul(item.get_quantity() as i64)
+}
+
+fn format_store_price(price: i64, store_union_index: Option<usize>) -> String {
+    if store_union_index.is_some() {
+        format!("{} Union Points", format_money(price))
+    } else {
+        format_money(price)
+    }
+}
+
+fn ui_add_store_price_breakdown(
+    ui: &mut egui::Ui,
+    label: &str,
+    base_price: i64,
+    price: i64,
+    store_union_index: Option<usize>,
+) {
+    if store_union_index.is_none() && base_price != price {
+        ui.label(format!("Base Price: {}", format_money(base_price)));
+
+        if price < base_price {
+            ui.colored_label(
+                egui::Color32::GREEN,
+                format!(
+                    "Discount: -{}",
+                    format_money(base_price.saturating_sub(price))
+                ),
+            );
+        } else {
+            ui.colored_label(
+                egui::Color32::LIGHT_RED,
+                format!(
+                    "Markup: +{}",
+                    format_money(price.saturating_sub(base_price))
+                ),
+            );
+        }
+    }
+
+    ui.colored_label(
+        egui::Color32::YELLOW,
+        format!(
+            "{}: {}",
+            label,
+            format_store_price(price, store_union_index)
+        ),
+    );
+}
+
 fn ui_add_store_item_slot(
     ui: &mut egui::Ui,
     ui_state_dnd: &mut UiStateDragAndDrop,
@@ -85,6 +215,8 @@ fn ui_add_store_item_slot(
     store_tab: Option<&NpcStoreTabData>,
     store_tab_index: usize,
     store_tab_slot: usize,
+    store_union_index: Option<usize>,
+    stock: Option<&StoreItemStock>,
     buy_list: &mut [Option<PendingBuyItem>; NUM_BUY_ITEMS],
     player: Option<&NpcStorePlayerWorldQueryItem>,
     player_tooltip_data: Option<&PlayerTooltipQueryItem>,
@@ -101,28 +233,34 @@ fn ui_add_store_item_slot(
     let sprite = item_data.and_then(|item_data| {
         ui_resources.get_sprite_by_index(UiSpriteSheetType::Item, item_data.icon_index as usize)
     });
-    let quantity = item.as_ref().and_then(|item| {
-        if item.get_item_type().is_stackable_item() {
-            Some(item.get_quantity() as usize)
-        } else {
-            None
-        }
-    });
+    // Limited-quantity items show their remaining stock instead of the stack size
+    let quantity = if let Some(stock) = stock {
+        Some(stock.remaining)
+    } else {
+        item.as_ref().and_then(|item| {
+            if item.get_item_type().is_stackable_item() {
+                Some(item.get_quantity() as usize)
+            } else {
+                None
+            }
+        })
+    };
+    let sold_out = stock.map_or(false, |stock| stock.is_sold_out());
 
     let item_price = if let Some(item_reference) = item_reference {
-        game_data
-            .ability_value_calculator
-            .calculate_npc_store_item_buy_price(
-                &game_data.items,
-                *item_reference,
-                player.map_or(0, |x| x.ability_values.get_npc_store_buy_rate()),
-                world_rates.map_or(100, |x| x.item_price_rate),
-                world_rates.map_or(100, |x| x.town_price_rate),
-            )
-            .unwrap_or(0) as i64
+        get_store_item_buy_price(
+            *item_reference,
+            store_union_index,
+            player,
+            game_data,
+            world_rates,
+        )
     } else {
         0
     };
+    let item_base_price = item_reference.map_or(0, |item_reference| {
+        get_store_item_base_price(*item_reference, game_data)
+    });
 
     let mut dropped_item = None;
     let response = ui
@@ -141,7 +279,8 @@ fn ui_add_store_item_slot(
                         &mut ui_state_dnd.dragged_item,
                         &mut dropped_item,
                         [40.0, 40.0],
-                    ),
+                    )
+                    .with_unusable(sold_out),
                     ui,
                 )
             },
@@ -149,10 +288,17 @@ fn ui_add_store_item_slot(
         .inner;
 
     if let Some(item) = item.as_ref() {
-        if response.double_clicked() {
+        if !sold_out {
+            ui_state_dnd.handle_quick_move(
+                &response,
+                DragAndDropId::NpcStore(store_tab_index, store_tab_slot),
+            );
+        }
+
+        if response.double_clicked() && !sold_out {
             if item.is_stackable_item() {
                 number_input_dialog_events.send(NumberInputDialogEvent::Show {
-                    max_value: Some(999),
+                    max_value: Some(stock.map_or(999, |stock| stock.remaining.min(999))),
                     modal: false,
                     ok: Some(Box::new(move |commands, quantity| {
                         commands.add(move |world: &mut World| {
@@ -184,11 +330,44 @@ fn ui_add_store_item_slot(
         response.on_hover_ui(|ui| {
             ui_add_item_tooltip(ui, game_data, player_tooltip_data, item);
 
-            ui.colored_label(egui::Color32::YELLOW, format!("Buy Price: {}", item_price));
+            ui_add_store_price_breakdown(
+                ui,
+                "Buy Price",
+                item_base_price,
+                item_price,
+                store_union_index,
+            );
+
+            if let Some(stock) = stock {
+                ui_add_store_stock(ui, stock);
+            }
         });
     }
 }
 
+fn ui_add_store_stock(ui: &mut egui::Ui, stock: &StoreItemStock) {
+    if stock.is_sold_out() {
+        ui.colored_label(egui::Color32::LIGHT_RED, "Sold Out");
+    } else {
+        ui.label(format!("In Stock: {}", stock.remaining));
+    }
+
+    if let Some(restock_at) = stock.restock_at {
+        let remaining = restock_at
+            .saturating_duration_since(Instant::now())
+            .as_secs();
+        if remaining > 0 {
+            ui.label(format!(
+                "Restocks in {}:{:02}",
+                remaining / 60,
+                remaining % 60
+            ));
+        } else {
+            ui.label("Restocking...");
+        }
+    }
+}
+
 fn buy_slot_drag_accepts(drag_source: &DragAndDropId) -> bool {
     matches!(drag_source, DragAndDropId::NpcStore(_, _))
 }
@@ -205,6 +384,8 @@ fn ui_add_buy_item_slot(
     ui_state_dnd: &mut UiStateDragAndDrop,
     pos: egui::Pos2,
     npc_data: &NpcData,
+    store_union_index: Option<usize>,
+    stock: &HashMap<(usize, usize), StoreItemStock>,
     buy_list: &mut [Option<PendingBuyItem>; NUM_BUY_ITEMS],
     buy_slot_index: usize,
     player: Option<&NpcStorePlayerWorldQueryItem>,
@@ -241,20 +422,21 @@ fn ui_add_buy_item_slot(
     });
 
     let item_price = if let Some(item_reference) = item_reference {
-        game_data
-            .ability_value_calculator
-            .calculate_npc_store_item_buy_price(
-                &game_data.items,
-                *item_reference,
-                player.map_or(0, |player| player.ability_values.get_npc_store_buy_rate()),
-                world_rates.map_or(100, |x| x.item_price_rate),
-                world_rates.map_or(100, |x| x.town_price_rate),
-            )
-            .unwrap_or(0) as i64
-            * quantity.unwrap_or(1) as i64
+        get_store_item_buy_price(
+            *item_reference,
+            store_union_index,
+            player,
+            game_data,
+            world_rates,
+        )
+        .saturating_mul(quantity.unwrap_or(1) as i64)
     } else {
         0
     };
+    let item_base_price = item_reference.map_or(0, |item_reference| {
+        get_store_item_base_price(*item_reference, game_data)
+            .saturating_mul(quantity.unwrap_or(1) as i64)
+    });
 
     let mut dropped_item = None;
     let response = ui
@@ -280,6 +462,8 @@ fn ui_add_buy_item_slot(
         )
         .inner;
 
+    ui_state_dnd.handle_quick_move(&response, DragAndDropId::NpcStoreBuyList(buy_slot_index));
+
     if response.double_clicked() {
         *pending_buy_item = None;
     }
@@ -288,11 +472,24 @@ fn ui_add_buy_item_slot(
         response.on_hover_ui(|ui| {
             ui_add_item_tooltip(ui, game_data, player_tooltip_data, &item);
 
-            ui.colored_label(egui::Color32::YELLOW, format!("Buy Price: {}", item_price));
+            ui_add_store_price_breakdown(
+                ui,
+                "Buy Price",
+                item_base_price,
+                item_price,
+                store_union_index,
+            );
         });
     }
 
     if let Some(DragAndDropId::NpcStore(store_tab_index, store_tab_slot)) = dropped_item {
+        if stock
+            .get(&(store_tab_index, store_tab_slot))
+            .map_or(false, |stock| stock.is_sold_out())
+        {
+            return item_price;
+        }
+
         *pending_buy_item = Some(PendingBuyItem {
             store_tab_index,
             store_tab_slot,
@@ -322,22 +519,13 @@ fn ui_add_sell_item_slot(
             .and_then(|pending_sell_item| player.inventory.get_item(pending_sell_item.item_slot))
     });
 
-    let item_price = if let Some(item) = item {
-        game_data
-            .ability_value_calculator
-            .calculate_npc_store_item_sell_price(
-                &game_data.items,
-                item,
-                player.map_or(0, |player| player.ability_values.get_npc_store_sell_rate()),
-                world_rates.map_or(0, |x| x.world_price_rate),
-                world_rates.map_or(0, |x| x.item_price_rate),
-                world_rates.map_or(0, |x| x.town_price_rate),
-            )
-            .unwrap_or(0) as i64
-            * item.get_quantity() as i64
-    } else {
-        0
-    };
+    let item_price = item.map_or(0, |item| {
+        get_store_item_sell_price(item, player, game_data, world_rates)
+    });
+    let item_base_price = item.map_or(0, |item| {
+        get_store_item_base_price(item.get_item_reference(), game_data)
+            .saturating_mul(item.get_quantity() as i64)
+    });
 
     let mut dropped_item = None;
     let response = ui
@@ -362,6 +550,8 @@ fn ui_add_sell_item_slot(
         )
         .inner;
 
+    ui_state_dnd.handle_quick_move(&response, DragAndDropId::NpcStoreSellList(sell_slot_index));
+
     if response.double_clicked() {
         *pending_sell_item = None;
     }
@@ -370,7 +560,11 @@ fn ui_add_sell_item_slot(
         response.on_hover_ui(|ui| {
             ui_add_item_tooltip(ui, game_data, player_tooltip_data, item);
 
-            ui.colored_label(egui::Color32::YELLOW, format!("Sell Value: {}", item_price));
+            ui.label(format!("Base Value: {}", format_money(item_base_price)));
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                format!("Sell Value: {}", format_money(item_price)),
+            );
         });
     }
 
@@ -390,6 +584,7 @@ pub struct NpcStorePlayerWorldQuery<'w> {
     inventory: &'w Inventory,
     position: &'w Position,
     player_character: &'w PlayerCharacter,
+    union_membership: &'w UnionMembership,
 }
 
 #[derive(WorldQuery)]
@@ -429,6 +624,8 @@ pub fn ui_npc_store_system(
             return;
         };
 
+    let player = query_player.get_single().ok();
+
     for event in npc_store_events.iter() {
         match *event {
             NpcStoreEvent::OpenClientEntityStore(client_entity_id) => {
@@ -447,6 +644,17 @@ pub fn ui_npc_store_system(
                             }
 
                             ui_state.owner_entity = Some((owner_entity, client_entity_id));
+
+                            // Servers without limited stock never reply, so all items keep
+                            // unlimited stock
+                            if let Some(game_connection) = game_connection.as_ref() {
+                                game_connection
+                                    .extension_message_tx
+                                    .send(ExtensionClientMessage::NpcStoreStockRequest {
+                                        npc_entity_id: client_entity_id,
+                                    })
+                                    .ok();
+                            }
                         }
                     }
                 }
@@ -456,6 +664,12 @@ pub fn ui_npc_store_system(
                 store_tab_slot,
                 quantity,
             } => {
+                let stock = ui_state.stock.get(&(store_tab_index, store_tab_slot));
+                if stock.map_or(false, |stock| stock.is_sold_out()) {
+                    continue;
+                }
+                let quantity = stock.map_or(quantity, |stock| quantity.min(stock.remaining));
+
                 for slot in ui_state.buy_list.iter_mut() {
                     if slot.is_none() {
                         *slot = Some(PendingBuyItem {
@@ -467,6 +681,33 @@ pub fn ui_npc_store_system(
                     }
                 }
             }
+            NpcStoreEvent::AddToSellList(item_slot) => {
+                let Some(quantity) = player.as_ref().and_then(|player| {
+                    player
+                        .inventory
+                        .get_item(item_slot)
+                        .map(|item| item.get_quantity() as usize)
+                }) else {
+                    continue;
+                };
+
+                let already_selling = ui_state.sell_list.iter().any(|pending_sell_item| {
+                    pending_sell_item
+                        .as_ref()
+                        .map_or(false, |pending_sell_item| {
+                            pending_sell_item.item_slot == item_slot
+                        })
+                });
+
+                if ui_state.owner_entity.is_some() && !already_selling {
+                    if let Some(slot) = ui_state.sell_list.iter_mut().find(|slot| slot.is_none()) {
+                        *slot = Some(PendingSellItem {
+                            item_slot,
+                            quantity,
+                        });
+                    }
+                }
+            }
             NpcStoreEvent::RemoveFromBuyList(index) => {
                 if let Some(buy_slot) = ui_state.buy_list.get_mut(index) {
                     buy_slot.take();
@@ -477,10 +718,52 @@ pub fn ui_npc_store_system(
                     buy_slot.take();
                 }
             }
+            NpcStoreEvent::UpdateStock {
+                npc_entity_id,
+                ref items,
+            } => {
+                if ui_state.owner_entity.map_or(true, |(_, client_entity_id)| {
+                    client_entity_id != npc_entity_id
+                }) {
+                    continue;
+                }
+
+                let now = Instant::now();
+                ui_state.stock = items
+                    .iter()
+                    .map(|item| {
+                        (
+                            (item.tab_index, item.item_index),
+                            StoreItemStock {
+                                remaining: item.remaining,
+                                restock_at: item.restock.map(|restock| now + restock),
+                            },
+                        )
+                    })
+                    .collect();
+
+                // Remove or reduce pending purchases which are no longer in stock
+                for slot in ui_state.buy_list.iter_mut() {
+                    let Some(pending_buy_item) = slot.as_mut() else {
+                        continue;
+                    };
+                    let Some(stock) = ui_state.stock.get(&(
+                        pending_buy_item.store_tab_index,
+                        pending_buy_item.store_tab_slot,
+                    )) else {
+                        continue;
+                    };
+
+                    if stock.is_sold_out() {
+                        *slot = None;
+                    } else {
+                        pending_buy_item.quantity = pending_buy_item.quantity.min(stock.remaining);
+                    }
+                }
+            }
         }
     }
 
-    let player = query_player.get_single().ok();
     let player_tooltip_data = query_player_tooltip.get_single().ok();
     let npc = ui_state
         .owner_entity
@@ -490,15 +773,18 @@ pub fn ui_npc_store_system(
     if let (Some(player), Some(npc)) = (player.as_ref(), npc.as_ref()) {
         if player.position.position.xy().distance(npc.position.xy()) > 600.0 {
             ui_state.owner_entity = None;
+            ui_state_dnd.npc_store_open = false;
             return;
         }
     }
+    ui_state_dnd.npc_store_open = npc.is_some();
 
     let npc_data = npc.and_then(|npc| game_data.npcs.get_npc(npc.npc.id));
     if npc_data.is_none() {
         return;
     }
     let npc_data = npc_data.unwrap();
+    let store_union_index = get_store_union_index(npc_data);
 
     let screen_size = egui_context
         .ctx_mut()
@@ -575,6 +861,8 @@ pub fn ui_npc_store_system(
                                     Some(current_store_tab),
                                     current_tab_index,
                                     column + row * 8,
+                                    store_union_index,
+                                    ui_state.stock.get(&(current_tab_index, column + row * 8)),
                                     &mut ui_state.buy_list,
                                     player.as_ref(),
                                     player_tooltip_data.as_ref(),
@@ -590,7 +878,9 @@ pub fn ui_npc_store_system(
             );
         });
 
-    let mut transaction_cost = 0;
+    let mut transaction_buy_cost = 0;
+    let mut transaction_sell_value = 0;
+    let mut response_sell_junk = false;
 
     egui::Window::new("NPC Transaction")
         .frame(egui::Frame::none())
@@ -617,50 +907,154 @@ pub fn ui_npc_store_system(
                         let column = (i % NUM_BUY_ITEMS_PER_ROW) as f32;
                         let row = (i / NUM_BUY_ITEMS_PER_ROW) as f32;
 
-                        buy_item_price += ui_add_buy_item_slot(
-                            ui,
-                            ui_state_dnd.as_mut(),
-                            egui::pos2(10.0 + column * 41.0, 52.0 + row * 41.0),
-                            npc_data,
-                            &mut ui_state.buy_list,
-                            i,
-                            player.as_ref(),
-                            player_tooltip_data.as_ref(),
-                            &game_data,
-                            &ui_resources,
-                            world_rates.as_ref(),
+                        buy_item_price = i64::saturating_add(
+                            buy_item_price,
+                            ui_add_buy_item_slot(
+                                ui,
+                                ui_state_dnd.as_mut(),
+                                egui::pos2(10.0 + column * 41.0, 52.0 + row * 41.0),
+                                npc_data,
+                                store_union_index,
+                                &ui_state.stock,
+                                &mut ui_state.buy_list,
+                                i,
+                                player.as_ref(),
+                                player_tooltip_data.as_ref(),
+                                &game_data,
+                                &ui_resources,
+                                world_rates.as_ref(),
+                            ),
                         );
                     }
-                    ui.add_label_at(egui::pos2(39.0, 139.0), format!("{}", buy_item_price));
-                    transaction_cost += buy_item_price;
+                    ui.add_label_at(
+                        egui::pos2(39.0, 139.0),
+                        format_store_price(buy_item_price, store_union_index),
+                    );
+                    transaction_buy_cost = transaction_buy_cost.saturating_add(buy_item_price);
 
                     let mut sell_item_value = 0;
                     for i in 0..NUM_SELL_ITEMS {
                         let column = (i % NUM_SELL_ITEMS_PER_ROW) as f32;
                         let row = (i / NUM_SELL_ITEMS_PER_ROW) as f32;
 
-                        sell_item_value += ui_add_sell_item_slot(
-                            ui,
-                            ui_state_dnd.as_mut(),
-                            egui::pos2(10.0 + column * 41.0, 183.0 + row * 41.0),
-                            &mut ui_state.sell_list,
-                            i,
-                            player.as_ref(),
-                            player_tooltip_data.as_ref(),
-                            &game_data,
-                            &ui_resources,
-                            world_rates.as_ref(),
+                        sell_item_value = i64::saturating_add(
+                            sell_item_value,
+                            ui_add_sell_item_slot(
+                                ui,
+                                ui_state_dnd.as_mut(),
+                                egui::pos2(10.0 + column * 41.0, 183.0 + row * 41.0),
+                                &mut ui_state.sell_list,
+                                i,
+                                player.as_ref(),
+                                player_tooltip_data.as_ref(),
+                                &game_data,
+                                &ui_resources,
+                                world_rates.as_ref(),
+                            ),
                         );
                     }
-                    ui.add_label_at(egui::pos2(39.0, 272.0), format!("{}", sell_item_value));
-                    transaction_cost -= sell_item_value;
+                    ui.add_label_at(egui::pos2(39.0, 272.0), format_money(sell_item_value));
+                    transaction_sell_value = transaction_sell_value.saturating_add(sell_item_value);
                 },
             );
+
+            if ui.button("Sell All Junk").clicked() {
+                response_sell_junk = true;
+            }
         });
 
+    if response_sell_junk {
+        if let Some(player) = player.as_ref() {
+            let num_free_sell_slots = ui_state
+                .sell_list
+                .iter()
+                .filter(|pending_sell_item| pending_sell_item.is_none())
+                .count();
+            let junk_items: Vec<(ItemSlot, &Item)> = (0..INVENTORY_PAGE_SIZE)
+                .map(|index| ItemSlot::Inventory(InventoryPageType::Equipment, index))
+                .filter(|item_slot| {
+                    !ui_state.sell_list.iter().any(|pending_sell_item| {
+                        pending_sell_item
+                            .as_ref()
+                            .map_or(false, |pending_sell_item| {
+                                pending_sell_item.item_slot == *item_slot
+                            })
+                    })
+                })
+                .filter_map(|item_slot| {
+                    player
+                        .inventory
+                        .get_item(item_slot)
+                        .map(|item| (item_slot, item))
+                })
+                .filter(|(_, item)| get_item_rarity(&game_data, item) == ItemRarity::Junk)
+                .take(num_free_sell_slots)
+                .collect();
+
+            if junk_items.is_empty() {
+                message_box_events.send(MessageBoxEvent::Show {
+                    message: "You have no junk items to sell.".to_string(),
+                    modal: false,
+                    ok: Some(Box::new(|_| {})),
+                    cancel: None,
+                });
+            } else {
+                let mut message = String::from("Add the following items to the sell list?\n");
+                let mut total_value: i64 = 0;
+                for (_, item) in junk_items.iter() {
+                    let value = get_store_item_sell_price(
+                        item,
+                        Some(player),
+                        &game_data,
+                        world_rates.as_ref(),
+                    );
+                    total_value = total_value.saturating_add(value);
+                    message.push_str(&format!(
+                        "\n{} ({})",
+                        game_data
+                            .items
+                            .get_base_item(item.get_item_reference())
+                            .map_or("?", |item_data| item_data.name),
+                        format_money(value)
+                    ));
+                }
+                message.push_str(&format!("\n\nTotal: {} Zuly", format_money(total_value)));
+
+                let sell_items: Vec<ItemSlot> =
+                    junk_items.iter().map(|(item_slot, _)| *item_slot).collect();
+                message_box_events.send(MessageBoxEvent::Show {
+                    message,
+                    modal: true,
+                    ok: Some(Box::new(move |commands| {
+                        commands.add(move |world: &mut World| {
+                            let mut npc_store_events =
+                                world.resource_mut::<Events<NpcStoreEvent>>();
+                            for item_slot in sell_items {
+                                npc_store_events.send(NpcStoreEvent::AddToSellList(item_slot));
+                            }
+                        });
+                    })),
+                    cancel: Some(Box::new(|_| {})),
+                });
+            }
+        }
+    }
+
     if response_ok.map_or(false, |x| x.clicked()) {
-        let can_afford_transaction =
-            player.map_or(true, |player| transaction_cost <= player.inventory.money.0);
+        let can_afford_transaction = player.map_or(true, |player| {
+            if let Some(store_union_index) = store_union_index {
+                // Union points pay for the items bought, items sold are still paid in zuly
+                transaction_buy_cost
+                    <= player
+                        .union_membership
+                        .points
+                        .get(store_union_index)
+                        .map_or(0, |points| *points as i64)
+            } else {
+                transaction_buy_cost.saturating_sub(transaction_sell_value)
+                    <= player.inventory.money.0
+            }
+        });
         // TODO: Check inventory space
 
         if can_afford_transaction {
@@ -691,7 +1085,11 @@ pub fn ui_npc_store_system(
             }
         } else {
             message_box_events.send(MessageBoxEvent::Show {
-                message: "You do not have enough Zuly for this transaction.".to_string(),
+                message: if store_union_index.is_some() {
+                    "You do not have enough Union Points for this transaction.".to_string()
+                } else {
+                    "You do not have enough Zuly for this transaction.".to_string()
+                },
                 modal: true,
                 ok: Some(Box::new(|_| {})),
                 cancel: None,
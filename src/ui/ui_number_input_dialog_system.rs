@@ -10,7 +10,7 @@ use bevy_egui::{
 
 use crate::{
     events::NumberInputDialogEvent,
-    resources::UiResources,
+    resources::{InputFocus, UiResources},
     ui::{
         UiSoundEvent,
         {widgets::Dialog, DataBindings},
@@ -42,6 +42,69 @@ pub struct ActiveNumberInputDialog {
     cancel: Option<Box<dyn FnOnce(&mut Commands) + Send + Sync>>,
 }
 
+const INCREMENT_BUTTONS: [usize; 3] = [1, 10, 100];
+
+fn parse_number(text: &str) -> Option<usize> {
+    match text.parse::<usize>() {
+        Ok(value) => Some(value),
+        Err(error) if *error.kind() == std::num::IntErrorKind::PosOverflow => Some(usize::MAX),
+        Err(_) => None,
+    }
+}
+
+fn evaluate_product(text: &str) -> Option<usize> {
+    let mut result = None;
+    let mut operator = '*';
+    let mut remaining = text;
+
+    loop {
+        let operator_index = remaining.find(['*', 'x', 'X', '/']);
+        let value = parse_number(&remaining[..operator_index.unwrap_or(remaining.len())])?;
+
+        result = Some(match (result, operator) {
+            (None, _) => value,
+            (Some(result), '/') => result.checked_div(value).unwrap_or(0),
+            (Some(result), _) => usize::saturating_mul(result, value),
+        });
+
+        let Some(operator_index) = operator_index else {
+            return result;
+        };
+        operator = remaining[operator_index..].chars().next()?;
+        remaining = &remaining[operator_index + 1..];
+    }
+}
+
+/// Evaluate simple arithmetic such as "5*99" or "1000-250", saturating rather than overflowing.
+/// Multiplication and division are applied before addition and subtraction.
+fn evaluate_number_input(text: &str) -> Option<usize> {
+    let text: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut result = None;
+    let mut operator = '+';
+    let mut remaining = text.as_str();
+
+    loop {
+        let operator_index = remaining.find(['+', '-']);
+        let value = evaluate_product(&remaining[..operator_index.unwrap_or(remaining.len())])?;
+
+        result = Some(match (result, operator) {
+            (None, _) => value,
+            (Some(result), '-') => usize::saturating_sub(result, value),
+            (Some(result), _) => usize::saturating_add(result, value),
+        });
+
+        let Some(operator_index) = operator_index else {
+            return result;
+        };
+        operator = remaining[operator_index..].chars().next()?;
+        remaining = &remaining[operator_index + 1..];
+    }
+}
+
+fn clamp_to_max_value(value: usize, max_value: Option<usize>) -> usize {
+    max_value.map_or(value, |max_value| value.min(max_value))
+}
+
 #[derive(Default)]
 pub struct UiStateMessageBox {
     active: Option<ActiveNumberInputDialog>,
@@ -55,6 +118,7 @@ pub fn ui_number_input_dialog_system(
     mut number_input_dialog_events: ResMut<Events<NumberInputDialogEvent>>,
     dialog_assets: Res<Assets<Dialog>>,
     ui_resources: Res<UiResources>,
+    mut input_focus: ResMut<InputFocus>,
 ) {
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_number_input) {
         dialog
@@ -87,6 +151,7 @@ pub fn ui_number_input_dialog_system(
     }
 
     if ui_state.active.as_ref().map_or(false, |x| x.modal) {
+        input_focus.request_modal();
         egui::Area::new("modal_ninput")
             .interactable(true)
             .fixed_pos(egui::Pos2::ZERO)
@@ -180,6 +245,45 @@ pub fn ui_number_input_dialog_system(
             |_ui, _bindings| {},
         );
 
+        let mut value = clamp_to_max_value(
+            evaluate_number_input(&active_dialog.current_value).unwrap_or(0),
+            active_dialog.max_value,
+        );
+        let mut value_changed = false;
+
+        egui::Frame::window(ui.style()).show(ui, |ui| {
+            ui.set_width(dialog.width - 16.0);
+
+            ui.horizontal(|ui| {
+                for increment in INCREMENT_BUTTONS {
+                    if ui.button(format!("+{}", increment)).clicked() {
+                        value = clamp_to_max_value(
+                            value.saturating_add(increment),
+                            active_dialog.max_value,
+                        );
+                        value_changed = true;
+                    }
+                }
+
+                if active_dialog
+                    .current_value
+                    .contains(['+', '-', '*', 'x', 'X', '/'])
+                {
+                    ui.label(format!("= {}", value));
+                }
+            });
+
+            if let Some(max_value) = active_dialog.max_value {
+                value_changed |= ui
+                    .add(egui::Slider::new(&mut value, 0..=max_value).show_value(false))
+                    .changed();
+            }
+        });
+
+        if value_changed {
+            active_dialog.current_value = format!("{}", value);
+        }
+
         response
     });
 
@@ -282,13 +386,10 @@ pub fn ui_number_input_dialog_system(
 
     if response_button_ok.map_or(false, |x| x.clicked()) {
         let active = ui_state.active.take().unwrap();
-        let mut value = active.current_value.parse::<usize>().unwrap_or(0);
-
-        if let Some(max_value) = active.max_value {
-            if value > max_value {
-                value = max_value;
-            }
-        }
+        let value = clamp_to_max_value(
+            evaluate_number_input(&active.current_value).unwrap_or(0),
+            active.max_value,
+        );
 
         if value > 0 {
             if let Some(ok) = active.ok {
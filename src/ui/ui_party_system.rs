@@ -1,20 +1,20 @@
 use bevy::{
     ecs::query::WorldQuery,
-    prelude::{Assets, Entity, EventReader, EventWriter, Local, Query, Res, ResMut, With},
+    prelude::{Assets, Entity, EventWriter, Local, Query, Res, ResMut, With},
 };
 use bevy_egui::{egui, EguiContexts};
 
 use rose_game_common::{
     components::{AbilityValues, CharacterInfo, HealthPoints, Level},
-    messages::{
-        client::ClientMessage, server::PartyMemberInfo, ClientEntityId, PartyRejectInviteReason,
-    },
+    messages::{client::ClientMessage, server::PartyMemberInfo},
 };
 
 use crate::{
-    components::{ClientEntity, ClientEntityName, PartyInfo, PartyOwner, PlayerCharacter},
-    events::PartyEvent,
-    resources::{ClientEntityList, GameConnection, SelectedTarget, UiResources},
+    components::{PartyInfo, PartyOwner, PlayerCharacter},
+    resources::{
+        ClientEntityList, GameConnection, GameData, HudSettings, HudWidget, SelectedTarget,
+        UiResources,
+    },
     ui::{
         widgets::{Dialog, Gauge},
         UiSoundEvent,
@@ -52,14 +52,7 @@ pub struct PartyMemberQuery<'w> {
     level: &'w Level,
 }
 
-pub struct PendingPartyInvite {
-    is_create: bool,
-    client_entity_id: ClientEntityId,
-    name: String,
-}
-
 pub struct UiStatePartySystem {
-    pending_invites: Vec<PendingPartyInvite>,
     party_xp_gauge: Gauge,
     party_member_health_gauge: Gauge,
     selected_party_member_index: Option<usize>,
@@ -68,7 +61,6 @@ pub struct UiStatePartySystem {
 impl Default for UiStatePartySystem {
     fn default() -> Self {
         Self {
-            pending_invites: Default::default(),
             party_xp_gauge: Gauge {
                 id: IID_PARTY_XP_GAUGE,
                 x: 96.0,
@@ -101,10 +93,10 @@ pub fn ui_party_system(
     mut egui_context: EguiContexts,
     query_player: Query<PlayerQuery>,
     query_party_member: Query<PartyMemberQuery>,
-    query_invite: Query<(&ClientEntity, &ClientEntityName)>,
-    mut party_events: EventReader<PartyEvent>,
     game_connection: Option<Res<GameConnection>>,
     client_entity_list: Res<ClientEntityList>,
+    game_data: Res<GameData>,
+    hud_settings: Res<HudSettings>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
     mut selected_target: ResMut<SelectedTarget>,
@@ -115,111 +107,6 @@ pub fn ui_party_system(
         return;
     };
 
-    // Add any new incoming invites
-    for event in party_events.iter() {
-        match *event {
-            PartyEvent::InvitedCreate(entity) => {
-                if let Ok((client_entity, client_entity_name)) = query_invite.get(entity) {
-                    ui_state.pending_invites.push(PendingPartyInvite {
-                        is_create: true,
-                        client_entity_id: client_entity.id,
-                        name: client_entity_name.to_string(),
-                    });
-                }
-            }
-            PartyEvent::InvitedJoin(entity) => {
-                if let Ok((client_entity, client_entity_name)) = query_invite.get(entity) {
-                    ui_state.pending_invites.push(PendingPartyInvite {
-                        is_create: false,
-                        client_entity_id: client_entity.id,
-                        name: client_entity_name.to_string(),
-                    });
-                }
-            }
-        }
-    }
-
-    let mut i = 0;
-    while i != ui_state.pending_invites.len() {
-        let mut window_open = true;
-        let mut accepted = false;
-        let mut rejected = false;
-        let pending_invite = &ui_state.pending_invites[i];
-
-        if player.party_info.is_none() {
-            egui::Window::new("Party Invite")
-                .id(egui::Id::new(format!(
-                    "party_invite_{}",
-                    &pending_invite.name
-                )))
-                .collapsible(false)
-                .open(&mut window_open)
-                .show(egui_context.ctx_mut(), |ui| {
-                    ui.label(format!(
-                        "{} has invited you to {} a party",
-                        &pending_invite.name,
-                        if pending_invite.is_create {
-                            "create"
-                        } else {
-                            "join"
-                        }
-                    ));
-
-                    if ui.button("Accept").clicked() {
-                        accepted = true;
-                    }
-
-                    if ui.button("Reject").clicked() {
-                        rejected = true;
-                    }
-                });
-        } else {
-            rejected = true;
-        }
-
-        if !window_open {
-            rejected = true;
-        }
-
-        if accepted {
-            if let Some(game_connection) = &game_connection {
-                if pending_invite.is_create {
-                    game_connection
-                        .client_message_tx
-                        .send(ClientMessage::PartyAcceptCreateInvite {
-                            owner_entity_id: pending_invite.client_entity_id,
-                        })
-                        .ok();
-                } else {
-                    game_connection
-                        .client_message_tx
-                        .send(ClientMessage::PartyAcceptJoinInvite {
-                            owner_entity_id: pending_invite.client_entity_id,
-                        })
-                        .ok();
-                }
-            }
-
-            ui_state.pending_invites.remove(i);
-            continue;
-        } else if rejected {
-            if let Some(game_connection) = &game_connection {
-                game_connection
-                    .client_message_tx
-                    .send(ClientMessage::PartyRejectInvite {
-                        reason: PartyRejectInviteReason::Reject,
-                        owner_entity_id: pending_invite.client_entity_id,
-                    })
-                    .ok();
-            }
-
-            ui_state.pending_invites.remove(i);
-            continue;
-        }
-
-        i += 1;
-    }
-
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_party) {
         if ui_state.party_xp_gauge.foreground_sprite.is_none() {
             ui_state.party_xp_gauge.load_widget(&ui_resources);
@@ -246,6 +133,9 @@ pub fn ui_party_system(
     let mut response_option_button = None;
 
     ui_state_windows.party_open = player.party_info.is_some();
+    if !hud_settings.is_visible(HudWidget::Party) {
+        return;
+    }
 
     if let Some(party_info) = player.party_info {
         let player_is_owner = matches!(party_info.owner, PartyOwner::Player);
@@ -291,11 +181,11 @@ pub fn ui_party_system(
                                     egui::vec2(220.0, 45.0),
                                     egui::Sense::click(),
                                 );
-                                {
+                                let job = {
                                     let ui = &mut ui.child_ui(rect, egui::Layout::default());
                                     let selected =
                                         ui_state.selected_party_member_index == Some(index);
-                                    let (online, name) = match member {
+                                    let (online, name, job) = match member {
                                         PartyMemberInfo::Online(member_info) => {
                                             if let Some(party_member) = client_entity_list
                                                 .get(member_info.entity_id)
@@ -322,12 +212,31 @@ pub fn ui_party_system(
                                                         ..Default::default()
                                                     },
                                                 );
-                                            }
 
-                                            (true, &member_info.name)
+                                                if let Some(job_icon) = ui_resources
+                                                    .get_job_icon(party_member.character_info.job)
+                                                {
+                                                    job_icon.draw(
+                                                        ui,
+                                                        rect.min
+                                                            + egui::vec2(
+                                                                204.0 - job_icon.width,
+                                                                4.0,
+                                                            ),
+                                                    );
+                                                }
+
+                                                (
+                                                    true,
+                                                    &member_info.name,
+                                                    Some(party_member.character_info.job),
+                                                )
+                                            } else {
+                                                (true, &member_info.name, None)
+                                            }
                                         }
                                         PartyMemberInfo::Offline(member_info) => {
-                                            (false, &member_info.name)
+                                            (false, &member_info.name, None)
                                         }
                                     };
 
@@ -345,7 +254,16 @@ pub fn ui_party_system(
                                             egui::Color32::GRAY
                                         }),
                                     );
-                                }
+
+                                    job
+                                };
+
+                                let response = if let Some(job) = job {
+                                    response
+                                        .on_hover_text(game_data.string_database.get_job_name(job))
+                                } else {
+                                    response
+                                };
 
                                 if response.clicked() {
                                     if let Some(entity) = member
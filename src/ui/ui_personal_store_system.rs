@@ -13,6 +13,7 @@ use crate::{
     events::{MessageBoxEvent, PersonalStoreEvent},
     resources::{GameConnection, GameData, UiResources},
     ui::{
+        format_money,
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
         ui_add_item_tooltip,
         widgets::{DataBindings, Dialog},
@@ -91,7 +92,7 @@ fn ui_add_store_item_slot(
             message: format!(
                 "Are you sure you want to buy {} for {} Zuly?",
                 item_data.as_ref().map(|x| x.name).unwrap_or(""),
-                price.0
+                format_money(price.0)
             ),
             modal: false,
             ok: Some(Box::new(move |commands| {
@@ -112,9 +113,15 @@ fn ui_add_store_item_slot(
         ui_add_item_tooltip(ui, game_data, player_tooltip_data, item);
 
         if is_sell_item {
-            ui.colored_label(egui::Color32::YELLOW, format!("Price: {}", price.0));
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                format!("Price: {}", format_money(price.0)),
+            );
         } else {
-            ui.colored_label(egui::Color32::GREEN, format!("Price: {}", price.0));
+            ui.colored_label(
+                egui::Color32::GREEN,
+                format!("Price: {}", format_money(price.0)),
+            );
         }
     });
 }
@@ -10,7 +10,7 @@ use rose_game_common::components::{
 
 use crate::{
     components::PlayerCharacter,
-    resources::{GameData, SelectedTarget, UiResources},
+    resources::{GameData, HudSettings, HudWidget, SelectedTarget, UiResources},
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
         ui_add_item_tooltip,
@@ -113,7 +113,12 @@ pub fn ui_player_info_system(
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
     mut selected_target: ResMut<SelectedTarget>,
+    hud_settings: Res<HudSettings>,
 ) {
+    if !hud_settings.is_visible(HudWidget::PlayerInfo) {
+        return;
+    }
+
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_player_info) {
         dialog
     } else {
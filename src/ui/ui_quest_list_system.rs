@@ -6,7 +6,7 @@ use rose_game_common::components::QuestState;
 
 use crate::{
     components::PlayerCharacter,
-    resources::{GameData, UiResources},
+    resources::{CharacterSettings, GameData, UiResources},
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
         ui_add_item_tooltip,
@@ -29,6 +29,8 @@ const IID_LIST_QUESTINFO: i32 = 30;
 // const IID_PANE_QUESTLIST: i32 = 100;
 const IID_PANE_QUESTINFO: i32 = 200;
 
+const TRACKED_QUEST_COLOR: egui::Color32 = egui::Color32::from_rgb(150, 255, 150);
+
 fn ui_add_quest_item_slot(
     ui: &mut egui::Ui,
     pos: egui::Pos2,
@@ -91,6 +93,7 @@ pub fn ui_quest_list_system(
     mut egui_context: EguiContexts,
     mut ui_state_windows: ResMut<UiStateWindows>,
     mut ui_sound_events: EventWriter<UiSoundEvent>,
+    mut character_settings: ResMut<CharacterSettings>,
     query_player: Query<&QuestState, With<PlayerCharacter>>,
     query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
     game_data: Res<GameData>,
@@ -125,6 +128,8 @@ pub fn ui_quest_list_system(
         .filter(|q| q.is_some())
         .count();
     let scrollbar_range = 0..num_quests as i32;
+    let tracked_quests = character_settings.tracked_quests.clone();
+    let mut toggle_tracked_quest = None;
 
     let mut response_close_button = None;
     let mut response_minimise_button = None;
@@ -175,6 +180,12 @@ pub fn ui_quest_list_system(
                                             egui::RichText::new(quest_data.name)
                                                 .color(egui::Color32::YELLOW),
                                         );
+                                    } else if tracked_quests.contains(&active_quest.quest_id) {
+                                        ui.add_label_at(
+                                            egui::pos2(28.0, 4.0),
+                                            egui::RichText::new(quest_data.name)
+                                                .color(TRACKED_QUEST_COLOR),
+                                        );
                                     } else {
                                         ui.add_label_at(egui::pos2(28.0, 4.0), quest_data.name);
                                     }
@@ -222,6 +233,12 @@ pub fn ui_quest_list_system(
                                             egui::RichText::new(quest_data.name)
                                                 .color(egui::Color32::YELLOW),
                                         ));
+
+                                        let mut tracked =
+                                            tracked_quests.contains(&selected_quest.quest_id);
+                                        if ui.checkbox(&mut tracked, "Track").changed() {
+                                            toggle_tracked_quest = Some(selected_quest.quest_id);
+                                        }
                                     })
                                 },
                             );
@@ -268,6 +285,24 @@ pub fn ui_quest_list_system(
             );
         });
 
+    if let Some(quest_id) = toggle_tracked_quest {
+        let tracked_quests = &mut character_settings.tracked_quests;
+        if let Some(index) = tracked_quests.iter().position(|id| *id == quest_id) {
+            tracked_quests.remove(index);
+        } else {
+            tracked_quests.push(quest_id);
+        }
+
+        // Forget quests which have since been completed or abandoned
+        tracked_quests.retain(|id| {
+            player_quest_state
+                .active_quests
+                .iter()
+                .flatten()
+                .any(|active_quest| active_quest.quest_id == *id)
+        });
+    }
+
     if response_close_button.map_or(false, |r| r.clicked()) {
         ui_state_windows.quest_list_open = false;
     }
@@ -0,0 +1,198 @@
+use bevy::{
+    ecs::query::WorldQuery,
+    math::Vec3Swizzles,
+    prelude::{EventReader, Local, Query, Res, ResMut, With},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::EquipmentItem;
+use rose_game_common::{
+    components::{Equipment, Inventory, InventoryPageType, ItemSlot, INVENTORY_PAGE_SIZE},
+    messages::{client::ClientMessage, ClientEntityId},
+};
+
+use crate::{
+    components::{PlayerCharacter, Position},
+    events::RepairEvent,
+    resources::{ClientEntityList, GameConnection, GameData},
+    ui::{format_money, UiStateWindows},
+};
+
+const REPAIR_MAX_ITEM_LIFE: u16 = 1000;
+const REPAIR_MAX_NPC_DISTANCE: f32 = 1000.0;
+
+#[derive(Default)]
+pub struct UiStateRepair {
+    npc_client_entity_id: Option<ClientEntityId>,
+}
+
+#[derive(WorldQuery)]
+pub struct PlayerQuery<'w> {
+    equipment: &'w Equipment,
+    inventory: &'w Inventory,
+    position: &'w Position,
+}
+
+/// An estimate of the repair cost to display, the server decides the actual price charged
+fn get_repair_cost(game_data: &GameData, equipment_item: &EquipmentItem) -> i64 {
+    let base_price = game_data
+        .items
+        .get_base_item(equipment_item.item)
+        .map_or(0, |item_data| item_data.base_price as i64);
+
+    base_price * (equipment_item.durability as i64 + 10) * (1100 - equipment_item.life as i64)
+        / 400000
+}
+
+fn get_damaged_items(player: &PlayerQueryItem) -> Vec<(ItemSlot, EquipmentItem)> {
+    let equipped_items =
+        player
+            .equipment
+            .equipped_items
+            .iter()
+            .filter_map(|(equipment_index, item)| {
+                item.as_ref()
+                    .map(|item| (ItemSlot::Equipment(equipment_index), item.clone()))
+            });
+
+    let inventory_items = (0..INVENTORY_PAGE_SIZE).filter_map(|index| {
+        let item_slot = ItemSlot::Inventory(InventoryPageType::Equipment, index);
+        player
+            .inventory
+            .get_item(item_slot)
+            .and_then(|item| item.as_equipment())
+            .map(|item| (item_slot, item.clone()))
+    });
+
+    equipped_items
+        .chain(inventory_items)
+        .filter(|(_, item)| item.life < REPAIR_MAX_ITEM_LIFE)
+        .collect()
+}
+
+pub fn ui_repair_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateRepair>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut repair_events: EventReader<RepairEvent>,
+    client_entity_list: Res<ClientEntityList>,
+    game_connection: Option<Res<GameConnection>>,
+    game_data: Res<GameData>,
+    query_player: Query<PlayerQuery, With<PlayerCharacter>>,
+    query_position: Query<&Position>,
+) {
+    for event in repair_events.iter() {
+        match *event {
+            RepairEvent::OpenRepairFromClientEntity { client_entity_id } => {
+                ui_state.npc_client_entity_id = Some(client_entity_id);
+                ui_state_windows.repair_open = true;
+            }
+        }
+    }
+
+    if !ui_state_windows.repair_open {
+        return;
+    }
+
+    let Ok(player) = query_player.get_single() else {
+        return;
+    };
+
+    let Some(npc_client_entity_id) = ui_state.npc_client_entity_id else {
+        ui_state_windows.repair_open = false;
+        return;
+    };
+
+    // If player has moved away from the repair npc, close the dialog
+    let npc_in_range = client_entity_list
+        .get(npc_client_entity_id)
+        .and_then(|npc_entity| query_position.get(npc_entity).ok())
+        .map_or(false, |npc_position| {
+            player
+                .position
+                .position
+                .xy()
+                .distance(npc_position.position.xy())
+                <= REPAIR_MAX_NPC_DISTANCE
+        });
+    if !npc_in_range {
+        ui_state_windows.repair_open = false;
+        ui_state.npc_client_entity_id = None;
+        return;
+    }
+
+    let damaged_items = get_damaged_items(&player);
+    let total_cost: i64 = damaged_items
+        .iter()
+        .map(|(_, item)| get_repair_cost(&game_data, item))
+        .fold(0, i64::saturating_add);
+    let money = player.inventory.money.0;
+    let mut repair_items = Vec::new();
+
+    egui::Window::new("Repair")
+        .open(&mut ui_state_windows.repair_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            if damaged_items.is_empty() {
+                ui.label("None of your equipment needs repairing.");
+                return;
+            }
+
+            egui::Grid::new("repair_items_grid")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Item");
+                    ui.strong("Life");
+                    ui.strong("Cost");
+                    ui.label("");
+                    ui.end_row();
+
+                    for (item_slot, item) in damaged_items.iter() {
+                        let cost = get_repair_cost(&game_data, item);
+                        let name = game_data
+                            .items
+                            .get_base_item(item.item)
+                            .map_or("?", |item_data| item_data.name);
+
+                        ui.label(name);
+                        if item.life == 0 {
+                            ui.colored_label(egui::Color32::RED, "Broken");
+                        } else {
+                            ui.label(format!("{}%", (item.life + 9) / 10));
+                        }
+                        ui.label(format_money(cost));
+                        if ui
+                            .add_enabled(cost <= money, egui::Button::new("Repair"))
+                            .clicked()
+                        {
+                            repair_items.push(*item_slot);
+                        }
+                        ui.end_row();
+                    }
+                });
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.label(format!("Total: {} Zuly", format_money(total_cost)));
+                if ui
+                    .add_enabled(total_cost <= money, egui::Button::new("Repair All"))
+                    .clicked()
+                {
+                    repair_items.extend(damaged_items.iter().map(|(item_slot, _)| *item_slot));
+                }
+            });
+        });
+
+    if let Some(game_connection) = game_connection.as_ref() {
+        for item_slot in repair_items {
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::RepairItemUsingNpc {
+                    npc_entity_id: npc_client_entity_id,
+                    item_slot,
+                })
+                .ok();
+        }
+    }
+}
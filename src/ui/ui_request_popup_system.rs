@@ -0,0 +1,221 @@
+use std::time::Duration;
+
+use bevy::prelude::{EventReader, Local, Query, Res, Time, With};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::messages::{client::ClientMessage, ClientEntityId, PartyRejectInviteReason};
+
+use crate::{
+    components::{
+        AwayFromKeyboard, Clan, ClientEntity, ClientEntityName, Command, PartyInfo, PlayerCharacter,
+    },
+    events::PartyEvent,
+    resources::{GameConnection, IgnoreList, RequestCategory, RequestSettings},
+};
+
+const REQUEST_POPUP_WIDTH: f32 = 300.0;
+
+#[derive(Copy, Clone)]
+enum RequestType {
+    PartyCreate,
+    PartyJoin,
+}
+
+impl RequestType {
+    fn category(&self) -> RequestCategory {
+        match self {
+            RequestType::PartyCreate | RequestType::PartyJoin => RequestCategory::Party,
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            RequestType::PartyCreate => "has invited you to create a party",
+            RequestType::PartyJoin => "has invited you to join their party",
+        }
+    }
+}
+
+struct PendingRequest {
+    request_type: RequestType,
+    client_entity_id: ClientEntityId,
+    name: String,
+    received: Duration,
+}
+
+#[derive(Default)]
+pub struct UiStateRequestPopups {
+    requests: Vec<PendingRequest>,
+}
+
+fn send_request_reply(
+    game_connection: Option<&GameConnection>,
+    request: &PendingRequest,
+    accepted: bool,
+    busy: bool,
+) {
+    let Some(game_connection) = game_connection else {
+        return;
+    };
+
+    let message = match (request.request_type, accepted) {
+        (RequestType::PartyCreate, true) => ClientMessage::PartyAcceptCreateInvite {
+            owner_entity_id: request.client_entity_id,
+        },
+        (RequestType::PartyJoin, true) => ClientMessage::PartyAcceptJoinInvite {
+            owner_entity_id: request.client_entity_id,
+        },
+        (RequestType::PartyCreate | RequestType::PartyJoin, false) => {
+            ClientMessage::PartyRejectInvite {
+                reason: if busy {
+                    PartyRejectInviteReason::Busy
+                } else {
+                    PartyRejectInviteReason::Reject
+                },
+                owner_entity_id: request.client_entity_id,
+            }
+        }
+    };
+    game_connection.client_message_tx.send(message).ok();
+}
+
+/// Shows incoming requests from other players as a queue of popups at the bottom centre of the
+/// screen, requests which are not answered before their timeout bar runs out are declined.
+#[allow(clippy::too_many_arguments)]
+pub fn ui_request_popup_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateRequestPopups>,
+    mut party_events: EventReader<PartyEvent>,
+    query_player: Query<
+        (
+            &Command,
+            Option<&PartyInfo>,
+            Option<&Clan>,
+            Option<&AwayFromKeyboard>,
+        ),
+        With<PlayerCharacter>,
+    >,
+    query_requester: Query<(&ClientEntity, &ClientEntityName)>,
+    game_connection: Option<Res<GameConnection>>,
+    ignore_list: Res<IgnoreList>,
+    request_settings: Res<RequestSettings>,
+    time: Res<Time>,
+) {
+    let ui_state = &mut *ui_state;
+    let now = time.elapsed();
+    let Ok((command, party_info, clan, away)) = query_player.get_single() else {
+        ui_state.requests.clear();
+        return;
+    };
+    let is_busy = away.is_some()
+        || matches!(
+            command,
+            Command::Attack(_) | Command::CastSkill(_) | Command::PersonalStore | Command::Die
+        );
+
+    for event in party_events.iter() {
+        let (entity, request_type) = match *event {
+            PartyEvent::InvitedCreate(entity) => (entity, RequestType::PartyCreate),
+            PartyEvent::InvitedJoin(entity) => (entity, RequestType::PartyJoin),
+        };
+
+        let Ok((client_entity, client_entity_name)) = query_requester.get(entity) else {
+            continue;
+        };
+        let request = PendingRequest {
+            request_type,
+            client_entity_id: client_entity.id,
+            name: client_entity_name.to_string(),
+            received: now,
+        };
+        let category = request_type.category();
+
+        // Automatically decline requests from ignored players, or which our settings decline
+        if ignore_list.is_ignored(&request.name)
+            || (category == RequestCategory::Party && party_info.is_some())
+            || (request_settings.decline_from_strangers[category]
+                && clan.map_or(true, |clan| clan.find_member(&request.name).is_none()))
+        {
+            send_request_reply(game_connection.as_deref(), &request, false, false);
+            continue;
+        }
+
+        if is_busy && request_settings.decline_while_busy[category] {
+            send_request_reply(game_connection.as_deref(), &request, false, true);
+            continue;
+        }
+
+        // A new request from the same player replaces their previous one
+        ui_state.requests.retain(|pending| {
+            pending.client_entity_id != request.client_entity_id
+                || pending.request_type.category() != category
+        });
+        ui_state.requests.push(request);
+    }
+
+    // Joining a party answers any other party requests
+    if party_info.is_some() {
+        ui_state.requests.retain(|request| {
+            if request.request_type.category() == RequestCategory::Party {
+                send_request_reply(game_connection.as_deref(), request, false, false);
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    if ui_state.requests.is_empty() {
+        return;
+    }
+
+    let mut replies = Vec::new();
+    egui::Area::new("request_popups")
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -160.0])
+        .order(egui::Order::Foreground)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
+                for (index, request) in ui_state.requests.iter().enumerate() {
+                    let elapsed = now.saturating_sub(request.received);
+                    if elapsed >= request_settings.timeout {
+                        replies.push((index, false));
+                        continue;
+                    }
+                    let remaining =
+                        1.0 - elapsed.as_secs_f32() / request_settings.timeout.as_secs_f32();
+
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.set_width(REQUEST_POPUP_WIDTH);
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{} Request",
+                                request.request_type.category().name()
+                            ))
+                            .strong(),
+                        );
+                        ui.label(format!(
+                            "{} {}",
+                            request.name,
+                            request.request_type.description()
+                        ));
+                        ui.add(egui::ProgressBar::new(remaining).desired_height(4.0));
+
+                        ui.horizontal(|ui| {
+                            if ui.button("Accept").clicked() {
+                                replies.push((index, true));
+                            }
+
+                            if ui.button("Decline").clicked() {
+                                replies.push((index, false));
+                            }
+                        });
+                    });
+                }
+            });
+        });
+
+    for (index, accepted) in replies.into_iter().rev() {
+        let request = ui_state.requests.remove(index);
+        send_request_reply(game_connection.as_deref(), &request, accepted, false);
+    }
+}
@@ -1,11 +1,13 @@
 use bevy::prelude::{Local, Query, Res, ResMut};
 use bevy_egui::{egui, EguiContexts};
 
-use rose_game_common::components::{AbilityValues, HealthPoints, Npc};
+use rose_game_common::components::{AbilityValues, CharacterInfo, HealthPoints, Npc};
 
 use crate::{
     components::{ClientEntityName, Dead},
-    resources::{SelectedTarget, UiResources, UiSprite},
+    resources::{
+        GameData, HudSettings, HudWidget, InterfaceSettings, SelectedTarget, UiResources, UiSprite,
+    },
     ui::UiStateWindows,
 };
 
@@ -21,15 +23,19 @@ pub struct UiSelectedTargetState {
 pub fn ui_selected_target_system(
     mut egui_context: EguiContexts,
     mut ui_state: Local<UiSelectedTargetState>,
-    ui_state_windows: Res<UiStateWindows>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
     query_target: Query<(
         &AbilityValues,
+        Option<&CharacterInfo>,
         &ClientEntityName,
         Option<&Dead>,
         &HealthPoints,
         Option<&Npc>,
     )>,
     ui_resources: Res<UiResources>,
+    game_data: Res<GameData>,
+    interface_settings: Res<InterfaceSettings>,
+    hud_settings: Res<HudSettings>,
     mut selected_target: ResMut<SelectedTarget>,
 ) {
     if ui_state.sprite_top.is_none() {
@@ -40,12 +46,14 @@ pub fn ui_selected_target_system(
         ui_state.hp_gauge_foreground = ui_resources.get_sprite(0, "UI00_GUAGE_RED");
     }
 
-    if !ui_state_windows.selected_target_ui_open {
+    if !ui_state_windows.selected_target_ui_open
+        || !hud_settings.is_visible(HudWidget::SelectedTarget)
+    {
         return;
     }
 
     if let Some(selected_target_entity) = selected_target.selected {
-        if let Ok((ability_values, client_entity_name, dead, health_points, npc)) =
+        if let Ok((ability_values, character_info, client_entity_name, dead, health_points, npc)) =
             query_target.get(selected_target_entity)
         {
             if dead.is_some() && npc.is_some() {
@@ -143,14 +151,70 @@ pub fn ui_selected_target_system(
                                 text_rect.set_height(20.0);
                                 text_rect.min.y += 11.0;
                                 text_rect.max.y += 11.0;
-                                ui.put(text_rect, egui::Label::new(client_entity_name.as_str()));
+                                let name = if npc.is_some() {
+                                    client_entity_name.as_str()
+                                } else {
+                                    interface_settings
+                                        .other_character_name(client_entity_name.as_str())
+                                };
+                                if selected_target.locked {
+                                    ui.put(
+                                        text_rect,
+                                        egui::Label::new(format!("{} [Locked]", name)),
+                                    );
+                                } else {
+                                    ui.put(text_rect, egui::Label::new(name));
+                                }
 
                                 text_rect.min.y += 14.0;
                                 text_rect.max.y += 14.0;
-                                ui.put(
-                                    text_rect,
-                                    egui::Label::new(format!("Level: {}", ability_values.level)),
-                                );
+                                if let Some(character_info) = character_info {
+                                    ui.put(
+                                        text_rect,
+                                        egui::Label::new(format!(
+                                            "Level: {} {}",
+                                            ability_values.level,
+                                            game_data
+                                                .string_database
+                                                .get_job_name(character_info.job)
+                                        )),
+                                    );
+
+                                    if let Some(job_icon) =
+                                        ui_resources.get_job_icon(character_info.job)
+                                    {
+                                        job_icon.draw(
+                                            ui,
+                                            egui::pos2(
+                                                rect.min.x + 8.0,
+                                                text_rect.center().y - job_icon.height / 2.0,
+                                            ),
+                                        );
+                                    }
+                                } else {
+                                    ui.put(
+                                        text_rect,
+                                        egui::Label::new(format!(
+                                            "Level: {}",
+                                            ability_values.level
+                                        )),
+                                    );
+                                }
+
+                                if npc.is_some() {
+                                    let info_rect = egui::Rect::from_min_size(
+                                        egui::pos2(rect.max.x - 26.0, rect.min.y + 8.0),
+                                        egui::vec2(18.0, 18.0),
+                                    );
+                                    if ui
+                                        .put(info_rect, egui::Button::new("i").small())
+                                        .on_hover_text("Monster Info")
+                                        .clicked()
+                                    {
+                                        ui_state_windows.monster_info_open =
+                                            !ui_state_windows.monster_info_open;
+                                    }
+                                }
                             }
                         }
                     });
@@ -1,9 +1,9 @@
-use bevy::prelude::{Assets, Commands, EventWriter, Local, Res};
+use bevy::prelude::{Assets, Commands, EventWriter, Local, Res, ResMut};
 use bevy_egui::{egui, EguiContexts};
 
 use crate::{
     events::LoginEvent,
-    resources::{LoginConnection, LoginState, ServerList, UiResources},
+    resources::{ChannelQueue, LoginConnection, LoginState, ServerList, UiResources},
     ui::{
         widgets::{DataBindings, Dialog},
         UiSoundEvent,
@@ -27,6 +27,7 @@ pub fn ui_server_select_system(
     server_list: Option<Res<ServerList>>,
     ui_resources: Res<UiResources>,
     mut login_events: EventWriter<LoginEvent>,
+    mut channel_queue: ResMut<ChannelQueue>,
 ) {
     if !matches!(*login_state, LoginState::ServerSelect) {
         return;
@@ -110,6 +111,17 @@ pub fn ui_server_select_system(
             );
         });
 
+    egui::Window::new("Select Server Options")
+        .title_bar(false)
+        .resizable(false)
+        .fixed_pos(egui::pos2(position.x, position.y + dialog.height + 4.0))
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.checkbox(
+                &mut channel_queue.auto_next_channel,
+                "Automatically try next channel if full",
+            );
+        });
+
     if response_ok_button.map_or(false, |r| r.clicked()) {
         try_select_server = true;
     }
@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use bevy::prelude::{Local, Res, ResMut, Time};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    resources::SessionStats,
+    ui::{format_money, UiStateWindows},
+};
+
+const SESSION_STATS_EXPORT_DIRECTORY: &str = "session_stats";
+
+#[derive(Default)]
+pub struct UiStateSessionStats {
+    export_result: Option<String>,
+}
+
+fn stats_grid(ui: &mut egui::Ui, id: &str, empty_text: &str, entries: &[(&String, &u32)]) {
+    if entries.is_empty() {
+        ui.label(empty_text);
+        return;
+    }
+
+    egui::ScrollArea::vertical()
+        .id_source(id)
+        .max_height(150.0)
+        .show(ui, |ui| {
+            egui::Grid::new(id)
+                .num_columns(2)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (name, count) in entries.iter() {
+                        ui.label(name.as_str());
+                        ui.label(format!("{}", count));
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+pub fn ui_session_stats_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateSessionStats>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut session_stats: ResMut<SessionStats>,
+    time: Res<Time>,
+) {
+    if !ui_state_windows.session_stats_open {
+        return;
+    }
+
+    let now = time.elapsed();
+
+    egui::Window::new("Session Statistics")
+        .open(&mut ui_state_windows.session_stats_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            let duration = session_stats.duration(now).as_secs();
+            egui::Grid::new("session_stats_grid")
+                .num_columns(2)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("Session Time");
+                    ui.label(format!(
+                        "{}:{:02}:{:02}",
+                        duration / 3600,
+                        (duration / 60) % 60,
+                        duration % 60
+                    ));
+                    ui.end_row();
+
+                    ui.label("XP Gained");
+                    ui.label(format!("{}", session_stats.xp_gained));
+                    ui.end_row();
+
+                    ui.label("Zuly Earned");
+                    ui.label(format_money(session_stats.zuly_earned as i64));
+                    ui.end_row();
+
+                    ui.label("Zuly Spent");
+                    ui.label(format_money(session_stats.zuly_spent as i64));
+                    ui.end_row();
+
+                    ui.label("Deaths");
+                    ui.label(format!("{}", session_stats.deaths));
+                    ui.end_row();
+                });
+
+            ui.separator();
+            ui.strong("Monsters Killed");
+            let mut monsters_killed: Vec<_> = session_stats.monsters_killed.iter().collect();
+            monsters_killed.sort_by(|(_, a), (_, b)| b.cmp(a));
+            stats_grid(
+                ui,
+                "session_stats_monsters_grid",
+                "No monsters killed.",
+                &monsters_killed,
+            );
+
+            ui.separator();
+            ui.strong("Items Looted");
+            let mut items_looted: Vec<_> = session_stats.items_looted.iter().collect();
+            items_looted.sort_by(|(_, a), (_, b)| b.cmp(a));
+            stats_grid(
+                ui,
+                "session_stats_items_grid",
+                "No items looted.",
+                &items_looted,
+            );
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button("Reset").clicked() {
+                    session_stats.reset(now);
+                    ui_state.export_result = None;
+                }
+
+                if ui.button("Export CSV").clicked() {
+                    ui_state.export_result = Some(
+                        match session_stats
+                            .export_csv(Path::new(SESSION_STATS_EXPORT_DIRECTORY), now)
+                        {
+                            Ok(path) => format!("Exported to {}", path.display()),
+                            Err(error) => format!("Failed to export: {}", error),
+                        },
+                    );
+                }
+            });
+
+            if let Some(export_result) = ui_state.export_result.as_ref() {
+                ui.label(export_result);
+            }
+        });
+}
@@ -1,23 +1,503 @@
-use bevy::prelude::{Local, Query, ResMut};
+use std::{thread::JoinHandle, time::Duration};
+
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::{Input, KeyCode, Local, MouseButton, Msaa, Query, Res, ResMut, With},
+    window::{PresentMode, PrimaryWindow, Window},
+};
 use bevy_egui::{egui, EguiContexts};
 
 use crate::{
-    audio::SoundGain, components::SoundCategory, resources::SoundSettings, ui::UiStateWindows,
+    audio::SoundGain,
+    components::SoundCategory,
+    data_verifier::{download_data_overlay, verify_data, DataVerifyReport},
+    resources::{
+        config_table_mut, AccessibilitySettings, CharacterSettings, ColorPalette, ConfigFile,
+        ControlScheme, DataOverlayConfiguration, DisplayMode, DisplaySettings,
+        DropNotificationSettings, EffectSettings, HudSettings, HudWidget, InterfaceSettings,
+        KeyBinding, KeyBindingAction, KeyBindingInput, KeyBindings, NameTagHealthbarCategory,
+        NameTagHealthbarPolicy, NameTagSettings, RenderConfiguration, RenderPreset,
+        RequestCategory, RequestSettings, ShadowQuality, SoundSettings, VfsResource, WaterQuality,
+        DISPLAY_RESOLUTIONS,
+    },
+    ui::{ItemRarity, UiStateWindows},
 };
 
 #[derive(Copy, Clone, PartialEq, Debug)]
 enum SettingsPage {
     Sound,
+    Display,
+    Interface,
+    Controls,
+    Accessibility,
+    Data,
+}
+
+const MAX_DISPLAY_MONITORS: usize = 4;
+
+fn color_palette_name(palette: ColorPalette) -> &'static str {
+    match palette {
+        ColorPalette::Default => "Default",
+        ColorPalette::RedGreen => "Red-Green Colour Blind",
+        ColorPalette::BlueYellow => "Blue-Yellow Colour Blind",
+    }
+}
+
+fn shadow_quality_name(quality: ShadowQuality) -> &'static str {
+    match quality {
+        ShadowQuality::Off => "Off",
+        ShadowQuality::Blob => "Blob",
+        ShadowQuality::ShadowMap => "Shadow Map",
+    }
+}
+
+fn render_preset_name(preset: RenderPreset) -> &'static str {
+    match preset {
+        RenderPreset::Low => "Low",
+        RenderPreset::Medium => "Medium",
+        RenderPreset::High => "High",
+        RenderPreset::Ultra => "Ultra",
+        RenderPreset::Custom => "Custom",
+    }
+}
+
+fn water_quality_name(quality: WaterQuality) -> &'static str {
+    match quality {
+        WaterQuality::Low => "Low",
+        WaterQuality::High => "High",
+    }
+}
+
+fn msaa_name(msaa: Msaa) -> &'static str {
+    match msaa {
+        Msaa::Off => "Off",
+        Msaa::Sample2 => "2x MSAA",
+        Msaa::Sample4 => "4x MSAA",
+        Msaa::Sample8 => "8x MSAA",
+    }
+}
+
+fn display_mode_name(mode: DisplayMode) -> &'static str {
+    match mode {
+        DisplayMode::Windowed => "Windowed",
+        DisplayMode::BorderlessFullscreen => "Borderless Fullscreen",
+        DisplayMode::ExclusiveFullscreen => "Exclusive Fullscreen",
+    }
+}
+
+fn fanfare_rarity_name(rarity: Option<ItemRarity>) -> &'static str {
+    match rarity {
+        None => "Off",
+        Some(ItemRarity::Junk) => "All Items",
+        Some(ItemRarity::Common) => "Common",
+        Some(ItemRarity::Rare) => "Rare",
+        Some(ItemRarity::Unique) => "Unique",
+    }
+}
+
+fn fanfare_rarity_combo(ui: &mut egui::Ui, id: &str, rarity: &mut Option<ItemRarity>) {
+    egui::ComboBox::from_id_source(id)
+        .selected_text(fanfare_rarity_name(*rarity))
+        .show_ui(ui, |ui| {
+            for value in [
+                None,
+                Some(ItemRarity::Common),
+                Some(ItemRarity::Rare),
+                Some(ItemRarity::Unique),
+            ] {
+                ui.selectable_value(rarity, value, fanfare_rarity_name(value));
+            }
+        });
+}
+
+#[derive(SystemParam)]
+pub struct SettingsInterfaceParams<'w> {
+    interface_settings: ResMut<'w, InterfaceSettings>,
+    hud_settings: ResMut<'w, HudSettings>,
+    drop_notification_settings: ResMut<'w, DropNotificationSettings>,
+    request_settings: ResMut<'w, RequestSettings>,
+}
+
+#[derive(SystemParam)]
+pub struct SettingsDisplayParams<'w, 's> {
+    display_settings: ResMut<'w, DisplaySettings>,
+    query_window: Query<'w, 's, &'static mut Window, With<PrimaryWindow>>,
+    msaa: ResMut<'w, Msaa>,
+    config_file: Res<'w, ConfigFile>,
+}
+
+#[derive(SystemParam)]
+pub struct SettingsInputParams<'w> {
+    key_bindings: ResMut<'w, KeyBindings>,
+    keyboard_input: Res<'w, Input<KeyCode>>,
+    mouse_input: Res<'w, Input<MouseButton>>,
+}
+
+#[derive(SystemParam)]
+pub struct SettingsRenderParams<'w> {
+    render_configuration: ResMut<'w, RenderConfiguration>,
+    effect_settings: ResMut<'w, EffectSettings>,
 }
 
 pub struct UiStateSettings {
     page: SettingsPage,
+    data_verify_report: Option<DataVerifyReport>,
+    data_download_thread: Option<JoinHandle<usize>>,
+    data_download_result: Option<String>,
+    /// The settings when the window was opened, only the settings which differ from these are
+    /// saved to the config file when the window is closed
+    opened_settings: Option<toml::Table>,
+    /// The action waiting for a key or mouse button to be pressed to bind to it
+    rebinding: Option<KeyBindingAction>,
+    /// Rebinding saves to the character settings instead of the config file
+    character_key_bindings: bool,
 }
 
 impl Default for UiStateSettings {
     fn default() -> Self {
         Self {
             page: SettingsPage::Sound,
+            data_verify_report: None,
+            data_download_thread: None,
+            data_download_result: None,
+            opened_settings: None,
+            rebinding: None,
+            character_key_bindings: false,
+        }
+    }
+}
+
+/// Returns the settings which can be changed in the settings window, laid out as in the config file
+#[allow(clippy::too_many_arguments)]
+fn settings_table(
+    sound_settings: &SoundSettings,
+    render_configuration: &RenderConfiguration,
+    effect_settings: &EffectSettings,
+    interface_settings: &InterfaceSettings,
+    hud_settings: &HudSettings,
+    drop_notification_settings: &DropNotificationSettings,
+    request_settings: &RequestSettings,
+    name_tag_settings: &NameTagSettings,
+    accessibility_settings: &AccessibilitySettings,
+    key_bindings: &KeyBindings,
+    window: Option<&Window>,
+    msaa: Msaa,
+) -> toml::Table {
+    let mut volume = toml::Table::new();
+    volume.insert("global".into(), (sound_settings.global_gain as f64).into());
+    for (category, gain) in sound_settings.gains.iter() {
+        let name = match category {
+            SoundCategory::BackgroundMusic => "background_music",
+            SoundCategory::PlayerFootstep => "player_footstep",
+            SoundCategory::PlayerCombat => "player_combat",
+            SoundCategory::OtherFootstep => "other_footstep",
+            SoundCategory::OtherCombat => "other_combat",
+            SoundCategory::NpcSounds => "npc_sounds",
+            SoundCategory::Ui => "ui_sounds",
+        };
+        volume.insert(name.into(), (*gain as f64).into());
+    }
+
+    let mut sound = toml::Table::new();
+    sound.insert("enabled".into(), sound_settings.enabled.into());
+    sound.insert(
+        "music_cross_fade".into(),
+        (sound_settings.music_cross_fade as f64).into(),
+    );
+    sound.insert("volume".into(), volume.into());
+
+    let mut graphics = toml::Table::new();
+    // A preset overrides the individual options, so it is only saved when still in use
+    let render_preset = match render_configuration.preset {
+        RenderPreset::Low => Some("low"),
+        RenderPreset::Medium => Some("medium"),
+        RenderPreset::High => Some("high"),
+        RenderPreset::Ultra => Some("ultra"),
+        RenderPreset::Custom => None,
+    };
+    if let Some(render_preset) = render_preset {
+        graphics.insert("render_preset".into(), render_preset.into());
+    }
+    graphics.insert(
+        "shadow_quality".into(),
+        match render_configuration.shadow_quality {
+            ShadowQuality::Off => "off",
+            ShadowQuality::Blob => "blob",
+            ShadowQuality::ShadowMap => "shadow_map",
+        }
+        .into(),
+    );
+    graphics.insert(
+        "water_quality".into(),
+        match render_configuration.water_quality {
+            WaterQuality::Low => "low",
+            WaterQuality::High => "high",
+        }
+        .into(),
+    );
+    graphics.insert(
+        "particle_density".into(),
+        (render_configuration.particle_density as f64).into(),
+    );
+    graphics.insert("bloom".into(), render_configuration.bloom.into());
+    graphics.insert(
+        "weapon_trails".into(),
+        render_configuration.weapon_trails.into(),
+    );
+    graphics.insert(
+        "projectile_collision".into(),
+        render_configuration.projectile_collision.into(),
+    );
+    graphics.insert(
+        "terrain_view_distance".into(),
+        (render_configuration.terrain_view_distance.unwrap_or(0.0) as f64).into(),
+    );
+    graphics.insert(
+        "zone_block_streaming_distance".into(),
+        (render_configuration
+            .zone_block_streaming_distance
+            .unwrap_or(0.0) as f64)
+            .into(),
+    );
+    graphics.insert(
+        "hide_other_player_skill_effects".into(),
+        effect_settings.hide_other_player_skill_effects.into(),
+    );
+    graphics.insert(
+        "hide_screen_covering_effects".into(),
+        effect_settings.hide_screen_covering_effects.into(),
+    );
+    graphics.insert(
+        "max_effects".into(),
+        (effect_settings.max_effects.unwrap_or(0) as i64).into(),
+    );
+    if let Some(window) = window {
+        graphics.insert(
+            "disable_vsync".into(),
+            (window.present_mode == PresentMode::Immediate).into(),
+        );
+    }
+    graphics.insert("msaa_samples".into(), (msaa.samples() as i64).into());
+
+    let fanfare_rarity = |rarity: Option<ItemRarity>| match rarity {
+        None => "off",
+        Some(ItemRarity::Junk) | Some(ItemRarity::Common) => "common",
+        Some(ItemRarity::Rare) => "rare",
+        Some(ItemRarity::Unique) => "unique",
+    };
+    let mut drop_notifications = toml::Table::new();
+    drop_notifications.insert(
+        "fanfare_rarity".into(),
+        fanfare_rarity(drop_notification_settings.fanfare_rarity).into(),
+    );
+    drop_notifications.insert(
+        "party_fanfare_rarity".into(),
+        fanfare_rarity(drop_notification_settings.party_fanfare_rarity).into(),
+    );
+    drop_notifications.insert(
+        "mute_common_drops".into(),
+        drop_notification_settings.mute_common_drops.into(),
+    );
+
+    let mut requests = toml::Table::new();
+    requests.insert(
+        "timeout_seconds".into(),
+        (request_settings.timeout.as_secs() as i64).into(),
+    );
+    for (category, decline) in request_settings.decline_while_busy.iter() {
+        let name = match category {
+            RequestCategory::Party => "decline_party_while_busy",
+            RequestCategory::Trade => "decline_trade_while_busy",
+            RequestCategory::Duel => "decline_duel_while_busy",
+        };
+        requests.insert(name.into(), (*decline).into());
+    }
+    for (category, decline) in request_settings.decline_from_strangers.iter() {
+        let name = match category {
+            RequestCategory::Party => "decline_party_from_strangers",
+            RequestCategory::Trade => "decline_trade_from_strangers",
+            RequestCategory::Duel => "decline_duel_from_strangers",
+        };
+        requests.insert(name.into(), (*decline).into());
+    }
+
+    let mut hud = toml::Table::new();
+    hud.insert(
+        "restore_on_combat".into(),
+        hud_settings.restore_on_combat.into(),
+    );
+    for (widget, visible) in hud_settings.widgets.iter() {
+        let name = match widget {
+            HudWidget::Chatbox => "chatbox",
+            HudWidget::Clock => "clock",
+            HudWidget::Hotbar => "hotbar",
+            HudWidget::Minimap => "minimap",
+            HudWidget::Party => "party",
+            HudWidget::PlayerInfo => "player_info",
+            HudWidget::SelectedTarget => "selected_target",
+            HudWidget::StatusEffects => "status_effects",
+        };
+        hud.insert(name.into(), (*visible).into());
+    }
+
+    let mut name_tags = toml::Table::new();
+    for (category, policy) in name_tag_settings.healthbar_policy.iter() {
+        let name = match category {
+            NameTagHealthbarCategory::PartyMember => "party_member_healthbar",
+            NameTagHealthbarCategory::Pet => "pet_healthbar",
+            NameTagHealthbarCategory::Character => "character_healthbar",
+            NameTagHealthbarCategory::Monster => "monster_healthbar",
+        };
+        let policy = match policy {
+            NameTagHealthbarPolicy::Always => "always",
+            NameTagHealthbarPolicy::InCombat => "in_combat",
+            NameTagHealthbarPolicy::Never => "never",
+        };
+        name_tags.insert(name.into(), policy.into());
+    }
+
+    let mut interface = toml::Table::new();
+    for (name, value) in [
+        ("show_clock", interface_settings.show_clock),
+        ("clock_use_24_hour", interface_settings.clock_use_24_hour),
+        ("streamer_mode", interface_settings.streamer_mode),
+        ("wasd_movement", interface_settings.wasd_movement),
+        (
+            "combat_click_through",
+            interface_settings.combat_click_through,
+        ),
+        ("aggro_indicator", interface_settings.aggro_indicator),
+        ("target_camera", interface_settings.target_camera),
+        ("minimap_rotate", interface_settings.minimap_rotate),
+        ("cooldown_text", interface_settings.cooldown_text),
+        (
+            "cooldown_ready_flash",
+            interface_settings.cooldown_ready_flash,
+        ),
+        ("auto_self_cast", interface_settings.auto_self_cast),
+        ("tutorial_hints", interface_settings.tutorial_hints),
+        (
+            "focus_loss_mute_audio",
+            interface_settings.focus_loss_mute_audio,
+        ),
+        (
+            "focus_loss_reduce_fps",
+            interface_settings.focus_loss_reduce_fps,
+        ),
+        (
+            "focus_loss_stop_auto_run",
+            interface_settings.focus_loss_stop_auto_run,
+        ),
+        ("shout_banners", interface_settings.shout_banners),
+        ("toast_items", interface_settings.toast_items),
+        ("toast_quests", interface_settings.toast_quests),
+        ("toast_social", interface_settings.toast_social),
+        ("toast_connection", interface_settings.toast_connection),
+    ] {
+        interface.insert(name.into(), value.into());
+    }
+    interface.insert(
+        "idle_camera_orbit_minutes".into(),
+        (interface_settings
+            .idle_camera_orbit
+            .map_or(0, |duration| duration.as_secs() / 60) as i64)
+            .into(),
+    );
+    interface.insert(
+        "control_scheme".into(),
+        match interface_settings.control_scheme {
+            ControlScheme::Classic => "classic",
+            ControlScheme::Modern => "modern",
+        }
+        .into(),
+    );
+    interface.insert(
+        "camera_sensitivity".into(),
+        (interface_settings.camera_sensitivity as f64).into(),
+    );
+    interface.insert(
+        "skill_queue_window_ms".into(),
+        (interface_settings.skill_queue_window.as_millis() as i64).into(),
+    );
+    interface.insert(
+        "announcement_banner_seconds".into(),
+        (interface_settings
+            .announcement_banner_duration
+            .map_or(0, |duration| duration.as_secs()) as i64)
+            .into(),
+    );
+    interface.insert("drop_notifications".into(), drop_notifications.into());
+    interface.insert("requests".into(), requests.into());
+    interface.insert("hud".into(), hud.into());
+    interface.insert("name_tags".into(), name_tags.into());
+
+    let mut accessibility = toml::Table::new();
+    accessibility.insert(
+        "color_palette".into(),
+        match accessibility_settings.color_palette {
+            ColorPalette::Default => "default",
+            ColorPalette::RedGreen => "red_green",
+            ColorPalette::BlueYellow => "blue_yellow",
+        }
+        .into(),
+    );
+    accessibility.insert(
+        "minimum_font_size".into(),
+        (accessibility_settings.minimum_font_size as f64).into(),
+    );
+    accessibility.insert(
+        "high_contrast".into(),
+        accessibility_settings.high_contrast.into(),
+    );
+    accessibility.insert(
+        "sound_captions".into(),
+        accessibility_settings.sound_captions.into(),
+    );
+
+    let mut bindings = toml::Table::new();
+    for (action, key_binding) in key_bindings.global.iter() {
+        bindings.insert(
+            action.config_name().into(),
+            key_binding.config_value().into(),
+        );
+    }
+
+    let mut settings = toml::Table::new();
+    settings.insert("sound".into(), sound.into());
+    settings.insert("graphics".into(), graphics.into());
+    settings.insert("interface".into(), interface.into());
+    settings.insert("accessibility".into(), accessibility.into());
+    settings.insert("key_bindings".into(), bindings.into());
+    settings
+}
+
+/// Copies the settings which differ between `opened` and `closed` into the config document.
+///
+/// Only settings the user changed are written, so options which were overridden from the
+/// command line (e.g. --disable-vsync) are not saved to the config file.
+fn apply_changed_settings(document: &mut toml::Table, opened: &toml::Table, closed: &toml::Table) {
+    for (name, value) in closed.iter() {
+        let opened_value = opened.get(name);
+        if opened_value == Some(value) {
+            continue;
+        }
+
+        if let toml::Value::Table(closed_table) = value {
+            let empty_table = toml::Table::new();
+            let opened_table = opened_value
+                .and_then(|value| value.as_table())
+                .unwrap_or(&empty_table);
+            if let Some(document_table) = config_table_mut(document, name) {
+                apply_changed_settings(document_table, opened_table, closed_table);
+            }
+        } else {
+            document.insert(name.clone(), value.clone());
+        }
+    }
+
+    for name in opened.keys() {
+        if !closed.contains_key(name) {
+            document.remove(name);
         }
     }
 }
@@ -27,63 +507,1131 @@ pub fn ui_settings_system(
     mut ui_state_windows: ResMut<UiStateWindows>,
     mut ui_state_settings: Local<UiStateSettings>,
     mut sound_settings: ResMut<SoundSettings>,
+    interface_params: SettingsInterfaceParams,
+    input_params: SettingsInputParams,
+    mut name_tag_settings: ResMut<NameTagSettings>,
+    mut character_settings: ResMut<CharacterSettings>,
     mut query_sounds: Query<(&SoundCategory, &mut SoundGain)>,
+    display_params: SettingsDisplayParams,
+    data_overlay_configuration: Res<DataOverlayConfiguration>,
+    vfs_resource: Res<VfsResource>,
+    render_params: SettingsRenderParams,
+    mut accessibility_settings: ResMut<AccessibilitySettings>,
 ) {
+    let SettingsInterfaceParams {
+        mut interface_settings,
+        mut hud_settings,
+        mut drop_notification_settings,
+        mut request_settings,
+    } = interface_params;
+    let SettingsInputParams {
+        mut key_bindings,
+        keyboard_input,
+        mouse_input,
+    } = input_params;
+    let SettingsRenderParams {
+        mut render_configuration,
+        mut effect_settings,
+    } = render_params;
+    let SettingsDisplayParams {
+        mut display_settings,
+        mut query_window,
+        mut msaa,
+        config_file,
+    } = display_params;
+    let ui_state_settings = &mut *ui_state_settings;
+
+    if ui_state_settings
+        .data_download_thread
+        .as_ref()
+        .map_or(false, |thread| thread.is_finished())
+    {
+        let thread = ui_state_settings.data_download_thread.take().unwrap();
+        let num_failures = ui_state_settings
+            .data_verify_report
+            .as_ref()
+            .map_or(0, |report| report.failures.len());
+        ui_state_settings.data_download_result = Some(match thread.join() {
+            Ok(num_downloaded) => {
+                format!("Downloaded {} of {} files", num_downloaded, num_failures)
+            }
+            Err(_) => "Download failed".to_string(),
+        });
+        ui_state_settings.data_verify_report = Some(verify_data(&vfs_resource.vfs));
+    }
+
+    if ui_state_windows.settings_open && ui_state_settings.opened_settings.is_none() {
+        ui_state_settings.opened_settings = Some(settings_table(
+            &sound_settings,
+            &render_configuration,
+            &effect_settings,
+            &interface_settings,
+            &hud_settings,
+            &drop_notification_settings,
+            &request_settings,
+            &name_tag_settings,
+            &accessibility_settings,
+            &key_bindings,
+            query_window.get_single().ok(),
+            *msaa,
+        ));
+    }
+
+    // Character key bindings can only be changed whilst in game
+    let has_character_settings = !character_settings.path.as_os_str().is_empty();
+    if !has_character_settings {
+        ui_state_settings.character_key_bindings = false;
+    }
+
+    if let Some(action) = ui_state_settings.rebinding {
+        if !ui_state_windows.settings_open || keyboard_input.just_pressed(KeyCode::Escape) {
+            ui_state_settings.rebinding = None;
+        } else if let Some(input) = keyboard_input
+            .get_just_pressed()
+            .find(|key| {
+                !matches!(
+                    key,
+                    KeyCode::ControlLeft
+                        | KeyCode::ControlRight
+                        | KeyCode::AltLeft
+                        | KeyCode::AltRight
+                        | KeyCode::ShiftLeft
+                        | KeyCode::ShiftRight
+                )
+            })
+            .map(|key| KeyBindingInput::Key(*key))
+            .or_else(|| {
+                mouse_input
+                    .get_just_pressed()
+                    .next()
+                    .map(|button| KeyBindingInput::Mouse(*button))
+            })
+        {
+            let key_binding = KeyBinding {
+                input,
+                control: keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+                alt: keyboard_input.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+                shift: keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            };
+
+            if ui_state_settings.character_key_bindings {
+                character_settings
+                    .hotkeys
+                    .insert(action.config_name().into(), key_binding.config_value());
+            } else {
+                key_bindings.global[action] = key_binding;
+            }
+            key_bindings.apply_overrides(&character_settings.hotkeys);
+            ui_state_settings.rebinding = None;
+        }
+    }
+
+    let mut open_bug_report = false;
+    let mut open_ignore_list = false;
+
     egui::Window::new("Settings")
         .open(&mut ui_state_windows.settings_open)
         .resizable(false)
         .show(egui_context.ctx_mut(), |ui| {
             ui.horizontal(|ui| {
                 ui.selectable_value(&mut ui_state_settings.page, SettingsPage::Sound, "Sound");
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::Display,
+                    "Display",
+                );
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::Interface,
+                    "Interface",
+                );
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::Controls,
+                    "Controls",
+                );
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::Accessibility,
+                    "Accessibility",
+                );
+                ui.selectable_value(&mut ui_state_settings.page, SettingsPage::Data, "Data");
             });
 
-            egui::Grid::new("sound_settings_gain")
-                .num_columns(2)
-                .show(ui, |ui| {
-                    let mut gain_changed = false;
-
-                    ui.label("Sound:");
-                    gain_changed |= ui
-                        .checkbox(&mut sound_settings.enabled, "Enabled")
-                        .changed();
-                    ui.end_row();
-
-                    ui.label("Global Volume:");
-                    gain_changed |= ui
-                        .add(
-                            egui::Slider::new(&mut sound_settings.global_gain, 0.0..=1.0)
-                                .show_value(true),
-                        )
-                        .changed();
-                    ui.end_row();
-
-                    let mut add_category_slider = |text: &str, category| {
-                        ui.label(text);
-                        gain_changed |= ui
-                            .add(
-                                egui::Slider::new(&mut sound_settings.gains[category], 0.0..=1.0)
+            match ui_state_settings.page {
+                SettingsPage::Sound => {
+                    egui::Grid::new("sound_settings_gain")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            let mut gain_changed = false;
+
+                            ui.label("Sound:");
+                            gain_changed |= ui
+                                .checkbox(&mut sound_settings.enabled, "Enabled")
+                                .changed();
+                            ui.end_row();
+
+                            ui.label("Global Volume:");
+                            gain_changed |= ui
+                                .add(
+                                    egui::Slider::new(&mut sound_settings.global_gain, 0.0..=1.0)
+                                        .show_value(true),
+                                )
+                                .changed();
+                            ui.end_row();
+
+                            let mut add_category_slider = |text: &str, category| {
+                                ui.label(text);
+                                gain_changed |= ui
+                                    .add(
+                                        egui::Slider::new(
+                                            &mut sound_settings.gains[category],
+                                            0.0..=1.0,
+                                        )
+                                        .show_value(true),
+                                    )
+                                    .changed();
+                                ui.end_row();
+                            };
+
+                            add_category_slider(
+                                "Background Music:",
+                                SoundCategory::BackgroundMusic,
+                            );
+                            add_category_slider("Player Footsteps:", SoundCategory::PlayerFootstep);
+                            add_category_slider("Other Footsteps:", SoundCategory::OtherFootstep);
+                            add_category_slider("Player Combat:", SoundCategory::PlayerCombat);
+                            add_category_slider("Other Combat:", SoundCategory::OtherCombat);
+                            add_category_slider("NPC Sounds:", SoundCategory::NpcSounds);
+
+                            ui.label("Music Cross Fade:");
+                            ui.add(
+                                egui::Slider::new(&mut sound_settings.music_cross_fade, 0.0..=10.0)
+                                    .suffix("s")
                                     .show_value(true),
+                            );
+                            ui.end_row();
+
+                            if gain_changed {
+                                for (category, mut gain) in query_sounds.iter_mut() {
+                                    let target_gain = sound_settings.gain(*category);
+
+                                    if target_gain != *gain {
+                                        *gain = target_gain;
+                                    }
+                                }
+                            }
+                        });
+                }
+                SettingsPage::Display => {
+                    let mut display_changed = false;
+
+                    egui::Grid::new("display_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Display Mode:");
+                            egui::ComboBox::from_id_source("display_mode")
+                                .selected_text(display_mode_name(display_settings.mode))
+                                .show_ui(ui, |ui| {
+                                    for mode in [
+                                        DisplayMode::Windowed,
+                                        DisplayMode::BorderlessFullscreen,
+                                        DisplayMode::ExclusiveFullscreen,
+                                    ] {
+                                        display_changed |= ui
+                                            .selectable_value(
+                                                &mut display_settings.mode,
+                                                mode,
+                                                display_mode_name(mode),
+                                            )
+                                            .changed();
+                                    }
+                                });
+                            ui.end_row();
+
+                            ui.label("Resolution:");
+                            ui.add_enabled_ui(
+                                display_settings.mode != DisplayMode::BorderlessFullscreen,
+                                |ui| {
+                                    let (width, height) = display_settings.resolution;
+                                    egui::ComboBox::from_id_source("display_resolution")
+                                        .selected_text(format!("{} x {}", width, height))
+                                        .show_ui(ui, |ui| {
+                                            for (width, height) in DISPLAY_RESOLUTIONS {
+                                                display_changed |= ui
+                                                    .selectable_value(
+                                                        &mut display_settings.resolution,
+                                                        (width, height),
+                                                        format!("{} x {}", width, height),
+                                                    )
+                                                    .changed();
+                                            }
+                                        });
+                                },
+                            );
+                            ui.end_row();
+
+                            ui.label("Monitor:");
+                            egui::ComboBox::from_id_source("display_monitor")
+                                .selected_text(display_settings.monitor.map_or_else(
+                                    || "Current".to_string(),
+                                    |index| format!("Monitor {}", index + 1),
+                                ))
+                                .show_ui(ui, |ui| {
+                                    display_changed |= ui
+                                        .selectable_value(
+                                            &mut display_settings.monitor,
+                                            None,
+                                            "Current",
+                                        )
+                                        .changed();
+                                    for index in 0..MAX_DISPLAY_MONITORS {
+                                        display_changed |= ui
+                                            .selectable_value(
+                                                &mut display_settings.monitor,
+                                                Some(index),
+                                                format!("Monitor {}", index + 1),
+                                            )
+                                            .changed();
+                                    }
+                                });
+                            ui.end_row();
+                        });
+
+                    if display_changed {
+                        if let Ok(mut window) = query_window.get_single_mut() {
+                            display_settings.apply(&mut window);
+                        }
+                        display_settings.save(&config_file);
+                    }
+
+                    egui::Grid::new("display_graphics_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("VSync:");
+                            if let Ok(mut window) = query_window.get_single_mut() {
+                                let mut vsync = window.present_mode != PresentMode::Immediate;
+                                if ui.checkbox(&mut vsync, "Enabled").changed() {
+                                    window.present_mode = if vsync {
+                                        PresentMode::Fifo
+                                    } else {
+                                        PresentMode::Immediate
+                                    };
+                                }
+                            }
+                            ui.end_row();
+
+                            ui.label("Anti-aliasing:");
+                            egui::ComboBox::from_id_source("msaa")
+                                .selected_text(msaa_name(*msaa))
+                                .show_ui(ui, |ui| {
+                                    for samples in
+                                        [Msaa::Off, Msaa::Sample2, Msaa::Sample4, Msaa::Sample8]
+                                    {
+                                        ui.selectable_value(
+                                            &mut *msaa,
+                                            samples,
+                                            msaa_name(samples),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Effects");
+                    let mut custom_changed = false;
+                    egui::Grid::new("effects_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Quality:");
+                            egui::ComboBox::from_id_source("render_preset")
+                                .selected_text(render_preset_name(render_configuration.preset))
+                                .show_ui(ui, |ui| {
+                                    for preset in [
+                                        RenderPreset::Low,
+                                        RenderPreset::Medium,
+                                        RenderPreset::High,
+                                        RenderPreset::Ultra,
+                                    ] {
+                                        if ui
+                                            .selectable_label(
+                                                render_configuration.preset == preset,
+                                                render_preset_name(preset),
+                                            )
+                                            .clicked()
+                                        {
+                                            render_configuration.apply_preset(preset);
+                                        }
+                                    }
+                                    ui.add_enabled(
+                                        false,
+                                        egui::SelectableLabel::new(
+                                            render_configuration.preset == RenderPreset::Custom,
+                                            render_preset_name(RenderPreset::Custom),
+                                        ),
+                                    );
+                                });
+                            ui.end_row();
+
+                            ui.label("Weapon Trails:");
+                            custom_changed |= ui
+                                .checkbox(&mut render_configuration.weapon_trails, "Enabled")
+                                .on_hover_text("Draw a trail behind melee weapons when attacking")
+                                .changed();
+                            ui.end_row();
+
+                            ui.label("Shadows:");
+                            egui::ComboBox::from_id_source("shadow_quality")
+                                .selected_text(shadow_quality_name(
+                                    render_configuration.shadow_quality,
+                                ))
+                                .show_ui(ui, |ui| {
+                                    for quality in [
+                                        ShadowQuality::Off,
+                                        ShadowQuality::Blob,
+                                        ShadowQuality::ShadowMap,
+                                    ] {
+                                        custom_changed |= ui
+                                            .selectable_value(
+                                                &mut render_configuration.shadow_quality,
+                                                quality,
+                                                shadow_quality_name(quality),
+                                            )
+                                            .changed();
+                                    }
+                                });
+                            ui.end_row();
+
+                            ui.label("Particles:");
+                            custom_changed |= ui
+                                .add(
+                                    egui::Slider::new(
+                                        &mut render_configuration.particle_density,
+                                        0.1..=1.0,
+                                    )
+                                    .custom_formatter(|value, _| {
+                                        format!("{:.0}%", value * 100.0)
+                                    }),
+                                )
+                                .changed();
+                            ui.end_row();
+
+                            ui.label("Water:");
+                            egui::ComboBox::from_id_source("water_quality")
+                                .selected_text(water_quality_name(
+                                    render_configuration.water_quality,
+                                ))
+                                .show_ui(ui, |ui| {
+                                    for quality in [WaterQuality::Low, WaterQuality::High] {
+                                        custom_changed |= ui
+                                            .selectable_value(
+                                                &mut render_configuration.water_quality,
+                                                quality,
+                                                water_quality_name(quality),
+                                            )
+                                            .changed();
+                                    }
+                                });
+                            ui.end_row();
+
+                            ui.label("Bloom:");
+                            custom_changed |= ui
+                                .checkbox(&mut render_configuration.bloom, "Enabled")
+                                .changed();
+                            ui.end_row();
+
+                            ui.label("Projectile Collision:");
+                            ui.checkbox(&mut render_configuration.projectile_collision, "Enabled")
+                                .on_hover_text("Projectiles impact walls and terrain in their path");
+                            ui.end_row();
+
+                            ui.label("View Distance:");
+                            let mut unlimited = render_configuration.terrain_view_distance.is_none();
+                            ui.horizontal(|ui| {
+                                if ui.checkbox(&mut unlimited, "Unlimited").changed() {
+                                    render_configuration.terrain_view_distance =
+                                        (!unlimited).then_some(1280.0);
+                                    custom_changed = true;
+                                }
+                                if let Some(view_distance) =
+                                    render_configuration.terrain_view_distance.as_mut()
+                                {
+                                    custom_changed |= ui
+                                        .add(
+                                            egui::Slider::new(view_distance, 320.0..=2560.0)
+                                                .step_by(160.0)
+                                                .suffix("m"),
+                                        )
+                                        .changed();
+                                }
+                            });
+                            ui.end_row();
+
+                            ui.label("Block Streaming:");
+                            let mut streaming = render_configuration
+                                .zone_block_streaming_distance
+                                .is_some();
+                            ui.horizontal(|ui| {
+                                if ui
+                                    .checkbox(&mut streaming, "Enabled")
+                                    .on_hover_text(
+                                        "Only spawn zone blocks near your character, reducing memory use in large zones",
+                                    )
+                                    .changed()
+                                {
+                                    render_configuration.zone_block_streaming_distance =
+                                        streaming.then_some(1600.0);
+                                }
+                                if let Some(streaming_distance) =
+                                    render_configuration.zone_block_streaming_distance.as_mut()
+                                {
+                                    ui.add(
+                                        egui::Slider::new(streaming_distance, 480.0..=3200.0)
+                                            .step_by(160.0)
+                                            .suffix("m"),
+                                    );
+                                }
+                            });
+                            ui.end_row();
+                        });
+
+                    if custom_changed {
+                        render_configuration.preset = RenderPreset::Custom;
+                    }
+
+                    ui.separator();
+                    ui.label("Skill Effects");
+                    egui::Grid::new("skill_effect_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Other Players:");
+                            ui.checkbox(
+                                &mut effect_settings.hide_other_player_skill_effects,
+                                "Hide",
+                            )
+                            .on_hover_text("Hide skill effects cast by other players");
+                            ui.end_row();
+
+                            ui.label("Screen Covering:");
+                            ui.checkbox(&mut effect_settings.hide_screen_covering_effects, "Hide")
+                                .on_hover_text("Hide effects of large area of effect skills");
+                            ui.end_row();
+
+                            ui.label("Maximum Effects:");
+                            ui.horizontal(|ui| {
+                                let mut limited = effect_settings.max_effects.is_some();
+                                if ui.checkbox(&mut limited, "Limit").changed() {
+                                    effect_settings.max_effects = limited.then_some(100);
+                                }
+                                if let Some(max_effects) = effect_settings.max_effects.as_mut() {
+                                    ui.add(egui::Slider::new(max_effects, 10..=500));
+                                }
+                            });
+                            ui.end_row();
+                        });
+                }
+                SettingsPage::Interface => {
+                    egui::Grid::new("interface_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Clock:");
+                            ui.checkbox(&mut interface_settings.show_clock, "Show");
+                            ui.end_row();
+
+                            ui.label("Clock Format:");
+                            ui.checkbox(&mut interface_settings.clock_use_24_hour, "24 Hour");
+                            ui.end_row();
+
+                            ui.label("Streamer Mode:");
+                            ui.checkbox(&mut interface_settings.streamer_mode, "Enabled")
+                                .on_hover_text(
+                                    "Hides other player names, whisper contents and server addresses",
+                                );
+                            ui.end_row();
+
+                            ui.label("Idle Camera:");
+                            ui.horizontal(|ui| {
+                                let mut enabled = interface_settings.idle_camera_orbit.is_some();
+                                let mut minutes = interface_settings
+                                    .idle_camera_orbit
+                                    .map_or(5, |delay| (delay.as_secs() / 60).max(1));
+
+                                ui.checkbox(&mut enabled, "Orbit after");
+                                ui.add_enabled(
+                                    enabled,
+                                    egui::DragValue::new(&mut minutes)
+                                        .clamp_range(1..=60)
+                                        .suffix(" min"),
+                                );
+
+                                interface_settings.idle_camera_orbit =
+                                    enabled.then(|| Duration::from_secs(minutes * 60));
+                            });
+                            ui.end_row();
+
+                            ui.label("Control Scheme:");
+                            egui::ComboBox::from_id_source("control_scheme")
+                                .selected_text(match interface_settings.control_scheme {
+                                    ControlScheme::Classic => "Classic",
+                                    ControlScheme::Modern => "Modern",
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(
+                                        &mut interface_settings.control_scheme,
+                                        ControlScheme::Classic,
+                                        "Classic",
+                                    )
+                                    .on_hover_text(
+                                        "Left click to move and interact, right drag to rotate the camera",
+                                    );
+                                    ui.selectable_value(
+                                        &mut interface_settings.control_scheme,
+                                        ControlScheme::Modern,
+                                        "Modern",
+                                    )
+                                    .on_hover_text(
+                                        "Move with WASD, drag either mouse button to rotate the camera",
+                                    );
+                                });
+                            ui.end_row();
+
+                            ui.label("Camera Sensitivity:");
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut interface_settings.camera_sensitivity,
+                                    0.1..=5.0,
+                                )
+                                .custom_formatter(|value, _| format!("{:.0}%", value * 100.0)),
+                            );
+                            ui.end_row();
+
+                            ui.label("WASD Movement:");
+                            ui.add_enabled(
+                                interface_settings.control_scheme == ControlScheme::Classic,
+                                egui::Checkbox::new(&mut interface_settings.wasd_movement, "Enabled"),
                             )
-                            .changed();
-                        ui.end_row();
-                    };
-
-                    add_category_slider("Background Music:", SoundCategory::BackgroundMusic);
-                    add_category_slider("Player Footsteps:", SoundCategory::PlayerFootstep);
-                    add_category_slider("Other Footsteps:", SoundCategory::OtherFootstep);
-                    add_category_slider("Player Combat:", SoundCategory::PlayerCombat);
-                    add_category_slider("Other Combat:", SoundCategory::OtherCombat);
-                    add_category_slider("NPC Sounds:", SoundCategory::NpcSounds);
-
-                    if gain_changed {
-                        for (category, mut gain) in query_sounds.iter_mut() {
-                            let target_gain = sound_settings.gain(*category);
-
-                            if target_gain != *gain {
-                                *gain = target_gain;
+                            .on_hover_text("Move relative to the camera with WASD");
+                            ui.end_row();
+
+                            ui.label("Combat Click Through:");
+                            ui.checkbox(&mut interface_settings.combat_click_through, "Enabled")
+                                .on_hover_text(
+                                    "Ignore friendly players and NPCs when clicking whilst attacking",
+                                );
+                            ui.end_row();
+
+                            ui.label("Aggro Indicator:");
+                            ui.checkbox(&mut interface_settings.aggro_indicator, "Enabled")
+                                .on_hover_text(
+                                    "Show arrows pointing towards off-screen monsters attacking you",
+                                );
+                            ui.end_row();
+
+                            ui.label("Target View:");
+                            ui.checkbox(&mut interface_settings.target_camera, "Enabled")
+                                .on_hover_text("Show a close up view of the selected target");
+                            ui.end_row();
+
+                            ui.label("Minimap:");
+                            ui.checkbox(
+                                &mut interface_settings.minimap_rotate,
+                                "Rotate With Camera",
+                            )
+                            .on_hover_text("Keep the camera facing up and rotate the map instead");
+                            ui.end_row();
+
+                            ui.label("Cooldowns:");
+                            ui.horizontal(|ui| {
+                                ui.checkbox(&mut interface_settings.cooldown_text, "Show Seconds");
+                                ui.checkbox(
+                                    &mut interface_settings.cooldown_ready_flash,
+                                    "Flash When Ready",
+                                );
+                            });
+                            ui.end_row();
+
+                            ui.label("Skill Queue Window:");
+                            let mut window_ms =
+                                interface_settings.skill_queue_window.as_millis() as u64;
+                            if ui
+                                .add(
+                                    egui::DragValue::new(&mut window_ms)
+                                        .clamp_range(0..=2000)
+                                        .speed(10)
+                                        .suffix(" ms"),
+                                )
+                                .on_hover_text("Set to 0 to disable queueing skills")
+                                .changed()
+                            {
+                                interface_settings.skill_queue_window =
+                                    Duration::from_millis(window_ms);
                             }
+                            ui.end_row();
+
+                            ui.label("Auto Self Cast:");
+                            ui.checkbox(&mut interface_settings.auto_self_cast, "Enabled")
+                                .on_hover_text(
+                                    "Cast beneficial skills on yourself when the target is invalid, hold Alt to always self cast",
+                                );
+                            ui.end_row();
+
+                            ui.label("Tutorial Hints:");
+                            ui.horizontal(|ui| {
+                                ui.checkbox(&mut interface_settings.tutorial_hints, "Enabled");
+                                if ui
+                                    .button("Reset")
+                                    .on_hover_text("Show all tutorial hints again for this character")
+                                    .clicked()
+                                {
+                                    character_settings.seen_tutorial_hints.clear();
+                                }
+                            });
+                            ui.end_row();
+
+                            ui.label("When Unfocused:");
+                            ui.vertical(|ui| {
+                                ui.checkbox(
+                                    &mut interface_settings.focus_loss_mute_audio,
+                                    "Mute Audio",
+                                );
+                                ui.checkbox(
+                                    &mut interface_settings.focus_loss_reduce_fps,
+                                    "Reduce Frame Rate",
+                                );
+                                ui.checkbox(
+                                    &mut interface_settings.focus_loss_stop_auto_run,
+                                    "Stop Auto Run",
+                                );
+                            });
+                            ui.end_row();
+
+                            ui.label("Announcement Banner:");
+                            ui.horizontal(|ui| {
+                                let mut banner_secs = interface_settings
+                                    .announcement_banner_duration
+                                    .map_or(0, |duration| duration.as_secs());
+                                if ui
+                                    .add(
+                                        egui::DragValue::new(&mut banner_secs)
+                                            .clamp_range(0..=30)
+                                            .suffix(" s"),
+                                    )
+                                    .on_hover_text("Set to 0 to only show announcements in chat")
+                                    .changed()
+                                {
+                                    interface_settings.announcement_banner_duration =
+                                        (banner_secs > 0).then(|| Duration::from_secs(banner_secs));
+                                }
+                                ui.checkbox(&mut interface_settings.shout_banners, "Show Shouts");
+                            });
+                            ui.end_row();
+
+                            ui.label("Notifications:");
+                            ui.horizontal(|ui| {
+                                ui.checkbox(&mut interface_settings.toast_items, "Items");
+                                ui.checkbox(&mut interface_settings.toast_quests, "Quests");
+                                ui.checkbox(&mut interface_settings.toast_social, "Social");
+                                ui.checkbox(&mut interface_settings.toast_connection, "Connection");
+                            });
+                            ui.end_row();
+
+                            ui.label("Rare Drop Fanfare:");
+                            ui.horizontal(|ui| {
+                                fanfare_rarity_combo(
+                                    ui,
+                                    "fanfare_rarity",
+                                    &mut drop_notification_settings.fanfare_rarity,
+                                );
+                                ui.label("Party:");
+                                fanfare_rarity_combo(
+                                    ui,
+                                    "party_fanfare_rarity",
+                                    &mut drop_notification_settings.party_fanfare_rarity,
+                                );
+                            });
+                            ui.end_row();
+
+                            ui.label("Mute Common Drops:");
+                            ui.checkbox(&mut drop_notification_settings.mute_common_drops, "")
+                                .on_hover_text("Hide chatbox messages for acquiring junk and common items");
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Requests");
+                    egui::Grid::new("interface_settings_requests")
+                        .num_columns(3)
+                        .show(ui, |ui| {
+                            ui.label("Timeout:");
+                            let mut timeout_secs = request_settings.timeout.as_secs();
+                            if ui
+                                .add(
+                                    egui::DragValue::new(&mut timeout_secs)
+                                        .clamp_range(5..=120)
+                                        .suffix(" s"),
+                                )
+                                .changed()
+                            {
+                                request_settings.timeout = Duration::from_secs(timeout_secs);
+                            }
+                            ui.end_row();
+
+                            let request_settings = &mut *request_settings;
+                            for (category, decline_while_busy) in
+                                request_settings.decline_while_busy.iter_mut()
+                            {
+                                ui.label(format!("{} Requests:", category.name()));
+                                ui.checkbox(decline_while_busy, "Decline while busy")
+                                    .on_hover_text(
+                                        "Automatically decline whilst away, fighting or running a personal store",
+                                    );
+                                ui.checkbox(
+                                    &mut request_settings.decline_from_strangers[category],
+                                    "Decline from non-clan members",
+                                );
+                                ui.end_row();
+                            }
+                        });
+
+                    ui.separator();
+                    ui.label("HUD");
+                    egui::Grid::new("interface_settings_hud")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Hide HUD:");
+                            ui.checkbox(&mut hud_settings.hidden, "").on_hover_text(format!(
+                                "Press {} to show the HUD again",
+                                key_bindings.get(KeyBindingAction::ToggleHud).display_name()
+                            ));
+                            ui.end_row();
+
+                            ui.label("Show HUD In Combat:");
+                            ui.checkbox(&mut hud_settings.restore_on_combat, "");
+                            ui.end_row();
+
+                            ui.label("Widgets:");
+                            ui.vertical(|ui| {
+                                for (widget, visible) in hud_settings.widgets.iter_mut() {
+                                    ui.checkbox(visible, widget.name());
+                                }
+                            });
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Health Bars");
+                    egui::Grid::new("interface_settings_healthbars")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            for (text, category) in [
+                                ("Party Members:", NameTagHealthbarCategory::PartyMember),
+                                ("Pets:", NameTagHealthbarCategory::Pet),
+                                ("Other Characters:", NameTagHealthbarCategory::Character),
+                                ("Monsters:", NameTagHealthbarCategory::Monster),
+                            ] {
+                                let policy = &mut name_tag_settings.healthbar_policy[category];
+
+                                ui.label(text);
+                                egui::ComboBox::from_id_source(text)
+                                    .selected_text(match policy {
+                                        NameTagHealthbarPolicy::Always => "Always",
+                                        NameTagHealthbarPolicy::InCombat => "In Combat",
+                                        NameTagHealthbarPolicy::Never => "Never",
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(
+                                            policy,
+                                            NameTagHealthbarPolicy::Always,
+                                            "Always",
+                                        );
+                                        ui.selectable_value(
+                                            policy,
+                                            NameTagHealthbarPolicy::InCombat,
+                                            "In Combat",
+                                        );
+                                        ui.selectable_value(
+                                            policy,
+                                            NameTagHealthbarPolicy::Never,
+                                            "Never",
+                                        );
+                                    });
+                                ui.end_row();
+                            }
+                        });
+
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        if ui.button("Ignore List...").clicked() {
+                            open_ignore_list = true;
+                        }
+
+                        if ui.button("Report Bug...").clicked() {
+                            open_bug_report = true;
                         }
+                    });
+                }
+                SettingsPage::Controls => {
+                    egui::ScrollArea::vertical()
+                        .max_height(400.0)
+                        .show(ui, |ui| {
+                            egui::Grid::new("controls_settings")
+                                .num_columns(2)
+                                .striped(true)
+                                .show(ui, |ui| {
+                                    for (action, key_binding) in key_bindings.bindings.iter() {
+                                        if matches!(action, KeyBindingAction::ToggleDebugUi)
+                                            && !cfg!(debug_assertions)
+                                        {
+                                            continue;
+                                        }
+
+                                        ui.label(action.description());
+
+                                        let button_text =
+                                            if ui_state_settings.rebinding == Some(action) {
+                                                egui::RichText::new("Press a key...")
+                                            } else {
+                                                egui::RichText::new(key_binding.display_name())
+                                            };
+                                        let conflict = key_bindings
+                                            .bindings
+                                            .iter()
+                                            .find(|(other_action, other_binding)| {
+                                                *other_action != action
+                                                    && *other_binding == key_binding
+                                            })
+                                            .map(|(other_action, _)| other_action);
+                                        let mut response = ui.add(
+                                            egui::Button::new(if conflict.is_some() {
+                                                button_text.color(egui::Color32::RED)
+                                            } else {
+                                                button_text
+                                            })
+                                            .min_size(egui::vec2(120.0, 0.0)),
+                                        );
+                                        if let Some(conflict) = conflict {
+                                            response = response.on_hover_text(format!(
+                                                "Also bound to: {}",
+                                                conflict.description()
+                                            ));
+                                        }
+                                        if character_settings
+                                            .hotkeys
+                                            .contains_key(action.config_name())
+                                        {
+                                            response = response
+                                                .on_hover_text("Bound for this character only");
+                                        }
+                                        if response.clicked() {
+                                            ui_state_settings.rebinding = Some(action);
+                                        }
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        if ui.button("Reset to Defaults").clicked() {
+                            key_bindings.global = KeyBindings::default().global;
+                            key_bindings.apply_overrides(&character_settings.hotkeys);
+                            ui_state_settings.rebinding = None;
+                        }
+
+                        if has_character_settings {
+                            ui.checkbox(
+                                &mut ui_state_settings.character_key_bindings,
+                                "Only for this character",
+                            );
+
+                            if ui
+                                .add_enabled(
+                                    !character_settings.hotkeys.is_empty(),
+                                    egui::Button::new("Clear Character Bindings"),
+                                )
+                                .clicked()
+                            {
+                                character_settings.hotkeys.clear();
+                                key_bindings.apply_overrides(&character_settings.hotkeys);
+                                ui_state_settings.rebinding = None;
+                            }
+                        }
+
+                        if ui_state_settings.rebinding.is_some() {
+                            ui.label("Press Escape to cancel");
+                        }
+                    });
+                }
+                SettingsPage::Accessibility => {
+                    egui::Grid::new("accessibility_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Colour Palette:");
+                            egui::ComboBox::from_id_source("accessibility_color_palette")
+                                .selected_text(color_palette_name(
+                                    accessibility_settings.color_palette,
+                                ))
+                                .show_ui(ui, |ui| {
+                                    for palette in [
+                                        ColorPalette::Default,
+                                        ColorPalette::RedGreen,
+                                        ColorPalette::BlueYellow,
+                                    ] {
+                                        ui.selectable_value(
+                                            &mut accessibility_settings.color_palette,
+                                            palette,
+                                            color_palette_name(palette),
+                                        );
+                                    }
+                                })
+                                .response
+                                .on_hover_text("Used for name tags and damage numbers");
+                            ui.end_row();
+
+                            ui.label("Minimum Font Size:");
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut accessibility_settings.minimum_font_size,
+                                    0.0..=24.0,
+                                )
+                                .step_by(1.0),
+                            )
+                            .on_hover_text("Applies to chat and tooltips, 0 to disable");
+                            ui.end_row();
+
+                            ui.label("High Contrast:");
+                            ui.checkbox(&mut accessibility_settings.high_contrast, "Enabled")
+                                .on_hover_text("Stronger item slot highlight colours");
+                            ui.end_row();
+
+                            ui.label("Sound Captions:");
+                            ui.checkbox(&mut accessibility_settings.sound_captions, "Enabled")
+                                .on_hover_text(
+                                    "Show monster aggro, low health and whisper sounds as captions",
+                                );
+                            ui.end_row();
+                        });
+                }
+                SettingsPage::Data => {
+                    let downloading = ui_state_settings.data_download_thread.is_some();
+
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add_enabled(!downloading, egui::Button::new("Verify Game Data"))
+                            .clicked()
+                        {
+                            ui_state_settings.data_verify_report =
+                                Some(verify_data(&vfs_resource.vfs));
+                            ui_state_settings.data_download_result = None;
+                        }
+
+                        let failed_paths: Vec<String> = ui_state_settings
+                            .data_verify_report
+                            .as_ref()
+                            .map(|report| {
+                                report
+                                    .failures
+                                    .iter()
+                                    .map(|failure| failure.path.clone())
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+
+                        if let (Some(overlay_url), Some(data_path)) = (
+                            data_overlay_configuration.overlay_url.clone(),
+                            data_overlay_configuration.data_path.clone(),
+                        ) {
+                            let manifest_path =
+                                data_overlay_configuration.overlay_manifest_path.clone();
+                            if ui
+                                .add_enabled(
+                                    !downloading && !failed_paths.is_empty(),
+                                    egui::Button::new("Download Missing Files"),
+                                )
+                                .clicked()
+                            {
+                                ui_state_settings.data_download_thread =
+                                    Some(std::thread::spawn(move || {
+                                        download_data_overlay(
+                                            &overlay_url,
+                                            &manifest_path,
+                                            &data_path,
+                                            &failed_paths,
+                                        )
+                                    }));
+                            }
+                        }
+                    });
+
+                    if downloading {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label("Downloading...");
+                        });
+                    } else if let Some(result) = ui_state_settings.data_download_result.as_ref() {
+                        ui.label(result);
                     }
-                });
+
+                    if let Some(report) = ui_state_settings.data_verify_report.as_ref() {
+                        ui.separator();
+                        ui.label(format!(
+                            "Verified {} files, {} failed",
+                            report.num_checked,
+                            report.failures.len()
+                        ));
+
+                        egui::ScrollArea::vertical()
+                            .max_height(200.0)
+                            .show(ui, |ui| {
+                                egui::Grid::new("data_verify_failures")
+                                    .num_columns(2)
+                                    .striped(true)
+                                    .show(ui, |ui| {
+                                        for failure in report.failures.iter() {
+                                            ui.label(&failure.path);
+                                            ui.colored_label(
+                                                egui::Color32::RED,
+                                                failure.error.to_string(),
+                                            );
+                                            ui.end_row();
+                                        }
+                                    });
+                            });
+                    }
+                }
+            }
         });
+
+    if !ui_state_windows.settings_open {
+        if let Some(opened_settings) = ui_state_settings.opened_settings.take() {
+            let closed_settings = settings_table(
+                &sound_settings,
+                &render_configuration,
+                &effect_settings,
+                &interface_settings,
+                &hud_settings,
+                &drop_notification_settings,
+                &request_settings,
+                &name_tag_settings,
+                &accessibility_settings,
+                &key_bindings,
+                query_window.get_single().ok(),
+                *msaa,
+            );
+            if closed_settings != opened_settings {
+                config_file.update(|document| {
+                    apply_changed_settings(document, &opened_settings, &closed_settings)
+                });
+            }
+        }
+    }
+
+    if open_bug_report {
+        ui_state_windows.bug_report_open = true;
+    }
+
+    if open_ignore_list {
+        ui_state_windows.ignore_list_open = true;
+    }
 }
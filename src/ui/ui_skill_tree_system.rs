@@ -4,12 +4,16 @@ use bevy::{
 };
 use bevy_egui::{egui, EguiContexts};
 
-use rose_data::SkillId;
-use rose_game_common::components::{CharacterInfo, SkillList, SkillPoints};
+use rose_data::{JobId, SkillData, SkillId};
+use rose_game_common::{
+    components::{CharacterInfo, SkillList, SkillPoints, SkillSlot},
+    messages::client::ClientMessage,
+};
 
 use crate::{
+    bundles::ability_values_get_value,
     components::PlayerCharacter,
-    resources::{GameData, UiResources, UiSpriteSheetType},
+    resources::{GameConnection, GameData, UiResources, UiSpriteSheetType},
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem, SkillTooltipType},
         ui_add_skill_tooltip,
@@ -26,11 +30,131 @@ const IID_TEXT_MUSE: i32 = 22;
 const IID_TEXT_HOWKER: i32 = 23;
 const IID_TEXT_DEALER: i32 = 24;
 
+const SKILL_TREE_COLOR_LEARNABLE: egui::Color32 = egui::Color32::from_rgb(100, 220, 100);
+const SKILL_TREE_COLOR_LEVEL_UP: egui::Color32 = egui::Color32::from_rgb(255, 200, 60);
+
 #[derive(Default)]
 pub struct UiStateSkillTree {
     skill_tree: Option<(u16, Dialog)>, // (job id, dialog)
 }
 
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum SkillTreeSlotState {
+    Locked,
+    /// Not yet learned but all requirements are met, it must be learned from a skill trainer
+    Learnable,
+    Learned,
+    /// Learned and the requirements for the next level are met
+    LevelUp(SkillSlot),
+}
+
+fn skill_requirements_met(
+    game_data: &GameData,
+    player: &PlayerTooltipQueryItem,
+    skill_data: &SkillData,
+) -> bool {
+    if let Some(job_class_id) = skill_data.required_job_class {
+        if !game_data
+            .job_class
+            .get(job_class_id)
+            .map_or(false, |job_class| {
+                job_class
+                    .jobs
+                    .contains(&JobId::new(player.character_info.job))
+            })
+        {
+            return false;
+        }
+    }
+
+    for &(required_skill_id, required_level) in skill_data.required_skills.iter() {
+        let required_base_skill_id = game_data
+            .skills
+            .get_skill(
+                SkillId::new(required_skill_id.get() + required_level.max(1) as u16 - 1).unwrap(),
+            )
+            .and_then(|required_skill_data| required_skill_data.base_skill_id)
+            .unwrap_or(required_skill_id);
+
+        if !player
+            .skill_list
+            .find_skill_level(&game_data.skills, required_base_skill_id)
+            .map_or(false, |(_, _, skill_level)| {
+                skill_level >= required_level as u32
+            })
+        {
+            return false;
+        }
+    }
+
+    for &(ability_type, value) in skill_data.required_ability.iter() {
+        if ability_values_get_value(
+            ability_type,
+            player.ability_values,
+            Some(player.character_info),
+            Some(player.experience_points),
+            Some(player.health_points),
+            Some(player.inventory),
+            Some(player.level),
+            Some(player.mana_points),
+            Some(player.move_speed),
+            Some(player.skill_points),
+            Some(player.stamina),
+            Some(player.stat_points),
+            Some(player.team),
+            Some(player.union_membership),
+        )
+        .map_or(true, |current_value| current_value < value)
+        {
+            return false;
+        }
+    }
+
+    player.skill_points.points >= skill_data.learn_point_cost
+}
+
+fn get_skill_tree_slot_state(
+    skill: &Skill,
+    base_skill_id: SkillId,
+    player: &PlayerQueryItem,
+    player_tooltip_data: Option<&PlayerTooltipQueryItem>,
+    game_data: &GameData,
+) -> SkillTreeSlotState {
+    let learned = match player
+        .skill_list
+        .find_skill_level(&game_data.skills, base_skill_id)
+    {
+        Some((skill_slot, skill_id, level)) if level >= skill.level => {
+            Some((skill_slot, skill_id, level))
+        }
+        // The earlier levels of this skill are shown in a different slot of the tree
+        Some(_) => return SkillTreeSlotState::Locked,
+        None => None,
+    };
+    let next_level = learned.map_or(skill.level.max(1), |(_, _, level)| level + 1);
+
+    if skill.limit_level > 0 && next_level > skill.limit_level {
+        return SkillTreeSlotState::Learned;
+    }
+
+    let next_skill_data = SkillId::new((skill.id + next_level - 1) as u16)
+        .and_then(|skill_id| game_data.skills.get_skill(skill_id))
+        .filter(|skill_data| learned.is_none() || skill_data.base_skill_id == Some(base_skill_id));
+    let can_learn_next = next_skill_data.map_or(false, |skill_data| {
+        player_tooltip_data.map_or(false, |player_tooltip_data| {
+            skill_requirements_met(game_data, player_tooltip_data, skill_data)
+        })
+    });
+
+    match learned {
+        Some((skill_slot, _, _)) if can_learn_next => SkillTreeSlotState::LevelUp(skill_slot),
+        Some(_) => SkillTreeSlotState::Learned,
+        None if can_learn_next => SkillTreeSlotState::Learnable,
+        None => SkillTreeSlotState::Locked,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn ui_add_skill_tree_slot(
     ui: &mut egui::Ui,
     pos: egui::Pos2,
@@ -39,6 +163,7 @@ fn ui_add_skill_tree_slot(
     player_tooltip_data: Option<&PlayerTooltipQueryItem>,
     game_data: &GameData,
     ui_resources: &UiResources,
+    level_up_skill_slot: &mut Option<SkillSlot>,
 ) -> egui::Response {
     let base_skill_id = if let Some(base_skill_id) = SkillId::new(skill.id as u16) {
         base_skill_id
@@ -105,10 +230,51 @@ fn ui_add_skill_tree_slot(
         )
         .inner;
 
-    if response.double_clicked() {
-        // player_command_events.send(PlayerCommandEvent::UseSkill(skill_slot));
+    let state =
+        get_skill_tree_slot_state(skill, base_skill_id, player, player_tooltip_data, game_data);
+    match state {
+        SkillTreeSlotState::Learnable => {
+            ui.painter().rect_stroke(
+                response.rect,
+                2.0,
+                egui::Stroke::new(2.0, SKILL_TREE_COLOR_LEARNABLE),
+            );
+        }
+        SkillTreeSlotState::LevelUp(_) => {
+            ui.painter().rect_stroke(
+                response.rect,
+                2.0,
+                egui::Stroke::new(2.0, SKILL_TREE_COLOR_LEVEL_UP),
+            );
+        }
+        SkillTreeSlotState::Locked | SkillTreeSlotState::Learned => {}
     }
 
+    if let SkillTreeSlotState::LevelUp(skill_slot) = state {
+        if response.double_clicked() {
+            *level_up_skill_slot = Some(skill_slot);
+        }
+    }
+
+    let response = response.context_menu(|ui| match state {
+        SkillTreeSlotState::LevelUp(skill_slot) => {
+            if ui.button("Level Up").clicked() {
+                *level_up_skill_slot = Some(skill_slot);
+                ui.close_menu();
+            }
+        }
+        SkillTreeSlotState::Learnable => {
+            ui.add_enabled(false, egui::Button::new("Learn"))
+                .on_disabled_hover_text("Visit a skill trainer to learn this skill");
+        }
+        SkillTreeSlotState::Learned => {
+            ui.label("Learned");
+        }
+        SkillTreeSlotState::Locked => {
+            ui.label("Requirements not met");
+        }
+    });
+
     if let Some(skill_data) = skill_data {
         response.on_hover_ui(|ui| {
             ui_add_skill_tooltip(
@@ -131,6 +297,7 @@ fn draw_skill_slots(
     game_data: &GameData,
     ui_resources: &UiResources,
     widgets: &[Widget],
+    level_up_skill_slot: &mut Option<SkillSlot>,
 ) {
     for skill in widgets.iter().filter_map(|x| match x {
         Widget::Skill(s) => Some(s),
@@ -144,6 +311,7 @@ fn draw_skill_slots(
             player_tooltip_data,
             game_data,
             ui_resources,
+            level_up_skill_slot,
         );
 
         draw_skill_slots(
@@ -153,6 +321,7 @@ fn draw_skill_slots(
             game_data,
             ui_resources,
             &skill.widgets,
+            level_up_skill_slot,
         );
     }
 }
@@ -171,6 +340,7 @@ pub fn ui_skill_tree_system(
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     query_player: Query<PlayerQuery, With<PlayerCharacter>>,
     query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
+    game_connection: Option<Res<GameConnection>>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
@@ -216,6 +386,7 @@ pub fn ui_skill_tree_system(
 
     let mut response_close_button = None;
     let mut select_base_skill_index = None;
+    let mut level_up_skill_slot = None;
 
     egui::Window::new("Skill Tree")
         .frame(egui::Frame::none())
@@ -249,6 +420,7 @@ pub fn ui_skill_tree_system(
                                 player_tooltip_data.as_ref(),
                                 &game_data,
                                 &ui_resources,
+                                &mut level_up_skill_slot,
                             )
                             .clicked()
                                 && index != 0
@@ -268,6 +440,7 @@ pub fn ui_skill_tree_system(
                             &game_data,
                             &ui_resources,
                             &base_skill.widgets,
+                            &mut level_up_skill_slot,
                         );
                     }
                 },
@@ -294,6 +467,15 @@ pub fn ui_skill_tree_system(
         }
     }
 
+    if let Some(skill_slot) = level_up_skill_slot {
+        if let Some(game_connection) = game_connection.as_ref() {
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::LevelUpSkill { skill_slot })
+                .ok();
+        }
+    }
+
     if response_close_button.map_or(false, |r| r.clicked()) {
         ui_state_windows.skill_tree_open = false;
     }
@@ -10,14 +10,15 @@ use bevy_egui::{egui, EguiContexts};
 use rose_game_common::components::StatusEffects;
 
 use crate::{
-    components::PlayerCharacter,
-    resources::{GameData, UiResources, UiSpriteSheetType},
+    components::{PlayerCharacter, Resting},
+    resources::{GameData, HudSettings, HudWidget, UiResources, UiSpriteSheetType},
 };
 
 #[derive(WorldQuery)]
 pub struct PlayerQuery<'w> {
     entity: Entity,
     status_effects: &'w StatusEffects,
+    resting: Option<&'w Resting>,
 }
 
 pub fn ui_status_effects_system(
@@ -26,7 +27,12 @@ pub fn ui_status_effects_system(
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
     time: Res<Time>,
+    hud_settings: Res<HudSettings>,
 ) {
+    if !hud_settings.is_visible(HudWidget::StatusEffects) {
+        return;
+    }
+
     let player = if let Ok(player) = query_player.get_single() {
         player
     } else {
@@ -40,6 +46,20 @@ pub fn ui_status_effects_system(
         .resizable(false)
         .show(egui_context.ctx_mut(), |ui| {
             ui.horizontal_top(|ui| {
+                if let Some(resting) = player.resting {
+                    ui.label(egui::RichText::new("💤").size(18.0))
+                        .on_hover_text("Resting\n\nTaking damage will interrupt your rest");
+
+                    if resting.near_campfire {
+                        ui.label(
+                            egui::RichText::new("🔥")
+                                .size(18.0)
+                                .color(egui::Color32::from_rgb(255, 150, 50)),
+                        )
+                        .on_hover_text("Campfire\n\nResting beside a campfire");
+                    }
+                }
+
                 for (status_effect_type, active_status_effect) in
                     player.status_effects.active.iter()
                 {
@@ -0,0 +1,56 @@
+use bevy::{
+    prelude::{Camera, Query, Res, With},
+    render::camera::RenderTarget,
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::Npc;
+
+use crate::{
+    components::{ClientEntityName, TargetCamera},
+    resources::{InterfaceSettings, SelectedTarget},
+    systems::TARGET_CAMERA_IMAGE_SIZE,
+};
+
+pub fn ui_target_camera_system(
+    mut egui_context: EguiContexts,
+    query_target_camera: Query<&Camera, With<TargetCamera>>,
+    query_name: Query<(&ClientEntityName, Option<&Npc>)>,
+    interface_settings: Res<InterfaceSettings>,
+    selected_target: Res<SelectedTarget>,
+) {
+    let Ok(camera) = query_target_camera.get_single() else {
+        return;
+    };
+    let RenderTarget::Image(image) = &camera.target else {
+        return;
+    };
+    let texture_id = egui_context.add_image(image.clone_weak());
+
+    let name = selected_target
+        .selected
+        .and_then(|entity| query_name.get(entity).ok())
+        .map_or("Target", |(name, npc)| {
+            if npc.is_some() {
+                name.as_str()
+            } else {
+                interface_settings.other_character_name(name.as_str())
+            }
+        });
+
+    egui::Window::new("Target View")
+        .id(egui::Id::new("target_camera"))
+        .title_bar(false)
+        .resizable(false)
+        .default_pos([10.0, 300.0])
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.label(name);
+            ui.image(
+                texture_id,
+                egui::vec2(
+                    TARGET_CAMERA_IMAGE_SIZE as f32,
+                    TARGET_CAMERA_IMAGE_SIZE as f32,
+                ),
+            );
+        });
+}
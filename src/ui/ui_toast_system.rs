@@ -0,0 +1,169 @@
+use std::time::Duration;
+
+use bevy::prelude::{EventReader, Local, Query, Res, Time, With};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::QuestState;
+
+use crate::{
+    components::PlayerCharacter,
+    events::{ToastCategory, ToastEvent},
+    resources::{GameData, HudSettings, InterfaceSettings},
+};
+
+const TOAST_DURATION: Duration = Duration::from_secs(5);
+const TOAST_FADE_DURATION: Duration = Duration::from_secs(1);
+const TOAST_WIDTH: f32 = 260.0;
+const MAX_TOASTS: usize = 5;
+
+const TOAST_COLOR_ITEM: egui::Color32 = egui::Color32::from_rgb(255, 255, 255);
+const TOAST_COLOR_RARE_ITEM: egui::Color32 = egui::Color32::from_rgb(255, 200, 40);
+const TOAST_COLOR_QUEST: egui::Color32 = egui::Color32::from_rgb(151, 221, 241);
+const TOAST_COLOR_SOCIAL: egui::Color32 = egui::Color32::from_rgb(201, 255, 144);
+const TOAST_COLOR_CONNECTION: egui::Color32 = egui::Color32::from_rgb(255, 160, 100);
+const TOAST_COLOR_SOUND_CAPTION: egui::Color32 = egui::Color32::from_rgb(255, 230, 120);
+const TOAST_COLOR_ACHIEVEMENT: egui::Color32 = egui::Color32::from_rgb(255, 220, 120);
+
+struct Toast {
+    category: ToastCategory,
+    text: String,
+    spawned: Duration,
+}
+
+#[derive(Default)]
+pub struct UiStateToasts {
+    toasts: Vec<Toast>,
+    previous_quests: Option<Vec<Option<(usize, Vec<u16>)>>>,
+}
+
+fn toast_color(category: ToastCategory) -> egui::Color32 {
+    match category {
+        ToastCategory::Item => TOAST_COLOR_ITEM,
+        ToastCategory::RareItem => TOAST_COLOR_RARE_ITEM,
+        ToastCategory::Quest => TOAST_COLOR_QUEST,
+        ToastCategory::Social => TOAST_COLOR_SOCIAL,
+        ToastCategory::Connection => TOAST_COLOR_CONNECTION,
+        ToastCategory::SoundCaption => TOAST_COLOR_SOUND_CAPTION,
+        ToastCategory::Achievement => TOAST_COLOR_ACHIEVEMENT,
+    }
+}
+
+pub fn ui_toast_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateToasts>,
+    mut toast_events: EventReader<ToastEvent>,
+    query_quest_state: Query<&QuestState, With<PlayerCharacter>>,
+    game_data: Res<GameData>,
+    interface_settings: Res<InterfaceSettings>,
+    hud_settings: Res<HudSettings>,
+    time: Res<Time>,
+) {
+    let ui_state = &mut *ui_state;
+    let now = time.elapsed();
+    let mut new_toasts: Vec<(ToastCategory, String)> = toast_events
+        .iter()
+        .map(|event| (event.category, event.text.clone()))
+        .collect();
+
+    // Quest progress is detected from changes to the player's active quests, as the server
+    // does not tell us which quest a trigger has updated
+    if let Ok(quest_state) = query_quest_state.get_single() {
+        let current_quests: Vec<Option<(usize, Vec<u16>)>> = quest_state
+            .active_quests
+            .iter()
+            .map(|active_quest| {
+                active_quest
+                    .as_ref()
+                    .map(|active_quest| (active_quest.quest_id, active_quest.variables.to_vec()))
+            })
+            .collect();
+
+        if let Some(previous_quests) = ui_state.previous_quests.as_ref() {
+            for (previous, current) in previous_quests.iter().zip(current_quests.iter()) {
+                let Some((quest_id, variables)) = current else {
+                    continue;
+                };
+                let quest_name = game_data
+                    .quests
+                    .get_quest_data(*quest_id)
+                    .map_or("?", |quest_data| quest_data.name);
+
+                match previous {
+                    Some((previous_quest_id, previous_variables))
+                        if previous_quest_id == quest_id =>
+                    {
+                        if previous_variables != variables {
+                            new_toasts.push((
+                                ToastCategory::Quest,
+                                format!("Quest updated: {}", quest_name),
+                            ));
+                        }
+                    }
+                    _ => new_toasts.push((
+                        ToastCategory::Quest,
+                        format!("Quest accepted: {}", quest_name),
+                    )),
+                }
+            }
+        }
+
+        ui_state.previous_quests = Some(current_quests);
+    } else {
+        ui_state.previous_quests = None;
+    }
+
+    for (category, text) in new_toasts {
+        // Only connection problems are shown whilst the HUD is hidden
+        if !interface_settings.is_toast_enabled(category)
+            || (hud_settings.hidden && category != ToastCategory::Connection)
+        {
+            continue;
+        }
+
+        if ui_state.toasts.len() == MAX_TOASTS {
+            ui_state.toasts.remove(0);
+        }
+
+        ui_state.toasts.push(Toast {
+            category,
+            text,
+            spawned: now,
+        });
+    }
+
+    ui_state
+        .toasts
+        .retain(|toast| now.saturating_sub(toast.spawned) < TOAST_DURATION);
+    if ui_state.toasts.is_empty() {
+        return;
+    }
+
+    egui::Area::new("toast_notifications")
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -80.0])
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.with_layout(egui::Layout::bottom_up(egui::Align::Max), |ui| {
+                for toast in ui_state.toasts.iter().rev() {
+                    let remaining =
+                        TOAST_DURATION.saturating_sub(now.saturating_sub(toast.spawned));
+                    let opacity = (remaining.as_secs_f32() / TOAST_FADE_DURATION.as_secs_f32())
+                        .clamp(0.0, 1.0);
+
+                    egui::Frame::popup(ui.style())
+                        .fill(ui.visuals().window_fill().linear_multiply(opacity))
+                        .stroke(egui::Stroke::new(
+                            1.0,
+                            toast_color(toast.category).linear_multiply(opacity),
+                        ))
+                        .show(ui, |ui| {
+                            ui.set_width(TOAST_WIDTH);
+                            ui.colored_label(
+                                toast_color(toast.category).linear_multiply(opacity),
+                                &toast.text,
+                            );
+                        });
+                }
+            });
+        });
+}
@@ -0,0 +1,101 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::{EventReader, Local, Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    events::{TutorialHint, TutorialHintEvent},
+    resources::{CharacterSettings, InterfaceSettings, KeyBindingAction, KeyBindings},
+};
+
+#[derive(Default)]
+pub struct UiStateTutorialHint {
+    pending: VecDeque<TutorialHint>,
+}
+
+fn get_hint_text(hint: TutorialHint, key_bindings: &KeyBindings) -> String {
+    let key_name = |action| key_bindings.get(action).display_name();
+
+    match hint {
+        TutorialHint::EnterGame => format!(
+            "Press {} to open your inventory, {} for skills and {} for quests. Press {} at any time to open the game guide.",
+            key_name(KeyBindingAction::ToggleInventory),
+            key_name(KeyBindingAction::ToggleSkillList),
+            key_name(KeyBindingAction::ToggleQuestList),
+            key_name(KeyBindingAction::Help)
+        ),
+        TutorialHint::SelectTarget => {
+            "Click on a selected monster again to attack it, or use a skill from your hotbar."
+                .to_string()
+        }
+        TutorialHint::ItemPickup => {
+            "Double-click an item in your inventory to equip or use it.".to_string()
+        }
+        TutorialHint::LearnSkill => format!(
+            "Drag skills from the skill list ({}) onto your hotbar to use them.",
+            key_name(KeyBindingAction::ToggleSkillList)
+        ),
+        TutorialHint::LevelUp => format!(
+            "You have gained stat and skill points, spend them in the character window ({}) and skill tree.",
+            key_name(KeyBindingAction::ToggleCharacterInfo)
+        ),
+        TutorialHint::PlayerDied => {
+            "You have died, choose to revive at the nearest save point or in town.".to_string()
+        }
+    }
+}
+
+pub fn ui_tutorial_hint_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateTutorialHint>,
+    mut tutorial_hint_events: EventReader<TutorialHintEvent>,
+    mut character_settings: ResMut<CharacterSettings>,
+    mut interface_settings: ResMut<InterfaceSettings>,
+    key_bindings: Res<KeyBindings>,
+) {
+    for TutorialHintEvent(hint) in tutorial_hint_events.iter() {
+        if !interface_settings.tutorial_hints
+            || character_settings.seen_tutorial_hints.contains(hint.key())
+        {
+            continue;
+        }
+
+        character_settings
+            .seen_tutorial_hints
+            .insert(hint.key().to_string());
+        ui_state.pending.push_back(*hint);
+    }
+
+    if !interface_settings.tutorial_hints {
+        ui_state.pending.clear();
+        return;
+    }
+
+    let Some(&hint) = ui_state.pending.front() else {
+        return;
+    };
+
+    let mut dismissed = false;
+    egui::Window::new("Hint")
+        .id(egui::Id::new("tutorial_hint"))
+        .title_bar(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -120.0))
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.set_max_width(320.0);
+            ui.label(get_hint_text(hint, &key_bindings));
+            ui.horizontal(|ui| {
+                if ui.button("OK").clicked() {
+                    dismissed = true;
+                }
+
+                if ui.button("Disable Hints").clicked() {
+                    interface_settings.tutorial_hints = false;
+                }
+            });
+        });
+
+    if dismissed {
+        ui_state.pending.pop_front();
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::{EventReader, Local, Res};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    events::ZoneEvent,
+    resources::{GameData, ZoneLoadTracker},
+};
+
+const ZONE_LOADING_BAR_WIDTH: f32 = 400.0;
+
+pub fn ui_zone_loading_system(
+    mut egui_context: EguiContexts,
+    mut progress_percent: Local<u8>,
+    mut zone_events: EventReader<ZoneEvent>,
+    game_data: Res<GameData>,
+    zone_load_tracker: Res<ZoneLoadTracker>,
+) {
+    for event in zone_events.iter() {
+        match *event {
+            ZoneEvent::LoadingProgress(percent) => *progress_percent = percent,
+            ZoneEvent::Loaded(_) => *progress_percent = 0,
+        }
+    }
+
+    let Some(zone_id) = zone_load_tracker.zone_id else {
+        return;
+    };
+    let zone_name = game_data
+        .zone_list
+        .get_zone(zone_id)
+        .map_or("?", |zone_data| zone_data.name);
+
+    egui::Area::new("zone_loading_bar")
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -120.0])
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_width(ZONE_LOADING_BAR_WIDTH);
+                ui.label(format!("Loading {}...", zone_name));
+
+                let text = if zone_load_tracker.handles.is_empty() {
+                    format!("{}%", *progress_percent)
+                } else {
+                    format!(
+                        "{}% ({} / {})",
+                        *progress_percent,
+                        zone_load_tracker.num_loaded,
+                        zone_load_tracker.handles.len()
+                    )
+                };
+                ui.add(egui::ProgressBar::new(*progress_percent as f32 / 100.0).text(text));
+            });
+        });
+}
@@ -76,6 +76,10 @@ pub struct Dialog {
 
     #[serde(skip)]
     pub loaded: bool,
+
+    /// Incremented every time the dialog is (re)loaded, so instances know to refresh
+    #[serde(skip)]
+    pub revision: u32,
 }
 
 impl Dialog {
@@ -140,6 +140,53 @@ impl Widget {
             Widget::Unknown => panic!("Use of unknown widget"),
         }
     }
+
+    /// The bounds of the widget, used by the debug dialog editor
+    pub fn debug_rect(&self, min: egui::Pos2) -> Option<egui::Rect> {
+        match self {
+            Widget::Button(x) => Some(x.widget_rect(min)),
+            Widget::Caption(x) => Some(egui::Rect::from_min_size(
+                min + egui::vec2(x.x, x.y),
+                egui::vec2(x.width, x.height),
+            )),
+            Widget::Checkbox(x) => Some(x.widget_rect(min)),
+            Widget::Gauge(x) => Some(x.widget_rect(min)),
+            Widget::Listbox(x) => Some(x.widget_rect(min)),
+            Widget::Editbox(x) => Some(x.widget_rect(min)),
+            Widget::Pane(x) => Some(x.widget_rect(min)),
+            Widget::RadioButton(x) => Some(x.widget_rect(min)),
+            Widget::Scrollbar(x) => Some(x.widget_rect(min)),
+            Widget::Image(x) => Some(x.widget_rect(min)),
+            Widget::Table(x) => Some(x.widget_rect(min)),
+            Widget::TabButton(x) => Some(x.widget_rect(min)),
+            Widget::ZListbox(x) => Some(x.widget_rect(min)),
+            Widget::RadioBox(_) | Widget::Skill(_) | Widget::TabbedPane(_) | Widget::Unknown => {
+                None
+            }
+        }
+    }
+
+    /// Mutable access to the X and Y position of the widget, used by the debug dialog editor
+    pub fn position_mut(&mut self) -> Option<(&mut f32, &mut f32)> {
+        match self {
+            Widget::Button(x) => Some((&mut x.x, &mut x.y)),
+            Widget::Caption(x) => Some((&mut x.x, &mut x.y)),
+            Widget::Checkbox(x) => Some((&mut x.x, &mut x.y)),
+            Widget::Gauge(x) => Some((&mut x.x, &mut x.y)),
+            Widget::Listbox(x) => Some((&mut x.x, &mut x.y)),
+            Widget::Editbox(x) => Some((&mut x.x, &mut x.y)),
+            Widget::Pane(x) => Some((&mut x.x, &mut x.y)),
+            Widget::RadioButton(x) => Some((&mut x.x, &mut x.y)),
+            Widget::Scrollbar(x) => Some((&mut x.x, &mut x.y)),
+            Widget::Skill(x) => Some((&mut x.x, &mut x.y)),
+            Widget::Image(x) => Some((&mut x.x, &mut x.y)),
+            Widget::Table(x) => Some((&mut x.x, &mut x.y)),
+            Widget::TabButton(x) => Some((&mut x.x, &mut x.y)),
+            Widget::TabbedPane(x) => Some((&mut x.x, &mut x.y)),
+            Widget::ZListbox(x) => Some((&mut x.x, &mut x.y)),
+            Widget::RadioBox(_) | Widget::Unknown => None,
+        }
+    }
 }
 
 impl DrawWidget for Widget {
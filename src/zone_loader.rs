@@ -1,6 +1,7 @@
 use std::{
     path::{Path, PathBuf},
     sync::Arc,
+    time::{Duration, Instant},
 };
 
 use anyhow::Result;
@@ -8,13 +9,13 @@ use arrayvec::ArrayVec;
 use bevy::{
     asset::{AssetLoader, BoxedFuture, LoadContext, LoadState, LoadedAsset},
     ecs::system::SystemParam,
-    hierarchy::{BuildChildren, DespawnRecursiveExt},
-    math::{Quat, Vec2, Vec3},
+    hierarchy::{BuildChildren, Children, DespawnRecursiveExt, HierarchyQueryExt},
+    math::{Quat, Vec2, Vec3, Vec3Swizzles},
     pbr::{NotShadowCaster, NotShadowReceiver},
     prelude::{
-        AssetServer, Assets, Commands, ComputedVisibility, Entity, EventReader, EventWriter,
-        GlobalTransform, Handle, HandleUntyped, Image, Local, Mesh, Res, ResMut, Transform,
-        Visibility,
+        AssetServer, Assets, Commands, Component, ComputedVisibility, Entity, EventReader,
+        EventWriter, GlobalTransform, Handle, HandleUntyped, Image, Local, Mesh, Query, Res,
+        ResMut, Time, Transform, Visibility, With,
     },
     reflect::{TypePath, TypeUuid},
     render::{
@@ -22,6 +23,7 @@ use bevy::{
         view::NoFrustumCulling,
     },
     tasks::IoTaskPool,
+    utils::HashMap,
 };
 use bevy_rapier3d::prelude::{
     AsyncCollider, Collider, CollisionGroups, ComputedColliderShape, RigidBody,
@@ -40,21 +42,26 @@ use crate::{
     animation::{MeshAnimation, TransformAnimation, ZmoTextureAssetLoader},
     audio::{SoundRadius, SpatialSound},
     components::{
-        ColliderParent, EventObject, NightTimeEffect, WarpObject, Zone, ZoneObject,
-        ZoneObjectAnimatedObject, ZoneObjectId, ZoneObjectPart, ZoneObjectTerrain,
-        COLLISION_FILTER_CLICKABLE, COLLISION_FILTER_COLLIDABLE, COLLISION_FILTER_INSPECTABLE,
-        COLLISION_FILTER_MOVEABLE, COLLISION_GROUP_PHYSICS_TOY, COLLISION_GROUP_ZONE_EVENT_OBJECT,
+        Campfire, ColliderParent, EventObject, NightTimeEffect, PlayerCharacter, SitPoints,
+        WarpObject, WaterPlane, Zone, ZoneBlock, ZoneObject, ZoneObjectAnimatedObject,
+        ZoneObjectId, ZoneObjectPart, ZoneObjectTerrain, COLLISION_FILTER_CLICKABLE,
+        COLLISION_FILTER_COLLIDABLE, COLLISION_FILTER_INSPECTABLE, COLLISION_FILTER_MOVEABLE,
+        COLLISION_GROUP_PHYSICS_TOY, COLLISION_GROUP_ZONE_EVENT_OBJECT,
         COLLISION_GROUP_ZONE_OBJECT, COLLISION_GROUP_ZONE_TERRAIN,
         COLLISION_GROUP_ZONE_WARP_OBJECT, COLLISION_GROUP_ZONE_WATER,
     },
     effect_loader::{decode_blend_factor, decode_blend_op, spawn_effect},
     events::{LoadZoneEvent, ZoneEvent},
+    far_terrain::{FarTerrainHeightmap, FAR_TERRAIN_HEIGHT_OFFSET},
     render::{
-        EffectMeshAnimationRenderState, EffectMeshMaterial, ObjectMaterial, ParticleMaterial,
-        SkyMaterial, TerrainMaterial, WaterMaterial, MESH_ATTRIBUTE_UV_1,
+        EffectMeshAnimationRenderState, EffectMeshMaterial, FarTerrainMaterial, ObjectMaterial,
+        ParticleMaterial, SkyMaterial, TerrainMaterial, WaterMaterial, MESH_ATTRIBUTE_UV_1,
         TERRAIN_MATERIAL_MAX_TEXTURES, TERRAIN_MESH_ATTRIBUTE_TILE_INFO,
     },
-    resources::{CurrentZone, DebugInspector, GameData, SpecularTexture},
+    resources::{
+        CurrentZone, DebugInspector, GameData, RenderConfiguration, SpecularTexture,
+        ZoneLoadTracker,
+    },
     VfsResource,
 };
 
@@ -68,6 +75,7 @@ pub struct ZoneLoaderBlock {
     pub block_x: usize,
     pub block_y: usize,
     pub him: HimFile,
+    pub him_hash: md5::Digest,
     pub til: Option<TilFile>,
     pub ifo: Option<IfoFile>,
     pub lit_cnst: Option<LitFile>,
@@ -79,6 +87,14 @@ pub struct ZoneNpc {
     pub npc_id: NpcId,
 }
 
+pub struct ZoneMonsterSpawn {
+    pub position: Vec3,
+    /// Radius in cm around the spawn point which monsters are spawned within
+    pub range: f32,
+    pub interval: Duration,
+    pub npc_ids: Vec<NpcId>,
+}
+
 #[derive(TypeUuid, TypePath)]
 #[uuid = "596e2c17-f2dd-4276-8df4-1e94dc0d056b"]
 pub struct ZoneLoaderAsset {
@@ -88,10 +104,25 @@ pub struct ZoneLoaderAsset {
     pub zsc_cnst: ZscFile,
     pub zsc_deco: ZscFile,
     pub blocks: Vec<Option<Box<ZoneLoaderBlock>>>,
+    pub far_terrain: FarTerrainHeightmap,
     pub npcs: Vec<ZoneNpc>,
+    pub monster_spawns: Vec<ZoneMonsterSpawn>,
 }
 
 impl ZoneLoaderAsset {
+    /// Returns true if there is a loaded heightmap block at this position
+    pub fn is_on_terrain(&self, x: f32, y: f32) -> bool {
+        let block_x = x / (16.0 * self.zon.grid_per_patch * self.zon.grid_size);
+        let block_y = 65.0 - (y / (16.0 * self.zon.grid_per_patch * self.zon.grid_size));
+        if !(0.0..64.0).contains(&block_x) || !(0.0..64.0).contains(&block_y) {
+            return false;
+        }
+
+        self.blocks
+            .get(block_x as usize + block_y as usize * 64)
+            .map_or(false, |block| block.is_some())
+    }
+
     pub fn get_terrain_height(&self, x: f32, y: f32) -> f32 {
         let block_x = x / (16.0 * self.zon.grid_per_patch * self.zon.grid_size);
         let block_y = 65.0 - (y / (16.0 * self.zon.grid_per_patch * self.zon.grid_size));
@@ -230,6 +261,7 @@ async fn load_zone<'a, 'b>(
         .filter_map(|result| result.ok());
 
     let mut npcs = Vec::new();
+    let mut monster_spawns = Vec::new();
     let mut blocks = Vec::new();
     blocks.resize_with(64 * 64, || None);
     for block in zone_blocks_iterator {
@@ -258,11 +290,37 @@ async fn load_zone<'a, 'b>(
                     ) + objects_offset,
                 });
             }
+
+            for spawn in ifo.monster_spawns.iter() {
+                monster_spawns.push(ZoneMonsterSpawn {
+                    position: Vec3::new(
+                        spawn.object.position.x,
+                        spawn.object.position.y,
+                        spawn.object.position.z,
+                    ) + objects_offset,
+                    range: spawn.range as f32 * 100.0,
+                    interval: Duration::from_secs(spawn.interval as u64),
+                    npc_ids: spawn
+                        .basic_spawns
+                        .iter()
+                        .chain(spawn.tactic_spawns.iter())
+                        .filter_map(|monster| NpcId::new(monster.id as u16))
+                        .collect(),
+                });
+            }
         }
 
         blocks[index] = Some(block);
     }
 
+    let far_terrain_hash = FarTerrainHeightmap::source_hash(&blocks);
+    let far_terrain =
+        FarTerrainHeightmap::load_cached(zone_id, &far_terrain_hash).unwrap_or_else(|| {
+            let far_terrain = FarTerrainHeightmap::from_blocks(&blocks);
+            far_terrain.save_cached(zone_id, &far_terrain_hash);
+            far_terrain
+        });
+
     load_context.set_default_asset(LoadedAsset::new(ZoneLoaderAsset {
         zone_path: zone_path.into(),
         zone_id,
@@ -270,7 +328,9 @@ async fn load_zone<'a, 'b>(
         zsc_cnst,
         zsc_deco,
         blocks,
+        far_terrain,
         npcs,
+        monster_spawns,
     }));
     Ok(())
 }
@@ -281,14 +341,11 @@ async fn load_block_files<'a>(
     block_x: usize,
     block_y: usize,
 ) -> Result<Box<ZoneLoaderBlock>, anyhow::Error> {
-    let him = RoseFile::read(
-        RoseFileReader::from(
-            &load_context
-                .read_asset_bytes(zone_path.join(format!("{}_{}.HIM", block_x, block_y)))
-                .await?,
-        ),
-        &Default::default(),
-    )?;
+    let him_bytes = load_context
+        .read_asset_bytes(zone_path.join(format!("{}_{}.HIM", block_x, block_y)))
+        .await?;
+    let him_hash = md5::compute(&him_bytes);
+    let him = RoseFile::read(RoseFileReader::from(&him_bytes), &Default::default())?;
 
     let til = if let Ok(data) = load_context
         .read_asset_bytes(zone_path.join(format!("{}_{}.TIL", block_x, block_y)))
@@ -337,6 +394,7 @@ async fn load_block_files<'a>(
         block_y,
         til,
         him,
+        him_hash,
         ifo,
         lit_cnst,
         lit_deco,
@@ -354,6 +412,7 @@ pub struct SpawnZoneParams<'w, 's> {
     pub sky_materials: ResMut<'w, Assets<SkyMaterial>>,
     pub terrain_materials: ResMut<'w, Assets<TerrainMaterial>>,
     pub effect_mesh_materials: ResMut<'w, Assets<EffectMeshMaterial>>,
+    pub far_terrain_materials: ResMut<'w, Assets<FarTerrainMaterial>>,
     pub particle_materials: ResMut<'w, Assets<ParticleMaterial>>,
     pub object_materials: ResMut<'w, Assets<ObjectMaterial>>,
     pub water_materials: ResMut<'w, Assets<WaterMaterial>>,
@@ -366,6 +425,7 @@ pub struct CachedZone {
 
 pub enum LoadingZoneState {
     Loading,
+    Spawning(ZoneSpawnState),
     Spawned,
 }
 
@@ -373,10 +433,42 @@ pub struct LoadingZone {
     pub state: LoadingZoneState,
     pub handle: Handle<ZoneLoaderAsset>,
     pub despawn_other_zones: bool,
-    pub zone_assets: Vec<HandleUntyped>,
     pub ready_frames: usize,
 }
 
+#[derive(SystemParam)]
+pub struct ZoneLoadDependencyQuery<'w, 's> {
+    pub query_children: Query<'w, 's, &'static Children>,
+    pub query_assets: Query<
+        'w,
+        's,
+        (
+            Option<&'static Handle<Mesh>>,
+            Option<&'static Handle<ObjectMaterial>>,
+            Option<&'static Handle<TerrainMaterial>>,
+            Option<&'static Handle<EffectMeshMaterial>>,
+            Option<&'static Handle<ParticleMaterial>>,
+            Option<&'static Handle<WaterMaterial>>,
+            Option<&'static Handle<SkyMaterial>>,
+        ),
+    >,
+}
+
+// Report the zone as loaded even if some of its assets are still loading after this long
+const ZONE_LOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub const ZONE_NUM_BLOCKS: usize = 64 * 64;
+
+/// Size of a zone block in metres
+pub const ZONE_BLOCK_SIZE: f32 = 160.0;
+
+// Streamed blocks are only despawned once they are this much further than the streaming
+// distance, so walking along a block edge does not repeatedly spawn and despawn it
+const ZONE_BLOCK_STREAMING_UNLOAD_MARGIN: f32 = 80.0;
+
+// Time spent spawning zone blocks each frame, so large zones do not hitch whilst loading
+const ZONE_SPAWN_FRAME_BUDGET: Duration = Duration::from_millis(8);
+
 #[derive(Default)]
 pub struct ZoneLoaderCache {
     pub cache: Vec<Option<CachedZone>>,
@@ -385,12 +477,20 @@ pub struct ZoneLoaderCache {
 pub fn zone_loader_system(
     mut zone_loader_cache: Local<ZoneLoaderCache>,
     mut loading_zones: Local<Vec<LoadingZone>>,
+    mut last_progress_percent: Local<Option<u8>>,
     mut load_zone_events: EventReader<LoadZoneEvent>,
     mut zone_events: EventWriter<ZoneEvent>,
     mut spawn_zone_params: SpawnZoneParams,
     zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
     mut debug_inspector_state: ResMut<DebugInspector>,
+    mut zone_load_tracker: ResMut<ZoneLoadTracker>,
+    zone_load_dependency_query: ZoneLoadDependencyQuery,
+    render_configuration: Res<RenderConfiguration>,
+    query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
+    time: Res<Time>,
 ) {
+    let streaming = zone_block_streaming(&render_configuration, &query_player);
+
     if zone_loader_cache.cache.is_empty() {
         zone_loader_cache
             .cache
@@ -422,16 +522,17 @@ pub fn zone_loader_system(
             state: LoadingZoneState::Loading,
             handle: cached_zone.data_handle.clone(),
             despawn_other_zones: event.despawn_other_zones,
-            zone_assets: Vec::default(),
             ready_frames: 0,
         });
+        zone_load_tracker.begin(event.id, time.elapsed());
     }
 
+    let mut despawned_zone_entities = Vec::new();
     let mut index = 0;
     while index < loading_zones.len() {
         let loading_zone = &mut loading_zones[index];
 
-        match loading_zone.state {
+        match &mut loading_zone.state {
             LoadingZoneState::Loading => {
                 match spawn_zone_params
                     .asset_server
@@ -455,36 +556,34 @@ pub fn zone_loader_system(
                                             .commands
                                             .entity(spawned_entity)
                                             .despawn_recursive();
+                                        despawned_zone_entities.push(spawned_entity);
                                     }
                                 }
 
                                 spawn_zone_params.commands.remove_resource::<CurrentZone>();
                             }
 
-                            // Spawn next zone
-                            if let Ok((zone_entity, loading_assets)) =
-                                spawn_zone(&mut spawn_zone_params, zone_data)
+                            // Begin spawning next zone, the blocks are spawned over the
+                            // following frames
+                            if let Ok(spawn_state) =
+                                begin_spawn_zone(&mut spawn_zone_params, zone_data)
                             {
                                 zone_loader_cache.cache[zone_data.zone_id.get() as usize] =
                                     Some(CachedZone {
                                         data_handle: loading_zone.handle.clone(),
-                                        spawned_entity: Some(zone_entity),
+                                        spawned_entity: Some(spawn_state.zone_entity),
                                     });
 
-                                spawn_zone_params.commands.insert_resource(CurrentZone {
-                                    id: zone_data.zone_id,
-                                    handle: loading_zone.handle.clone(),
-                                });
-
-                                debug_inspector_state.entity = Some(zone_entity);
-                                loading_zone.zone_assets = loading_assets;
-                            }
+                                if zone_load_tracker.zone_id == Some(zone_data.zone_id) {
+                                    zone_load_tracker.zone_entity = Some(spawn_state.zone_entity);
+                                }
 
-                            if loading_zone.zone_assets.is_empty() {
+                                loading_zone.state = LoadingZoneState::Spawning(spawn_state);
+                                index += 1;
+                            } else {
                                 zone_events.send(ZoneEvent::Loaded(zone_data.zone_id));
                                 loading_zones.remove(index);
-                            } else {
-                                loading_zone.state = LoadingZoneState::Spawned;
+                                zone_load_tracker.finish();
                             }
                         } else {
                             index += 1;
@@ -492,54 +591,359 @@ pub fn zone_loader_system(
                     }
                     LoadState::Unloaded | LoadState::Failed => {
                         loading_zones.remove(index);
+                        zone_load_tracker.finish();
                     }
                 }
             }
+            LoadingZoneState::Spawning(spawn_state) => {
+                let Some(zone_data) = zone_loader_assets.get(&loading_zone.handle) else {
+                    index += 1;
+                    continue;
+                };
+                let is_tracked = zone_load_tracker.zone_id == Some(zone_data.zone_id);
+
+                // Stop spawning if the zone was despawned by another zone loading
+                if despawned_zone_entities.contains(&spawn_state.zone_entity)
+                    || spawn_zone_params
+                        .commands
+                        .get_entity(spawn_state.zone_entity)
+                        .is_none()
+                {
+                    loading_zones.remove(index);
+                    continue;
+                }
+
+                let is_complete = spawn_zone_blocks(
+                    &mut spawn_zone_params,
+                    zone_data,
+                    spawn_state,
+                    ZONE_SPAWN_FRAME_BUDGET,
+                    streaming,
+                );
+
+                if is_tracked {
+                    zone_load_tracker.blocks_spawned = spawn_state.next_block;
+                    for handle in spawn_state.loading_assets.drain(..) {
+                        zone_load_tracker.track(handle);
+                    }
+                } else {
+                    spawn_state.loading_assets.clear();
+                }
+
+                if is_complete {
+                    spawn_zone_params.commands.insert_resource(CurrentZone {
+                        id: zone_data.zone_id,
+                        handle: loading_zone.handle.clone(),
+                    });
+                    debug_inspector_state.entity = Some(spawn_state.zone_entity);
+
+                    // Keep the spawn state on the zone entity for block streaming, then wait a
+                    // frame for the spawn commands to be applied so we can collect the
+                    // dependencies of the spawned zone entities
+                    let zone_entity = spawn_state.zone_entity;
+                    if let LoadingZoneState::Spawning(spawn_state) =
+                        std::mem::replace(&mut loading_zone.state, LoadingZoneState::Spawned)
+                    {
+                        spawn_zone_params
+                            .commands
+                            .entity(zone_entity)
+                            .insert(spawn_state);
+                    }
+                }
+                index += 1;
+            }
             LoadingZoneState::Spawned => {
-                let is_loading = loading_zone.zone_assets.iter().any(|handle| {
-                    matches!(
-                        spawn_zone_params.asset_server.get_load_state(handle),
-                        LoadState::NotLoaded | LoadState::Loading
-                    )
-                });
+                let Some(zone_data) = zone_loader_assets.get(&loading_zone.handle) else {
+                    index += 1;
+                    continue;
+                };
+                let is_tracked = zone_load_tracker.zone_id == Some(zone_data.zone_id);
+
+                if is_tracked && !zone_load_tracker.dependencies_collected {
+                    if let Some(zone_entity) = zone_load_tracker.zone_entity {
+                        collect_zone_dependencies(
+                            &mut zone_load_tracker,
+                            &spawn_zone_params,
+                            &zone_load_dependency_query,
+                            zone_entity,
+                        );
+                    }
+                    zone_load_tracker.dependencies_collected = true;
+                }
+
+                let is_loading = if is_tracked {
+                    let asset_server = &spawn_zone_params.asset_server;
+                    zone_load_tracker.num_loaded = zone_load_tracker
+                        .handles
+                        .iter()
+                        .filter(|handle| {
+                            !matches!(
+                                asset_server.get_load_state(*handle),
+                                LoadState::NotLoaded | LoadState::Loading
+                            )
+                        })
+                        .count();
+
+                    if zone_load_tracker.num_loaded == zone_load_tracker.handles.len() {
+                        false
+                    } else if time.elapsed().saturating_sub(zone_load_tracker.started)
+                        > ZONE_LOAD_TIMEOUT
+                    {
+                        warn!(
+                            "Zone {} load timed out with {} of {} assets loaded",
+                            zone_data.zone_id.get(),
+                            zone_load_tracker.num_loaded,
+                            zone_load_tracker.handles.len()
+                        );
+                        false
+                    } else {
+                        true
+                    }
+                } else {
+                    false
+                };
 
                 if is_loading {
                     index += 1;
-                } else if let Some(zone_data) = zone_loader_assets.get(&loading_zone.handle) {
+                } else {
                     // The physics system will take 2 frames to initialise colliders properly
                     loading_zone.ready_frames += 1;
 
                     if loading_zone.ready_frames == 2 {
                         zone_events.send(ZoneEvent::Loaded(zone_data.zone_id));
                         loading_zones.remove(index);
+
+                        if is_tracked {
+                            zone_load_tracker.finish();
+                        }
                     } else {
                         index += 1;
                     }
-                } else {
-                    index += 1;
                 }
             }
         }
     }
+
+    let progress_percent = zone_load_tracker
+        .is_loading()
+        .then(|| (zone_load_tracker.progress() * 100.0) as u8);
+    if progress_percent != *last_progress_percent {
+        if let Some(progress_percent) = progress_percent {
+            zone_events.send(ZoneEvent::LoadingProgress(progress_percent));
+        }
+        *last_progress_percent = progress_percent;
+    }
 }
 
-pub fn spawn_zone(
+/// Returns the position and distance to spawn zone blocks within, or None if every block should
+/// be spawned because block streaming is disabled or there is no player to stream around.
+fn zone_block_streaming(
+    render_configuration: &RenderConfiguration,
+    query_player: &Query<&GlobalTransform, With<PlayerCharacter>>,
+) -> Option<(Vec2, f32)> {
+    let distance = render_configuration.zone_block_streaming_distance?;
+    let player_transform = query_player.get_single().ok()?;
+    Some((player_transform.translation().xz(), distance))
+}
+
+/// Spawns the blocks of the current zone which are within the streaming distance of the player
+/// and despawns those which are too far away, nearest blocks are spawned first.
+pub fn zone_block_streaming_system(
+    mut spawn_zone_params: SpawnZoneParams,
+    mut query_zone: Query<(&Zone, &mut ZoneSpawnState)>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    render_configuration: Res<RenderConfiguration>,
+    query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
+) {
+    let Some(zone_data) = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+    else {
+        return;
+    };
+    let streaming = zone_block_streaming(&render_configuration, &query_player);
+
+    for (zone, mut spawn_state) in query_zone.iter_mut() {
+        if zone.id != zone_data.zone_id {
+            continue;
+        }
+
+        let mut spawn_blocks = Vec::new();
+
+        for (block_index, block_data) in zone_data.blocks.iter().enumerate() {
+            if block_data.is_none() {
+                continue;
+            }
+
+            let distance = streaming.map_or(0.0, |(position, _)| {
+                zone_block_distance(position, block_index % 64, block_index / 64)
+            });
+            let streaming_distance = streaming.map_or(f32::INFINITY, |(_, distance)| distance);
+
+            if let Some(&block_entity) = spawn_state.block_entities.get(&block_index) {
+                if distance > streaming_distance + ZONE_BLOCK_STREAMING_UNLOAD_MARGIN {
+                    spawn_zone_params
+                        .commands
+                        .entity(block_entity)
+                        .despawn_recursive();
+                    spawn_state.block_entities.remove(&block_index);
+                }
+            } else if distance <= streaming_distance {
+                spawn_blocks.push((distance, block_index));
+            }
+        }
+
+        spawn_blocks.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let started = Instant::now();
+        for (_, block_index) in spawn_blocks {
+            if let Some(block_entity) = spawn_zone_block(
+                &mut spawn_zone_params,
+                zone_data,
+                &mut spawn_state,
+                block_index % 64,
+                block_index / 64,
+            ) {
+                spawn_state.block_entities.insert(block_index, block_entity);
+            }
+
+            if started.elapsed() >= ZONE_SPAWN_FRAME_BUDGET {
+                break;
+            }
+        }
+
+        // Streamed blocks are not part of zone loading, so their assets are not tracked
+        spawn_state.loading_assets.clear();
+    }
+}
+
+fn collect_zone_dependencies(
+    zone_load_tracker: &mut ZoneLoadTracker,
+    spawn_zone_params: &SpawnZoneParams,
+    zone_load_dependency_query: &ZoneLoadDependencyQuery,
+    zone_entity: Entity,
+) {
+    let asset_server = &spawn_zone_params.asset_server;
+    let mut track = |handle: HandleUntyped| {
+        // Only assets loaded from file have a load state, ignore any created at runtime
+        if asset_server.get_handle_path(&handle).is_some() {
+            zone_load_tracker.track(handle);
+        }
+    };
+
+    for entity in zone_load_dependency_query
+        .query_children
+        .iter_descendants(zone_entity)
+    {
+        let Ok((
+            mesh,
+            object_material,
+            terrain_material,
+            effect_mesh_material,
+            particle_material,
+            water_material,
+            sky_material,
+        )) = zone_load_dependency_query.query_assets.get(entity)
+        else {
+            continue;
+        };
+
+        if let Some(mesh) = mesh {
+            track(mesh.clone_untyped());
+        }
+
+        if let Some(material) =
+            object_material.and_then(|handle| spawn_zone_params.object_materials.get(handle))
+        {
+            for texture in [&material.base_texture, &material.lightmap_texture]
+                .into_iter()
+                .flatten()
+            {
+                track(texture.clone_untyped());
+            }
+        }
+
+        if let Some(material) =
+            terrain_material.and_then(|handle| spawn_zone_params.terrain_materials.get(handle))
+        {
+            for texture in material.textures.iter() {
+                track(texture.clone_untyped());
+            }
+        }
+
+        if let Some(material) = effect_mesh_material
+            .and_then(|handle| spawn_zone_params.effect_mesh_materials.get(handle))
+        {
+            for texture in [&material.base_texture, &material.animation_texture]
+                .into_iter()
+                .flatten()
+            {
+                track(texture.clone_untyped());
+            }
+        }
+
+        if let Some(material) =
+            particle_material.and_then(|handle| spawn_zone_params.particle_materials.get(handle))
+        {
+            track(material.texture.clone_untyped());
+        }
+
+        if let Some(material) =
+            water_material.and_then(|handle| spawn_zone_params.water_materials.get(handle))
+        {
+            for texture in material.textures.iter() {
+                track(texture.clone_untyped());
+            }
+        }
+
+        if let Some(material) =
+            sky_material.and_then(|handle| spawn_zone_params.sky_materials.get(handle))
+        {
+            for texture in [&material.texture_day, &material.texture_night]
+                .into_iter()
+                .flatten()
+            {
+                track(texture.clone_untyped());
+            }
+        }
+    }
+}
+
+/// A zone which is being spawned a few blocks at a time over several frames, once spawned it is
+/// inserted on the zone entity so block streaming can spawn and despawn blocks later.
+#[derive(Component)]
+pub struct ZoneSpawnState {
+    pub zone_entity: Entity,
+    pub next_block: usize,
+    /// Assets requested by the blocks spawned since this was last drained
+    pub loading_assets: Vec<HandleUntyped>,
+    /// The `ZoneBlock` entity of each spawned block, indexed by `block_x + block_y * 64`
+    pub block_entities: HashMap<usize, Entity>,
+    tile_textures: Vec<Handle<Image>>,
+    water_material: Handle<WaterMaterial>,
+    far_terrain_material: Handle<FarTerrainMaterial>,
+}
+
+impl ZoneSpawnState {
+    pub fn is_complete(&self) -> bool {
+        self.next_block >= ZONE_NUM_BLOCKS
+    }
+}
+
+/// Spawns the zone entity and skybox, the blocks are then spawned by `spawn_zone_blocks`
+pub fn begin_spawn_zone(
     params: &mut SpawnZoneParams,
     zone_data: &ZoneLoaderAsset,
-) -> Result<(Entity, Vec<HandleUntyped>), anyhow::Error> {
+) -> Result<ZoneSpawnState, anyhow::Error> {
     let SpawnZoneParams {
         commands,
         asset_server,
         game_data,
-        vfs_resource,
-        meshes,
-        specular_texture,
         sky_materials,
-        terrain_materials,
-        effect_mesh_materials,
-        particle_materials,
-        object_materials,
+        far_terrain_materials,
         water_materials,
+        ..
     } = params;
 
     let zone_list_entry = game_data
@@ -569,7 +973,6 @@ pub fn spawn_zone(
         })
     };
 
-    let mut zone_loading_assets: Vec<HandleUntyped> = Vec::default();
     let zone_entity = commands
         .spawn((
             Zone {
@@ -590,185 +993,305 @@ pub fn spawn_zone(
         commands.entity(zone_entity).add_child(skybox_entity);
     }
 
-    for block_y in 0..64 {
-        for block_x in 0..64 {
-            if let Some(block_data) = zone_data.blocks[block_x + block_y * 64].as_ref() {
-                let terrain_entity = spawn_terrain(
-                    commands,
-                    asset_server,
-                    meshes,
-                    terrain_materials,
-                    &tile_textures,
-                    zone_data,
-                    block_data,
-                );
-                commands.entity(zone_entity).add_child(terrain_entity);
-
-                if let Some(ifo) = block_data.ifo.as_ref() {
-                    let lightmap_path = zone_data
-                        .zone_path
-                        .join(format!("{}_{}/LIGHTMAP/", block_x, block_y));
-
-                    for (plane_start, plane_end) in ifo.water_planes.iter() {
-                        let water_entity = spawn_water(
-                            commands,
-                            meshes,
-                            &water_material,
-                            ifo.water_size,
-                            Vec3::new(plane_start.x, plane_start.y, plane_start.z),
-                            Vec3::new(plane_end.x, plane_end.y, plane_end.z),
-                        );
-                        commands.entity(zone_entity).add_child(water_entity);
-                    }
+    // Far terrain is only shown for blocks beyond the terrain view distance, it is always
+    // spawned so the view distance can be changed while in the zone
+    let far_terrain_material = far_terrain_materials.add(FarTerrainMaterial::default());
+
+    Ok(ZoneSpawnState {
+        zone_entity,
+        next_block: 0,
+        loading_assets: Vec::default(),
+        block_entities: HashMap::default(),
+        tile_textures,
+        water_material,
+        far_terrain_material,
+    })
+}
 
-                    for (ifo_object_id, event_object) in ifo.event_objects.iter().enumerate() {
-                        let event_entity = spawn_object(
-                            commands,
-                            asset_server,
-                            &mut zone_loading_assets,
-                            vfs_resource,
-                            effect_mesh_materials.as_mut(),
-                            particle_materials.as_mut(),
-                            object_materials.as_mut(),
-                            specular_texture,
-                            &game_data.zsc_event_object,
-                            &lightmap_path,
-                            None,
-                            &event_object.object,
-                            ifo_object_id,
-                            event_object.object.object_id as usize,
-                            ZoneObject::EventObject,
-                            ZoneObject::EventObjectPart,
-                            COLLISION_GROUP_ZONE_EVENT_OBJECT,
-                        );
+/// Distance in metres from a position on the xz plane to the nearest edge of a zone block
+pub fn zone_block_distance(position: Vec2, block_x: usize, block_y: usize) -> f32 {
+    let block_min = Vec2::new(
+        ZONE_BLOCK_SIZE * block_x as f32,
+        -ZONE_BLOCK_SIZE * (65.0 - block_y as f32),
+    );
+    let block_max = block_min + Vec2::splat(ZONE_BLOCK_SIZE);
+    position.distance(position.clamp(block_min, block_max))
+}
 
-                        commands.entity(event_entity).insert(EventObject::new(
-                            event_object.quest_trigger_name.clone(),
-                            event_object.script_function_name.clone(),
-                        ));
-                        commands.entity(zone_entity).add_child(event_entity);
-                    }
+/// Spawns the next blocks of the zone until the time budget for this frame is used up,
+/// returns true once every block has been spawned.
+///
+/// Far terrain is spawned for every block, but when `streaming` is set to a position and
+/// distance only the blocks within that distance are spawned in full.
+pub fn spawn_zone_blocks(
+    params: &mut SpawnZoneParams,
+    zone_data: &ZoneLoaderAsset,
+    spawn_state: &mut ZoneSpawnState,
+    budget: Duration,
+    streaming: Option<(Vec2, f32)>,
+) -> bool {
+    let started = Instant::now();
+
+    while !spawn_state.is_complete() {
+        let block_index = spawn_state.next_block;
+        let block_x = block_index % 64;
+        let block_y = block_index / 64;
+
+        if let Some(far_terrain_entity) = spawn_far_terrain(
+            &mut params.commands,
+            &mut params.meshes,
+            &spawn_state.far_terrain_material,
+            zone_data,
+            block_x,
+            block_y,
+        ) {
+            params
+                .commands
+                .entity(spawn_state.zone_entity)
+                .add_child(far_terrain_entity);
+        }
 
-                    for (ifo_object_id, warp_object) in ifo.warps.iter().enumerate() {
-                        let warp_entity = spawn_object(
-                            commands,
-                            asset_server,
-                            &mut zone_loading_assets,
-                            vfs_resource,
-                            effect_mesh_materials.as_mut(),
-                            particle_materials.as_mut(),
-                            object_materials.as_mut(),
-                            specular_texture,
-                            &game_data.zsc_special_object,
-                            &lightmap_path,
-                            None,
-                            warp_object,
-                            ifo_object_id,
-                            1,
-                            ZoneObject::WarpObject,
-                            ZoneObject::WarpObjectPart,
-                            COLLISION_GROUP_ZONE_WARP_OBJECT,
-                        );
+        if streaming.map_or(true, |(position, distance)| {
+            zone_block_distance(position, block_x, block_y) <= distance
+        }) {
+            if let Some(block_entity) =
+                spawn_zone_block(params, zone_data, spawn_state, block_x, block_y)
+            {
+                spawn_state.block_entities.insert(block_index, block_entity);
+            }
+        }
+        spawn_state.next_block += 1;
 
-                        commands
-                            .entity(warp_entity)
-                            .insert(WarpObject::new(WarpGateId::new(warp_object.warp_id)));
-                        commands.entity(zone_entity).add_child(warp_entity);
-                    }
+        if started.elapsed() >= budget {
+            break;
+        }
+    }
 
-                    for (ifo_object_id, object_instance) in ifo.cnst_objects.iter().enumerate() {
-                        let lit_object = block_data.lit_cnst.as_ref().and_then(|lit| {
-                            lit.objects
-                                .iter()
-                                .find(|lit_object| lit_object.id as usize == ifo_object_id + 1)
-                        });
-
-                        let object_entity = spawn_object(
-                            commands,
-                            asset_server,
-                            &mut zone_loading_assets,
-                            vfs_resource,
-                            effect_mesh_materials.as_mut(),
-                            particle_materials.as_mut(),
-                            object_materials.as_mut(),
-                            specular_texture,
-                            &zone_data.zsc_cnst,
-                            &lightmap_path,
-                            lit_object,
-                            object_instance,
-                            ifo_object_id,
-                            object_instance.object_id as usize,
-                            ZoneObject::CnstObject,
-                            ZoneObject::CnstObjectPart,
-                            COLLISION_GROUP_ZONE_OBJECT,
-                        );
-                        commands.entity(zone_entity).add_child(object_entity);
-                    }
+    spawn_state.is_complete()
+}
 
-                    for (ifo_object_id, object_instance) in ifo.deco_objects.iter().enumerate() {
-                        let lit_object = block_data.lit_deco.as_ref().and_then(|lit| {
-                            lit.objects
-                                .iter()
-                                .find(|lit_object| lit_object.id as usize == ifo_object_id + 1)
-                        });
-
-                        let object_entity = spawn_object(
-                            commands,
-                            asset_server,
-                            &mut zone_loading_assets,
-                            vfs_resource,
-                            effect_mesh_materials.as_mut(),
-                            particle_materials.as_mut(),
-                            object_materials.as_mut(),
-                            specular_texture,
-                            &zone_data.zsc_deco,
-                            &lightmap_path,
-                            lit_object,
-                            object_instance,
-                            ifo_object_id,
-                            object_instance.object_id as usize,
-                            ZoneObject::DecoObject,
-                            ZoneObject::DecoObjectPart,
-                            COLLISION_GROUP_ZONE_OBJECT,
-                        );
-                        commands.entity(zone_entity).add_child(object_entity);
-                    }
+/// Spawns the terrain, water and objects of a zone block as children of a single block entity,
+/// so the block can be despawned again when block streaming unloads it.
+fn spawn_zone_block(
+    params: &mut SpawnZoneParams,
+    zone_data: &ZoneLoaderAsset,
+    spawn_state: &mut ZoneSpawnState,
+    block_x: usize,
+    block_y: usize,
+) -> Option<Entity> {
+    let SpawnZoneParams {
+        commands,
+        asset_server,
+        game_data,
+        vfs_resource,
+        meshes,
+        specular_texture,
+        terrain_materials,
+        effect_mesh_materials,
+        particle_materials,
+        object_materials,
+        ..
+    } = params;
+    let zone_entity = spawn_state.zone_entity;
 
-                    for object_instance in ifo.animated_objects.iter() {
-                        let object_entity = spawn_animated_object(
-                            commands,
-                            asset_server,
-                            effect_mesh_materials.as_mut(),
-                            &game_data.stb_morph_object,
-                            object_instance,
-                        );
-                        commands.entity(zone_entity).add_child(object_entity);
-                    }
+    let zone_list_entry = game_data.zone_list.get_zone(zone_data.zone_id)?;
 
-                    for (ifo_object_id, effect_object) in ifo.effect_objects.iter().enumerate() {
-                        let object_entity = spawn_effect_object(
-                            commands,
-                            asset_server,
-                            vfs_resource,
-                            effect_mesh_materials.as_mut(),
-                            particle_materials.as_mut(),
-                            effect_object,
-                            ifo_object_id,
-                        );
-                        commands.entity(zone_entity).add_child(object_entity);
-                    }
+    let block_data = zone_data.blocks[block_x + block_y * 64].as_ref()?;
+    let block_entity = commands
+        .spawn((
+            ZoneBlock { block_x, block_y },
+            Visibility::default(),
+            ComputedVisibility::default(),
+            Transform::default(),
+            GlobalTransform::default(),
+        ))
+        .id();
+    commands.entity(zone_entity).add_child(block_entity);
 
-                    for (ifo_object_id, sound_object) in ifo.sound_objects.iter().enumerate() {
-                        let object_entity =
-                            spawn_sound_object(commands, asset_server, sound_object, ifo_object_id);
-                        commands.entity(zone_entity).add_child(object_entity);
-                    }
-                }
+    let terrain_entity = spawn_terrain(
+        commands,
+        asset_server,
+        meshes,
+        terrain_materials,
+        &spawn_state.tile_textures,
+        zone_data,
+        block_data,
+    );
+    commands.entity(block_entity).add_child(terrain_entity);
+
+    if let Some(ifo) = block_data.ifo.as_ref() {
+        let lightmap_path = zone_data
+            .zone_path
+            .join(format!("{}_{}/LIGHTMAP/", block_x, block_y));
+
+        for (plane_start, plane_end) in ifo.water_planes.iter() {
+            let water_entity = spawn_water(
+                commands,
+                meshes,
+                &spawn_state.water_material,
+                ifo.water_size,
+                Vec3::new(plane_start.x, plane_start.y, plane_start.z),
+                Vec3::new(plane_end.x, plane_end.y, plane_end.z),
+            );
+            commands.entity(block_entity).add_child(water_entity);
+        }
+
+        for (ifo_object_id, event_object) in ifo.event_objects.iter().enumerate() {
+            let event_entity = spawn_object(
+                commands,
+                asset_server,
+                &mut spawn_state.loading_assets,
+                vfs_resource,
+                effect_mesh_materials.as_mut(),
+                particle_materials.as_mut(),
+                object_materials.as_mut(),
+                specular_texture,
+                &game_data.zsc_event_object,
+                &lightmap_path,
+                None,
+                &event_object.object,
+                ifo_object_id,
+                event_object.object.object_id as usize,
+                ZoneObject::EventObject,
+                ZoneObject::EventObjectPart,
+                COLLISION_GROUP_ZONE_EVENT_OBJECT,
+            );
+
+            commands.entity(event_entity).insert(EventObject::new(
+                event_object.quest_trigger_name.clone(),
+                event_object.script_function_name.clone(),
+            ));
+            commands.entity(block_entity).add_child(event_entity);
+        }
+
+        for (ifo_object_id, warp_object) in ifo.warps.iter().enumerate() {
+            let warp_entity = spawn_object(
+                commands,
+                asset_server,
+                &mut spawn_state.loading_assets,
+                vfs_resource,
+                effect_mesh_materials.as_mut(),
+                particle_materials.as_mut(),
+                object_materials.as_mut(),
+                specular_texture,
+                &game_data.zsc_special_object,
+                &lightmap_path,
+                None,
+                warp_object,
+                ifo_object_id,
+                1,
+                ZoneObject::WarpObject,
+                ZoneObject::WarpObjectPart,
+                COLLISION_GROUP_ZONE_WARP_OBJECT,
+            );
+
+            commands
+                .entity(warp_entity)
+                .insert(WarpObject::new(WarpGateId::new(warp_object.warp_id)));
+            commands.entity(block_entity).add_child(warp_entity);
+        }
+
+        for (ifo_object_id, object_instance) in ifo.cnst_objects.iter().enumerate() {
+            let lit_object = block_data.lit_cnst.as_ref().and_then(|lit| {
+                lit.objects
+                    .iter()
+                    .find(|lit_object| lit_object.id as usize == ifo_object_id + 1)
+            });
+
+            let object_entity = spawn_object(
+                commands,
+                asset_server,
+                &mut spawn_state.loading_assets,
+                vfs_resource,
+                effect_mesh_materials.as_mut(),
+                particle_materials.as_mut(),
+                object_materials.as_mut(),
+                specular_texture,
+                &zone_data.zsc_cnst,
+                &lightmap_path,
+                lit_object,
+                object_instance,
+                ifo_object_id,
+                object_instance.object_id as usize,
+                ZoneObject::CnstObject,
+                ZoneObject::CnstObjectPart,
+                COLLISION_GROUP_ZONE_OBJECT,
+            );
+            commands.entity(block_entity).add_child(object_entity);
+        }
+
+        for (ifo_object_id, object_instance) in ifo.deco_objects.iter().enumerate() {
+            let lit_object = block_data.lit_deco.as_ref().and_then(|lit| {
+                lit.objects
+                    .iter()
+                    .find(|lit_object| lit_object.id as usize == ifo_object_id + 1)
+            });
+
+            let object_entity = spawn_object(
+                commands,
+                asset_server,
+                &mut spawn_state.loading_assets,
+                vfs_resource,
+                effect_mesh_materials.as_mut(),
+                particle_materials.as_mut(),
+                object_materials.as_mut(),
+                specular_texture,
+                &zone_data.zsc_deco,
+                &lightmap_path,
+                lit_object,
+                object_instance,
+                ifo_object_id,
+                object_instance.object_id as usize,
+                ZoneObject::DecoObject,
+                ZoneObject::DecoObjectPart,
+                COLLISION_GROUP_ZONE_OBJECT,
+            );
+            if is_campfire_object(&zone_data.zsc_deco, object_instance.object_id as usize) {
+                commands.entity(object_entity).insert(Campfire);
+            }
+            if let Some(sit_points) = game_data.sit_points.get(
+                &zone_list_entry.zsc_deco_path.path().to_string_lossy(),
+                object_instance.object_id as usize,
+            ) {
+                commands.entity(object_entity).insert(SitPoints {
+                    points: sit_points.to_vec(),
+                });
             }
+            commands.entity(block_entity).add_child(object_entity);
+        }
+
+        for object_instance in ifo.animated_objects.iter() {
+            let object_entity = spawn_animated_object(
+                commands,
+                asset_server,
+                effect_mesh_materials.as_mut(),
+                &game_data.stb_morph_object,
+                object_instance,
+            );
+            commands.entity(block_entity).add_child(object_entity);
+        }
+
+        for (ifo_object_id, effect_object) in ifo.effect_objects.iter().enumerate() {
+            let object_entity = spawn_effect_object(
+                commands,
+                asset_server,
+                vfs_resource,
+                effect_mesh_materials.as_mut(),
+                particle_materials.as_mut(),
+                effect_object,
+                ifo_object_id,
+            );
+            commands.entity(block_entity).add_child(object_entity);
+        }
+
+        for (ifo_object_id, sound_object) in ifo.sound_objects.iter().enumerate() {
+            let object_entity =
+                spawn_sound_object(commands, asset_server, sound_object, ifo_object_id);
+            commands.entity(block_entity).add_child(object_entity);
         }
     }
 
-    Ok((zone_entity, zone_loading_assets))
+    Some(block_entity)
 }
 
 const SKYBOX_MODEL_SCALE: f32 = 10.0;
@@ -1016,6 +1539,38 @@ fn spawn_terrain(
         .id()
 }
 
+fn spawn_far_terrain(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    far_terrain_material: &Handle<FarTerrainMaterial>,
+    zone_data: &ZoneLoaderAsset,
+    block_x: usize,
+    block_y: usize,
+) -> Option<Entity> {
+    let mesh = zone_data.far_terrain.create_block_mesh(block_x, block_y)?;
+    let offset_x = 160.0 * block_x as f32;
+    let offset_y = 160.0 * (65.0 - block_y as f32);
+
+    Some(
+        commands
+            .spawn((
+                ZoneObject::FarTerrain(ZoneObjectTerrain {
+                    block_x: block_x as u32,
+                    block_y: block_y as u32,
+                }),
+                meshes.add(mesh),
+                far_terrain_material.clone(),
+                Transform::from_xyz(offset_x, -FAR_TERRAIN_HEIGHT_OFFSET, -offset_y),
+                GlobalTransform::default(),
+                Visibility::Hidden,
+                ComputedVisibility::default(),
+                NotShadowCaster,
+                NotShadowReceiver,
+            ))
+            .id(),
+    )
+}
+
 fn spawn_water(
     commands: &mut Commands,
     meshes: &mut Assets<Mesh>,
@@ -1066,6 +1621,7 @@ fn spawn_water(
     commands
         .spawn((
             ZoneObject::Water,
+            WaterPlane::new(start, end),
             meshes.add(mesh),
             water_material.clone(),
             Transform::default(),
@@ -1081,6 +1637,28 @@ fn spawn_water(
         .id()
 }
 
+/// Effect file names used by campfire deco objects, torches, braziers and fire
+/// damage effects also live under fire named folders so only the file name is checked
+const CAMPFIRE_EFFECT_NAMES: &[&str] = &["CAMPFIRE", "CAMP_FIRE", "BONFIRE"];
+
+fn is_campfire_object(zsc: &ZscFile, zsc_object_id: usize) -> bool {
+    let Some(object) = zsc.objects.get(zsc_object_id) else {
+        return false;
+    };
+
+    object.effects.iter().any(|object_effect| {
+        zsc.effects
+            .get(object_effect.effect_id as usize)
+            .and_then(|effect_path| effect_path.path().file_stem())
+            .map_or(false, |file_stem| {
+                let file_stem = file_stem.to_string_lossy().to_uppercase();
+                CAMPFIRE_EFFECT_NAMES
+                    .iter()
+                    .any(|name| file_stem.contains(name))
+            })
+    })
+}
+
 fn spawn_object(
     commands: &mut Commands,
     asset_server: &AssetServer,